@@ -23,4 +23,7 @@ defmodule CtaraDhruv.Dasha do
 
   def complete_level(engine, request),
     do: Native.call_engine(&Native.dasha_run/2, engine, Map.put(request, :op, :complete_level))
+
+  def transitions(engine, request),
+    do: Native.call_engine(&Native.dasha_run/2, engine, Map.put(request, :op, :transitions))
 end
@@ -14,6 +14,9 @@ defmodule CtaraDhruv.Jyotish do
     do:
       Native.call_engine(&Native.jyotish_run/2, engine, Map.put(request, :op, :graha_longitudes))
 
+  def graha_speeds(engine, request),
+    do: Native.call_engine(&Native.jyotish_run/2, engine, Map.put(request, :op, :graha_speeds))
+
   def moving_osculating_apogees(engine, request),
     do:
       Native.call_engine(
@@ -20,6 +20,12 @@ defmodule CtaraDhruv.Math do
         Map.put(request, :op, :nakshatra28_from_longitude)
       )
 
+  def kp_sublord(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :kp_sublord))
+
+  def gandanta_status(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :gandanta_status))
+
   def rashi_from_tropical(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :rashi_from_tropical))
 
@@ -67,6 +73,12 @@ defmodule CtaraDhruv.Math do
   def hora_lord(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :hora_lord))
 
+  def nakshatra_lord(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :nakshatra_lord))
+
+  def navatara_from(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :navatara_from))
+
   def masa_lord(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :masa_lord))
 
@@ -101,6 +113,12 @@ defmodule CtaraDhruv.Math do
   def panchadha_maitri(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :panchadha_maitri))
 
+  def relationship_matrix(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :relationship_matrix))
+
+  def whole_sign_bhavas(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :whole_sign_bhavas))
+
   def dignity_in_rashi(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :dignity_in_rashi))
 
@@ -135,6 +153,12 @@ defmodule CtaraDhruv.Math do
   def graha_drishti_matrix(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :graha_drishti_matrix))
 
+  def rashi_drishti(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :rashi_drishti))
+
+  def rashi_drishti_matrix(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :rashi_drishti_matrix))
+
   def sun_based_upagrahas(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :sun_based_upagrahas))
 
@@ -156,6 +180,9 @@ defmodule CtaraDhruv.Math do
   def calculate_sav(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :calculate_sav))
 
+  def transit_bindus(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :transit_bindus))
+
   def trikona_sodhana(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :trikona_sodhana))
 
@@ -15,6 +15,9 @@ defmodule CtaraDhruv.Panchang do
   def nakshatra(engine, request),
     do: Native.call_engine(&Native.panchang_run/2, engine, Map.put(request, :op, :nakshatra))
 
+  def tithi_day(engine, request),
+    do: Native.call_engine(&Native.panchang_run/2, engine, Map.put(request, :op, :tithi_day))
+
   def vaar(engine, request),
     do: Native.call_engine(&Native.panchang_run/2, engine, Map.put(request, :op, :vaar))
 
@@ -36,6 +39,9 @@ defmodule CtaraDhruv.Panchang do
   def daily(engine, request),
     do: Native.call_engine(&Native.panchang_run/2, engine, Map.put(request, :op, :daily))
 
+  def diff(engine, request),
+    do: Native.call_engine(&Native.panchang_run/2, engine, Map.put(request, :op, :diff))
+
   def elongation_at(engine, request),
     do: Native.call_engine(&Native.panchang_run/2, engine, Map.put(request, :op, :elongation_at))
 
@@ -93,6 +99,12 @@ defmodule CtaraDhruv.Panchang do
   def ghatika_from_elapsed(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :ghatika_from_elapsed))
 
+  def ghatika_to_duration(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :ghatika_to_duration))
+
+  def duration_to_ghatika(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :duration_to_ghatika))
+
   def ghatikas_since_sunrise(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :ghatikas_since_sunrise))
 end
@@ -15,6 +15,36 @@ defmodule CtaraDhruv.Search do
   def sankranti(engine, request),
     do: Native.call_engine(&Native.search_run/2, engine, Map.put(request, :op, :sankranti))
 
+  def moon_rashi_ingresses(engine, request),
+    do:
+      Native.call_engine(
+        &Native.search_run/2,
+        engine,
+        Map.put(request, :op, :moon_rashi_ingresses)
+      )
+
+  def nakshatra_ingress(engine, request),
+    do:
+      Native.call_engine(
+        &Native.search_run/2,
+        engine,
+        Map.put(request, :op, :nakshatra_ingress)
+      )
+
   def motion(engine, request),
     do: Native.call_engine(&Native.search_run/2, engine, Map.put(request, :op, :motion))
+
+  def node_station(engine, request),
+    do: Native.call_engine(&Native.search_run/2, engine, Map.put(request, :op, :node_station))
+
+  def retrograde_intervals(engine, request),
+    do:
+      Native.call_engine(
+        &Native.search_run/2,
+        engine,
+        Map.put(request, :op, :retrograde_intervals)
+      )
+
+  def heliacal(engine, request),
+    do: Native.call_engine(&Native.search_run/2, engine, Map.put(request, :op, :heliacal))
 end
@@ -12,6 +12,9 @@ defmodule CtaraDhruv.Time do
   def nutation(request),
     do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :nutation))
 
+  def node_speed(request),
+    do: Native.call_util(&Native.util_run/1, Map.put(request, :op, :node_speed))
+
   def nutation_utc(engine, request),
     do: Native.call_engine(&Native.time_run/2, engine, Map.put(request, :op, :nutation_utc))
 
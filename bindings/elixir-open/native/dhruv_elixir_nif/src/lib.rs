@@ -5,8 +5,8 @@ use std::sync::{Arc, RwLock};
 use dhruv_config::{ConfigResolver, DefaultsMode, load_with_discovery};
 use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query, StateVector};
 use dhruv_frames::{
-    PrecessionModel, ReferencePlane, cartesian_state_to_spherical_state, cartesian_to_spherical,
-    nutation_iau2000b,
+    DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane, cartesian_state_to_spherical_state,
+    cartesian_to_spherical, nutation_iau2000b,
 };
 use dhruv_search::ConjunctionConfig;
 use dhruv_search::operations::{
@@ -17,24 +17,30 @@ use dhruv_search::operations::{
     SankrantiQuery, SankrantiResult, SankrantiTarget, TaraOperation, TaraOutputKind, TaraResult,
 };
 use dhruv_search::{
-    GrahaLongitudeKind, GrahaLongitudesConfig, all_upagrahas_for_date,
-    all_upagrahas_for_date_with_config, amsha_charts_for_date, arudha_padas_for_date,
-    ashtakavarga_for_date, avastha_for_date, balas_for_date, bhavabala_for_date,
-    charakaraka_for_date, core_bindus, drishti_for_date, graha_positions as graha_positions_fn,
-    moving_osculating_apogees_for_date, shadbala_for_date, sidereal_bhavas_for_date,
-    sidereal_lagna_for_date, sidereal_mc_for_date, special_lagnas_for_date, vimsopaka_for_date,
+    DashaRangePeriod, PANCHANG_INCLUDE_ALL, PANCHANG_INCLUDE_AYANA, PANCHANG_INCLUDE_MASA,
+    PANCHANG_INCLUDE_VARSHA, SankrantiConfig, StationaryConfig, ayanamsha, body_ecliptic_lon_lat,
+    conjunction, dasha_child_period_for_birth, dasha_child_period_with_inputs,
+    dasha_children_for_birth, dasha_children_with_inputs, dasha_complete_level_for_birth,
+    dasha_complete_level_with_inputs, dasha_hierarchy_for_birth, dasha_hierarchy_with_inputs,
+    dasha_level0_entity_for_birth, dasha_level0_entity_with_inputs, dasha_level0_for_birth,
+    dasha_level0_with_inputs, dasha_snapshot_at, dasha_snapshot_with_inputs,
+    dasha_transitions_in_range, dasha_transitions_with_inputs, elongation_at,
+    full_kundali_for_date, ghatika_from_sunrises, graha_longitudes, graha_speeds,
+    hora_from_sunrises, karana_at, lunar_node, motion, nakshatra_at, panchang,
+    set_time_conversion_policy, sidereal_sum_at, tara as tara_op, tithi_at, vaar_from_sunrises,
+    vedic_day_sunrises, yoga_at,
 };
 use dhruv_search::{
-    PANCHANG_INCLUDE_ALL, PANCHANG_INCLUDE_AYANA, PANCHANG_INCLUDE_MASA, PANCHANG_INCLUDE_VARSHA,
-    SankrantiConfig, StationaryConfig, ayanamsha, body_ecliptic_lon_lat, conjunction,
-    dasha_child_period_for_birth, dasha_child_period_with_inputs, dasha_children_for_birth,
-    dasha_children_with_inputs, dasha_complete_level_for_birth, dasha_complete_level_with_inputs,
-    dasha_hierarchy_for_birth, dasha_hierarchy_with_inputs, dasha_level0_entity_for_birth,
-    dasha_level0_entity_with_inputs, dasha_level0_for_birth, dasha_level0_with_inputs,
-    dasha_snapshot_at, dasha_snapshot_with_inputs, elongation_at, full_kundali_for_date,
-    ghatika_from_sunrises, graha_longitudes, hora_from_sunrises, karana_at, lunar_node, motion,
-    nakshatra_at, panchang, set_time_conversion_policy, sidereal_sum_at, tara as tara_op, tithi_at,
-    vaar_from_sunrises, vedic_day_sunrises, yoga_at,
+    GrahaLongitudeKind, GrahaLongitudesConfig, HeliacalEvent, HeliacalEventConfig, HeliacalKind,
+    HeliacalTarget, PanchangDiff, PanchangInfo, TransitAshtakavargaConfig, TransitHouseMode,
+    all_upagrahas_for_date, all_upagrahas_for_date_with_config, amsha_charts_for_date,
+    arudha_padas_for_date, ashtakavarga_for_date, avastha_for_date, balas_for_date,
+    bhavabala_for_date, charakaraka_for_date, core_bindus, drishti_for_date,
+    graha_positions as graha_positions_fn, moving_osculating_apogees_for_date,
+    next_heliacal_rising, next_heliacal_setting, next_nakshatra_ingress, next_node_station,
+    panchang_diff, prev_heliacal_rising, prev_heliacal_setting, retrograde_intervals,
+    search_heliacal_events, shadbala_for_date, sidereal_bhavas_for_date, sidereal_lagna_for_date,
+    sidereal_mc_for_date, special_lagnas_for_date, transit_bindus, vimsopaka_for_date,
 };
 use dhruv_tara::apparent::{apply_aberration, apply_light_deflection};
 use dhruv_tara::galactic::galactic_anticenter_icrs;
@@ -48,27 +54,30 @@ use dhruv_time::{
 use dhruv_vedic_base::bhava_types::ALL_BHAVA_SYSTEMS;
 use dhruv_vedic_base::bhava_types::SayanadiGhatikaRounding;
 use dhruv_vedic_base::combustion::{
-    all_combustion_status as all_combustion_status_fn,
+    CombustionConfig, all_combustion_status as all_combustion_status_fn,
     combustion_threshold as combustion_threshold_fn, is_combust as is_combust_fn,
 };
-use dhruv_vedic_base::dasha::yogini_name;
 use dhruv_vedic_base::dasha::{
     ALL_DASHA_SYSTEMS, DashaEntity, DashaHierarchy, DashaLevel, DashaPeriod, DashaSnapshot,
-    DashaSystem, DashaVariationConfig, RashiDashaInputs, SubPeriodMethod, YoginiScheme,
+    DashaSystem, DashaVariationConfig, DashaYearLength, RashiDashaInputs, SubPeriodMethod,
+    YoginiScheme,
 };
+use dhruv_vedic_base::dasha::{nakshatra_dasha_lord, yogini_name};
 use dhruv_vedic_base::drishti::{
     DrishtiEntry, GrahaDrishtiMatrix, graha_drishti, graha_drishti_matrix,
 };
-use dhruv_vedic_base::ghatika::ghatika_from_elapsed;
+use dhruv_vedic_base::ghatika::{duration_to_ghatika, ghatika_from_elapsed, ghatika_to_duration};
 use dhruv_vedic_base::graha_relationships::{
     BeneficNature, ChandraBeneficRule, NaisargikaMaitri, TatkalikaMaitri, debilitation_degree,
     dignity_in_rashi, dignity_in_rashi_with_positions, exaltation_degree, graha_gender, hora_lord,
     masa_lord, moolatrikone_range, moon_benefic_nature, naisargika_maitri, natural_benefic_malefic,
-    node_dignity_in_rashi, panchadha_maitri, samvatsara_lord, tatkalika_maitri,
+    node_dignity_in_rashi, panchadha_maitri, relationship_matrix, samvatsara_lord,
+    tatkalika_maitri,
 };
 use dhruv_vedic_base::riseset::{approximate_local_noon_jd, compute_all_events, compute_rise_set};
 use dhruv_vedic_base::riseset_types::{
-    GeoLocation, RiseSetConfig, RiseSetEvent, RiseSetResult, SunLimb,
+    DayDefinitionFallback, GeoLocation, RefractionModel, RiseSetConfig, RiseSetEvent,
+    RiseSetResult, SunLimb,
 };
 use dhruv_vedic_base::special_lagna::ghatikas_since_sunrise;
 use dhruv_vedic_base::sphuta::{ALL_SPHUTAS, SphutalInputs, all_sphutas};
@@ -76,12 +85,14 @@ use dhruv_vedic_base::{
     ALL_GRAHAS, ALL_MASAS, ALL_NAKSHATRAS_27, ALL_NAKSHATRAS_28, ALL_RASHIS, ALL_SAMVATSARAS,
     ALL_UPAGRAHAS, ALL_VAARS, Amsha, AmshaRequest, AyanamshaSystem, BhavaConfig,
     BhavaReferenceMode, BhavaResult, BhavaStartingPoint, BhavaSystem, CharakarakaResult,
-    CharakarakaScheme, Graha, GulikaMaandiPlanet, LunarNode, Nakshatra28Info, NodeDignityPolicy,
-    NodeMode, RashiInfo, SunBasedUpagrahas, TimeUpagrahaConfig, TimeUpagrahaPoint, Upagraha,
-    amsha_variation_catalog, amsha_variation_info, compute_bhavas, default_amsha_variation,
-    is_valid_amsha_variation, lagna_longitude_rad, mc_longitude_rad, nakshatra_from_longitude,
-    nakshatra_from_tropical, nakshatra28_from_longitude, nakshatra28_from_tropical, ramc_rad,
-    rashi_from_longitude, rashi_from_tropical, sun_based_upagrahas, time_upagraha_jd,
+    CharakarakaScheme, CustomAyanamsha, Graha, GulikaMaandiPlanet, LunarNode, Nakshatra28Info,
+    NodeDignityPolicy, NodeMode, RashiInfo, SamvatsaraScheme, SunBasedUpagrahas,
+    TimeUpagrahaConfig, TimeUpagrahaPoint, Upagraha, amsha_variation_catalog, amsha_variation_info,
+    compute_bhavas, default_amsha_variation, gandanta_status, is_valid_amsha_variation, kp_sublord,
+    lagna_longitude_rad, mc_longitude_rad, nakshatra_from_longitude, nakshatra_from_tropical,
+    nakshatra28_from_longitude, nakshatra28_from_tropical, navatara_from, node_speed_deg_per_day,
+    ramc_rad, rashi_drishti, rashi_drishti_matrix, rashi_from_longitude, rashi_from_tropical,
+    sun_based_upagrahas, time_upagraha_jd, whole_sign_from_lagna,
 };
 use dhruv_vedic_base::{
     calculate_all_bav, calculate_ashtakavarga, calculate_bav, calculate_sav, ekadhipatya_sodhana,
@@ -254,6 +265,7 @@ struct PanchangRequest {
     include_calendar: Option<bool>,
     riseset_config: Option<RiseSetConfigInput>,
     sankranti_config: Option<SankrantiConfigInput>,
+    sankranti_config_b: Option<SankrantiConfigInput>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -265,6 +277,8 @@ struct SearchRequest {
     body: Option<EnumInput>,
     kind: Option<EnumInput>,
     target: Option<EnumInput>,
+    tara: Option<EnumInput>,
+    location: Option<GeoLocationInput>,
     at_jd_tdb: Option<f64>,
     start_jd_tdb: Option<f64>,
     end_jd_tdb: Option<f64>,
@@ -273,6 +287,7 @@ struct SearchRequest {
     end_utc: Option<UtcInput>,
     config: Option<SearchConfigInput>,
     sankranti_config: Option<SankrantiConfigInput>,
+    heliacal_config: Option<HeliacalConfigInput>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -319,6 +334,11 @@ struct DashaRequest {
     child_entity: Option<DashaEntityInput>,
     child_level: Option<EnumInput>,
     parent_periods: Option<Vec<DashaPeriodInput>>,
+    start_utc: Option<UtcInput>,
+    start_jd: Option<f64>,
+    end_utc: Option<UtcInput>,
+    end_jd: Option<f64>,
+    level: Option<EnumInput>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -352,6 +372,11 @@ struct RiseSetConfigInput {
     use_refraction: Option<bool>,
     altitude_correction: Option<bool>,
     sun_limb: Option<EnumInput>,
+    refraction_model: Option<EnumInput>,
+    refraction_pressure_hpa: Option<f64>,
+    refraction_temperature_c: Option<f64>,
+    refraction_arcmin: Option<f64>,
+    day_fallback: Option<EnumInput>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -370,12 +395,27 @@ struct BhavaConfigInput {
 #[derive(Debug, Clone, Deserialize)]
 struct SankrantiConfigInput {
     ayanamsha_system: Option<EnumInput>,
+    custom_ayanamsha: Option<CustomAyanamshaInput>,
     use_nutation: Option<bool>,
     precession_model: Option<EnumInput>,
     reference_plane: Option<EnumInput>,
     step_size_days: Option<f64>,
     max_iterations: Option<u32>,
     convergence_days: Option<f64>,
+    samvatsara_scheme: Option<EnumInput>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct HeliacalConfigInput {
+    arcus_visionis_deg: Option<f64>,
+    min_altitude_deg: Option<f64>,
+    min_elongation_deg: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct CustomAyanamshaInput {
+    anchor_deg: f64,
+    anchor_jd_tdb: f64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -395,6 +435,7 @@ struct GrahaPositionsConfigInput {
     include_lagna: Option<bool>,
     include_outer_planets: Option<bool>,
     include_bhava: Option<bool>,
+    include_flags: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -479,6 +520,7 @@ struct DashaVariationInput {
     level_methods: Option<Vec<EnumInput>>,
     yogini_scheme: Option<EnumInput>,
     use_abhijit: Option<bool>,
+    year_length: Option<EnumInput>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -514,10 +556,13 @@ const FRAME_VARIANTS: [Frame; 2] = [Frame::IcrfJ2000, Frame::EclipticJ2000];
 const LUNAR_NODE_VARIANTS: [LunarNode; 2] = [LunarNode::Rahu, LunarNode::Ketu];
 const NODE_MODE_VARIANTS: [NodeMode; 2] = [NodeMode::Mean, NodeMode::True];
 const NODE_BACKEND_VARIANTS: [NodeBackend; 2] = [NodeBackend::Analytic, NodeBackend::Engine];
-const AYANAMSHA_MODE_VARIANTS: [AyanamshaMode; 3] = [
+const TRANSIT_HOUSE_MODE_VARIANTS: [TransitHouseMode; 2] =
+    [TransitHouseMode::WholeSign, TransitHouseMode::Chalit];
+const AYANAMSHA_MODE_VARIANTS: [AyanamshaMode; 4] = [
     AyanamshaMode::Mean,
     AyanamshaMode::True,
     AyanamshaMode::Unified,
+    AyanamshaMode::Rate,
 ];
 const RISESET_EVENT_VARIANTS: [RiseSetEvent; 8] = [
     RiseSetEvent::Sunrise,
@@ -530,6 +575,13 @@ const RISESET_EVENT_VARIANTS: [RiseSetEvent; 8] = [
     RiseSetEvent::AstronomicalDusk,
 ];
 const SUN_LIMB_VARIANTS: [SunLimb; 3] = [SunLimb::UpperLimb, SunLimb::Center, SunLimb::LowerLimb];
+const DAY_FALLBACK_VARIANTS: [DayDefinitionFallback; 3] = [
+    DayDefinitionFallback::Error,
+    DayDefinitionFallback::CivilTwilight,
+    DayDefinitionFallback::LocalSixAm,
+];
+/// `RefractionModel` names in code order (0-3); the variants carry data.
+const REFRACTION_MODEL_NAMES: [&str; 4] = ["standard", "bennett", "saemundsson", "custom"];
 const REFERENCE_PLANE_VARIANTS: [ReferencePlane; 2] =
     [ReferencePlane::Ecliptic, ReferencePlane::Invariable];
 const BHAVA_REFERENCE_MODE_VARIANTS: [BhavaReferenceMode; 2] = [
@@ -538,6 +590,7 @@ const BHAVA_REFERENCE_MODE_VARIANTS: [BhavaReferenceMode; 2] = [
 ];
 const GRAHAN_KIND_VARIANTS: [GrahanKind; 2] = [GrahanKind::Chandra, GrahanKind::Surya];
 const MOTION_KIND_VARIANTS: [MotionKind; 2] = [MotionKind::Stationary, MotionKind::MaxSpeed];
+const HELIACAL_KIND_VARIANTS: [HeliacalKind; 2] = [HeliacalKind::Rising, HeliacalKind::Setting];
 const LUNAR_PHASE_KIND_VARIANTS: [LunarPhaseKind; 2] =
     [LunarPhaseKind::Amavasya, LunarPhaseKind::Purnima];
 const TARA_OUTPUT_VARIANTS: [TaraOutputKind; 3] = [
@@ -553,6 +606,11 @@ const CHARAKARAKA_SCHEME_VARIANTS: [CharakarakaScheme; 4] = [
     CharakarakaScheme::SevenPkMergedMk,
     CharakarakaScheme::MixedParashara,
 ];
+const SAMVATSARA_SCHEME_VARIANTS: [SamvatsaraScheme; 3] = [
+    SamvatsaraScheme::SouthIndian,
+    SamvatsaraScheme::NorthIndian,
+    SamvatsaraScheme::Barhaspatya,
+];
 const NODE_DIGNITY_POLICY_VARIANTS: [NodeDignityPolicy; 2] = [
     NodeDignityPolicy::SignLordBased,
     NodeDignityPolicy::AlwaysSama,
@@ -574,6 +632,12 @@ const GULIKA_MAANDI_PLANET_VARIANTS: [GulikaMaandiPlanet; 2] =
     [GulikaMaandiPlanet::Rahu, GulikaMaandiPlanet::Saturn];
 const YOGINI_SCHEME_VARIANTS: [YoginiScheme; 2] =
     [YoginiScheme::Default, YoginiScheme::LaDeepanshuGiri];
+const DASHA_YEAR_LENGTH_VARIANTS: [DashaYearLength; 4] = [
+    DashaYearLength::Julian,
+    DashaYearLength::Savana,
+    DashaYearLength::Tropical,
+    DashaYearLength::Sidereal,
+];
 const SUB_PERIOD_METHOD_VARIANTS: [SubPeriodMethod; 4] = [
     SubPeriodMethod::ProportionalFromParent,
     SubPeriodMethod::EqualFromNext,
@@ -697,6 +761,16 @@ fn raw_required_f64(raw: &Value, key: &str) -> Result<f64, rustler::Error> {
         .ok_or(rustler::Error::BadArg)
 }
 
+/// Optional `moon_orb_deg`; absent keeps the BPHS default.
+fn raw_combustion_config(raw: &Value) -> Result<CombustionConfig, rustler::Error> {
+    let mut config = CombustionConfig::default();
+    if let Some(value) = raw.get("moon_orb_deg").filter(|value| !value.is_null()) {
+        config.moon_orb_deg = value.as_f64().ok_or(rustler::Error::BadArg)?;
+    }
+    config.validate().map_err(|_| rustler::Error::BadArg)?;
+    Ok(config)
+}
+
 fn raw_required_bool(raw: &Value, key: &str) -> Result<bool, rustler::Error> {
     raw.get(key)
         .and_then(Value::as_bool)
@@ -758,6 +832,38 @@ fn raw_u8_array<const N: usize>(raw: &Value, key: &str) -> Result<[u8; N], rustl
     Ok(out)
 }
 
+/// A `BhavaResult` map as returned by the bhava ops (`lagna_deg`, `mc_deg`,
+/// 12 `bhavas`).
+fn raw_bhava_result(raw: &Value, key: &str) -> Result<BhavaResult, rustler::Error> {
+    let value = raw.get(key).ok_or(rustler::Error::BadArg)?;
+    let entries = value
+        .get("bhavas")
+        .and_then(Value::as_array)
+        .filter(|entries| entries.len() == 12)
+        .ok_or(rustler::Error::BadArg)?;
+    let mut bhavas = [dhruv_vedic_base::Bhava {
+        number: 0,
+        cusp_deg: 0.0,
+        start_deg: 0.0,
+        end_deg: 0.0,
+        madhya_deg: 0.0,
+    }; 12];
+    for (bhava, entry) in bhavas.iter_mut().zip(entries) {
+        *bhava = dhruv_vedic_base::Bhava {
+            number: raw_required_u8(entry, "number")?,
+            cusp_deg: raw_required_f64(entry, "cusp_deg")?,
+            start_deg: raw_required_f64(entry, "start_deg")?,
+            end_deg: raw_required_f64(entry, "end_deg")?,
+            madhya_deg: raw_required_f64(entry, "madhya_deg")?,
+        };
+    }
+    Ok(BhavaResult {
+        bhavas,
+        lagna_deg: raw_required_f64(value, "lagna_deg")?,
+        mc_deg: raw_required_f64(value, "mc_deg")?,
+    })
+}
+
 fn parse_delta_t_model(input: &EnumInput) -> Result<DeltaTModel, Value> {
     match input {
         EnumInput::Int(index) => DELTA_T_MODEL_VARIANTS
@@ -1078,6 +1184,17 @@ fn parse_charakaraka_scheme(input: Option<&EnumInput>) -> Result<CharakarakaSche
     }
 }
 
+fn parse_samvatsara_scheme(input: &EnumInput) -> Result<SamvatsaraScheme, Value> {
+    match input {
+        EnumInput::Int(value) => u8::try_from(*value)
+            .ok()
+            .and_then(SamvatsaraScheme::from_u8)
+            .ok_or_else(|| error_payload("invalid_request", "unknown samvatsara scheme")),
+        EnumInput::Str(value) => parse_named(value, &SAMVATSARA_SCHEME_VARIANTS)
+            .ok_or_else(|| error_payload("invalid_request", "unknown samvatsara scheme")),
+    }
+}
+
 fn parse_node_dignity_policy(input: Option<&EnumInput>) -> Result<NodeDignityPolicy, Value> {
     match input {
         None => Ok(NodeDignityPolicy::default()),
@@ -1257,6 +1374,18 @@ fn parse_node_mode(input: Option<&EnumInput>) -> Result<NodeMode, Value> {
     }
 }
 
+fn parse_transit_house_mode(input: Option<&EnumInput>) -> Result<TransitHouseMode, Value> {
+    match input {
+        None => Ok(TransitHouseMode::WholeSign),
+        Some(EnumInput::Int(value)) => TRANSIT_HOUSE_MODE_VARIANTS
+            .get(*value as usize)
+            .copied()
+            .ok_or_else(|| error_payload("invalid_request", "unknown transit house mode")),
+        Some(EnumInput::Str(value)) => parse_named(value, &TRANSIT_HOUSE_MODE_VARIANTS)
+            .ok_or_else(|| error_payload("invalid_request", "unknown transit house mode")),
+    }
+}
+
 fn parse_node_backend(input: Option<&EnumInput>) -> Result<NodeBackend, Value> {
     match input {
         None => Ok(NodeBackend::Engine),
@@ -1329,6 +1458,18 @@ fn parse_motion_kind(input: &EnumInput) -> Result<MotionKind, Value> {
     }
 }
 
+fn parse_heliacal_kind(input: Option<&EnumInput>) -> Result<HeliacalKind, Value> {
+    match input {
+        None => Ok(HeliacalKind::Rising),
+        Some(EnumInput::Int(value)) => HELIACAL_KIND_VARIANTS
+            .get(*value as usize)
+            .copied()
+            .ok_or_else(|| error_payload("invalid_request", "unknown heliacal kind")),
+        Some(EnumInput::Str(value)) => parse_named(value, &HELIACAL_KIND_VARIANTS)
+            .ok_or_else(|| error_payload("invalid_request", "unknown heliacal kind")),
+    }
+}
+
 fn parse_grahan_kind(input: &EnumInput) -> Result<GrahanKind, Value> {
     match input {
         EnumInput::Int(value) => GRAHAN_KIND_VARIANTS
@@ -1524,6 +1665,46 @@ fn to_riseset_config(
                     .ok_or_else(|| error_payload("invalid_request", "unknown sun limb"))?,
             };
         }
+        if let Some(fallback) = input.day_fallback.as_ref() {
+            config.day_fallback = match fallback {
+                EnumInput::Int(value) => DAY_FALLBACK_VARIANTS
+                    .get(*value as usize)
+                    .copied()
+                    .ok_or_else(|| error_payload("invalid_request", "unknown day fallback"))?,
+                EnumInput::Str(value) => parse_named(value, &DAY_FALLBACK_VARIANTS)
+                    .ok_or_else(|| error_payload("invalid_request", "unknown day fallback"))?,
+            };
+        }
+        if let Some(model) = input.refraction_model.as_ref() {
+            let idx = match model {
+                EnumInput::Int(value) => usize::try_from(*value).ok(),
+                EnumInput::Str(value) => {
+                    let normalized = value.trim().to_ascii_lowercase();
+                    REFRACTION_MODEL_NAMES
+                        .iter()
+                        .position(|name| *name == normalized)
+                }
+            };
+            let pressure_hpa = input.refraction_pressure_hpa.unwrap_or(1010.0);
+            let temperature_c = input.refraction_temperature_c.unwrap_or(10.0);
+            config.refraction_model = match idx {
+                Some(0) => RefractionModel::Standard,
+                Some(1) => RefractionModel::Bennett {
+                    pressure_hpa,
+                    temperature_c,
+                },
+                Some(2) => RefractionModel::Saemundsson {
+                    pressure_hpa,
+                    temperature_c,
+                },
+                Some(3) => RefractionModel::Custom {
+                    arcmin: input.refraction_arcmin.unwrap_or(34.0),
+                },
+                _ => {
+                    return Err(error_payload("invalid_request", "unknown refraction model"));
+                }
+            };
+        }
     }
     Ok(config)
 }
@@ -1607,10 +1788,31 @@ fn to_sankranti_config(
         if let Some(convergence) = input.convergence_days {
             config.convergence_days = convergence;
         }
+        if let Some(scheme) = input.samvatsara_scheme.as_ref() {
+            config.samvatsara_scheme = parse_samvatsara_scheme(scheme)?;
+        }
+        if let Some(custom) = parse_custom_ayanamsha(input.custom_ayanamsha)? {
+            config = config.with_custom_ayanamsha(custom);
+        }
     }
     Ok(config)
 }
 
+fn parse_custom_ayanamsha(
+    input: Option<CustomAyanamshaInput>,
+) -> Result<Option<CustomAyanamsha>, Value> {
+    match input {
+        None => Ok(None),
+        Some(value) if value.anchor_deg.is_finite() && value.anchor_jd_tdb.is_finite() => Ok(Some(
+            CustomAyanamsha::new(value.anchor_deg, value.anchor_jd_tdb),
+        )),
+        Some(_) => Err(error_payload(
+            "invalid_request",
+            "custom_ayanamsha must be finite",
+        )),
+    }
+}
+
 fn to_conjunction_config(
     state: &EngineState,
     input: Option<&SearchConfigInput>,
@@ -1725,6 +1927,9 @@ fn to_graha_positions_config(
         if let Some(include_bhava) = input.include_bhava {
             config.include_bhava = include_bhava;
         }
+        if let Some(include_flags) = input.include_flags {
+            config.include_flags = include_flags;
+        }
     }
     Ok(config)
 }
@@ -2019,6 +2224,18 @@ fn to_dasha_variation(input: Option<&DashaVariationInput>) -> Result<DashaVariat
                     .ok_or_else(|| error_payload("invalid_request", "unknown yogini scheme"))?,
             };
         }
+        if let Some(year_length) = input.year_length.as_ref() {
+            config.year_length = match year_length {
+                EnumInput::Int(value) => DASHA_YEAR_LENGTH_VARIANTS
+                    .get(*value as usize)
+                    .copied()
+                    .ok_or_else(|| {
+                    error_payload("invalid_request", "unknown dasha year length")
+                })?,
+                EnumInput::Str(value) => parse_named(value, &DASHA_YEAR_LENGTH_VARIANTS)
+                    .ok_or_else(|| error_payload("invalid_request", "unknown dasha year length"))?,
+            };
+        }
         if let Some(level_methods) = input.level_methods.as_ref() {
             for (index, method) in level_methods
                 .iter()
@@ -2334,7 +2551,8 @@ fn bhava_result_json(result: BhavaResult) -> Value {
             "number": bhava.number,
             "cusp_deg": bhava.cusp_deg,
             "start_deg": bhava.start_deg,
-            "end_deg": bhava.end_deg
+            "end_deg": bhava.end_deg,
+            "madhya_deg": bhava.madhya_deg
         })).collect::<Vec<_>>()
     })
 }
@@ -2348,6 +2566,7 @@ fn rashi_bhava_result_from_lagna(lagna_deg: f64) -> BhavaResult {
         cusp_deg: 0.0,
         start_deg: 0.0,
         end_deg: 0.0,
+        madhya_deg: 0.0,
     }; 12];
     for i in 0..12 {
         let rashi = (lagna_rashi + i as u8) % 12;
@@ -2357,6 +2576,7 @@ fn rashi_bhava_result_from_lagna(lagna_deg: f64) -> BhavaResult {
             cusp_deg: (start + degree_in_rashi).rem_euclid(360.0),
             start_deg: start,
             end_deg: (start + 30.0).rem_euclid(360.0),
+            madhya_deg: start + 15.0,
         };
     }
     BhavaResult {
@@ -2374,9 +2594,39 @@ fn bhava_result_json_with_rashi(result: BhavaResult, include_rashi_bhava: bool)
     value
 }
 
+fn panchang_info_json(info: &PanchangInfo) -> Value {
+    panchang_value_json(&PanchangResult {
+        tithi: Some(info.tithi),
+        tithi_day_kind: Some(info.tithi_day_kind),
+        karana: Some(info.karana),
+        yoga: Some(info.yoga),
+        vaar: Some(info.vaar),
+        hora: Some(info.hora),
+        ghatika: Some(info.ghatika),
+        nakshatra: Some(info.nakshatra),
+        masa: info.masa,
+        ayana: info.ayana,
+        varsha: info.varsha,
+    })
+}
+
+fn panchang_diff_json(diff: &PanchangDiff) -> Value {
+    json!({
+        "a": panchang_info_json(&diff.a),
+        "b": panchang_info_json(&diff.b),
+        "limbs": diff.limbs.iter().map(|d| json!({
+            "limb": debug_name(d.limb),
+            "value_differs": d.value_differs,
+            "start_shift_s": d.start_shift_s,
+            "end_shift_s": d.end_shift_s
+        })).collect::<Vec<_>>()
+    })
+}
+
 fn panchang_value_json(result: &PanchangResult) -> Value {
     json!({
         "tithi": result.tithi.map(tithi_json),
+        "tithi_day": result.tithi_day_kind.map(tithi_day_kind_json),
         "karana": result.karana.map(karana_json),
         "yoga": result.yoga.map(yoga_json),
         "vaar": result.vaar.map(vaar_json),
@@ -2399,6 +2649,16 @@ fn tithi_json(info: dhruv_search::TithiInfo) -> Value {
     })
 }
 
+fn tithi_day_kind_json(kind: dhruv_vedic_base::TithiDayKind) -> Value {
+    use dhruv_vedic_base::TithiDayKind;
+    let (name, tithi) = match kind {
+        TithiDayKind::Normal => ("normal", None),
+        TithiDayKind::Kshaya(t) => ("kshaya", Some(t.index())),
+        TithiDayKind::Vriddhi(t) => ("vriddhi", Some(t.index())),
+    };
+    json!({ "kind": name, "tithi_index": tithi })
+}
+
 fn karana_json(info: dhruv_search::KaranaInfo) -> Value {
     json!({
         "karana_index": info.karana_index,
@@ -2594,6 +2854,41 @@ fn sankranti_event_json(event: dhruv_search::SankrantiEvent) -> Value {
     })
 }
 
+fn heliacal_event_json(event: HeliacalEvent) -> Value {
+    json!({
+        "utc": utc_json(event.utc),
+        "jd_tdb": event.jd_tdb,
+        "kind": debug_name(event.kind),
+        "altitude_deg": event.altitude_deg,
+        "elongation_deg": event.elongation_deg
+    })
+}
+
+fn ingress_event_json(event: dhruv_search::IngressEvent) -> Value {
+    json!({
+        "utc": utc_json(event.utc),
+        "rashi": debug_name(event.rashi),
+        "rashi_index": event.rashi_index,
+        "jd_tdb": event.jd_tdb
+    })
+}
+
+fn retrograde_interval_json(
+    engine: &Engine,
+    interval: dhruv_search::stationary_types::RetrogradeInterval,
+) -> Value {
+    json!({
+        "start_jd_tdb": interval.start_jd,
+        "end_jd_tdb": interval.end_jd,
+        "start_utc": utc_json(UtcTime::from_jd_tdb(interval.start_jd, engine.lsk())),
+        "end_utc": utc_json(UtcTime::from_jd_tdb(interval.end_jd, engine.lsk())),
+        "start_lon_deg": interval.start_lon_deg,
+        "end_lon_deg": interval.end_lon_deg,
+        "open_start": interval.open_start,
+        "open_end": interval.open_end
+    })
+}
+
 fn motion_result_json(result: MotionResult) -> Value {
     match result {
         MotionResult::StationarySingle(event) => {
@@ -2644,7 +2939,10 @@ fn graha_entry_json(entry: dhruv_search::GrahaEntry, graha: Option<Graha>) -> Va
         "nakshatra_index": entry.nakshatra_index,
         "pada": entry.pada,
         "bhava_number": entry.bhava_number,
-        "rashi_bhava_number": entry.rashi_bhava_number
+        "rashi_bhava_number": entry.rashi_bhava_number,
+        "gandanta": entry.gandanta,
+        "rashi_sandhi": entry.rashi_sandhi,
+        "pushkara": entry.pushkara
     })
 }
 
@@ -2819,7 +3117,9 @@ fn shadbala_json(result: dhruv_search::ShadbalaResult) -> Value {
             "total_shashtiamsas": entry.total_shashtiamsas,
             "total_rupas": entry.total_rupas,
             "required_strength": entry.required_strength,
-            "is_strong": entry.is_strong
+            "is_strong": entry.is_strong,
+            "ishta": entry.ishta,
+            "kashta": entry.kashta
         })).collect::<Vec<_>>()
     })
 }
@@ -2984,18 +3284,7 @@ fn full_kundali_json(result: dhruv_search::FullKundaliResult) -> Value {
         "vimsopaka": result.vimsopaka.map(vimsopaka_json),
         "avastha": result.avastha.map(avastha_json),
         "charakaraka": result.charakaraka.map(charakaraka_json),
-        "panchang": result.panchang.map(|panchang| panchang_value_json(&PanchangResult {
-            tithi: Some(panchang.tithi),
-            karana: Some(panchang.karana),
-            yoga: Some(panchang.yoga),
-            vaar: Some(panchang.vaar),
-            hora: Some(panchang.hora),
-            ghatika: Some(panchang.ghatika),
-            nakshatra: Some(panchang.nakshatra),
-            masa: panchang.masa,
-            ayana: panchang.ayana,
-            varsha: panchang.varsha
-        })),
+        "panchang": result.panchang.map(|panchang| panchang_info_json(&panchang)),
         "dasha": result.dasha.map(|items| items.into_iter().map(dasha_hierarchy_json).collect::<Vec<_>>()),
         "dasha_snapshots": result.dasha_snapshots.map(|items| items.into_iter().map(dasha_snapshot_json).collect::<Vec<_>>())
     })
@@ -3022,6 +3311,14 @@ fn dasha_snapshot_json(result: DashaSnapshot) -> Value {
     })
 }
 
+fn dasha_range_period_json(period: DashaRangePeriod) -> Value {
+    json!({
+        "period": dasha_period_json(period.period),
+        "clipped_start": period.clipped_start,
+        "clipped_end": period.clipped_end
+    })
+}
+
 fn dasha_period_json(period: DashaPeriod) -> Value {
     json!({
         "entity": dasha_entity_json(period.entity),
@@ -3187,6 +3484,12 @@ fn handle_vedic(resource: &ResourceArc<EngineResource>, request: VedicRequest) -
             "ayanamsha" => {
                 let op = AyanamshaOperation {
                     system: parse_ayanamsha_system(request.system.as_ref())?,
+                    custom_ayanamsha: parse_custom_ayanamsha(
+                        request
+                            .sankranti_config
+                            .as_ref()
+                            .and_then(|config| config.custom_ayanamsha),
+                    )?,
                     mode: request
                         .mode
                         .as_ref()
@@ -3213,6 +3516,13 @@ fn handle_vedic(resource: &ResourceArc<EngineResource>, request: VedicRequest) -
                         .and_then(|config| config.use_nutation)
                         .unwrap_or(false),
                     delta_psi_arcsec: 0.0,
+                    precession_model: parse_precession_model(
+                        request
+                            .sankranti_config
+                            .as_ref()
+                            .and_then(|config| config.precession_model.as_ref()),
+                    )?
+                    .unwrap_or(DEFAULT_PRECESSION_MODEL),
                 };
                 ayanamsha(&op)
                     .map(|value| json!({ "ayanamsha_deg": value }))
@@ -3428,6 +3738,12 @@ fn handle_panchang(resource: &ResourceArc<EngineResource>, request: PanchangRequ
                     .ok_or_else(|| error_payload("invalid_request", "utc is required"))?;
                 json!({ "nakshatra": nakshatra_json(dhruv_search::nakshatra_for_date(engine, utc, &sankranti_config).map_err(|err| map_error("search_error", err))?) })
             }
+            "tithi_day" => {
+                let utc = utc
+                    .as_ref()
+                    .ok_or_else(|| error_payload("invalid_request", "utc is required"))?;
+                json!({ "tithi_day": tithi_day_kind_json(dhruv_search::tithi_day_kind_for_date(engine, eop, utc, &location, &riseset_config).map_err(|err| map_error("search_error", err))?) })
+            }
             "vaar" => {
                 let utc = utc
                     .as_ref()
@@ -3485,6 +3801,23 @@ fn handle_panchang(resource: &ResourceArc<EngineResource>, request: PanchangRequ
                     panchang(engine, eop, &op).map_err(|err| map_error("search_error", err))?;
                 panchang_value_json(&result)
             }
+            "diff" => {
+                let utc = utc
+                    .as_ref()
+                    .ok_or_else(|| error_payload("invalid_request", "utc is required"))?;
+                let config_b = to_sankranti_config(state, request.sankranti_config_b.as_ref())?;
+                let diff = panchang_diff(
+                    engine,
+                    eop,
+                    utc,
+                    &location,
+                    &riseset_config,
+                    &sankranti_config,
+                    &config_b,
+                )
+                .map_err(|err| map_error("search_error", err))?;
+                panchang_diff_json(&diff)
+            }
             "elongation_at" => json!({
                 "value": elongation_at(
                     engine,
@@ -3741,6 +4074,44 @@ fn handle_search(resource: &ResourceArc<EngineResource>, request: SearchRequest)
                     .map(sankranti_result_json)
                     .map_err(|err| map_error("search_error", err))
             }
+            "moon_rashi_ingresses" => {
+                let (start_jd_tdb, end_jd_tdb) = search_range_jd_tdb(engine, &request)?;
+                let config = to_sankranti_config(state, request.sankranti_config.as_ref())?;
+                dhruv_search::moon_rashi_ingresses(
+                    engine,
+                    &UtcTime::from_jd_tdb(start_jd_tdb, engine.lsk()),
+                    &UtcTime::from_jd_tdb(end_jd_tdb, engine.lsk()),
+                    &config,
+                )
+                .map(|events| {
+                    json!({ "events": events.into_iter().map(ingress_event_json).collect::<Vec<_>>() })
+                })
+                .map_err(|err| map_error("search_error", err))
+            }
+            "nakshatra_ingress" => {
+                let body = parse_body(
+                    request
+                        .body
+                        .as_ref()
+                        .ok_or_else(|| error_payload("invalid_request", "body is required"))?,
+                )?;
+                let config = to_sankranti_config(state, request.sankranti_config.as_ref())?;
+                next_nakshatra_ingress(engine, body, search_at_jd_tdb(engine, &request)?, &config)
+                    .map(|event| {
+                        json!({
+                            "event": event.map(|event| json!({
+                                "utc": utc_json(event.utc),
+                                "jd_tdb": event.jd_tdb,
+                                "body": debug_name(event.body),
+                                "nakshatra": debug_name(event.nakshatra),
+                                "nakshatra_index": event.nakshatra_index,
+                                "boundary_deg": event.boundary_deg,
+                                "retrograde": event.retrograde
+                            }))
+                        })
+                    })
+                    .map_err(|err| map_error("search_error", err))
+            }
             "motion" => {
                 let query = match request.mode {
                     EnumInput::Str(ref value) if value == "range" => {
@@ -3772,6 +4143,109 @@ fn handle_search(resource: &ResourceArc<EngineResource>, request: SearchRequest)
                     .map(motion_result_json)
                     .map_err(|err| map_error("search_error", err))
             }
+            "node_station" => next_node_station(engine, search_at_jd_tdb(engine, &request)?)
+                .map(|event| {
+                    json!({
+                        "event": event.map(|event| json!({
+                            "utc": utc_json(event.utc),
+                            "jd_tdb": event.jd_tdb,
+                            "rahu_longitude_deg": event.rahu_longitude_deg,
+                            "station_type": debug_name(event.station_type)
+                        }))
+                    })
+                })
+                .map_err(|err| map_error("search_error", err)),
+            "retrograde_intervals" => {
+                let body = parse_body(
+                    request
+                        .body
+                        .as_ref()
+                        .ok_or_else(|| error_payload("invalid_request", "body is required"))?,
+                )?;
+                let (start_jd_tdb, end_jd_tdb) = search_range_jd_tdb(engine, &request)?;
+                let config = to_stationary_config(state, request.config.as_ref());
+                retrograde_intervals(engine, body, start_jd_tdb, end_jd_tdb, &config)
+                    .map(|intervals| {
+                        json!({
+                            "intervals": intervals
+                                .into_iter()
+                                .map(|interval| retrograde_interval_json(engine, interval))
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .map_err(|err| map_error("search_error", err))
+            }
+            "heliacal" => {
+                let eop = state.eop.as_ref().ok_or_else(|| {
+                    error_payload("missing_eop", "heliacal search requires loaded EOP data")
+                })?;
+                let location = parse_location(
+                    request
+                        .location
+                        .ok_or_else(|| error_payload("invalid_request", "location is required"))?,
+                );
+                let (target, mut config) = match (&request.body, &request.tara) {
+                    (Some(body), None) => {
+                        let body = parse_body(body)?;
+                        (
+                            HeliacalTarget::Body(body),
+                            HeliacalEventConfig::for_body(body),
+                        )
+                    }
+                    (None, Some(tara)) => (
+                        HeliacalTarget::Tara(state.tara_catalog.as_ref(), parse_tara_id(tara)?),
+                        HeliacalEventConfig::default(),
+                    ),
+                    _ => {
+                        return Err(error_payload(
+                            "invalid_request",
+                            "exactly one of body or tara is required",
+                        ));
+                    }
+                };
+                if let Some(input) = request.heliacal_config.as_ref() {
+                    if let Some(value) = input.arcus_visionis_deg {
+                        config.arcus_visionis_deg = value;
+                    }
+                    if let Some(value) = input.min_altitude_deg {
+                        config.min_altitude_deg = value;
+                    }
+                    if let Some(value) = input.min_elongation_deg {
+                        config.min_elongation_deg = value;
+                    }
+                }
+                let result = match request.mode {
+                    EnumInput::Str(ref value) if value == "range" => {
+                        let (start_jd_tdb, end_jd_tdb) = search_range_jd_tdb(engine, &request)?;
+                        search_heliacal_events(
+                            engine,
+                            eop,
+                            target,
+                            &location,
+                            start_jd_tdb,
+                            end_jd_tdb,
+                            &config,
+                        )
+                        .map(|events| {
+                            json!({ "events": events.into_iter().map(heliacal_event_json).collect::<Vec<_>>() })
+                        })
+                    }
+                    _ => {
+                        let prev =
+                            matches!(request.mode, EnumInput::Str(ref value) if value == "prev");
+                        let search = match (parse_heliacal_kind(request.kind.as_ref())?, prev) {
+                            (HeliacalKind::Rising, false) => next_heliacal_rising,
+                            (HeliacalKind::Rising, true) => prev_heliacal_rising,
+                            (HeliacalKind::Setting, false) => next_heliacal_setting,
+                            (HeliacalKind::Setting, true) => prev_heliacal_setting,
+                        };
+                        let at_jd_tdb = search_at_jd_tdb(engine, &request)?;
+                        search(engine, eop, target, &location, at_jd_tdb, &config)
+                            .map(|event| json!({ "event": event.map(heliacal_event_json) }))
+                    }
+                };
+                result.map_err(|err| map_error("search_error", err))
+            }
             _ => Err(error_payload("invalid_request", "unknown search operation")),
         }
     })
@@ -3789,7 +4263,7 @@ fn handle_jyotish(resource: &ResourceArc<EngineResource>, request: JyotishReques
         let sankranti_config = to_sankranti_config(state, request.sankranti_config.as_ref())?;
         let bhava_config = to_bhava_config(state, request.bhava_config.as_ref())?;
         match request.op.as_str() {
-            "graha_longitudes" => {
+            "graha_longitudes" | "graha_speeds" => {
                 let kind = parse_graha_longitude_kind(request.kind.as_ref())?;
                 let system = request
                     .system
@@ -3813,6 +4287,11 @@ fn handle_jyotish(resource: &ResourceArc<EngineResource>, request: JyotishReques
                 let jd_tdb = request
                     .jd_tdb
                     .ok_or_else(|| error_payload("invalid_request", "jd_tdb is required"))?;
+                if request.op == "graha_speeds" {
+                    let speeds = graha_speeds(engine, jd_tdb, &config)
+                        .map_err(|err| map_error("search_error", err))?;
+                    return Ok(json!({ "speeds": speeds }));
+                }
                 let navagrahas = graha_longitudes(engine, jd_tdb, &config)
                     .map_err(|err| map_error("search_error", err))?;
                 Ok(graha_longitudes_json(navagrahas))
@@ -4209,7 +4688,7 @@ fn handle_dasha(resource: &ResourceArc<EngineResource>, request: DashaRequest) -
             "level0" => {
                 if let Some(inputs) = raw_inputs.as_ref() {
                     let inputs = inputs.borrowed();
-                    dasha_level0_with_inputs(birth_jd, system, &inputs)
+                    dasha_level0_with_inputs(birth_jd, system, &variation, &inputs)
                         .map(|periods| {
                             json!(
                                 periods
@@ -4240,6 +4719,7 @@ fn handle_dasha(resource: &ResourceArc<EngineResource>, request: DashaRequest) -
                         &bhava_config,
                         &riseset_config,
                         &sankranti_config,
+                        &variation,
                     )
                     .map(|periods| {
                         json!(
@@ -4261,7 +4741,7 @@ fn handle_dasha(resource: &ResourceArc<EngineResource>, request: DashaRequest) -
                 )?;
                 if let Some(inputs) = raw_inputs.as_ref() {
                     let inputs = inputs.borrowed();
-                    dasha_level0_entity_with_inputs(birth_jd, system, entity, &inputs)
+                    dasha_level0_entity_with_inputs(birth_jd, system, entity, &variation, &inputs)
                         .map(|period| period.map(dasha_period_json).unwrap_or(Value::Null))
                         .map_err(|err| map_error("search_error", err))
                 } else {
@@ -4286,6 +4766,7 @@ fn handle_dasha(resource: &ResourceArc<EngineResource>, request: DashaRequest) -
                         &bhava_config,
                         &riseset_config,
                         &sankranti_config,
+                        &variation,
                     )
                     .map(|period| period.map(dasha_period_json).unwrap_or(Value::Null))
                     .map_err(|err| map_error("search_error", err))
@@ -4462,6 +4943,89 @@ fn handle_dasha(resource: &ResourceArc<EngineResource>, request: DashaRequest) -
                     .map_err(|err| map_error("search_error", err))
                 }
             }
+            "transitions" => {
+                let level = match request.level.as_ref() {
+                    Some(level) => parse_dasha_level(level)?,
+                    None => DashaLevel::Antardasha,
+                };
+                if let Some(inputs) = raw_inputs.as_ref() {
+                    let start_jd = match (request.start_jd, request.start_utc.as_ref()) {
+                        (Some(jd), _) => jd,
+                        (None, Some(utc)) => utc_to_jd_utc(&parse_utc(utc.clone())?),
+                        (None, None) => {
+                            return Err(error_payload(
+                                "invalid_request",
+                                "start_utc or start_jd is required",
+                            ));
+                        }
+                    };
+                    let end_jd = match (request.end_jd, request.end_utc.as_ref()) {
+                        (Some(jd), _) => jd,
+                        (None, Some(utc)) => utc_to_jd_utc(&parse_utc(utc.clone())?),
+                        (None, None) => {
+                            return Err(error_payload(
+                                "invalid_request",
+                                "end_utc or end_jd is required",
+                            ));
+                        }
+                    };
+                    let inputs = inputs.borrowed();
+                    dasha_transitions_with_inputs(
+                        birth_jd, start_jd, end_jd, system, level, &variation, &inputs,
+                    )
+                    .map(|periods| {
+                        json!(
+                            periods
+                                .into_iter()
+                                .map(dasha_range_period_json)
+                                .collect::<Vec<_>>()
+                        )
+                    })
+                    .map_err(|err| map_error("search_error", err))
+                } else {
+                    let birth_utc = parse_utc(request.birth_utc.ok_or_else(|| {
+                        error_payload("invalid_request", "birth_utc is required")
+                    })?)?;
+                    let start_utc = parse_utc(request.start_utc.ok_or_else(|| {
+                        error_payload("invalid_request", "start_utc is required")
+                    })?)?;
+                    let end_utc =
+                        parse_utc(request.end_utc.ok_or_else(|| {
+                            error_payload("invalid_request", "end_utc is required")
+                        })?)?;
+                    let location =
+                        parse_location(request.location.ok_or_else(|| {
+                            error_payload("invalid_request", "location is required")
+                        })?);
+                    let bhava_config = to_bhava_config(state, request.bhava_config.as_ref())?;
+                    let riseset_config = to_riseset_config(state, request.riseset_config.as_ref())?;
+                    let sankranti_config =
+                        to_sankranti_config(state, request.sankranti_config.as_ref())?;
+                    dasha_transitions_in_range(
+                        engine,
+                        eop,
+                        &birth_utc,
+                        &start_utc,
+                        &end_utc,
+                        &location,
+                        system,
+                        level,
+                        &bhava_config,
+                        &riseset_config,
+                        &sankranti_config,
+                        &variation,
+                    )
+                    .map(|periods| {
+                        json!(
+                            periods
+                                .into_iter()
+                                .map(dasha_range_period_json)
+                                .collect::<Vec<_>>()
+                        )
+                    })
+                    .map_err(|err| map_error("search_error", err))
+                }
+            }
             _ => Err(error_payload("invalid_request", "unknown dasha operation")),
         }
     })
@@ -4729,6 +5293,16 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
             let (dpsi_arcsec, deps_arcsec) = nutation_iau2000b(t);
             Ok(json!({ "dpsi_arcsec": dpsi_arcsec, "deps_arcsec": deps_arcsec }))
         }
+        "whole_sign_bhavas" => Ok(bhava_result_json(whole_sign_from_lagna(raw_required_f64(
+            &raw,
+            "lagna_deg",
+        )?))),
+        "node_speed" => {
+            let mode = parse_node_mode(raw_optional_enum(&raw, "mode")?.as_ref())
+                .map_err(|_| rustler::Error::BadArg)?;
+            let t = dhruv_vedic_base::jd_tdb_to_centuries(raw_required_f64(&raw, "jd_tdb")?);
+            Ok(json!({ "speed_deg_per_day": node_speed_deg_per_day(t, mode) }))
+        }
         "approximate_local_noon" => {
             let jd_ut_midnight = raw
                 .get("jd_ut_midnight")
@@ -4773,6 +5347,26 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
         "nakshatra28_from_longitude" => Ok(nakshatra28_info_json(nakshatra28_from_longitude(
             raw_required_f64(&raw, "sidereal_lon_deg")?,
         ))),
+        "kp_sublord" => {
+            let (nakshatra, star_lord, sub_lord) =
+                kp_sublord(raw_required_f64(&raw, "sidereal_lon_deg")?);
+            Ok(json!({
+                "nakshatra": debug_name(nakshatra),
+                "nakshatra_index": nakshatra.index(),
+                "star_lord": debug_name(star_lord),
+                "sub_lord": debug_name(sub_lord)
+            }))
+        }
+        "gandanta_status" => {
+            let status = gandanta_status(raw_required_f64(&raw, "sidereal_lon_deg")?);
+            Ok(json!({
+                "gandanta": status.map(|status| json!({
+                    "kind": debug_name(status.kind),
+                    "half": debug_name(status.half),
+                    "offset_deg": status.offset_deg
+                }))
+            }))
+        }
         "rashi_from_tropical" => Ok(rashi_info_json(rashi_from_tropical(
             raw_required_f64(&raw, "tropical_lon_deg")?,
             parse_ayanamsha_system(raw_optional_enum(&raw, "system")?.as_ref())
@@ -4881,6 +5475,25 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
                 json!({ "graha": debug_name(hora_lord(vaar, raw_required_u8(&raw, "hora_index")?)) }),
             )
         }
+        "nakshatra_lord" => {
+            let lord = nakshatra_dasha_lord(raw_required_u8(&raw, "nakshatra_index")?)
+                .ok_or(rustler::Error::BadArg)?;
+            Ok(json!({ "graha": debug_name(lord) }))
+        }
+        "navatara_from" => {
+            let nakshatra = |key: &str| -> Result<_, rustler::Error> {
+                let idx = raw_required_u8(&raw, key)?;
+                ALL_NAKSHATRAS_27
+                    .get(idx as usize)
+                    .copied()
+                    .ok_or(rustler::Error::BadArg)
+            };
+            let tara = navatara_from(
+                nakshatra("start_nakshatra")?,
+                nakshatra("target_nakshatra")?,
+            );
+            Ok(json!({ "tara": debug_name(tara), "index": tara.index() }))
+        }
         "masa_lord" => {
             let masa = parse_masa(&raw_required_enum(&raw, "masa")?)
                 .map_err(|_| rustler::Error::BadArg)?;
@@ -4923,6 +5536,7 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
                 "threshold_deg": combustion_threshold_fn(
                     graha,
                     raw.get("is_retrograde").and_then(Value::as_bool).unwrap_or(false),
+                    &raw_combustion_config(&raw)?,
                 )
             }))
         }
@@ -4935,6 +5549,7 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
                     raw_required_f64(&raw, "graha_sid_lon")?,
                     raw_required_f64(&raw, "sun_sid_lon")?,
                     raw.get("is_retrograde").and_then(Value::as_bool).unwrap_or(false),
+                    &raw_combustion_config(&raw)?,
                 )
             }))
         }
@@ -4942,6 +5557,7 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
             "statuses": all_combustion_status_fn(
                 &raw_f64_array::<9>(&raw, "sidereal_lons_9")?,
                 &raw_bool_array::<9>(&raw, "retrograde_flags_9")?,
+                &raw_combustion_config(&raw)?,
             )
         })),
         "naisargika_maitri" => {
@@ -4982,6 +5598,15 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
             };
             Ok(json!({ "relationship": debug_name(panchadha_maitri(naisargika, tatkalika)) }))
         }
+        "relationship_matrix" => {
+            let matrix = relationship_matrix(&raw_f64_array::<9>(&raw, "sidereal_lons_9")?);
+            Ok(json!({
+                "matrix": matrix
+                    .iter()
+                    .map(|row| row.iter().map(|cell| cell.map(debug_name)).collect::<Vec<_>>())
+                    .collect::<Vec<_>>()
+            }))
+        }
         "dignity_in_rashi" => {
             let graha = parse_graha(&raw_required_enum(&raw, "graha")?)
                 .map_err(|_| rustler::Error::BadArg)?;
@@ -5047,6 +5672,19 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
         "graha_drishti_matrix" => Ok(graha_drishti_matrix_json(graha_drishti_matrix(
             &raw_f64_array::<9>(&raw, "longitudes")?,
         ))),
+        "rashi_drishti" => {
+            let rashi = |key: &str| -> Result<_, rustler::Error> {
+                let idx = raw_required_u8(&raw, key)?;
+                ALL_RASHIS
+                    .get(idx as usize)
+                    .copied()
+                    .ok_or(rustler::Error::BadArg)
+            };
+            Ok(json!({
+                "aspects": rashi_drishti(rashi("from_rashi_index")?, rashi("to_rashi_index")?)
+            }))
+        }
+        "rashi_drishti_matrix" => Ok(json!({ "matrix": rashi_drishti_matrix() })),
         "sun_based_upagrahas" => Ok(sun_based_upagrahas_value_json(sun_based_upagrahas(
             raw_required_f64(&raw, "sun_sid_lon")?,
         ))),
@@ -5066,15 +5704,15 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
         }
         "all_sphutas" => {
             let inputs = SphutalInputs {
-                sun: raw_required_f64(&raw, "sun")?,
-                moon: raw_required_f64(&raw, "moon")?,
-                mars: raw_required_f64(&raw, "mars")?,
-                jupiter: raw_required_f64(&raw, "jupiter")?,
-                venus: raw_required_f64(&raw, "venus")?,
-                rahu: raw_required_f64(&raw, "rahu")?,
-                lagna: raw_required_f64(&raw, "lagna")?,
-                eighth_lord: raw_required_f64(&raw, "eighth_lord")?,
-                gulika: raw_required_f64(&raw, "gulika")?,
+                sun: raw_required_f64(&raw, "sun")?.into(),
+                moon: raw_required_f64(&raw, "moon")?.into(),
+                mars: raw_required_f64(&raw, "mars")?.into(),
+                jupiter: raw_required_f64(&raw, "jupiter")?.into(),
+                venus: raw_required_f64(&raw, "venus")?.into(),
+                rahu: raw_required_f64(&raw, "rahu")?.into(),
+                lagna: raw_required_f64(&raw, "lagna")?.into(),
+                eighth_lord: raw_required_f64(&raw, "eighth_lord")?.into(),
+                gulika: raw_required_f64(&raw, "gulika")?.into(),
             };
             Ok(json!({
                 "entries": all_sphutas(&inputs)
@@ -5131,6 +5769,32 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
             &raw_u8_array::<7>(&raw, "graha_rashis")?,
             raw_required_u8(&raw, "lagna_rashi")?,
         ))),
+        "transit_bindus" => {
+            let graha = parse_graha(&raw_required_enum(&raw, "graha")?)
+                .map_err(|_| rustler::Error::BadArg)?;
+            let house_mode =
+                parse_transit_house_mode(raw_optional_enum(&raw, "house_mode")?.as_ref())
+                    .map_err(|_| rustler::Error::BadArg)?;
+            let natal = calculate_ashtakavarga(
+                &raw_u8_array::<7>(&raw, "graha_rashis")?,
+                raw_required_u8(&raw, "lagna_rashi")?,
+            );
+            let result = transit_bindus(
+                &natal,
+                &raw_bhava_result(&raw, "natal_bhavas")?,
+                graha,
+                raw_required_f64(&raw, "transit_lon")?,
+                &TransitAshtakavargaConfig { house_mode },
+            );
+            Ok(json!({
+                "graha": debug_name(result.graha),
+                "transit_longitude_deg": result.transit_longitude_deg,
+                "house": result.house,
+                "rashi_index": result.rashi_index,
+                "bav_bindus": result.bav_bindus,
+                "sav_bindus": result.sav_bindus
+            }))
+        }
         "trikona_sodhana" => Ok(json!({
             "totals": trikona_sodhana(&raw_u8_array::<12>(&raw, "totals")?)
         })),
@@ -5144,6 +5808,16 @@ fn util_run<'a>(env: Env<'a>, request: Term<'a>) -> Result<Term<'a>, rustler::Er
             );
             Ok(json!({ "value": position.value, "index": position.index }))
         }
+        "ghatika_to_duration" => Ok(json!({
+            "hours": ghatika_to_duration(
+                raw_required_f64(&raw, "ghati")?,
+                raw_required_f64(&raw, "vighati")?,
+            )
+        })),
+        "duration_to_ghatika" => {
+            let (ghati, vighati) = duration_to_ghatika(raw_required_f64(&raw, "hours")?);
+            Ok(json!({ "ghati": ghati, "vighati": vighati }))
+        }
         "ghatikas_since_sunrise" => Ok(json!({
             "ghatikas": ghatikas_since_sunrise(
                 raw_required_f64(&raw, "jd_moment")?,
@@ -37,6 +37,79 @@ defmodule CtaraDhruvTest do
     assert {:ok, %{name: "Mangala"}} = Math.yogini_name(%{index: 0})
   end
 
+  test "nakshatra lord follows the Vimshottari order" do
+    assert {:ok, %{graha: "ketu"}} = Math.nakshatra_lord(%{nakshatra_index: 0})
+    assert {:ok, %{graha: "buddh"}} = Math.nakshatra_lord(%{nakshatra_index: 26})
+  end
+
+  test "navatara counts the tara from a start nakshatra" do
+    assert {:ok, %{tara: "parama_mitra", index: 8}} =
+             Math.navatara_from(%{start_nakshatra: 0, target_nakshatra: 26})
+
+    assert {:ok, %{tara: "pratyak", index: 4}} =
+             Math.navatara_from(%{start_nakshatra: 7, target_nakshatra: 2})
+  end
+
+  test "kp sublord splits a nakshatra into Vimshottari subs" do
+    assert {:ok, %{nakshatra: "ashwini", star_lord: "ketu", sub_lord: "surya"}} =
+             Math.kp_sublord(%{sidereal_lon_deg: 3.0})
+  end
+
+  test "relationship matrix keeps Sun-Saturn enmity in the same sign" do
+    assert {:ok, %{matrix: matrix}} =
+             Math.relationship_matrix(%{
+               sidereal_lons_9: [5.0, 70.0, 100.0, 130.0, 160.0, 190.0, 10.0, 220.0, 40.0]
+             })
+
+    assert matrix |> Enum.at(0) |> Enum.at(6) == "adhi_shatru"
+    assert matrix |> Enum.at(0) |> Enum.at(0) == nil
+  end
+
+  test "rashi drishti follows the Jaimini sign aspects" do
+    assert {:ok, %{aspects: true}} =
+             Math.rashi_drishti(%{from_rashi_index: 0, to_rashi_index: 4})
+
+    assert {:ok, %{aspects: false}} =
+             Math.rashi_drishti(%{from_rashi_index: 0, to_rashi_index: 1})
+
+    assert {:ok, %{matrix: matrix}} = Math.rashi_drishti_matrix(%{})
+    assert Enum.all?(matrix, fn row -> Enum.count(row, & &1) == 3 end)
+  end
+
+  test "gandanta status reports the junction and half" do
+    assert {:ok, %{gandanta: %{kind: "karka_simha", half: "leaving", offset_deg: offset}}} =
+             Math.gandanta_status(%{sidereal_lon_deg: 121.0})
+
+    assert_in_delta offset, 1.0, 1.0e-9
+    assert {:ok, %{gandanta: nil}} = Math.gandanta_status(%{sidereal_lon_deg: 60.0})
+  end
+
+  test "ghatika durations convert to and from clock hours" do
+    assert {:ok, %{hours: 5.0}} = Panchang.ghatika_to_duration(%{ghati: 12, vighati: 30})
+    assert {:ok, %{ghati: 12, vighati: vighati}} = Panchang.duration_to_ghatika(%{hours: 5.0})
+    assert_in_delta vighati, 30.0, 1.0e-9
+  end
+
+  test "whole-sign bhavas start at the lagna rashi" do
+    assert {:ok, %{mc_deg: 300.0, bhavas: [%{cusp_deg: 30.0} | _]}} =
+             Math.whole_sign_bhavas(%{lagna_deg: 48.25})
+  end
+
+  test "transit bindus count the house from the natal lagna rashi" do
+    {:ok, natal_bhavas} = Math.whole_sign_bhavas(%{lagna_deg: 20.0})
+
+    assert {:ok, %{house: 4, rashi_index: 3, bav_bindus: bav}} =
+             Math.transit_bindus(%{
+               graha_rashis: [0, 0, 0, 0, 0, 0, 0],
+               lagna_rashi: 0,
+               natal_bhavas: natal_bhavas,
+               graha: :shani,
+               transit_lon: 95.0
+             })
+
+    assert is_integer(bav)
+  end
+
   test "engine lifecycle and native families smoke" do
     case with_engine() do
       :skip ->
@@ -45,6 +118,19 @@ defmodule CtaraDhruvTest do
       {:ok, engine} ->
         assert {:ok, _} = Ephemeris.cartesian_to_spherical(%{x: 1.0, y: 0.0, z: 0.0})
         assert {:ok, _} = Time.nutation(%{jd_tdb: 2_451_545.0})
+        assert {:ok, %{speed_deg_per_day: _}} =
+                 Time.node_speed(%{mode: :mean, jd_tdb: 2_451_545.0})
+        assert {:ok, %{event: _}} = Search.node_station(engine, %{at_jd_tdb: 2_451_545.0})
+
+        assert {:ok, %{event: %{nakshatra_index: _, retrograde: false}}} =
+                 Search.nakshatra_ingress(engine, %{body: :moon, at_jd_tdb: 2_451_545.0})
+
+        assert {:ok, %{intervals: [%{open_start: false, open_end: false}]}} =
+                 Search.retrograde_intervals(engine, %{
+                   body: :mars,
+                   start_jd_tdb: 2_459_031.5,
+                   end_jd_tdb: 2_459_215.5
+                 })
 
         assert {:ok, _} =
                  Ephemeris.query(engine, %{
@@ -77,7 +163,24 @@ defmodule CtaraDhruvTest do
                    Vedic.rise_set(engine, %{utc: utc, location: location, event: :sunrise})
 
           assert {:ok, _} = Panchang.tithi(engine, %{utc: utc})
+          assert {:ok, _} = Panchang.tithi_day(engine, %{utc: utc, location: location})
+          assert {:ok, %{limbs: []}} = Panchang.diff(engine, %{utc: utc, location: location})
           assert {:ok, _} = Search.sankranti(engine, %{mode: :next, at_utc: utc})
+
+          assert {:ok, %{events: [_ | _]}} =
+                   Search.moon_rashi_ingresses(engine, %{
+                     start_utc: utc,
+                     end_utc: %{utc | day: 20}
+                   })
+
+          assert {:ok, %{events: [_ | _]}} =
+                   Search.heliacal(engine, %{
+                     mode: :range,
+                     body: :venus,
+                     location: location,
+                     start_utc: %{utc | year: 2024},
+                     end_utc: %{utc | year: 2026}
+                   })
           assert {:ok, _} = Jyotish.graha_positions(engine, %{utc: utc, location: location})
           assert {:ok, _} = Jyotish.bindus(engine, %{utc: utc, location: location})
 
@@ -141,6 +244,20 @@ defmodule CtaraDhruvTest do
                    })
 
           assert length(complete_level) >= length(children)
+
+          assert {:ok, transitions} =
+                   Dasha.transitions(engine, %{
+                     birth_utc: utc,
+                     location: location,
+                     system: :vimshottari,
+                     start_utc: %{year: 2020, month: 1, day: 1, hour: 0, minute: 0, second: 0.0},
+                     end_utc: %{year: 2040, month: 1, day: 1, hour: 0, minute: 0, second: 0.0},
+                     level: :antardasha
+                   })
+
+          assert length(transitions) > 0
+          assert hd(transitions).clipped_start
+          assert List.last(transitions).clipped_end
         end
 
         if File.exists?(@tara) do
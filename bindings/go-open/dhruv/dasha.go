@@ -74,3 +74,8 @@ func (e *Engine) DashaCompleteLevel(ep *EOP, request DashaCompleteLevelRequest,
 	out, st := cabi.DashaCompleteLevel(e.h, ep.h, request, parentPeriods)
 	return out, statusErr("dasha_complete_level", st)
 }
+
+func (e *Engine) DashaTransitions(ep *EOP, request DashaTransitionsRequest) ([]DashaRangePeriod, error) {
+	out, st := cabi.DashaTransitions(e.h, ep.h, request)
+	return out, statusErr("dasha_transitions", st)
+}
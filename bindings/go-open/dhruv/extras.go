@@ -22,6 +22,16 @@ func Nakshatra28FromLongitude(siderealLon float64) (Nakshatra28Info, error) {
 	return out, statusErr("nakshatra28_from_longitude", st)
 }
 
+func KpSublordOf(siderealLon float64) (KpSublord, error) {
+	out, st := cabi.KpSublordOf(siderealLon)
+	return out, statusErr("kp_sublord", st)
+}
+
+func GandantaStatusOf(siderealLon float64) (GandantaStatus, bool, error) {
+	out, found, st := cabi.GandantaStatusOf(siderealLon)
+	return out, found, statusErr("gandanta_status", st)
+}
+
 func RashiFromTropical(tropicalLon float64, ayanamshaSystem uint32, jdTdb float64, useNutation bool) (RashiInfo, error) {
 	out, st := cabi.RashiFromTropical(tropicalLon, ayanamshaSystem, jdTdb, useNutation)
 	return out, statusErr("rashi_from_tropical", st)
@@ -67,6 +77,7 @@ func VaarName(index uint32) string            { return cabi.VaarName(index) }
 func HoraName(index uint32) string            { return cabi.HoraName(index) }
 func GrahaName(index uint32) string           { return cabi.GrahaName(index) }
 func YoginiName(index uint32) string          { return cabi.YoginiName(index) }
+func NavataraName(index uint32) string        { return cabi.NavataraName(index) }
 func SphutaName(index uint32) string          { return cabi.SphutaName(index) }
 func SpecialLagnaName(index uint32) string    { return cabi.SpecialLagnaName(index) }
 func ArudhaPadaName(index uint32) string      { return cabi.ArudhaPadaName(index) }
@@ -92,6 +103,8 @@ func MasaFromRashiIndex(rashi uint32) int32        { return cabi.MasaFromRashiIn
 func AyanaFromSiderealLongitude(lon float64) int32 { return cabi.AyanaFromSiderealLongitude(lon) }
 func NthRashiFrom(rashi, offset uint32) int32      { return cabi.NthRashiFrom(rashi, offset) }
 func RashiLord(rashi uint32) int32                 { return cabi.RashiLord(rashi) }
+func NakshatraLord(nakshatra uint32) int32         { return cabi.NakshatraLord(nakshatra) }
+func NavataraFrom(start, target uint32) int32      { return cabi.NavataraFrom(start, target) }
 func HoraAt(vaarIndex, horaIndex uint32) int32     { return cabi.HoraAt(vaarIndex, horaIndex) }
 func HoraLord(vaarIndex, horaIndex uint32) int32   { return cabi.HoraLord(vaarIndex, horaIndex) }
 func MasaLord(masaIndex uint32) int32              { return cabi.MasaLord(masaIndex) }
@@ -112,18 +125,22 @@ func MoolatrikoneRange(grahaIndex uint32) (bool, uint8, float64, float64, error)
 	return has, rashiIndex, startDeg, endDeg, statusErr("moolatrikone_range", st)
 }
 
-func CombustionThreshold(grahaIndex uint32, isRetrograde bool) (bool, float64, error) {
-	has, value, st := cabi.CombustionThreshold(grahaIndex, isRetrograde)
+func CombustionConfigDefault() CombustionConfig {
+	return cabi.CombustionConfigDefault()
+}
+
+func CombustionThreshold(grahaIndex uint32, isRetrograde bool, cfg CombustionConfig) (bool, float64, error) {
+	has, value, st := cabi.CombustionThreshold(grahaIndex, isRetrograde, cfg)
 	return has, value, statusErr("combustion_threshold", st)
 }
 
-func IsCombust(grahaIndex uint32, grahaSidLon, sunSidLon float64, isRetrograde bool) (bool, error) {
-	out, st := cabi.IsCombust(grahaIndex, grahaSidLon, sunSidLon, isRetrograde)
+func IsCombust(grahaIndex uint32, grahaSidLon, sunSidLon float64, isRetrograde bool, cfg CombustionConfig) (bool, error) {
+	out, st := cabi.IsCombust(grahaIndex, grahaSidLon, sunSidLon, isRetrograde, cfg)
 	return out, statusErr("is_combust", st)
 }
 
-func AllCombustionStatus(siderealLons [9]float64, retrogradeFlags [9]bool) ([9]bool, error) {
-	out, st := cabi.AllCombustionStatus(siderealLons, retrogradeFlags)
+func AllCombustionStatus(siderealLons [9]float64, retrogradeFlags [9]bool, cfg CombustionConfig) ([9]bool, error) {
+	out, st := cabi.AllCombustionStatus(siderealLons, retrogradeFlags, cfg)
 	return out, statusErr("all_combustion_status", st)
 }
 
@@ -142,6 +159,11 @@ func PanchadhaMaitri(naisargikaCode, tatkalikaCode int32) (int32, error) {
 	return out, statusErr("panchadha_maitri", st)
 }
 
+func RelationshipMatrix(siderealLons [9]float64) ([9][9]int32, error) {
+	out, st := cabi.RelationshipMatrix(siderealLons)
+	return out, statusErr("relationship_matrix", st)
+}
+
 func DignityInRashi(grahaIndex uint32, siderealLon float64, rashiIndex uint32) (int32, error) {
 	out, st := cabi.DignityInRashi(grahaIndex, siderealLon, rashiIndex)
 	return out, statusErr("dignity_in_rashi", st)
@@ -172,8 +194,8 @@ func GrahaGender(grahaIndex uint32) (int32, error) {
 	return out, statusErr("graha_gender", st)
 }
 
-func SamvatsaraFromYear(year int32) (SamvatsaraResult, error) {
-	out, st := cabi.SamvatsaraFromYear(year)
+func SamvatsaraFromYear(year, scheme int32) (SamvatsaraResult, error) {
+	out, st := cabi.SamvatsaraFromYear(year, scheme)
 	return out, statusErr("samvatsara_from_year", st)
 }
 
@@ -247,6 +269,16 @@ func GhatikasSinceSunrise(queryJd, sunriseJd float64) (float64, error) {
 	return out, statusErr("ghatikas_since_sunrise", st)
 }
 
+func GhatikaToDuration(ghati, vighati float64) (float64, error) {
+	out, st := cabi.GhatikaToDuration(ghati, vighati)
+	return out, statusErr("ghatika_to_duration", st)
+}
+
+func DurationToGhatika(hours float64) (uint32, float64, error) {
+	ghati, vighati, st := cabi.DurationToGhatika(hours)
+	return ghati, vighati, statusErr("duration_to_ghatika", st)
+}
+
 func AllSphutas(inputs SphutalInputs) (SphutalResult, error) {
 	out, st := cabi.AllSphutas(inputs)
 	return out, statusErr("all_sphutas", st)
@@ -367,6 +399,11 @@ func EkadhipatyaSodhana(totals [12]uint8, grahaRashis [7]uint8, lagnaRashi uint8
 	return out, statusErr("ekadhipatya_sodhana", st)
 }
 
+func TransitBindus(natal AshtakavargaResult, natalBhavas BhavaResult, grahaIndex uint32, transitLonSid float64, houseMode int32) (TransitBindusResult, error) {
+	out, st := cabi.TransitBindus(natal, natalBhavas, grahaIndex, transitLonSid, houseMode)
+	return out, statusErr("transit_bindus", st)
+}
+
 func (e *Engine) AshtakavargaForDate(ep *EOP, utc UtcTime, loc GeoLocation, ayanamshaSystem uint32, useNutation bool) (AshtakavargaResult, error) {
 	out, st := cabi.AshtakavargaForDate(e.h, ep.h, utc, loc, ayanamshaSystem, useNutation)
 	return out, statusErr("ashtakavarga_for_date", st)
@@ -382,6 +419,16 @@ func GrahaDrishtiMatrixForLongitudes(siderealLons [GrahaCount]float64) (GrahaDri
 	return out, statusErr("graha_drishti_matrix", st)
 }
 
+func RashiDrishti(fromRashi, toRashi uint32) (bool, error) {
+	out, st := cabi.RashiDrishti(fromRashi, toRashi)
+	return out, statusErr("rashi_drishti", st)
+}
+
+func RashiDrishtiMatrix() ([12][12]bool, error) {
+	out, st := cabi.RashiDrishtiMatrix()
+	return out, statusErr("rashi_drishti_matrix", st)
+}
+
 func (e *Engine) DrishtiForDate(ep *EOP, utc UtcTime, loc GeoLocation, bhavaCfg BhavaConfig, riseCfg RiseSetConfig, ayanamshaSystem uint32, useNutation bool, config DrishtiConfig) (DrishtiResult, error) {
 	out, st := cabi.DrishtiForDate(e.h, ep.h, utc, loc, bhavaCfg, riseCfg, ayanamshaSystem, useNutation, config)
 	return out, statusErr("drishti", st)
@@ -148,6 +148,45 @@ func TestHelperAndTaraPrimitives(t *testing.T) {
 	if HoraLord(0, 0) != 0 {
 		t.Fatalf("HoraLord(sunday,0) = %d, want 0", HoraLord(0, 0))
 	}
+	if NakshatraLord(0) != 8 || NakshatraLord(27) != -1 {
+		t.Fatalf("NakshatraLord: got %d/%d, want 8/-1", NakshatraLord(0), NakshatraLord(27))
+	}
+	if NavataraFrom(7, 2) != 4 || NavataraFrom(27, 0) != -1 || NavataraName(8) != "Parama Mitra" {
+		t.Fatalf("NavataraFrom/NavataraName: got %d/%d/%q", NavataraFrom(7, 2), NavataraFrom(27, 0), NavataraName(8))
+	}
+	kp, err := KpSublordOf(3.0)
+	if err != nil || kp != (KpSublord{NakshatraIndex: 0, StarLord: 8, SubLord: 0}) {
+		t.Fatalf("KpSublordOf(3.0) = %+v, %v; want Ashwini/Ketu/Surya", kp, err)
+	}
+	g, found, err := GandantaStatusOf(121.0)
+	if err != nil || !found || g.Kind != GandantaKarkaSimha || g.Half != GandantaLeaving || math.Abs(g.OffsetDeg-1.0) > 1e-9 {
+		t.Fatalf("GandantaStatusOf(121.0) = %+v, %v, %v; want Karka/Simha leaving at 1°", g, found, err)
+	}
+	if _, found, err := GandantaStatusOf(60.0); err != nil || found {
+		t.Fatalf("GandantaStatusOf(60.0) found = %v, %v; want not found", found, err)
+	}
+	maitri, err := RelationshipMatrix([9]float64{5, 70, 100, 130, 160, 190, 10, 220, 40})
+	if err != nil || maitri[0][6] != 0 || maitri[6][0] != 0 || maitri[0][0] != -1 {
+		t.Fatalf("RelationshipMatrix Surya/Shani = %d/%d, diagonal %d, %v; want adhi-shatru both ways and -1", maitri[0][6], maitri[6][0], maitri[0][0], err)
+	}
+	if aspects, err := RashiDrishti(0, 4); err != nil || !aspects {
+		t.Fatalf("RashiDrishti(Mesha, Simha) = %v, %v; want true", aspects, err)
+	}
+	if _, err := RashiDrishti(12, 0); err == nil {
+		t.Fatal("RashiDrishti(12, 0) succeeded; want an invalid-query error")
+	}
+	rashiMatrix, err := RashiDrishtiMatrix()
+	if err != nil || rashiMatrix[0][1] || !rashiMatrix[0][4] {
+		t.Fatalf("RashiDrishtiMatrix Mesha row = %v, %v", rashiMatrix[0], err)
+	}
+	hours, err := GhatikaToDuration(12, 30)
+	if err != nil || math.Abs(hours-5.0) > 1e-12 {
+		t.Fatalf("GhatikaToDuration(12, 30) = %v, %v; want 5", hours, err)
+	}
+	ghati, vighati, err := DurationToGhatika(5.0)
+	if err != nil || ghati != 12 || math.Abs(vighati-30.0) > 1e-9 {
+		t.Fatalf("DurationToGhatika(5) = %d, %v, %v; want 12, 30", ghati, vighati, err)
+	}
 
 	has, exalt, err := ExaltationDegree(0)
 	if err != nil {
@@ -165,6 +204,38 @@ func TestHelperAndTaraPrimitives(t *testing.T) {
 		t.Fatalf("unexpected naisargika relationship: got=%d", relationship)
 	}
 
+	nodeSpeed, err := NodeSpeedDegPerDay(0, 2451545.0)
+	if err != nil {
+		t.Fatalf("NodeSpeedDegPerDay: %v", err)
+	}
+	if math.Abs(nodeSpeed+0.053) > 0.001 {
+		t.Fatalf("unexpected mean node speed: %v", nodeSpeed)
+	}
+
+	wholeSign, err := WholeSignFromLagna(48.25)
+	if err != nil {
+		t.Fatalf("WholeSignFromLagna: %v", err)
+	}
+	if wholeSign.Bhavas[0].CuspDeg != 30.0 || wholeSign.MCDeg != 300.0 || wholeSign.RashiBhava != nil {
+		t.Fatalf("unexpected whole-sign bhavas: %+v", wholeSign)
+	}
+
+	natal, err := CalculateAshtakavarga([7]uint8{}, 0)
+	if err != nil {
+		t.Fatalf("CalculateAshtakavarga: %v", err)
+	}
+	transitBhavas, err := WholeSignFromLagna(20.0)
+	if err != nil {
+		t.Fatalf("WholeSignFromLagna: %v", err)
+	}
+	transit, err := TransitBindus(natal, transitBhavas, 6, 95.0, TransitHouseWholeSign)
+	if err != nil {
+		t.Fatalf("TransitBindus: %v", err)
+	}
+	if transit.House != 4 || transit.RashiIndex != 3 || transit.BAVBindus == nil || *transit.BAVBindus != natal.BAVs[6].Points[3] {
+		t.Fatalf("unexpected transit bindus: %+v", transit)
+	}
+
 	position, err := TaraPropagatePosition(10.0, 20.0, 10.0, 0.0, 0.0, 0.0, 0.0)
 	if err != nil {
 		t.Fatalf("TaraPropagatePosition: %v", err)
@@ -370,9 +441,65 @@ func TestSearchAndPanchangSmoke(t *testing.T) {
 	}
 
 	loc := GeoLocation{LatitudeDeg: 12.9716, LongitudeDeg: 77.5946, AltitudeM: 920}
+	if day, err := eng.TithiDayKindForDate(eop, utc, loc, RiseSetConfigDefault()); err != nil {
+		t.Fatalf("TithiDayKindForDate: %v", err)
+	} else if (day.Kind == 0) != (day.TithiIndex == -1) {
+		t.Fatalf("unexpected tithi day: %+v", day)
+	}
 	if _, err := eng.VaarForDate(eop, utc, loc, RiseSetConfigDefault()); err != nil {
 		t.Fatalf("VaarForDate: %v", err)
 	}
+	sankranti := SankrantiConfigDefault()
+	if diff, err := eng.PanchangDiff(eop, utc, loc, RiseSetConfigDefault(), sankranti, sankranti); err != nil {
+		t.Fatalf("PanchangDiff: %v", err)
+	} else if len(diff.Limbs) != 0 {
+		t.Fatalf("expected no differing limbs for identical configs, got %+v", diff.Limbs)
+	}
+	ingresses, err := eng.MoonRashiIngresses(
+		UtcTime{Year: 2024, Month: 3, Day: 1},
+		UtcTime{Year: 2024, Month: 3, Day: 31},
+		sankranti,
+		1,
+	)
+	if err != nil {
+		t.Fatalf("MoonRashiIngresses: %v", err)
+	}
+	if len(ingresses) < 12 || len(ingresses) > 14 {
+		t.Fatalf("expected 12-14 Moon rashi ingresses in March 2024, got %d", len(ingresses))
+	}
+	nakIngress, found, err := eng.NextNakshatraIngress(301, 2451545.0, sankranti)
+	if err != nil {
+		t.Fatalf("NextNakshatraIngress: %v", err)
+	}
+	if !found || nakIngress.JdTdb <= 2451545.0 || nakIngress.JdTdb > 2451546.3 {
+		t.Fatalf("expected a Moon nakshatra ingress within a day, got found=%v %+v", found, nakIngress)
+	}
+	// 2020-07-01 .. 2021-01-01 holds Mars's single 2020 retrograde span.
+	retro, err := eng.RetrogradeIntervals(499, 2459031.5, 2459215.5, StationaryConfigDefault(), 1)
+	if err != nil {
+		t.Fatalf("RetrogradeIntervals: %v", err)
+	}
+	if len(retro) != 1 || retro[0].OpenStart || retro[0].OpenEnd || retro[0].StartJdTdb >= retro[0].EndJdTdb {
+		t.Fatalf("expected one closed Mars retrograde span in late 2020, got %+v", retro)
+	}
+	venus, err := HeliacalConfigForBody(299)
+	if err != nil {
+		t.Fatalf("HeliacalConfigForBody: %v", err)
+	}
+	_, found, heliacal, err := eng.HeliacalSearch(eop, nil, loc, HeliacalSearchRequest{
+		TargetKind: 0,
+		BodyCode:   299,
+		QueryMode:  2,
+		StartUTC:   UtcTime{Year: 2024, Month: 1, Day: 1},
+		EndUTC:     UtcTime{Year: 2026, Month: 1, Day: 1},
+		Config:     venus,
+	}, 1)
+	if err != nil {
+		t.Fatalf("HeliacalSearch: %v", err)
+	}
+	if found || len(heliacal) == 0 {
+		t.Fatalf("expected Venus heliacal range events, got found=%v n=%d", found, len(heliacal))
+	}
 
 	bhava := BhavaConfigDefault()
 	if !bhava.UseRashiBhavaForBalaAvastha {
@@ -613,7 +740,8 @@ func TestLowTierDashaWrappers(t *testing.T) {
 		SankrantiConfig: SankrantiConfigDefault(),
 	}
 
-	level0, err := eng.DashaLevel0(eop, DashaLevel0Request{Birth: birth, System: 0})
+	variation := DashaVariationConfigDefault()
+	level0, err := eng.DashaLevel0(eop, DashaLevel0Request{Birth: birth, System: 0, Variation: variation})
 	if err != nil {
 		t.Fatalf("DashaLevel0: %v", err)
 	}
@@ -625,6 +753,7 @@ func TestLowTierDashaWrappers(t *testing.T) {
 	same, found, err := eng.DashaLevel0Entity(eop, DashaLevel0EntityRequest{
 		Birth:       birth,
 		System:      0,
+		Variation:   variation,
 		EntityType:  first.EntityType,
 		EntityIndex: first.EntityIndex,
 	})
@@ -635,7 +764,6 @@ func TestLowTierDashaWrappers(t *testing.T) {
 		t.Fatalf("unexpected level0 entity lookup: found=%v same=%+v first=%+v", found, same, first)
 	}
 
-	variation := DashaVariationConfigDefault()
 	children, err := eng.DashaChildren(eop, DashaChildrenRequest{
 		Birth:     birth,
 		System:    0,
@@ -676,4 +804,21 @@ func TestLowTierDashaWrappers(t *testing.T) {
 	if len(complete) < len(children) {
 		t.Fatalf("expected complete child level, got=%d children=%d", len(complete), len(children))
 	}
+
+	transitions, err := eng.DashaTransitions(eop, DashaTransitionsRequest{
+		Birth:         birth,
+		StartTimeKind: DashaTimeUTC,
+		StartUTC:      UtcTime{Year: 2000, Month: 1, Day: 1},
+		EndTimeKind:   DashaTimeUTC,
+		EndUTC:        UtcTime{Year: 2030, Month: 1, Day: 1},
+		System:        0,
+		Level:         1,
+		Variation:     variation,
+	})
+	if err != nil {
+		t.Fatalf("DashaTransitions: %v", err)
+	}
+	if len(transitions) == 0 || !transitions[0].ClippedStart || !transitions[len(transitions)-1].ClippedEnd {
+		t.Fatalf("expected clipped transitions, got=%+v", transitions)
+	}
 }
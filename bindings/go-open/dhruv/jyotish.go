@@ -7,6 +7,13 @@ func (e *Engine) GrahaLongitudes(jdTdb float64, cfg GrahaLongitudesConfig) (Grah
 	return out, statusErr("graha_longitudes", st)
 }
 
+// GrahaSpeeds returns longitude speeds (deg/day) of the 9 grahas in the
+// frame cfg selects for GrahaLongitudes. Negative means retrograde.
+func (e *Engine) GrahaSpeeds(jdTdb float64, cfg GrahaLongitudesConfig) ([GrahaCount]float64, error) {
+	out, st := cabi.ComputeGrahaSpeeds(e.h, jdTdb, cfg)
+	return out, statusErr("graha_speeds", st)
+}
+
 func (e *Engine) MovingOsculatingApogeesForDate(ep *EOP, utc UtcTime, grahas []uint8, cfg GrahaLongitudesConfig) (MovingOsculatingApogees, error) {
 	out, st := cabi.MovingOsculatingApogeesForDate(e.h, ep.h, utc, grahas, cfg)
 	return out, statusErr("moving_osculating_apogees_for_date", st)
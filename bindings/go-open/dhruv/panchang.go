@@ -37,6 +37,11 @@ func (e *Engine) ComputeBhavasUTC(ep *EOP, lsk *LSK, loc GeoLocation, utc UtcTim
 	return out, statusErr("compute_bhavas_utc", st)
 }
 
+func WholeSignFromLagna(lagnaSidDeg float64) (BhavaResult, error) {
+	out, st := cabi.WholeSignFromLagna(lagnaSidDeg)
+	return out, statusErr("whole_sign_from_lagna", st)
+}
+
 func (e *Engine) LagnaDeg(lsk *LSK, ep *EOP, loc GeoLocation, jdTdb float64) (float64, error) {
 	out, st := cabi.LagnaDeg(lsk.h, ep.h, loc, jdTdb)
 	return out, statusErr("lagna_deg", st)
@@ -107,6 +112,11 @@ func (e *Engine) NakshatraForDate(utc UtcTime, cfg SankrantiConfig) (PanchangNak
 	return out, statusErr("nakshatra_for_date", st)
 }
 
+func (e *Engine) TithiDayKindForDate(ep *EOP, utc UtcTime, loc GeoLocation, cfg RiseSetConfig) (TithiDayInfo, error) {
+	out, st := cabi.TithiDayKindForDate(e.h, ep.h, utc, loc, cfg)
+	return out, statusErr("tithi_day_kind_for_date", st)
+}
+
 func (e *Engine) VaarForDate(ep *EOP, utc UtcTime, loc GeoLocation, cfg RiseSetConfig) (VaarInfo, error) {
 	out, st := cabi.VaarForDate(e.h, ep.h, utc, loc, cfg)
 	return out, statusErr("vaar_for_date", st)
@@ -137,6 +147,11 @@ func (e *Engine) VarshaForDate(utc UtcTime, cfg SankrantiConfig) (VarshaInfo, er
 	return out, statusErr("varsha_for_date", st)
 }
 
+func (e *Engine) PanchangDiff(ep *EOP, utc UtcTime, loc GeoLocation, rs RiseSetConfig, cfgA, cfgB SankrantiConfig) (PanchangDiff, error) {
+	out, st := cabi.PanchangDiffForDate(e.h, ep.h, utc, loc, rs, cfgA, cfgB)
+	return out, statusErr("panchang_diff", st)
+}
+
 func (e *Engine) PanchangComputeEx(ep *EOP, lsk *LSK, req PanchangComputeRequest) (PanchangOperationResult, error) {
 	out, st := cabi.PanchangComputeEx(e.h, ep.h, lsk.h, req)
 	return out, statusErr("panchang_compute_ex", st)
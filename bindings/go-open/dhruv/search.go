@@ -56,6 +56,44 @@ func (e *Engine) MotionSearch(req MotionSearchRequest, pageSize ...uint32) (Stat
 	return se, me, found, ses, mes, statusErr("motion_search_ex", st)
 }
 
+func (e *Engine) NextNodeStation(jdTdb float64) (NodeStationEvent, bool, error) {
+	ev, found, st := cabi.NextNodeStation(e.h, jdTdb)
+	return ev, found, statusErr("next_node_station", st)
+}
+
+func (e *Engine) RetrogradeIntervals(bodyCode int32, startJdTdb, endJdTdb float64, config StationaryConfig, pageSize ...uint32) ([]RetrogradeInterval, error) {
+	capacity := normalizeSearchPageSize(pageSize)
+	intervals, st := cabi.RetrogradeIntervals(e.h, bodyCode, startJdTdb, endJdTdb, config, capacity)
+	for st == 0 && len(intervals) >= int(capacity) && capacity != ^uint32(0) {
+		capacity = nextSearchPageSize(capacity)
+		intervals, st = cabi.RetrogradeIntervals(e.h, bodyCode, startJdTdb, endJdTdb, config, capacity)
+	}
+	return intervals, statusErr("retrograde_intervals", st)
+}
+
+func HeliacalConfigDefault() HeliacalConfig { return cabi.HeliacalConfigDefault() }
+
+func HeliacalConfigForBody(bodyCode int32) (HeliacalConfig, error) {
+	cfg, st := cabi.HeliacalConfigForBody(bodyCode)
+	return cfg, statusErr("heliacal_config_for_body", st)
+}
+
+// HeliacalSearch finds heliacal risings or settings of a body or a catalog
+// star. catalog is only read for star targets and may be nil otherwise.
+func (e *Engine) HeliacalSearch(ep *EOP, catalog *TaraCatalog, loc GeoLocation, req HeliacalSearchRequest, pageSize ...uint32) (HeliacalEvent, bool, []HeliacalEvent, error) {
+	var cat cabi.TaraCatalogHandle
+	if catalog != nil {
+		cat = catalog.h
+	}
+	capacity := normalizeSearchPageSize(pageSize)
+	ev, found, events, st := cabi.SearchHeliacal(e.h, ep.h, cat, loc, req, capacity)
+	for st == 0 && req.QueryMode == searchRangeMode && len(events) >= int(capacity) && capacity != ^uint32(0) {
+		capacity = nextSearchPageSize(capacity)
+		ev, found, events, st = cabi.SearchHeliacal(e.h, ep.h, cat, loc, req, capacity)
+	}
+	return ev, found, events, statusErr("heliacal_search_ex", st)
+}
+
 func (e *Engine) LunarPhaseSearch(req LunarPhaseSearchRequest, pageSize ...uint32) (LunarPhaseEvent, bool, []LunarPhaseEvent, error) {
 	capacity := normalizeSearchPageSize(pageSize)
 	ev, found, events, st := cabi.SearchLunarPhase(e.h, req, capacity)
@@ -75,3 +113,18 @@ func (e *Engine) SankrantiSearch(req SankrantiSearchRequest, pageSize ...uint32)
 	}
 	return ev, found, events, statusErr("sankranti_search_ex", st)
 }
+
+func (e *Engine) MoonRashiIngresses(start, end UtcTime, config SankrantiConfig, pageSize ...uint32) ([]IngressEvent, error) {
+	capacity := normalizeSearchPageSize(pageSize)
+	events, st := cabi.MoonRashiIngresses(e.h, start, end, config, capacity)
+	for st == 0 && len(events) >= int(capacity) && capacity != ^uint32(0) {
+		capacity = nextSearchPageSize(capacity)
+		events, st = cabi.MoonRashiIngresses(e.h, start, end, config, capacity)
+	}
+	return events, statusErr("moon_rashi_ingresses", st)
+}
+
+func (e *Engine) NextNakshatraIngress(bodyCode int32, jdTdb float64, config SankrantiConfig) (NakshatraIngressEvent, bool, error) {
+	ev, found, st := cabi.NextNakshatraIngress(e.h, bodyCode, jdTdb, config)
+	return ev, found, statusErr("next_nakshatra_ingress", st)
+}
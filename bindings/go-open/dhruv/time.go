@@ -48,6 +48,11 @@ func LunarNodeDeg(nodeCode, modeCode int32, jdTdb float64) (float64, error) {
 	return out, statusErr("lunar_node_deg", st)
 }
 
+func NodeSpeedDegPerDay(modeCode int32, jdTdb float64) (float64, error) {
+	out, st := cabi.NodeSpeedDegPerDay(modeCode, jdTdb)
+	return out, statusErr("node_speed_deg_per_day", st)
+}
+
 func (e *Engine) LunarNodeDegWithEngine(nodeCode, modeCode int32, jdTdb float64) (float64, error) {
 	out, st := cabi.LunarNodeDegWithEngine(e.h, nodeCode, modeCode, jdTdb)
 	return out, statusErr("lunar_node_deg_with_engine", st)
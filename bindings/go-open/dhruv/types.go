@@ -2,7 +2,7 @@ package dhruv
 
 import "ctara-dhruv-core/bindings/go-open/internal/cabi"
 
-const ExpectedAPIVersion = 70
+const ExpectedAPIVersion = 71
 
 const (
 	PathCapacity          = cabi.PathCapacity
@@ -133,6 +133,19 @@ const (
 	GrahaLongitudeKindTropical = cabi.GrahaLongitudeKindTropical
 )
 
+const (
+	TransitHouseWholeSign = cabi.TransitHouseWholeSign
+	TransitHouseChalit    = cabi.TransitHouseChalit
+)
+
+const (
+	GandantaMeenaMesha     = cabi.GandantaMeenaMesha
+	GandantaKarkaSimha     = cabi.GandantaKarkaSimha
+	GandantaVrischikaDhanu = cabi.GandantaVrischikaDhanu
+	GandantaApproaching    = cabi.GandantaApproaching
+	GandantaLeaving        = cabi.GandantaLeaving
+)
+
 const (
 	TimePolicyStrictLSK    = cabi.TimePolicyStrictLSK
 	TimePolicyHybridDeltaT = cabi.TimePolicyHybridDeltaT
@@ -211,14 +224,23 @@ type (
 	MotionSearchRequest = cabi.MotionSearchRequest
 	StationaryEvent     = cabi.StationaryEvent
 	MaxSpeedEvent       = cabi.MaxSpeedEvent
+	NodeStationEvent    = cabi.NodeStationEvent
+	RetrogradeInterval  = cabi.RetrogradeInterval
+
+	HeliacalConfig        = cabi.HeliacalConfig
+	HeliacalSearchRequest = cabi.HeliacalSearchRequest
+	HeliacalEvent         = cabi.HeliacalEvent
 
 	SankrantiConfig         = cabi.SankrantiConfig
 	SankrantiSearchRequest  = cabi.SankrantiSearchRequest
 	SankrantiEvent          = cabi.SankrantiEvent
+	IngressEvent            = cabi.IngressEvent
+	NakshatraIngressEvent   = cabi.NakshatraIngressEvent
 	LunarPhaseSearchRequest = cabi.LunarPhaseSearchRequest
 	LunarPhaseEvent         = cabi.LunarPhaseEvent
 
 	TithiInfo               = cabi.TithiInfo
+	TithiDayInfo            = cabi.TithiDayInfo
 	KaranaInfo              = cabi.KaranaInfo
 	YogaInfo                = cabi.YogaInfo
 	VaarInfo                = cabi.VaarInfo
@@ -239,6 +261,7 @@ type (
 	ArudhaResult                = cabi.ArudhaResult
 	AllUpagrahas                = cabi.AllUpagrahas
 	TimeUpagrahaConfig          = cabi.TimeUpagrahaConfig
+	CombustionConfig            = cabi.CombustionConfig
 	DrishtiConfig               = cabi.DrishtiConfig
 	GrahaPositionsConfig        = cabi.GrahaPositionsConfig
 	BindusConfig                = cabi.BindusConfig
@@ -255,6 +278,7 @@ type (
 	DashaChildrenRequest        = cabi.DashaChildrenRequest
 	DashaChildPeriodRequest     = cabi.DashaChildPeriodRequest
 	DashaCompleteLevelRequest   = cabi.DashaCompleteLevelRequest
+	DashaTransitionsRequest     = cabi.DashaTransitionsRequest
 	AmshaSelectionConfig        = cabi.AmshaSelectionConfig
 	FullKundaliConfig           = cabi.FullKundaliConfig
 
@@ -264,8 +288,9 @@ type (
 	TaraComputeRequest = cabi.TaraComputeRequest
 	TaraComputeResult  = cabi.TaraComputeResult
 
-	DashaPeriod   = cabi.DashaPeriod
-	DashaSnapshot = cabi.DashaSnapshot
+	DashaPeriod      = cabi.DashaPeriod
+	DashaSnapshot    = cabi.DashaSnapshot
+	DashaRangePeriod = cabi.DashaRangePeriod
 
 	SthanaBalaBreakdown       = cabi.SthanaBalaBreakdown
 	KalaBalaBreakdown         = cabi.KalaBalaBreakdown
@@ -284,6 +309,8 @@ type (
 	CharakarakaResult         = cabi.CharakarakaResult
 	FullKundaliSummary        = cabi.FullKundaliSummary
 	FullPanchangInfo          = cabi.FullPanchangInfo
+	PanchangLimbDiff          = cabi.PanchangLimbDiff
+	PanchangDiff              = cabi.PanchangDiff
 	FullKundaliDashaLevel     = cabi.FullKundaliDashaLevel
 	FullKundaliDashaHierarchy = cabi.FullKundaliDashaHierarchy
 	FullKundaliResult         = cabi.FullKundaliResult
@@ -292,6 +319,8 @@ type (
 	RashiInfo       = cabi.RashiInfo
 	NakshatraInfo   = cabi.NakshatraInfo
 	Nakshatra28Info = cabi.Nakshatra28Info
+	KpSublord       = cabi.KpSublord
+	GandantaStatus  = cabi.GandantaStatus
 
 	TithiPosition    = cabi.TithiPosition
 	KaranaPosition   = cabi.KaranaPosition
@@ -309,9 +338,10 @@ type (
 	GrahaPositions = cabi.GrahaPositions
 	BindusResult   = cabi.BindusResult
 
-	BhinnaAshtakavarga = cabi.BhinnaAshtakavarga
-	SarvaAshtakavarga  = cabi.SarvaAshtakavarga
-	AshtakavargaResult = cabi.AshtakavargaResult
+	BhinnaAshtakavarga  = cabi.BhinnaAshtakavarga
+	SarvaAshtakavarga   = cabi.SarvaAshtakavarga
+	AshtakavargaResult  = cabi.AshtakavargaResult
+	TransitBindusResult = cabi.TransitBindusResult
 
 	AmshaEntry            = cabi.AmshaEntry
 	AmshaChartScope       = cabi.AmshaChartScope
@@ -250,9 +250,14 @@ func cGeo(loc GeoLocation) C.DhruvGeoLocation {
 
 func cRiseSetConfig(cfg RiseSetConfig) C.DhruvRiseSetConfig {
 	return C.DhruvRiseSetConfig{
-		use_refraction:      boolU8(cfg.UseRefraction),
-		sun_limb:            C.int32_t(cfg.SunLimb),
-		altitude_correction: boolU8(cfg.AltitudeCorrection),
+		use_refraction:           boolU8(cfg.UseRefraction),
+		sun_limb:                 C.int32_t(cfg.SunLimb),
+		altitude_correction:      boolU8(cfg.AltitudeCorrection),
+		refraction_model:         C.int32_t(cfg.RefractionModel),
+		refraction_pressure_hpa:  C.double(cfg.RefractionPressureHpa),
+		refraction_temperature_c: C.double(cfg.RefractionTemperatureC),
+		refraction_arcmin:        C.double(cfg.RefractionArcmin),
+		day_fallback:             C.int32_t(cfg.DayFallback),
 	}
 }
 
@@ -276,15 +281,29 @@ func cTimeUpagrahaConfig(cfg TimeUpagrahaConfig) C.DhruvTimeUpagrahaConfig {
 	}
 }
 
+func cCombustionConfig(cfg CombustionConfig) C.DhruvCombustionConfig {
+	return C.DhruvCombustionConfig{moon_orb_deg: C.double(cfg.MoonOrbDeg)}
+}
+
+func CombustionConfigDefault() CombustionConfig {
+	cfg := C.dhruv_combustion_config_default()
+	return CombustionConfig{MoonOrbDeg: float64(cfg.moon_orb_deg)}
+}
+
 func TimeUpagrahaConfigDefault() TimeUpagrahaConfig {
 	return goTimeUpagrahaConfig(C.dhruv_time_upagraha_config_default())
 }
 
 func goRiseSetConfig(cfg C.DhruvRiseSetConfig) RiseSetConfig {
 	return RiseSetConfig{
-		UseRefraction:      cfg.use_refraction != 0,
-		SunLimb:            int32(cfg.sun_limb),
-		AltitudeCorrection: cfg.altitude_correction != 0,
+		UseRefraction:          cfg.use_refraction != 0,
+		SunLimb:                int32(cfg.sun_limb),
+		AltitudeCorrection:     cfg.altitude_correction != 0,
+		RefractionModel:        int32(cfg.refraction_model),
+		RefractionPressureHpa:  float64(cfg.refraction_pressure_hpa),
+		RefractionTemperatureC: float64(cfg.refraction_temperature_c),
+		RefractionArcmin:       float64(cfg.refraction_arcmin),
+		DayFallback:            int32(cfg.day_fallback),
 	}
 }
 
@@ -336,6 +355,11 @@ func cSankrantiConfig(cfg SankrantiConfig) C.DhruvSankrantiConfig {
 		step_size_days:   C.double(cfg.StepSizeDays),
 		max_iterations:   C.uint32_t(cfg.MaxIterations),
 		convergence_days: C.double(cfg.ConvergenceDays),
+
+		use_custom_ayanamsha:           boolU8(cfg.UseCustomAyanamsha),
+		custom_ayanamsha_anchor_deg:    C.double(cfg.CustomAyanamshaAnchorDeg),
+		custom_ayanamsha_anchor_jd_tdb: C.double(cfg.CustomAyanamshaAnchorJdTdb),
+		samvatsara_scheme:              C.int32_t(cfg.SamvatsaraScheme),
 	}
 }
 
@@ -347,6 +371,11 @@ func goSankrantiConfig(cfg C.DhruvSankrantiConfig) SankrantiConfig {
 		StepSizeDays:    float64(cfg.step_size_days),
 		MaxIterations:   uint32(cfg.max_iterations),
 		ConvergenceDays: float64(cfg.convergence_days),
+
+		UseCustomAyanamsha:         cfg.use_custom_ayanamsha != 0,
+		CustomAyanamshaAnchorDeg:   float64(cfg.custom_ayanamsha_anchor_deg),
+		CustomAyanamshaAnchorJdTdb: float64(cfg.custom_ayanamsha_anchor_jd_tdb),
+		SamvatsaraScheme:           int32(cfg.samvatsara_scheme),
 	}
 }
 
@@ -357,6 +386,10 @@ func cGrahaLongitudesConfig(cfg GrahaLongitudesConfig) C.DhruvGrahaLongitudesCon
 		use_nutation:     boolU8(cfg.UseNutation),
 		precession_model: C.int32_t(cfg.PrecessionModel),
 		reference_plane:  C.int32_t(cfg.ReferencePlane),
+
+		use_custom_ayanamsha:           boolU8(cfg.UseCustomAyanamsha),
+		custom_ayanamsha_anchor_deg:    C.double(cfg.CustomAyanamshaAnchorDeg),
+		custom_ayanamsha_anchor_jd_tdb: C.double(cfg.CustomAyanamshaAnchorJdTdb),
 	}
 }
 
@@ -368,6 +401,10 @@ func GrahaLongitudesConfigDefault() GrahaLongitudesConfig {
 		UseNutation:     cfg.use_nutation != 0,
 		PrecessionModel: int32(cfg.precession_model),
 		ReferencePlane:  int32(cfg.reference_plane),
+
+		UseCustomAyanamsha:         cfg.use_custom_ayanamsha != 0,
+		CustomAyanamshaAnchorDeg:   float64(cfg.custom_ayanamsha_anchor_deg),
+		CustomAyanamshaAnchorJdTdb: float64(cfg.custom_ayanamsha_anchor_jd_tdb),
 	}
 }
 
@@ -661,6 +698,7 @@ func AyanamshaComputeEx(lsk LskHandle, eop EopHandle, req AyanamshaComputeReques
 		utc:              cUTC(req.UTC),
 		use_nutation:     boolU8(req.UseNutation),
 		delta_psi_arcsec: C.double(req.DeltaPsiArcsec),
+		precession_model: C.int32_t(req.PrecessionModel),
 	}
 	var out C.double
 	st := Status(C.dhruv_ayanamsha_compute_ex(lsk.ptr, &creq, eop.ptr, &out))
@@ -725,10 +763,11 @@ func goBhavaResult(v C.DhruvBhavaResult) BhavaResult {
 	var out BhavaResult
 	for i := 0; i < 12; i++ {
 		out.Bhavas[i] = Bhava{
-			Number:   uint8(v.bhavas[i].number),
-			CuspDeg:  float64(v.bhavas[i].cusp_deg),
-			StartDeg: float64(v.bhavas[i].start_deg),
-			EndDeg:   float64(v.bhavas[i].end_deg),
+			Number:    uint8(v.bhavas[i].number),
+			CuspDeg:   float64(v.bhavas[i].cusp_deg),
+			StartDeg:  float64(v.bhavas[i].start_deg),
+			EndDeg:    float64(v.bhavas[i].end_deg),
+			MadhyaDeg: float64(v.bhavas[i].madhya_deg),
 		}
 	}
 	out.LagnaDeg = float64(v.lagna_deg)
@@ -740,10 +779,11 @@ func goBhavaResult(v C.DhruvBhavaResult) BhavaResult {
 		}
 		for i := 0; i < 12; i++ {
 			rashi.Bhavas[i] = Bhava{
-				Number:   uint8(v.rashi_bhava_bhavas[i].number),
-				CuspDeg:  float64(v.rashi_bhava_bhavas[i].cusp_deg),
-				StartDeg: float64(v.rashi_bhava_bhavas[i].start_deg),
-				EndDeg:   float64(v.rashi_bhava_bhavas[i].end_deg),
+				Number:    uint8(v.rashi_bhava_bhavas[i].number),
+				CuspDeg:   float64(v.rashi_bhava_bhavas[i].cusp_deg),
+				StartDeg:  float64(v.rashi_bhava_bhavas[i].start_deg),
+				EndDeg:    float64(v.rashi_bhava_bhavas[i].end_deg),
+				MadhyaDeg: float64(v.rashi_bhava_bhavas[i].madhya_deg),
 			}
 		}
 		out.RashiBhava = &rashi
@@ -759,6 +799,12 @@ func ComputeBhavas(engine EngineHandle, eop EopHandle, loc GeoLocation, lsk LskH
 	return goBhavaResult(out), st
 }
 
+func WholeSignFromLagna(lagnaSidDeg float64) (BhavaResult, Status) {
+	var out C.DhruvBhavaResult
+	st := Status(C.dhruv_whole_sign_from_lagna(C.double(lagnaSidDeg), &out))
+	return goBhavaResult(out), st
+}
+
 func ComputeBhavasUTC(engine EngineHandle, eop EopHandle, lsk LskHandle, loc GeoLocation, utc UtcTime, cfg BhavaConfig) (BhavaResult, Status) {
 	cloc := cGeo(loc)
 	cutc := cUTC(utc)
@@ -855,6 +901,12 @@ func LunarNodeDeg(nodeCode, modeCode int32, jdTdb float64) (float64, Status) {
 	return float64(out), st
 }
 
+func NodeSpeedDegPerDay(modeCode int32, jdTdb float64) (float64, Status) {
+	var out C.double
+	st := Status(C.dhruv_node_speed_deg_per_day(C.int32_t(modeCode), C.double(jdTdb), &out))
+	return float64(out), st
+}
+
 func LunarNodeDegWithEngine(engine EngineHandle, nodeCode, modeCode int32, jdTdb float64) (float64, Status) {
 	var out C.double
 	st := Status(C.dhruv_lunar_node_deg_with_engine(engine.ptr, C.int32_t(nodeCode), C.int32_t(modeCode), C.double(jdTdb), &out))
@@ -1113,6 +1165,104 @@ func SearchMotion(engine EngineHandle, req MotionSearchRequest, capacity uint32)
 	return convSt(outSt), convMs(outMs), found != 0, stEvents, msEvents, st
 }
 
+func NextNodeStation(engine EngineHandle, jdTdb float64) (NodeStationEvent, bool, Status) {
+	var out C.DhruvNodeStationEvent
+	var found C.uint8_t
+	st := Status(C.dhruv_next_node_station(engine.ptr, C.double(jdTdb), &out, &found))
+	return NodeStationEvent{UTC: goUTC(out.utc), JdTdb: float64(out.jd_tdb), RahuLongitudeDeg: float64(out.rahu_longitude_deg), StationType: int32(out.station_type)}, found != 0, st
+}
+
+func RetrogradeIntervals(engine EngineHandle, bodyCode int32, startJdTdb, endJdTdb float64, config StationaryConfig, capacity uint32) ([]RetrogradeInterval, Status) {
+	ccfg := C.DhruvStationaryConfig{
+		step_size_days:      C.double(config.StepSizeDays),
+		max_iterations:      C.uint32_t(config.MaxIterations),
+		convergence_days:    C.double(config.ConvergenceDays),
+		numerical_step_days: C.double(config.NumericalStepDays),
+	}
+	var outCount C.uint32_t
+	var arr []C.DhruvRetrogradeInterval
+	var ptr *C.DhruvRetrogradeInterval
+	if capacity > 0 {
+		arr = make([]C.DhruvRetrogradeInterval, capacity)
+		ptr = &arr[0]
+	}
+	st := Status(C.dhruv_retrograde_intervals(engine.ptr, C.int32_t(bodyCode), C.double(startJdTdb), C.double(endJdTdb), &ccfg, ptr, C.uint32_t(capacity), &outCount))
+	count := int(outCount)
+	if count > len(arr) {
+		count = len(arr)
+	}
+	intervals := make([]RetrogradeInterval, count)
+	for i := 0; i < count; i++ {
+		v := arr[i]
+		intervals[i] = RetrogradeInterval{StartJdTdb: float64(v.start_jd_tdb), EndJdTdb: float64(v.end_jd_tdb), StartUTC: goUTC(v.start_utc), EndUTC: goUTC(v.end_utc), StartLonDeg: float64(v.start_lon_deg), EndLonDeg: float64(v.end_lon_deg), OpenStart: v.open_start != 0, OpenEnd: v.open_end != 0}
+	}
+	return intervals, st
+}
+
+func goHeliacalConfig(c C.DhruvHeliacalConfig) HeliacalConfig {
+	return HeliacalConfig{
+		ArcusVisionisDeg: float64(c.arcus_visionis_deg),
+		MinAltitudeDeg:   float64(c.min_altitude_deg),
+		MinElongationDeg: float64(c.min_elongation_deg),
+	}
+}
+
+func HeliacalConfigDefault() HeliacalConfig {
+	return goHeliacalConfig(C.dhruv_heliacal_config_default())
+}
+
+func HeliacalConfigForBody(bodyCode int32) (HeliacalConfig, Status) {
+	var out C.DhruvHeliacalConfig
+	st := Status(C.dhruv_heliacal_config_for_body(C.int32_t(bodyCode), &out))
+	return goHeliacalConfig(out), st
+}
+
+func SearchHeliacal(engine EngineHandle, eop EopHandle, catalog TaraCatalogHandle, loc GeoLocation, req HeliacalSearchRequest, capacity uint32) (HeliacalEvent, bool, []HeliacalEvent, Status) {
+	timeKind := resolveSearchTimeKind(req.QueryMode, req.TimeKind, req.AtUTC, req.StartUTC, req.EndUTC)
+	cloc := cGeo(loc)
+	creq := C.DhruvHeliacalSearchRequest{
+		target_kind:  C.int32_t(req.TargetKind),
+		body_code:    C.int32_t(req.BodyCode),
+		tara_id:      C.int32_t(req.TaraID),
+		event_kind:   C.int32_t(req.EventKind),
+		query_mode:   C.int32_t(req.QueryMode),
+		time_kind:    C.int32_t(timeKind),
+		at_jd_tdb:    C.double(req.AtJdTdb),
+		start_jd_tdb: C.double(req.StartJdTdb),
+		end_jd_tdb:   C.double(req.EndJdTdb),
+		at_utc:       cUTC(req.AtUTC),
+		start_utc:    cUTC(req.StartUTC),
+		end_utc:      cUTC(req.EndUTC),
+		config: C.DhruvHeliacalConfig{
+			arcus_visionis_deg: C.double(req.Config.ArcusVisionisDeg),
+			min_altitude_deg:   C.double(req.Config.MinAltitudeDeg),
+			min_elongation_deg: C.double(req.Config.MinElongationDeg),
+		},
+	}
+	var out C.DhruvHeliacalEvent
+	var found C.uint8_t
+	var outCount C.uint32_t
+	var arr []C.DhruvHeliacalEvent
+	var ptr *C.DhruvHeliacalEvent
+	if capacity > 0 {
+		arr = make([]C.DhruvHeliacalEvent, capacity)
+		ptr = &arr[0]
+	}
+	st := Status(C.dhruv_heliacal_search_ex(engine.ptr, eop.ptr, catalog.ptr, &cloc, &creq, &out, &found, ptr, C.uint32_t(capacity), &outCount))
+	conv := func(v C.DhruvHeliacalEvent) HeliacalEvent {
+		return HeliacalEvent{UTC: goUTC(v.utc), JdTdb: float64(v.jd_tdb), EventKind: int32(v.event_kind), AltitudeDeg: float64(v.altitude_deg), ElongationDeg: float64(v.elongation_deg)}
+	}
+	count := int(outCount)
+	if count > len(arr) {
+		count = len(arr)
+	}
+	events := make([]HeliacalEvent, count)
+	for i := 0; i < count; i++ {
+		events[i] = conv(arr[i])
+	}
+	return conv(out), found != 0, events, st
+}
+
 func SankrantiConfigDefault() SankrantiConfig {
 	return goSankrantiConfig(C.dhruv_sankranti_config_default())
 }
@@ -1193,6 +1343,37 @@ func SearchSankranti(engine EngineHandle, req SankrantiSearchRequest, capacity u
 	return conv(out), found != 0, events, st
 }
 
+func MoonRashiIngresses(engine EngineHandle, start, end UtcTime, config SankrantiConfig, capacity uint32) ([]IngressEvent, Status) {
+	cstart := cUTC(start)
+	cend := cUTC(end)
+	ccfg := cSankrantiConfig(config)
+	var outCount C.uint32_t
+	var arr []C.DhruvIngressEvent
+	var ptr *C.DhruvIngressEvent
+	if capacity > 0 {
+		arr = make([]C.DhruvIngressEvent, capacity)
+		ptr = &arr[0]
+	}
+	st := Status(C.dhruv_moon_rashi_ingresses(engine.ptr, &cstart, &cend, &ccfg, ptr, C.uint32_t(capacity), &outCount))
+	count := int(outCount)
+	if count > len(arr) {
+		count = len(arr)
+	}
+	events := make([]IngressEvent, count)
+	for i := 0; i < count; i++ {
+		events[i] = IngressEvent{UTC: goUTC(arr[i].utc), RashiIndex: int32(arr[i].rashi_index), JdTdb: float64(arr[i].jd_tdb)}
+	}
+	return events, st
+}
+
+func NextNakshatraIngress(engine EngineHandle, bodyCode int32, jdTdb float64, config SankrantiConfig) (NakshatraIngressEvent, bool, Status) {
+	ccfg := cSankrantiConfig(config)
+	var out C.DhruvNakshatraIngressEvent
+	var found C.uint8_t
+	st := Status(C.dhruv_next_nakshatra_ingress(engine.ptr, C.int32_t(bodyCode), C.double(jdTdb), &ccfg, &out, &found))
+	return NakshatraIngressEvent{UTC: goUTC(out.utc), JdTdb: float64(out.jd_tdb), BodyCode: int32(out.body_code), NakshatraIndex: int32(out.nakshatra_index), BoundaryDeg: float64(out.boundary_deg), Retrograde: out.retrograde != 0}, found != 0, st
+}
+
 func goTithiInfo(v C.DhruvTithiInfo) TithiInfo {
 	return TithiInfo{TithiIndex: int32(v.tithi_index), Paksha: int32(v.paksha), TithiInPaksha: int32(v.tithi_in_paksha), Start: goUTC(v.start), End: goUTC(v.end)}
 }
@@ -1263,6 +1444,13 @@ func NakshatraForDate(engine EngineHandle, utc UtcTime, cfg SankrantiConfig) (Pa
 	return goNakshatraInfo(out), st
 }
 
+func TithiDayKindForDate(engine EngineHandle, eop EopHandle, utc UtcTime, loc GeoLocation, cfg RiseSetConfig) (TithiDayInfo, Status) {
+	cutc, cloc, ccfg := cUTC(utc), cGeo(loc), cRiseSetConfig(cfg)
+	var out C.DhruvTithiDayInfo
+	st := Status(C.dhruv_tithi_day_kind_for_date(engine.ptr, eop.ptr, &cutc, &cloc, &ccfg, &out))
+	return TithiDayInfo{Kind: int32(out.kind), TithiIndex: int32(out.tithi_index)}, st
+}
+
 func VaarForDate(engine EngineHandle, eop EopHandle, utc UtcTime, loc GeoLocation, cfg RiseSetConfig) (VaarInfo, Status) {
 	cutc, cloc, ccfg := cUTC(utc), cGeo(loc), cRiseSetConfig(cfg)
 	var out C.DhruvVaarInfo
@@ -1308,6 +1496,28 @@ func VarshaForDate(engine EngineHandle, utc UtcTime, cfg SankrantiConfig) (Varsh
 	return goVarshaInfo(out), st
 }
 
+func PanchangDiffForDate(engine EngineHandle, eop EopHandle, utc UtcTime, loc GeoLocation, rs RiseSetConfig, cfgA, cfgB SankrantiConfig) (PanchangDiff, Status) {
+	cutc, cloc, crs := cUTC(utc), cGeo(loc), cRiseSetConfig(rs)
+	ca, cb := cSankrantiConfig(cfgA), cSankrantiConfig(cfgB)
+	var out C.DhruvPanchangDiff
+	st := Status(C.dhruv_panchang_diff(engine.ptr, eop.ptr, &cutc, &cloc, &crs, &ca, &cb, &out))
+	diff := PanchangDiff{
+		A:     goFullPanchangInfo(out.a),
+		B:     goFullPanchangInfo(out.b),
+		Limbs: make([]PanchangLimbDiff, 0, int(out.limb_count)),
+	}
+	for i := 0; i < int(out.limb_count); i++ {
+		d := out.limbs[i]
+		diff.Limbs = append(diff.Limbs, PanchangLimbDiff{
+			Limb:         int32(d.limb),
+			ValueDiffers: d.value_differs != 0,
+			StartShiftS:  float64(d.start_shift_s),
+			EndShiftS:    float64(d.end_shift_s),
+		})
+	}
+	return diff, st
+}
+
 func PanchangComputeEx(engine EngineHandle, eop EopHandle, lsk LskHandle, req PanchangComputeRequest) (PanchangOperationResult, Status) {
 	creq := C.DhruvPanchangComputeRequest{
 		time_kind:        C.int32_t(req.TimeKind),
@@ -1323,6 +1533,8 @@ func PanchangComputeEx(engine EngineHandle, eop EopHandle, lsk LskHandle, req Pa
 	res := PanchangOperationResult{
 		TithiValid:     out.tithi_valid != 0,
 		Tithi:          goTithiInfo(out.tithi),
+		TithiDayKind:   int32(out.tithi_day_kind),
+		TithiDayTithi:  int32(out.tithi_day_tithi_index),
 		KaranaValid:    out.karana_valid != 0,
 		Karana:         goKaranaInfo(out.karana),
 		YogaValid:      out.yoga_valid != 0,
@@ -1360,6 +1572,17 @@ func ComputeGrahaLongitudes(engine EngineHandle, jdTdb float64, cfg GrahaLongitu
 	return goOut, st
 }
 
+func ComputeGrahaSpeeds(engine EngineHandle, jdTdb float64, cfg GrahaLongitudesConfig) ([GrahaCount]float64, Status) {
+	var out C.DhruvGrahaSpeeds
+	ccfg := cGrahaLongitudesConfig(cfg)
+	st := Status(C.dhruv_graha_speeds(engine.ptr, C.double(jdTdb), &ccfg, &out))
+	var speeds [GrahaCount]float64
+	for i := 0; i < GrahaCount; i++ {
+		speeds[i] = float64(out.speeds[i])
+	}
+	return speeds, st
+}
+
 func MovingOsculatingApogeesForDate(engine EngineHandle, eop EopHandle, utc UtcTime, grahas []uint8, cfg GrahaLongitudesConfig) (MovingOsculatingApogees, Status) {
 	cutc := cUTC(utc)
 	ccfg := cGrahaLongitudesConfig(cfg)
@@ -1484,6 +1707,7 @@ func goDashaSelectionConfig(v C.DhruvDashaSelectionConfig) DashaSelectionConfig
 	out.MaxLevel = uint8(v.max_level)
 	out.YoginiScheme = uint8(v.yogini_scheme)
 	out.UseAbhijit = v.use_abhijit != 0
+	out.YearLength = uint8(v.year_length)
 	for i := 0; i < MaxDashaSystems; i++ {
 		out.Systems[i] = uint8(v.systems[i])
 		out.MaxLevels[i] = uint8(v.max_levels[i])
@@ -1507,6 +1731,7 @@ func cDashaSelectionConfig(cfg DashaSelectionConfig) C.DhruvDashaSelectionConfig
 	out.max_level = C.uint8_t(cfg.MaxLevel)
 	out.yogini_scheme = C.uint8_t(cfg.YoginiScheme)
 	out.use_abhijit = boolU8(cfg.UseAbhijit)
+	out.year_length = C.uint8_t(cfg.YearLength)
 	for i := 0; i < MaxDashaSystems; i++ {
 		out.systems[i] = C.uint8_t(cfg.Systems[i])
 		out.max_levels[i] = C.uint8_t(cfg.MaxLevels[i])
@@ -1531,6 +1756,7 @@ func goDashaVariationConfig(v C.DhruvDashaVariationConfig) DashaVariationConfig
 	var out DashaVariationConfig
 	out.YoginiScheme = uint8(v.yogini_scheme)
 	out.UseAbhijit = v.use_abhijit != 0
+	out.YearLength = uint8(v.year_length)
 	for i := 0; i < len(out.LevelMethods); i++ {
 		out.LevelMethods[i] = uint8(v.level_methods[i])
 	}
@@ -1541,6 +1767,7 @@ func cDashaVariationConfig(cfg DashaVariationConfig) C.DhruvDashaVariationConfig
 	var out C.DhruvDashaVariationConfig
 	out.yogini_scheme = C.uint8_t(cfg.YoginiScheme)
 	out.use_abhijit = boolU8(cfg.UseAbhijit)
+	out.year_length = C.uint8_t(cfg.YearLength)
 	for i := 0; i < len(cfg.LevelMethods); i++ {
 		out.level_methods[i] = C.uint8_t(cfg.LevelMethods[i])
 	}
@@ -1622,6 +1849,7 @@ func FullKundaliConfigDefault() FullKundaliConfig {
 			IncludeLagna:        cfg.graha_positions_config.include_lagna != 0,
 			IncludeOuterPlanets: cfg.graha_positions_config.include_outer_planets != 0,
 			IncludeBhava:        cfg.graha_positions_config.include_bhava != 0,
+			IncludeFlags:        cfg.graha_positions_config.include_flags != 0,
 		},
 		BindusConfig: BindusConfig{
 			IncludeNakshatra: cfg.bindus_config.include_nakshatra != 0,
@@ -1673,6 +1901,7 @@ func cFullKundaliConfig(cfg FullKundaliConfig) C.DhruvFullKundaliConfig {
 		include_lagna:         boolU8(cfg.GrahaPositionsConfig.IncludeLagna),
 		include_outer_planets: boolU8(cfg.GrahaPositionsConfig.IncludeOuterPlanets),
 		include_bhava:         boolU8(cfg.GrahaPositionsConfig.IncludeBhava),
+		include_flags:         boolU8(cfg.GrahaPositionsConfig.IncludeFlags),
 	}
 	out.bindus_config = C.DhruvBindusConfig{
 		include_nakshatra: boolU8(cfg.BindusConfig.IncludeNakshatra),
@@ -1789,8 +2018,9 @@ func goDashaPeriodList(handle DashaPeriodListHandle) ([]DashaPeriod, Status) {
 
 func DashaLevel0(engine EngineHandle, eop EopHandle, request DashaLevel0Request) ([]DashaPeriod, Status) {
 	crequest := C.DhruvDashaLevel0Request{
-		birth:  cDashaBirthContext(request.Birth),
-		system: C.uint8_t(request.System),
+		birth:     cDashaBirthContext(request.Birth),
+		system:    C.uint8_t(request.System),
+		variation: cDashaVariationConfig(request.Variation),
 	}
 	var handle C.DhruvDashaPeriodListHandle
 	st := Status(C.dhruv_dasha_level0(engine.ptr, eop.ptr, &crequest, &handle))
@@ -1804,6 +2034,7 @@ func DashaLevel0Entity(engine EngineHandle, eop EopHandle, request DashaLevel0En
 	crequest := C.DhruvDashaLevel0EntityRequest{
 		birth:        cDashaBirthContext(request.Birth),
 		system:       C.uint8_t(request.System),
+		variation:    cDashaVariationConfig(request.Variation),
 		entity_type:  C.uint8_t(request.EntityType),
 		entity_index: C.uint8_t(request.EntityIndex),
 	}
@@ -1866,6 +2097,44 @@ func DashaCompleteLevel(engine EngineHandle, eop EopHandle, request DashaComplet
 	return goDashaPeriodList(DashaPeriodListHandle{ptr: handle})
 }
 
+func DashaTransitions(engine EngineHandle, eop EopHandle, request DashaTransitionsRequest) ([]DashaRangePeriod, Status) {
+	crequest := C.DhruvDashaTransitionsRequest{
+		birth:           cDashaBirthContext(request.Birth),
+		start_time_kind: C.int32_t(request.StartTimeKind),
+		start_jd:        C.double(request.StartJD),
+		start_utc:       cUTC(request.StartUTC),
+		end_time_kind:   C.int32_t(request.EndTimeKind),
+		end_jd:          C.double(request.EndJD),
+		end_utc:         cUTC(request.EndUTC),
+		system:          C.uint8_t(request.System),
+		level:           C.uint8_t(request.Level),
+		variation:       cDashaVariationConfig(request.Variation),
+	}
+	var handle C.DhruvDashaRangePeriodListHandle
+	st := Status(C.dhruv_dasha_transitions(engine.ptr, eop.ptr, &crequest, &handle))
+	if st != StatusOK {
+		return nil, st
+	}
+	defer C.dhruv_dasha_range_period_list_free(handle)
+	var count C.uint32_t
+	if st := Status(C.dhruv_dasha_range_period_list_count(handle, &count)); st != StatusOK {
+		return nil, st
+	}
+	periods := make([]DashaRangePeriod, int(count))
+	for idx := range periods {
+		var out C.DhruvDashaRangePeriod
+		if st := Status(C.dhruv_dasha_range_period_list_at(handle, C.uint32_t(idx), &out)); st != StatusOK {
+			return nil, st
+		}
+		periods[idx] = DashaRangePeriod{
+			Period:       goDashaPeriod(out.period),
+			ClippedStart: out.clipped_start != 0,
+			ClippedEnd:   out.clipped_end != 0,
+		}
+	}
+	return periods, StatusOK
+}
+
 func LoadTaraCatalog(path string) (TaraCatalogHandle, Status) {
 	bytes := []byte(path)
 	ptr := C.CBytes(bytes)
@@ -2012,6 +2281,8 @@ func goShadbala(out C.DhruvShadbalaResult) ShadbalaResult {
 			TotalRupas:        float64(e.total_rupas),
 			RequiredStrength:  float64(e.required_strength),
 			IsStrong:          e.is_strong != 0,
+			Ishta:             float64(e.ishta),
+			Kashta:            float64(e.kashta),
 		}
 	}
 	return res
@@ -2246,6 +2517,8 @@ func goFullPanchangInfo(v C.DhruvPanchangInfo) FullPanchangInfo {
 			Start:         goUTC(v.tithi.start),
 			End:           goUTC(v.tithi.end),
 		},
+		TithiDayKind:  int32(v.tithi_day_kind),
+		TithiDayTithi: int32(v.tithi_day_tithi_index),
 		Karana: KaranaInfo{
 			KaranaIndex:     int32(v.karana.karana_index),
 			KaranaNameIndex: int32(v.karana.karana_name_index),
@@ -53,6 +53,9 @@ func goGrahaEntry(v C.DhruvGrahaEntry) GrahaEntry {
 		Pada:              uint8(v.pada),
 		BhavaNumber:       uint8(v.bhava_number),
 		RashiBhavaNumber:  uint8(v.rashi_bhava_number),
+		Gandanta:          v.gandanta != 0,
+		RashiSandhi:       v.rashi_sandhi != 0,
+		Pushkara:          v.pushkara != 0,
 	}
 }
 
@@ -87,6 +90,19 @@ func Nakshatra28FromLongitude(siderealLon float64) (Nakshatra28Info, Status) {
 	return goNakshatra28Info(out), st
 }
 
+func KpSublordOf(siderealLon float64) (KpSublord, Status) {
+	var out C.DhruvKpSublord
+	st := Status(C.dhruv_kp_sublord(C.double(siderealLon), &out))
+	return KpSublord{NakshatraIndex: uint8(out.nakshatra_index), StarLord: uint8(out.star_lord), SubLord: uint8(out.sub_lord)}, st
+}
+
+func GandantaStatusOf(siderealLon float64) (GandantaStatus, bool, Status) {
+	var out C.DhruvGandantaStatus
+	var found C.uint8_t
+	st := Status(C.dhruv_gandanta_status(C.double(siderealLon), &out, &found))
+	return GandantaStatus{Kind: int32(out.kind), Half: int32(out.half), OffsetDeg: float64(out.offset_deg)}, found != 0, st
+}
+
 func RashiFromTropical(tropicalLon float64, ayanamshaSystem uint32, jdTdb float64, useNutation bool) (RashiInfo, Status) {
 	var out C.DhruvRashiInfo
 	st := Status(C.dhruv_rashi_from_tropical(C.double(tropicalLon), C.uint32_t(ayanamshaSystem), C.double(jdTdb), boolU8(useNutation), &out))
@@ -169,6 +185,9 @@ func GrahaName(index uint32) string {
 func YoginiName(index uint32) string {
 	return cString((*C.char)(unsafe.Pointer(C.dhruv_yogini_name(C.uint32_t(index)))))
 }
+func NavataraName(index uint32) string {
+	return cString((*C.char)(unsafe.Pointer(C.dhruv_navatara_name(C.uint32_t(index)))))
+}
 func SphutaName(index uint32) string {
 	return cString((*C.char)(unsafe.Pointer(C.dhruv_sphuta_name(C.uint32_t(index)))))
 }
@@ -211,6 +230,12 @@ func NthRashiFrom(rashi, offset uint32) int32 {
 	return int32(C.dhruv_nth_rashi_from(C.uint32_t(rashi), C.uint32_t(offset)))
 }
 func RashiLord(rashi uint32) int32 { return int32(C.dhruv_rashi_lord(C.uint32_t(rashi))) }
+func NakshatraLord(nakshatra uint32) int32 {
+	return int32(C.dhruv_nakshatra_lord(C.uint32_t(nakshatra)))
+}
+func NavataraFrom(start, target uint32) int32 {
+	return int32(C.dhruv_navatara_from(C.uint32_t(start), C.uint32_t(target)))
+}
 func HoraAt(vaarIndex, horaIndex uint32) int32 {
 	return int32(C.dhruv_hora_at(C.uint32_t(vaarIndex), C.uint32_t(horaIndex)))
 }
@@ -247,20 +272,22 @@ func MoolatrikoneRange(grahaIndex uint32) (bool, uint8, float64, float64, Status
 	return has != 0, uint8(rashi), float64(start), float64(end), st
 }
 
-func CombustionThreshold(grahaIndex uint32, isRetrograde bool) (bool, float64, Status) {
+func CombustionThreshold(grahaIndex uint32, isRetrograde bool, cfg CombustionConfig) (bool, float64, Status) {
 	var has C.uint8_t
 	var out C.double
-	st := Status(C.dhruv_combustion_threshold(C.uint32_t(grahaIndex), boolU8(isRetrograde), &has, &out))
+	ccfg := cCombustionConfig(cfg)
+	st := Status(C.dhruv_combustion_threshold(C.uint32_t(grahaIndex), boolU8(isRetrograde), &ccfg, &has, &out))
 	return has != 0, float64(out), st
 }
 
-func IsCombust(grahaIndex uint32, grahaSidLon, sunSidLon float64, isRetrograde bool) (bool, Status) {
+func IsCombust(grahaIndex uint32, grahaSidLon, sunSidLon float64, isRetrograde bool, cfg CombustionConfig) (bool, Status) {
 	var out C.uint8_t
-	st := Status(C.dhruv_is_combust(C.uint32_t(grahaIndex), C.double(grahaSidLon), C.double(sunSidLon), boolU8(isRetrograde), &out))
+	ccfg := cCombustionConfig(cfg)
+	st := Status(C.dhruv_is_combust(C.uint32_t(grahaIndex), C.double(grahaSidLon), C.double(sunSidLon), boolU8(isRetrograde), &ccfg, &out))
 	return out != 0, st
 }
 
-func AllCombustionStatus(siderealLons [9]float64, retrogradeFlags [9]bool) ([9]bool, Status) {
+func AllCombustionStatus(siderealLons [9]float64, retrogradeFlags [9]bool, cfg CombustionConfig) ([9]bool, Status) {
 	var csidereal [9]C.double
 	var cretro [9]C.uint8_t
 	for i := 0; i < 9; i++ {
@@ -268,7 +295,8 @@ func AllCombustionStatus(siderealLons [9]float64, retrogradeFlags [9]bool) ([9]b
 		cretro[i] = boolU8(retrogradeFlags[i])
 	}
 	var out [9]C.uint8_t
-	st := Status(C.dhruv_all_combustion_status(&csidereal[0], &cretro[0], &out[0]))
+	ccfg := cCombustionConfig(cfg)
+	st := Status(C.dhruv_all_combustion_status(&csidereal[0], &cretro[0], &ccfg, &out[0]))
 	var result [9]bool
 	for i := 0; i < 9; i++ {
 		result[i] = out[i] != 0
@@ -294,6 +322,22 @@ func PanchadhaMaitri(naisargikaCode, tatkalikaCode int32) (int32, Status) {
 	return int32(out), st
 }
 
+func RelationshipMatrix(siderealLons [9]float64) ([9][9]int32, Status) {
+	var csidereal [9]C.double
+	for i := 0; i < 9; i++ {
+		csidereal[i] = C.double(siderealLons[i])
+	}
+	var out [81]C.int32_t
+	st := Status(C.dhruv_relationship_matrix(&csidereal[0], &out[0]))
+	var result [9][9]int32
+	for i := 0; i < 9; i++ {
+		for j := 0; j < 9; j++ {
+			result[i][j] = int32(out[i*9+j])
+		}
+	}
+	return result, st
+}
+
 func DignityInRashi(grahaIndex uint32, siderealLon float64, rashiIndex uint32) (int32, Status) {
 	var out C.int32_t
 	st := Status(C.dhruv_dignity_in_rashi(C.uint32_t(grahaIndex), C.double(siderealLon), C.uint32_t(rashiIndex), &out))
@@ -338,9 +382,9 @@ func GrahaGender(grahaIndex uint32) (int32, Status) {
 	return int32(out), st
 }
 
-func SamvatsaraFromYear(year int32) (SamvatsaraResult, Status) {
+func SamvatsaraFromYear(year, scheme int32) (SamvatsaraResult, Status) {
 	var out C.DhruvSamvatsaraResult
-	st := Status(C.dhruv_samvatsara_from_year(C.int32_t(year), &out))
+	st := Status(C.dhruv_samvatsara_from_year(C.int32_t(year), C.int32_t(scheme), &out))
 	return SamvatsaraResult{SamvatsaraIndex: int32(out.samvatsara_index), CyclePosition: int32(out.cycle_position)}, st
 }
 
@@ -433,6 +477,19 @@ func GhatikasSinceSunrise(queryJd, sunriseJd float64) (float64, Status) {
 	return float64(out), st
 }
 
+func GhatikaToDuration(ghati, vighati float64) (float64, Status) {
+	var out C.double
+	st := Status(C.dhruv_ghatika_to_duration(C.double(ghati), C.double(vighati), &out))
+	return float64(out), st
+}
+
+func DurationToGhatika(hours float64) (uint32, float64, Status) {
+	var ghati C.uint32_t
+	var vighati C.double
+	st := Status(C.dhruv_duration_to_ghatika(C.double(hours), &ghati, &vighati))
+	return uint32(ghati), float64(vighati), st
+}
+
 func AllSphutas(inputs SphutalInputs) (SphutalResult, Status) {
 	cin := C.DhruvSphutalInputs{
 		sun:         C.double(inputs.Sun),
@@ -685,6 +742,59 @@ func EkadhipatyaSodhana(totals [12]uint8, grahaRashis [SaptaGrahaCount]uint8, la
 	return res, st
 }
 
+func cAshtakavarga(v AshtakavargaResult) C.DhruvAshtakavargaResult {
+	var out C.DhruvAshtakavargaResult
+	for i := 0; i < SaptaGrahaCount; i++ {
+		out.bavs[i].graha_index = C.uint8_t(v.BAVs[i].GrahaIndex)
+		for j := 0; j < 12; j++ {
+			out.bavs[i].points[j] = C.uint8_t(v.BAVs[i].Points[j])
+			for k := 0; k < 8; k++ {
+				out.bavs[i].contributors[j][k] = C.uint8_t(v.BAVs[i].Contributors[j][k])
+			}
+		}
+	}
+	for i := 0; i < 12; i++ {
+		out.sav.total_points[i] = C.uint8_t(v.SAV.TotalPoints[i])
+		out.sav.after_trikona[i] = C.uint8_t(v.SAV.AfterTrikona[i])
+		out.sav.after_ekadhipatya[i] = C.uint8_t(v.SAV.AfterEkadhipatya[i])
+	}
+	return out
+}
+
+func cBhavaResult(v BhavaResult) C.DhruvBhavaResult {
+	var out C.DhruvBhavaResult
+	for i := 0; i < 12; i++ {
+		out.bhavas[i] = C.DhruvBhava{
+			number:     C.uint8_t(v.Bhavas[i].Number),
+			cusp_deg:   C.double(v.Bhavas[i].CuspDeg),
+			start_deg:  C.double(v.Bhavas[i].StartDeg),
+			end_deg:    C.double(v.Bhavas[i].EndDeg),
+			madhya_deg: C.double(v.Bhavas[i].MadhyaDeg),
+		}
+	}
+	out.lagna_deg = C.double(v.LagnaDeg)
+	out.mc_deg = C.double(v.MCDeg)
+	return out
+}
+
+func TransitBindus(natal AshtakavargaResult, natalBhavas BhavaResult, grahaIndex uint32, transitLonSid float64, houseMode int32) (TransitBindusResult, Status) {
+	cnatal, cbhavas := cAshtakavarga(natal), cBhavaResult(natalBhavas)
+	var out C.DhruvTransitBindus
+	st := Status(C.dhruv_transit_bindus(&cnatal, &cbhavas, C.uint32_t(grahaIndex), C.double(transitLonSid), C.int32_t(houseMode), &out))
+	res := TransitBindusResult{
+		GrahaIndex:          uint8(out.graha_index),
+		TransitLongitudeDeg: float64(out.transit_longitude_deg),
+		House:               uint8(out.house),
+		RashiIndex:          uint8(out.rashi_index),
+		SAVBindus:           uint8(out.sav_bindus),
+	}
+	if out.bav_valid != 0 {
+		bav := uint8(out.bav_bindus)
+		res.BAVBindus = &bav
+	}
+	return res, st
+}
+
 func AshtakavargaForDate(engine EngineHandle, eop EopHandle, utc UtcTime, loc GeoLocation, ayanamshaSystem uint32, useNutation bool) (AshtakavargaResult, Status) {
 	cutc, cloc := cUTC(utc), cGeo(loc)
 	var out C.DhruvAshtakavargaResult
@@ -714,6 +824,24 @@ func GrahaDrishtiMatrixForLongitudes(siderealLons [GrahaCount]float64) (GrahaDri
 	return res, st
 }
 
+func RashiDrishti(fromRashi, toRashi uint32) (bool, Status) {
+	var out C.uint8_t
+	st := Status(C.dhruv_rashi_drishti(C.uint32_t(fromRashi), C.uint32_t(toRashi), &out))
+	return out != 0, st
+}
+
+func RashiDrishtiMatrix() ([12][12]bool, Status) {
+	var out C.DhruvRashiDrishtiMatrix
+	st := Status(C.dhruv_rashi_drishti_matrix(&out))
+	var res [12][12]bool
+	for i := 0; i < 12; i++ {
+		for j := 0; j < 12; j++ {
+			res[i][j] = out.aspects[i][j] != 0
+		}
+	}
+	return res, st
+}
+
 func cDrishtiConfig(cfg DrishtiConfig) C.DhruvDrishtiConfig {
 	return C.DhruvDrishtiConfig{include_bhava: boolU8(cfg.IncludeBhava), include_lagna: boolU8(cfg.IncludeLagna), include_bindus: boolU8(cfg.IncludeBindus)}
 }
@@ -746,6 +874,7 @@ func cGrahaPositionsConfig(cfg GrahaPositionsConfig) C.DhruvGrahaPositionsConfig
 		include_lagna:         boolU8(cfg.IncludeLagna),
 		include_outer_planets: boolU8(cfg.IncludeOuterPlanets),
 		include_bhava:         boolU8(cfg.IncludeBhava),
+		include_flags:         boolU8(cfg.IncludeFlags),
 	}
 }
 
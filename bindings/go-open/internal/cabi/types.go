@@ -137,6 +137,19 @@ const (
 	GrahaLongitudeKindTropical int32 = 1
 )
 
+const (
+	TransitHouseWholeSign int32 = 0
+	TransitHouseChalit    int32 = 1
+)
+
+const (
+	GandantaMeenaMesha     int32 = 0
+	GandantaKarkaSimha     int32 = 1
+	GandantaVrischikaDhanu int32 = 2
+	GandantaApproaching    int32 = 0
+	GandantaLeaving        int32 = 1
+)
+
 const (
 	TimePolicyStrictLSK    int32 = 0
 	TimePolicyHybridDeltaT int32 = 1
@@ -325,9 +338,14 @@ type GeoLocation struct {
 }
 
 type RiseSetConfig struct {
-	UseRefraction      bool
-	SunLimb            int32
-	AltitudeCorrection bool
+	UseRefraction          bool
+	SunLimb                int32
+	AltitudeCorrection     bool
+	RefractionModel        int32
+	RefractionPressureHpa  float64
+	RefractionTemperatureC float64
+	RefractionArcmin       float64
+	DayFallback            int32
 }
 
 type RiseSetResult struct {
@@ -343,13 +361,14 @@ type RiseSetResultUTC struct {
 }
 
 type AyanamshaComputeRequest struct {
-	SystemCode     int32
-	Mode           int32
-	TimeKind       int32
-	JdTdb          float64
-	UTC            UtcTime
-	UseNutation    bool
-	DeltaPsiArcsec float64
+	SystemCode      int32
+	Mode            int32
+	TimeKind        int32
+	JdTdb           float64
+	UTC             UtcTime
+	UseNutation     bool
+	DeltaPsiArcsec  float64
+	PrecessionModel int32
 }
 
 type BhavaConfig struct {
@@ -371,10 +390,11 @@ type BhavaConfig struct {
 }
 
 type Bhava struct {
-	Number   uint8
-	CuspDeg  float64
-	StartDeg float64
-	EndDeg   float64
+	Number    uint8
+	CuspDeg   float64
+	StartDeg  float64
+	EndDeg    float64
+	MadhyaDeg float64
 }
 
 type BhavaResult struct {
@@ -513,6 +533,54 @@ type StationaryEvent struct {
 	StationType  int32
 }
 
+type NodeStationEvent struct {
+	UTC              UtcTime
+	JdTdb            float64
+	RahuLongitudeDeg float64
+	StationType      int32
+}
+
+type RetrogradeInterval struct {
+	StartJdTdb  float64
+	EndJdTdb    float64
+	StartUTC    UtcTime
+	EndUTC      UtcTime
+	StartLonDeg float64
+	EndLonDeg   float64
+	OpenStart   bool
+	OpenEnd     bool
+}
+
+type HeliacalConfig struct {
+	ArcusVisionisDeg float64
+	MinAltitudeDeg   float64
+	MinElongationDeg float64
+}
+
+type HeliacalSearchRequest struct {
+	TargetKind int32
+	BodyCode   int32
+	TaraID     int32
+	EventKind  int32
+	QueryMode  int32
+	TimeKind   int32
+	AtJdTdb    float64
+	StartJdTdb float64
+	EndJdTdb   float64
+	AtUTC      UtcTime
+	StartUTC   UtcTime
+	EndUTC     UtcTime
+	Config     HeliacalConfig
+}
+
+type HeliacalEvent struct {
+	UTC           UtcTime
+	JdTdb         float64
+	EventKind     int32
+	AltitudeDeg   float64
+	ElongationDeg float64
+}
+
 type MaxSpeedEvent struct {
 	UTC            UtcTime
 	JdTdb          float64
@@ -524,20 +592,27 @@ type MaxSpeedEvent struct {
 }
 
 type SankrantiConfig struct {
-	AyanamshaSystem int32
-	UseNutation     bool
-	ReferencePlane  int32
-	StepSizeDays    float64
-	MaxIterations   uint32
-	ConvergenceDays float64
+	AyanamshaSystem            int32
+	UseNutation                bool
+	ReferencePlane             int32
+	StepSizeDays               float64
+	MaxIterations              uint32
+	ConvergenceDays            float64
+	UseCustomAyanamsha         bool
+	CustomAyanamshaAnchorDeg   float64
+	CustomAyanamshaAnchorJdTdb float64
+	SamvatsaraScheme           int32
 }
 
 type GrahaLongitudesConfig struct {
-	Kind            int32
-	AyanamshaSystem int32
-	UseNutation     bool
-	PrecessionModel int32
-	ReferencePlane  int32
+	Kind                       int32
+	AyanamshaSystem            int32
+	UseNutation                bool
+	PrecessionModel            int32
+	ReferencePlane             int32
+	UseCustomAyanamsha         bool
+	CustomAyanamshaAnchorDeg   float64
+	CustomAyanamshaAnchorJdTdb float64
 }
 
 type SankrantiEvent struct {
@@ -547,6 +622,21 @@ type SankrantiEvent struct {
 	SunTropicalLongitudeDeg float64
 }
 
+type IngressEvent struct {
+	UTC        UtcTime
+	RashiIndex int32
+	JdTdb      float64
+}
+
+type NakshatraIngressEvent struct {
+	UTC            UtcTime
+	JdTdb          float64
+	BodyCode       int32
+	NakshatraIndex int32
+	BoundaryDeg    float64
+	Retrograde     bool
+}
+
 type SankrantiSearchRequest struct {
 	TargetKind int32
 	QueryMode  int32
@@ -601,6 +691,11 @@ type YogaInfo struct {
 	End       UtcTime
 }
 
+type TithiDayInfo struct {
+	Kind       int32
+	TithiIndex int32
+}
+
 type VaarInfo struct {
 	VaarIndex int32
 	Start     UtcTime
@@ -660,6 +755,8 @@ type PanchangComputeRequest struct {
 type PanchangOperationResult struct {
 	TithiValid     bool
 	Tithi          TithiInfo
+	TithiDayKind   int32
+	TithiDayTithi  int32
 	KaranaValid    bool
 	Karana         KaranaInfo
 	YogaValid      bool
@@ -739,6 +836,11 @@ type GrahaPositionsConfig struct {
 	IncludeLagna        bool
 	IncludeOuterPlanets bool
 	IncludeBhava        bool
+	IncludeFlags        bool
+}
+
+type CombustionConfig struct {
+	MoonOrbDeg float64
 }
 
 type TimeUpagrahaConfig struct {
@@ -801,6 +903,12 @@ type DashaPeriod struct {
 	ParentIdx   uint32
 }
 
+type DashaRangePeriod struct {
+	Period       DashaPeriod
+	ClippedStart bool
+	ClippedEnd   bool
+}
+
 type DashaSnapshot struct {
 	System   uint8
 	QueryJD  float64
@@ -834,6 +942,18 @@ type Nakshatra28Info struct {
 	DegreesInNakshatra float64
 }
 
+type KpSublord struct {
+	NakshatraIndex uint8
+	StarLord       uint8
+	SubLord        uint8
+}
+
+type GandantaStatus struct {
+	Kind      int32
+	Half      int32
+	OffsetDeg float64
+}
+
 type TithiPosition struct {
 	TithiIndex     int32
 	Paksha         int32
@@ -898,6 +1018,9 @@ type GrahaEntry struct {
 	Pada              uint8
 	BhavaNumber       uint8
 	RashiBhavaNumber  uint8
+	Gandanta          bool
+	RashiSandhi       bool
+	Pushkara          bool
 }
 
 type GrahaPositions struct {
@@ -936,6 +1059,17 @@ type AshtakavargaResult struct {
 	SAV  SarvaAshtakavarga
 }
 
+// TransitBindusResult holds natal bindus under a transiting graha.
+// BAVBindus is nil for Rahu/Ketu, which have no BAV of their own.
+type TransitBindusResult struct {
+	GrahaIndex          uint8
+	TransitLongitudeDeg float64
+	House               uint8
+	RashiIndex          uint8
+	BAVBindus           *uint8
+	SAVBindus           uint8
+}
+
 type AmshaEntry struct {
 	SiderealLongitude float64
 	RashiIndex        uint8
@@ -1026,6 +1160,8 @@ type ShadbalaEntry struct {
 	TotalRupas        float64
 	RequiredStrength  float64
 	IsStrong          bool
+	Ishta             float64
+	Kashta            float64
 }
 
 type ShadbalaResult struct {
@@ -1152,6 +1288,7 @@ type DashaSelectionConfig struct {
 	LevelMethods [5]uint8
 	YoginiScheme uint8
 	UseAbhijit   bool
+	YearLength   uint8
 	SnapshotTime *DashaSnapshotTime
 }
 
@@ -1165,6 +1302,7 @@ type DashaVariationConfig struct {
 	LevelMethods [5]uint8
 	YoginiScheme uint8
 	UseAbhijit   bool
+	YearLength   uint8
 }
 
 type RashiDashaInputs struct {
@@ -1213,13 +1351,15 @@ type DashaSnapshotRequest struct {
 }
 
 type DashaLevel0Request struct {
-	Birth  DashaBirthContext
-	System uint8
+	Birth     DashaBirthContext
+	System    uint8
+	Variation DashaVariationConfig
 }
 
 type DashaLevel0EntityRequest struct {
 	Birth       DashaBirthContext
 	System      uint8
+	Variation   DashaVariationConfig
 	EntityType  uint8
 	EntityIndex uint8
 }
@@ -1247,6 +1387,19 @@ type DashaCompleteLevelRequest struct {
 	ChildLevel uint8
 }
 
+type DashaTransitionsRequest struct {
+	Birth         DashaBirthContext
+	StartTimeKind int32
+	StartJD       float64
+	StartUTC      UtcTime
+	EndTimeKind   int32
+	EndJD         float64
+	EndUTC        UtcTime
+	System        uint8
+	Level         uint8
+	Variation     DashaVariationConfig
+}
+
 type AmshaSelectionConfig struct {
 	Count      uint8
 	Codes      [40]uint16
@@ -1284,6 +1437,8 @@ type FullKundaliConfig struct {
 
 type FullPanchangInfo struct {
 	Tithi         TithiInfo
+	TithiDayKind  int32
+	TithiDayTithi int32
 	Karana        KaranaInfo
 	Yoga          YogaInfo
 	Vaar          VaarInfo
@@ -1296,6 +1451,19 @@ type FullPanchangInfo struct {
 	Varsha        *VarshaInfo
 }
 
+type PanchangLimbDiff struct {
+	Limb         int32
+	ValueDiffers bool
+	StartShiftS  float64
+	EndShiftS    float64
+}
+
+type PanchangDiff struct {
+	A     FullPanchangInfo
+	B     FullPanchangInfo
+	Limbs []PanchangLimbDiff
+}
+
 type FullKundaliDashaLevel struct {
 	Level   uint8
 	Periods []DashaPeriod
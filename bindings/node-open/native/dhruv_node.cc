@@ -298,13 +298,25 @@ bool ReadBoolArrayFixed(napi_env env, napi_value arr, uint8_t* out, uint32_t cou
 }
 
 bool ReadRiseSetConfig(napi_env env, napi_value obj, DhruvRiseSetConfig* out) {
+    *out = dhruv_riseset_config_default();
     napi_value v;
     bool b = false;
+    bool has = false;
     if (!GetNamedProperty(env, obj, "useRefraction", &v) || !GetBool(env, v, &b)) return false;
     out->use_refraction = b ? 1 : 0;
     if (!GetNamedProperty(env, obj, "sunLimb", &v) || !GetInt32(env, v, &out->sun_limb)) return false;
     if (!GetNamedProperty(env, obj, "altitudeCorrection", &v) || !GetBool(env, v, &b)) return false;
     out->altitude_correction = b ? 1 : 0;
+    if (!GetOptionalNamedProperty(env, obj, "refractionModel", &v, &has)) return false;
+    if (has && !GetInt32(env, v, &out->refraction_model)) return false;
+    if (!GetOptionalNamedProperty(env, obj, "refractionPressureHpa", &v, &has)) return false;
+    if (has && !GetDouble(env, v, &out->refraction_pressure_hpa)) return false;
+    if (!GetOptionalNamedProperty(env, obj, "refractionTemperatureC", &v, &has)) return false;
+    if (has && !GetDouble(env, v, &out->refraction_temperature_c)) return false;
+    if (!GetOptionalNamedProperty(env, obj, "refractionArcmin", &v, &has)) return false;
+    if (has && !GetDouble(env, v, &out->refraction_arcmin)) return false;
+    if (!GetOptionalNamedProperty(env, obj, "dayFallback", &v, &has)) return false;
+    if (has && !GetInt32(env, v, &out->day_fallback)) return false;
     return true;
 }
 
@@ -379,6 +391,19 @@ bool ReadSankrantiConfig(napi_env env, napi_value obj, DhruvSankrantiConfig* out
     if (!GetNamedProperty(env, obj, "stepSizeDays", &v) || !GetDouble(env, v, &out->step_size_days)) return false;
     if (!GetNamedProperty(env, obj, "maxIterations", &v) || !GetUint32(env, v, &out->max_iterations)) return false;
     if (!GetNamedProperty(env, obj, "convergenceDays", &v) || !GetDouble(env, v, &out->convergence_days)) return false;
+    bool has = false;
+    if (napi_has_named_property(env, obj, "useCustomAyanamsha", &has) != napi_ok) return false;
+    if (has) {
+        bool b = false;
+        if (!GetNamedProperty(env, obj, "useCustomAyanamsha", &v) || !GetBool(env, v, &b)) return false;
+        out->use_custom_ayanamsha = b ? 1 : 0;
+    }
+    if (napi_has_named_property(env, obj, "customAyanamshaAnchorDeg", &has) != napi_ok) return false;
+    if (has && (!GetNamedProperty(env, obj, "customAyanamshaAnchorDeg", &v) || !GetDouble(env, v, &out->custom_ayanamsha_anchor_deg))) return false;
+    if (napi_has_named_property(env, obj, "customAyanamshaAnchorJdTdb", &has) != napi_ok) return false;
+    if (has && (!GetNamedProperty(env, obj, "customAyanamshaAnchorJdTdb", &v) || !GetDouble(env, v, &out->custom_ayanamsha_anchor_jd_tdb))) return false;
+    if (napi_has_named_property(env, obj, "samvatsaraScheme", &has) != napi_ok) return false;
+    if (has && (!GetNamedProperty(env, obj, "samvatsaraScheme", &v) || !GetInt32(env, v, &out->samvatsara_scheme))) return false;
     return true;
 }
 
@@ -399,6 +424,16 @@ bool ReadGrahaLongitudesConfig(napi_env env, napi_value obj, DhruvGrahaLongitude
     if (has && (!GetNamedProperty(env, obj, "precessionModel", &v) || !GetInt32(env, v, &out->precession_model))) return false;
     if (napi_has_named_property(env, obj, "referencePlane", &has) != napi_ok) return false;
     if (has && (!GetNamedProperty(env, obj, "referencePlane", &v) || !GetInt32(env, v, &out->reference_plane))) return false;
+    if (napi_has_named_property(env, obj, "useCustomAyanamsha", &has) != napi_ok) return false;
+    if (has) {
+        bool b = false;
+        if (!GetNamedProperty(env, obj, "useCustomAyanamsha", &v) || !GetBool(env, v, &b)) return false;
+        out->use_custom_ayanamsha = b ? 1 : 0;
+    }
+    if (napi_has_named_property(env, obj, "customAyanamshaAnchorDeg", &has) != napi_ok) return false;
+    if (has && (!GetNamedProperty(env, obj, "customAyanamshaAnchorDeg", &v) || !GetDouble(env, v, &out->custom_ayanamsha_anchor_deg))) return false;
+    if (napi_has_named_property(env, obj, "customAyanamshaAnchorJdTdb", &has) != napi_ok) return false;
+    if (has && (!GetNamedProperty(env, obj, "customAyanamshaAnchorJdTdb", &v) || !GetDouble(env, v, &out->custom_ayanamsha_anchor_jd_tdb))) return false;
     return true;
 }
 
@@ -425,6 +460,13 @@ bool ReadGrahaPositionsConfig(napi_env env, napi_value obj, DhruvGrahaPositionsC
     out->include_outer_planets = b ? 1 : 0;
     if (!GetNamedProperty(env, obj, "includeBhava", &v) || !GetBool(env, v, &b)) return false;
     out->include_bhava = b ? 1 : 0;
+    bool has = false;
+    if (!GetOptionalNamedProperty(env, obj, "includeFlags", &v, &has)) return false;
+    out->include_flags = 0;
+    if (has) {
+        if (!GetBool(env, v, &b)) return false;
+        out->include_flags = b ? 1 : 0;
+    }
     return true;
 }
 
@@ -526,6 +568,11 @@ bool ReadDashaSelectionConfig(napi_env env, napi_value obj, DhruvDashaSelectionC
     out->yogini_scheme = static_cast<uint8_t>(count);
     if (!GetNamedProperty(env, obj, "useAbhijit", &v) || !GetBool(env, v, &b)) return false;
     out->use_abhijit = b ? 1 : 0;
+    if (!GetOptionalNamedProperty(env, obj, "yearLength", &v, &has)) return false;
+    if (has) {
+        if (!GetUint32(env, v, &count)) return false;
+        out->year_length = static_cast<uint8_t>(count);
+    }
     if (!GetOptionalNamedProperty(env, obj, "snapshotUtc", &v, &has)) return false;
     if (has) {
         napi_valuetype snapshot_type;
@@ -560,6 +607,12 @@ bool ReadDashaVariationConfig(napi_env env, napi_value obj, DhruvDashaVariationC
         if (!GetBool(env, v, &value)) return false;
         out->use_abhijit = value ? 1 : 0;
     }
+    if (!GetOptionalNamedProperty(env, obj, "yearLength", &v, &has)) return false;
+    if (has) {
+        uint32_t value = 0;
+        if (!GetUint32(env, v, &value)) return false;
+        out->year_length = static_cast<uint8_t>(value);
+    }
     return true;
 }
 
@@ -787,6 +840,46 @@ bool ReadBhinnaAshtakavarga(napi_env env, napi_value obj, DhruvBhinnaAshtakavarg
     return true;
 }
 
+bool ReadAshtakavargaResult(napi_env env, napi_value obj, DhruvAshtakavargaResult* out) {
+    napi_value v;
+    if (!GetNamedProperty(env, obj, "bavs", &v)) return false;
+    uint32_t len = 0;
+    if (napi_get_array_length(env, v, &len) != napi_ok || len != DHRUV_SAPTA_GRAHA_COUNT) return false;
+    for (uint32_t i = 0; i < DHRUV_SAPTA_GRAHA_COUNT; ++i) {
+        napi_value item;
+        if (napi_get_element(env, v, i, &item) != napi_ok || !ReadBhinnaAshtakavarga(env, item, &out->bavs[i])) return false;
+    }
+    napi_value sav;
+    if (!GetNamedProperty(env, obj, "sav", &sav)) return false;
+    if (!GetNamedProperty(env, sav, "totalPoints", &v) || !ReadUint8ArrayFixed(env, v, out->sav.total_points, 12)) return false;
+    if (!GetNamedProperty(env, sav, "afterTrikona", &v) || !ReadUint8ArrayFixed(env, v, out->sav.after_trikona, 12)) return false;
+    if (!GetNamedProperty(env, sav, "afterEkadhipatya", &v) || !ReadUint8ArrayFixed(env, v, out->sav.after_ekadhipatya, 12)) return false;
+    return true;
+}
+
+bool ReadBhavaResult(napi_env env, napi_value obj, DhruvBhavaResult* out) {
+    napi_value v;
+    if (!GetNamedProperty(env, obj, "lagnaDeg", &v) || !GetDouble(env, v, &out->lagna_deg)) return false;
+    if (!GetNamedProperty(env, obj, "mcDeg", &v) || !GetDouble(env, v, &out->mc_deg)) return false;
+    napi_value arr;
+    if (!GetNamedProperty(env, obj, "bhavas", &arr)) return false;
+    uint32_t len = 0;
+    if (napi_get_array_length(env, arr, &len) != napi_ok || len != 12) return false;
+    for (uint32_t i = 0; i < 12; ++i) {
+        napi_value item;
+        uint32_t number = 0;
+        DhruvBhava* b = &out->bhavas[i];
+        if (napi_get_element(env, arr, i, &item) != napi_ok) return false;
+        if (!GetNamedProperty(env, item, "number", &v) || !GetUint32(env, v, &number)) return false;
+        b->number = static_cast<uint8_t>(number);
+        if (!GetNamedProperty(env, item, "cuspDeg", &v) || !GetDouble(env, v, &b->cusp_deg)) return false;
+        if (!GetNamedProperty(env, item, "startDeg", &v) || !GetDouble(env, v, &b->start_deg)) return false;
+        if (!GetNamedProperty(env, item, "endDeg", &v) || !GetDouble(env, v, &b->end_deg)) return false;
+        if (!GetNamedProperty(env, item, "madhyaDeg", &v) || !GetDouble(env, v, &b->madhya_deg)) return false;
+    }
+    return true;
+}
+
 bool ReadBhavaBalaInputs(napi_env env, napi_value obj, DhruvBhavaBalaInputs* out) {
     napi_value v;
     uint32_t u32 = 0;
@@ -1133,6 +1226,8 @@ napi_value WritePanchangOperationResult(napi_env env, const DhruvPanchangOperati
     napi_create_object(env, &obj);
     SetNamed(env, obj, "tithiValid", MakeBool(env, p.tithi_valid != 0));
     SetNamed(env, obj, "tithi", WriteTithiInfo(env, p.tithi));
+    SetNamed(env, obj, "tithiDayKind", MakeInt32(env, p.tithi_day_kind));
+    SetNamed(env, obj, "tithiDayTithiIndex", MakeInt32(env, p.tithi_day_tithi_index));
     SetNamed(env, obj, "karanaValid", MakeBool(env, p.karana_valid != 0));
     SetNamed(env, obj, "karana", WriteKaranaInfo(env, p.karana));
     SetNamed(env, obj, "yogaValid", MakeBool(env, p.yoga_valid != 0));
@@ -1181,6 +1276,7 @@ napi_value WriteDashaVariationConfig(napi_env env, const DhruvDashaVariationConf
     SetNamed(env, obj, "levelMethods", methods);
     SetNamed(env, obj, "yoginiScheme", MakeUint32(env, cfg.yogini_scheme));
     SetNamed(env, obj, "useAbhijit", MakeBool(env, cfg.use_abhijit != 0));
+    SetNamed(env, obj, "yearLength", MakeUint32(env, cfg.year_length));
     return obj;
 }
 
@@ -1213,6 +1309,8 @@ napi_value WriteFullPanchangInfo(napi_env env, const DhruvPanchangInfo& p) {
     napi_value obj;
     napi_create_object(env, &obj);
     SetNamed(env, obj, "tithi", WriteTithiInfo(env, p.tithi));
+    SetNamed(env, obj, "tithiDayKind", MakeInt32(env, p.tithi_day_kind));
+    SetNamed(env, obj, "tithiDayTithiIndex", MakeInt32(env, p.tithi_day_tithi_index));
     SetNamed(env, obj, "karana", WriteKaranaInfo(env, p.karana));
     SetNamed(env, obj, "yoga", WriteYogaInfo(env, p.yoga));
     SetNamed(env, obj, "vaar", WriteVaarInfo(env, p.vaar));
@@ -1375,6 +1473,8 @@ napi_value WriteShadbalaResult(napi_env env, const DhruvShadbalaResult& s) {
         SetNamed(env, eo, "totalRupas", MakeDouble(env, e.total_rupas));
         SetNamed(env, eo, "requiredStrength", MakeDouble(env, e.required_strength));
         SetNamed(env, eo, "isStrong", MakeBool(env, e.is_strong != 0));
+        SetNamed(env, eo, "ishta", MakeDouble(env, e.ishta));
+        SetNamed(env, eo, "kashta", MakeDouble(env, e.kashta));
 
         napi_set_element(env, entries, i, eo);
         napi_set_element(env, totals, i, MakeDouble(env, e.total_rupas));
@@ -1536,6 +1636,9 @@ napi_value WriteGrahaEntry(napi_env env, const DhruvGrahaEntry& g) {
     SetNamed(env, obj, "pada", MakeUint32(env, g.pada));
     SetNamed(env, obj, "bhavaNumber", MakeUint32(env, g.bhava_number));
     SetNamed(env, obj, "rashiBhavaNumber", MakeUint32(env, g.rashi_bhava_number));
+    SetNamed(env, obj, "gandanta", MakeBool(env, g.gandanta != 0));
+    SetNamed(env, obj, "rashiSandhi", MakeBool(env, g.rashi_sandhi != 0));
+    SetNamed(env, obj, "pushkara", MakeBool(env, g.pushkara != 0));
     return obj;
 }
 
@@ -1862,6 +1965,15 @@ napi_value WriteSankrantiEvent(napi_env env, const DhruvSankrantiEvent& ev) {
     return obj;
 }
 
+napi_value WriteIngressEvent(napi_env env, const DhruvIngressEvent& ev) {
+    napi_value obj;
+    napi_create_object(env, &obj);
+    SetNamed(env, obj, "utc", WriteUtcTime(env, ev.utc));
+    SetNamed(env, obj, "rashiIndex", MakeInt32(env, ev.rashi_index));
+    SetNamed(env, obj, "jdTdb", MakeDouble(env, ev.jd_tdb));
+    return obj;
+}
+
 napi_value WriteStationaryEvent(napi_env env, const DhruvStationaryEvent& ev) {
     napi_value obj;
     napi_create_object(env, &obj);
@@ -1968,6 +2080,7 @@ napi_value WriteBhavaResult(napi_env env, const DhruvBhavaResult& b) {
         SetNamed(env, x, "cuspDeg", MakeDouble(env, b.bhavas[i].cusp_deg));
         SetNamed(env, x, "startDeg", MakeDouble(env, b.bhavas[i].start_deg));
         SetNamed(env, x, "endDeg", MakeDouble(env, b.bhavas[i].end_deg));
+        SetNamed(env, x, "madhyaDeg", MakeDouble(env, b.bhavas[i].madhya_deg));
         napi_set_element(env, arr, i, x);
     }
     SetNamed(env, obj, "bhavas", arr);
@@ -1985,6 +2098,7 @@ napi_value WriteBhavaResult(napi_env env, const DhruvBhavaResult& b) {
             SetNamed(env, x, "cuspDeg", MakeDouble(env, b.rashi_bhava_bhavas[i].cusp_deg));
             SetNamed(env, x, "startDeg", MakeDouble(env, b.rashi_bhava_bhavas[i].start_deg));
             SetNamed(env, x, "endDeg", MakeDouble(env, b.rashi_bhava_bhavas[i].end_deg));
+            SetNamed(env, x, "madhyaDeg", MakeDouble(env, b.rashi_bhava_bhavas[i].madhya_deg));
             napi_set_element(env, rashi_arr, i, x);
         }
         SetNamed(env, rashi, "bhavas", rashi_arr);
@@ -2677,6 +2791,10 @@ napi_value AyanamshaComputeEx(napi_env env, napi_callback_info info) {
     if (!GetOptionalNamedProperty(env, args[1], "deltaPsiArcsec", &v, &has_dpsi)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     req.delta_psi_arcsec = 0.0;
     if (has_dpsi && !GetDouble(env, v, &req.delta_psi_arcsec)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    bool has_model = false;
+    if (!GetOptionalNamedProperty(env, args[1], "precessionModel", &v, &has_model)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    req.precession_model = DHRUV_PRECESSION_MODEL_VONDRAK2011;
+    if (has_model && !GetInt32(env, v, &req.precession_model)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
 
     double out_val = 0.0;
     int32_t status = dhruv_ayanamsha_compute_ex(
@@ -2710,6 +2828,21 @@ napi_value LunarNodeDeg(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value NodeSpeedDegPerDay(napi_env env, napi_callback_info info) {
+    size_t argc = 2;
+    napi_value args[2];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 2) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    int32_t mode_code = 0;
+    double jd = 0.0;
+    if (!GetInt32(env, args[0], &mode_code) || !GetDouble(env, args[1], &jd)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double out_val = 0.0;
+    int32_t status = dhruv_node_speed_deg_per_day(mode_code, jd, &out_val);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) SetNamed(env, out, "speedDegPerDay", MakeDouble(env, out_val));
+    return out;
+}
+
 napi_value LunarNodeDegWithEngine(napi_env env, napi_callback_info info) {
     size_t argc = 4;
     napi_value args[4];
@@ -2857,6 +2990,52 @@ napi_value Nakshatra28FromLongitude(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value KpSublord(napi_env env, napi_callback_info info) {
+    size_t argc = 1;
+    napi_value args[1];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 1) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double lon = 0.0;
+    if (!GetDouble(env, args[0], &lon)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvKpSublord kp{};
+    int32_t status = dhruv_kp_sublord(lon, &kp);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        napi_value obj;
+        napi_create_object(env, &obj);
+        SetNamed(env, obj, "nakshatraIndex", MakeUint32(env, kp.nakshatra_index));
+        SetNamed(env, obj, "starLord", MakeUint32(env, kp.star_lord));
+        SetNamed(env, obj, "subLord", MakeUint32(env, kp.sub_lord));
+        SetNamed(env, out, "kp", obj);
+    }
+    return out;
+}
+
+napi_value GandantaStatus(napi_env env, napi_callback_info info) {
+    size_t argc = 1;
+    napi_value args[1];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 1) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double lon = 0.0;
+    if (!GetDouble(env, args[0], &lon)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvGandantaStatus gandanta{};
+    uint8_t found = 0;
+    int32_t status = dhruv_gandanta_status(lon, &gandanta, &found);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        SetNamed(env, out, "found", MakeBool(env, found != 0));
+        if (found != 0) {
+            napi_value obj;
+            napi_create_object(env, &obj);
+            SetNamed(env, obj, "kind", MakeInt32(env, gandanta.kind));
+            SetNamed(env, obj, "half", MakeInt32(env, gandanta.half));
+            SetNamed(env, obj, "offsetDeg", MakeDouble(env, gandanta.offset_deg));
+            SetNamed(env, out, "gandanta", obj);
+        }
+    }
+    return out;
+}
+
 napi_value RashiFromTropical(napi_env env, napi_callback_info info) {
     size_t argc = 4;
     napi_value args[4];
@@ -2971,6 +3150,31 @@ napi_value Nakshatra28FromTropicalUtc(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value GrahaSpeeds(napi_env env, napi_callback_info info) {
+    size_t argc = 3;
+    napi_value args[3];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 2) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    void* ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double jd = 0.0;
+    if (!GetDouble(env, args[1], &jd)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvGrahaLongitudesConfig cfg = dhruv_graha_longitudes_config_default();
+    if (argc >= 3 && !ReadGrahaLongitudesConfig(env, args[2], &cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvGrahaSpeeds out_speeds{};
+    int32_t status = dhruv_graha_speeds(static_cast<const DhruvEngineHandle*>(ptr), jd, &cfg, &out_speeds);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        napi_value arr;
+        napi_create_array_with_length(env, DHRUV_GRAHA_COUNT, &arr);
+        for (uint32_t i = 0; i < DHRUV_GRAHA_COUNT; ++i) {
+            napi_set_element(env, arr, i, MakeDouble(env, out_speeds.speeds[i]));
+        }
+        SetNamed(env, out, "speeds", arr);
+    }
+    return out;
+}
+
 napi_value GrahaLongitudes(napi_env env, napi_callback_info info) {
     size_t argc = 3;
     napi_value args[3];
@@ -3083,6 +3287,7 @@ napi_value VaarName(napi_env env, napi_callback_info info) { return NameLookup(e
 napi_value HoraName(napi_env env, napi_callback_info info) { return NameLookup(env, info, dhruv_hora_name); }
 napi_value GrahaName(napi_env env, napi_callback_info info) { return NameLookup(env, info, dhruv_graha_name); }
 napi_value YoginiName(napi_env env, napi_callback_info info) { return NameLookup(env, info, dhruv_yogini_name); }
+napi_value NavataraName(napi_env env, napi_callback_info info) { return NameLookup(env, info, dhruv_navatara_name); }
 napi_value SphutaName(napi_env env, napi_callback_info info) { return NameLookup(env, info, dhruv_sphuta_name); }
 napi_value SpecialLagnaName(napi_env env, napi_callback_info info) { return NameLookup(env, info, dhruv_special_lagna_name); }
 napi_value ArudhaPadaName(napi_env env, napi_callback_info info) { return NameLookup(env, info, dhruv_arudha_pada_name); }
@@ -3185,17 +3390,31 @@ napi_value MoolatrikoneRange(napi_env env, napi_callback_info info) {
     return out;
 }
 
+// Optional Moon combustion orb: null/undefined leaves the library default.
+static bool ReadCombustionConfig(napi_env env, napi_value value, DhruvCombustionConfig* cfg, const DhruvCombustionConfig** out) {
+    *out = nullptr;
+    napi_valuetype t;
+    napi_typeof(env, value, &t);
+    if (t == napi_null || t == napi_undefined) return true;
+    *cfg = dhruv_combustion_config_default();
+    if (!GetDouble(env, value, &cfg->moon_orb_deg)) return false;
+    *out = cfg;
+    return true;
+}
+
 napi_value CombustionThreshold(napi_env env, napi_callback_info info) {
-    size_t argc = 2;
-    napi_value args[2];
+    size_t argc = 3;
+    napi_value args[3];
     napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
     if (argc < 2) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     uint32_t graha = 0;
     bool retrograde = false;
-    if (!GetUint32(env, args[0], &graha) || !GetBool(env, args[1], &retrograde)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvCombustionConfig cfg{};
+    const DhruvCombustionConfig* cfg_ptr = nullptr;
+    if (!GetUint32(env, args[0], &graha) || !GetBool(env, args[1], &retrograde) || !ReadCombustionConfig(env, args[2], &cfg, &cfg_ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     uint8_t has_value = 0;
     double value = 0.0;
-    int32_t status = dhruv_combustion_threshold(graha, retrograde ? 1 : 0, &has_value, &value);
+    int32_t status = dhruv_combustion_threshold(graha, retrograde ? 1 : 0, cfg_ptr, &has_value, &value);
     napi_value out = MakeStatusResult(env, status);
     if (status == STATUS_OK) {
         SetNamed(env, out, "hasValue", MakeBool(env, has_value != 0));
@@ -3205,36 +3424,40 @@ napi_value CombustionThreshold(napi_env env, napi_callback_info info) {
 }
 
 napi_value IsCombust(napi_env env, napi_callback_info info) {
-    size_t argc = 4;
-    napi_value args[4];
+    size_t argc = 5;
+    napi_value args[5];
     napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
     if (argc < 4) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     uint32_t graha = 0;
     double graha_lon = 0.0;
     double sun_lon = 0.0;
     bool retrograde = false;
-    if (!GetUint32(env, args[0], &graha) || !GetDouble(env, args[1], &graha_lon) || !GetDouble(env, args[2], &sun_lon) || !GetBool(env, args[3], &retrograde)) {
+    DhruvCombustionConfig cfg{};
+    const DhruvCombustionConfig* cfg_ptr = nullptr;
+    if (!GetUint32(env, args[0], &graha) || !GetDouble(env, args[1], &graha_lon) || !GetDouble(env, args[2], &sun_lon) || !GetBool(env, args[3], &retrograde) || !ReadCombustionConfig(env, args[4], &cfg, &cfg_ptr)) {
         return MakeStatusResult(env, STATUS_INVALID_INPUT);
     }
     uint8_t result = 0;
-    int32_t status = dhruv_is_combust(graha, graha_lon, sun_lon, retrograde ? 1 : 0, &result);
+    int32_t status = dhruv_is_combust(graha, graha_lon, sun_lon, retrograde ? 1 : 0, cfg_ptr, &result);
     napi_value out = MakeStatusResult(env, status);
     if (status == STATUS_OK) SetNamed(env, out, "value", MakeBool(env, result != 0));
     return out;
 }
 
 napi_value AllCombustionStatus(napi_env env, napi_callback_info info) {
-    size_t argc = 2;
-    napi_value args[2];
+    size_t argc = 3;
+    napi_value args[3];
     napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
     if (argc < 2) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     double longitudes[DHRUV_GRAHA_COUNT]{};
     uint8_t retrograde[DHRUV_GRAHA_COUNT]{};
-    if (!ReadDoubleArrayFixed(env, args[0], longitudes, DHRUV_GRAHA_COUNT) || !ReadBoolArrayFixed(env, args[1], retrograde, DHRUV_GRAHA_COUNT)) {
+    DhruvCombustionConfig cfg{};
+    const DhruvCombustionConfig* cfg_ptr = nullptr;
+    if (!ReadDoubleArrayFixed(env, args[0], longitudes, DHRUV_GRAHA_COUNT) || !ReadBoolArrayFixed(env, args[1], retrograde, DHRUV_GRAHA_COUNT) || !ReadCombustionConfig(env, args[2], &cfg, &cfg_ptr)) {
         return MakeStatusResult(env, STATUS_INVALID_INPUT);
     }
     uint8_t result[DHRUV_GRAHA_COUNT]{};
-    int32_t status = dhruv_all_combustion_status(longitudes, retrograde, result);
+    int32_t status = dhruv_all_combustion_status(longitudes, retrograde, cfg_ptr, result);
     napi_value out = MakeStatusResult(env, status);
     if (status == STATUS_OK) {
         napi_value arr;
@@ -3292,6 +3515,32 @@ napi_value PanchadhaMaitri(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value RelationshipMatrix(napi_env env, napi_callback_info info) {
+    size_t argc = 1;
+    napi_value args[1];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 1) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double longitudes[DHRUV_GRAHA_COUNT]{};
+    if (!ReadDoubleArrayFixed(env, args[0], longitudes, DHRUV_GRAHA_COUNT)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    int32_t codes[DHRUV_GRAHA_COUNT * DHRUV_GRAHA_COUNT]{};
+    int32_t status = dhruv_relationship_matrix(longitudes, codes);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        napi_value matrix;
+        napi_create_array_with_length(env, DHRUV_GRAHA_COUNT, &matrix);
+        for (uint32_t i = 0; i < DHRUV_GRAHA_COUNT; ++i) {
+            napi_value row;
+            napi_create_array_with_length(env, DHRUV_GRAHA_COUNT, &row);
+            for (uint32_t j = 0; j < DHRUV_GRAHA_COUNT; ++j) {
+                napi_set_element(env, row, j, MakeInt32(env, codes[i * DHRUV_GRAHA_COUNT + j]));
+            }
+            napi_set_element(env, matrix, i, row);
+        }
+        SetNamed(env, out, "result", matrix);
+    }
+    return out;
+}
+
 napi_value DignityInRashi(napi_env env, napi_callback_info info) {
     size_t argc = 3;
     napi_value args[3];
@@ -3500,14 +3749,16 @@ napi_value AyanaFromSiderealLongitude(napi_env env, napi_callback_info info) {
 }
 
 napi_value SamvatsaraFromYear(napi_env env, napi_callback_info info) {
-    size_t argc = 1;
-    napi_value args[1];
+    size_t argc = 2;
+    napi_value args[2];
     napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
     if (argc < 1) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     int32_t year = 0;
     if (!GetInt32(env, args[0], &year)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    int32_t scheme = 0;
+    if (argc >= 2 && !GetInt32(env, args[1], &scheme)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     DhruvSamvatsaraResult out_s{};
-    int32_t status = dhruv_samvatsara_from_year(year, &out_s);
+    int32_t status = dhruv_samvatsara_from_year(year, scheme, &out_s);
     napi_value out = MakeStatusResult(env, status);
     if (status == STATUS_OK) {
         napi_value s;
@@ -3540,6 +3791,27 @@ napi_value RashiLord(napi_env env, napi_callback_info info) {
     return MakeInt32(env, dhruv_rashi_lord(rashi));
 }
 
+napi_value NakshatraLord(napi_env env, napi_callback_info info) {
+    size_t argc = 1;
+    napi_value args[1];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 1) return MakeInt32(env, -1);
+    uint32_t nakshatra = 0;
+    if (!GetUint32(env, args[0], &nakshatra)) return MakeInt32(env, -1);
+    return MakeInt32(env, dhruv_nakshatra_lord(nakshatra));
+}
+
+napi_value NavataraFrom(napi_env env, napi_callback_info info) {
+    size_t argc = 2;
+    napi_value args[2];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 2) return MakeInt32(env, -1);
+    uint32_t start = 0;
+    uint32_t target = 0;
+    if (!GetUint32(env, args[0], &start) || !GetUint32(env, args[1], &target)) return MakeInt32(env, -1);
+    return MakeInt32(env, dhruv_navatara_from(start, target));
+}
+
 napi_value HoraAt(napi_env env, napi_callback_info info) {
     size_t argc = 2;
     napi_value args[2];
@@ -3559,6 +3831,11 @@ napi_value RiseSetConfigDefault(napi_env env, napi_callback_info info) {
     SetNamed(env, out, "useRefraction", MakeBool(env, cfg.use_refraction != 0));
     SetNamed(env, out, "sunLimb", MakeInt32(env, cfg.sun_limb));
     SetNamed(env, out, "altitudeCorrection", MakeBool(env, cfg.altitude_correction != 0));
+    SetNamed(env, out, "refractionModel", MakeInt32(env, cfg.refraction_model));
+    SetNamed(env, out, "refractionPressureHpa", MakeDouble(env, cfg.refraction_pressure_hpa));
+    SetNamed(env, out, "refractionTemperatureC", MakeDouble(env, cfg.refraction_temperature_c));
+    SetNamed(env, out, "refractionArcmin", MakeDouble(env, cfg.refraction_arcmin));
+    SetNamed(env, out, "dayFallback", MakeInt32(env, cfg.day_fallback));
     return out;
 }
 
@@ -3596,6 +3873,10 @@ napi_value SankrantiConfigDefault(napi_env env, napi_callback_info info) {
     SetNamed(env, out, "stepSizeDays", MakeDouble(env, cfg.step_size_days));
     SetNamed(env, out, "maxIterations", MakeUint32(env, cfg.max_iterations));
     SetNamed(env, out, "convergenceDays", MakeDouble(env, cfg.convergence_days));
+    SetNamed(env, out, "useCustomAyanamsha", MakeBool(env, cfg.use_custom_ayanamsha != 0));
+    SetNamed(env, out, "customAyanamshaAnchorDeg", MakeDouble(env, cfg.custom_ayanamsha_anchor_deg));
+    SetNamed(env, out, "customAyanamshaAnchorJdTdb", MakeDouble(env, cfg.custom_ayanamsha_anchor_jd_tdb));
+    SetNamed(env, out, "samvatsaraScheme", MakeInt32(env, cfg.samvatsara_scheme));
     return out;
 }
 
@@ -3764,6 +4045,20 @@ napi_value ComputeBhavas(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value WholeSignFromLagna(napi_env env, napi_callback_info info) {
+    size_t argc = 1;
+    napi_value args[1];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 1) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double lagna = 0.0;
+    if (!GetDouble(env, args[0], &lagna)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvBhavaResult out_b{};
+    int32_t status = dhruv_whole_sign_from_lagna(lagna, &out_b);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) SetNamed(env, out, "bhava", WriteBhavaResult(env, out_b));
+    return out;
+}
+
 napi_value ComputeBhavasUtc(napi_env env, napi_callback_info info) {
     size_t argc = 6;
     napi_value args[6];
@@ -3987,6 +4282,35 @@ napi_value StationaryConfigDefault(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value WriteHeliacalConfig(napi_env env, const DhruvHeliacalConfig& cfg) {
+    napi_value out;
+    napi_create_object(env, &out);
+    SetNamed(env, out, "arcusVisionisDeg", MakeDouble(env, cfg.arcus_visionis_deg));
+    SetNamed(env, out, "minAltitudeDeg", MakeDouble(env, cfg.min_altitude_deg));
+    SetNamed(env, out, "minElongationDeg", MakeDouble(env, cfg.min_elongation_deg));
+    return out;
+}
+
+napi_value HeliacalConfigDefault(napi_env env, napi_callback_info info) {
+    (void)info;
+    return WriteHeliacalConfig(env, dhruv_heliacal_config_default());
+}
+
+napi_value HeliacalConfigForBody(napi_env env, napi_callback_info info) {
+    size_t argc = 1;
+    napi_value args[1];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 1) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    int32_t body_code = 0;
+    if (!GetInt32(env, args[0], &body_code)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvHeliacalConfig cfg{};
+    int32_t status = dhruv_heliacal_config_for_body(body_code, &cfg);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) SetNamed(env, out, "config", WriteHeliacalConfig(env, cfg));
+    return out;
+}
+
 napi_value ConjunctionSearch(napi_env env, napi_callback_info info) {
     size_t argc = 3;
     napi_value args[3];
@@ -4244,7 +4568,36 @@ napi_value MotionSearch(napi_env env, napi_callback_info info) {
     return out;
 }
 
-napi_value SankrantiSearch(napi_env env, napi_callback_info info) {
+napi_value NextNodeStation(napi_env env, napi_callback_info info) {
+    size_t argc = 2;
+    napi_value args[2];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 2) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    void* ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double jd = 0.0;
+    if (!GetDouble(env, args[1], &jd)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvNodeStationEvent ev{};
+    uint8_t found = 0;
+    int32_t status = dhruv_next_node_station(static_cast<const DhruvEngineHandle*>(ptr), jd, &ev, &found);
+    napi_value out = MakeStatusResult(env, status);
+    if (status != STATUS_OK) return out;
+    SetNamed(env, out, "found", MakeBool(env, found != 0));
+    if (found != 0) {
+        napi_value obj;
+        napi_create_object(env, &obj);
+        SetNamed(env, obj, "utc", WriteUtcTime(env, ev.utc));
+        SetNamed(env, obj, "jdTdb", MakeDouble(env, ev.jd_tdb));
+        SetNamed(env, obj, "rahuLongitudeDeg", MakeDouble(env, ev.rahu_longitude_deg));
+        SetNamed(env, obj, "stationType", MakeInt32(env, ev.station_type));
+        SetNamed(env, out, "event", obj);
+    }
+    return out;
+}
+
+napi_value RetrogradeIntervals(napi_env env, napi_callback_info info) {
     size_t argc = 3;
     napi_value args[3];
     napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
@@ -4253,90 +4606,349 @@ napi_value SankrantiSearch(napi_env env, napi_callback_info info) {
     void* ptr = nullptr;
     if (!ReadExternalPtr(env, args[0], &ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
 
-    DhruvSankrantiSearchRequest req{};
-    DhruvSankrantiConfig cfg = dhruv_sankranti_config_default();
     napi_value v;
-    if (!GetNamedProperty(env, args[1], "targetKind", &v) || !GetInt32(env, v, &req.target_kind)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
-    if (!GetNamedProperty(env, args[1], "queryMode", &v) || !GetInt32(env, v, &req.query_mode)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
-    if (!GetNamedProperty(env, args[1], "rashiIndex", &v) || !GetInt32(env, v, &req.rashi_index)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
-    if (!ReadSearchTimeRequest(
-            env,
-            args[1],
-            req.query_mode,
-            &req.time_kind,
-            &req.at_jd_tdb,
-            &req.start_jd_tdb,
-            &req.end_jd_tdb,
-            &req.at_utc,
-            &req.start_utc,
-            &req.end_utc)) {
-        return MakeStatusResult(env, STATUS_INVALID_INPUT);
-    }
+    int32_t body_code = 0;
+    double start_jd = 0.0;
+    double end_jd = 0.0;
+    if (!GetNamedProperty(env, args[1], "bodyCode", &v) || !GetInt32(env, v, &body_code)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetNamedProperty(env, args[1], "startJdTdb", &v) || !GetDouble(env, v, &start_jd)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetNamedProperty(env, args[1], "endJdTdb", &v) || !GetDouble(env, v, &end_jd)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
 
+    DhruvStationaryConfig cfg = dhruv_stationary_config_default();
     bool has_cfg = false;
     napi_value cfg_obj;
     if (!GetOptionalNamedProperty(env, args[1], "config", &cfg_obj, &has_cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     if (has_cfg) {
         bool present = false;
-        if (!GetOptionalNamedProperty(env, cfg_obj, "ayanamshaSystem", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
-        if (present && !GetInt32(env, v, &cfg.ayanamsha_system)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
-        if (!GetOptionalNamedProperty(env, cfg_obj, "useNutation", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
-        if (present) {
-            bool b = false;
-            if (!GetBool(env, v, &b)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
-            cfg.use_nutation = b ? 1 : 0;
-        }
-        if (!GetOptionalNamedProperty(env, cfg_obj, "referencePlane", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
-        if (present && !GetInt32(env, v, &cfg.reference_plane)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
         if (!GetOptionalNamedProperty(env, cfg_obj, "stepSizeDays", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
         if (present && !GetDouble(env, v, &cfg.step_size_days)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
         if (!GetOptionalNamedProperty(env, cfg_obj, "maxIterations", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
         if (present && !GetUint32(env, v, &cfg.max_iterations)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
         if (!GetOptionalNamedProperty(env, cfg_obj, "convergenceDays", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
         if (present && !GetDouble(env, v, &cfg.convergence_days)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (!GetOptionalNamedProperty(env, cfg_obj, "numericalStepDays", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetDouble(env, v, &cfg.numerical_step_days)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
     }
-    req.config = cfg;
 
     uint32_t capacity = 0;
-    if (!GetUint32(env, args[2], &capacity)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetUint32(env, args[2], &capacity) || capacity == 0) return MakeStatusResult(env, STATUS_INVALID_INPUT);
 
-    DhruvSankrantiEvent out_event{};
-    uint8_t found = 0;
     uint32_t out_count = 0;
-    std::vector<DhruvSankrantiEvent> events(capacity > 0 ? capacity : 1);
-    int32_t status = dhruv_sankranti_search_ex(
+    std::vector<DhruvRetrogradeInterval> intervals(capacity);
+    int32_t status = dhruv_retrograde_intervals(
         static_cast<const DhruvEngineHandle*>(ptr),
-        &req,
-        &out_event,
-        &found,
-        capacity > 0 ? events.data() : nullptr,
+        body_code,
+        start_jd,
+        end_jd,
+        &cfg,
+        intervals.data(),
         capacity,
         &out_count);
 
     napi_value out = MakeStatusResult(env, status);
     if (status == STATUS_OK) {
-        SetNamed(env, out, "found", MakeBool(env, found != 0));
         SetNamed(env, out, "count", MakeUint32(env, out_count));
-        if (found != 0) SetNamed(env, out, "event", WriteSankrantiEvent(env, out_event));
         napi_value arr;
         napi_create_array_with_length(env, out_count, &arr);
         for (uint32_t i = 0; i < out_count; ++i) {
-            napi_set_element(env, arr, i, WriteSankrantiEvent(env, events[i]));
+            const DhruvRetrogradeInterval& iv = intervals[i];
+            napi_value obj;
+            napi_create_object(env, &obj);
+            SetNamed(env, obj, "startJdTdb", MakeDouble(env, iv.start_jd_tdb));
+            SetNamed(env, obj, "endJdTdb", MakeDouble(env, iv.end_jd_tdb));
+            SetNamed(env, obj, "startUtc", WriteUtcTime(env, iv.start_utc));
+            SetNamed(env, obj, "endUtc", WriteUtcTime(env, iv.end_utc));
+            SetNamed(env, obj, "startLonDeg", MakeDouble(env, iv.start_lon_deg));
+            SetNamed(env, obj, "endLonDeg", MakeDouble(env, iv.end_lon_deg));
+            SetNamed(env, obj, "openStart", MakeBool(env, iv.open_start != 0));
+            SetNamed(env, obj, "openEnd", MakeBool(env, iv.open_end != 0));
+            napi_set_element(env, arr, i, obj);
         }
-        SetNamed(env, out, "events", arr);
+        SetNamed(env, out, "intervals", arr);
     }
     return out;
 }
 
-napi_value LunarPhaseSearch(napi_env env, napi_callback_info info) {
-    size_t argc = 3;
-    napi_value args[3];
-    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
-    if (argc < 3) {
-        return MakeStatusResult(env, STATUS_INVALID_INPUT);
-    }
+napi_value WriteHeliacalEvent(napi_env env, const DhruvHeliacalEvent& ev) {
+    napi_value obj;
+    napi_create_object(env, &obj);
+    SetNamed(env, obj, "utc", WriteUtcTime(env, ev.utc));
+    SetNamed(env, obj, "jdTdb", MakeDouble(env, ev.jd_tdb));
+    SetNamed(env, obj, "eventKind", MakeInt32(env, ev.event_kind));
+    SetNamed(env, obj, "altitudeDeg", MakeDouble(env, ev.altitude_deg));
+    SetNamed(env, obj, "elongationDeg", MakeDouble(env, ev.elongation_deg));
+    return obj;
+}
 
-    void* ptr = nullptr;
+napi_value HeliacalSearch(napi_env env, napi_callback_info info) {
+    size_t argc = 6;
+    napi_value args[6];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 6) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    void* e_ptr = nullptr;
+    void* ep_ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &e_ptr) || !ReadExternalPtr(env, args[1], &ep_ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    void* cat_ptr = nullptr;
+    napi_valuetype cat_type;
+    if (napi_typeof(env, args[2], &cat_type) != napi_ok) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (cat_type != napi_null && cat_type != napi_undefined && !ReadExternalPtr(env, args[2], &cat_ptr)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    DhruvGeoLocation loc{};
+    if (!ReadGeoLocation(env, args[3], &loc)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvHeliacalSearchRequest req{};
+    napi_value v;
+    bool present = false;
+    if (!GetNamedProperty(env, args[4], "targetKind", &v) || !GetInt32(env, v, &req.target_kind)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetOptionalNamedProperty(env, args[4], "bodyCode", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (present && !GetInt32(env, v, &req.body_code)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetOptionalNamedProperty(env, args[4], "taraId", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (present && !GetInt32(env, v, &req.tara_id)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetOptionalNamedProperty(env, args[4], "eventKind", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (present && !GetInt32(env, v, &req.event_kind)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetNamedProperty(env, args[4], "queryMode", &v) || !GetInt32(env, v, &req.query_mode)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!ReadSearchTimeRequest(
+            env,
+            args[4],
+            req.query_mode,
+            &req.time_kind,
+            &req.at_jd_tdb,
+            &req.start_jd_tdb,
+            &req.end_jd_tdb,
+            &req.at_utc,
+            &req.start_utc,
+            &req.end_utc)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    // Unset config fields fall back to the body's own arcus visionis.
+    DhruvHeliacalConfig cfg = dhruv_heliacal_config_default();
+    if (req.target_kind == DHRUV_HELIACAL_TARGET_BODY) {
+        int32_t cfg_status = dhruv_heliacal_config_for_body(req.body_code, &cfg);
+        if (cfg_status != STATUS_OK) return MakeStatusResult(env, cfg_status);
+    }
+    bool has_cfg = false;
+    napi_value cfg_obj;
+    if (!GetOptionalNamedProperty(env, args[4], "config", &cfg_obj, &has_cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (has_cfg) {
+        if (!GetOptionalNamedProperty(env, cfg_obj, "arcusVisionisDeg", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetDouble(env, v, &cfg.arcus_visionis_deg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (!GetOptionalNamedProperty(env, cfg_obj, "minAltitudeDeg", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetDouble(env, v, &cfg.min_altitude_deg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (!GetOptionalNamedProperty(env, cfg_obj, "minElongationDeg", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetDouble(env, v, &cfg.min_elongation_deg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    req.config = cfg;
+
+    uint32_t capacity = 0;
+    if (!GetUint32(env, args[5], &capacity)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvHeliacalEvent out_event{};
+    uint8_t found = 0;
+    uint32_t out_count = 0;
+    std::vector<DhruvHeliacalEvent> events(capacity > 0 ? capacity : 1);
+    int32_t status = dhruv_heliacal_search_ex(
+        static_cast<const DhruvEngineHandle*>(e_ptr),
+        static_cast<const DhruvEopHandle*>(ep_ptr),
+        static_cast<const DhruvTaraCatalogHandle*>(cat_ptr),
+        &loc,
+        &req,
+        &out_event,
+        &found,
+        capacity > 0 ? events.data() : nullptr,
+        capacity,
+        &out_count);
+
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        SetNamed(env, out, "found", MakeBool(env, found != 0));
+        SetNamed(env, out, "count", MakeUint32(env, out_count));
+        if (found != 0) SetNamed(env, out, "event", WriteHeliacalEvent(env, out_event));
+        napi_value arr;
+        napi_create_array_with_length(env, out_count, &arr);
+        for (uint32_t i = 0; i < out_count; ++i) {
+            napi_set_element(env, arr, i, WriteHeliacalEvent(env, events[i]));
+        }
+        SetNamed(env, out, "events", arr);
+    }
+    return out;
+}
+
+napi_value SankrantiSearch(napi_env env, napi_callback_info info) {
+    size_t argc = 3;
+    napi_value args[3];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 3) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    void* ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvSankrantiSearchRequest req{};
+    DhruvSankrantiConfig cfg = dhruv_sankranti_config_default();
+    napi_value v;
+    if (!GetNamedProperty(env, args[1], "targetKind", &v) || !GetInt32(env, v, &req.target_kind)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetNamedProperty(env, args[1], "queryMode", &v) || !GetInt32(env, v, &req.query_mode)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetNamedProperty(env, args[1], "rashiIndex", &v) || !GetInt32(env, v, &req.rashi_index)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!ReadSearchTimeRequest(
+            env,
+            args[1],
+            req.query_mode,
+            &req.time_kind,
+            &req.at_jd_tdb,
+            &req.start_jd_tdb,
+            &req.end_jd_tdb,
+            &req.at_utc,
+            &req.start_utc,
+            &req.end_utc)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    bool has_cfg = false;
+    napi_value cfg_obj;
+    if (!GetOptionalNamedProperty(env, args[1], "config", &cfg_obj, &has_cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (has_cfg) {
+        bool present = false;
+        if (!GetOptionalNamedProperty(env, cfg_obj, "ayanamshaSystem", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetInt32(env, v, &cfg.ayanamsha_system)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (!GetOptionalNamedProperty(env, cfg_obj, "useNutation", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present) {
+            bool b = false;
+            if (!GetBool(env, v, &b)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+            cfg.use_nutation = b ? 1 : 0;
+        }
+        if (!GetOptionalNamedProperty(env, cfg_obj, "referencePlane", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetInt32(env, v, &cfg.reference_plane)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (!GetOptionalNamedProperty(env, cfg_obj, "stepSizeDays", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetDouble(env, v, &cfg.step_size_days)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (!GetOptionalNamedProperty(env, cfg_obj, "maxIterations", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetUint32(env, v, &cfg.max_iterations)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (!GetOptionalNamedProperty(env, cfg_obj, "convergenceDays", &v, &present)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+        if (present && !GetDouble(env, v, &cfg.convergence_days)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    req.config = cfg;
+
+    uint32_t capacity = 0;
+    if (!GetUint32(env, args[2], &capacity)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvSankrantiEvent out_event{};
+    uint8_t found = 0;
+    uint32_t out_count = 0;
+    std::vector<DhruvSankrantiEvent> events(capacity > 0 ? capacity : 1);
+    int32_t status = dhruv_sankranti_search_ex(
+        static_cast<const DhruvEngineHandle*>(ptr),
+        &req,
+        &out_event,
+        &found,
+        capacity > 0 ? events.data() : nullptr,
+        capacity,
+        &out_count);
+
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        SetNamed(env, out, "found", MakeBool(env, found != 0));
+        SetNamed(env, out, "count", MakeUint32(env, out_count));
+        if (found != 0) SetNamed(env, out, "event", WriteSankrantiEvent(env, out_event));
+        napi_value arr;
+        napi_create_array_with_length(env, out_count, &arr);
+        for (uint32_t i = 0; i < out_count; ++i) {
+            napi_set_element(env, arr, i, WriteSankrantiEvent(env, events[i]));
+        }
+        SetNamed(env, out, "events", arr);
+    }
+    return out;
+}
+
+napi_value MoonRashiIngresses(napi_env env, napi_callback_info info) {
+    size_t argc = 3;
+    napi_value args[3];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 3) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    void* ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    napi_value v;
+    DhruvUtcTime start{};
+    DhruvUtcTime end{};
+    if (!GetNamedProperty(env, args[1], "start", &v) || !ReadUtcTime(env, v, &start)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (!GetNamedProperty(env, args[1], "end", &v) || !ReadUtcTime(env, v, &end)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvSankrantiConfig cfg = dhruv_sankranti_config_default();
+    bool has_cfg = false;
+    if (!GetOptionalNamedProperty(env, args[1], "config", &v, &has_cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (has_cfg && !ReadSankrantiConfig(env, v, &cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    uint32_t capacity = 0;
+    if (!GetUint32(env, args[2], &capacity) || capacity == 0) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    uint32_t out_count = 0;
+    std::vector<DhruvIngressEvent> events(capacity);
+    int32_t status = dhruv_moon_rashi_ingresses(
+        static_cast<const DhruvEngineHandle*>(ptr),
+        &start,
+        &end,
+        &cfg,
+        events.data(),
+        capacity,
+        &out_count);
+
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        SetNamed(env, out, "count", MakeUint32(env, out_count));
+        napi_value arr;
+        napi_create_array_with_length(env, out_count, &arr);
+        for (uint32_t i = 0; i < out_count; ++i) {
+            napi_set_element(env, arr, i, WriteIngressEvent(env, events[i]));
+        }
+        SetNamed(env, out, "events", arr);
+    }
+    return out;
+}
+
+napi_value NextNakshatraIngress(napi_env env, napi_callback_info info) {
+    size_t argc = 4;
+    napi_value args[4];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 3) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    void* ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    int32_t body_code = 0;
+    double jd = 0.0;
+    if (!GetInt32(env, args[1], &body_code) || !GetDouble(env, args[2], &jd)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvSankrantiConfig cfg = dhruv_sankranti_config_default();
+    if (argc >= 4 && !ReadSankrantiConfig(env, args[3], &cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvNakshatraIngressEvent ev{};
+    uint8_t found = 0;
+    int32_t status = dhruv_next_nakshatra_ingress(static_cast<const DhruvEngineHandle*>(ptr), body_code, jd, &cfg, &ev, &found);
+    napi_value out = MakeStatusResult(env, status);
+    if (status != STATUS_OK) return out;
+    SetNamed(env, out, "found", MakeBool(env, found != 0));
+    if (found != 0) {
+        napi_value obj;
+        napi_create_object(env, &obj);
+        SetNamed(env, obj, "utc", WriteUtcTime(env, ev.utc));
+        SetNamed(env, obj, "jdTdb", MakeDouble(env, ev.jd_tdb));
+        SetNamed(env, obj, "bodyCode", MakeInt32(env, ev.body_code));
+        SetNamed(env, obj, "nakshatraIndex", MakeInt32(env, ev.nakshatra_index));
+        SetNamed(env, obj, "boundaryDeg", MakeDouble(env, ev.boundary_deg));
+        SetNamed(env, obj, "retrograde", MakeBool(env, ev.retrograde != 0));
+        SetNamed(env, out, "event", obj);
+    }
+    return out;
+}
+
+napi_value LunarPhaseSearch(napi_env env, napi_callback_info info) {
+    size_t argc = 3;
+    napi_value args[3];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 3) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    void* ptr = nullptr;
     if (!ReadExternalPtr(env, args[0], &ptr)) {
         return MakeStatusResult(env, STATUS_INVALID_INPUT);
     }
@@ -4490,6 +5102,52 @@ napi_value NakshatraForDate(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value TithiDayKindForDate(napi_env env, napi_callback_info info) {
+    size_t argc = 5;
+    napi_value args[5];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 4) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    void* e_ptr = nullptr;
+    void* ep_ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &e_ptr) || !ReadExternalPtr(env, args[1], &ep_ptr)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    DhruvUtcTime utc{};
+    if (!ReadUtcTime(env, args[2], &utc)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    DhruvGeoLocation loc{};
+    if (!ReadGeoLocation(env, args[3], &loc)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    DhruvRiseSetConfig rise_cfg = dhruv_riseset_config_default();
+    if (argc >= 5 && !ReadRiseSetConfig(env, args[4], &rise_cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvTithiDayInfo info_out{};
+    int32_t status = dhruv_tithi_day_kind_for_date(
+        static_cast<const DhruvEngineHandle*>(e_ptr),
+        static_cast<const DhruvEopHandle*>(ep_ptr),
+        &utc,
+        &loc,
+        &rise_cfg,
+        &info_out);
+
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        napi_value day;
+        napi_create_object(env, &day);
+        SetNamed(env, day, "kind", MakeInt32(env, info_out.kind));
+        SetNamed(env, day, "tithiIndex", MakeInt32(env, info_out.tithi_index));
+        SetNamed(env, out, "tithiDay", day);
+    }
+    return out;
+}
+
 napi_value VaarForDate(napi_env env, napi_callback_info info) {
     size_t argc = 5;
     napi_value args[5];
@@ -4663,6 +5321,61 @@ napi_value VarshaForDate(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value PanchangDiff(napi_env env, napi_callback_info info) {
+    size_t argc = 7;
+    napi_value args[7];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 4) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    void* e_ptr = nullptr;
+    void* ep_ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &e_ptr) || !ReadExternalPtr(env, args[1], &ep_ptr)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvUtcTime utc{};
+    DhruvGeoLocation loc{};
+    if (!ReadUtcTime(env, args[2], &utc) || !ReadGeoLocation(env, args[3], &loc)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvSankrantiConfig cfg_a = dhruv_sankranti_config_default();
+    DhruvSankrantiConfig cfg_b = dhruv_sankranti_config_default();
+    DhruvRiseSetConfig rise_cfg = dhruv_riseset_config_default();
+    if (argc >= 5 && !ReadSankrantiConfig(env, args[4], &cfg_a)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (argc >= 6 && !ReadSankrantiConfig(env, args[5], &cfg_b)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    if (argc >= 7 && !ReadRiseSetConfig(env, args[6], &rise_cfg)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+
+    DhruvPanchangDiff diff{};
+    int32_t status = dhruv_panchang_diff(
+        static_cast<const DhruvEngineHandle*>(e_ptr),
+        static_cast<const DhruvEopHandle*>(ep_ptr),
+        &utc,
+        &loc,
+        &rise_cfg,
+        &cfg_a,
+        &cfg_b,
+        &diff);
+
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        napi_value obj;
+        napi_create_object(env, &obj);
+        SetNamed(env, obj, "a", WriteFullPanchangInfo(env, diff.a));
+        SetNamed(env, obj, "b", WriteFullPanchangInfo(env, diff.b));
+        napi_value limbs;
+        napi_create_array_with_length(env, diff.limb_count, &limbs);
+        for (uint32_t i = 0; i < diff.limb_count; ++i) {
+            napi_value limb;
+            napi_create_object(env, &limb);
+            SetNamed(env, limb, "limb", MakeInt32(env, diff.limbs[i].limb));
+            SetNamed(env, limb, "valueDiffers", MakeBool(env, diff.limbs[i].value_differs != 0));
+            SetNamed(env, limb, "startShiftS", MakeDouble(env, diff.limbs[i].start_shift_s));
+            SetNamed(env, limb, "endShiftS", MakeDouble(env, diff.limbs[i].end_shift_s));
+            napi_set_element(env, limbs, i, limb);
+        }
+        SetNamed(env, obj, "limbs", limbs);
+        SetNamed(env, out, "diff", obj);
+    }
+    return out;
+}
+
 napi_value PanchangComputeEx(napi_env env, napi_callback_info info) {
     size_t argc = 4;
     napi_value args[4];
@@ -5079,6 +5792,41 @@ napi_value GhatikasSinceSunrise(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value GhatikaToDuration(napi_env env, napi_callback_info info) {
+    size_t argc = 2;
+    napi_value args[2];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 2) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double ghati = 0.0;
+    double vighati = 0.0;
+    if (!GetDouble(env, args[0], &ghati) || !GetDouble(env, args[1], &vighati)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    double out_value = 0.0;
+    int32_t status = dhruv_ghatika_to_duration(ghati, vighati, &out_value);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) SetNamed(env, out, "value", MakeDouble(env, out_value));
+    return out;
+}
+
+napi_value DurationToGhatika(napi_env env, napi_callback_info info) {
+    size_t argc = 1;
+    napi_value args[1];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 1) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    double hours = 0.0;
+    if (!GetDouble(env, args[0], &hours)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    uint32_t ghati = 0;
+    double vighati = 0.0;
+    int32_t status = dhruv_duration_to_ghatika(hours, &ghati, &vighati);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        SetNamed(env, out, "ghati", MakeUint32(env, ghati));
+        SetNamed(env, out, "vighati", MakeDouble(env, vighati));
+    }
+    return out;
+}
+
 napi_value AllSphutas(napi_env env, napi_callback_info info) {
     size_t argc = 1;
     napi_value args[1];
@@ -5459,6 +6207,44 @@ napi_value EkadhipatyaSodhana(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value TransitBindus(napi_env env, napi_callback_info info) {
+    size_t argc = 5;
+    napi_value args[5];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 5) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    DhruvAshtakavargaResult natal{};
+    DhruvBhavaResult bhavas{};
+    uint32_t graha_index = 0;
+    double transit_lon = 0.0;
+    int32_t house_mode = 0;
+    if (!ReadAshtakavargaResult(env, args[0], &natal) || !ReadBhavaResult(env, args[1], &bhavas) ||
+        !GetUint32(env, args[2], &graha_index) || !GetDouble(env, args[3], &transit_lon) ||
+        !GetInt32(env, args[4], &house_mode)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    DhruvTransitBindus result{};
+    int32_t status = dhruv_transit_bindus(&natal, &bhavas, graha_index, transit_lon, house_mode, &result);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        napi_value obj;
+        napi_create_object(env, &obj);
+        SetNamed(env, obj, "grahaIndex", MakeUint32(env, result.graha_index));
+        SetNamed(env, obj, "transitLongitudeDeg", MakeDouble(env, result.transit_longitude_deg));
+        SetNamed(env, obj, "house", MakeUint32(env, result.house));
+        SetNamed(env, obj, "rashiIndex", MakeUint32(env, result.rashi_index));
+        if (result.bav_valid != 0) {
+            SetNamed(env, obj, "bavBindus", MakeUint32(env, result.bav_bindus));
+        } else {
+            napi_value nullv;
+            napi_get_null(env, &nullv);
+            SetNamed(env, obj, "bavBindus", nullv);
+        }
+        SetNamed(env, obj, "savBindus", MakeUint32(env, result.sav_bindus));
+        SetNamed(env, out, "result", obj);
+    }
+    return out;
+}
+
 napi_value AshtakavargaForDate(napi_env env, napi_callback_info info) {
     size_t argc = 6;
     napi_value args[6];
@@ -5520,6 +6306,42 @@ napi_value GrahaDrishtiMatrix(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value RashiDrishti(napi_env env, napi_callback_info info) {
+    size_t argc = 2;
+    napi_value args[2];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 2) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    uint32_t from = 0;
+    uint32_t to = 0;
+    if (!GetUint32(env, args[0], &from) || !GetUint32(env, args[1], &to)) return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    uint8_t aspects = 0;
+    int32_t status = dhruv_rashi_drishti(from, to, &aspects);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) SetNamed(env, out, "aspects", MakeBool(env, aspects != 0));
+    return out;
+}
+
+napi_value RashiDrishtiMatrix(napi_env env, napi_callback_info info) {
+    (void)info;
+    DhruvRashiDrishtiMatrix result{};
+    int32_t status = dhruv_rashi_drishti_matrix(&result);
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        napi_value matrix;
+        napi_create_array_with_length(env, 12, &matrix);
+        for (uint32_t i = 0; i < 12; ++i) {
+            napi_value row;
+            napi_create_array_with_length(env, 12, &row);
+            for (uint32_t j = 0; j < 12; ++j) {
+                napi_set_element(env, row, j, MakeBool(env, result.aspects[i][j] != 0));
+            }
+            napi_set_element(env, matrix, i, row);
+        }
+        SetNamed(env, out, "result", matrix);
+    }
+    return out;
+}
+
 napi_value DrishtiForDate(napi_env env, napi_callback_info info) {
     size_t argc = 9;
     napi_value args[9];
@@ -6246,6 +7068,7 @@ napi_value FullKundaliConfigDefault(napi_env env, napi_callback_info info) {
     SetNamed(env, dasha_cfg, "levelMethods", methods);
     SetNamed(env, dasha_cfg, "yoginiScheme", MakeUint32(env, cfg.dasha_config.yogini_scheme));
     SetNamed(env, dasha_cfg, "useAbhijit", MakeBool(env, cfg.dasha_config.use_abhijit != 0));
+    SetNamed(env, dasha_cfg, "yearLength", MakeUint32(env, cfg.dasha_config.year_length));
     if (cfg.dasha_config.snapshot_time.time_kind == DHRUV_DASHA_TIME_UTC) {
         SetNamed(env, dasha_cfg, "snapshotUtc", WriteUtcTime(env, cfg.dasha_config.snapshot_time.utc));
     } else {
@@ -6287,6 +7110,7 @@ napi_value DashaSelectionConfigDefault(napi_env env, napi_callback_info info) {
     SetNamed(env, obj, "levelMethods", methods);
     SetNamed(env, obj, "yoginiScheme", MakeUint32(env, cfg.yogini_scheme));
     SetNamed(env, obj, "useAbhijit", MakeBool(env, cfg.use_abhijit != 0));
+    SetNamed(env, obj, "yearLength", MakeUint32(env, cfg.year_length));
     if (cfg.snapshot_time.time_kind == DHRUV_DASHA_TIME_UTC) {
         SetNamed(env, obj, "snapshotUtc", WriteUtcTime(env, cfg.snapshot_time.utc));
     } else {
@@ -6707,6 +7531,14 @@ napi_value DashaLevel0(napi_env env, napi_callback_info info) {
         return MakeStatusResult(env, STATUS_INVALID_INPUT);
     }
     request.system = static_cast<uint8_t>(system);
+    request.variation = dhruv_dasha_variation_config_default();
+    bool has_variation = false;
+    if (!GetOptionalNamedProperty(env, args[2], "variationConfig", &v, &has_variation)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_variation && !ReadDashaVariationConfig(env, v, &request.variation)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
 
     DhruvDashaPeriodListHandle handle = nullptr;
     int32_t status = dhruv_dasha_level0(
@@ -6762,6 +7594,14 @@ napi_value DashaLevel0Entity(napi_env env, napi_callback_info info) {
         return MakeStatusResult(env, STATUS_INVALID_INPUT);
     }
     request.system = static_cast<uint8_t>(system);
+    request.variation = dhruv_dasha_variation_config_default();
+    bool has_variation = false;
+    if (!GetOptionalNamedProperty(env, args[2], "variationConfig", &v, &has_variation)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_variation && !ReadDashaVariationConfig(env, v, &request.variation)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
     request.entity_type = static_cast<uint8_t>(entity_type);
     request.entity_index = static_cast<uint8_t>(entity_index);
 
@@ -6999,6 +7839,122 @@ napi_value DashaCompleteLevel(napi_env env, napi_callback_info info) {
     return out;
 }
 
+napi_value DashaTransitions(napi_env env, napi_callback_info info) {
+    size_t argc = 3;
+    napi_value args[3];
+    napi_get_cb_info(env, info, &argc, args, nullptr, nullptr);
+    if (argc < 3) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    void* e_ptr = nullptr;
+    void* ep_ptr = nullptr;
+    if (!ReadExternalPtr(env, args[0], &e_ptr) || !ReadExternalPtr(env, args[1], &ep_ptr)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    napi_value v;
+    int32_t ayanamsha = 0;
+    bool use_nutation = false;
+    uint32_t system = 0;
+    uint32_t level = 0;
+    if (!GetNamedProperty(env, args[2], "ayanamshaSystem", &v) || !GetInt32(env, v, &ayanamsha)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (!GetNamedProperty(env, args[2], "useNutation", &v) || !GetBool(env, v, &use_nutation) ||
+        !GetNamedProperty(env, args[2], "system", &v) || !GetUint32(env, v, &system) ||
+        !GetNamedProperty(env, args[2], "level", &v) || !GetUint32(env, v, &level)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    DhruvDashaTransitionsRequest request{};
+    if (!ReadDashaBirthContext(env, args[2], ayanamsha, use_nutation, &request.birth)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    request.system = static_cast<uint8_t>(system);
+    request.level = static_cast<uint8_t>(level);
+    request.variation = dhruv_dasha_variation_config_default();
+    bool has_variation = false;
+    if (!GetOptionalNamedProperty(env, args[2], "variationConfig", &v, &has_variation)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_variation && !ReadDashaVariationConfig(env, v, &request.variation)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+
+    bool has_start_jd = false;
+    bool has_start_utc = false;
+    if (!GetOptionalNamedProperty(env, args[2], "startJd", &v, &has_start_jd)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_start_jd && !GetDouble(env, v, &request.start_jd)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (!GetOptionalNamedProperty(env, args[2], "startUtc", &v, &has_start_utc)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_start_utc && !ReadUtcTime(env, v, &request.start_utc)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_start_jd == has_start_utc) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    request.start_time_kind = has_start_jd ? DHRUV_DASHA_TIME_JD_UTC : DHRUV_DASHA_TIME_UTC;
+
+    bool has_end_jd = false;
+    bool has_end_utc = false;
+    if (!GetOptionalNamedProperty(env, args[2], "endJd", &v, &has_end_jd)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_end_jd && !GetDouble(env, v, &request.end_jd)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (!GetOptionalNamedProperty(env, args[2], "endUtc", &v, &has_end_utc)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_end_utc && !ReadUtcTime(env, v, &request.end_utc)) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    if (has_end_jd == has_end_utc) {
+        return MakeStatusResult(env, STATUS_INVALID_INPUT);
+    }
+    request.end_time_kind = has_end_jd ? DHRUV_DASHA_TIME_JD_UTC : DHRUV_DASHA_TIME_UTC;
+
+    DhruvDashaRangePeriodListHandle handle = nullptr;
+    int32_t status = dhruv_dasha_transitions(
+        static_cast<const DhruvEngineHandle*>(e_ptr),
+        static_cast<const DhruvEopHandle*>(ep_ptr),
+        &request,
+        &handle);
+
+    napi_value out = MakeStatusResult(env, status);
+    if (status == STATUS_OK) {
+        uint32_t count = 0;
+        status = dhruv_dasha_range_period_list_count(handle, &count);
+        napi_value periods;
+        napi_create_array_with_length(env, count, &periods);
+        for (uint32_t i = 0; status == STATUS_OK && i < count; ++i) {
+            DhruvDashaRangePeriod item{};
+            status = dhruv_dasha_range_period_list_at(handle, i, &item);
+            if (status != STATUS_OK) {
+                break;
+            }
+            napi_value obj;
+            napi_create_object(env, &obj);
+            SetNamed(env, obj, "period", WriteDashaPeriod(env, item.period));
+            SetNamed(env, obj, "clippedStart", MakeBool(env, item.clipped_start != 0));
+            SetNamed(env, obj, "clippedEnd", MakeBool(env, item.clipped_end != 0));
+            napi_set_element(env, periods, i, obj);
+        }
+        dhruv_dasha_range_period_list_free(handle);
+        SetNamed(env, out, "status", MakeInt32(env, status));
+        if (status == STATUS_OK) {
+            SetNamed(env, out, "periods", periods);
+        }
+    }
+    return out;
+}
+
 napi_value TaraCatalogLoad(napi_env env, napi_callback_info info) {
     size_t argc = 1;
     napi_value args[1];
@@ -7269,6 +8225,7 @@ napi_value Init(napi_env env, napi_value exports) {
         {"ayanamshaComputeEx", nullptr, AyanamshaComputeEx, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"lunarNodeCount", nullptr, LunarNodeCount, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"lunarNodeDeg", nullptr, LunarNodeDeg, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"nodeSpeedDegPerDay", nullptr, NodeSpeedDegPerDay, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"lunarNodeDegWithEngine", nullptr, LunarNodeDegWithEngine, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"lunarNodeDegUtc", nullptr, LunarNodeDegUtc, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"lunarNodeDegUtcWithEngine", nullptr, LunarNodeDegUtcWithEngine, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7278,6 +8235,8 @@ napi_value Init(napi_env env, napi_value exports) {
         {"rashiFromLongitude", nullptr, RashiFromLongitude, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nakshatraFromLongitude", nullptr, NakshatraFromLongitude, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nakshatra28FromLongitude", nullptr, Nakshatra28FromLongitude, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"kpSublord", nullptr, KpSublord, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"gandantaStatus", nullptr, GandantaStatus, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"rashiFromTropical", nullptr, RashiFromTropical, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nakshatraFromTropical", nullptr, NakshatraFromTropical, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nakshatra28FromTropical", nullptr, Nakshatra28FromTropical, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7285,6 +8244,7 @@ napi_value Init(napi_env env, napi_value exports) {
         {"nakshatraFromTropicalUtc", nullptr, NakshatraFromTropicalUtc, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nakshatra28FromTropicalUtc", nullptr, Nakshatra28FromTropicalUtc, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"grahaLongitudes", nullptr, GrahaLongitudes, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"grahaSpeeds", nullptr, GrahaSpeeds, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"movingOsculatingApogeesForDate", nullptr, MovingOsculatingApogeesForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"rashiName", nullptr, RashiName, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nakshatraName", nullptr, NakshatraName, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7299,6 +8259,7 @@ napi_value Init(napi_env env, napi_value exports) {
         {"horaName", nullptr, HoraName, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"grahaName", nullptr, GrahaName, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"yoginiName", nullptr, YoginiName, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"navataraName", nullptr, NavataraName, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"horaLord", nullptr, HoraLord, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"masaLord", nullptr, MasaLord, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"samvatsaraLord", nullptr, SamvatsaraLord, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7311,6 +8272,7 @@ napi_value Init(napi_env env, napi_value exports) {
         {"naisargikaMaitri", nullptr, NaisargikaMaitri, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"tatkalikaMaitri", nullptr, TatkalikaMaitri, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"panchadhaMaitri", nullptr, PanchadhaMaitri, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"relationshipMatrix", nullptr, RelationshipMatrix, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"dignityInRashi", nullptr, DignityInRashi, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"dignityInRashiWithPositions", nullptr, DignityInRashiWithPositions, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nodeDignityInRashi", nullptr, NodeDignityInRashi, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7331,6 +8293,8 @@ napi_value Init(napi_env env, napi_value exports) {
         {"samvatsaraFromYear", nullptr, SamvatsaraFromYear, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nthRashiFrom", nullptr, NthRashiFrom, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"rashiLord", nullptr, RashiLord, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"nakshatraLord", nullptr, NakshatraLord, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"navataraFrom", nullptr, NavataraFrom, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"horaAt", nullptr, HoraAt, nullptr, nullptr, nullptr, napi_default, nullptr},
 
         {"riseSetConfigDefault", nullptr, RiseSetConfigDefault, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7347,6 +8311,7 @@ napi_value Init(napi_env env, napi_value exports) {
         {"computeAllEventsUtc", nullptr, ComputeAllEventsUtc, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"computeBhavas", nullptr, ComputeBhavas, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"computeBhavasUtc", nullptr, ComputeBhavasUtc, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"wholeSignFromLagna", nullptr, WholeSignFromLagna, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"lagnaDeg", nullptr, LagnaDeg, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"mcDeg", nullptr, MCDeg, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"ramcDeg", nullptr, RAMCDeg, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7358,12 +8323,20 @@ napi_value Init(napi_env env, napi_value exports) {
         {"conjunctionSearch", nullptr, ConjunctionSearch, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"grahanSearch", nullptr, GrahanSearch, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"motionSearch", nullptr, MotionSearch, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"nextNodeStation", nullptr, NextNodeStation, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"retrogradeIntervals", nullptr, RetrogradeIntervals, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"heliacalConfigDefault", nullptr, HeliacalConfigDefault, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"heliacalConfigForBody", nullptr, HeliacalConfigForBody, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"heliacalSearch", nullptr, HeliacalSearch, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"lunarPhaseSearch", nullptr, LunarPhaseSearch, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"sankrantiSearch", nullptr, SankrantiSearch, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"moonRashiIngresses", nullptr, MoonRashiIngresses, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"nextNakshatraIngress", nullptr, NextNakshatraIngress, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"tithiForDate", nullptr, TithiForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"karanaForDate", nullptr, KaranaForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"yogaForDate", nullptr, YogaForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nakshatraForDate", nullptr, NakshatraForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"tithiDayKindForDate", nullptr, TithiDayKindForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"vaarForDate", nullptr, VaarForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"horaForDate", nullptr, HoraForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"ghatikaForDate", nullptr, GhatikaForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7371,6 +8344,7 @@ napi_value Init(napi_env env, napi_value exports) {
         {"ayanaForDate", nullptr, AyanaForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"varshaForDate", nullptr, VarshaForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"panchangComputeEx", nullptr, PanchangComputeEx, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"panchangDiff", nullptr, PanchangDiff, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"elongationAt", nullptr, ElongationAt, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"siderealSumAt", nullptr, SiderealSumAt, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"vedicDaySunrises", nullptr, VedicDaySunrises, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7383,6 +8357,8 @@ napi_value Init(napi_env env, napi_value exports) {
         {"ghatikaFromSunrises", nullptr, GhatikaFromSunrises, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"nakshatraAt", nullptr, NakshatraAt, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"ghatikaFromElapsed", nullptr, GhatikaFromElapsed, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"ghatikaToDuration", nullptr, GhatikaToDuration, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"durationToGhatika", nullptr, DurationToGhatika, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"ghatikasSinceSunrise", nullptr, GhatikasSinceSunrise, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"allSphutas", nullptr, AllSphutas, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"bhriguBindu", nullptr, BhriguBindu, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7420,9 +8396,12 @@ napi_value Init(napi_env env, napi_value exports) {
         {"calculateSav", nullptr, CalculateSav, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"trikonaSodhana", nullptr, TrikonaSodhana, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"ekadhipatyaSodhana", nullptr, EkadhipatyaSodhana, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"transitBindus", nullptr, TransitBindus, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"ashtakavargaForDate", nullptr, AshtakavargaForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"grahaDrishti", nullptr, GrahaDrishti, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"grahaDrishtiMatrix", nullptr, GrahaDrishtiMatrix, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"rashiDrishti", nullptr, RashiDrishti, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"rashiDrishtiMatrix", nullptr, RashiDrishtiMatrix, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"drishtiForDate", nullptr, DrishtiForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"grahaPositionsForDate", nullptr, GrahaPositionsForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"coreBindusForDate", nullptr, CoreBindusForDate, nullptr, nullptr, nullptr, napi_default, nullptr},
@@ -7458,6 +8437,7 @@ napi_value Init(napi_env env, napi_value exports) {
         {"dashaChildren", nullptr, DashaChildren, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"dashaChildPeriod", nullptr, DashaChildPeriod, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"dashaCompleteLevel", nullptr, DashaCompleteLevel, nullptr, nullptr, nullptr, napi_default, nullptr},
+        {"dashaTransitions", nullptr, DashaTransitions, nullptr, nullptr, nullptr, napi_default, nullptr},
 
         {"taraCatalogLoad", nullptr, TaraCatalogLoad, nullptr, nullptr, nullptr, napi_default, nullptr},
         {"taraCatalogFree", nullptr, TaraCatalogFree, nullptr, nullptr, nullptr, napi_default, nullptr},
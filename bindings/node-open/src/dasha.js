@@ -129,6 +129,19 @@ function dashaCompleteLevel(engine, eop, request = {}) {
   return r.periods;
 }
 
+function dashaTransitions(engine, eop, request = {}) {
+  const normalized = baseRequest(request, {
+    level: request.level ?? 1,
+  });
+  assignOptional(normalized, 'startUtc', request.startUtc);
+  assignOptional(normalized, 'startJd', request.startJd);
+  assignOptional(normalized, 'endUtc', request.endUtc);
+  assignOptional(normalized, 'endJd', request.endJd);
+  const r = addon.dashaTransitions(engine._handle, eop._handle, normalized);
+  checkStatus('dasha_transitions', r.status);
+  return r.periods;
+}
+
 module.exports = {
   DashaHierarchy,
   dashaSelectionConfigDefault,
@@ -140,4 +153,5 @@ module.exports = {
   dashaChildren,
   dashaChildPeriod,
   dashaCompleteLevel,
+  dashaTransitions,
 };
@@ -81,6 +81,18 @@ function ghatikasSinceSunrise(queryJd, sunriseJd) {
   return r.value;
 }
 
+function ghatikaToDuration(ghati, vighati = 0) {
+  const r = addon.ghatikaToDuration(ghati, vighati);
+  checkStatus('ghatika_to_duration', r.status);
+  return r.value;
+}
+
+function durationToGhatika(hours) {
+  const r = addon.durationToGhatika(hours);
+  checkStatus('duration_to_ghatika', r.status);
+  return { ghati: r.ghati, vighati: r.vighati };
+}
+
 function allSphutas(inputs) {
   const r = addon.allSphutas(inputs);
   checkStatus('all_sphutas', r.status);
@@ -195,6 +207,17 @@ function trikonaSodhana(totals) {
   return r.result;
 }
 
+const TRANSIT_HOUSE_MODE = Object.freeze({
+  WHOLE_SIGN: 0,
+  CHALIT: 1,
+});
+
+function transitBindus(natal, natalBhavas, grahaIndex, transitLonSid, houseMode = TRANSIT_HOUSE_MODE.WHOLE_SIGN) {
+  const r = addon.transitBindus(natal, natalBhavas, grahaIndex, transitLonSid, houseMode);
+  checkStatus('transit_bindus', r.status);
+  return r.result;
+}
+
 function ekadhipatyaSodhana(totals, grahaRashis, lagnaRashi) {
   const r = addon.ekadhipatyaSodhana(totals, grahaRashis, lagnaRashi);
   checkStatus('ekadhipatya_sodhana', r.status);
@@ -219,6 +242,18 @@ function grahaDrishtiMatrixForLongitudes(siderealLongitudes) {
   return r.result;
 }
 
+function rashiDrishti(fromRashi, toRashi) {
+  const r = addon.rashiDrishti(fromRashi, toRashi);
+  checkStatus('rashi_drishti', r.status);
+  return r.aspects;
+}
+
+function rashiDrishtiMatrix() {
+  const r = addon.rashiDrishtiMatrix();
+  checkStatus('rashi_drishti_matrix', r.status);
+  return r.result;
+}
+
 function drishtiForDate(engine, eop, utc, location, bhavaConfig, riseSetConfig, ayanamshaSystem = 0, useNutation = true, config) {
   const r = addon.drishtiForDate(
     engine._handle,
@@ -280,20 +315,20 @@ function moolatrikoneRange(grahaIndex) {
   return r.hasValue ? { rashiIndex: r.rashiIndex, startDeg: r.startDeg, endDeg: r.endDeg } : null;
 }
 
-function combustionThreshold(grahaIndex, isRetrograde = false) {
-  const r = addon.combustionThreshold(grahaIndex, !!isRetrograde);
+function combustionThreshold(grahaIndex, isRetrograde = false, moonOrbDeg = null) {
+  const r = addon.combustionThreshold(grahaIndex, !!isRetrograde, moonOrbDeg);
   checkStatus('combustion_threshold', r.status);
   return r.hasValue ? r.value : null;
 }
 
-function isCombust(grahaIndex, grahaSidLon, sunSidLon, isRetrograde = false) {
-  const r = addon.isCombust(grahaIndex, grahaSidLon, sunSidLon, !!isRetrograde);
+function isCombust(grahaIndex, grahaSidLon, sunSidLon, isRetrograde = false, moonOrbDeg = null) {
+  const r = addon.isCombust(grahaIndex, grahaSidLon, sunSidLon, !!isRetrograde, moonOrbDeg);
   checkStatus('is_combust', r.status);
   return r.value;
 }
 
-function allCombustionStatus(siderealLons9, retrogradeFlags9) {
-  const r = addon.allCombustionStatus(siderealLons9, retrogradeFlags9);
+function allCombustionStatus(siderealLons9, retrogradeFlags9, moonOrbDeg = null) {
+  const r = addon.allCombustionStatus(siderealLons9, retrogradeFlags9, moonOrbDeg);
   checkStatus('all_combustion_status', r.status);
   return r.result;
 }
@@ -316,6 +351,12 @@ function panchadhaMaitri(naisargikaCode, tatkalikaCode) {
   return r.code;
 }
 
+function relationshipMatrix(siderealLons9) {
+  const r = addon.relationshipMatrix(siderealLons9);
+  checkStatus('relationship_matrix', r.status);
+  return r.result;
+}
+
 function dignityInRashi(grahaIndex, siderealLon, rashiIndex) {
   const r = addon.dignityInRashi(grahaIndex, siderealLon, rashiIndex);
   checkStatus('dignity_in_rashi', r.status);
@@ -442,6 +483,8 @@ module.exports = {
   nakshatraAt,
   ghatikaFromElapsed,
   ghatikasSinceSunrise,
+  ghatikaToDuration,
+  durationToGhatika,
   allSphutas,
   bhriguBindu,
   pranaSphuta,
@@ -477,9 +520,13 @@ module.exports = {
   calculateSav,
   trikonaSodhana,
   ekadhipatyaSodhana,
+  TRANSIT_HOUSE_MODE,
+  transitBindus,
   ashtakavargaForDate,
   grahaDrishti,
   grahaDrishtiMatrixForLongitudes,
+  rashiDrishti,
+  rashiDrishtiMatrix,
   drishtiForDate,
   grahaPositionsForDate,
   horaLord,
@@ -494,6 +541,7 @@ module.exports = {
   naisargikaMaitri,
   tatkalikaMaitri,
   panchadhaMaitri,
+  relationshipMatrix,
   dignityInRashi,
   dignityInRashiWithPositions,
   nodeDignityInRashi,
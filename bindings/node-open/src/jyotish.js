@@ -64,6 +64,14 @@ function grahaLongitudes(engine, jdTdb, config = undefined) {
   return r.longitudes;
 }
 
+function grahaSpeeds(engine, jdTdb, config = undefined) {
+  const r = config === undefined
+    ? addon.grahaSpeeds(engine._handle, jdTdb)
+    : addon.grahaSpeeds(engine._handle, jdTdb, config);
+  checkStatus('graha_speeds', r.status);
+  return r.speeds;
+}
+
 function movingOsculatingApogeesForDate(engine, eop, utc, grahas, config = undefined) {
   const r = config === undefined
     ? addon.movingOsculatingApogeesForDate(engine._handle, eop._handle, utc, grahas)
@@ -139,6 +147,26 @@ function nakshatra28FromLongitude(siderealLongitudeDeg) {
   return r.nakshatra28;
 }
 
+function kpSublord(siderealLongitudeDeg) {
+  const r = addon.kpSublord(siderealLongitudeDeg);
+  checkStatus('kp_sublord', r.status);
+  return r.kp;
+}
+
+const GANDANTA = Object.freeze({
+  MEENA_MESHA: 0,
+  KARKA_SIMHA: 1,
+  VRISCHIKA_DHANU: 2,
+  APPROACHING: 0,
+  LEAVING: 1,
+});
+
+function gandantaStatus(siderealLongitudeDeg) {
+  const r = addon.gandantaStatus(siderealLongitudeDeg);
+  checkStatus('gandanta_status', r.status);
+  return r.found ? r.gandanta : null;
+}
+
 function rashiFromTropical(tropicalLongitudeDeg, ayanamshaSystem, jdTdb, useNutation = true) {
   const r = addon.rashiFromTropical(tropicalLongitudeDeg, ayanamshaSystem, jdTdb, !!useNutation);
   checkStatus('rashi_from_tropical', r.status);
@@ -199,8 +227,8 @@ function yogaFromSum(sumDeg) {
   return r.yogaPosition;
 }
 
-function samvatsaraFromYear(year) {
-  const r = addon.samvatsaraFromYear(year);
+function samvatsaraFromYear(year, scheme = 0) {
+  const r = addon.samvatsaraFromYear(year, scheme);
   checkStatus('samvatsara_from_year', r.status);
   return r.samvatsara;
 }
@@ -218,6 +246,7 @@ function vaarName(index) { return addon.vaarName(index); }
 function horaName(index) { return addon.horaName(index); }
 function grahaName(index) { return addon.grahaName(index); }
 function yoginiName(index) { return addon.yoginiName(index); }
+function navataraName(index) { return addon.navataraName(index); }
 function sphutaName(index) { return addon.sphutaName(index); }
 function specialLagnaName(index) { return addon.specialLagnaName(index); }
 function arudhaPadaName(index) { return addon.arudhaPadaName(index); }
@@ -228,12 +257,15 @@ function masaFromRashiIndex(rashiIndex) { return addon.masaFromRashiIndex(rashiI
 function ayanaFromSiderealLongitude(lonDeg) { return addon.ayanaFromSiderealLongitude(lonDeg); }
 function nthRashiFrom(rashiIndex, offset) { return addon.nthRashiFrom(rashiIndex, offset); }
 function rashiLord(rashiIndex) { return addon.rashiLord(rashiIndex); }
+function nakshatraLord(nakshatraIndex) { return addon.nakshatraLord(nakshatraIndex); }
+function navataraFrom(startNakshatra, targetNakshatra) { return addon.navataraFrom(startNakshatra, targetNakshatra); }
 function horaAt(vaarIndex, horaIndex) { return addon.horaAt(vaarIndex, horaIndex); }
 
 module.exports = {
   GRAHA_LONGITUDE_KIND,
   PRECESSION_MODEL,
   grahaLongitudes,
+  grahaSpeeds,
   movingOsculatingApogeesForDate,
   specialLagnasForDate,
   arudhaPadasForDate,
@@ -246,6 +278,9 @@ module.exports = {
   rashiFromLongitude,
   nakshatraFromLongitude,
   nakshatra28FromLongitude,
+  kpSublord,
+  GANDANTA,
+  gandantaStatus,
   rashiFromTropical,
   nakshatraFromTropical,
   nakshatra28FromTropical,
@@ -270,6 +305,7 @@ module.exports = {
   horaName,
   grahaName,
   yoginiName,
+  navataraName,
   sphutaName,
   specialLagnaName,
   arudhaPadaName,
@@ -279,5 +315,7 @@ module.exports = {
   ayanaFromSiderealLongitude,
   nthRashiFrom,
   rashiLord,
+  nakshatraLord,
+  navataraFrom,
   horaAt,
 };
@@ -37,6 +37,12 @@ function computeBhavas(engine, eop, location, lsk, jdTdb, config) {
   return r.bhava;
 }
 
+function wholeSignFromLagna(lagnaSidDeg) {
+  const r = addon.wholeSignFromLagna(lagnaSidDeg);
+  checkStatus('whole_sign_from_lagna', r.status);
+  return r.bhava;
+}
+
 function computeBhavasUtc(engine, eop, lsk, location, utc, config) {
   const r = addon.computeBhavasUtc(engine._handle, eop._handle, lsk._handle, location, utc, config);
   checkStatus('compute_bhavas_utc', r.status);
@@ -117,6 +123,12 @@ function nakshatraForDate(engine, utc, config = addon.sankrantiConfigDefault())
   return r.nakshatra;
 }
 
+function tithiDayKindForDate(engine, eop, utc, location, config = addon.riseSetConfigDefault()) {
+  const r = addon.tithiDayKindForDate(engine._handle, eop._handle, utc, location, config);
+  checkStatus('tithi_day_kind_for_date', r.status);
+  return r.tithiDay;
+}
+
 function vaarForDate(engine, eop, utc, location, config = addon.riseSetConfigDefault()) {
   const r = addon.vaarForDate(engine._handle, eop._handle, utc, location, config);
   checkStatus('vaar_for_date', r.status);
@@ -159,6 +171,20 @@ function panchangComputeEx(engine, eop, lsk, request) {
   return r.result;
 }
 
+function panchangDiff(
+  engine,
+  eop,
+  utc,
+  location,
+  configA = addon.sankrantiConfigDefault(),
+  configB = addon.sankrantiConfigDefault(),
+  riseSetConfig = addon.riseSetConfigDefault(),
+) {
+  const r = addon.panchangDiff(engine._handle, eop._handle, utc, location, configA, configB, riseSetConfig);
+  checkStatus('panchang_diff', r.status);
+  return r.diff;
+}
+
 module.exports = {
   bhavaSystemCount,
   computeRiseSet,
@@ -167,6 +193,7 @@ module.exports = {
   computeAllEventsUtc,
   computeBhavas,
   computeBhavasUtc,
+  wholeSignFromLagna,
   lagnaDeg,
   mcDeg,
   ramcDeg,
@@ -178,6 +205,7 @@ module.exports = {
   karanaForDate,
   yogaForDate,
   nakshatraForDate,
+  tithiDayKindForDate,
   vaarForDate,
   horaForDate,
   ghatikaForDate,
@@ -185,4 +213,5 @@ module.exports = {
   ayanaForDate,
   varshaForDate,
   panchangComputeEx,
+  panchangDiff,
 };
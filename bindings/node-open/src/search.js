@@ -18,6 +18,16 @@ function stationaryConfigDefault() {
   return addon.stationaryConfigDefault();
 }
 
+function heliacalConfigDefault() {
+  return addon.heliacalConfigDefault();
+}
+
+function heliacalConfigForBody(bodyCode) {
+  const r = addon.heliacalConfigForBody(bodyCode);
+  checkStatus('heliacal_config_for_body', r.status);
+  return r.config;
+}
+
 function normalizeRangeCapacity(capacity) {
   if (!Number.isFinite(capacity) || capacity < 1) {
     return DEFAULT_RANGE_CAPACITY;
@@ -123,6 +133,35 @@ function motionSearch(engine, request, capacity = DEFAULT_RANGE_CAPACITY) {
   );
 }
 
+function nextNodeStation(engine, jdTdb) {
+  const r = addon.nextNodeStation(engine._handle, jdTdb);
+  checkStatus('next_node_station', r.status);
+  return r.found ? r.event : null;
+}
+
+function retrogradeIntervals(engine, request, capacity = DEFAULT_RANGE_CAPACITY) {
+  const response = collectRangeSearch(
+    'retrograde_intervals',
+    addon.retrogradeIntervals,
+    engine,
+    request,
+    capacity,
+  );
+  return response.intervals || [];
+}
+
+function heliacalSearch(engine, eop, catalog, location, request, capacity = DEFAULT_RANGE_CAPACITY) {
+  const catalogHandle = catalog ? catalog._handle : null;
+  return searchResult(
+    'heliacal_search_ex',
+    (handle, req, cap) => addon.heliacalSearch(handle, eop._handle, catalogHandle, location, req, cap),
+    engine,
+    request,
+    capacity,
+    formatSimpleSearch,
+  );
+}
+
 function sankrantiSearch(engine, request, capacity = DEFAULT_RANGE_CAPACITY) {
   return searchResult(
     'sankranti_search_ex',
@@ -134,13 +173,37 @@ function sankrantiSearch(engine, request, capacity = DEFAULT_RANGE_CAPACITY) {
   );
 }
 
+function moonRashiIngresses(engine, request, capacity = DEFAULT_RANGE_CAPACITY) {
+  const response = collectRangeSearch(
+    'moon_rashi_ingresses',
+    addon.moonRashiIngresses,
+    engine,
+    request,
+    capacity,
+  );
+  return response.events || [];
+}
+
+function nextNakshatraIngress(engine, bodyCode, jdTdb, config = addon.sankrantiConfigDefault()) {
+  const r = addon.nextNakshatraIngress(engine._handle, bodyCode, jdTdb, config);
+  checkStatus('next_nakshatra_ingress', r.status);
+  return r.found ? r.event : null;
+}
+
 module.exports = {
   conjunctionConfigDefault,
   grahanConfigDefault,
   stationaryConfigDefault,
+  heliacalConfigDefault,
+  heliacalConfigForBody,
   conjunctionSearch,
   grahanSearch,
   motionSearch,
+  nextNodeStation,
+  retrogradeIntervals,
+  heliacalSearch,
   lunarPhaseSearch,
   sankrantiSearch,
+  moonRashiIngresses,
+  nextNakshatraIngress,
 };
@@ -58,6 +58,12 @@ function lunarNodeDeg(nodeCode, modeCode, jdTdb) {
   return r.longitudeDeg;
 }
 
+function nodeSpeedDegPerDay(modeCode, jdTdb) {
+  const r = addon.nodeSpeedDegPerDay(modeCode, jdTdb);
+  checkStatus('node_speed_deg_per_day', r.status);
+  return r.speedDegPerDay;
+}
+
 function lunarNodeDegWithEngine(engine, nodeCode, modeCode, jdTdb) {
   const r = addon.lunarNodeDegWithEngine(engine._handle, nodeCode, modeCode, jdTdb);
   checkStatus('lunar_node_deg_with_engine', r.status);
@@ -105,6 +111,7 @@ module.exports = {
   ayanamshaComputeEx,
   lunarNodeCount,
   lunarNodeDeg,
+  nodeSpeedDegPerDay,
   lunarNodeDegWithEngine,
   lunarNodeDegUtc,
   lunarNodeDegUtcWithEngine,
@@ -113,6 +113,21 @@ test('low-tier dasha wrappers smoke', { skip: !(hasKernels() && hasEop()) }, ()
   });
   assert.ok(complete.length >= children.length);
 
+  const transitions = dhruv.dashaTransitions(engine, eop, {
+    birthUtc,
+    location: loc,
+    startUtc: { year: 2000, month: 1, day: 1, hour: 0, minute: 0, second: 0 },
+    endUtc: { year: 2030, month: 1, day: 1, hour: 0, minute: 0, second: 0 },
+    level: 1,
+    ayanamshaSystem: 0,
+    useNutation: true,
+    system: 0,
+    variationConfig: variation,
+  });
+  assert.ok(transitions.length > 0);
+  assert.ok(transitions[0].clippedStart);
+  assert.ok(transitions[transitions.length - 1].clippedEnd);
+
   const rawSnapshot = dhruv.dashaSnapshot(engine, eop, {
     birthJd: 2447893.0,
     queryJd: 2460677.0,
@@ -99,6 +99,67 @@ test('amsha variation helpers expose per-amsha catalogs', () => {
   assert.equal(many[1].variations[0].variationCode, 0);
 });
 
+test('nakshatraLord follows the Vimshottari order', () => {
+  assert.equal(dhruv.nakshatraLord(0), 8);
+  assert.equal(dhruv.nakshatraLord(26), 3);
+  assert.equal(dhruv.nakshatraLord(27), -1);
+});
+
+test('navataraFrom counts the tara from a start nakshatra', () => {
+  assert.equal(dhruv.navataraFrom(0, 26), 8);
+  assert.equal(dhruv.navataraFrom(7, 2), 4);
+  assert.equal(dhruv.navataraFrom(27, 0), -1);
+  assert.equal(dhruv.navataraName(8), 'Parama Mitra');
+});
+
+test('kpSublord splits a nakshatra into Vimshottari subs', () => {
+  assert.deepEqual(dhruv.kpSublord(0), { nakshatraIndex: 0, starLord: 8, subLord: 8 });
+  assert.equal(dhruv.kpSublord(3).subLord, 0);
+});
+
+test('relationshipMatrix keeps Sun-Saturn enmity in the same sign', () => {
+  const m = dhruv.relationshipMatrix([5, 70, 100, 130, 160, 190, 10, 220, 40]);
+  assert.equal(m[0][6], dhruv.PANCHADHA.ADHI_SHATRU);
+  assert.equal(m[6][0], dhruv.PANCHADHA.ADHI_SHATRU);
+  assert.equal(m[0][0], -1);
+});
+
+test('rashiDrishti follows the Jaimini sign aspects', () => {
+  assert.equal(dhruv.rashiDrishti(0, 4), true);
+  assert.equal(dhruv.rashiDrishti(0, 1), false);
+  assert.throws(() => dhruv.rashiDrishti(12, 0));
+  const matrix = dhruv.rashiDrishtiMatrix();
+  assert.equal(matrix.length, 12);
+  matrix.forEach((row, i) => {
+    assert.equal(row[i], false);
+    assert.equal(row.filter(Boolean).length, 3);
+  });
+});
+
+test('gandantaStatus reports the junction, half and offset', () => {
+  const s = dhruv.gandantaStatus(121);
+  assert.equal(s.kind, dhruv.GANDANTA.KARKA_SIMHA);
+  assert.equal(s.half, dhruv.GANDANTA.LEAVING);
+  assert.ok(Math.abs(s.offsetDeg - 1) < 1e-9);
+  assert.equal(dhruv.gandantaStatus(60), null);
+});
+
+test('ghatika durations convert to and from clock hours', () => {
+  assert.equal(dhruv.ghatikaToDuration(12, 30), 5);
+  const split = dhruv.durationToGhatika(5);
+  assert.equal(split.ghati, 12);
+  assert.ok(Math.abs(split.vighati - 30) < 1e-9);
+  assert.throws(() => dhruv.ghatikaToDuration(Number.NaN));
+});
+
+test('heliacalConfigForBody uses per-body arcus visionis', () => {
+  const venus = dhruv.heliacalConfigForBody(299);
+  assert.equal(venus.arcusVisionisDeg, 5);
+  assert.equal(venus.minElongationDeg, 5);
+  assert.equal(dhruv.heliacalConfigForBody(10).arcusVisionisDeg, dhruv.heliacalConfigDefault().arcusVisionisDeg);
+  assert.throws(() => dhruv.heliacalConfigForBody(-1));
+});
+
 test('config loading supports discovery defaults', () => {
   const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'dhruv-config-'));
   const configPath = path.join(dir, 'config.toml');
@@ -281,6 +342,16 @@ test('search and panchang smoke', { skip: !(hasKernels() && hasEop()) }, () => {
   );
   assert.equal(sank.found, true);
 
+  const ingresses = dhruv.moonRashiIngresses(
+    engine,
+    {
+      start: { year: 2024, month: 3, day: 1, hour: 0, minute: 0, second: 0 },
+      end: { year: 2024, month: 3, day: 31, hour: 0, minute: 0, second: 0 },
+    },
+    1,
+  );
+  assert.ok(ingresses.length >= 12 && ingresses.length <= 14);
+
   const grahan = dhruv.grahanSearch(
     engine,
     {
@@ -304,6 +375,24 @@ test('search and panchang smoke', { skip: !(hasKernels() && hasEop()) }, () => {
   );
   assert.equal(typeof motion.found, 'boolean');
 
+  const nodeStation = dhruv.nextNodeStation(engine, 2451545.0);
+  assert.ok(nodeStation === null || nodeStation.jdTdb > 2451545.0);
+
+  // 2020-07-01 .. 2021-01-01 holds Mars's single 2020 retrograde span.
+  const retro = dhruv.retrogradeIntervals(
+    engine,
+    { bodyCode: 499, startJdTdb: 2459031.5, endJdTdb: 2459215.5 },
+    1,
+  );
+  assert.equal(retro.length, 1);
+  assert.equal(retro[0].openStart, false);
+  assert.equal(retro[0].openEnd, false);
+  assert.ok(retro[0].startJdTdb < retro[0].endJdTdb);
+
+  const nakIngress = dhruv.nextNakshatraIngress(engine, 301, 2451545.0, sankCfg);
+  assert.equal(nakIngress.bodyCode, 301);
+  assert.ok(nakIngress.jdTdb > 2451545.0 && nakIngress.jdTdb < 2451546.3);
+
   const tithi = dhruv.tithiForDate(engine, utc);
   assert.ok(Number.isInteger(tithi.tithiIndex));
 
@@ -317,9 +406,29 @@ test('search and panchang smoke', { skip: !(hasKernels() && hasEop()) }, () => {
   assert.ok(Number.isInteger(nak.nakshatraIndex));
 
   const loc = { latitudeDeg: 12.9716, longitudeDeg: 77.5946, altitudeM: 920 };
+  const tithiDay = dhruv.tithiDayKindForDate(engine, eop, utc, loc, riseCfg);
+  assert.equal(tithiDay.kind === 0, tithiDay.tithiIndex === -1);
+
   const vaar = dhruv.vaarForDate(engine, eop, utc, loc, riseCfg);
   assert.ok(Number.isInteger(vaar.vaarIndex));
 
+  const heliacal = dhruv.heliacalSearch(
+    engine,
+    eop,
+    null,
+    loc,
+    {
+      targetKind: 0,
+      bodyCode: 299,
+      queryMode: 2,
+      startUtc: { year: 2024, month: 1, day: 1, hour: 0, minute: 0, second: 0 },
+      endUtc: { year: 2026, month: 1, day: 1, hour: 0, minute: 0, second: 0 },
+    },
+    1,
+  );
+  assert.equal(heliacal.found, false);
+  assert.ok(heliacal.events.length > 0);
+
   const hora = dhruv.horaForDate(engine, eop, utc, loc, riseCfg);
   assert.ok(Number.isInteger(hora.horaIndex));
 
@@ -344,6 +453,8 @@ test('search and panchang smoke', { skip: !(hasKernels() && hasEop()) }, () => {
     sankrantiConfig: sankCfg,
   });
   assert.equal(typeof panchang, 'object');
+  const panchangDiff = dhruv.panchangDiff(engine, eop, utc, loc, sankCfg, sankCfg, riseCfg);
+  assert.deepEqual(panchangDiff.limbs, []);
   assert.ok(dhruv.bhavaSystemCount() >= 1);
 
   const riseSet = dhruv.computeRiseSet(engine, eop, loc, riseCfg, 0, 2460000.5, lsk);
@@ -366,6 +477,9 @@ test('search and panchang smoke', { skip: !(hasKernels() && hasEop()) }, () => {
   assert.equal(bhavas.bhavas.length, 12);
   const bhavasUtc = dhruv.computeBhavasUtc(engine, eop, lsk, loc, utc, bhavaCfg);
   assert.equal(bhavasUtc.bhavas.length, 12);
+  const wholeSign = dhruv.wholeSignFromLagna(48.25);
+  assert.equal(wholeSign.bhavas[0].cuspDeg, 30.0);
+  assert.equal(wholeSign.mcDeg, 300.0);
 
   const lagna = dhruv.lagnaDeg(lsk, eop, loc, 2460000.5);
   const mc = dhruv.mcDeg(lsk, eop, loc, 2460000.5);
@@ -397,6 +511,8 @@ test('search and panchang smoke', { skip: !(hasKernels() && hasEop()) }, () => {
 
   const node = dhruv.lunarNodeDeg(0, 0, 2451545.0);
   assert.ok(Number.isFinite(node));
+  const nodeSpeed = dhruv.nodeSpeedDegPerDay(0, 2451545.0);
+  assert.ok(Math.abs(nodeSpeed + 0.053) < 0.001);
   const node2 = dhruv.lunarNodeDegWithEngine(engine, 0, 1, 2451545.0);
   assert.ok(Number.isFinite(node2));
   const nodeUtc = dhruv.lunarNodeDegUtc(lsk, 0, 0, utc);
@@ -596,6 +712,11 @@ test('search and panchang smoke', { skip: !(hasKernels() && hasEop()) }, () => {
   const ashtakavarga = dhruv.calculateAshtakavarga([0, 1, 2, 3, 4, 5, 6], 0);
   assert.equal(ashtakavarga.bavs.length, 7);
   assert.equal(ashtakavarga.bavs[0].contributors.length, 12);
+  const transit = dhruv.transitBindus(ashtakavarga, dhruv.wholeSignFromLagna(20.0), 6, 95.0);
+  assert.equal(transit.house, 4);
+  assert.equal(transit.rashiIndex, 3);
+  assert.equal(transit.bavBindus, ashtakavarga.bavs[6].points[3]);
+  assert.equal(dhruv.transitBindus(ashtakavarga, dhruv.wholeSignFromLagna(20.0), 7, 95.0).bavBindus, null);
   const bav = dhruv.calculateBav(0, [0, 1, 2, 3, 4, 5, 6], 0);
   assert.equal(bav.points.length, 12);
   assert.equal(bav.contributors.length, 12);
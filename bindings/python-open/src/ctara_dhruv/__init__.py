@@ -102,15 +102,22 @@ from ctara_dhruv.types import (
     RashiInfo,
     NakshatraInfo,
     Nakshatra28Info,
+    KpSublord,
+    GandantaStatus,
     BhavaEntry,
     BhavaResult,
     ConjunctionEvent,
     ChandraGrahanResult,
     SuryaGrahanResult,
     StationaryEvent,
+    NodeStationEvent,
+    RetrogradeInterval,
+    HeliacalEvent,
     MaxSpeedEvent,
     LunarPhaseEvent,
     SankrantiEvent,
+    IngressEvent,
+    NakshatraIngressEvent,
     GrahaEntry,
     GrahaPositions,
     MovingOsculatingApogeeEntry,
@@ -156,6 +163,7 @@ from ctara_dhruv.amsha import (
 from ctara_dhruv.dasha import (
     DashaLevel,
     DashaHierarchy,
+    DashaRangePeriod,
     dasha_selection_config_default,
     dasha_variation_config_default,
     dasha_hierarchy,
@@ -165,6 +173,7 @@ from ctara_dhruv.dasha import (
     dasha_children,
     dasha_child_period,
     dasha_complete_level,
+    dasha_transitions,
 )
 
 __all__ = [
@@ -196,11 +205,12 @@ __all__ = [
     "StateVector", "SphericalCoords", "SphericalState", "UtcTime",
     "TimeConversionOptions", "TimePolicy", "TimeWarning", "TimeDiagnostics",
     "UtcToTdbRequest", "UtcToTdbResult", "GrahaLongitudesConfig",
-    "GeoLocation", "Dms", "RashiInfo", "NakshatraInfo", "Nakshatra28Info",
+    "GeoLocation", "Dms", "RashiInfo", "NakshatraInfo", "Nakshatra28Info", "KpSublord",
+    "GandantaStatus",
     "BhavaEntry", "BhavaResult", "ConjunctionEvent",
     "ChandraGrahanResult", "SuryaGrahanResult",
-    "StationaryEvent", "MaxSpeedEvent",
-    "LunarPhaseEvent", "SankrantiEvent",
+    "StationaryEvent", "NodeStationEvent", "RetrogradeInterval", "HeliacalEvent", "MaxSpeedEvent",
+    "LunarPhaseEvent", "SankrantiEvent", "IngressEvent", "NakshatraIngressEvent",
     "GrahaEntry", "GrahaPositions", "MovingOsculatingApogeeEntry", "MovingOsculatingApogees",
     "CharakarakaEntry", "CharakarakaResult", "DashaPeriod",
     "DashaSnapshot", "AmshaVariationCatalog", "AmshaVariationInfo",
@@ -214,9 +224,10 @@ __all__ = [
     "amsha_longitude", "amsha_longitudes", "amsha_rashi_info", "amsha_chart_for_date",
     "amsha_variations", "amsha_variations_many",
     "TaraCatalog",
-    "DashaLevel", "DashaHierarchy",
+    "DashaLevel", "DashaHierarchy", "DashaRangePeriod",
     "dasha_selection_config_default", "dasha_variation_config_default",
     "dasha_hierarchy", "dasha_snapshot",
     "dasha_level0", "dasha_level0_entity",
     "dasha_children", "dasha_child_period", "dasha_complete_level",
+    "dasha_transitions",
 ]
@@ -41,7 +41,7 @@ extern "C" {
  * =================================================================== */
 
 /* API version */
-#define DHRUV_API_VERSION       70
+#define DHRUV_API_VERSION       71
 #define DHRUV_PATH_CAPACITY     512
 #define DHRUV_MAX_SPK_PATHS     8
 #define DHRUV_MAX_AMSHA_VARIATIONS 16
@@ -159,6 +159,22 @@ typedef int32_t DhruvStatus;
 #define DHRUV_SUN_LIMB_CENTER    1
 #define DHRUV_SUN_LIMB_LOWER     2
 
+/* Refraction model */
+#define DHRUV_REFRACTION_MODEL_STANDARD    0
+#define DHRUV_REFRACTION_MODEL_BENNETT     1
+#define DHRUV_REFRACTION_MODEL_SAEMUNDSSON 2
+#define DHRUV_REFRACTION_MODEL_CUSTOM      3
+
+/* Vedic-day fallback when the Sun does not rise */
+#define DHRUV_DAY_FALLBACK_ERROR          0
+#define DHRUV_DAY_FALLBACK_CIVIL_TWILIGHT 1
+#define DHRUV_DAY_FALLBACK_LOCAL_SIX_AM   2
+
+/* Tithi day kind (kshaya = skipped, vriddhi = repeated) */
+#define DHRUV_TITHI_DAY_NORMAL  0
+#define DHRUV_TITHI_DAY_KSHAYA  1
+#define DHRUV_TITHI_DAY_VRIDDHI 2
+
 /* Rise/set result type */
 #define DHRUV_RISESET_EVENT       0
 #define DHRUV_RISESET_NEVER_RISES 1
@@ -178,6 +194,7 @@ typedef int32_t DhruvStatus;
 #define DHRUV_AYANAMSHA_MODE_MEAN    0
 #define DHRUV_AYANAMSHA_MODE_TRUE    1
 #define DHRUV_AYANAMSHA_MODE_UNIFIED 2
+#define DHRUV_AYANAMSHA_MODE_RATE    3
 
 /* Ayanamsha time */
 #define DHRUV_AYANAMSHA_TIME_JD_TDB 0
@@ -194,6 +211,7 @@ typedef int32_t DhruvStatus;
 #define DHRUV_BHAVA_AXIAL_ROTATION  7
 #define DHRUV_BHAVA_TOPOCENTRIC     8
 #define DHRUV_BHAVA_ALCABITUS       9
+#define DHRUV_BHAVA_PORPHYRY        10
 
 /* Bhava reference mode */
 #define DHRUV_BHAVA_REF_START   0
@@ -260,6 +278,17 @@ typedef int32_t DhruvStatus;
 #define DHRUV_MOTION_QUERY_MODE_PREV  1
 #define DHRUV_MOTION_QUERY_MODE_RANGE 2
 
+/* Heliacal rising / setting */
+#define DHRUV_HELIACAL_TARGET_BODY 0
+#define DHRUV_HELIACAL_TARGET_TARA 1
+
+#define DHRUV_HELIACAL_RISING  0
+#define DHRUV_HELIACAL_SETTING 1
+
+#define DHRUV_HELIACAL_QUERY_MODE_NEXT  0
+#define DHRUV_HELIACAL_QUERY_MODE_PREV  1
+#define DHRUV_HELIACAL_QUERY_MODE_RANGE 2
+
 /* Lunar phase */
 #define DHRUV_LUNAR_PHASE_NEW_MOON  0
 #define DHRUV_LUNAR_PHASE_FULL_MOON 1
@@ -287,6 +316,19 @@ typedef int32_t DhruvStatus;
 #define DHRUV_PANCHANG_TIME_JD_TDB 0
 #define DHRUV_PANCHANG_TIME_UTC    1
 
+/* Panchang diff limbs */
+#define DHRUV_PANCHANG_LIMB_TITHI     0
+#define DHRUV_PANCHANG_LIMB_KARANA    1
+#define DHRUV_PANCHANG_LIMB_YOGA      2
+#define DHRUV_PANCHANG_LIMB_VAAR      3
+#define DHRUV_PANCHANG_LIMB_HORA      4
+#define DHRUV_PANCHANG_LIMB_GHATIKA   5
+#define DHRUV_PANCHANG_LIMB_NAKSHATRA 6
+#define DHRUV_PANCHANG_LIMB_MASA      7
+#define DHRUV_PANCHANG_LIMB_AYANA     8
+#define DHRUV_PANCHANG_LIMB_VARSHA    9
+#define DHRUV_PANCHANG_LIMB_COUNT     10
+
 /* Panchang include masks */
 #define DHRUV_PANCHANG_INCLUDE_TITHI      (1U << 0)
 #define DHRUV_PANCHANG_INCLUDE_KARANA     (1U << 1)
@@ -362,6 +404,8 @@ typedef struct DhruvTaraCatalogHandle DhruvTaraCatalogHandle;
 typedef void *DhruvDashaHierarchyHandle;
 /* DhruvDashaPeriodListHandle is void* */
 typedef void *DhruvDashaPeriodListHandle;
+/* DhruvDashaRangePeriodListHandle is void* */
+typedef void *DhruvDashaRangePeriodListHandle;
 
 /* ===================================================================
  * Structs
@@ -506,6 +550,11 @@ typedef struct {
     uint8_t use_refraction;
     int32_t sun_limb;
     uint8_t altitude_correction;
+    int32_t refraction_model;
+    double  refraction_pressure_hpa;
+    double  refraction_temperature_c;
+    double  refraction_arcmin;
+    int32_t day_fallback;
 } DhruvRiseSetConfig;
 
 typedef struct {
@@ -522,6 +571,7 @@ typedef struct {
     DhruvUtcTime utc;
     uint8_t use_nutation;
     double  delta_psi_arcsec;
+    int32_t precession_model;
 } DhruvAyanamshaComputeRequest;
 
 typedef struct {
@@ -549,6 +599,24 @@ typedef struct {
     double  degrees_in_nakshatra;
 } DhruvNakshatra28Info;
 
+typedef struct {
+    uint8_t nakshatra_index;
+    uint8_t star_lord;
+    uint8_t sub_lord;
+} DhruvKpSublord;
+
+#define DHRUV_GANDANTA_MEENA_MESHA     0
+#define DHRUV_GANDANTA_KARKA_SIMHA     1
+#define DHRUV_GANDANTA_VRISCHIKA_DHANU 2
+#define DHRUV_GANDANTA_APPROACHING 0
+#define DHRUV_GANDANTA_LEAVING     1
+
+typedef struct {
+    int32_t kind;       /* DHRUV_GANDANTA_* junction */
+    int32_t half;       /* DHRUV_GANDANTA_APPROACHING / _LEAVING */
+    double  offset_deg; /* distance from the junction, [0, 3deg20') */
+} DhruvGandantaStatus;
+
 /* --- Bhava --- */
 
 typedef struct {
@@ -574,6 +642,7 @@ typedef struct {
     double  cusp_deg;
     double  start_deg;
     double  end_deg;
+    double  madhya_deg;
 } DhruvBhava;
 
 typedef struct {
@@ -733,6 +802,56 @@ typedef struct {
     int32_t speed_type;
 } DhruvMaxSpeedEvent;
 
+typedef struct {
+    double  jd_tdb;
+    DhruvUtcTime utc;
+    double  rahu_longitude_deg;
+    int32_t station_type;
+} DhruvNodeStationEvent;
+
+typedef struct {
+    double  start_jd_tdb;
+    double  end_jd_tdb;
+    DhruvUtcTime start_utc;
+    DhruvUtcTime end_utc;
+    double  start_lon_deg;
+    double  end_lon_deg;
+    uint8_t open_start;
+    uint8_t open_end;
+} DhruvRetrogradeInterval;
+
+/* --- Heliacal rising / setting --- */
+
+typedef struct {
+    double arcus_visionis_deg;
+    double min_altitude_deg;
+    double min_elongation_deg;
+} DhruvHeliacalConfig;
+
+typedef struct {
+    int32_t target_kind;
+    int32_t body_code;
+    int32_t tara_id;
+    int32_t event_kind;
+    int32_t query_mode;
+    int32_t time_kind;
+    double  at_jd_tdb;
+    double  start_jd_tdb;
+    double  end_jd_tdb;
+    DhruvUtcTime at_utc;
+    DhruvUtcTime start_utc;
+    DhruvUtcTime end_utc;
+    DhruvHeliacalConfig config;
+} DhruvHeliacalSearchRequest;
+
+typedef struct {
+    double  jd_tdb;
+    DhruvUtcTime utc;
+    int32_t event_kind;
+    double  altitude_deg;
+    double  elongation_deg;
+} DhruvHeliacalEvent;
+
 /* --- Sankranti / Lunar phase --- */
 
 typedef struct {
@@ -742,6 +861,10 @@ typedef struct {
     double   step_size_days;
     uint32_t max_iterations;
     double   convergence_days;
+    uint8_t  use_custom_ayanamsha;
+    double   custom_ayanamsha_anchor_deg;
+    double   custom_ayanamsha_anchor_jd_tdb;
+    int32_t  samvatsara_scheme;
 } DhruvSankrantiConfig;
 
 typedef struct {
@@ -751,6 +874,21 @@ typedef struct {
     double  sun_tropical_longitude_deg;
 } DhruvSankrantiEvent;
 
+typedef struct {
+    DhruvUtcTime utc;
+    int32_t rashi_index;
+    double  jd_tdb;
+} DhruvIngressEvent;
+
+typedef struct {
+    DhruvUtcTime utc;
+    double  jd_tdb;
+    int32_t body_code;
+    int32_t nakshatra_index;
+    double  boundary_deg;
+    uint8_t retrograde;
+} DhruvNakshatraIngressEvent;
+
 typedef struct {
     int32_t target_kind;
     int32_t query_mode;
@@ -829,6 +967,11 @@ typedef struct {
     DhruvUtcTime end;
 } DhruvYogaInfo;
 
+typedef struct {
+    int32_t kind;        /* DHRUV_TITHI_DAY_* */
+    int32_t tithi_index; /* skipped/repeated tithi 0-29, -1 when normal */
+} DhruvTithiDayInfo;
+
 typedef struct {
     int32_t      vaar_index;
     DhruvUtcTime start;
@@ -888,6 +1031,8 @@ typedef struct {
 typedef struct {
     uint8_t                  tithi_valid;
     DhruvTithiInfo           tithi;
+    int32_t                  tithi_day_kind;
+    int32_t                  tithi_day_tithi_index;
     uint8_t                  karana_valid;
     DhruvKaranaInfo          karana;
     uint8_t                  yoga_valid;
@@ -910,6 +1055,8 @@ typedef struct {
 
 typedef struct {
     DhruvTithiInfo              tithi;
+    int32_t                     tithi_day_kind;
+    int32_t                     tithi_day_tithi_index;
     DhruvKaranaInfo             karana;
     DhruvYogaInfo               yoga;
     DhruvVaarInfo               vaar;
@@ -922,6 +1069,20 @@ typedef struct {
     DhruvVarshaInfo             varsha;
 } DhruvPanchangInfo;
 
+typedef struct {
+    int32_t limb;
+    uint8_t value_differs;
+    double  start_shift_s;
+    double  end_shift_s;
+} DhruvPanchangLimbDiff;
+
+typedef struct {
+    DhruvPanchangInfo     a;
+    DhruvPanchangInfo     b;
+    uint32_t              limb_count;
+    DhruvPanchangLimbDiff limbs[DHRUV_PANCHANG_LIMB_COUNT];
+} DhruvPanchangDiff;
+
 /* --- UTC event variants --- */
 
 typedef struct {
@@ -1086,6 +1247,20 @@ typedef struct {
     DhruvSarvaAshtakavarga  sav;
 } DhruvAshtakavargaResult;
 
+/* Transit house modes */
+#define DHRUV_TRANSIT_HOUSE_WHOLE_SIGN 0
+#define DHRUV_TRANSIT_HOUSE_CHALIT     1
+
+typedef struct {
+    uint8_t graha_index;
+    double  transit_longitude_deg;
+    uint8_t house;
+    uint8_t rashi_index;
+    uint8_t bav_valid;
+    uint8_t bav_bindus;
+    uint8_t sav_bindus;
+} DhruvTransitBindus;
+
 /* --- Drishti --- */
 
 typedef struct {
@@ -1099,6 +1274,10 @@ typedef struct {
     DhruvDrishtiEntry entries[9][9];
 } DhruvGrahaDrishtiMatrix;
 
+typedef struct {
+    uint8_t aspects[12][12]; /* [from][to] by rashi index, 1 = aspects */
+} DhruvRashiDrishtiMatrix;
+
 typedef struct {
     uint8_t include_bhava;
     uint8_t include_lagna;
@@ -1120,6 +1299,7 @@ typedef struct {
     uint8_t include_lagna;
     uint8_t include_outer_planets;
     uint8_t include_bhava;
+    uint8_t include_flags;
 } DhruvGrahaPositionsConfig;
 
 typedef struct {
@@ -1129,6 +1309,9 @@ typedef struct {
     uint8_t pada;
     uint8_t bhava_number;
     uint8_t rashi_bhava_number;
+    uint8_t gandanta;
+    uint8_t rashi_sandhi;
+    uint8_t pushkara;
 } DhruvGrahaEntry;
 
 typedef struct {
@@ -1172,8 +1355,15 @@ typedef struct {
     uint8_t use_nutation;
     int32_t precession_model;
     int32_t reference_plane;
+    uint8_t use_custom_ayanamsha;
+    double custom_ayanamsha_anchor_deg;
+    double custom_ayanamsha_anchor_jd_tdb;
 } DhruvGrahaLongitudesConfig;
 
+typedef struct {
+    double speeds[9];
+} DhruvGrahaSpeeds;
+
 typedef struct {
     uint8_t graha_index;
     double sidereal_longitude;
@@ -1310,6 +1500,8 @@ typedef struct {
     double                   total_rupas;
     double                   required_strength;
     uint8_t                  is_strong;
+    double                   ishta;
+    double                   kashta;
 } DhruvShadbalaEntry;
 
 typedef struct {
@@ -1408,6 +1600,12 @@ typedef struct {
     uint32_t parent_idx;
 } DhruvDashaPeriod;
 
+typedef struct {
+    DhruvDashaPeriod period;
+    uint8_t          clipped_start;
+    uint8_t          clipped_end;
+} DhruvDashaRangePeriod;
+
 typedef struct {
     uint8_t          system;
     double           query_jd;
@@ -1430,6 +1628,7 @@ typedef struct {
     uint8_t level_methods[5];
     uint8_t yogini_scheme;
     uint8_t use_abhijit;
+    uint8_t year_length;
     DhruvDashaSnapshotTime snapshot_time;
 } DhruvDashaSelectionConfig;
 
@@ -1437,6 +1636,7 @@ typedef struct {
     uint8_t level_methods[5];
     uint8_t yogini_scheme;
     uint8_t use_abhijit;
+    uint8_t year_length;
 } DhruvDashaVariationConfig;
 
 typedef struct {
@@ -1487,11 +1687,13 @@ typedef struct {
 typedef struct {
     DhruvDashaBirthContext birth;
     uint8_t                system;
+    DhruvDashaVariationConfig variation;
 } DhruvDashaLevel0Request;
 
 typedef struct {
     DhruvDashaBirthContext birth;
     uint8_t                system;
+    DhruvDashaVariationConfig variation;
     uint8_t                entity_type;
     uint8_t                entity_index;
 } DhruvDashaLevel0EntityRequest;
@@ -1519,6 +1721,19 @@ typedef struct {
     uint8_t                  child_level;
 } DhruvDashaCompleteLevelRequest;
 
+typedef struct {
+    DhruvDashaBirthContext   birth;
+    int32_t                  start_time_kind;
+    double                   start_jd;
+    DhruvUtcTime             start_utc;
+    int32_t                  end_time_kind;
+    double                   end_jd;
+    DhruvUtcTime             end_utc;
+    uint8_t                  system;
+    uint8_t                  level;
+    DhruvDashaVariationConfig variation;
+} DhruvDashaTransitionsRequest;
+
 /* --- Full Kundali --- */
 
 typedef struct {
@@ -1740,6 +1955,9 @@ DhruvStatus dhruv_compute_bhavas(
     double jd_tdb,
     const DhruvBhavaConfig *config,
     DhruvBhavaResult *out);
+DhruvStatus dhruv_whole_sign_from_lagna(
+    double lagna_sid_deg,
+    DhruvBhavaResult *out_result);
 DhruvStatus dhruv_lagna_deg(
     const DhruvLskHandle *lsk,
     const DhruvEopHandle *eop,
@@ -1781,6 +1999,8 @@ DhruvStatus dhruv_lunar_node_deg_with_engine(
     const DhruvEngineHandle *engine,
     int32_t node_code, int32_t mode_code,
     double jd_tdb, double *out);
+DhruvStatus dhruv_node_speed_deg_per_day(
+    int32_t mode_code, double jd_tdb, double *out_deg_per_day);
 DhruvStatus dhruv_lunar_node_compute_ex(
     const DhruvLskHandle *lsk,
     const DhruvEopHandle *eop,
@@ -1824,12 +2044,45 @@ DhruvStatus dhruv_motion_search_ex(
     DhruvMaxSpeedEvent *out_max_speed_events,
     uint32_t out_capacity,
     uint32_t *out_count);
+DhruvStatus dhruv_next_node_station(
+    const DhruvEngineHandle *engine,
+    double jd_tdb,
+    DhruvNodeStationEvent *out_event,
+    uint8_t *out_found);
+DhruvStatus dhruv_retrograde_intervals(
+    const DhruvEngineHandle *engine,
+    int32_t body_code,
+    double start_jd_tdb,
+    double end_jd_tdb,
+    const DhruvStationaryConfig *config,
+    DhruvRetrogradeInterval *out_intervals,
+    uint32_t max_count,
+    uint32_t *out_count);
+
+/* --- Heliacal rising / setting --- */
+DhruvHeliacalConfig dhruv_heliacal_config_default(void);
+DhruvStatus dhruv_heliacal_config_for_body(
+    int32_t body_code,
+    DhruvHeliacalConfig *out_config);
+DhruvStatus dhruv_heliacal_search_ex(
+    const DhruvEngineHandle *engine,
+    const DhruvEopHandle *eop,
+    const DhruvTaraCatalogHandle *catalog,
+    const DhruvGeoLocation *location,
+    const DhruvHeliacalSearchRequest *request,
+    DhruvHeliacalEvent *out_event,
+    uint8_t *out_found,
+    DhruvHeliacalEvent *out_events,
+    uint32_t max_count,
+    uint32_t *out_count);
 
 /* --- Rashi / Nakshatra --- */
 DhruvStatus dhruv_deg_to_dms(double degrees, DhruvDms *out);
 DhruvStatus dhruv_rashi_from_longitude(double sidereal_lon, DhruvRashiInfo *out);
 DhruvStatus dhruv_nakshatra_from_longitude(double sidereal_lon, DhruvNakshatraInfo *out);
 DhruvStatus dhruv_nakshatra28_from_longitude(double sidereal_lon, DhruvNakshatra28Info *out);
+DhruvStatus dhruv_kp_sublord(double sidereal_lon, DhruvKpSublord *out);
+DhruvStatus dhruv_gandanta_status(double sidereal_lon, DhruvGandantaStatus *out, uint8_t *out_found);
 DhruvStatus dhruv_rashi_from_tropical(
     double tropical_lon, uint32_t ayanamsha_system,
     double jd_tdb, uint8_t use_nutation,
@@ -1866,6 +2119,21 @@ DhruvStatus dhruv_sankranti_search_ex(
     DhruvSankrantiEvent *out_events,
     uint32_t out_capacity,
     uint32_t *out_count);
+DhruvStatus dhruv_moon_rashi_ingresses(
+    const DhruvEngineHandle *engine,
+    const DhruvUtcTime *start_utc,
+    const DhruvUtcTime *end_utc,
+    const DhruvSankrantiConfig *config,
+    DhruvIngressEvent *out_events,
+    uint32_t max_count,
+    uint32_t *out_count);
+DhruvStatus dhruv_next_nakshatra_ingress(
+    const DhruvEngineHandle *engine,
+    int32_t body_code,
+    double jd_tdb,
+    const DhruvSankrantiConfig *config,
+    DhruvNakshatraIngressEvent *out_event,
+    uint8_t *out_found);
 
 /* --- Calendar --- */
 DhruvStatus dhruv_masa_for_date(
@@ -1894,7 +2162,8 @@ DhruvStatus dhruv_yoga_from_sum(double sum_deg, DhruvYogaPosition *out);
 int32_t dhruv_vaar_from_jd(double jd);
 int32_t dhruv_masa_from_rashi_index(uint32_t rashi_index);
 int32_t dhruv_ayana_from_sidereal_longitude(double lon_deg);
-DhruvStatus dhruv_samvatsara_from_year(int32_t ce_year, DhruvSamvatsaraResult *out);
+DhruvStatus dhruv_samvatsara_from_year(int32_t ce_year, int32_t scheme,
+                                       DhruvSamvatsaraResult *out);
 int32_t dhruv_nth_rashi_from(uint32_t rashi_index, uint32_t offset);
 
 /* --- UTC wrapper functions --- */
@@ -1990,6 +2259,13 @@ DhruvStatus dhruv_tithi_for_date(
     const DhruvEngineHandle *engine,
     const DhruvUtcTime *utc,
     DhruvTithiInfo *out);
+DhruvStatus dhruv_tithi_day_kind_for_date(
+    const DhruvEngineHandle *engine,
+    const DhruvEopHandle *eop,
+    const DhruvUtcTime *utc,
+    const DhruvGeoLocation *location,
+    const DhruvRiseSetConfig *riseset_config,
+    DhruvTithiDayInfo *out);
 DhruvStatus dhruv_karana_for_date(
     const DhruvEngineHandle *engine,
     const DhruvUtcTime *utc,
@@ -2033,6 +2309,15 @@ DhruvStatus dhruv_panchang_compute_ex(
     const DhruvLskHandle *lsk,
     const DhruvPanchangComputeRequest *request,
     DhruvPanchangOperationResult *out);
+DhruvStatus dhruv_panchang_diff(
+    const DhruvEngineHandle *engine,
+    const DhruvEopHandle *eop,
+    const DhruvUtcTime *utc,
+    const DhruvGeoLocation *location,
+    const DhruvRiseSetConfig *riseset_config,
+    const DhruvSankrantiConfig *config_a,
+    const DhruvSankrantiConfig *config_b,
+    DhruvPanchangDiff *out);
 
 /* --- Panchang name lookups --- */
 const char *dhruv_tithi_name(uint32_t index);
@@ -2094,6 +2379,9 @@ DhruvStatus dhruv_ghatika_from_sunrises(
 const char *dhruv_graha_name(uint32_t index);
 const char *dhruv_yogini_name(uint32_t index);
 int32_t dhruv_rashi_lord(uint32_t rashi_index);
+int32_t dhruv_nakshatra_lord(uint32_t nakshatra_index);
+int32_t dhruv_navatara_from(uint32_t start_nakshatra, uint32_t target_nakshatra);
+const char *dhruv_navatara_name(uint32_t index);
 int32_t dhruv_hora_lord(uint32_t vaar_index, uint32_t hora_index);
 int32_t dhruv_masa_lord(uint32_t masa_index);
 int32_t dhruv_samvatsara_lord(uint32_t samvatsara_index);
@@ -2137,6 +2425,11 @@ int32_t dhruv_samvatsara_lord(uint32_t samvatsara_index);
 #define DHRUV_GRAHA_GENDER_NEUTER 2
 
 /* --- Graha relationship / dignity / combustion helpers --- */
+typedef struct {
+    double moon_orb_deg; /* 0 exempts the Moon */
+} DhruvCombustionConfig;
+
+DhruvCombustionConfig dhruv_combustion_config_default(void);
 DhruvStatus dhruv_exaltation_degree(
     uint32_t graha_index,
     uint8_t *out_has_value,
@@ -2154,6 +2447,7 @@ DhruvStatus dhruv_moolatrikone_range(
 DhruvStatus dhruv_combustion_threshold(
     uint32_t graha_index,
     uint8_t is_retrograde,
+    const DhruvCombustionConfig *config,
     uint8_t *out_has_value,
     double *out_threshold_deg);
 DhruvStatus dhruv_is_combust(
@@ -2161,10 +2455,12 @@ DhruvStatus dhruv_is_combust(
     double graha_sid_lon,
     double sun_sid_lon,
     uint8_t is_retrograde,
+    const DhruvCombustionConfig *config,
     uint8_t *out_is_combust);
 DhruvStatus dhruv_all_combustion_status(
     const double *sidereal_lons_9,
     const uint8_t *retrograde_flags_9,
+    const DhruvCombustionConfig *config,
     uint8_t *out_combust_flags_9);
 DhruvStatus dhruv_naisargika_maitri(
     uint32_t graha_index,
@@ -2178,6 +2474,9 @@ DhruvStatus dhruv_panchadha_maitri(
     int32_t naisargika_code,
     int32_t tatkalika_code,
     int32_t *out_code);
+DhruvStatus dhruv_relationship_matrix(
+    const double *sidereal_lons_9,
+    int32_t *out_codes_81);
 DhruvStatus dhruv_dignity_in_rashi(
     uint32_t graha_index,
     double sidereal_lon,
@@ -2342,6 +2641,13 @@ DhruvStatus dhruv_ekadhipatya_sodhana(
     const uint8_t *totals,
     const uint8_t *graha_rashis, uint8_t lagna_rashi,
     uint8_t *out);
+DhruvStatus dhruv_transit_bindus(
+    const DhruvAshtakavargaResult *natal,
+    const DhruvBhavaResult *natal_bhavas,
+    uint32_t graha_index,
+    double transit_lon_sid,
+    int32_t house_mode,
+    DhruvTransitBindus *out);
 DhruvStatus dhruv_ashtakavarga_for_date(
     const DhruvEngineHandle *engine,
     const DhruvEopHandle *eop,
@@ -2359,6 +2665,11 @@ DhruvStatus dhruv_graha_drishti(
 DhruvStatus dhruv_graha_drishti_matrix(
     const double *sidereal_lons,
     DhruvGrahaDrishtiMatrix *out);
+DhruvStatus dhruv_rashi_drishti(
+    uint32_t from_rashi,
+    uint32_t to_rashi,
+    uint8_t *out_aspects);
+DhruvStatus dhruv_rashi_drishti_matrix(DhruvRashiDrishtiMatrix *out);
 DhruvStatus dhruv_drishti(
     const DhruvEngineHandle *engine,
     const DhruvEopHandle *eop,
@@ -2378,6 +2689,12 @@ DhruvStatus dhruv_ghatika_from_elapsed(
 DhruvStatus dhruv_ghatikas_since_sunrise(
     double query_jd, double sunrise_jd,
     double *out);
+DhruvStatus dhruv_ghatika_to_duration(
+    double ghati, double vighati,
+    double *out_hours);
+DhruvStatus dhruv_duration_to_ghatika(
+    double hours,
+    uint32_t *out_ghati, double *out_vighati);
 int32_t dhruv_hora_at(uint32_t vaar_index, uint32_t hora_index);
 
 /* --- Graha positions --- */
@@ -2412,6 +2729,11 @@ DhruvStatus dhruv_graha_longitudes(
     double jd_tdb,
     const DhruvGrahaLongitudesConfig *config,
     DhruvGrahaLongitudes *out);
+DhruvStatus dhruv_graha_speeds(
+    const DhruvEngineHandle *engine,
+    double jd_tdb,
+    const DhruvGrahaLongitudesConfig *config,
+    DhruvGrahaSpeeds *out);
 DhruvStatus dhruv_moving_osculating_apogees_for_date(
     const DhruvEngineHandle *engine,
     const DhruvEopHandle *eop,
@@ -2565,6 +2887,13 @@ DhruvStatus dhruv_dasha_period_list_at(
     uint32_t idx,
     DhruvDashaPeriod *out);
 void dhruv_dasha_period_list_free(DhruvDashaPeriodListHandle handle);
+DhruvStatus dhruv_dasha_range_period_list_count(
+    DhruvDashaRangePeriodListHandle handle, uint32_t *out);
+DhruvStatus dhruv_dasha_range_period_list_at(
+    DhruvDashaRangePeriodListHandle handle,
+    uint32_t idx,
+    DhruvDashaRangePeriod *out);
+void dhruv_dasha_range_period_list_free(DhruvDashaRangePeriodListHandle handle);
 DhruvStatus dhruv_dasha_hierarchy(
     const DhruvEngineHandle *engine,
     const DhruvEopHandle *eop,
@@ -2604,6 +2933,11 @@ DhruvStatus dhruv_dasha_complete_level(
     const DhruvDashaPeriod *parent_periods,
     uint32_t parent_count,
     DhruvDashaPeriodListHandle *out);
+DhruvStatus dhruv_dasha_transitions(
+    const DhruvEngineHandle *engine,
+    const DhruvEopHandle *eop,
+    const DhruvDashaTransitionsRequest *request,
+    DhruvDashaRangePeriodListHandle *out);
 
 /* --- Full Kundali --- */
 DhruvFullKundaliConfig dhruv_full_kundali_config_default(void);
@@ -72,6 +72,11 @@ def _make_riseset_config(riseset_config):
     cfg.use_refraction = riseset_config.get("use_refraction", 1)
     cfg.sun_limb = riseset_config.get("sun_limb", 0)
     cfg.altitude_correction = riseset_config.get("altitude_correction", 0)
+    cfg.refraction_model = riseset_config.get("refraction_model", 0)
+    cfg.refraction_pressure_hpa = riseset_config.get("refraction_pressure_hpa", 1010.0)
+    cfg.refraction_temperature_c = riseset_config.get("refraction_temperature_c", 10.0)
+    cfg.refraction_arcmin = riseset_config.get("refraction_arcmin", 34.0)
+    cfg.day_fallback = riseset_config.get("day_fallback", 0)
     return cfg
 
 
@@ -29,6 +29,7 @@ def ayanamsha(
     use_nutation: int = 1,
     delta_psi: float = 0.0,
     catalog=None,
+    precession_model: int = 3,
 ) -> float:
     """Compute ayanamsha using the unified request API.
 
@@ -40,19 +41,22 @@ def ayanamsha(
         system: Ayanamsha system code (0-19).
         jd_tdb: Julian Date in TDB (when using TDB time input).
         utc: UtcTime dataclass (when using UTC time input).
-        mode: Computation mode (0=MEAN, 1=TRUE, 2=UNIFIED).
+        mode: Computation mode (0=MEAN, 1=TRUE, 2=UNIFIED, 3=RATE).
         use_nutation: For UNIFIED mode: 0=false, 1=true.
         delta_psi: For TRUE mode: nutation in longitude (arcsec).
         catalog: DhruvTaraCatalogHandle pointer (optional).
+        precession_model: Precession model code (0=Newcomb1895,
+            1=Lieske1977, 2=IAU2006, 3=Vondrak2011).
 
     Returns:
-        Ayanamsha value in degrees.
+        Ayanamsha value in degrees, or degrees per Julian century for RATE.
     """
     req = ffi.new("DhruvAyanamshaComputeRequest *")
     req.system_code = system
     req.mode = mode
     req.use_nutation = use_nutation
     req.delta_psi_arcsec = delta_psi
+    req.precession_model = precession_model
 
     if utc is not None:
         req.time_kind = 1  # DHRUV_AYANAMSHA_TIME_UTC
@@ -45,6 +45,20 @@ class DashaHierarchy:
     system: Optional[int] = None
 
 
+@dataclass(frozen=True)
+class DashaRangePeriod:
+    """A dasha period overlapping a query range, clipped to it.
+
+    ``period``: DashaPeriod whose start/end are clipped to the range.
+    ``clipped_start``: the period began before the range start.
+    ``clipped_end``: the period ends after the range end.
+    """
+
+    period: DashaPeriod
+    clipped_start: bool
+    clipped_end: bool
+
+
 # ---------------------------------------------------------------------------
 # Helpers
 # ---------------------------------------------------------------------------
@@ -111,6 +125,11 @@ def _make_riseset_config(riseset_config):
     cfg.use_refraction = riseset_config.get("use_refraction", 1)
     cfg.sun_limb = riseset_config.get("sun_limb", 0)
     cfg.altitude_correction = riseset_config.get("altitude_correction", 0)
+    cfg.refraction_model = riseset_config.get("refraction_model", 0)
+    cfg.refraction_pressure_hpa = riseset_config.get("refraction_pressure_hpa", 1010.0)
+    cfg.refraction_temperature_c = riseset_config.get("refraction_temperature_c", 10.0)
+    cfg.refraction_arcmin = riseset_config.get("refraction_arcmin", 34.0)
+    cfg.day_fallback = riseset_config.get("day_fallback", 0)
     return cfg
 
 
@@ -164,6 +183,7 @@ def _make_variation_config(variation_config):
     cfg.level_methods = default.level_methods
     cfg.yogini_scheme = default.yogini_scheme
     cfg.use_abhijit = default.use_abhijit
+    cfg.year_length = default.year_length
     for idx, method in enumerate(variation_config.get("level_methods", [])):
         if idx >= 5:
             break
@@ -172,6 +192,8 @@ def _make_variation_config(variation_config):
         cfg.yogini_scheme = variation_config["yogini_scheme"]
     if "use_abhijit" in variation_config:
         cfg.use_abhijit = 1 if variation_config["use_abhijit"] else 0
+    if "year_length" in variation_config:
+        cfg.year_length = variation_config["year_length"]
     return cfg
 
 
@@ -508,6 +530,7 @@ def dasha_level0(
     use_nutation=1,
     bhava_config=None,
     riseset_config=None,
+    variation_config=None,
     *,
     birth_jd=None,
     inputs=None,
@@ -524,6 +547,7 @@ def dasha_level0(
         inputs,
     )[0]
     request.system = system
+    request.variation = _make_variation_config(variation_config)[0]
     handle = ffi.new("void **")
     check(
         lib.dhruv_dasha_level0(
@@ -549,6 +573,7 @@ def dasha_level0_entity(
     use_nutation=1,
     bhava_config=None,
     riseset_config=None,
+    variation_config=None,
     *,
     birth_jd=None,
     inputs=None,
@@ -568,6 +593,7 @@ def dasha_level0_entity(
         inputs,
     )[0]
     request.system = system
+    request.variation = _make_variation_config(variation_config)[0]
     request.entity_type = entity_type
     request.entity_index = entity_index
     found = ffi.new("uint8_t *")
@@ -737,3 +763,90 @@ def dasha_complete_level(
         "dasha_complete_level",
     )
     return _extract_period_list(handle[0])
+
+
+def dasha_transitions(
+    engine,
+    lsk,
+    eop,
+    jd_utc_birth=None,
+    jd_utc_start=None,
+    jd_utc_end=None,
+    location=None,
+    level=1,
+    system=0,
+    ayanamsha_system=0,
+    use_nutation=1,
+    bhava_config=None,
+    riseset_config=None,
+    variation_config=None,
+    *,
+    birth_jd=None,
+    start_jd=None,
+    end_jd=None,
+    inputs=None,
+):
+    """Periods at one level that overlap a date range, clipped to it.
+
+    The range is given as UTC tuples (``jd_utc_start``/``jd_utc_end``) or
+    JD UTC (``start_jd``/``end_jd``). Returns a list of DashaRangePeriod in
+    start order.
+    """
+    request = ffi.new("DhruvDashaTransitionsRequest *")
+    request.birth = _make_dasha_birth_context(
+        jd_utc_birth,
+        location,
+        ayanamsha_system,
+        use_nutation,
+        bhava_config,
+        riseset_config,
+        birth_jd,
+        inputs,
+    )[0]
+    request.system = system
+    request.level = level
+    request.variation = _make_variation_config(variation_config)[0]
+    if start_jd is not None:
+        request.start_time_kind = DHRUV_DASHA_TIME_JD_UTC
+        request.start_jd = start_jd
+    else:
+        if jd_utc_start is None:
+            raise ValueError("start_jd or jd_utc_start is required")
+        request.start_time_kind = DHRUV_DASHA_TIME_UTC
+        request.start_utc = _make_utc(jd_utc_start)[0]
+    if end_jd is not None:
+        request.end_time_kind = DHRUV_DASHA_TIME_JD_UTC
+        request.end_jd = end_jd
+    else:
+        if jd_utc_end is None:
+            raise ValueError("end_jd or jd_utc_end is required")
+        request.end_time_kind = DHRUV_DASHA_TIME_UTC
+        request.end_utc = _make_utc(jd_utc_end)[0]
+    handle = ffi.new("void **")
+    check(
+        lib.dhruv_dasha_transitions(
+            engine._ptr,
+            eop,
+            request,
+            handle,
+        ),
+        "dasha_transitions",
+    )
+    h = handle[0]
+    try:
+        count_out = ffi.new("uint32_t *")
+        check(lib.dhruv_dasha_range_period_list_count(h, count_out), "range_period_list_count")
+        out = ffi.new("DhruvDashaRangePeriod *")
+        periods = []
+        for idx in range(count_out[0]):
+            check(lib.dhruv_dasha_range_period_list_at(h, idx, out), "range_period_list_at")
+            periods.append(
+                DashaRangePeriod(
+                    period=_extract_period(out.period),
+                    clipped_start=bool(out.clipped_start),
+                    clipped_end=bool(out.clipped_end),
+                )
+            )
+        return periods
+    finally:
+        lib.dhruv_dasha_range_period_list_free(h)
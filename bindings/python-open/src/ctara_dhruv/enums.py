@@ -143,7 +143,7 @@ class AyanamshaMode(IntEnum):
 # ---------------------------------------------------------------------------
 
 class BhavaSystem(IntEnum):
-    """10 bhava (house) systems.  Codes match C ABI (0-9)."""
+    """11 bhava (house) systems.  Codes match C ABI (0-10)."""
 
     EQUAL = 0
     SURYA_SIDDHANTA = 1
@@ -155,6 +155,7 @@ class BhavaSystem(IntEnum):
     AXIAL_ROTATION = 7
     TOPOCENTRIC = 8
     ALCABITUS = 9
+    PORPHYRY = 10
 
 
 # ---------------------------------------------------------------------------
@@ -248,11 +249,11 @@ class MaxSpeedType(IntEnum):
 
 
 # ---------------------------------------------------------------------------
-# Dasha systems  (DashaSystem repr(u8), 0-22)
+# Dasha systems  (DashaSystem repr(u8), 0-24)
 # ---------------------------------------------------------------------------
 
 class DashaSystem(IntEnum):
-    """23 dasha systems.  Codes match DashaSystem repr(u8)."""
+    """25 dasha systems.  Codes match DashaSystem repr(u8)."""
 
     # Nakshatra-based (10)
     VIMSHOTTARI = 0
@@ -283,6 +284,10 @@ class DashaSystem(IntEnum):
     KENDRADI = 20
     KARAKA_KENDRADI = 21
     KARAKA_KENDRADI_GRAHA = 22
+    # 28-nakshatra variant (1)
+    VIMSHOTTARI_28 = 23
+    # Jaimini padakrama (1)
+    NARAYANA = 24
 
 
 # ---------------------------------------------------------------------------
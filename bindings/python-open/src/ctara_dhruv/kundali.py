@@ -124,6 +124,11 @@ def _make_riseset_config(riseset_config):
     cfg.use_refraction = riseset_config.get("use_refraction", 1)
     cfg.sun_limb = riseset_config.get("sun_limb", 0)
     cfg.altitude_correction = riseset_config.get("altitude_correction", 0)
+    cfg.refraction_model = riseset_config.get("refraction_model", 0)
+    cfg.refraction_pressure_hpa = riseset_config.get("refraction_pressure_hpa", 1010.0)
+    cfg.refraction_temperature_c = riseset_config.get("refraction_temperature_c", 10.0)
+    cfg.refraction_arcmin = riseset_config.get("refraction_arcmin", 34.0)
+    cfg.day_fallback = riseset_config.get("day_fallback", 0)
     return cfg
 
 
@@ -138,6 +143,12 @@ def _make_sankranti_config(sankranti_config):
     cfg.step_size_days = sankranti_config.get("step_size_days", 1.0)
     cfg.max_iterations = sankranti_config.get("max_iterations", 50)
     cfg.convergence_days = sankranti_config.get("convergence_days", 1e-10)
+    cfg.use_custom_ayanamsha = 1 if sankranti_config.get("use_custom_ayanamsha", False) else 0
+    cfg.custom_ayanamsha_anchor_deg = sankranti_config.get("custom_ayanamsha_anchor_deg", 0.0)
+    cfg.custom_ayanamsha_anchor_jd_tdb = sankranti_config.get(
+        "custom_ayanamsha_anchor_jd_tdb", 0.0
+    )
+    cfg.samvatsara_scheme = sankranti_config.get("samvatsara_scheme", 0)
     return cfg
 
 
@@ -151,18 +162,27 @@ def _make_graha_longitudes_config(config):
         use_nutation = config.use_nutation
         precession_model = config.precession_model
         reference_plane = config.reference_plane
+        use_custom_ayanamsha = config.use_custom_ayanamsha
+        custom_ayanamsha_anchor_deg = config.custom_ayanamsha_anchor_deg
+        custom_ayanamsha_anchor_jd_tdb = config.custom_ayanamsha_anchor_jd_tdb
     else:
         kind = config.get("kind", 0)
         ayanamsha_system = config.get("ayanamsha_system", 0)
         use_nutation = config.get("use_nutation", False)
         precession_model = config.get("precession_model", 3)
         reference_plane = config.get("reference_plane", -1)
+        use_custom_ayanamsha = config.get("use_custom_ayanamsha", False)
+        custom_ayanamsha_anchor_deg = config.get("custom_ayanamsha_anchor_deg", 0.0)
+        custom_ayanamsha_anchor_jd_tdb = config.get("custom_ayanamsha_anchor_jd_tdb", 0.0)
     cfg = ffi.new("DhruvGrahaLongitudesConfig *")
     cfg.kind = kind
     cfg.ayanamsha_system = ayanamsha_system
     cfg.use_nutation = 1 if use_nutation else 0
     cfg.precession_model = precession_model
     cfg.reference_plane = reference_plane
+    cfg.use_custom_ayanamsha = 1 if use_custom_ayanamsha else 0
+    cfg.custom_ayanamsha_anchor_deg = custom_ayanamsha_anchor_deg
+    cfg.custom_ayanamsha_anchor_jd_tdb = custom_ayanamsha_anchor_jd_tdb
     return cfg
 
 
@@ -175,6 +195,9 @@ def _graha_entry_from_ffi(e):
         pada=e.pada,
         bhava_number=e.bhava_number,
         rashi_bhava_number=e.rashi_bhava_number,
+        gandanta=bool(e.gandanta),
+        rashi_sandhi=bool(e.rashi_sandhi),
+        pushkara=bool(e.pushkara),
     )
 
 
@@ -221,6 +244,26 @@ def graha_longitudes(engine, jd_tdb, config=None, ayanamsha_system=0, use_nutati
     return GrahaLongitudes(longitudes=[out.longitudes[i] for i in range(9)], outer_planets=outer)
 
 
+def graha_speeds(engine, jd_tdb, config=None):
+    """Return longitude speeds (deg/day) of 9 grahas as a list of 9 floats.
+
+    Speeds are the rates of the longitudes `graha_longitudes` returns for the
+    same config; a negative value means the graha is retrograde.
+
+    Args:
+        engine: Engine instance (use engine._ptr).
+        jd_tdb: Julian date in TDB.
+        config: Optional `GrahaLongitudesConfig` or dict (default sidereal Lahiri).
+    """
+    cfg = _make_graha_longitudes_config(config)
+    out = ffi.new("DhruvGrahaSpeeds *")
+    check(
+        lib.dhruv_graha_speeds(engine._ptr, jd_tdb, cfg, out),
+        "graha_speeds",
+    )
+    return [out.speeds[i] for i in range(9)]
+
+
 def moving_osculating_apogees_for_date(
     engine,
     eop,
@@ -298,7 +341,8 @@ def graha_positions(
         ayanamsha_system: Ayanamsha system code.
         use_nutation: 1=apply nutation, 0=skip.
         config: Optional dict with keys include_nakshatra, include_lagna,
-                include_outer_planets, include_bhava (all u8 0/1).
+                include_outer_planets, include_bhava, include_flags
+                (all u8 0/1).
         bhava_config: Optional dict for bhava system config.
         sankranti_config: Optional dict for sankranti config.
 
@@ -315,6 +359,7 @@ def graha_positions(
         cfg.include_lagna = config.get("include_lagna", 0)
         cfg.include_outer_planets = config.get("include_outer_planets", 1)
         cfg.include_bhava = config.get("include_bhava", 0)
+        cfg.include_flags = config.get("include_flags", 0)
     else:
         cfg = ffi.NULL
 
@@ -541,6 +586,8 @@ def _extract_shadbala_entry(e):
         total_rupas=e.total_rupas,
         required_strength=e.required_strength,
         is_strong=bool(e.is_strong),
+        ishta=e.ishta,
+        kashta=e.kashta,
     )
 
 
@@ -700,6 +747,8 @@ def _extract_panchang_info(p):
 
     return PanchangInfo(
         tithi=tithi,
+        tithi_day_kind=p.tithi_day_kind,
+        tithi_day_tithi_index=p.tithi_day_tithi_index,
         karana=karana,
         yoga=yoga,
         vaar=vaar,
@@ -825,6 +874,7 @@ def full_kundali(
                 bhavas.append(BhavaEntry(
                     number=b.number, cusp_deg=b.cusp_deg,
                     start_deg=b.start_deg, end_deg=b.end_deg,
+                    madhya_deg=b.madhya_deg,
                 ))
             bhava_cusps = BhavaResult(
                 bhavas=bhavas,
@@ -840,6 +890,7 @@ def full_kundali(
                 bhavas.append(BhavaEntry(
                     number=b.number, cusp_deg=b.cusp_deg,
                     start_deg=b.start_deg, end_deg=b.end_deg,
+                    madhya_deg=b.madhya_deg,
                 ))
             rashi_bhava_cusps = BhavaResult(
                 bhavas=bhavas,
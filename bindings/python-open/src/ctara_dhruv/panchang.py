@@ -9,11 +9,15 @@ from typing import Optional, Union
 
 from ._ffi import ffi, lib
 from ._check import check
+from .kundali import _extract_panchang_info
 from .types import (
     GeoLocation,
+    PanchangDiff,
+    PanchangLimbDiff,
     PanchangResult,
     SamvatsaraResult,
     TithiInfo,
+    TithiDayInfo,
     KaranaInfo,
     YogaInfo,
     VaarInfo,
@@ -45,6 +49,18 @@ INCLUDE_ALL_CORE = 0x7F
 INCLUDE_ALL_CALENDAR = 0x380
 INCLUDE_ALL = 0x3FF
 
+# Panchang diff limb codes (match C ABI)
+LIMB_TITHI = 0
+LIMB_KARANA = 1
+LIMB_YOGA = 2
+LIMB_VAAR = 3
+LIMB_HORA = 4
+LIMB_GHATIKA = 5
+LIMB_NAKSHATRA = 6
+LIMB_MASA = 7
+LIMB_AYANA = 8
+LIMB_VARSHA = 9
+
 # Time kind constants
 _TIME_JD_TDB = 0
 _TIME_UTC = 1
@@ -189,6 +205,8 @@ def _panchang_result_from_c(out) -> PanchangResult:
     """Convert DhruvPanchangOperationResult to PanchangResult."""
     return PanchangResult(
         tithi=_tithi_from_c(out.tithi) if out.tithi_valid else None,
+        tithi_day_kind=out.tithi_day_kind if out.tithi_valid else None,
+        tithi_day_tithi_index=out.tithi_day_tithi_index if out.tithi_valid else None,
         karana=_karana_from_c(out.karana) if out.karana_valid else None,
         yoga=_yoga_from_c(out.yoga) if out.yoga_valid else None,
         vaar=_vaar_from_c(out.vaar) if out.vaar_valid else None,
@@ -261,6 +279,47 @@ def panchang(
     return _panchang_result_from_c(out[0])
 
 
+def panchang_diff(
+    engine,
+    eop,
+    utc: UtcTime,
+    location: GeoLocation,
+    config_a=None,
+    config_b=None,
+    riseset_config=None,
+) -> PanchangDiff:
+    """Compare the panchang for one date under two sankranti configurations.
+
+    ``limbs`` lists the elements whose value differs or whose boundaries
+    shift by more than 1 ms; it is empty when the configurations agree.
+    ``None`` configs use the library defaults.
+    """
+    c_utc = _make_utc_c(utc)
+    c_loc = _make_location_c(location)
+    rs_cfg = riseset_config if riseset_config is not None else ffi.NULL
+    cfg_a = config_a if config_a is not None else ffi.NULL
+    cfg_b = config_b if config_b is not None else ffi.NULL
+    out = ffi.new("DhruvPanchangDiff *")
+    check(
+        lib.dhruv_panchang_diff(engine, eop, c_utc, c_loc, rs_cfg, cfg_a, cfg_b, out),
+        "panchang_diff",
+    )
+    limbs = [
+        PanchangLimbDiff(
+            limb=d.limb,
+            value_differs=bool(d.value_differs),
+            start_shift_s=d.start_shift_s,
+            end_shift_s=d.end_shift_s,
+        )
+        for d in out.limbs[0 : out.limb_count]
+    ]
+    return PanchangDiff(
+        a=_extract_panchang_info(out.a),
+        b=_extract_panchang_info(out.b),
+        limbs=limbs,
+    )
+
+
 # ---------------------------------------------------------------------------
 # Individual _for_date functions
 # ---------------------------------------------------------------------------
@@ -305,6 +364,21 @@ def nakshatra_for_date(engine, utc: UtcTime, config=None) -> PanchangNakshatraIn
     return _nakshatra_from_c(out[0])
 
 
+def tithi_day_kind_for_date(
+    engine, eop, utc: UtcTime, location: GeoLocation, riseset_config=None
+) -> TithiDayInfo:
+    """Classify the Vedic day containing a UTC date as a normal, kshaya, or vriddhi tithi day."""
+    c_utc = _make_utc_c(utc)
+    c_loc = _make_location_c(location)
+    rs_cfg = riseset_config if riseset_config is not None else ffi.NULL
+    out = ffi.new("DhruvTithiDayInfo *")
+    check(
+        lib.dhruv_tithi_day_kind_for_date(engine, eop, c_utc, c_loc, rs_cfg, out),
+        "tithi_day_kind_for_date",
+    )
+    return TithiDayInfo(kind=out[0].kind, tithi_index=out[0].tithi_index)
+
+
 def vaar_for_date(
     engine, eop, utc: UtcTime, location: GeoLocation, riseset_config=None
 ) -> VaarInfo:
@@ -419,19 +493,20 @@ def nakshatra_at(
 # ---------------------------------------------------------------------------
 
 
-def samvatsara_from_year(ce_year: int) -> SamvatsaraResult:
+def samvatsara_from_year(ce_year: int, scheme: int = 0) -> SamvatsaraResult:
     """Determine Samvatsara (Jovian year) from a CE year.
 
     Pure math, no engine needed.
 
     Args:
         ce_year: Common Era year (e.g. 2025).
+        scheme: 0=SouthIndian (default), 1=NorthIndian, 2=Barhaspatya.
 
     Returns:
         SamvatsaraResult with 0-based index and 1-based cycle position.
     """
     out = ffi.new("DhruvSamvatsaraResult *")
-    check(lib.dhruv_samvatsara_from_year(ce_year, out), "samvatsara_from_year")
+    check(lib.dhruv_samvatsara_from_year(ce_year, scheme, out), "samvatsara_from_year")
     return SamvatsaraResult(
         samvatsara_index=out.samvatsara_index,
         cycle_position=out.cycle_position,
@@ -1,4 +1,4 @@
-"""Unified search APIs for conjunction, eclipse, motion, lunar phase, and sankranti.
+"""Unified search APIs for conjunction, eclipse, motion, heliacal, lunar phase, and sankranti.
 
 All functions use the unified ``*_search_ex`` FFI entrypoints introduced in ABI v42.
 """
@@ -14,9 +14,15 @@ from .types import (
     ChandraGrahanResult,
     SuryaGrahanResult,
     StationaryEvent,
+    NodeStationEvent,
+    RetrogradeInterval,
+    HeliacalEvent,
     MaxSpeedEvent,
+    GeoLocation,
     LunarPhaseEvent,
     SankrantiEvent,
+    IngressEvent,
+    NakshatraIngressEvent,
     UtcTime,
 )
 
@@ -535,6 +541,66 @@ def search_stationary(
     return _collect_full_range(fetch, max_results)
 
 
+def retrograde_intervals(
+    engine,
+    body_code: int,
+    start_jd_tdb: float,
+    end_jd_tdb: float,
+    config=None,
+    max_results: int = 50,
+) -> list[RetrogradeInterval]:
+    """List the retrograde spans of a body in a JD(TDB) range."""
+    cfg = ffi.new("DhruvStationaryConfig *")
+    cfg[0] = config if config is not None else lib.dhruv_stationary_config_default()
+
+    def fetch(capacity: int):
+        out_intervals = ffi.new("DhruvRetrogradeInterval[]", capacity)
+        out_count = ffi.new("uint32_t *")
+        check(
+            lib.dhruv_retrograde_intervals(
+                engine, body_code, start_jd_tdb, end_jd_tdb, cfg,
+                out_intervals, capacity, out_count,
+            ),
+            "retrograde_intervals",
+        )
+        count = int(out_count[0])
+        intervals = [
+            RetrogradeInterval(
+                start_jd_tdb=out_intervals[i].start_jd_tdb,
+                end_jd_tdb=out_intervals[i].end_jd_tdb,
+                start_utc=_utc_from_c(out_intervals[i].start_utc),
+                end_utc=_utc_from_c(out_intervals[i].end_utc),
+                start_lon_deg=out_intervals[i].start_lon_deg,
+                end_lon_deg=out_intervals[i].end_lon_deg,
+                open_start=out_intervals[i].open_start != 0,
+                open_end=out_intervals[i].open_end != 0,
+            )
+            for i in range(count)
+        ]
+        return (intervals, count)
+
+    return _collect_full_range(fetch, max_results)
+
+
+def next_node_station(engine, after_jd_tdb: float) -> Optional[NodeStationEvent]:
+    """Find the next true (osculating) lunar node station after a JD(TDB)."""
+    out_event = ffi.new("DhruvNodeStationEvent *")
+    out_found = ffi.new("uint8_t *")
+    check(
+        lib.dhruv_next_node_station(engine, after_jd_tdb, out_event, out_found),
+        "next_node_station",
+    )
+    if out_found[0] == 0:
+        return None
+    e = out_event[0]
+    return NodeStationEvent(
+        utc=_utc_from_c(e.utc),
+        jd_tdb=e.jd_tdb,
+        rahu_longitude_deg=e.rahu_longitude_deg,
+        station_type=e.station_type,
+    )
+
+
 def next_max_speed(
     engine, body_code: int, after_jd, config=None
 ) -> Optional[MaxSpeedEvent]:
@@ -582,6 +648,138 @@ def search_max_speeds(
     return _collect_full_range(fetch, max_results)
 
 
+# ---------------------------------------------------------------------------
+# Heliacal search (dhruv_heliacal_search_ex)
+# ---------------------------------------------------------------------------
+
+HELIACAL_RISING = 0
+HELIACAL_SETTING = 1
+
+_HELIACAL_TARGET_BODY = 0
+_HELIACAL_TARGET_TARA = 1
+_HELIACAL_NEXT = 0
+_HELIACAL_PREV = 1
+_HELIACAL_RANGE = 2
+
+
+def heliacal_config_default():
+    """Return the default DhruvHeliacalConfig (10 deg arcus visionis)."""
+    return lib.dhruv_heliacal_config_default()
+
+
+def heliacal_config_for_body(body_code: int):
+    """Return the traditional DhruvHeliacalConfig for a planet."""
+    out = ffi.new("DhruvHeliacalConfig *")
+    check(lib.dhruv_heliacal_config_for_body(body_code, out), "heliacal_config_for_body")
+    return out[0]
+
+
+def _heliacal_event(e) -> HeliacalEvent:
+    return HeliacalEvent(
+        utc=_utc_from_c(e.utc),
+        jd_tdb=e.jd_tdb,
+        kind=e.event_kind,
+        altitude_deg=e.altitude_deg,
+        elongation_deg=e.elongation_deg,
+    )
+
+
+def _heliacal_request(target, query_mode: int, config):
+    """Internal: build the request; ``target`` is a body code or ``(catalog, tara_id)``."""
+    req = ffi.new("DhruvHeliacalSearchRequest *")
+    req.query_mode = query_mode
+    if isinstance(target, tuple):
+        catalog, tara_id = target
+        req.target_kind = _HELIACAL_TARGET_TARA
+        req.tara_id = tara_id
+        catalog_ptr = catalog._ptr
+        default_config = lib.dhruv_heliacal_config_default()
+    else:
+        req.target_kind = _HELIACAL_TARGET_BODY
+        req.body_code = target
+        catalog_ptr = ffi.NULL
+        default_config = heliacal_config_for_body(target)
+    req.config = config if config is not None else default_config
+    return req, catalog_ptr
+
+
+def _heliacal_location(location: GeoLocation):
+    g = ffi.new("DhruvGeoLocation *")
+    g.latitude_deg = location.lat_deg
+    g.longitude_deg = location.lon_deg
+    g.altitude_m = location.alt_m
+    return g
+
+
+def _heliacal_single(engine, eop, target, location, kind: int, query_mode: int, when, config):
+    req, catalog_ptr = _heliacal_request(target, query_mode, config)
+    req.event_kind = kind
+    _set_single_search_time(req, when, arg_name="jd")
+
+    out_event = ffi.new("DhruvHeliacalEvent *")
+    out_found = ffi.new("uint8_t *")
+    check(
+        lib.dhruv_heliacal_search_ex(
+            engine, eop, catalog_ptr, _heliacal_location(location), req,
+            out_event, out_found, ffi.NULL, 0, ffi.NULL,
+        ),
+        "heliacal_search_ex(single)",
+    )
+    if out_found[0] == 0:
+        return None
+    return _heliacal_event(out_event[0])
+
+
+def next_heliacal_event(
+    engine, eop, target, location: GeoLocation, kind: int, after_jd, config=None
+) -> Optional[HeliacalEvent]:
+    """Find the next heliacal rising or setting after a ``UtcTime`` or JD(TDB) anchor.
+
+    ``target`` is a planet body code or a ``(TaraCatalog, tara_id)`` tuple;
+    ``kind`` is ``HELIACAL_RISING`` or ``HELIACAL_SETTING``. Without a
+    ``config``, planets use their traditional arcus visionis and stars 10 deg.
+    """
+    return _heliacal_single(engine, eop, target, location, kind, _HELIACAL_NEXT, after_jd, config)
+
+
+def prev_heliacal_event(
+    engine, eop, target, location: GeoLocation, kind: int, before_jd, config=None
+) -> Optional[HeliacalEvent]:
+    """Find the previous heliacal rising or setting before a ``UtcTime`` or JD(TDB) anchor."""
+    return _heliacal_single(engine, eop, target, location, kind, _HELIACAL_PREV, before_jd, config)
+
+
+def search_heliacal_events(
+    engine,
+    eop,
+    target,
+    location: GeoLocation,
+    start_jd,
+    end_jd,
+    config=None,
+    max_results: int = 100,
+) -> list[HeliacalEvent]:
+    """Search for heliacal risings and settings in a UTC or JD(TDB) range."""
+    req, catalog_ptr = _heliacal_request(target, _HELIACAL_RANGE, config)
+    _set_range_search_time(req, start_jd, end_jd, start_name="start_jd", end_name="end_jd")
+    geo = _heliacal_location(location)
+
+    def fetch(capacity: int):
+        out_events = ffi.new("DhruvHeliacalEvent[]", capacity)
+        out_count = ffi.new("uint32_t *")
+        check(
+            lib.dhruv_heliacal_search_ex(
+                engine, eop, catalog_ptr, geo, req,
+                ffi.NULL, ffi.NULL, out_events, capacity, out_count,
+            ),
+            "heliacal_search_ex(range)",
+        )
+        count = int(out_count[0])
+        return ([_heliacal_event(out_events[i]) for i in range(count)], count)
+
+    return _collect_full_range(fetch, max_results)
+
+
 # ---------------------------------------------------------------------------
 # Lunar phase search (dhruv_lunar_phase_search_ex)
 # ---------------------------------------------------------------------------
@@ -802,3 +1000,67 @@ def search_sankrantis(
         return ([_sankranti_event(out_events[i]) for i in range(count)], count)
 
     return _collect_full_range(fetch, max_results)
+
+
+def moon_rashi_ingresses(
+    engine,
+    start_utc: UtcTime,
+    end_utc: UtcTime,
+    config=None,
+    max_results: int = 50,
+) -> list[IngressEvent]:
+    """List every Moon entry into a sidereal rashi in a UTC range."""
+    start = _utc_struct(start_utc)
+    end = _utc_struct(end_utc)
+    cfg = ffi.new("DhruvSankrantiConfig *")
+    cfg[0] = config if config is not None else lib.dhruv_sankranti_config_default()
+
+    def fetch(capacity: int):
+        out_events = ffi.new("DhruvIngressEvent[]", capacity)
+        out_count = ffi.new("uint32_t *")
+        check(
+            lib.dhruv_moon_rashi_ingresses(
+                engine, start, end, cfg,
+                out_events, capacity, out_count,
+            ),
+            "moon_rashi_ingresses",
+        )
+        count = int(out_count[0])
+        events = [
+            IngressEvent(
+                utc=_utc_from_c(out_events[i].utc),
+                rashi_index=out_events[i].rashi_index,
+                jd_tdb=out_events[i].jd_tdb,
+            )
+            for i in range(count)
+        ]
+        return (events, count)
+
+    return _collect_full_range(fetch, max_results)
+
+
+def next_nakshatra_ingress(
+    engine, body_code: int, after_jd_tdb: float, config=None
+) -> Optional[NakshatraIngressEvent]:
+    """Find the next time a body enters a new sidereal nakshatra after a JD(TDB)."""
+    cfg = ffi.new("DhruvSankrantiConfig *")
+    cfg[0] = config if config is not None else lib.dhruv_sankranti_config_default()
+    out_event = ffi.new("DhruvNakshatraIngressEvent *")
+    out_found = ffi.new("uint8_t *")
+    check(
+        lib.dhruv_next_nakshatra_ingress(
+            engine, body_code, after_jd_tdb, cfg, out_event, out_found,
+        ),
+        "next_nakshatra_ingress",
+    )
+    if out_found[0] == 0:
+        return None
+    e = out_event[0]
+    return NakshatraIngressEvent(
+        utc=_utc_from_c(e.utc),
+        jd_tdb=e.jd_tdb,
+        body_code=e.body_code,
+        nakshatra_index=e.nakshatra_index,
+        boundary_deg=e.boundary_deg,
+        retrograde=e.retrograde != 0,
+    )
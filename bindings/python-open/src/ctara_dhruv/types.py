@@ -220,6 +220,9 @@ class GrahaLongitudesConfig:
     use_nutation: bool = False
     precession_model: int = 3
     reference_plane: int = -1
+    use_custom_ayanamsha: bool = False
+    custom_ayanamsha_anchor_deg: float = 0.0
+    custom_ayanamsha_anchor_jd_tdb: float = 0.0
 
 
 @dataclass(frozen=True)
@@ -288,6 +291,33 @@ class Nakshatra28Info:
     degrees_in_nakshatra: float
 
 
+@dataclass(frozen=True)
+class KpSublord:
+    """KP (Krishnamurti Paddhati) star lord and sub lord of a longitude.
+
+    ``nakshatra_index``: 0-based (0=Ashwini .. 26=Revati).
+    ``star_lord`` / ``sub_lord``: graha index (0-8).
+    """
+
+    nakshatra_index: int
+    star_lord: int
+    sub_lord: int
+
+
+@dataclass(frozen=True)
+class GandantaStatus:
+    """Gandanta placement of a sidereal longitude.
+
+    ``kind``: junction code (0=Meena/Mesha, 1=Karka/Simha, 2=Vrischika/Dhanu).
+    ``half``: 0=approaching (water rashi), 1=leaving (fire rashi).
+    ``offset_deg``: distance from the exact junction, [0, 3°20').
+    """
+
+    kind: int
+    half: int
+    offset_deg: float
+
+
 # ---------------------------------------------------------------------------
 # Bhava (House Systems)
 # ---------------------------------------------------------------------------
@@ -300,12 +330,14 @@ class BhavaEntry:
     ``number``: bhava number 1-12.
     ``cusp_deg``: cusp longitude [0, 360).
     ``start_deg`` / ``end_deg``: span in degrees.
+    ``madhya_deg``: bhava madhya (middle) [0, 360).
     """
 
     number: int
     cusp_deg: float
     start_deg: float
     end_deg: float
+    madhya_deg: float
 
 
 @dataclass(frozen=True)
@@ -427,6 +459,52 @@ class StationaryEvent:
     station_type: int
 
 
+@dataclass(frozen=True)
+class NodeStationEvent:
+    """True (osculating) lunar node station event.
+
+    ``station_type``: 0=retrograde, 1=direct. Ketu stations at the same instant.
+    """
+
+    utc: UtcTime
+    jd_tdb: float
+    rahu_longitude_deg: float
+    station_type: int
+
+
+@dataclass(frozen=True)
+class RetrogradeInterval:
+    """Retrograde (vakra) span between a retrograde and a direct station.
+
+    ``open_start`` / ``open_end`` mark spans already retrograde at the range
+    start or still retrograde at the range end; those ends are the range bounds.
+    """
+
+    start_jd_tdb: float
+    end_jd_tdb: float
+    start_utc: UtcTime
+    end_utc: UtcTime
+    start_lon_deg: float
+    end_lon_deg: float
+    open_start: bool
+    open_end: bool
+
+
+@dataclass(frozen=True)
+class HeliacalEvent:
+    """Heliacal rising or setting of a planet or star.
+
+    ``kind``: 0=rising (first visible dawn), 1=setting (last visible dusk).
+    The time is the twilight instant the visibility test was made at.
+    """
+
+    utc: UtcTime
+    jd_tdb: float
+    kind: int
+    altitude_deg: float
+    elongation_deg: float
+
+
 @dataclass(frozen=True)
 class MaxSpeedEvent:
     """Peak-speed event.
@@ -469,6 +547,34 @@ class SankrantiEvent:
     sun_tropical_longitude_deg: float
 
 
+@dataclass(frozen=True)
+class IngressEvent:
+    """Moon entering a sidereal rashi.
+
+    ``rashi_index``: 0-based rashi entered (0=Mesha .. 11=Meena).
+    """
+
+    utc: UtcTime
+    rashi_index: int
+    jd_tdb: float
+
+
+@dataclass(frozen=True)
+class NakshatraIngressEvent:
+    """A body entering a sidereal nakshatra.
+
+    ``nakshatra_index``: 0-based nakshatra entered (0=Ashwini .. 26=Revati).
+    ``retrograde``: True when the body re-entered the previous nakshatra.
+    """
+
+    utc: UtcTime
+    jd_tdb: float
+    body_code: int
+    nakshatra_index: int
+    boundary_deg: float
+    retrograde: bool
+
+
 # ---------------------------------------------------------------------------
 # Pure-math Panchang classifiers
 # ---------------------------------------------------------------------------
@@ -573,6 +679,18 @@ class YogaInfo:
     end: UtcTime
 
 
+@dataclass(frozen=True)
+class TithiDayInfo:
+    """Tithi classification of a Vedic day (sunrise to next sunrise).
+
+    ``kind``: ``DHRUV_TITHI_DAY_*`` code (0=normal, 1=kshaya, 2=vriddhi).
+    ``tithi_index``: skipped or repeated tithi 0-29, -1 when normal.
+    """
+
+    kind: int
+    tithi_index: int
+
+
 @dataclass(frozen=True)
 class VaarInfo:
     """Vaar (weekday) with time boundaries.
@@ -670,9 +788,14 @@ class PanchangResult:
     """Combined panchang result with optional calendar fields.
 
     Each field is ``None`` when not requested or not computed.
+    ``tithi_day_kind`` is a ``DHRUV_TITHI_DAY_*`` code (0=normal,
+    1=kshaya, 2=vriddhi) and ``tithi_day_tithi_index`` the skipped or
+    repeated tithi (-1 when normal); both are set with ``tithi``.
     """
 
     tithi: Optional[TithiInfo] = None
+    tithi_day_kind: Optional[int] = None
+    tithi_day_tithi_index: Optional[int] = None
     karana: Optional[KaranaInfo] = None
     yoga: Optional[YogaInfo] = None
     vaar: Optional[VaarInfo] = None
@@ -692,6 +815,8 @@ class PanchangInfo:
     """
 
     tithi: TithiInfo
+    tithi_day_kind: int
+    tithi_day_tithi_index: int
     karana: KaranaInfo
     yoga: YogaInfo
     vaar: VaarInfo
@@ -704,6 +829,29 @@ class PanchangInfo:
     varsha: Optional[VarshaInfo] = None
 
 
+@dataclass(frozen=True)
+class PanchangLimbDiff:
+    """One panchang element that differs between two configurations.
+
+    ``limb`` is a ``LIMB_*`` code from ``ctara_dhruv.panchang``; shifts are
+    config B minus config A, in seconds.
+    """
+
+    limb: int
+    value_differs: bool
+    start_shift_s: float
+    end_shift_s: float
+
+
+@dataclass(frozen=True)
+class PanchangDiff:
+    """Side-by-side panchang under two configurations. Matches ``DhruvPanchangDiff``."""
+
+    a: PanchangInfo
+    b: PanchangInfo
+    limbs: list[PanchangLimbDiff]
+
+
 # ---------------------------------------------------------------------------
 # Sphuta
 # ---------------------------------------------------------------------------
@@ -813,6 +961,24 @@ class AshtakavargaResult:
     sav: SarvaAshtakavarga
 
 
+@dataclass(frozen=True)
+class TransitBindus:
+    """Natal ashtakavarga bindus under a transiting graha.
+
+    ``house``: natal house 1-12 the graha is scored in.
+    ``rashi_index``: 0-based rashi whose bindus were read.
+    ``bav_bindus``: the graha's own BAV bindus (None for Rahu/Ketu).
+    ``sav_bindus``: SAV total bindus in that rashi.
+    """
+
+    graha_index: int
+    transit_longitude_deg: float
+    house: int
+    rashi_index: int
+    bav_bindus: Optional[int]
+    sav_bindus: int
+
+
 # ---------------------------------------------------------------------------
 # Drishti (Planetary Aspects)
 # ---------------------------------------------------------------------------
@@ -868,6 +1034,7 @@ class GrahaEntry:
     ``pada``: 1-4, 0 if not computed.
     ``bhava_number``: 1-12, 0 if not computed.
     ``rashi_bhava_number``: 1-12, 0 if not computed.
+    ``gandanta`` / ``rashi_sandhi`` / ``pushkara``: False if not computed.
     """
 
     sidereal_longitude: float
@@ -876,6 +1043,9 @@ class GrahaEntry:
     pada: int
     bhava_number: int
     rashi_bhava_number: int = 0
+    gandanta: bool = False
+    rashi_sandhi: bool = False
+    pushkara: bool = False
 
 
 @dataclass(frozen=True)
@@ -1021,6 +1191,7 @@ class ShadbalaEntry:
 
     ``graha_index``: 0-6 (Sun through Saturn).
     ``is_strong``: True if total meets required strength.
+    ``ishta`` / ``kashta``: Ishta and Kashta phala in virupas (0-60).
     """
 
     graha_index: int
@@ -1034,6 +1205,8 @@ class ShadbalaEntry:
     total_rupas: float
     required_strength: float
     is_strong: bool
+    ishta: float
+    kashta: float
 
 
 @dataclass(frozen=True)
@@ -22,10 +22,12 @@ from .types import (
     Dms,
     DrishtiEntry,
     DrishtiResult,
+    GandantaStatus,
     GeoLocation,
     GrahaDrishtiMatrix,
     KaranaPosition,
     Nakshatra28Info,
+    KpSublord,
     NakshatraInfo,
     PanchangNakshatraInfo,
     RashiInfo,
@@ -35,6 +37,7 @@ from .types import (
     SpecialLagnas,
     SphutalResult,
     TithiPosition,
+    TransitBindus,
     UtcTime,
     YogaPosition,
 )
@@ -333,7 +336,7 @@ def bhava_config_default():
 
 
 def bhava_system_count() -> int:
-    """Return number of supported bhava systems (currently 10)."""
+    """Return number of supported bhava systems (currently 11)."""
     return lib.dhruv_bhava_system_count()
 
 
@@ -344,6 +347,7 @@ def _extract_bhava_result(out) -> BhavaResult:
             cusp_deg=out.bhavas[i].cusp_deg,
             start_deg=out.bhavas[i].start_deg,
             end_deg=out.bhavas[i].end_deg,
+            madhya_deg=out.bhavas[i].madhya_deg,
         )
         for i in range(12)
     ]
@@ -355,6 +359,7 @@ def _extract_bhava_result(out) -> BhavaResult:
                 cusp_deg=out.rashi_bhava_bhavas[i].cusp_deg,
                 start_deg=out.rashi_bhava_bhavas[i].start_deg,
                 end_deg=out.rashi_bhava_bhavas[i].end_deg,
+                madhya_deg=out.rashi_bhava_bhavas[i].madhya_deg,
             )
             for i in range(12)
         ]
@@ -382,6 +387,17 @@ def compute_bhavas(engine, lsk, eop, location: GeoLocation,
     return _extract_bhava_result(out)
 
 
+def whole_sign_from_lagna(lagna_sid_deg: float) -> BhavaResult:
+    """Whole-sign bhavas from a stored sidereal lagna (pure math, no engine).
+
+    Cusp ``n`` is the start of rashi ``lagna_rashi + n - 1``; ``mc_deg`` is
+    the 10th cusp.
+    """
+    out = ffi.new("DhruvBhavaResult *")
+    check(lib.dhruv_whole_sign_from_lagna(lagna_sid_deg, out), "dhruv_whole_sign_from_lagna")
+    return _extract_bhava_result(out)
+
+
 def lagna_deg(lsk, eop, location: GeoLocation, jd_utc: float, config=None) -> float:
     """Compute Ascendant longitude in degrees.
 
@@ -534,6 +550,36 @@ def nakshatra28_from_longitude(sidereal_lon_deg: float) -> Nakshatra28Info:
     )
 
 
+def kp_sublord(sidereal_lon_deg: float) -> KpSublord:
+    """KP star lord and sub lord of a sidereal longitude (pure math)."""
+    out = ffi.new("DhruvKpSublord *")
+    status = lib.dhruv_kp_sublord(sidereal_lon_deg, out)
+    check(status, "dhruv_kp_sublord")
+    return KpSublord(
+        nakshatra_index=out.nakshatra_index,
+        star_lord=out.star_lord,
+        sub_lord=out.sub_lord,
+    )
+
+
+GANDANTA_MEENA_MESHA = 0
+GANDANTA_KARKA_SIMHA = 1
+GANDANTA_VRISCHIKA_DHANU = 2
+GANDANTA_APPROACHING = 0
+GANDANTA_LEAVING = 1
+
+
+def gandanta_status(sidereal_lon_deg: float) -> Optional[GandantaStatus]:
+    """Gandanta junction, half and offset of a sidereal longitude, or None (pure math)."""
+    out = ffi.new("DhruvGandantaStatus *")
+    found = ffi.new("uint8_t *")
+    status = lib.dhruv_gandanta_status(sidereal_lon_deg, out, found)
+    check(status, "dhruv_gandanta_status")
+    if not found[0]:
+        return None
+    return GandantaStatus(kind=out.kind, half=out.half, offset_deg=out.offset_deg)
+
+
 def rashi_from_tropical(tropical_lon: float, aya_system: int,
                         jd_tdb: float, use_nutation: int = 1) -> RashiInfo:
     """Classify tropical longitude into rashi (applies ayanamsha)."""
@@ -661,6 +707,16 @@ def rashi_lord(rashi_index: int) -> int:
     return lib.dhruv_rashi_lord(rashi_index)
 
 
+def nakshatra_lord(nakshatra_index: int) -> int:
+    """Return graha index (0-8) of the Vimshottari lord of a nakshatra (0-26). -1 for invalid."""
+    return lib.dhruv_nakshatra_lord(nakshatra_index)
+
+
+def navatara_from(start_nakshatra: int, target_nakshatra: int) -> int:
+    """Return navatara index (0-8, Janma = 0) of target counted from start (0-26). -1 for invalid."""
+    return lib.dhruv_navatara_from(start_nakshatra, target_nakshatra)
+
+
 def nakshatra_at(engine, jd_tdb: float, moon_sidereal_deg: float,
                  config=None) -> PanchangNakshatraInfo:
     """Compute nakshatra with time boundaries using engine.
@@ -717,6 +773,11 @@ def yogini_name(index: int) -> Optional[str]:
     return _str_or_none(lib.dhruv_yogini_name(index))
 
 
+def navatara_name(index: int) -> Optional[str]:
+    """Return navatara name by index (0-8), or None."""
+    return _str_or_none(lib.dhruv_navatara_name(index))
+
+
 def tithi_name(index: int) -> Optional[str]:
     """Return tithi name by 0-based index (0-29), or None."""
     return _str_or_none(lib.dhruv_tithi_name(index))
@@ -802,6 +863,19 @@ def lunar_node_deg(node_code: int, mode_code: int,
     return out[0]
 
 
+def node_speed_deg_per_day(mode_code: int, jd_tdb: float) -> float:
+    """Compute the Rahu longitude rate in deg/day (pure math, no engine).
+
+    Args:
+        mode_code: 0=Mean, 1=True (50-term fitted series).
+        jd_tdb: Julian Date in TDB.
+    """
+    out = ffi.new("double *")
+    status = lib.dhruv_node_speed_deg_per_day(mode_code, jd_tdb, out)
+    check(status, "dhruv_node_speed_deg_per_day")
+    return out[0]
+
+
 def lunar_node_deg_with_engine(engine, node_code: int, mode_code: int,
                                jd_tdb: float) -> float:
     """Compute lunar node longitude using engine (osculating for true mode)."""
@@ -1324,6 +1398,58 @@ def ekadhipatya_sodhana(after_trikona: list[int]) -> list[int]:
     return [dst[i] for i in range(12)]
 
 
+TRANSIT_HOUSE_WHOLE_SIGN = 0
+TRANSIT_HOUSE_CHALIT = 1
+
+
+def transit_bindus(natal: AshtakavargaResult, natal_bhavas: BhavaResult,
+                   graha_index: int, transit_lon_sid: float,
+                   house_mode: int = TRANSIT_HOUSE_WHOLE_SIGN) -> TransitBindus:
+    """Read natal BAV/SAV bindus under a transiting graha (pure math).
+
+    Args:
+        natal: natal ashtakavarga (e.g. from ``ashtakavarga_for_date``).
+        natal_bhavas: sidereal natal bhavas.
+        graha_index: 0=Surya .. 8=Ketu.
+        transit_lon_sid: sidereal transit longitude (degrees).
+        house_mode: ``TRANSIT_HOUSE_WHOLE_SIGN`` counts from the natal lagna
+            rashi; ``TRANSIT_HOUSE_CHALIT`` uses the natal bhava bounds.
+    """
+    natal_buf = ffi.new("DhruvAshtakavargaResult *")
+    for i, bav in enumerate(natal.bavs):
+        natal_buf.bavs[i].graha_index = bav.graha_index
+        for j in range(12):
+            natal_buf.bavs[i].points[j] = bav.points[j]
+            for k in range(8):
+                natal_buf.bavs[i].contributors[j][k] = bav.contributors[j][k]
+    for j in range(12):
+        natal_buf.sav.total_points[j] = natal.sav.total_points[j]
+        natal_buf.sav.after_trikona[j] = natal.sav.after_trikona[j]
+        natal_buf.sav.after_ekadhipatya[j] = natal.sav.after_ekadhipatya[j]
+    bhava_buf = ffi.new("DhruvBhavaResult *")
+    for i, b in enumerate(natal_bhavas.bhavas):
+        bhava_buf.bhavas[i].number = b.number
+        bhava_buf.bhavas[i].cusp_deg = b.cusp_deg
+        bhava_buf.bhavas[i].start_deg = b.start_deg
+        bhava_buf.bhavas[i].end_deg = b.end_deg
+        bhava_buf.bhavas[i].madhya_deg = b.madhya_deg
+    bhava_buf.lagna_deg = natal_bhavas.lagna_deg
+    bhava_buf.mc_deg = natal_bhavas.mc_deg
+    out = ffi.new("DhruvTransitBindus *")
+    status = lib.dhruv_transit_bindus(
+        natal_buf, bhava_buf, graha_index, transit_lon_sid, house_mode, out
+    )
+    check(status, "dhruv_transit_bindus")
+    return TransitBindus(
+        graha_index=out.graha_index,
+        transit_longitude_deg=out.transit_longitude_deg,
+        house=out.house,
+        rashi_index=out.rashi_index,
+        bav_bindus=out.bav_bindus if out.bav_valid else None,
+        sav_bindus=out.sav_bindus,
+    )
+
+
 def ashtakavarga_for_date(engine, eop, utc: UtcTime,
                           location: GeoLocation,
                           ayanamsha_system: int = 0,
@@ -1389,6 +1515,22 @@ def graha_drishti_matrix(longitudes_9: list[float]) -> GrahaDrishtiMatrix:
     return GrahaDrishtiMatrix(matrix=matrix)
 
 
+def rashi_drishti(from_rashi: int, to_rashi: int) -> bool:
+    """Whether one rashi (0-11) aspects another under Jaimini rashi drishti (pure math)."""
+    out = ffi.new("uint8_t *")
+    status = lib.dhruv_rashi_drishti(from_rashi, to_rashi, out)
+    check(status, "dhruv_rashi_drishti")
+    return bool(out[0])
+
+
+def rashi_drishti_matrix() -> list[list[bool]]:
+    """Full 12x12 Jaimini rashi drishti table, ``[from][to]`` by rashi index (pure math)."""
+    out = ffi.new("DhruvRashiDrishtiMatrix *")
+    status = lib.dhruv_rashi_drishti_matrix(out)
+    check(status, "dhruv_rashi_drishti_matrix")
+    return [[bool(out.aspects[i][j]) for j in range(12)] for i in range(12)]
+
+
 def drishti_for_date(engine, eop, utc: UtcTime, location: GeoLocation,
                      ayanamsha_system: int = 0, use_nutation: int = 1,
                      bhava_config=None, riseset_config=None,
@@ -1506,10 +1648,13 @@ def ayana_from_sidereal_longitude(lon_deg: float) -> int:
     return lib.dhruv_ayana_from_sidereal_longitude(lon_deg)
 
 
-def samvatsara_from_year(ce_year: int) -> SamvatsaraResult:
-    """Determine Samvatsara (Jovian year) from a CE year."""
+def samvatsara_from_year(ce_year: int, scheme: int = 0) -> SamvatsaraResult:
+    """Determine Samvatsara (Jovian year) from a CE year.
+
+    ``scheme``: 0=SouthIndian, 1=NorthIndian, 2=Barhaspatya.
+    """
     out = ffi.new("DhruvSamvatsaraResult *")
-    status = lib.dhruv_samvatsara_from_year(ce_year, out)
+    status = lib.dhruv_samvatsara_from_year(ce_year, scheme, out)
     check(status, "dhruv_samvatsara_from_year")
     return SamvatsaraResult(
         samvatsara_index=out.samvatsara_index,
@@ -1541,6 +1686,23 @@ def ghatikas_since_sunrise(jd_moment: float, jd_sunrise: float,
     return out[0]
 
 
+def ghatika_to_duration(ghati: float, vighati: float = 0.0) -> float:
+    """Convert ghatis and vighatis to clock hours (pure math)."""
+    out = ffi.new("double *")
+    status = lib.dhruv_ghatika_to_duration(ghati, vighati, out)
+    check(status, "dhruv_ghatika_to_duration")
+    return out[0]
+
+
+def duration_to_ghatika(hours: float) -> tuple[int, float]:
+    """Convert clock hours to (whole ghatis, remaining vighatis) (pure math)."""
+    ghati = ffi.new("uint32_t *")
+    vighati = ffi.new("double *")
+    status = lib.dhruv_duration_to_ghatika(hours, ghati, vighati)
+    check(status, "dhruv_duration_to_ghatika")
+    return (ghati[0], vighati[0])
+
+
 def hora_at(vaar_index: int, hora_index: int) -> int:
     """Determine hora lord for a weekday and position. Returns Chaldean index or -1."""
     return lib.dhruv_hora_at(vaar_index, hora_index)
@@ -1629,13 +1791,29 @@ def moolatrikone_range(graha_index: int):
     return (int(rashi_index[0]), float(start_deg[0]), float(end_deg[0]))
 
 
-def combustion_threshold(graha_index: int, is_retrograde: bool = False):
-    """Return the combustion threshold in degrees, or ``None`` when undefined."""
+def _combustion_config(moon_orb_deg):
+    if moon_orb_deg is None:
+        return ffi.NULL
+    cfg = ffi.new("DhruvCombustionConfig *")
+    cfg.moon_orb_deg = moon_orb_deg
+    return cfg
+
+
+def combustion_threshold(
+    graha_index: int,
+    is_retrograde: bool = False,
+    moon_orb_deg: float | None = None,
+):
+    """Return the combustion threshold in degrees, or ``None`` when undefined.
+
+    ``moon_orb_deg`` overrides the Moon's 12 degree orb; ``0`` exempts the Moon.
+    """
     return _optional_degree(
         "dhruv_combustion_threshold",
         lib.dhruv_combustion_threshold,
         graha_index,
         1 if is_retrograde else 0,
+        _combustion_config(moon_orb_deg),
     )
 
 
@@ -1644,6 +1822,7 @@ def is_combust(
     graha_sid_lon: float,
     sun_sid_lon: float,
     is_retrograde: bool = False,
+    moon_orb_deg: float | None = None,
 ) -> bool:
     """Return whether the graha is combust for the given longitudes."""
     out = ffi.new("uint8_t *")
@@ -1653,6 +1832,7 @@ def is_combust(
             graha_sid_lon,
             sun_sid_lon,
             1 if is_retrograde else 0,
+            _combustion_config(moon_orb_deg),
             out,
         ),
         "dhruv_is_combust",
@@ -1660,7 +1840,11 @@ def is_combust(
     return bool(out[0])
 
 
-def all_combustion_status(sidereal_lons_9: list[float], retrograde_flags_9: list[bool]) -> list[bool]:
+def all_combustion_status(
+    sidereal_lons_9: list[float],
+    retrograde_flags_9: list[bool],
+    moon_orb_deg: float | None = None,
+) -> list[bool]:
     """Return combustion flags for the 9 grahas."""
     if len(sidereal_lons_9) != 9 or len(retrograde_flags_9) != 9:
         raise ValueError("expected 9 longitudes and 9 retrograde flags")
@@ -1668,7 +1852,7 @@ def all_combustion_status(sidereal_lons_9: list[float], retrograde_flags_9: list
     retro_buf = ffi.new("uint8_t[9]", [1 if value else 0 for value in retrograde_flags_9])
     out = ffi.new("uint8_t[9]")
     check(
-        lib.dhruv_all_combustion_status(lon_buf, retro_buf, out),
+        lib.dhruv_all_combustion_status(lon_buf, retro_buf, _combustion_config(moon_orb_deg), out),
         "dhruv_all_combustion_status",
     )
     return [bool(out[i]) for i in range(9)]
@@ -1695,6 +1879,22 @@ def panchadha_maitri(naisargika_code: int, tatkalika_code: int) -> str:
     return _label(out[0], _PANCHADHA_LABELS, "dhruv_panchadha_maitri")
 
 
+def relationship_matrix(sidereal_lons_9: list[float]) -> list[list[Optional[str]]]:
+    """Return the 9x9 five-fold relationship grid; ``[g][o]`` is how g regards o, diagonal None."""
+    if len(sidereal_lons_9) != 9:
+        raise ValueError("expected 9 longitudes")
+    lon_buf = ffi.new("double[9]", sidereal_lons_9)
+    out = ffi.new("int32_t[81]")
+    check(lib.dhruv_relationship_matrix(lon_buf, out), "dhruv_relationship_matrix")
+    return [
+        [
+            None if i == j else _label(out[i * 9 + j], _PANCHADHA_LABELS, "dhruv_relationship_matrix")
+            for j in range(9)
+        ]
+        for i in range(9)
+    ]
+
+
 def dignity_in_rashi(graha_index: int, sidereal_lon: float, rashi_index: int) -> str:
     """Return the dignity label in the target rashi without temporal context."""
     out = ffi.new("int32_t *")
@@ -157,6 +157,26 @@ class TestLowTierDasha:
         )
         assert len(complete) == len(level0) * len(children)
 
+    def test_dasha_transitions_clips_to_range(self, engine_handles):
+        from ctara_dhruv.dasha import dasha_transitions
+        from ctara_dhruv.engine import engine, lsk, eop
+
+        periods = dasha_transitions(
+            engine(), lsk(), eop(),
+            jd_utc_birth=BIRTH_UTC,
+            jd_utc_start=(2034, 1, 1),
+            jd_utc_end=(2054, 1, 1),
+            location=BIRTH_LOC,
+            level=1,
+            system=0,
+        )
+        assert len(periods) > 1
+        assert periods[0].clipped_start
+        assert periods[-1].clipped_end
+        assert all(p.period.level == 1 for p in periods)
+        for prev, cur in zip(periods, periods[1:]):
+            assert abs(cur.period.start_jd - prev.period.end_jd) < 1e-9
+
 
 @skip_no_kernels
 @skip_no_eop
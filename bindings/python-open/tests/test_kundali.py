@@ -35,6 +35,15 @@ class TestGrahaLongitudes:
         diff = (trop.longitudes[0] - sid.longitudes[0]) % 360
         assert 23.0 < diff < 25.0
 
+    def test_graha_speeds(self, engine_handles):
+        """Sun moves ~1 deg/day direct; the true node moves retrograde."""
+        from ctara_dhruv.kundali import graha_speeds
+        from ctara_dhruv.engine import engine
+        speeds = graha_speeds(engine(), jd_tdb=J2000)
+        assert len(speeds) == 9
+        assert 0.9 < speeds[0] < 1.1
+        assert speeds[7] < 0.0
+
 
 @skip_no_kernels
 @skip_no_eop
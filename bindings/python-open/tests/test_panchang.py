@@ -56,6 +56,17 @@ class TestPanchangCompute:
         )
         assert result.tithi is not None
 
+    def test_panchang_diff_identical_configs(self, engine_handles):
+        """The same config on both sides reports no differing limbs."""
+        from ctara_dhruv.panchang import panchang_diff
+        from ctara_dhruv.types import UtcTime, GeoLocation
+        from ctara_dhruv.engine import engine, eop
+        utc = UtcTime(2024, 1, 15, 12, 0, 0.0)
+        delhi = GeoLocation(lat_deg=28.6139, lon_deg=77.2090)
+        diff = panchang_diff(engine()._ptr, eop(), utc, delhi)
+        assert diff.limbs == []
+        assert diff.a == diff.b
+
 
 @skip_no_kernels
 @skip_no_eop
@@ -70,6 +81,16 @@ class TestIndividualPanchang:
         assert t.paksha in (0, 1)
         assert 1 <= t.tithi_in_paksha <= 15
 
+    def test_tithi_day_kind_for_date(self, engine_handles):
+        from ctara_dhruv.panchang import tithi_day_kind_for_date
+        from ctara_dhruv.types import UtcTime, GeoLocation
+        from ctara_dhruv.engine import engine, eop
+        utc = UtcTime(2024, 1, 15, 12, 0, 0.0)
+        delhi = GeoLocation(lat_deg=28.6139, lon_deg=77.2090)
+        day = tithi_day_kind_for_date(engine()._ptr, eop(), utc, delhi)
+        assert day.kind in (0, 1, 2)
+        assert (day.tithi_index == -1) == (day.kind == 0)
+
     def test_karana_for_date(self, engine_handles):
         from ctara_dhruv.panchang import karana_for_date
         from ctara_dhruv.types import UtcTime
@@ -175,3 +196,9 @@ class TestSamvatsara:
         from ctara_dhruv.panchang import samvatsara_from_year
         s = samvatsara_from_year(2000)
         assert 0 <= s.samvatsara_index <= 59
+
+    def test_samvatsara_north_indian_scheme(self):
+        from ctara_dhruv.panchang import samvatsara_from_year
+        south = samvatsara_from_year(2024)
+        north = samvatsara_from_year(2024, scheme=1)
+        assert north.samvatsara_index == (south.samvatsara_index + 14) % 60
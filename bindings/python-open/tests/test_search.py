@@ -1,7 +1,7 @@
 """Tests for search APIs: conjunction, eclipse, motion, lunar phase, sankranti."""
 
 import pytest
-from conftest import skip_no_kernels
+from conftest import skip_no_kernels, skip_no_eop
 from ctara_dhruv.types import UtcTime
 
 
@@ -87,6 +87,55 @@ class TestMotion:
         assert evt.jd_tdb > J2000
         assert abs(evt.speed_deg_per_day) > 0
 
+    def test_next_node_station(self, engine_handles):
+        """Find next true-node station after J2000."""
+        from ctara_dhruv.search import next_node_station
+        evt = next_node_station(engine_handles._ptr, J2000)
+        assert evt is not None
+        assert evt.jd_tdb > J2000
+        assert 0 <= evt.rahu_longitude_deg < 360
+
+    def test_retrograde_intervals(self, engine_handles):
+        """Mars has one retrograde span in the 2020 opposition season."""
+        from ctara_dhruv.search import retrograde_intervals
+        start = J2000 + 7486.5  # 2020-07-01
+        spans = retrograde_intervals(engine_handles._ptr, 499, start, start + 184.0)
+        assert len(spans) == 1
+        span = spans[0]
+        assert not span.open_start and not span.open_end
+        assert start < span.start_jd_tdb < span.end_jd_tdb < start + 184.0
+
+
+@skip_no_kernels
+@skip_no_eop
+class TestHeliacal:
+    def test_venus_heliacal_rising(self, engine_handles):
+        """Find the next heliacal rising of Venus after J2000 at Delhi."""
+        from ctara_dhruv.engine import eop
+        from ctara_dhruv.search import HELIACAL_RISING, next_heliacal_event
+        from ctara_dhruv.types import GeoLocation
+        delhi = GeoLocation(lat_deg=28.6139, lon_deg=77.2090, alt_m=0.0)
+        evt = next_heliacal_event(
+            engine_handles._ptr, eop(), 299, delhi, HELIACAL_RISING, J2000
+        )
+        assert evt is not None
+        assert evt.jd_tdb > J2000
+        assert evt.kind == HELIACAL_RISING
+        assert evt.elongation_deg >= 5.0
+
+    def test_heliacal_range(self, engine_handles):
+        """Jupiter rises and sets heliacally within one synodic period."""
+        from ctara_dhruv.engine import eop
+        from ctara_dhruv.search import search_heliacal_events
+        from ctara_dhruv.types import GeoLocation
+        delhi = GeoLocation(lat_deg=28.6139, lon_deg=77.2090, alt_m=0.0)
+        events = search_heliacal_events(
+            engine_handles._ptr, eop(), 599, delhi, J2000, J2000 + 800.0
+        )
+        assert {e.kind for e in events} == {0, 1}
+        for a, b in zip(events, events[1:]):
+            assert a.jd_tdb < b.jd_tdb
+
 
 @skip_no_kernels
 class TestLunarPhase:
@@ -152,3 +201,27 @@ class TestSankranti:
             max_results=1,
         )
         assert len(events) == 12
+
+    def test_moon_rashi_ingresses(self, engine_handles):
+        """The Moon enters a new rashi roughly every 2.25 days."""
+        from ctara_dhruv.search import moon_rashi_ingresses
+        events = moon_rashi_ingresses(
+            engine_handles._ptr,
+            UtcTime(2024, 3, 1, 0, 0, 0.0),
+            UtcTime(2024, 3, 31, 0, 0, 0.0),
+            max_results=1,
+        )
+        assert 12 <= len(events) <= 14
+        for prev, cur in zip(events, events[1:]):
+            assert cur.rashi_index == (prev.rashi_index + 1) % 12
+            assert 2.0 < cur.jd_tdb - prev.jd_tdb < 2.6
+
+    def test_next_nakshatra_ingress(self, engine_handles):
+        """The Moon enters its next nakshatra within about a day."""
+        from ctara_dhruv.search import next_nakshatra_ingress
+        evt = next_nakshatra_ingress(engine_handles._ptr, 301, J2000)
+        assert evt is not None
+        assert evt.body_code == 301
+        assert 0 <= evt.nakshatra_index < 27
+        assert not evt.retrograde
+        assert J2000 < evt.jd_tdb < J2000 + 1.3
@@ -117,7 +117,9 @@ class TestEnums:
         assert DashaSystem.VIMSHOTTARI == 0
         assert DashaSystem.CHARA == 11
         assert DashaSystem.KARAKA_KENDRADI_GRAHA == 22
-        assert len(DashaSystem) == 23
+        assert DashaSystem.VIMSHOTTARI_28 == 23
+        assert DashaSystem.NARAYANA == 24
+        assert len(DashaSystem) == 25
 
     def test_reference_plane(self):
         from ctara_dhruv.enums import ReferencePlane
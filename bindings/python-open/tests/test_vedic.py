@@ -131,6 +131,81 @@ class TestRashiLord:
         assert rashi_lord(12) == -1
 
 
+class TestNakshatraLord:
+    def test_vimshottari_lords(self):
+        from ctara_dhruv.vedic import nakshatra_lord
+        assert nakshatra_lord(0) == 8  # Ashwini -> Ketu
+        assert nakshatra_lord(26) == 3  # Revati -> Buddh
+        assert nakshatra_lord(27) == -1
+
+
+class TestNavatara:
+    def test_counted_from_start(self):
+        from ctara_dhruv.vedic import navatara_from, navatara_name
+        assert navatara_from(0, 0) == 0  # Janma
+        assert navatara_from(0, 26) == 8  # Revati from Ashwini -> Parama Mitra
+        assert navatara_from(7, 2) == 4  # wraps past Revati -> Pratyak
+        assert navatara_from(27, 0) == -1
+        assert navatara_name(8) == "Parama Mitra"
+        assert navatara_name(9) is None
+
+
+class TestKpSublord:
+    def test_ashwini_subs(self):
+        from ctara_dhruv.vedic import kp_sublord
+        at_zero = kp_sublord(0.0)
+        assert (at_zero.nakshatra_index, at_zero.star_lord, at_zero.sub_lord) == (0, 8, 8)
+        assert kp_sublord(3.0).sub_lord == 0  # Surya sub from 3°00'
+
+
+class TestRelationshipMatrix:
+    def test_sun_saturn_enmity_and_diagonal(self):
+        from ctara_dhruv.vedic import relationship_matrix
+        # Surya and Shani both in Mesha: natural enmity stands in full.
+        m = relationship_matrix([5.0, 70.0, 100.0, 130.0, 160.0, 190.0, 10.0, 220.0, 40.0])
+        assert m[0][6] == "adhi_shatru"
+        assert m[6][0] == "adhi_shatru"
+        assert all(m[i][i] is None for i in range(9))
+
+
+class TestRashiDrishti:
+    def test_pair_and_matrix(self):
+        from ctara_dhruv._check import InvalidQueryError
+        from ctara_dhruv.vedic import rashi_drishti, rashi_drishti_matrix
+        assert rashi_drishti(0, 4)  # Mesha aspects Simha
+        assert not rashi_drishti(0, 1)  # but not the adjacent Vrishabha
+        matrix = rashi_drishti_matrix()
+        assert all(sum(row) == 3 and not row[i] for i, row in enumerate(matrix))
+        with pytest.raises(InvalidQueryError):
+            rashi_drishti(12, 0)
+
+
+class TestGandantaStatus:
+    def test_junction_half_and_offset(self):
+        from ctara_dhruv.vedic import (
+            GANDANTA_APPROACHING,
+            GANDANTA_KARKA_SIMHA,
+            GANDANTA_LEAVING,
+            GANDANTA_MEENA_MESHA,
+            gandanta_status,
+        )
+        s = gandanta_status(359.0 + 55.0 / 60.0)  # 29°55' Meena
+        assert (s.kind, s.half) == (GANDANTA_MEENA_MESHA, GANDANTA_APPROACHING)
+        assert s.offset_deg == pytest.approx(5.0 / 60.0)
+        s = gandanta_status(121.0)
+        assert (s.kind, s.half) == (GANDANTA_KARKA_SIMHA, GANDANTA_LEAVING)
+        assert gandanta_status(60.0) is None
+
+
+class TestGhatikaDuration:
+    def test_round_trip(self):
+        from ctara_dhruv.vedic import duration_to_ghatika, ghatika_to_duration
+        assert ghatika_to_duration(12, 30) == pytest.approx(5.0)
+        ghati, vighati = duration_to_ghatika(5.0)
+        assert ghati == 12
+        assert vighati == pytest.approx(30.0)
+
+
 class TestDms:
     def test_deg_to_dms(self):
         """Convert 45.5083 degrees to DMS."""
@@ -320,6 +395,38 @@ class TestLunarNodeHelpers:
         from ctara_dhruv.vedic import lunar_node_count
         assert lunar_node_count() == 2  # Rahu, Ketu
 
+    def test_mean_node_speed_regresses(self):
+        from ctara_dhruv.vedic import node_speed_deg_per_day
+        speed = node_speed_deg_per_day(0, 2451545.0)  # Mean
+        assert abs(speed + 0.053) < 0.001
+
+
+class TestWholeSignBhavas:
+    def test_cusps_at_rashi_starts(self):
+        from ctara_dhruv.vedic import whole_sign_from_lagna
+        result = whole_sign_from_lagna(48.25)
+        assert result.lagna_deg == 48.25
+        assert [b.cusp_deg for b in result.bhavas][:3] == [30.0, 60.0, 90.0]
+        assert result.mc_deg == 300.0
+        assert result.rashi_bhava is None
+
+
+class TestTransitBindus:
+    def test_whole_sign_house_and_bindus(self):
+        from ctara_dhruv.vedic import (
+            calculate_ashtakavarga,
+            transit_bindus,
+            whole_sign_from_lagna,
+        )
+        natal = calculate_ashtakavarga([0] * 7, 0)
+        bhavas = whole_sign_from_lagna(20.0)
+        result = transit_bindus(natal, bhavas, 6, 95.0)
+        assert result.house == 4
+        assert result.rashi_index == 3
+        assert result.bav_bindus == natal.bavs[6].points[3]
+        assert result.sav_bindus == natal.sav.total_points[3]
+        assert transit_bindus(natal, bhavas, 7, 95.0).bav_bindus is None
+
 
 @skip_no_kernels
 class TestLunarNodeUtc:
@@ -363,7 +470,7 @@ class TestSunrise:
 class TestBhava:
     def test_bhava_system_count(self, engine_handles):
         from ctara_dhruv.vedic import bhava_system_count
-        assert bhava_system_count() == 10
+        assert bhava_system_count() == 11
 
     def test_bhava_compute(self, engine_handles):
         """Compute bhava at Delhi for a known date, check 12 houses."""
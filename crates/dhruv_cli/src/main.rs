@@ -5,9 +5,10 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use clap::{Parser, Subcommand, ValueEnum};
 use dhruv_config::{ConfigResolver, DefaultsMode, EngineConfigPatch, load_with_discovery};
-use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query};
+use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query, StateVector};
 use dhruv_frames::{
-    PrecessionModel, ReferencePlane, cartesian_to_spherical, icrf_to_ecliptic, nutation_iau2000b,
+    PrecessionModel, ReferencePlane, cartesian_state_to_spherical_state, cartesian_to_spherical,
+    icrf_to_ecliptic, mean_obliquity_of_date_rad, nutation_iau2000b,
     precess_ecliptic_j2000_to_date,
 };
 use dhruv_search::conjunction_types::{ConjunctionConfig, ConjunctionEvent};
@@ -16,24 +17,27 @@ use dhruv_search::sankranti_types::SankrantiConfig;
 use dhruv_search::stationary_types::StationaryConfig;
 use dhruv_search::{
     ConjunctionOperation, ConjunctionQuery, ConjunctionResult, GrahanKind, GrahanOperation,
-    GrahanQuery, GrahanResult, LunarPhaseKind, LunarPhaseOperation, LunarPhaseQuery,
-    LunarPhaseResult, MotionKind, MotionOperation, MotionQuery, MotionResult, SankrantiOperation,
-    SankrantiQuery, SankrantiResult, SankrantiTarget,
+    GrahanQuery, GrahanResult, HeliacalEventConfig, HeliacalTarget, LunarPhaseKind,
+    LunarPhaseOperation, LunarPhaseQuery, LunarPhaseResult, MotionKind, MotionOperation,
+    MotionQuery, MotionResult, SankrantiOperation, SankrantiQuery, SankrantiResult,
+    SankrantiTarget, next_heliacal_rising, next_heliacal_setting, prev_heliacal_rising,
+    prev_heliacal_setting, search_heliacal_events,
 };
 use dhruv_tara::{EarthState, TaraAccuracy, TaraCatalog, TaraConfig, TaraId};
 use dhruv_time::{
     DeltaTModel, EopKernel, FutureDeltaTTransition, LeapSecondKernel, SmhFutureParabolaFamily,
     TimeConversionOptions, TimeConversionPolicy, TimeWarning, UtcTime, calendar_to_jd,
-    jd_to_calendar, jd_to_tdb_seconds, tdb_seconds_to_jd,
+    jd_to_calendar, jd_to_tdb_seconds, parse_utc_offset, tdb_seconds_to_jd,
 };
 use dhruv_vedic_base::bhava_types::SayanadiGhatikaRounding;
 use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig, RiseSetResult};
 use dhruv_vedic_base::{
-    ALL_GRAHAS, AyanamshaSystem, Graha, GulikaMaandiPlanet, LunarNode, NodeDignityPolicy, NodeMode,
-    Rashi, TimeUpagrahaConfig, TimeUpagrahaPoint, ayanamsha_deg, ayanamsha_deg_with_catalog,
-    ayanamsha_mean_deg_with_catalog, ayanamsha_true_deg, deg_to_dms, jd_tdb_to_centuries,
-    nakshatra_from_longitude, nakshatra_from_tropical, nakshatra28_from_longitude,
-    nakshatra28_from_tropical, rashi_from_longitude, rashi_from_tropical,
+    ALL_GRAHAS, AyanamshaSystem, CombustionConfig, CustomAyanamsha, Graha, GulikaMaandiPlanet,
+    LunarNode, NodeDignityPolicy, NodeMode, Rashi, TimeUpagrahaConfig, TimeUpagrahaPoint,
+    TithiDayKind, ayanamsha_deg, ayanamsha_deg_with_catalog_and_model,
+    ayanamsha_mean_deg_with_catalog_and_model, ayanamsha_rate_deg_per_century, ayanamsha_spread,
+    ayanamsha_true_deg_with_model, deg_to_dms, jd_tdb_to_centuries, nakshatra_from_longitude,
+    nakshatra28_from_longitude, node_speed_deg_per_day, rashi_from_longitude,
 };
 use dhruv_vedic_base::{BhavaConfig, ChandraBeneficRule};
 use dhruv_vedic_ops::{
@@ -103,6 +107,13 @@ struct Cli {
     /// (typically `finals2000A.daily.extended`).
     #[arg(long, global = true)]
     eop_daily: Option<PathBuf>,
+    /// Custom ayanamsha: mean value in degrees at --custom-ayanamsha-jd.
+    /// Overrides --ayanamsha wherever a sidereal longitude is computed.
+    #[arg(long, global = true, requires = "custom_ayanamsha_jd")]
+    custom_ayanamsha_deg: Option<f64>,
+    /// Anchor epoch (JD TDB) for --custom-ayanamsha-deg
+    #[arg(long, global = true, requires = "custom_ayanamsha_deg")]
+    custom_ayanamsha_jd: Option<f64>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -120,6 +131,9 @@ struct RashiTropicalArgs {
     /// Apply nutation correction
     #[arg(long)]
     nutation: bool,
+    /// Precession model: vondrak2011 (default), iau2006, lieske1977, newcomb1895
+    #[arg(long, default_value = "vondrak2011")]
+    precession: String,
 }
 
 #[derive(clap::Args)]
@@ -135,6 +149,9 @@ struct NakshatraTropicalArgs {
     /// Apply nutation correction
     #[arg(long)]
     nutation: bool,
+    /// Precession model: vondrak2011 (default), iau2006, lieske1977, newcomb1895
+    #[arg(long, default_value = "vondrak2011")]
+    precession: String,
     /// Scheme: 27 (default) or 28
     #[arg(long, default_value = "27")]
     scheme: u32,
@@ -159,6 +176,28 @@ struct NextSankrantiArgs {
     lsk: Option<PathBuf>,
 }
 
+#[derive(clap::Args)]
+struct NextNakshatraIngressArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// NAIF body code (default 301=Moon; 10=Sun, 499=Mars, ...)
+    #[arg(long, default_value = "301")]
+    body: i32,
+    /// Ayanamsha system code (0-19, default 0=Lahiri)
+    #[arg(long, default_value = "0")]
+    ayanamsha: i32,
+    /// Apply nutation correction
+    #[arg(long)]
+    nutation: bool,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
 #[derive(clap::Args)]
 struct MasaArgs {
     /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
@@ -208,6 +247,9 @@ struct VarshaArgs {
     /// Apply nutation correction
     #[arg(long)]
     nutation: bool,
+    /// Samvatsara naming scheme: south-indian, north-indian, barhaspatya
+    #[arg(long, default_value = "south-indian")]
+    samvatsara_scheme: String,
     /// Path to SPK kernel
     #[arg(long)]
     bsp: Option<PathBuf>,
@@ -279,6 +321,8 @@ struct VaarArgs {
     eop: PathBuf,
     #[command(flatten)]
     bhava_behavior: BhavaBehaviorArgs,
+    #[command(flatten)]
+    riseset: RiseSetArgs,
 }
 
 #[derive(clap::Args)]
@@ -304,6 +348,8 @@ struct HoraArgs {
     /// Path to IERS EOP file (finals2000A.all)
     #[arg(long)]
     eop: PathBuf,
+    #[command(flatten)]
+    riseset: RiseSetArgs,
 }
 
 #[derive(clap::Args)]
@@ -329,6 +375,8 @@ struct GhatikaArgs {
     /// Path to IERS EOP file (finals2000A.all)
     #[arg(long)]
     eop: PathBuf,
+    #[command(flatten)]
+    riseset: RiseSetArgs,
 }
 
 #[derive(clap::Args)]
@@ -360,6 +408,10 @@ struct SphutasArgs {
     /// Path to IERS EOP file (finals2000A.all)
     #[arg(long)]
     eop: PathBuf,
+    #[command(flatten)]
+    riseset: RiseSetArgs,
+    #[command(flatten)]
+    upagraha: TimeUpagrahaArgs,
 }
 
 #[derive(clap::Args)]
@@ -449,10 +501,16 @@ struct PanchangArgs {
     /// Include calendar elements (masa, ayana, varsha)
     #[arg(long)]
     calendar: bool,
+    /// Samvatsara naming scheme: south-indian, north-indian, barhaspatya
+    #[arg(long, default_value = "south-indian")]
+    samvatsara_scheme: String,
     /// Include mask tokens (comma-separated):
     /// tithi,karana,yoga,vaar,hora,ghatika,nakshatra,masa,ayana,varsha,core,calendar,all
     #[arg(long)]
     include: Option<String>,
+    /// Compare against this ayanamsha system code and list the differing limbs
+    #[arg(long)]
+    diff_ayanamsha: Option<i32>,
     /// Path to SPK kernel
     #[arg(long)]
     bsp: Option<PathBuf>,
@@ -464,6 +522,8 @@ struct PanchangArgs {
     eop: PathBuf,
     #[command(flatten)]
     bhava_behavior: BhavaBehaviorArgs,
+    #[command(flatten)]
+    riseset: RiseSetArgs,
 }
 
 #[derive(clap::Args)]
@@ -546,6 +606,15 @@ struct AshtakavargaArgs {
     /// Path to IERS EOP file (finals2000A.all)
     #[arg(long)]
     eop: PathBuf,
+    /// Also score this transiting graha against the natal chart (e.g. Saturn)
+    #[arg(long, requires = "transit_lon")]
+    transit_graha: Option<String>,
+    /// Sidereal longitude of the transiting graha in degrees
+    #[arg(long, requires = "transit_graha")]
+    transit_lon: Option<f64>,
+    /// Place the transit by natal bhava cusps instead of whole signs
+    #[arg(long, requires = "transit_graha")]
+    chalit: bool,
 }
 
 #[derive(clap::Args)]
@@ -618,8 +687,11 @@ struct GrahaPositionsArgs {
     /// Include bhava placement
     #[arg(long)]
     bhava: bool,
+    /// Include gandanta / rashi-sandhi / pushkara flags
+    #[arg(long)]
+    flags: bool,
     /// Output tropical (ecliptic-of-date) longitudes instead of sidereal
-    #[arg(long, conflicts_with_all = ["nakshatra", "lagna", "outer", "no_outer", "bhava"])]
+    #[arg(long, conflicts_with_all = ["nakshatra", "lagna", "outer", "no_outer", "bhava", "flags"])]
     tropical: bool,
     /// Precession model: vondrak2011 (default), iau2006, lieske1977, newcomb1895
     #[arg(long, default_value = "vondrak2011")]
@@ -637,6 +709,34 @@ struct GrahaPositionsArgs {
     bhava_behavior: BhavaBehaviorArgs,
 }
 
+#[derive(clap::Args)]
+struct GrahaSpeedsArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Ayanamsha system code (0-19, default 0=Lahiri)
+    #[arg(long, default_value = "0")]
+    ayanamsha: i32,
+    /// Apply nutation correction
+    #[arg(long)]
+    nutation: bool,
+    /// Speeds of tropical (ecliptic-of-date) longitudes instead of sidereal
+    #[arg(long)]
+    tropical: bool,
+    /// Precession model: vondrak2011 (default), iau2006, lieske1977, newcomb1895
+    #[arg(long, default_value = "vondrak2011")]
+    precession: String,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all)
+    #[arg(long)]
+    eop: PathBuf,
+}
+
 #[derive(clap::Args)]
 struct CoreBindusArgs {
     /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
@@ -760,6 +860,37 @@ struct BhavaBehaviorArgs {
     no_rashi_bhava_results: bool,
 }
 
+#[derive(clap::Args)]
+struct RiseSetArgs {
+    /// Refraction model: standard (34'), bennett, saemundsson, custom
+    #[arg(long, default_value = "standard")]
+    refraction: String,
+    /// Air pressure in hPa for bennett/saemundsson
+    #[arg(long, default_value = "1010")]
+    pressure_hpa: f64,
+    /// Air temperature in degrees Celsius for bennett/saemundsson
+    #[arg(long, default_value = "10")]
+    temperature_c: f64,
+    /// Horizon refraction in arcminutes for --refraction custom
+    #[arg(long, default_value = "34")]
+    refraction_arcmin: f64,
+    /// Put the limb on the geometric horizon (no refraction)
+    #[arg(long)]
+    no_refraction: bool,
+    /// Vedic-day start when the Sun does not rise: error, civil-twilight, local-six-am
+    #[arg(long, default_value = "error")]
+    day_fallback: String,
+}
+
+fn riseset_config_from_cli(args: &RiseSetArgs) -> RiseSetConfig {
+    RiseSetConfig {
+        use_refraction: !args.no_refraction,
+        refraction_model: parse_refraction_model(args),
+        day_fallback: parse_day_fallback(&args.day_fallback),
+        ..RiseSetConfig::default()
+    }
+}
+
 fn bhava_config_from_cli(args: &BhavaBehaviorArgs) -> BhavaConfig {
     let mut config = BhavaConfig::default();
     if args.use_configured_bhava_for_bala_avastha {
@@ -847,6 +978,7 @@ fn rashi_bhava_result_from_lagna(lagna_deg: f64) -> dhruv_vedic_base::BhavaResul
         cusp_deg: 0.0,
         start_deg: 0.0,
         end_deg: 0.0,
+        madhya_deg: 0.0,
     }; 12];
     for i in 0..12 {
         let rashi = (lagna_rashi + i as u8) % 12;
@@ -856,6 +988,7 @@ fn rashi_bhava_result_from_lagna(lagna_deg: f64) -> dhruv_vedic_base::BhavaResul
             cusp_deg: (start + degree_in_rashi).rem_euclid(360.0),
             start_deg: start,
             end_deg: (start + 30.0).rem_euclid(360.0),
+            madhya_deg: start + 15.0,
         };
     }
     dhruv_vedic_base::BhavaResult {
@@ -872,14 +1005,14 @@ fn print_bhava_table(title: &str, result: &dhruv_vedic_base::BhavaResult) {
         result.lagna_deg, result.mc_deg
     );
     println!(
-        "{:>6} {:>10} {:>10} {:>10}",
-        "Bhava", "Cusp", "Start", "End"
+        "{:>6} {:>10} {:>10} {:>10} {:>10}",
+        "Bhava", "Cusp", "Start", "End", "Madhya"
     );
-    println!("{}", "-".repeat(40));
+    println!("{}", "-".repeat(52));
     for b in &result.bhavas {
         println!(
-            "{:>6} {:>11.6}° {:>11.6}° {:>11.6}°",
-            b.number, b.cusp_deg, b.start_deg, b.end_deg
+            "{:>6} {:>11.6}° {:>11.6}° {:>11.6}° {:>11.6}°",
+            b.number, b.cusp_deg, b.start_deg, b.end_deg, b.madhya_deg
         );
     }
 }
@@ -919,6 +1052,9 @@ struct KundaliArgs {
     /// Max dasha hierarchy depth (0-4, default 2)
     #[arg(long, default_value = "2")]
     dasha_max_level: u8,
+    /// Dasha year length: julian (365.25 d), savana (360 d), tropical, sidereal
+    #[arg(long, default_value = "julian")]
+    dasha_year_length: String,
     /// UTC datetime for dasha snapshot query
     #[arg(long)]
     dasha_snapshot_date: Option<String>,
@@ -991,6 +1127,9 @@ struct KundaliArgs {
     /// Include calendar (masa, ayana, varsha). Implies --include-panchang
     #[arg(long)]
     include_calendar: bool,
+    /// Samvatsara naming scheme: south-indian, north-indian, barhaspatya
+    #[arg(long, default_value = "south-indian")]
+    samvatsara_scheme: String,
     /// Node dignity policy: "sign-lord" (default) or "sama"
     #[arg(long)]
     node_policy: Option<String>,
@@ -1003,6 +1142,37 @@ struct KundaliArgs {
     bhava_behavior: BhavaBehaviorArgs,
 }
 
+#[derive(clap::Args)]
+struct NavamsaArgs {
+    /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: String,
+    /// Latitude in degrees (north positive)
+    #[arg(long)]
+    lat: f64,
+    /// Longitude in degrees (east positive)
+    #[arg(long)]
+    lon: f64,
+    /// Altitude in meters (default 0)
+    #[arg(long, default_value = "0")]
+    alt: f64,
+    /// Ayanamsha system code (0-19, default 0=Lahiri)
+    #[arg(long, default_value = "0")]
+    ayanamsha: i32,
+    /// Apply nutation correction
+    #[arg(long)]
+    nutation: bool,
+    /// Path to SPK kernel
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    /// Path to leap second kernel
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    /// Path to IERS EOP file (finals2000A.all)
+    #[arg(long)]
+    eop: PathBuf,
+}
+
 #[derive(clap::Args)]
 struct AmshaChartArgs {
     /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
@@ -1189,14 +1359,17 @@ struct AyanamshaComputeArgs {
     date: String,
     #[arg(long, default_value = "0")]
     ayanamsha: i32,
-    /// Mode: unified (default), mean, or true
-    #[arg(long, value_parser = ["unified", "mean", "true"], default_value = "unified")]
+    /// Mode: unified (default), mean, true, or rate (deg/century)
+    #[arg(long, value_parser = ["unified", "mean", "true", "rate"], default_value = "unified")]
     mode: String,
     #[arg(long)]
     nutation: bool,
     /// Delta-psi (arcsec) used by `--mode true`
     #[arg(long, default_value_t = 0.0)]
     delta_psi_arcsec: f64,
+    /// Precession model: vondrak2011 (default), iau2006, lieske1977, newcomb1895
+    #[arg(long, default_value = "vondrak2011")]
+    precession: String,
     #[arg(long)]
     bsp: Option<PathBuf>,
     #[arg(long)]
@@ -1222,6 +1395,8 @@ struct SunriseArgs {
     lsk: Option<PathBuf>,
     #[arg(long)]
     eop: PathBuf,
+    #[command(flatten)]
+    riseset: RiseSetArgs,
 }
 
 #[derive(clap::Args)]
@@ -1295,6 +1470,9 @@ struct LunarNodeArgs {
     /// Backend: engine (default) or analytic
     #[arg(long, value_parser = ["engine", "analytic"], default_value = "engine")]
     backend: String,
+    /// Output: longitude (default), speed (analytic deg/day), or next-station (true node)
+    #[arg(long, value_parser = ["longitude", "speed", "next-station"], default_value = "longitude")]
+    output: String,
     #[arg(long)]
     bsp: Option<PathBuf>,
     #[arg(long)]
@@ -1442,6 +1620,9 @@ struct SankrantiOpArgs {
     /// Optional specific rashi index (0=Mesha .. 11=Meena)
     #[arg(long)]
     rashi: Option<i32>,
+    /// List Moon rashi ingresses instead of solar ones (range mode only)
+    #[arg(long)]
+    moon: bool,
     /// Ayanamsha system code (0-19, default 0=Lahiri)
     #[arg(long, default_value = "0")]
     ayanamsha: i32,
@@ -1517,6 +1698,21 @@ struct SearchStationaryArgs {
     lsk: Option<PathBuf>,
 }
 
+#[derive(clap::Args)]
+struct RetrogradeIntervalsArgs {
+    #[arg(long)]
+    start: String,
+    #[arg(long)]
+    end: String,
+    /// NAIF body code (e.g. 499=Mars, 599=Jupiter)
+    #[arg(long)]
+    body: i32,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+}
+
 #[derive(clap::Args)]
 struct NextMaxSpeedArgs {
     #[arg(long)]
@@ -1581,6 +1777,49 @@ struct MotionOpArgs {
     lsk: Option<PathBuf>,
 }
 
+#[derive(clap::Args)]
+struct HeliacalArgs {
+    /// Phenomenon for next/prev mode: rising or setting
+    #[arg(long, value_parser = ["rising", "setting"], default_value = "rising")]
+    kind: String,
+    /// Mode: next, prev, or range (range lists risings and settings)
+    #[arg(long, value_parser = ["next", "prev", "range"])]
+    mode: String,
+    /// NAIF body code of a planet (e.g. 299=Venus, 499=Mars)
+    #[arg(long, conflicts_with = "star")]
+    body: Option<i32>,
+    /// Star name (e.g. "Agastya", "Sirius")
+    #[arg(long)]
+    star: Option<String>,
+    /// Path to star catalog JSON (defaults to the embedded catalog)
+    #[arg(long)]
+    catalog: Option<PathBuf>,
+    /// UTC datetime for next/prev mode (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    date: Option<String>,
+    /// UTC start datetime for range mode (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    start: Option<String>,
+    /// UTC end datetime for range mode (YYYY-MM-DDThh:mm:ssZ)
+    #[arg(long)]
+    end: Option<String>,
+    #[arg(long)]
+    lat: f64,
+    #[arg(long)]
+    lon: f64,
+    #[arg(long, default_value = "0")]
+    alt: f64,
+    /// Arcus visionis in degrees (defaults to the traditional value for the target)
+    #[arg(long)]
+    arcus_visionis: Option<f64>,
+    #[arg(long)]
+    bsp: Option<PathBuf>,
+    #[arg(long)]
+    lsk: Option<PathBuf>,
+    #[arg(long)]
+    eop: PathBuf,
+}
+
 #[derive(clap::Args)]
 struct PositionArgs {
     /// UTC datetime (YYYY-MM-DDThh:mm:ssZ)
@@ -1592,6 +1831,9 @@ struct PositionArgs {
     /// NAIF body code for observer (0=SSB, 399=Earth)
     #[arg(long, default_value = "399")]
     observer: i32,
+    /// Output frame for the Cartesian state and spherical coordinates
+    #[arg(long, value_enum, default_value = "ecliptic-of-date")]
+    frame: CliPositionFrameArg,
     /// Path to SPK kernel
     #[arg(long)]
     bsp: Option<PathBuf>,
@@ -1753,6 +1995,8 @@ struct VedicDaySunrisesArgs {
     lsk: Option<PathBuf>,
     #[arg(long)]
     eop: PathBuf,
+    #[command(flatten)]
+    riseset: RiseSetArgs,
 }
 
 #[derive(clap::Args)]
@@ -2040,7 +2284,7 @@ struct DashaArgs {
     /// Dasha system (vimshottari)
     #[arg(long, default_value = "vimshottari")]
     system: String,
-    /// Dasha mode: hierarchy, snapshot, level0, level0-entity, children, child-period, complete-level
+    /// Dasha mode: hierarchy, snapshot, level0, level0-entity, children, child-period, complete-level, transitions
     #[arg(long)]
     mode: Option<String>,
     /// Birth UTC datetime (YYYY-MM-DDThh:mm:ssZ)
@@ -2055,6 +2299,21 @@ struct DashaArgs {
     /// Query JD UTC for snapshot mode.
     #[arg(long)]
     query_jd: Option<f64>,
+    /// Range start UTC datetime for transitions mode
+    #[arg(long)]
+    start_date: Option<String>,
+    /// Range start JD UTC for transitions mode.
+    #[arg(long)]
+    start_jd: Option<f64>,
+    /// Range end UTC datetime for transitions mode
+    #[arg(long)]
+    end_date: Option<String>,
+    /// Range end JD UTC for transitions mode.
+    #[arg(long)]
+    end_jd: Option<f64>,
+    /// Dasha level listed by transitions mode (0-4, default 1)
+    #[arg(long, default_value = "1")]
+    level: u8,
     /// Latitude in degrees (north positive)
     #[arg(long)]
     lat: Option<f64>,
@@ -2082,6 +2341,9 @@ struct DashaArgs {
     /// Maximum dasha depth (0-4, default 2)
     #[arg(long, default_value = "2")]
     max_level: u8,
+    /// Dasha year length: julian (365.25 d), savana (360 d), tropical, sidereal
+    #[arg(long, default_value = "julian")]
+    year_length: String,
     /// Parent level index for children/child-period/complete-level (0-4)
     #[arg(long)]
     parent_level: Option<u8>,
@@ -2141,6 +2403,10 @@ struct TaraPositionArgs {
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
 enum GrahaHelperOp {
+    NakshatraLord,
+    Navatara,
+    KpSublord,
+    Gandanta,
     HoraLord,
     MasaLord,
     SamvatsaraLord,
@@ -2153,12 +2419,16 @@ enum GrahaHelperOp {
     NaisargikaMaitri,
     TatkalikaMaitri,
     PanchadhaMaitri,
+    RelationshipMatrix,
+    RashiDrishti,
+    RashiDrishtiMatrix,
     DignityInRashi,
     DignityInRashiWithPositions,
     NodeDignityInRashi,
     NaturalBeneficMalefic,
     MoonBeneficNature,
     GrahaGender,
+    WholeSignBhavas,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
@@ -2177,6 +2447,8 @@ enum TimeUtilityOp {
     EclipticLonToInvariableLon,
     InvariableLonToEclipticLon,
     PrecessEclipticJ2000ToDate,
+    GhatikaToDuration,
+    DurationToGhatika,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
@@ -2216,6 +2488,20 @@ enum CliNodePolicyArg {
 enum CliCalendarPolicyArg {
     ProlepticGregorian,
     GregorianCutover1582,
+    ProlepticJulian,
+}
+
+/// Output frame for `position`. Of-date frames use mean (IAU 2006) precession
+/// and obliquity without nutation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+enum CliPositionFrameArg {
+    Icrf,
+    #[value(alias = "ecliptic_j2000")]
+    EclipticJ2000,
+    #[value(alias = "ecliptic_of_date")]
+    EclipticOfDate,
+    #[value(alias = "equator_of_date")]
+    EquatorOfDate,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
@@ -2240,6 +2526,12 @@ struct GrahaHelperArgs {
     masa: Option<u8>,
     #[arg(long)]
     samvatsara: Option<u8>,
+    /// Nakshatra index (0-26, Ashwini = 0)
+    #[arg(long)]
+    nakshatra: Option<u8>,
+    /// Target nakshatra index (0-26) for --op navatara
+    #[arg(long)]
+    other_nakshatra: Option<u8>,
     #[arg(long)]
     rashi: Option<u8>,
     #[arg(long)]
@@ -2250,6 +2542,9 @@ struct GrahaHelperArgs {
     sun_lon: Option<f64>,
     #[arg(long)]
     retrograde: bool,
+    /// Moon's combustion orb in degrees (0 exempts the Moon)
+    #[arg(long, default_value_t = dhruv_vedic_base::MOON_COMBUSTION_DEG)]
+    moon_combustion_orb: f64,
     #[arg(long)]
     longitudes: Option<String>,
     #[arg(long)]
@@ -2301,6 +2596,15 @@ struct TimeUtilityArgs {
     t_centuries: Option<f64>,
     #[arg(long)]
     precession: Option<String>,
+    /// Whole ghatis since sunrise
+    #[arg(long)]
+    ghati: Option<f64>,
+    /// Vighatis past the last ghati (default 0)
+    #[arg(long, default_value_t = 0.0)]
+    vighati: f64,
+    /// Clock hours since sunrise
+    #[arg(long)]
+    hours: Option<f64>,
 }
 
 #[derive(clap::Args)]
@@ -2386,6 +2690,8 @@ enum Commands {
     },
     /// Find next Sankranti (Sun entering a rashi)
     NextSankranti(NextSankrantiArgs),
+    /// Find next nakshatra ingress of any body (Moon by default)
+    NextNakshatraIngress(NextNakshatraIngressArgs),
     /// Determine the Masa (lunar month) for a date
     Masa(MasaArgs),
     /// Determine the Ayana (Uttarayana/Dakshinayana) for a date
@@ -2440,6 +2746,8 @@ enum Commands {
     Upagrahas(UpagrahasArgs),
     /// Compute comprehensive graha positions
     GrahaPositions(GrahaPositionsArgs),
+    /// Compute longitude speeds (deg/day) and retrograde flags for all 9 grahas
+    GrahaSpeeds(GrahaSpeedsArgs),
     /// Compute curated sensitive points (bindus) with optional nakshatra/bhava
     CoreBindus(CoreBindusArgs),
     /// Compute graha drishti (planetary aspects) with virupa strength
@@ -2478,6 +2786,15 @@ enum Commands {
     PrevSpecificSankranti(PrevSpecificSankrantiArgs),
     /// Compute ayanamsha for a date
     AyanamshaCompute(AyanamshaComputeArgs),
+    /// Compare mean ayanamsha of every system for a date, sorted
+    AyanamshaTable {
+        #[arg(long)]
+        date: String,
+        #[arg(long)]
+        bsp: Option<PathBuf>,
+        #[arg(long)]
+        lsk: Option<PathBuf>,
+    },
     /// Compute nutation (dpsi, deps) for a date
     NutationCompute {
         #[arg(long)]
@@ -2557,12 +2874,16 @@ enum Commands {
     PrevStationary(PrevStationaryArgs),
     /// Search stationary points of a planet in a date range
     SearchStationary(SearchStationaryArgs),
+    /// List retrograde spans of a planet in a date range
+    RetrogradeIntervals(RetrogradeIntervalsArgs),
     /// Find next max-speed event of a planet
     NextMaxSpeed(NextMaxSpeedArgs),
     /// Find previous max-speed event of a planet
     PrevMaxSpeed(PrevMaxSpeedArgs),
     /// Search max-speed events of a planet in a date range
     SearchMaxSpeed(SearchMaxSpeedArgs),
+    /// Heliacal rising/setting of a planet or star (`--mode next|prev|range`)
+    Heliacal(HeliacalArgs),
     /// Query spherical position of a body (lon, lat, distance)
     Position(PositionArgs),
     /// Sidereal longitude of a body
@@ -2797,6 +3118,9 @@ enum Commands {
         /// CE year
         #[arg(long)]
         year: i32,
+        /// Naming scheme: south-indian, north-indian, barhaspatya
+        #[arg(long, default_value = "south-indian")]
+        scheme: String,
     },
     /// Compute the rashi index that is N signs from a starting rashi
     NthRashiFrom {
@@ -2914,6 +3238,8 @@ enum Commands {
     AmshaVariations(AmshaVariationsArgs),
     /// Compute amsha charts for a date and location
     AmshaChart(AmshaChartArgs),
+    /// Compute the navamsa (D9) lagna and graha placements for a date and location
+    Navamsa(NavamsaArgs),
     /// Compute Graha Avasthas (planetary states) for a date and location
     Avastha(AvasthaArgs),
     /// Compute Dasha (planetary period) hierarchy or snapshot
@@ -2938,24 +3264,39 @@ fn aya_system_from_code(code: i32) -> Option<AyanamshaSystem> {
 }
 
 fn parse_utc(s: &str) -> Result<UtcTime, String> {
-    // Parse "YYYY-MM-DDThh:mm:ssZ" or "YYYY-MM-DDThh:mm:ss"
+    // Parse "YYYY-MM-DDThh:mm:ssZ", "YYYY-MM-DDThh:mm:ss", or
+    // "YYYY-MM-DDThh:mm:ss±HH:MM" (local time, normalized to UTC). A leading
+    // '-' gives an astronomical year before 1 CE (0000 = 1 BCE, -0001 = 2 BCE).
     let s = s.trim_end_matches('Z');
     let parts: Vec<&str> = s.split('T').collect();
     if parts.len() != 2 {
         return Err(format!("expected YYYY-MM-DDThh:mm:ssZ, got {s}"));
     }
-    let date_parts: Vec<&str> = parts[0].split('-').collect();
-    let time_parts: Vec<&str> = parts[1].split(':').collect();
+    let (time, offset_minutes) = match parts[1].find(['+', '-']) {
+        Some(i) => (
+            &parts[1][..i],
+            parse_utc_offset(&parts[1][i..]).map_err(|e| e.to_string())?,
+        ),
+        None => (parts[1], 0),
+    };
+    let (sign, date) = match parts[0].strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, parts[0].strip_prefix('+').unwrap_or(parts[0])),
+    };
+    let date_parts: Vec<&str> = date.split('-').collect();
+    let time_parts: Vec<&str> = time.split(':').collect();
     if date_parts.len() != 3 || time_parts.len() != 3 {
         return Err(format!("invalid date/time format: {s}"));
     }
-    let year: i32 = date_parts[0].parse().map_err(|e| format!("{e}"))?;
+    let year: i32 = sign * date_parts[0].parse::<i32>().map_err(|e| format!("{e}"))?;
     let month: u32 = date_parts[1].parse().map_err(|e| format!("{e}"))?;
     let day: u32 = date_parts[2].parse().map_err(|e| format!("{e}"))?;
     let hour: u32 = time_parts[0].parse().map_err(|e| format!("{e}"))?;
     let minute: u32 = time_parts[1].parse().map_err(|e| format!("{e}"))?;
     let second: f64 = time_parts[2].parse().map_err(|e| format!("{e}"))?;
-    UtcTime::try_new(year, month, day, hour, minute, second, None).map_err(|e| e.to_string())
+    let local = UtcTime::try_new(year, month, day, hour, minute, second, None)
+        .map_err(|e| e.to_string())?;
+    Ok(local.local_to_utc(offset_minutes))
 }
 
 fn parse_defaults_mode(s: &str) -> DefaultsMode {
@@ -3180,6 +3521,29 @@ static STALE_EOP_THRESHOLD_DAYS: OnceLock<Option<f64>> = OnceLock::new();
 static EOP_C04_PATH: OnceLock<Option<PathBuf>> = OnceLock::new();
 static EOP_DAILY_PATH: OnceLock<Option<PathBuf>> = OnceLock::new();
 static CLI_CONFIG_RESOLVER: OnceLock<Option<ConfigResolver>> = OnceLock::new();
+static CUSTOM_AYANAMSHA: OnceLock<Option<CustomAyanamsha>> = OnceLock::new();
+
+/// Apply `--custom-ayanamsha-deg/--custom-ayanamsha-jd`, if given.
+fn with_cli_custom_ayanamsha(config: SankrantiConfig) -> SankrantiConfig {
+    match CUSTOM_AYANAMSHA.get().copied().flatten() {
+        Some(custom) => config.with_custom_ayanamsha(custom),
+        None => config,
+    }
+}
+
+/// Sidereal graha-longitude config carrying the CLI ayanamsha settings.
+fn cli_graha_longitudes_config(
+    system: AyanamshaSystem,
+    use_nutation: bool,
+    model: PrecessionModel,
+    plane: ReferencePlane,
+) -> dhruv_search::GrahaLongitudesConfig {
+    let mut aya_config = SankrantiConfig::new_with_model(system, use_nutation, model);
+    aya_config.reference_plane = plane;
+    dhruv_search::GrahaLongitudesConfig::from_sankranti_config(&with_cli_custom_ayanamsha(
+        aya_config,
+    ))
+}
 
 fn now_jd_utc() -> f64 {
     let now = SystemTime::now()
@@ -3414,6 +3778,16 @@ fn require_vaar(index: u8) -> dhruv_vedic_base::Vaar {
         })
 }
 
+fn require_rashi(index: u8) -> dhruv_vedic_base::Rashi {
+    dhruv_vedic_base::ALL_RASHIS
+        .get(index as usize)
+        .copied()
+        .unwrap_or_else(|| {
+            eprintln!("Invalid rashi index: {index} (0-11: Mesha..Meena)");
+            std::process::exit(1);
+        })
+}
+
 fn require_masa(index: u8) -> dhruv_vedic_base::Masa {
     dhruv_vedic_base::ALL_MASAS
         .get(index as usize)
@@ -3630,9 +4004,57 @@ fn parse_cli_calendar_policy(value: CliCalendarPolicyArg) -> dhruv_time::Calenda
         CliCalendarPolicyArg::GregorianCutover1582 => {
             dhruv_time::CalendarPolicy::GregorianCutover1582
         }
+        CliCalendarPolicyArg::ProlepticJulian => dhruv_time::CalendarPolicy::ProlepticJulian,
     }
 }
 
+/// Rotate an ICRF position into the `position` command's output frame.
+fn icrf_to_position_frame(v: &[f64; 3], jd_tdb: f64, frame: CliPositionFrameArg) -> [f64; 3] {
+    let tc = (jd_tdb - 2_451_545.0) / 36525.0;
+    match frame {
+        CliPositionFrameArg::Icrf => *v,
+        CliPositionFrameArg::EclipticJ2000 => icrf_to_ecliptic(v),
+        CliPositionFrameArg::EclipticOfDate => {
+            precess_ecliptic_j2000_to_date(&icrf_to_ecliptic(v), tc)
+        }
+        CliPositionFrameArg::EquatorOfDate => {
+            let ecl = precess_ecliptic_j2000_to_date(&icrf_to_ecliptic(v), tc);
+            let (sin_eps, cos_eps) = mean_obliquity_of_date_rad(tc).sin_cos();
+            [
+                ecl[0],
+                ecl[1] * cos_eps - ecl[2] * sin_eps,
+                ecl[1] * sin_eps + ecl[2] * cos_eps,
+            ]
+        }
+    }
+}
+
+/// Rotate an ICRF state (km, km/s) into the `position` command's output frame.
+///
+/// The kernel velocity is rotated directly. Of-date frames also turn with
+/// precession; that term is differenced on the fixed position at t±1 min,
+/// which needs no further ephemeris queries.
+fn icrf_state_to_position_frame(
+    sv: &StateVector,
+    jd_tdb: f64,
+    frame: CliPositionFrameArg,
+) -> ([f64; 3], [f64; 3]) {
+    let pos = icrf_to_position_frame(&sv.position_km, jd_tdb, frame);
+    let mut vel = icrf_to_position_frame(&sv.velocity_km_s, jd_tdb, frame);
+    if matches!(
+        frame,
+        CliPositionFrameArg::EclipticOfDate | CliPositionFrameArg::EquatorOfDate
+    ) {
+        const DT: f64 = 1.0 / 1440.0;
+        let plus = icrf_to_position_frame(&sv.position_km, jd_tdb + DT, frame);
+        let minus = icrf_to_position_frame(&sv.position_km, jd_tdb - DT, frame);
+        for ((v, p), m) in vel.iter_mut().zip(plus).zip(minus) {
+            *v += (p - m) / (2.0 * DT * 86_400.0);
+        }
+    }
+    (pos, vel)
+}
+
 fn parse_cli_reference_plane(value: CliReferencePlaneArg) -> ReferencePlane {
     match value {
         CliReferencePlaneArg::Ecliptic => ReferencePlane::Ecliptic,
@@ -3664,6 +4086,17 @@ fn main() {
     let _ = STALE_EOP_THRESHOLD_DAYS.set(cli.stale_eop_threshold_days);
     let _ = EOP_C04_PATH.set(cli.eop_c04.clone());
     let _ = EOP_DAILY_PATH.set(cli.eop_daily.clone());
+    let custom_ayanamsha = match (cli.custom_ayanamsha_deg, cli.custom_ayanamsha_jd) {
+        (Some(deg), Some(jd)) if deg.is_finite() && jd.is_finite() => {
+            Some(CustomAyanamsha::new(deg, jd))
+        }
+        (None, None) => None,
+        _ => {
+            eprintln!("Invalid --custom-ayanamsha-deg/--custom-ayanamsha-jd: must be finite");
+            std::process::exit(1);
+        }
+    };
+    let _ = CUSTOM_AYANAMSHA.set(custom_ayanamsha);
 
     let defaults_mode = parse_defaults_mode(&cli.defaults_mode);
     let loaded_config =
@@ -3770,9 +4203,15 @@ fn main() {
 
         Commands::RashiTropical(args) => {
             let system = require_aya_system(args.ayanamsha);
+            let model = parse_precession_model(&args.precession);
             let t = jd_tdb_to_centuries(args.jd);
-            let aya = ayanamsha_deg(system, t, args.nutation);
-            let info = rashi_from_tropical(args.lon, system, args.jd, args.nutation);
+            let aya = with_cli_custom_ayanamsha(SankrantiConfig::new_with_model(
+                system,
+                args.nutation,
+                model,
+            ))
+            .ayanamsha_deg_ecliptic(t);
+            let info = rashi_from_longitude(args.lon - aya);
             let dms = info.dms;
             println!("Ayanamsha: {:.6} deg", aya);
             println!("Sidereal: {:.6} deg", args.lon - aya);
@@ -3788,13 +4227,19 @@ fn main() {
 
         Commands::NakshatraTropical(args) => {
             let system = require_aya_system(args.ayanamsha);
+            let model = parse_precession_model(&args.precession);
             let t = jd_tdb_to_centuries(args.jd);
-            let aya = ayanamsha_deg(system, t, args.nutation);
+            let aya = with_cli_custom_ayanamsha(SankrantiConfig::new_with_model(
+                system,
+                args.nutation,
+                model,
+            ))
+            .ayanamsha_deg_ecliptic(t);
             println!("Ayanamsha: {:.6} deg", aya);
             println!("Sidereal: {:.6} deg", args.lon - aya);
             match args.scheme {
                 27 => {
-                    let info = nakshatra_from_tropical(args.lon, system, args.jd, args.nutation);
+                    let info = nakshatra_from_longitude(args.lon - aya);
                     println!(
                         "{} (index {}) - Pada {} ({:.6} deg in nakshatra, {:.6} deg in pada)",
                         info.nakshatra.name(),
@@ -3805,7 +4250,7 @@ fn main() {
                     );
                 }
                 28 => {
-                    let info = nakshatra28_from_tropical(args.lon, system, args.jd, args.nutation);
+                    let info = nakshatra28_from_longitude(args.lon - aya);
                     println!(
                         "{} (index {}) - Pada {} ({:.6} deg in nakshatra)",
                         info.nakshatra.name(),
@@ -3895,7 +4340,7 @@ fn main() {
             });
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
             let op = SankrantiOperation {
                 target: SankrantiTarget::Any,
@@ -3923,6 +4368,35 @@ fn main() {
             }
         }
 
+        Commands::NextNakshatraIngress(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let body = require_body(args.body);
+            let system = require_aya_system(args.ayanamsha);
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
+            let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
+            match dhruv_search::next_nakshatra_ingress(&engine, body, jd_tdb, &config) {
+                Ok(Some(ev)) => {
+                    println!(
+                        "Next nakshatra ingress of {:?}: {}{}",
+                        ev.body,
+                        ev.nakshatra.name(),
+                        if ev.retrograde { " (retrograde)" } else { "" }
+                    );
+                    println!("  Time: {}", ev.utc);
+                    println!("  Boundary: {:.6} deg", ev.boundary_deg);
+                }
+                Ok(None) => println!("No nakshatra ingress found in search range"),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+
         Commands::Masa(args) => {
             let utc = parse_utc(&args.date).unwrap_or_else(|e| {
                 eprintln!("{e}");
@@ -3930,7 +4404,7 @@ fn main() {
             });
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             match dhruv_search::masa_for_date(&engine, &utc, &config) {
                 Ok(info) => {
                     let adhika_str = if info.adhika { " (Adhika)" } else { "" };
@@ -3952,7 +4426,7 @@ fn main() {
             });
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             match dhruv_search::ayana_for_date(&engine, &utc, &config) {
                 Ok(info) => {
                     println!("Ayana: {}", info.ayana.name());
@@ -3973,7 +4447,8 @@ fn main() {
             });
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let mut config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
+            config.samvatsara_scheme = parse_samvatsara_scheme(&args.samvatsara_scheme);
             match dhruv_search::varsha_for_date(&engine, &utc, &config) {
                 Ok(info) => {
                     println!(
@@ -4045,7 +4520,7 @@ fn main() {
             });
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             match dhruv_search::yoga_for_date(&engine, &utc, &config) {
                 Ok(info) => {
                     println!("Yoga: {} (index {})", info.yoga.name(), info.yoga_index);
@@ -4066,7 +4541,7 @@ fn main() {
             });
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             match dhruv_search::nakshatra_for_date(&engine, &utc, &config) {
                 Ok(info) => {
                     println!(
@@ -4093,7 +4568,7 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let rs_config = RiseSetConfig::default();
+            let rs_config = riseset_config_from_cli(&args.riseset);
             match dhruv_search::vaar_for_date(&engine, &eop_kernel, &utc, &location, &rs_config) {
                 Ok(info) => {
                     println!("Vaar: {}", info.vaar.name());
@@ -4115,7 +4590,7 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let rs_config = RiseSetConfig::default();
+            let rs_config = riseset_config_from_cli(&args.riseset);
             match dhruv_search::hora_for_date(&engine, &eop_kernel, &utc, &location, &rs_config) {
                 Ok(info) => {
                     println!(
@@ -4141,7 +4616,7 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let rs_config = RiseSetConfig::default();
+            let rs_config = riseset_config_from_cli(&args.riseset);
             match dhruv_search::ghatika_for_date(&engine, &eop_kernel, &utc, &location, &rs_config)
             {
                 Ok(info) => {
@@ -4176,43 +4651,32 @@ fn main() {
             let graha_lons = dhruv_search::graha_longitudes(
                 &engine,
                 jd_tdb,
-                &dhruv_search::GrahaLongitudesConfig::sidereal(system, args.nutation),
+                &cli_graha_longitudes_config(
+                    system,
+                    args.nutation,
+                    dhruv_frames::DEFAULT_PRECESSION_MODEL,
+                    system.default_reference_plane(),
+                ),
             )
             .unwrap_or_else(|e| {
                 eprintln!("Error computing graha longitudes: {e}");
                 std::process::exit(1);
             });
 
-            // Get lagna (sidereal)
-            let jd_utc = jd_tdb; // approximate; for more precision would use LSK
-            let asc_rad =
-                dhruv_vedic_base::lagna_longitude_rad(engine.lsk(), &eop_kernel, &location, jd_utc)
-                    .unwrap_or_else(|e| {
-                        eprintln!("Error computing lagna: {e}");
-                        std::process::exit(1);
-                    });
-            let t = dhruv_vedic_base::jd_tdb_to_centuries(jd_tdb);
-            let aya = dhruv_vedic_base::ayanamsha_deg(system, t, args.nutation);
-            let lagna_sid = (asc_rad.to_degrees() - aya).rem_euclid(360.0);
-
-            // Get 8th lord longitude
-            let lagna_rashi_idx = (lagna_sid / 30.0).floor() as u8;
-            let eighth_rashi_idx = dhruv_vedic_base::nth_rashi_from(lagna_rashi_idx, 8);
-            let eighth_lord = dhruv_vedic_base::rashi_lord_by_index(eighth_rashi_idx).unwrap();
-            let eighth_lord_lon = graha_lons.longitude(eighth_lord);
-
-            // Build sphuta inputs (gulika = 0 for now, as it requires upagraha computation)
-            let inputs = dhruv_vedic_base::SphutalInputs {
-                sun: graha_lons.longitude(dhruv_vedic_base::Graha::Surya),
-                moon: graha_lons.longitude(dhruv_vedic_base::Graha::Chandra),
-                mars: graha_lons.longitude(dhruv_vedic_base::Graha::Mangal),
-                jupiter: graha_lons.longitude(dhruv_vedic_base::Graha::Guru),
-                venus: graha_lons.longitude(dhruv_vedic_base::Graha::Shukra),
-                rahu: graha_lons.longitude(dhruv_vedic_base::Graha::Rahu),
-                lagna: lagna_sid,
-                eighth_lord: eighth_lord_lon,
-                gulika: 0.0,
-            };
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
+            let inputs = dhruv_search::sphuta_inputs_for_date(
+                &engine,
+                &eop_kernel,
+                &utc,
+                &location,
+                &riseset_config_from_cli(&args.riseset),
+                &config,
+                &build_time_upagraha_config(&args.upagraha),
+            )
+            .unwrap_or_else(|e| {
+                eprintln!("Error computing sphuta inputs: {e}");
+                std::process::exit(1);
+            });
 
             let results = dhruv_vedic_base::all_sphutas(&inputs);
             println!(
@@ -4231,24 +4695,19 @@ fn main() {
                     graha_lons.longitude(graha)
                 );
             }
-            println!("  {:8} {:>10.6}°\n", "Lagna", lagna_sid);
+            println!("  {:8} {:>10.6}°", "Lagna", inputs.lagna.deg());
+            println!("  {:8} {:>10.6}°\n", "Gulika", inputs.gulika.deg());
             println!("Sphutas:");
             for (sphuta, lon) in &results {
                 let rashi_info = dhruv_vedic_base::rashi_from_longitude(*lon);
                 println!(
-                    "  {:24} {:>10.6}° ({} {}°{:02}'{:04.1}\")",
+                    "  {:24} {:>10.6}° ({} {})",
                     sphuta.name(),
                     lon,
                     rashi_info.rashi.name(),
-                    rashi_info.dms.degrees,
-                    rashi_info.dms.minutes,
-                    rashi_info.dms.seconds,
+                    rashi_info.dms.format(1),
                 );
             }
-            println!("\nNote: Gulika=0° (placeholder until upagraha computation is available).");
-            println!(
-                "  TriSphuta, ChatusSphuta, PanchaSphuta, SookshmaTrisphuta depend on Gulika."
-            );
         }
 
         Commands::SpecialLagnas(args) => {
@@ -4259,7 +4718,7 @@ fn main() {
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let rs_config = RiseSetConfig::default();
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
 
             let result = dhruv_search::special_lagnas_for_date(
                 &engine,
@@ -4295,7 +4754,7 @@ fn main() {
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
 
             let results = dhruv_search::arudha_padas_for_date(
                 &engine,
@@ -4317,13 +4776,11 @@ fn main() {
             for r in &results {
                 let rashi_info = dhruv_vedic_base::rashi_from_longitude(r.longitude_deg);
                 println!(
-                    "  {:16} {:>10.6}° ({} {}°{:02}'{:04.1}\")",
+                    "  {:16} {:>10.6}° ({} {})",
                     r.pada.name(),
                     r.longitude_deg,
                     rashi_info.rashi.name(),
-                    rashi_info.dms.degrees,
-                    rashi_info.dms.minutes,
-                    rashi_info.dms.seconds,
+                    rashi_info.dms.format(1),
                 );
             }
         }
@@ -4337,8 +4794,48 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let rs_config = RiseSetConfig::default();
-            let config = SankrantiConfig::new(system, args.nutation);
+            let rs_config = riseset_config_from_cli(&args.riseset);
+            let mut config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
+            config.samvatsara_scheme = parse_samvatsara_scheme(&args.samvatsara_scheme);
+            if let Some(code) = args.diff_ayanamsha {
+                let mut config_b = SankrantiConfig::new(require_aya_system(code), args.nutation);
+                config_b.samvatsara_scheme = config.samvatsara_scheme;
+                let diff = dhruv_search::panchang_diff(
+                    &engine,
+                    &eop_kernel,
+                    &utc,
+                    &location,
+                    &rs_config,
+                    &config,
+                    &config_b,
+                )
+                .unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                });
+                println!(
+                    "Panchang diff for {} at {:.6}°N, {:.6}°E ({:?} vs {:?})\n",
+                    args.date,
+                    args.lat,
+                    args.lon,
+                    config.ayanamsha_system,
+                    config_b.ayanamsha_system
+                );
+                if diff.is_empty() {
+                    println!("No differences.");
+                }
+                for d in &diff.limbs {
+                    let marker = if d.value_differs { "value" } else { "bounds" };
+                    println!(
+                        "{:<10} {:<7} start {:+.1} s  end {:+.1} s",
+                        format!("{:?}", d.limb),
+                        marker,
+                        d.start_shift_s,
+                        d.end_shift_s
+                    );
+                }
+                return;
+            }
             let include_mask = if let Some(raw) = args.include.as_deref() {
                 parse_panchang_include_mask(raw).unwrap_or_else(|e| {
                     eprintln!("Invalid --include value: {e}");
@@ -4377,6 +4874,9 @@ fn main() {
                         );
                         println!("  Start:  {}  End: {}", tithi.start, tithi.end);
                     }
+                    if let Some(kind) = info.tithi_day_kind {
+                        println!("  Day:    {}", tithi_day_label(kind));
+                    }
                     if let Some(karana) = info.karana {
                         println!(
                             "Karana:   {} (sequence {})",
@@ -4448,7 +4948,9 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let config = dhruv_search::sankranti_types::SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(
+                dhruv_search::sankranti_types::SankrantiConfig::new(system, args.nutation),
+            );
 
             let result =
                 dhruv_search::ashtakavarga_for_date(&engine, &eop_kernel, &utc, &location, &config)
@@ -4531,7 +5033,48 @@ fn main() {
             }
             let ek_total: u16 = result.sav.after_ekadhipatya.iter().map(|&p| p as u16).sum();
             println!("{:>7}", ek_total);
-        }
+
+            if let (Some(name), Some(transit_lon)) = (&args.transit_graha, args.transit_lon) {
+                let graha = parse_graha_name(name);
+                let natal_bhavas = dhruv_search::sidereal_bhavas_for_date(
+                    &engine,
+                    &eop_kernel,
+                    &utc,
+                    &location,
+                    &BhavaConfig::default(),
+                    &config,
+                )
+                .unwrap_or_else(|e| {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                });
+                let house_mode = if args.chalit {
+                    dhruv_search::TransitHouseMode::Chalit
+                } else {
+                    dhruv_search::TransitHouseMode::WholeSign
+                };
+                let transit = dhruv_search::transit_bindus(
+                    &result,
+                    &natal_bhavas,
+                    graha,
+                    transit_lon,
+                    &dhruv_search::TransitAshtakavargaConfig { house_mode },
+                );
+                println!(
+                    "\nTransit {} at {:.4}° ({:?}): house {}, rashi {}",
+                    graha.name(),
+                    transit.transit_longitude_deg,
+                    house_mode,
+                    transit.house,
+                    rashi_names[transit.rashi_index as usize],
+                );
+                match transit.bav_bindus {
+                    Some(bav) => println!("  BAV bindus: {bav}"),
+                    None => println!("  BAV bindus: n/a"),
+                }
+                println!("  SAV bindus: {}", transit.sav_bindus);
+            }
+        }
 
         Commands::Upagrahas(args) => {
             let system = require_aya_system(args.ayanamsha);
@@ -4543,7 +5086,9 @@ fn main() {
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let rs_config = RiseSetConfig::default();
-            let config = dhruv_search::sankranti_types::SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(
+                dhruv_search::sankranti_types::SankrantiConfig::new(system, args.nutation),
+            );
             let upagraha_config = build_time_upagraha_config(&args.upagraha);
 
             let result = dhruv_search::all_upagrahas_for_date_with_config(
@@ -4575,13 +5120,11 @@ fn main() {
             ] {
                 let rashi_info = dhruv_vedic_base::rashi_from_longitude(lon);
                 println!(
-                    "  {:16} {:>10.6}° ({} {}°{:02}'{:04.1}\")",
+                    "  {:16} {:>10.6}° ({} {})",
                     name,
                     lon,
                     rashi_info.rashi.name(),
-                    rashi_info.dms.degrees,
-                    rashi_info.dms.minutes,
-                    rashi_info.dms.seconds,
+                    rashi_info.dms.format(1),
                 );
             }
             println!("\nSun-based:");
@@ -4594,13 +5137,11 @@ fn main() {
             ] {
                 let rashi_info = dhruv_vedic_base::rashi_from_longitude(lon);
                 println!(
-                    "  {:16} {:>10.6}° ({} {}°{:02}'{:04.1}\")",
+                    "  {:16} {:>10.6}° ({} {})",
                     name,
                     lon,
                     rashi_info.rashi.name(),
-                    rashi_info.dms.degrees,
-                    rashi_info.dms.minutes,
-                    rashi_info.dms.seconds,
+                    rashi_info.dms.format(1),
                 );
             }
         }
@@ -4655,12 +5196,18 @@ fn main() {
                 let location = GeoLocation::new(args.lat, args.lon, args.alt);
                 let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
                 let prec = parse_precession_model(&args.precession);
-                let aya_config = SankrantiConfig::new_with_model(system, args.nutation, prec);
+                let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new_with_model(
+                    system,
+                    args.nutation,
+                    prec,
+                ));
                 let gp_config = dhruv_search::GrahaPositionsConfig {
                     include_nakshatra: args.nakshatra,
                     include_lagna: args.lagna,
                     include_outer_planets: args.outer || !args.no_outer,
                     include_bhava: args.bhava,
+                    include_flags: args.flags,
+                    time_anchor: dhruv_search::GrahaPositionsTimeAnchor::Exact,
                 };
 
                 let result = dhruv_search::graha_positions(
@@ -4693,9 +5240,14 @@ fn main() {
                 if args.bhava {
                     print!("  {:>5}", "Bhava");
                 }
+                if args.flags {
+                    print!("  {:<24}", "Flags");
+                }
                 println!();
-                let width =
-                    32 + if args.nakshatra { 24 } else { 0 } + if args.bhava { 7 } else { 0 };
+                let width = 32
+                    + if args.nakshatra { 24 } else { 0 }
+                    + if args.bhava { 7 } else { 0 }
+                    + if args.flags { 26 } else { 0 };
                 println!("{}", "-".repeat(width));
 
                 let print_entry =
@@ -4721,6 +5273,10 @@ fn main() {
                             let bh = force_bhava.unwrap_or(entry.bhava_number);
                             print!("  {:>5}", if bh > 0 { bh.to_string() } else { "-".into() },);
                         }
+                        if args.flags {
+                            let labels = graha_flag_labels(entry);
+                            print!("  {:<24}", if labels.is_empty() { "-" } else { &labels });
+                        }
                         println!();
                     };
 
@@ -4740,6 +5296,49 @@ fn main() {
                 }
             }
         }
+        Commands::GrahaSpeeds(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop_kernel = load_eop(&args.eop);
+            let jd_tdb = utc_to_jd_tdb_with_policy_and_eop(
+                &utc,
+                engine.lsk(),
+                Some(&eop_kernel),
+                time_policy,
+            );
+            let prec = parse_precession_model(&args.precession);
+            let config = if args.tropical {
+                dhruv_search::GrahaLongitudesConfig::tropical_with_model(
+                    args.nutation,
+                    prec,
+                    dhruv_frames::ReferencePlane::Ecliptic,
+                )
+            } else {
+                let system = require_aya_system(args.ayanamsha);
+                cli_graha_longitudes_config(
+                    system,
+                    args.nutation,
+                    prec,
+                    system.default_reference_plane(),
+                )
+            };
+            let speeds = dhruv_search::graha_speeds(&engine, jd_tdb, &config).unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
+
+            println!("Graha Speeds for {}\n", args.date);
+            println!("{:<10} {:>14}", "Graha", "Speed (°/day)");
+            println!("{}", "-".repeat(27));
+            for graha in ALL_GRAHAS {
+                let speed = speeds[graha.index() as usize];
+                let flag = if speed < 0.0 { "  R" } else { "" };
+                println!("{:<10} {:>+14.6}{}", graha.name(), speed, flag);
+            }
+        }
         Commands::CoreBindus(args) => {
             let system = require_aya_system(args.ayanamsha);
             let utc = parse_utc(&args.date).unwrap_or_else(|e| {
@@ -4751,7 +5350,7 @@ fn main() {
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let upagraha_config = build_time_upagraha_config(&args.upagraha);
             let bindus_config = dhruv_search::BindusConfig {
                 include_nakshatra: args.nakshatra,
@@ -4861,7 +5460,7 @@ fn main() {
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let drishti_config = dhruv_search::DrishtiConfig {
                 include_bhava: args.bhava,
                 include_lagna: args.lagna,
@@ -4982,7 +5581,9 @@ fn main() {
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let mut aya_config =
+                with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
+            aya_config.samvatsara_scheme = parse_samvatsara_scheme(&args.samvatsara_scheme);
 
             let node_dignity_policy = match args.node_policy.as_deref() {
                 Some("sama") => NodeDignityPolicy::AlwaysSama,
@@ -5036,7 +5637,7 @@ fn main() {
                 dhruv_search::DashaSnapshotTime::Utc(snap_utc)
             });
 
-            let full_config = build_kundali_config(
+            let mut full_config = build_kundali_config(
                 &resolved,
                 args.dasha_systems.as_deref(),
                 args.dasha_max_level,
@@ -5048,6 +5649,8 @@ fn main() {
                 build_time_upagraha_config(&args.upagraha),
                 !args.no_outer,
             );
+            full_config.dasha_config.year_length =
+                parse_dasha_year_length(&args.dasha_year_length) as u8;
 
             let result = dhruv_search::full_kundali_for_date(
                 &engine,
@@ -5143,7 +5746,7 @@ fn main() {
             });
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
             let op = SankrantiOperation {
                 target: SankrantiTarget::Any,
@@ -5262,7 +5865,7 @@ fn main() {
             });
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let jd_start = utc_to_jd_tdb_with_policy(&s, engine.lsk(), time_policy);
             let jd_end = utc_to_jd_tdb_with_policy(&e, engine.lsk(), time_policy);
             let op = SankrantiOperation {
@@ -5305,7 +5908,7 @@ fn main() {
             let target = rashi_from_index(args.rashi);
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
             let op = SankrantiOperation {
                 target: SankrantiTarget::SpecificRashi(target),
@@ -5342,7 +5945,7 @@ fn main() {
             let target = rashi_from_index(args.rashi);
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
             let op = SankrantiOperation {
                 target: SankrantiTarget::SpecificRashi(target),
@@ -5386,12 +5989,29 @@ fn main() {
                     std::process::exit(1);
                 })
             });
+            let model = parse_precession_model(&args.precession);
+            if args.mode == "rate" {
+                let rate = ayanamsha_rate_deg_per_century(system, t, model);
+                println!(
+                    "Ayanamsha rate ({:?}): {:.6} deg/century ({:.4}\"/yr)",
+                    system,
+                    rate,
+                    rate * 36.0
+                );
+                return;
+            }
             let aya = match args.mode.as_str() {
-                "mean" => ayanamsha_mean_deg_with_catalog(system, t, cat.as_ref()),
-                "true" => ayanamsha_true_deg(system, t, args.delta_psi_arcsec),
-                "unified" => ayanamsha_deg_with_catalog(system, t, args.nutation, cat.as_ref()),
+                "mean" => ayanamsha_mean_deg_with_catalog_and_model(system, t, cat.as_ref(), model),
+                "true" => ayanamsha_true_deg_with_model(system, t, args.delta_psi_arcsec, model),
+                "unified" => ayanamsha_deg_with_catalog_and_model(
+                    system,
+                    t,
+                    args.nutation,
+                    cat.as_ref(),
+                    model,
+                ),
                 _ => {
-                    eprintln!("Invalid mode: {} (unified|mean|true)", args.mode);
+                    eprintln!("Invalid mode: {} (unified|mean|true|rate)", args.mode);
                     std::process::exit(1);
                 }
             };
@@ -5418,6 +6038,30 @@ fn main() {
             );
         }
 
+        Commands::AyanamshaTable { date, bsp, lsk } => {
+            let utc = parse_utc(&date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let engine = load_engine(&bsp, &lsk);
+            let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
+            let mut spread = ayanamsha_spread(jd_tdb_to_centuries(jd_tdb));
+            let lahiri = spread
+                .iter()
+                .find(|(system, _)| *system == AyanamshaSystem::Lahiri)
+                .map_or(0.0, |&(_, aya)| aya);
+            spread.sort_by(|a, b| a.1.total_cmp(&b.1));
+            println!("Mean ayanamsha at {}:", date);
+            for (system, aya) in spread {
+                println!(
+                    "  {:<24} {:>11.6}°  ({:+.6}° vs Lahiri)",
+                    format!("{system:?}"),
+                    aya,
+                    aya - lahiri
+                );
+            }
+        }
+
         Commands::NutationCompute { date, bsp, lsk } => {
             let utc = parse_utc(&date).unwrap_or_else(|e| {
                 eprintln!("{e}");
@@ -5440,7 +6084,7 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let rs_config = RiseSetConfig::default();
+            let rs_config = riseset_config_from_cli(&args.riseset);
             let jd_utc = utc_to_jd_utc(&utc);
             let jd_noon = dhruv_vedic_base::approximate_local_noon_jd(
                 dhruv_vedic_base::utc_day_start_jd(jd_utc),
@@ -5466,8 +6110,11 @@ fn main() {
             );
             for result in &events {
                 match result {
-                    RiseSetResult::Event { jd_tdb, event } => {
-                        println!("  {:20} JD TDB {:.6}", format!("{event:?}"), jd_tdb);
+                    RiseSetResult::Event { event, .. } => {
+                        let utc = result
+                            .to_utc(engine.lsk())
+                            .expect("event result has a time");
+                        println!("  {:20} {}", format!("{event:?}"), utc);
                     }
                     RiseSetResult::NeverRises => println!("  Sun never rises (polar night)"),
                     RiseSetResult::NeverSets => println!("  Sun never sets (midnight sun)"),
@@ -5492,7 +6139,8 @@ fn main() {
             };
             let result = if args.sidereal {
                 let system = require_aya_system(args.ayanamsha);
-                let aya_config = SankrantiConfig::new(system, args.nutation);
+                let aya_config =
+                    with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
                 dhruv_search::sidereal_bhavas_for_date(
                     &engine,
                     &eop_kernel,
@@ -5547,7 +6195,8 @@ fn main() {
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let (lagna_label, lagna_deg, mc_label, mc_deg) = if args.sidereal {
                 let system = require_aya_system(args.ayanamsha);
-                let aya_config = SankrantiConfig::new(system, args.nutation);
+                let aya_config =
+                    with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
                 let lagna = dhruv_search::sidereal_lagna_for_date(
                     &engine,
                     &eop_kernel,
@@ -5622,6 +6271,31 @@ fn main() {
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
             let lunar_node = parse_lunar_node(&args.node);
             let node_mode = parse_node_mode(&args.mode);
+            match args.output.as_str() {
+                "speed" => {
+                    let speed = node_speed_deg_per_day(jd_tdb_to_centuries(jd_tdb), node_mode);
+                    println!(
+                        "{:?} speed ({:?}): {:.6} deg/day",
+                        lunar_node, node_mode, speed
+                    );
+                    return;
+                }
+                "next-station" => {
+                    match dhruv_search::next_node_station(&engine, jd_tdb) {
+                        Ok(Some(ev)) => println!(
+                            "Next true-node station: {:?} at UTC {} (JD TDB {:.6}), Rahu {:.6}°",
+                            ev.station_type, ev.utc, ev.jd_tdb, ev.rahu_longitude_deg
+                        ),
+                        Ok(None) => println!("No true-node station found"),
+                        Err(e) => {
+                            eprintln!("Error: {e}");
+                            std::process::exit(1);
+                        }
+                    }
+                    return;
+                }
+                _ => {}
+            }
             let backend = match args.backend.as_str() {
                 "engine" => NodeBackend::Engine,
                 "analytic" => NodeBackend::Analytic,
@@ -6080,7 +6754,38 @@ fn main() {
             };
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
+            if args.moon {
+                if args.mode != "range" || args.rashi.is_some() {
+                    eprintln!("--moon requires --mode range and no --rashi");
+                    std::process::exit(1);
+                }
+                let parse = |value: &Option<String>, flag: &str| {
+                    let value = value.as_deref().unwrap_or_else(|| {
+                        eprintln!("{flag} is required when --mode range");
+                        std::process::exit(1);
+                    });
+                    parse_utc(value).unwrap_or_else(|e| {
+                        eprintln!("{e}");
+                        std::process::exit(1);
+                    })
+                };
+                let start = parse(&args.start, "--start");
+                let end = parse(&args.end, "--end");
+                match dhruv_search::moon_rashi_ingresses(&engine, &start, &end, &config) {
+                    Ok(events) => {
+                        println!("Found {} Moon rashi ingresses:", events.len());
+                        for ev in &events {
+                            println!("  {} at {}", ev.rashi.name(), ev.utc);
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("Error: {e}");
+                        std::process::exit(1);
+                    }
+                }
+                return;
+            }
             let query = match args.mode.as_str() {
                 "next" => {
                     let date = args.date.as_deref().unwrap_or_else(|| {
@@ -6620,6 +7325,44 @@ fn main() {
             }
         }
 
+        Commands::RetrogradeIntervals(args) => {
+            let s = parse_utc(&args.start).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let e = parse_utc(&args.end).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let b = require_body(args.body);
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let jd_start = utc_to_jd_tdb_with_policy(&s, engine.lsk(), time_policy);
+            let jd_end = utc_to_jd_tdb_with_policy(&e, engine.lsk(), time_policy);
+            let config = StationaryConfig::inner_planet();
+            match dhruv_search::retrograde_intervals(&engine, b, jd_start, jd_end, &config) {
+                Ok(intervals) => {
+                    println!("Found {} retrograde intervals:", intervals.len());
+                    for iv in &intervals {
+                        let start = UtcTime::from_jd_tdb(iv.start_jd, engine.lsk());
+                        let end = UtcTime::from_jd_tdb(iv.end_jd, engine.lsk());
+                        println!(
+                            "  {}{} -> {}{}  ({:.6}° -> {:.6}°)",
+                            if iv.open_start { "(open) " } else { "" },
+                            start,
+                            end,
+                            if iv.open_end { " (open)" } else { "" },
+                            iv.start_lon_deg,
+                            iv.end_lon_deg
+                        );
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+
         Commands::NextMaxSpeed(args) => {
             let utc = parse_utc(&args.date).unwrap_or_else(|e| {
                 eprintln!("{e}");
@@ -6723,6 +7466,115 @@ fn main() {
             }
         }
 
+        Commands::Heliacal(args) => {
+            let catalog = match (&args.star, &args.catalog) {
+                (Some(_), Some(path)) => TaraCatalog::load(path).unwrap_or_else(|e| {
+                    eprintln!("Failed to load catalog: {e}");
+                    std::process::exit(1);
+                }),
+                _ => TaraCatalog::embedded().clone(),
+            };
+            let (target, config) = match (args.body, &args.star) {
+                (Some(code), None) => {
+                    let body = require_body(code);
+                    (
+                        HeliacalTarget::Body(body),
+                        HeliacalEventConfig::for_body(body),
+                    )
+                }
+                (None, Some(name)) => {
+                    let id = TaraId::from_str(name).unwrap_or_else(|| {
+                        eprintln!("Unknown star: {name}");
+                        std::process::exit(1);
+                    });
+                    (
+                        HeliacalTarget::Tara(&catalog, id),
+                        HeliacalEventConfig::default(),
+                    )
+                }
+                _ => {
+                    eprintln!("Exactly one of --body or --star is required");
+                    std::process::exit(1);
+                }
+            };
+            let config = match args.arcus_visionis {
+                Some(arcus_visionis_deg) => HeliacalEventConfig {
+                    arcus_visionis_deg,
+                    ..config
+                },
+                None => config,
+            };
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop_kernel = load_eop(&args.eop);
+            let location = GeoLocation::new(args.lat, args.lon, args.alt);
+            let parse_flag = |value: &Option<String>, flag: &str| {
+                let value = value.as_deref().unwrap_or_else(|| {
+                    eprintln!("--{flag} is required when --mode {}", args.mode);
+                    std::process::exit(1);
+                });
+                let utc = parse_utc(value).unwrap_or_else(|e| {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                });
+                utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy)
+            };
+            if args.mode == "range" {
+                let jd_start = parse_flag(&args.start, "start");
+                let jd_end = parse_flag(&args.end, "end");
+                match search_heliacal_events(
+                    &engine,
+                    &eop_kernel,
+                    target,
+                    &location,
+                    jd_start,
+                    jd_end,
+                    &config,
+                ) {
+                    Ok(events) => {
+                        println!("Found {} heliacal events:", events.len());
+                        for ev in &events {
+                            print_heliacal_event("  Heliacal", ev);
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("Error: {e}");
+                        std::process::exit(1);
+                    }
+                }
+                return;
+            }
+            let jd_tdb = parse_flag(&args.date, "date");
+            let result = match (args.mode.as_str(), args.kind.as_str()) {
+                ("next", "rising") => {
+                    next_heliacal_rising(&engine, &eop_kernel, target, &location, jd_tdb, &config)
+                }
+                ("prev", "rising") => {
+                    prev_heliacal_rising(&engine, &eop_kernel, target, &location, jd_tdb, &config)
+                }
+                ("next", _) => {
+                    next_heliacal_setting(&engine, &eop_kernel, target, &location, jd_tdb, &config)
+                }
+                _ => {
+                    prev_heliacal_setting(&engine, &eop_kernel, target, &location, jd_tdb, &config)
+                }
+            };
+            match result {
+                Ok(Some(ev)) => {
+                    let label = if args.mode == "next" {
+                        "Next heliacal"
+                    } else {
+                        "Previous heliacal"
+                    };
+                    print_heliacal_event(label, &ev);
+                }
+                Ok(None) => println!("No heliacal {} found", args.kind),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+
         Commands::Position(args) => {
             let utc = parse_utc(&args.date).unwrap_or_else(|e| {
                 eprintln!("{e}");
@@ -6733,39 +7585,34 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
 
-            // Helper: ecliptic-of-date spherical coords at a given JD TDB.
-            let ecl_sph = |jd: f64| {
-                let q = Query {
+            let sv = engine
+                .query(Query {
                     target: t,
                     observer: obs,
                     frame: Frame::IcrfJ2000,
-                    epoch_tdb_jd: jd,
-                };
-                let sv = engine.query(q).unwrap_or_else(|e| {
+                    epoch_tdb_jd: jd_tdb,
+                })
+                .unwrap_or_else(|e| {
                     eprintln!("Error: {e}");
                     std::process::exit(1);
                 });
-                let ecl_j2000 = icrf_to_ecliptic(&sv.position_km);
-                let tc = (jd - 2_451_545.0) / 36525.0;
-                let ecl_date = precess_ecliptic_j2000_to_date(&ecl_j2000, tc);
-                cartesian_to_spherical(&ecl_date)
-            };
-
-            let sph = ecl_sph(jd_tdb);
-            const DT: f64 = 1.0 / 1440.0;
-            let sph_p = ecl_sph(jd_tdb + DT);
-            let sph_m = ecl_sph(jd_tdb - DT);
-            let dlon = ((sph_p.lon_deg - sph_m.lon_deg + 180.0).rem_euclid(360.0)) - 180.0;
-            let lon_speed = dlon / (2.0 * DT);
-            let lat_speed = (sph_p.lat_deg - sph_m.lat_deg) / (2.0 * DT);
-            let dist_speed = (sph_p.distance_km - sph_m.distance_km) / (2.0 * DT * 86_400.0);
-            println!("Position of {:?} from {:?}:", t, obs);
+            let (pos, vel) = icrf_state_to_position_frame(&sv, jd_tdb, args.frame);
+            let sph = cartesian_state_to_spherical_state(&pos, &vel);
+            println!("Position of {:?} from {:?} ({:?}):", t, obs, args.frame);
+            println!(
+                "  Position:       [{:.6}, {:.6}, {:.6}] km",
+                pos[0], pos[1], pos[2]
+            );
+            println!(
+                "  Velocity:       [{:.9}, {:.9}, {:.9}] km/s",
+                vel[0], vel[1], vel[2]
+            );
             println!("  Longitude:      {:.6}°", sph.lon_deg);
             println!("  Latitude:       {:.6}°", sph.lat_deg);
             println!("  Distance:       {:.6} km", sph.distance_km);
-            println!("  Lon speed:      {:.6} deg/day", lon_speed);
-            println!("  Lat speed:      {:.6} deg/day", lat_speed);
-            println!("  Distance speed: {:.6} km/s", dist_speed);
+            println!("  Lon speed:      {:.6} deg/day", sph.lon_speed);
+            println!("  Lat speed:      {:.6} deg/day", sph.lat_speed);
+            println!("  Distance speed: {:.6} km/s", sph.distance_speed);
         }
 
         Commands::SiderealLongitude(args) => {
@@ -6821,7 +7668,7 @@ fn main() {
                     reference_plane,
                 )
             } else {
-                dhruv_search::GrahaLongitudesConfig::sidereal_with_model(
+                cli_graha_longitudes_config(
                     system,
                     args.nutation,
                     precession_model,
@@ -6866,13 +7713,11 @@ fn main() {
                 let lon = lons.longitudes[i];
                 let rashi_info = dhruv_vedic_base::rashi_from_longitude(lon);
                 println!(
-                    "  {:8} {:>11.6}° ({} {}°{:02}'{:04.1}\")",
+                    "  {:8} {:>11.6}° ({} {})",
                     name,
                     lon,
                     rashi_info.rashi.name(),
-                    rashi_info.dms.degrees,
-                    rashi_info.dms.minutes,
-                    rashi_info.dms.seconds,
+                    rashi_info.dms.format(1),
                 );
             }
             if let Some(outer_lons) = outer_lons {
@@ -6881,13 +7726,11 @@ fn main() {
                 for (name, lon) in outer_names.iter().zip(outer_lons.iter()) {
                     let rashi_info = dhruv_vedic_base::rashi_from_longitude(*lon);
                     println!(
-                        "  {:8} {:>11.6}° ({} {}°{:02}'{:04.1}\")",
+                        "  {:8} {:>11.6}° ({} {})",
                         name,
                         lon,
                         rashi_info.rashi.name(),
-                        rashi_info.dms.degrees,
-                        rashi_info.dms.minutes,
-                        rashi_info.dms.seconds,
+                        rashi_info.dms.format(1),
                     );
                 }
             }
@@ -6912,7 +7755,7 @@ fn main() {
             let result = dhruv_search::moving_osculating_apogees(
                 &engine,
                 jd_tdb,
-                &dhruv_search::GrahaLongitudesConfig::sidereal_with_model(
+                &cli_graha_longitudes_config(
                     system,
                     args.nutation,
                     precession_model,
@@ -7151,8 +7994,9 @@ fn main() {
             println!("{}", ayana.name());
         }
 
-        Commands::SamvatsaraCompute { year } => {
-            let (samvatsara, cycle_index) = dhruv_vedic_base::samvatsara_from_year(year);
+        Commands::SamvatsaraCompute { year, scheme } => {
+            let (samvatsara, cycle_index) =
+                dhruv_vedic_base::samvatsara_from_year(year, parse_samvatsara_scheme(&scheme));
             println!(
                 "{} (index {} in 60-year cycle)",
                 samvatsara.name(),
@@ -7193,195 +8037,327 @@ fn main() {
             println!("Upaketu:     {:.6}°", upa.upaketu);
         }
 
-        Commands::GrahaHelper(args) => match args.op {
-            GrahaHelperOp::HoraLord => {
-                let vaar = require_vaar(args.vaar.unwrap_or_else(|| {
-                    eprintln!("--vaar is required for --op hora-lord");
-                    std::process::exit(1);
-                }));
-                let hora_index = args.hora_index.unwrap_or_else(|| {
-                    eprintln!("--hora-index is required for --op hora-lord");
-                    std::process::exit(1);
-                });
-                let lord = dhruv_vedic_base::hora_lord(vaar, hora_index);
-                println!("{} ({})", lord.index(), lord.name());
-            }
-            GrahaHelperOp::MasaLord => {
-                let masa = require_masa(args.masa.unwrap_or_else(|| {
-                    eprintln!("--masa is required for --op masa-lord");
-                    std::process::exit(1);
-                }));
-                let lord = dhruv_vedic_base::masa_lord(masa);
-                println!("{} ({})", lord.index(), lord.name());
-            }
-            GrahaHelperOp::SamvatsaraLord => {
-                let samvatsara = require_samvatsara(args.samvatsara.unwrap_or_else(|| {
-                    eprintln!("--samvatsara is required for --op samvatsara-lord");
-                    std::process::exit(1);
-                }));
-                let lord = dhruv_vedic_base::samvatsara_lord(samvatsara);
-                println!("{} ({})", lord.index(), lord.name());
+        Commands::GrahaHelper(args) => {
+            let combustion = CombustionConfig {
+                moon_orb_deg: args.moon_combustion_orb,
+            };
+            if let Err(e) = combustion.validate() {
+                eprintln!("Invalid --moon-combustion-orb: {e}");
+                std::process::exit(1);
             }
-            GrahaHelperOp::ExaltationDegree => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op exaltation-degree");
-                    std::process::exit(1);
-                }));
-                match dhruv_vedic_base::exaltation_degree(graha) {
-                    Some(value) => println!("{value:.6}"),
-                    None => println!("none"),
+            match args.op {
+                GrahaHelperOp::NakshatraLord => {
+                    let nakshatra = args.nakshatra.unwrap_or_else(|| {
+                        eprintln!("--nakshatra is required for --op nakshatra-lord");
+                        std::process::exit(1);
+                    });
+                    let lord = dhruv_vedic_base::dasha::nakshatra_dasha_lord(nakshatra)
+                        .unwrap_or_else(|| {
+                            eprintln!(
+                                "Invalid nakshatra index: {nakshatra} (0-26: Ashwini..Revati)"
+                            );
+                            std::process::exit(1);
+                        });
+                    println!("{} ({})", lord.index(), lord.name());
                 }
-            }
-            GrahaHelperOp::DebilitationDegree => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op debilitation-degree");
-                    std::process::exit(1);
-                }));
-                match dhruv_vedic_base::debilitation_degree(graha) {
-                    Some(value) => println!("{value:.6}"),
-                    None => println!("none"),
+                GrahaHelperOp::Navatara => {
+                    let (Some(start), Some(target)) = (args.nakshatra, args.other_nakshatra) else {
+                        eprintln!(
+                            "--nakshatra and --other-nakshatra are required for --op navatara"
+                        );
+                        std::process::exit(1);
+                    };
+                    let nakshatra = |index: u8| {
+                        dhruv_vedic_base::ALL_NAKSHATRAS_27
+                            .get(index as usize)
+                            .copied()
+                            .unwrap_or_else(|| {
+                                eprintln!(
+                                    "Invalid nakshatra index: {index} (0-26: Ashwini..Revati)"
+                                );
+                                std::process::exit(1);
+                            })
+                    };
+                    let tara = dhruv_vedic_base::navatara_from(nakshatra(start), nakshatra(target));
+                    println!("{} ({})", tara.index(), tara.name());
                 }
-            }
-            GrahaHelperOp::MoolatrikoneRange => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op moolatrikone-range");
-                    std::process::exit(1);
-                }));
-                match dhruv_vedic_base::moolatrikone_range(graha) {
-                    Some((rashi_index, start_deg, end_deg)) => {
-                        println!("{rashi_index},{start_deg:.6},{end_deg:.6}")
+                GrahaHelperOp::KpSublord => {
+                    let lon = args.sidereal_lon.unwrap_or_else(|| {
+                        eprintln!("--sidereal-lon is required for --op kp-sublord");
+                        std::process::exit(1);
+                    });
+                    let (nakshatra, star, sub) = dhruv_vedic_base::kp_sublord(lon);
+                    println!("Nakshatra: {}", nakshatra.name());
+                    println!("Star lord: {}", star.name());
+                    println!("Sub lord:  {}", sub.name());
+                }
+                GrahaHelperOp::Gandanta => {
+                    let lon = args.sidereal_lon.unwrap_or_else(|| {
+                        eprintln!("--sidereal-lon is required for --op gandanta");
+                        std::process::exit(1);
+                    });
+                    let Some(status) = dhruv_vedic_base::gandanta_status(lon) else {
+                        println!("Not in a gandanta zone");
+                        return;
+                    };
+                    let junction = match status.kind {
+                        dhruv_vedic_base::GandantaKind::MeenaMesha => "Meena-Mesha",
+                        dhruv_vedic_base::GandantaKind::KarkaSimha => "Karka-Simha",
+                        dhruv_vedic_base::GandantaKind::VrischikaDhanu => "Vrischika-Dhanu",
+                    };
+                    let half = match status.half {
+                        dhruv_vedic_base::GandantaHalf::Approaching => "approaching",
+                        dhruv_vedic_base::GandantaHalf::Leaving => "leaving",
+                    };
+                    println!("Junction: {junction}");
+                    println!("Half:     {half}");
+                    println!("Offset:   {:.6}°", status.offset_deg);
+                }
+                GrahaHelperOp::HoraLord => {
+                    let vaar = require_vaar(args.vaar.unwrap_or_else(|| {
+                        eprintln!("--vaar is required for --op hora-lord");
+                        std::process::exit(1);
+                    }));
+                    let hora_index = args.hora_index.unwrap_or_else(|| {
+                        eprintln!("--hora-index is required for --op hora-lord");
+                        std::process::exit(1);
+                    });
+                    let lord = dhruv_vedic_base::hora_lord(vaar, hora_index);
+                    println!("{} ({})", lord.index(), lord.name());
+                }
+                GrahaHelperOp::MasaLord => {
+                    let masa = require_masa(args.masa.unwrap_or_else(|| {
+                        eprintln!("--masa is required for --op masa-lord");
+                        std::process::exit(1);
+                    }));
+                    let lord = dhruv_vedic_base::masa_lord(masa);
+                    println!("{} ({})", lord.index(), lord.name());
+                }
+                GrahaHelperOp::SamvatsaraLord => {
+                    let samvatsara = require_samvatsara(args.samvatsara.unwrap_or_else(|| {
+                        eprintln!("--samvatsara is required for --op samvatsara-lord");
+                        std::process::exit(1);
+                    }));
+                    let lord = dhruv_vedic_base::samvatsara_lord(samvatsara);
+                    println!("{} ({})", lord.index(), lord.name());
+                }
+                GrahaHelperOp::ExaltationDegree => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op exaltation-degree");
+                        std::process::exit(1);
+                    }));
+                    match dhruv_vedic_base::exaltation_degree(graha) {
+                        Some(value) => println!("{value:.6}"),
+                        None => println!("none"),
                     }
-                    None => println!("none"),
                 }
-            }
-            GrahaHelperOp::CombustionThreshold => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op combustion-threshold");
-                    std::process::exit(1);
-                }));
-                match dhruv_vedic_base::combustion_threshold(graha, args.retrograde) {
-                    Some(value) => println!("{value:.6}"),
-                    None => println!("none"),
+                GrahaHelperOp::DebilitationDegree => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op debilitation-degree");
+                        std::process::exit(1);
+                    }));
+                    match dhruv_vedic_base::debilitation_degree(graha) {
+                        Some(value) => println!("{value:.6}"),
+                        None => println!("none"),
+                    }
                 }
-            }
-            GrahaHelperOp::IsCombust => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op is-combust");
-                    std::process::exit(1);
-                }));
-                let graha_lon = args.sidereal_lon.unwrap_or_else(|| {
-                    eprintln!("--sidereal-lon is required for --op is-combust");
-                    std::process::exit(1);
-                });
-                let sun_lon = args.sun_lon.unwrap_or_else(|| {
-                    eprintln!("--sun-lon is required for --op is-combust");
-                    std::process::exit(1);
-                });
-                println!(
-                    "{}",
-                    dhruv_vedic_base::is_combust(graha, graha_lon, sun_lon, args.retrograde)
-                );
-            }
-            GrahaHelperOp::AllCombustionStatus => {
-                let longitudes =
-                    parse_longitudes_9(args.longitudes.as_deref().unwrap_or_else(|| {
-                        eprintln!("--longitudes is required for --op all-combustion-status");
+                GrahaHelperOp::MoolatrikoneRange => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op moolatrikone-range");
+                        std::process::exit(1);
+                    }));
+                    match dhruv_vedic_base::moolatrikone_range(graha) {
+                        Some((rashi_index, start_deg, end_deg)) => {
+                            println!("{rashi_index},{start_deg:.6},{end_deg:.6}")
+                        }
+                        None => println!("none"),
+                    }
+                }
+                GrahaHelperOp::CombustionThreshold => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op combustion-threshold");
+                        std::process::exit(1);
+                    }));
+                    match dhruv_vedic_base::combustion_threshold(
+                        graha,
+                        args.retrograde,
+                        &combustion,
+                    ) {
+                        Some(value) => println!("{value:.6}"),
+                        None => println!("none"),
+                    }
+                }
+                GrahaHelperOp::IsCombust => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op is-combust");
+                        std::process::exit(1);
+                    }));
+                    let graha_lon = args.sidereal_lon.unwrap_or_else(|| {
+                        eprintln!("--sidereal-lon is required for --op is-combust");
+                        std::process::exit(1);
+                    });
+                    let sun_lon = args.sun_lon.unwrap_or_else(|| {
+                        eprintln!("--sun-lon is required for --op is-combust");
+                        std::process::exit(1);
+                    });
+                    println!(
+                        "{}",
+                        dhruv_vedic_base::is_combust(
+                            graha,
+                            graha_lon,
+                            sun_lon,
+                            args.retrograde,
+                            &combustion,
+                        )
+                    );
+                }
+                GrahaHelperOp::AllCombustionStatus => {
+                    let longitudes =
+                        parse_longitudes_9(args.longitudes.as_deref().unwrap_or_else(|| {
+                            eprintln!("--longitudes is required for --op all-combustion-status");
+                            std::process::exit(1);
+                        }));
+                    let retrograde_flags =
+                        parse_bools_9(args.retrograde_flags.as_deref().unwrap_or_else(|| {
+                            eprintln!(
+                                "--retrograde-flags is required for --op all-combustion-status"
+                            );
+                            std::process::exit(1);
+                        }));
+                    let out = dhruv_vedic_base::all_combustion_status(
+                        &longitudes,
+                        &retrograde_flags,
+                        &combustion,
+                    );
+                    println!(
+                        "{}",
+                        out.iter()
+                            .map(|value| if *value { "true" } else { "false" })
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    );
+                }
+                GrahaHelperOp::NaisargikaMaitri => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op naisargika-maitri");
+                        std::process::exit(1);
+                    }));
+                    let other = require_graha(args.other.unwrap_or_else(|| {
+                        eprintln!("--other is required for --op naisargika-maitri");
+                        std::process::exit(1);
+                    }));
+                    println!(
+                        "{}",
+                        naisargika_label(dhruv_vedic_base::naisargika_maitri(graha, other))
+                    );
+                }
+                GrahaHelperOp::TatkalikaMaitri => {
+                    let graha_rashi = args.rashi.unwrap_or_else(|| {
+                        eprintln!("--rashi is required for --op tatkalika-maitri");
+                        std::process::exit(1);
+                    });
+                    let other_rashi = args.other_rashi.unwrap_or_else(|| {
+                        eprintln!("--other-rashi is required for --op tatkalika-maitri");
+                        std::process::exit(1);
+                    });
+                    println!(
+                        "{}",
+                        tatkalika_label(dhruv_vedic_base::tatkalika_maitri(
+                            graha_rashi,
+                            other_rashi
+                        ))
+                    );
+                }
+                GrahaHelperOp::PanchadhaMaitri => {
+                    let naisargika = parse_cli_naisargika(args.naisargika.unwrap_or_else(|| {
+                        eprintln!("--naisargika is required for --op panchadha-maitri");
+                        std::process::exit(1);
+                    }));
+                    let tatkalika = parse_cli_tatkalika(args.tatkalika.unwrap_or_else(|| {
+                        eprintln!("--tatkalika is required for --op panchadha-maitri");
+                        std::process::exit(1);
+                    }));
+                    println!(
+                        "{}",
+                        panchadha_label(dhruv_vedic_base::panchadha_maitri(naisargika, tatkalika))
+                    );
+                }
+                GrahaHelperOp::RelationshipMatrix => {
+                    let longitudes =
+                        parse_longitudes_9(args.longitudes.as_deref().unwrap_or_else(|| {
+                            eprintln!("--longitudes is required for --op relationship-matrix");
+                            std::process::exit(1);
+                        }));
+                    let matrix = dhruv_vedic_base::relationship_matrix(&longitudes);
+                    for (graha, row) in ALL_GRAHAS.iter().zip(matrix.iter()) {
+                        let cells: Vec<&str> = row
+                            .iter()
+                            .map(|cell| cell.map_or("-", panchadha_label))
+                            .collect();
+                        println!("{:<8} {}", graha.name(), cells.join(","));
+                    }
+                }
+                GrahaHelperOp::RashiDrishti => {
+                    let from = require_rashi(args.rashi.unwrap_or_else(|| {
+                        eprintln!("--rashi is required for --op rashi-drishti");
+                        std::process::exit(1);
+                    }));
+                    let to = require_rashi(args.other_rashi.unwrap_or_else(|| {
+                        eprintln!("--other-rashi is required for --op rashi-drishti");
+                        std::process::exit(1);
+                    }));
+                    println!("{}", dhruv_vedic_base::rashi_drishti(from, to));
+                }
+                GrahaHelperOp::RashiDrishtiMatrix => {
+                    let matrix = dhruv_vedic_base::rashi_drishti_matrix();
+                    for (from, row) in dhruv_vedic_base::ALL_RASHIS.iter().zip(matrix.iter()) {
+                        let aspected: Vec<&str> = dhruv_vedic_base::ALL_RASHIS
+                            .iter()
+                            .zip(row.iter())
+                            .filter(|&(_, &aspects)| aspects)
+                            .map(|(to, _)| to.name())
+                            .collect();
+                        println!("{:<10} {}", from.name(), aspected.join(", "));
+                    }
+                }
+                GrahaHelperOp::DignityInRashi => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op dignity-in-rashi");
+                        std::process::exit(1);
+                    }));
+                    let sidereal_lon = args.sidereal_lon.unwrap_or_else(|| {
+                        eprintln!("--sidereal-lon is required for --op dignity-in-rashi");
+                        std::process::exit(1);
+                    });
+                    let rashi = args.rashi.unwrap_or_else(|| {
+                        eprintln!("--rashi is required for --op dignity-in-rashi");
+                        std::process::exit(1);
+                    });
+                    println!(
+                        "{}",
+                        dignity_label(dhruv_vedic_base::dignity_in_rashi(
+                            graha,
+                            sidereal_lon,
+                            rashi
+                        ))
+                    );
+                }
+                GrahaHelperOp::DignityInRashiWithPositions => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op dignity-in-rashi-with-positions");
+                        std::process::exit(1);
+                    }));
+                    let sidereal_lon = args.sidereal_lon.unwrap_or_else(|| {
+                        eprintln!(
+                            "--sidereal-lon is required for --op dignity-in-rashi-with-positions"
+                        );
                         std::process::exit(1);
-                    }));
-                let retrograde_flags =
-                    parse_bools_9(args.retrograde_flags.as_deref().unwrap_or_else(|| {
-                        eprintln!("--retrograde-flags is required for --op all-combustion-status");
+                    });
+                    let rashi = args.rashi.unwrap_or_else(|| {
+                        eprintln!("--rashi is required for --op dignity-in-rashi-with-positions");
                         std::process::exit(1);
-                    }));
-                let out = dhruv_vedic_base::all_combustion_status(&longitudes, &retrograde_flags);
-                println!(
-                    "{}",
-                    out.iter()
-                        .map(|value| if *value { "true" } else { "false" })
-                        .collect::<Vec<_>>()
-                        .join(",")
-                );
-            }
-            GrahaHelperOp::NaisargikaMaitri => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op naisargika-maitri");
-                    std::process::exit(1);
-                }));
-                let other = require_graha(args.other.unwrap_or_else(|| {
-                    eprintln!("--other is required for --op naisargika-maitri");
-                    std::process::exit(1);
-                }));
-                println!(
-                    "{}",
-                    naisargika_label(dhruv_vedic_base::naisargika_maitri(graha, other))
-                );
-            }
-            GrahaHelperOp::TatkalikaMaitri => {
-                let graha_rashi = args.rashi.unwrap_or_else(|| {
-                    eprintln!("--rashi is required for --op tatkalika-maitri");
-                    std::process::exit(1);
-                });
-                let other_rashi = args.other_rashi.unwrap_or_else(|| {
-                    eprintln!("--other-rashi is required for --op tatkalika-maitri");
-                    std::process::exit(1);
-                });
-                println!(
-                    "{}",
-                    tatkalika_label(dhruv_vedic_base::tatkalika_maitri(graha_rashi, other_rashi))
-                );
-            }
-            GrahaHelperOp::PanchadhaMaitri => {
-                let naisargika = parse_cli_naisargika(args.naisargika.unwrap_or_else(|| {
-                    eprintln!("--naisargika is required for --op panchadha-maitri");
-                    std::process::exit(1);
-                }));
-                let tatkalika = parse_cli_tatkalika(args.tatkalika.unwrap_or_else(|| {
-                    eprintln!("--tatkalika is required for --op panchadha-maitri");
-                    std::process::exit(1);
-                }));
-                println!(
-                    "{}",
-                    panchadha_label(dhruv_vedic_base::panchadha_maitri(naisargika, tatkalika))
-                );
-            }
-            GrahaHelperOp::DignityInRashi => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op dignity-in-rashi");
-                    std::process::exit(1);
-                }));
-                let sidereal_lon = args.sidereal_lon.unwrap_or_else(|| {
-                    eprintln!("--sidereal-lon is required for --op dignity-in-rashi");
-                    std::process::exit(1);
-                });
-                let rashi = args.rashi.unwrap_or_else(|| {
-                    eprintln!("--rashi is required for --op dignity-in-rashi");
-                    std::process::exit(1);
-                });
-                println!(
-                    "{}",
-                    dignity_label(dhruv_vedic_base::dignity_in_rashi(
-                        graha,
-                        sidereal_lon,
-                        rashi
-                    ))
-                );
-            }
-            GrahaHelperOp::DignityInRashiWithPositions => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op dignity-in-rashi-with-positions");
-                    std::process::exit(1);
-                }));
-                let sidereal_lon = args.sidereal_lon.unwrap_or_else(|| {
-                    eprintln!(
-                        "--sidereal-lon is required for --op dignity-in-rashi-with-positions"
-                    );
-                    std::process::exit(1);
-                });
-                let rashi = args.rashi.unwrap_or_else(|| {
-                    eprintln!("--rashi is required for --op dignity-in-rashi-with-positions");
-                    std::process::exit(1);
-                });
-                let all_rashi_indices = parse_u8s::<7>(
+                    });
+                    let all_rashi_indices = parse_u8s::<7>(
                     args.all_rashi_indices_7.as_deref().unwrap_or_else(|| {
                         eprintln!(
                             "--all-rashi-indices-7 is required as D1 positions for --op dignity-in-rashi-with-positions"
@@ -7390,26 +8366,26 @@ fn main() {
                     }),
                     "D1 rashi index",
                 );
-                println!(
-                    "{}",
-                    dignity_label(dhruv_vedic_base::dignity_in_rashi_with_positions(
-                        graha,
-                        sidereal_lon,
-                        rashi,
-                        &all_rashi_indices,
-                    ))
-                );
-            }
-            GrahaHelperOp::NodeDignityInRashi => {
-                let node = parse_cli_node(args.node.unwrap_or_else(|| {
-                    eprintln!("--node is required for --op node-dignity-in-rashi");
-                    std::process::exit(1);
-                }));
-                let rashi = args.rashi.unwrap_or_else(|| {
-                    eprintln!("--rashi is required for --op node-dignity-in-rashi");
-                    std::process::exit(1);
-                });
-                let all_rashi_indices = parse_u8s::<9>(
+                    println!(
+                        "{}",
+                        dignity_label(dhruv_vedic_base::dignity_in_rashi_with_positions(
+                            graha,
+                            sidereal_lon,
+                            rashi,
+                            &all_rashi_indices,
+                        ))
+                    );
+                }
+                GrahaHelperOp::NodeDignityInRashi => {
+                    let node = parse_cli_node(args.node.unwrap_or_else(|| {
+                        eprintln!("--node is required for --op node-dignity-in-rashi");
+                        std::process::exit(1);
+                    }));
+                    let rashi = args.rashi.unwrap_or_else(|| {
+                        eprintln!("--rashi is required for --op node-dignity-in-rashi");
+                        std::process::exit(1);
+                    });
+                    let all_rashi_indices = parse_u8s::<9>(
                     args.all_rashi_indices_9.as_deref().unwrap_or_else(|| {
                         eprintln!(
                             "--all-rashi-indices-9 is required as D1 positions for --op node-dignity-in-rashi"
@@ -7418,44 +8394,55 @@ fn main() {
                     }),
                     "D1 rashi index",
                 );
-                println!(
-                    "{}",
-                    dignity_label(dhruv_vedic_base::node_dignity_in_rashi(
-                        node,
-                        rashi,
-                        &all_rashi_indices,
-                        parse_cli_node_policy(args.node_policy),
-                    ))
-                );
-            }
-            GrahaHelperOp::NaturalBeneficMalefic => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op natural-benefic-malefic");
-                    std::process::exit(1);
-                }));
-                println!(
-                    "{}",
-                    benefic_label(dhruv_vedic_base::natural_benefic_malefic(graha))
-                );
-            }
-            GrahaHelperOp::MoonBeneficNature => {
-                let elongation = args.moon_sun_elongation.unwrap_or_else(|| {
-                    eprintln!("--moon-sun-elongation is required for --op moon-benefic-nature");
-                    std::process::exit(1);
-                });
-                println!(
-                    "{}",
-                    benefic_label(dhruv_vedic_base::moon_benefic_nature(elongation))
-                );
-            }
-            GrahaHelperOp::GrahaGender => {
-                let graha = require_graha(args.graha.unwrap_or_else(|| {
-                    eprintln!("--graha is required for --op graha-gender");
-                    std::process::exit(1);
-                }));
-                println!("{}", gender_label(dhruv_vedic_base::graha_gender(graha)));
+                    println!(
+                        "{}",
+                        dignity_label(dhruv_vedic_base::node_dignity_in_rashi(
+                            node,
+                            rashi,
+                            &all_rashi_indices,
+                            parse_cli_node_policy(args.node_policy),
+                        ))
+                    );
+                }
+                GrahaHelperOp::NaturalBeneficMalefic => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op natural-benefic-malefic");
+                        std::process::exit(1);
+                    }));
+                    println!(
+                        "{}",
+                        benefic_label(dhruv_vedic_base::natural_benefic_malefic(graha))
+                    );
+                }
+                GrahaHelperOp::MoonBeneficNature => {
+                    let elongation = args.moon_sun_elongation.unwrap_or_else(|| {
+                        eprintln!("--moon-sun-elongation is required for --op moon-benefic-nature");
+                        std::process::exit(1);
+                    });
+                    println!(
+                        "{}",
+                        benefic_label(dhruv_vedic_base::moon_benefic_nature(elongation))
+                    );
+                }
+                GrahaHelperOp::GrahaGender => {
+                    let graha = require_graha(args.graha.unwrap_or_else(|| {
+                        eprintln!("--graha is required for --op graha-gender");
+                        std::process::exit(1);
+                    }));
+                    println!("{}", gender_label(dhruv_vedic_base::graha_gender(graha)));
+                }
+                GrahaHelperOp::WholeSignBhavas => {
+                    let lagna = args.sidereal_lon.unwrap_or_else(|| {
+                        eprintln!("--sidereal-lon (lagna) is required for --op whole-sign-bhavas");
+                        std::process::exit(1);
+                    });
+                    print_bhava_table(
+                        "Whole-sign bhavas from lagna (sidereal)",
+                        &dhruv_vedic_base::whole_sign_from_lagna(lagna),
+                    );
+                }
             }
-        },
+        }
 
         Commands::TimeUtility(args) => match args.op {
             TimeUtilityOp::AyanamshaSystemCount => {
@@ -7633,6 +8620,22 @@ fn main() {
                 };
                 println!("{:.12},{:.12},{:.12}", out[0], out[1], out[2]);
             }
+            TimeUtilityOp::GhatikaToDuration => {
+                let ghati = args.ghati.unwrap_or_else(|| {
+                    eprintln!("--ghati is required for --op ghatika-to-duration");
+                    std::process::exit(1);
+                });
+                let hours = dhruv_vedic_base::ghatika_to_duration(ghati, args.vighati);
+                println!("{hours:.9} h");
+            }
+            TimeUtilityOp::DurationToGhatika => {
+                let hours = args.hours.unwrap_or_else(|| {
+                    eprintln!("--hours is required for --op duration-to-ghatika");
+                    std::process::exit(1);
+                });
+                let (ghati, vighati) = dhruv_vedic_base::duration_to_ghatika(hours);
+                println!("{ghati} ghati {vighati:.6} vighati");
+            }
         },
 
         Commands::TaraPrimitive(args) => match args.op {
@@ -7751,7 +8754,7 @@ fn main() {
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             match dhruv_search::sidereal_sum_at(&engine, jd_tdb, &config) {
                 Ok(val) => println!("{:.6}°", val),
                 Err(e) => {
@@ -7790,7 +8793,7 @@ fn main() {
                 longitude_deg: args.lon,
                 altitude_m: args.alt,
             };
-            let rs_config = RiseSetConfig::default();
+            let rs_config = riseset_config_from_cli(&args.riseset);
             match dhruv_search::vedic_day_sunrises(
                 &engine,
                 &eop_kernel,
@@ -7866,7 +8869,7 @@ fn main() {
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             match dhruv_search::yoga_at(&engine, jd_tdb, args.sum, &config) {
                 Ok(info) => {
                     println!(
@@ -7892,7 +8895,7 @@ fn main() {
             let system = require_aya_system(args.ayanamsha);
             let engine = load_engine(&args.bsp, &args.lsk);
             let jd_tdb = utc_to_jd_tdb_with_policy(&utc, engine.lsk(), time_policy);
-            let config = SankrantiConfig::new(system, args.nutation);
+            let config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             match dhruv_search::nakshatra_at(&engine, jd_tdb, args.moon_sid, &config) {
                 Ok(info) => {
                     println!(
@@ -7994,7 +8997,7 @@ fn main() {
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let amsha_selection = args
                 .amsha
                 .as_deref()
@@ -8046,7 +9049,7 @@ fn main() {
                     args.date, args.lat, args.lon
                 );
                 println!(
-                    "{:<8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>6}",
+                    "{:<8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>6} {:>8} {:>8}",
                     "Graha",
                     "Sthana",
                     "Dig",
@@ -8056,12 +9059,14 @@ fn main() {
                     "Drik",
                     "Total",
                     "Reqd",
-                    "Strong"
+                    "Strong",
+                    "Ishta",
+                    "Kashta"
                 );
-                println!("{}", "-".repeat(88));
+                println!("{}", "-".repeat(106));
                 for (i, entry) in result.entries.iter().enumerate() {
                     println!(
-                        "{:<8} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>6}",
+                        "{:<8} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>6} {:>8.2} {:>8.2}",
                         graha_names[i],
                         entry.sthana.total,
                         entry.dig,
@@ -8072,6 +9077,8 @@ fn main() {
                         entry.total_shashtiamsas,
                         entry.required_strength,
                         if entry.is_strong { "Yes" } else { "No" },
+                        entry.ishta,
+                        entry.kashta,
                     );
                 }
             }
@@ -8087,7 +9094,7 @@ fn main() {
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
 
             if let Some(bhava_number) = args.bhava {
                 let entry = dhruv_search::bhavabala_for_bhava(
@@ -8163,7 +9170,7 @@ fn main() {
             let scheme = parse_charakaraka_scheme(&args.scheme);
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
 
             let result =
                 dhruv_search::charakaraka_for_date(&engine, &eop_kernel, &utc, &aya_config, scheme)
@@ -8207,7 +9214,7 @@ fn main() {
             let engine = load_engine(&args.bsp, &args.lsk);
             let eop_kernel = load_eop(&args.eop);
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let policy = parse_node_policy(&args.node_policy);
             let amsha_selection = args
                 .amsha
@@ -8288,7 +9295,7 @@ fn main() {
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let policy = parse_node_policy(&args.node_policy);
             let amsha_selection = args
                 .amsha
@@ -8319,7 +9326,7 @@ fn main() {
             );
             println!("Shadbala:");
             println!(
-                "{:<8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>6}",
+                "{:<8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>6} {:>8} {:>8}",
                 "Graha",
                 "Sthana",
                 "Dig",
@@ -8329,12 +9336,14 @@ fn main() {
                 "Drik",
                 "Total",
                 "Reqd",
-                "Strong"
+                "Strong",
+                "Ishta",
+                "Kashta"
             );
-            println!("{}", "-".repeat(88));
+            println!("{}", "-".repeat(106));
             for entry in &result.shadbala.entries {
                 println!(
-                    "{:<8} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>6}",
+                    "{:<8} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>8.2} {:>6} {:>8.2} {:>8.2}",
                     entry.graha.name(),
                     entry.sthana.total,
                     entry.dig,
@@ -8345,6 +9354,8 @@ fn main() {
                     entry.total_shashtiamsas,
                     entry.required_strength,
                     if entry.is_strong { "Yes" } else { "No" },
+                    entry.ishta,
+                    entry.kashta,
                 );
             }
             println!();
@@ -8434,7 +9445,7 @@ fn main() {
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let requests = parse_amsha_specs(&args.amsha);
             let scope = amsha_scope(
                 args.include_bhava_cusps,
@@ -8474,6 +9485,43 @@ fn main() {
                 });
             }
         }
+        Commands::Navamsa(args) => {
+            let utc = parse_utc(&args.date).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let system = require_aya_system(args.ayanamsha);
+            let engine = load_engine(&args.bsp, &args.lsk);
+            let eop_kernel = load_eop(&args.eop);
+            let location = GeoLocation::new(args.lat, args.lon, args.alt);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
+            let chart =
+                dhruv_search::navamsa_chart(&engine, &eop_kernel, &utc, &location, &aya_config)
+                    .unwrap_or_else(|e| {
+                        eprintln!("Error: {e}");
+                        std::process::exit(1);
+                    });
+            println!(
+                "Navamsa (D9) for {} at {:.6}°N, {:.6}°E\n",
+                args.date, args.lat, args.lon
+            );
+            println!("  {:8} {:<24} Navamsa (D9)", "", "Rasi (D1)");
+            println!(
+                "  {:8} {:<24} {}",
+                "Lagna",
+                format_rashi_dms(chart.rasi_lagna.sidereal_longitude),
+                chart.lagna.rashi.name()
+            );
+            for graha in ALL_GRAHAS {
+                let i = graha.index() as usize;
+                println!(
+                    "  {:8} {:<24} {}",
+                    graha.name(),
+                    format_rashi_dms(chart.rasi_grahas[i].sidereal_longitude),
+                    chart.grahas[i].rashi.name()
+                );
+            }
+        }
         Commands::Avastha(args) => {
             let system = require_aya_system(args.ayanamsha);
             let utc = parse_utc(&args.date).unwrap_or_else(|e| {
@@ -8485,7 +9533,7 @@ fn main() {
             let location = GeoLocation::new(args.lat, args.lon, args.alt);
             let bhava_config = bhava_config_from_cli(&args.bhava_behavior);
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(system, args.nutation);
+            let aya_config = with_cli_custom_ayanamsha(SankrantiConfig::new(system, args.nutation));
             let policy = parse_node_policy(&args.node_policy);
             let amsha_selection = args
                 .amsha
@@ -8563,9 +9611,13 @@ fn main() {
             let eop_kernel = load_eop(&args.eop);
             let bhava_config = BhavaConfig::default();
             let rs_config = RiseSetConfig::default();
-            let aya_config = SankrantiConfig::new(aya_system, args.nutation);
+            let aya_config =
+                with_cli_custom_ayanamsha(SankrantiConfig::new(aya_system, args.nutation));
             let dasha_system = parse_dasha_system(&args.system);
-            let variation = dhruv_vedic_base::dasha::DashaVariationConfig::default();
+            let variation = dhruv_vedic_base::dasha::DashaVariationConfig {
+                year_length: parse_dasha_year_length(&args.year_length),
+                ..Default::default()
+            };
             let clamped_level = args.max_level.min(dhruv_vedic_base::dasha::MAX_DASHA_LEVEL);
             let mode = args.mode.as_deref().unwrap_or(
                 if args.query_date.is_some() || args.query_jd.is_some() {
@@ -8642,6 +9694,10 @@ fn main() {
                 eprintln!("--query-jd is only supported together with raw dasha inputs.");
                 std::process::exit(1);
             }
+            if !raw_inputs_requested && (args.start_jd.is_some() || args.end_jd.is_some()) {
+                eprintln!("--start-jd/--end-jd are only supported together with raw dasha inputs.");
+                std::process::exit(1);
+            }
             let birth_label = if raw_inputs_requested {
                 if let Some(date) = args.birth_date.as_deref() {
                     date.to_string()
@@ -8773,7 +9829,12 @@ fn main() {
                 }
                 "level0" => {
                     let periods = if raw_inputs_requested {
-                        dhruv_search::dasha_level0_with_inputs(birth_jd, dasha_system, &raw_inputs)
+                        dhruv_search::dasha_level0_with_inputs(
+                            birth_jd,
+                            dasha_system,
+                            &variation,
+                            &raw_inputs,
+                        )
                     } else {
                         let birth_utc = birth_utc.as_ref().unwrap_or_else(|| {
                             eprintln!("--birth-date is required for --mode level0");
@@ -8792,6 +9853,7 @@ fn main() {
                             &bhava_config,
                             &rs_config,
                             &aya_config,
+                            &variation,
                         )
                     }
                     .unwrap_or_else(|e| {
@@ -8816,6 +9878,7 @@ fn main() {
                             birth_jd,
                             dasha_system,
                             entity,
+                            &variation,
                             &raw_inputs,
                         )
                     } else {
@@ -8837,6 +9900,7 @@ fn main() {
                             &bhava_config,
                             &rs_config,
                             &aya_config,
+                            &variation,
                         )
                     }
                     .unwrap_or_else(|e| {
@@ -8855,6 +9919,93 @@ fn main() {
                         None => println!("No matching level0 period found."),
                     }
                 }
+                "transitions" => {
+                    let level = dhruv_vedic_base::dasha::DashaLevel::from_u8(args.level)
+                        .unwrap_or_else(|| {
+                            eprintln!("--level must be 0-4");
+                            std::process::exit(1);
+                        });
+                    let parse_bound = |date: Option<&String>, flag: &str| {
+                        let date = date.unwrap_or_else(|| {
+                            eprintln!("--{flag}-date is required for --mode transitions");
+                            std::process::exit(1);
+                        });
+                        parse_utc(date).unwrap_or_else(|e| {
+                            eprintln!("{e}");
+                            std::process::exit(1);
+                        })
+                    };
+                    let periods = if raw_inputs_requested {
+                        let start_jd = args.start_jd.unwrap_or_else(|| {
+                            utc_to_jd_utc(&parse_bound(args.start_date.as_ref(), "start"))
+                        });
+                        let end_jd = args.end_jd.unwrap_or_else(|| {
+                            utc_to_jd_utc(&parse_bound(args.end_date.as_ref(), "end"))
+                        });
+                        dhruv_search::dasha_transitions_with_inputs(
+                            birth_jd,
+                            start_jd,
+                            end_jd,
+                            dasha_system,
+                            level,
+                            &variation,
+                            &raw_inputs,
+                        )
+                    } else {
+                        let birth_utc = birth_utc.as_ref().unwrap_or_else(|| {
+                            eprintln!("--birth-date is required for --mode transitions");
+                            std::process::exit(1);
+                        });
+                        let location = location.as_ref().unwrap_or_else(|| {
+                            eprintln!("--lat and --lon are required for --mode transitions");
+                            std::process::exit(1);
+                        });
+                        dhruv_search::dasha_transitions_in_range(
+                            &engine,
+                            &eop_kernel,
+                            birth_utc,
+                            &parse_bound(args.start_date.as_ref(), "start"),
+                            &parse_bound(args.end_date.as_ref(), "end"),
+                            location,
+                            dasha_system,
+                            level,
+                            &bhava_config,
+                            &rs_config,
+                            &aya_config,
+                            &variation,
+                        )
+                    }
+                    .unwrap_or_else(|e| {
+                        eprintln!("Error: {e}");
+                        std::process::exit(1);
+                    });
+                    println!(
+                        "Dasha Transitions ({}) level {} for birth {}\n",
+                        dasha_system.name(),
+                        level.name(),
+                        birth_label
+                    );
+                    for range_period in &periods {
+                        let period = &range_period.period;
+                        println!(
+                            "  {}{} (UTC {} - {}){}{}",
+                            "  ".repeat(period.level as usize),
+                            format_dasha_entity(&period.entity),
+                            jd_utc_to_iso_string(period.start_jd),
+                            jd_utc_to_iso_string(period.end_jd),
+                            if range_period.clipped_start {
+                                " [began before range]"
+                            } else {
+                                ""
+                            },
+                            if range_period.clipped_end {
+                                " [ends after range]"
+                            } else {
+                                ""
+                            },
+                        );
+                    }
+                }
                 "children" | "child-period" | "complete-level" => {
                     let parent_level = args.parent_level.unwrap_or_else(|| {
                         eprintln!("--parent-level is required for --mode {mode}");
@@ -9287,6 +10438,20 @@ fn parse_dasha_systems_config(s: &str, max_level: u8) -> dhruv_search::DashaSele
     }
 }
 
+fn parse_dasha_year_length(s: &str) -> dhruv_vedic_base::dasha::DashaYearLength {
+    match s.to_ascii_lowercase().as_str() {
+        "julian" | "0" => dhruv_vedic_base::dasha::DashaYearLength::Julian,
+        "savana" | "1" => dhruv_vedic_base::dasha::DashaYearLength::Savana,
+        "tropical" | "2" => dhruv_vedic_base::dasha::DashaYearLength::Tropical,
+        "sidereal" | "3" => dhruv_vedic_base::dasha::DashaYearLength::Sidereal,
+        other => {
+            eprintln!("Unknown dasha year length: {other}");
+            eprintln!("Valid: julian, savana, tropical, sidereal");
+            std::process::exit(1);
+        }
+    }
+}
+
 fn parse_dasha_system(s: &str) -> dhruv_vedic_base::dasha::DashaSystem {
     match s.to_lowercase().as_str() {
         "vimshottari" => dhruv_vedic_base::dasha::DashaSystem::Vimshottari,
@@ -9312,13 +10477,15 @@ fn parse_dasha_system(s: &str) -> dhruv_vedic_base::dasha::DashaSystem {
         "kendradi" => dhruv_vedic_base::dasha::DashaSystem::Kendradi,
         "karaka-kendradi" => dhruv_vedic_base::dasha::DashaSystem::KarakaKendradi,
         "karaka-kendradi-graha" => dhruv_vedic_base::dasha::DashaSystem::KarakaKendradiGraha,
+        "vimshottari-28" => dhruv_vedic_base::dasha::DashaSystem::Vimshottari28,
+        "narayana" => dhruv_vedic_base::dasha::DashaSystem::Narayana,
         other => {
             eprintln!("Unknown dasha system: {other}");
             eprintln!("Valid: vimshottari, ashtottari, shodsottari, dwadashottari, panchottari,");
             eprintln!("       shatabdika, chaturashiti, dwisaptati-sama, shashtihayani,");
             eprintln!("       shat-trimsha-sama, yogini, chara, sthira, yogardha, driga,");
             eprintln!("       shoola, mandooka, chakra, kala, kaal-chakra, kendradi,");
-            eprintln!("       karaka-kendradi, karaka-kendradi-graha");
+            eprintln!("       karaka-kendradi, karaka-kendradi-graha, vimshottari-28, narayana");
             std::process::exit(1);
         }
     }
@@ -9372,6 +10539,54 @@ fn parse_dasha_entity_spec(spec: &str) -> dhruv_vedic_base::dasha::DashaEntity {
     }
 }
 
+fn parse_samvatsara_scheme(s: &str) -> dhruv_vedic_base::SamvatsaraScheme {
+    match s.to_ascii_lowercase().replace('_', "-").as_str() {
+        "south-indian" | "south" | "0" => dhruv_vedic_base::SamvatsaraScheme::SouthIndian,
+        "north-indian" | "north" | "1" => dhruv_vedic_base::SamvatsaraScheme::NorthIndian,
+        "barhaspatya" | "2" => dhruv_vedic_base::SamvatsaraScheme::Barhaspatya,
+        other => {
+            eprintln!("Unknown samvatsara scheme: {other}");
+            eprintln!("Valid: south-indian, north-indian, barhaspatya");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn parse_day_fallback(s: &str) -> dhruv_vedic_base::DayDefinitionFallback {
+    use dhruv_vedic_base::DayDefinitionFallback;
+    match s.to_ascii_lowercase().replace('_', "-").as_str() {
+        "error" | "0" => DayDefinitionFallback::Error,
+        "civil-twilight" | "civil" | "1" => DayDefinitionFallback::CivilTwilight,
+        "local-six-am" | "six-am" | "2" => DayDefinitionFallback::LocalSixAm,
+        other => {
+            eprintln!("Unknown day fallback: {other}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn parse_refraction_model(args: &RiseSetArgs) -> dhruv_vedic_base::RefractionModel {
+    use dhruv_vedic_base::RefractionModel;
+    match args.refraction.to_ascii_lowercase().as_str() {
+        "standard" | "0" => RefractionModel::Standard,
+        "bennett" | "1" => RefractionModel::Bennett {
+            pressure_hpa: args.pressure_hpa,
+            temperature_c: args.temperature_c,
+        },
+        "saemundsson" | "2" => RefractionModel::Saemundsson {
+            pressure_hpa: args.pressure_hpa,
+            temperature_c: args.temperature_c,
+        },
+        "custom" | "3" => RefractionModel::Custom {
+            arcmin: args.refraction_arcmin,
+        },
+        other => {
+            eprintln!("Unknown refraction model: {other}");
+            std::process::exit(1);
+        }
+    }
+}
+
 fn parse_charakaraka_scheme(s: &str) -> dhruv_vedic_base::CharakarakaScheme {
     match s.to_ascii_lowercase().replace('_', "-").as_str() {
         "eight" | "8" | "8-chara" | "8-charakaraka" | "jaimini-8" => {
@@ -9522,13 +10737,11 @@ fn write_amsha_transform_rows(
                 for row in rows {
                     writeln!(
                         w,
-                        "{}{}: {:?} {:02}°{:02}'{:05.2}\"  ({:.6}°)",
+                        "{}{}: {:?} {}  ({:.6}°)",
                         row.amsha.name(),
                         format_amsha_variation_label(row.amsha, row.variation_code),
                         row.info.rashi,
-                        row.info.dms.degrees,
-                        row.info.dms.minutes,
-                        row.info.dms.seconds,
+                        row.info.dms.format(2),
                         row.longitude,
                     )?;
                 }
@@ -9843,6 +11056,8 @@ fn print_shadbala_entry(entry: &dhruv_search::ShadbalaEntry) {
         "  Strong:          {}",
         if entry.is_strong { "Yes" } else { "No" }
     );
+    println!("  Ishta Phala:     {:>8.2}", entry.ishta);
+    println!("  Kashta Phala:    {:>8.2}", entry.kashta);
 }
 
 fn print_bhavabala_entry(entry: &dhruv_search::BhavaBalaEntry) {
@@ -9931,6 +11146,17 @@ fn print_max_speed_event(label: &str, ev: &dhruv_search::stationary_types::MaxSp
     );
 }
 
+fn print_heliacal_event(label: &str, ev: &dhruv_search::HeliacalEvent) {
+    println!(
+        "{}: {:?} at UTC {} (JD TDB {:.6})",
+        label, ev.kind, ev.utc, ev.jd_tdb
+    );
+    println!(
+        "  Altitude: {:.4}°  Elongation: {:.4}°",
+        ev.altitude_deg, ev.elongation_deg
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Kundali flag resolution and config construction helpers
 // ---------------------------------------------------------------------------
@@ -10076,6 +11302,8 @@ fn build_kundali_config(
             include_lagna: true,
             include_outer_planets,
             include_bhava: true,
+            include_flags: true,
+            time_anchor: dhruv_search::GrahaPositionsTimeAnchor::Exact,
         }
     } else {
         dhruv_search::GrahaPositionsConfig::default()
@@ -10168,28 +11396,16 @@ fn build_time_upagraha_config(args: &TimeUpagrahaArgs) -> TimeUpagrahaConfig {
 
 fn format_rashi_dms(sidereal_lon: f64) -> String {
     let info = rashi_from_longitude(sidereal_lon);
-    let mut degs = info.dms.degrees;
-    let mut mins = info.dms.minutes;
-    let mut secs = info.dms.seconds;
     let mut rashi_name = info.rashi.name();
+    let mut dms = info.dms.rounded(0);
 
-    // Carry normalization for display (seconds rounding to 60)
-    if secs >= 59.5 {
-        secs = 0.0;
-        mins += 1;
-        if mins >= 60 {
-            mins = 0;
-            degs += 1;
-        }
-    }
-    // If carry pushed past 30°, show next rashi at 0°
-    if degs >= 30 {
-        let next = rashi_from_longitude(sidereal_lon + 0.001);
-        rashi_name = next.rashi.name();
-        degs = 0;
+    // If rounding carried to 30°, show next rashi at 0°
+    if dms.degrees >= 30 {
+        rashi_name = rashi_from_longitude(sidereal_lon + 0.001).rashi.name();
+        dms = deg_to_dms(0.0);
     }
 
-    format!("{:<10} {:02}°{:02}'{:02.0}\"", rashi_name, degs, mins, secs)
+    format!("{:<10} {}", rashi_name, dms.format(0))
 }
 
 fn write_amsha_chart(
@@ -10353,6 +11569,29 @@ fn write_amsha_chart(
     Ok(())
 }
 
+/// Comma-separated gandanta / rashi-sandhi / pushkara labels set on an entry.
+fn graha_flag_labels(entry: &dhruv_search::GrahaEntry) -> String {
+    [
+        (entry.gandanta, "gandanta"),
+        (entry.rashi_sandhi, "rashi-sandhi"),
+        (entry.pushkara, "pushkara"),
+    ]
+    .iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, name)| *name)
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// Kshaya / vriddhi description of a Vedic day.
+fn tithi_day_label(kind: TithiDayKind) -> String {
+    match kind {
+        TithiDayKind::Normal => "normal".to_string(),
+        TithiDayKind::Kshaya(t) => format!("kshaya ({} skipped)", t.name()),
+        TithiDayKind::Vriddhi(t) => format!("vriddhi ({} repeated)", t.name()),
+    }
+}
+
 fn print_kundali(
     w: &mut impl std::io::Write,
     result: &dhruv_search::FullKundaliResult,
@@ -10379,6 +11618,10 @@ fn print_kundali(
             if entry.rashi_bhava_number > 0 {
                 writeln!(w, "           Rashi-Bhava: {}", entry.rashi_bhava_number)?;
             }
+            let labels = graha_flag_labels(entry);
+            if !labels.is_empty() {
+                writeln!(w, "           Flags: {labels}")?;
+            }
         }
         writeln!(
             w,
@@ -10619,14 +11862,25 @@ fn print_kundali(
         writeln!(w, "Shadbala:")?;
         writeln!(
             w,
-            "  {:<8} {:>8} {:>6} {:>8} {:>8} {:>6} {:>6} {:>8} {:>6} {:>6}",
-            "Graha", "Sthana", "Dig", "Kala", "Cheshta", "Nais", "Drik", "Total", "Reqd", "OK?"
+            "  {:<8} {:>8} {:>6} {:>8} {:>8} {:>6} {:>6} {:>8} {:>6} {:>6} {:>6} {:>6}",
+            "Graha",
+            "Sthana",
+            "Dig",
+            "Kala",
+            "Cheshta",
+            "Nais",
+            "Drik",
+            "Total",
+            "Reqd",
+            "OK?",
+            "Ishta",
+            "Kashta"
         )?;
-        writeln!(w, "  {}", "-".repeat(78))?;
+        writeln!(w, "  {}", "-".repeat(92))?;
         for e in &sb.entries {
             writeln!(
                 w,
-                "  {:<8} {:>8.2} {:>6.2} {:>8.2} {:>8.2} {:>6.2} {:>6.2} {:>8.2} {:>6.2} {:>6}",
+                "  {:<8} {:>8.2} {:>6.2} {:>8.2} {:>8.2} {:>6.2} {:>6.2} {:>8.2} {:>6.2} {:>6} {:>6.2} {:>6.2}",
                 e.graha.name(),
                 e.sthana.total,
                 e.dig,
@@ -10637,6 +11891,8 @@ fn print_kundali(
                 e.total_shashtiamsas,
                 e.required_strength,
                 if e.is_strong { "Yes" } else { "No" },
+                e.ishta,
+                e.kashta,
             )?;
         }
         writeln!(w)?;
@@ -10767,6 +12023,7 @@ fn print_kundali(
             p.tithi.tithi_in_paksha
         )?;
         writeln!(w, "    Start:  {}  End: {}", p.tithi.start, p.tithi.end)?;
+        writeln!(w, "    Day:    {}", tithi_day_label(p.tithi_day_kind))?;
         writeln!(
             w,
             "  Karana:    {} (sequence {})",
@@ -10915,6 +12172,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_position_frame_velocity_matches_propagated_position() {
+        // Straight-line ICRF motion: the frame velocity must equal the rate of
+        // the rotated, propagated position, including the frame's own turn.
+        let sv = StateVector {
+            position_km: [1.5e8, -4.0e7, 2.0e7],
+            velocity_km_s: [9.0, 27.0, 11.0],
+        };
+        let jd = 2_460_676.0;
+        let h = 1.0 / 1440.0;
+        let at = |dt: f64| -> [f64; 3] {
+            std::array::from_fn(|i| sv.position_km[i] + sv.velocity_km_s[i] * dt * 86_400.0)
+        };
+        for frame in [
+            CliPositionFrameArg::Icrf,
+            CliPositionFrameArg::EclipticJ2000,
+            CliPositionFrameArg::EclipticOfDate,
+            CliPositionFrameArg::EquatorOfDate,
+        ] {
+            let (_, vel) = icrf_state_to_position_frame(&sv, jd, frame);
+            let plus = icrf_to_position_frame(&at(h), jd + h, frame);
+            let minus = icrf_to_position_frame(&at(-h), jd - h, frame);
+            for i in 0..3 {
+                let expected = (plus[i] - minus[i]) / (2.0 * h * 86_400.0);
+                assert!(
+                    (vel[i] - expected).abs() < 1e-6,
+                    "{frame:?}[{i}]: {} vs {expected}",
+                    vel[i]
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_bhava_config_from_cli_special_bhavabala_default_and_opt_out() {
         let default_cfg = bhava_config_from_cli(&default_bhava_behavior_args());
@@ -11356,12 +12646,14 @@ mod tests {
             cusp_deg: 0.0,
             start_deg: 0.0,
             end_deg: 30.0,
+            madhya_deg: 15.0,
         }; 12];
         for i in 0..12 {
             bhavas[i].number = (i + 1) as u8;
             bhavas[i].cusp_deg = (i as f64) * 30.0;
             bhavas[i].start_deg = (i as f64) * 30.0;
             bhavas[i].end_deg = ((i + 1) as f64) * 30.0;
+            bhavas[i].madhya_deg = (i as f64) * 30.0 + 15.0;
         }
         dhruv_vedic_base::BhavaResult {
             bhavas,
@@ -11528,6 +12820,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_utc_uses_astronomical_year_numbering() {
+        let bce1 = parse_utc("0000-01-01T00:00:00Z").unwrap();
+        assert_eq!(bce1.year, 0);
+        let bce2 = parse_utc("-0001-12-31T12:00:00Z").unwrap();
+        assert_eq!((bce2.year, bce2.month, bce2.day), (-1, 12, 31));
+        assert_eq!(bce2.to_string(), "-0001-12-31T12:00:00Z");
+        assert_eq!(parse_utc("+0001-01-01T00:00:00").unwrap().year, 1);
+        assert!(parse_utc("-0001-02-29T00:00:00Z").is_err());
+        assert!(parse_utc("--0001-01-01T00:00:00Z").is_err());
+    }
+
+    #[test]
+    fn parse_utc_normalizes_offsets() {
+        let ist = parse_utc("2024-03-20T12:00:00+05:30").unwrap();
+        assert_eq!(ist, UtcTime::new(2024, 3, 20, 6, 30, 0.0));
+        let pst = parse_utc("2024-03-20T20:00:00-08:00").unwrap();
+        assert_eq!(pst, UtcTime::new(2024, 3, 21, 4, 0, 0.0));
+        let rollback = parse_utc("2024-03-01T03:00:00+05:30").unwrap();
+        assert_eq!(rollback, UtcTime::new(2024, 2, 29, 21, 30, 0.0));
+        let new_year = parse_utc("2025-01-01T01:00:00+02:00").unwrap();
+        assert_eq!(new_year, UtcTime::new(2024, 12, 31, 23, 0, 0.0));
+    }
+
+    #[test]
+    fn parse_utc_rejects_malformed_offsets() {
+        for bad in [
+            "2024-03-20T12:00:00+5:30",
+            "2024-03-20T12:00:00+0530",
+            "2024-03-20T12:00:00+25:00",
+            "2024-03-20T12:00:00-08:75",
+            "2024-03-20T12:00:00+",
+        ] {
+            let err = parse_utc(bad).unwrap_err();
+            assert!(err.contains("UTC offset"), "{bad}: {err}");
+        }
+    }
+
     #[test]
     fn tropical_alone_parses_ok() {
         let args = [
@@ -240,3 +240,77 @@ fn cli_amsha_variations_lists_catalogs() {
     assert!(stdout.contains("1   cancer-leo-only"));
     assert!(stdout.contains("Navamsha (D9):"));
 }
+
+fn tropical_ayanamsha(subcommand: &str, precession: &str) -> f64 {
+    // JD 1721423.5 ~ 1 CE, two millennia from J2000 where models diverge.
+    let output = run_cli(&[
+        "--no-config",
+        subcommand,
+        "100",
+        "--ayanamsha",
+        "0",
+        "--jd",
+        "1721423.5",
+        "--precession",
+        precession,
+    ]);
+    assert_success(&output, subcommand);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .find_map(|l| l.strip_prefix("Ayanamsha: "))
+        .and_then(|v| v.trim_end_matches(" deg").parse().ok())
+        .unwrap_or_else(|| panic!("no ayanamsha line in {subcommand} output:\n{stdout}"))
+}
+
+#[test]
+fn cli_tropical_classifiers_honor_precession_model() {
+    for subcommand in ["rashi-tropical", "nakshatra-tropical"] {
+        let vondrak = tropical_ayanamsha(subcommand, "vondrak2011");
+        let newcomb = tropical_ayanamsha(subcommand, "newcomb1895");
+        assert!(
+            (vondrak - newcomb).abs() > 1e-3,
+            "{subcommand}: vondrak={vondrak} newcomb={newcomb}"
+        );
+    }
+}
+
+#[test]
+fn cli_position_frames_return_finite_coordinates() {
+    if !kernels_available() {
+        eprintln!("Skipping cli_position_frames: kernels not found");
+        return;
+    }
+    for frame in [
+        "icrf",
+        "ecliptic-j2000",
+        "ecliptic-of-date",
+        "equator-of-date",
+    ] {
+        let mut args = vec![
+            "--no-config".to_string(),
+            "position".to_string(),
+            "--date".to_string(),
+            "2025-01-15T12:00:00Z".to_string(),
+            "--target".to_string(),
+            "499".to_string(),
+            "--frame".to_string(),
+            frame.to_string(),
+        ];
+        args.extend(kernel_args_no_eop());
+        let refs = args.iter().map(String::as_str).collect::<Vec<_>>();
+        let output = run_cli(&refs);
+        assert_success(&output, &format!("position --frame {frame}"));
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for label in ["Longitude:", "Latitude:", "Distance:"] {
+            let value = stdout
+                .lines()
+                .find_map(|line| line.trim().strip_prefix(label))
+                .and_then(|rest| rest.split_whitespace().next())
+                .map(|v| v.trim_end_matches('°'))
+                .and_then(|v| v.parse::<f64>().ok())
+                .unwrap_or_else(|| panic!("{frame}: missing {label}\n{stdout}"));
+            assert!(value.is_finite(), "{frame}: {label} = {value}");
+        }
+    }
+}
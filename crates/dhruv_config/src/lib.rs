@@ -14,8 +14,8 @@ use dhruv_core::{Body, EngineConfig};
 use dhruv_frames::{PrecessionModel, ReferencePlane};
 use dhruv_search::{
     AmshaSelectionConfig, BindusConfig, ConjunctionConfig, DashaSelectionConfig, DashaSnapshotTime,
-    DrishtiConfig, FullKundaliConfig, GrahaPositionsConfig, GrahanConfig, SankrantiConfig,
-    StationaryConfig,
+    DrishtiConfig, FullKundaliConfig, GrahaPositionsConfig, GrahaPositionsTimeAnchor, GrahanConfig,
+    SankrantiConfig, StationaryConfig,
 };
 use dhruv_tara::{TaraAccuracy, TaraConfig};
 use dhruv_time::UtcTime;
@@ -23,7 +23,8 @@ use dhruv_vedic_base::bhava_types::{ALL_BHAVA_SYSTEMS, SayanadiGhatikaRounding};
 use dhruv_vedic_base::dasha::MAX_DASHA_SYSTEMS;
 use dhruv_vedic_base::{
     AyanamshaSystem, BhavaConfig, BhavaReferenceMode, BhavaStartingPoint, ChandraBeneficRule,
-    NodeDignityPolicy, RiseSetConfig, SunLimb,
+    CustomAyanamsha, DayDefinitionFallback, NodeDignityPolicy, RefractionModel, RiseSetConfig,
+    SamvatsaraScheme, SunLimb,
 };
 use serde::Deserialize;
 
@@ -119,6 +120,7 @@ pub struct CommonConfigPatch {
     pub use_nutation: Option<bool>,
     pub precession_model: Option<EnumInput>,
     pub reference_plane: Option<EnumInput>,
+    pub custom_ayanamsha: Option<CustomAyanamshaConfigValue>,
     pub step_size_days: Option<f64>,
     pub max_iterations: Option<u32>,
     pub convergence_days: Option<f64>,
@@ -194,9 +196,25 @@ pub struct SankrantiConfigPatch {
     pub use_nutation: Option<bool>,
     pub precession_model: Option<EnumInput>,
     pub reference_plane: Option<EnumInput>,
+    pub custom_ayanamsha: Option<CustomAyanamshaConfigValue>,
     pub step_size_days: Option<f64>,
     pub max_iterations: Option<u32>,
     pub convergence_days: Option<f64>,
+    pub samvatsara_scheme: Option<EnumInput>,
+}
+
+/// User-defined ayanamsha anchor; overrides `ayanamsha_system` when set.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomAyanamshaConfigValue {
+    pub anchor_deg: f64,
+    pub anchor_jd_tdb: f64,
+}
+
+impl From<CustomAyanamshaConfigValue> for CustomAyanamsha {
+    fn from(value: CustomAyanamshaConfigValue) -> Self {
+        CustomAyanamsha::new(value.anchor_deg, value.anchor_jd_tdb)
+    }
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -205,6 +223,11 @@ pub struct RiseSetConfigPatch {
     pub use_refraction: Option<bool>,
     pub sun_limb: Option<EnumInput>,
     pub altitude_correction: Option<bool>,
+    pub refraction_model: Option<EnumInput>,
+    pub refraction_pressure_hpa: Option<f64>,
+    pub refraction_temperature_c: Option<f64>,
+    pub refraction_arcmin: Option<f64>,
+    pub day_fallback: Option<EnumInput>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -238,6 +261,7 @@ pub struct GrahaPositionsConfigPatch {
     pub include_lagna: Option<bool>,
     pub include_outer_planets: Option<bool>,
     pub include_bhava: Option<bool>,
+    pub include_flags: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -301,6 +325,7 @@ pub struct DashaSelectionConfigPatch {
     pub level_methods: Option<Vec<u8>>,
     pub yogini_scheme: Option<u8>,
     pub use_abhijit: Option<u8>,
+    pub year_length: Option<u8>,
     pub snapshot_utc: Option<UtcTimeConfigValue>,
     pub snapshot_jd_utc: Option<f64>,
 }
@@ -663,25 +688,61 @@ impl ConfigResolver {
             recommended(self.defaults_mode, 1e-8),
             "sankranti.convergence_days",
         )?;
+        let (scheme_input, scheme_source) = choose_enum(
+            explicit.samvatsara_scheme,
+            op.samvatsara_scheme.clone(),
+            None,
+            recommended_enum(
+                self.defaults_mode,
+                EnumInput::Str("south-indian".to_string()),
+            ),
+            "sankranti.samvatsara_scheme",
+        )?;
+        let samvatsara_scheme =
+            parse_samvatsara_scheme(&scheme_input, "sankranti.samvatsara_scheme")?;
+
+        let custom = [
+            (explicit.custom_ayanamsha, ConfigSource::Explicit),
+            (op.custom_ayanamsha, ConfigSource::Operation),
+            (self.file.common.custom_ayanamsha, ConfigSource::Common),
+        ]
+        .into_iter()
+        .find_map(|(value, source)| value.map(|v| (v, source)));
+        if let Some((value, _)) = custom
+            && (!value.anchor_deg.is_finite() || !value.anchor_jd_tdb.is_finite())
+        {
+            return Err(ConfigError::InvalidConfig(
+                "sankranti.custom_ayanamsha must be finite".to_string(),
+            ));
+        }
 
-        let cfg = SankrantiConfig {
+        let mut cfg = SankrantiConfig {
             ayanamsha_system,
+            custom_ayanamsha: None,
             use_nutation,
             precession_model,
             reference_plane,
             step_size_days,
             max_iterations,
             convergence_days,
+            samvatsara_scheme,
         };
+        if let Some((value, _)) = custom {
+            cfg = cfg.with_custom_ayanamsha(value.into());
+        }
 
         let mut source = BTreeMap::new();
         source.insert("ayanamsha_system".to_string(), system_source);
         source.insert("use_nutation".to_string(), nut_source);
         source.insert("precession_model".to_string(), model_source);
         source.insert("reference_plane".to_string(), plane_source);
+        if let Some((_, custom_source)) = custom {
+            source.insert("custom_ayanamsha".to_string(), custom_source);
+        }
         source.insert("step_size_days".to_string(), step_source);
         source.insert("max_iterations".to_string(), iter_source);
         source.insert("convergence_days".to_string(), conv_source);
+        source.insert("samvatsara_scheme".to_string(), scheme_source);
 
         Ok(EffectiveConfig {
             value: cfg,
@@ -721,16 +782,68 @@ impl ConfigResolver {
             "riseset.altitude_correction",
         )?;
 
+        let (model_input, model_source) = choose_enum(
+            explicit.refraction_model,
+            op.refraction_model.clone(),
+            None,
+            recommended_enum(self.defaults_mode, EnumInput::Str("standard".to_string())),
+            "riseset.refraction_model",
+        )?;
+        let (pressure_hpa, p_source) = choose_copy(
+            explicit.refraction_pressure_hpa,
+            op.refraction_pressure_hpa,
+            None,
+            recommended(self.defaults_mode, 1010.0),
+            "riseset.refraction_pressure_hpa",
+        )?;
+        let (temperature_c, t_source) = choose_copy(
+            explicit.refraction_temperature_c,
+            op.refraction_temperature_c,
+            None,
+            recommended(self.defaults_mode, 10.0),
+            "riseset.refraction_temperature_c",
+        )?;
+        let (arcmin, arcmin_source) = choose_copy(
+            explicit.refraction_arcmin,
+            op.refraction_arcmin,
+            None,
+            recommended(self.defaults_mode, 34.0),
+            "riseset.refraction_arcmin",
+        )?;
+        let refraction_model = parse_refraction_model(
+            &model_input,
+            pressure_hpa,
+            temperature_c,
+            arcmin,
+            "riseset.refraction_model",
+        )?;
+
+        let (fallback_input, fallback_source) = choose_enum(
+            explicit.day_fallback,
+            op.day_fallback.clone(),
+            None,
+            recommended_enum(self.defaults_mode, EnumInput::Str("error".to_string())),
+            "riseset.day_fallback",
+        )?;
+        let day_fallback = parse_day_fallback(&fallback_input, "riseset.day_fallback")?;
+
         let mut source = BTreeMap::new();
         source.insert("use_refraction".to_string(), r_source);
         source.insert("sun_limb".to_string(), limb_source);
         source.insert("altitude_correction".to_string(), a_source);
+        source.insert("refraction_model".to_string(), model_source);
+        source.insert("refraction_pressure_hpa".to_string(), p_source);
+        source.insert("refraction_temperature_c".to_string(), t_source);
+        source.insert("refraction_arcmin".to_string(), arcmin_source);
+        source.insert("day_fallback".to_string(), fallback_source);
 
         Ok(EffectiveConfig {
             value: RiseSetConfig {
                 use_refraction,
+                refraction_model,
                 sun_limb,
                 altitude_correction,
+                day_fallback,
             },
             source_by_field: source,
         })
@@ -921,12 +1034,20 @@ impl ConfigResolver {
             recommended(self.defaults_mode, false),
             "graha_positions.include_bhava",
         )?;
+        let (include_flags, s5) = choose_copy(
+            explicit.include_flags,
+            op.include_flags,
+            None,
+            recommended(self.defaults_mode, false),
+            "graha_positions.include_flags",
+        )?;
 
         let mut source = BTreeMap::new();
         source.insert("include_nakshatra".to_string(), s1);
         source.insert("include_lagna".to_string(), s2);
         source.insert("include_outer_planets".to_string(), s3);
         source.insert("include_bhava".to_string(), s4);
+        source.insert("include_flags".to_string(), s5);
 
         Ok(EffectiveConfig {
             value: GrahaPositionsConfig {
@@ -934,6 +1055,8 @@ impl ConfigResolver {
                 include_lagna,
                 include_outer_planets,
                 include_bhava,
+                include_flags,
+                time_anchor: GrahaPositionsTimeAnchor::Exact,
             },
             source_by_field: source,
         })
@@ -1483,6 +1606,61 @@ fn parse_sun_limb(input: &EnumInput, field: &'static str) -> Result<SunLimb, Con
     }
 }
 
+fn parse_day_fallback(
+    input: &EnumInput,
+    field: &'static str,
+) -> Result<DayDefinitionFallback, ConfigError> {
+    match input.as_lower().replace('_', "-").as_str() {
+        "0" | "error" => Ok(DayDefinitionFallback::Error),
+        "1" | "civil-twilight" => Ok(DayDefinitionFallback::CivilTwilight),
+        "2" | "local-six-am" => Ok(DayDefinitionFallback::LocalSixAm),
+        other => Err(ConfigError::InvalidEnumValue {
+            field,
+            value: other.to_string(),
+        }),
+    }
+}
+
+fn parse_refraction_model(
+    input: &EnumInput,
+    pressure_hpa: f64,
+    temperature_c: f64,
+    arcmin: f64,
+    field: &'static str,
+) -> Result<RefractionModel, ConfigError> {
+    match input.as_lower().replace('_', "-").as_str() {
+        "0" | "standard" => Ok(RefractionModel::Standard),
+        "1" | "bennett" => Ok(RefractionModel::Bennett {
+            pressure_hpa,
+            temperature_c,
+        }),
+        "2" | "saemundsson" => Ok(RefractionModel::Saemundsson {
+            pressure_hpa,
+            temperature_c,
+        }),
+        "3" | "custom" => Ok(RefractionModel::Custom { arcmin }),
+        other => Err(ConfigError::InvalidEnumValue {
+            field,
+            value: other.to_string(),
+        }),
+    }
+}
+
+fn parse_samvatsara_scheme(
+    input: &EnumInput,
+    field: &'static str,
+) -> Result<SamvatsaraScheme, ConfigError> {
+    match input.as_lower().replace('_', "-").as_str() {
+        "0" | "south-indian" => Ok(SamvatsaraScheme::SouthIndian),
+        "1" | "north-indian" => Ok(SamvatsaraScheme::NorthIndian),
+        "2" | "barhaspatya" => Ok(SamvatsaraScheme::Barhaspatya),
+        other => Err(ConfigError::InvalidEnumValue {
+            field,
+            value: other.to_string(),
+        }),
+    }
+}
+
 fn parse_bhava_system(
     input: &EnumInput,
     field: &'static str,
@@ -1745,6 +1923,9 @@ fn apply_dasha_selection_patch(
     if let Some(v) = patch.use_abhijit {
         base.use_abhijit = v;
     }
+    if let Some(v) = patch.year_length {
+        base.year_length = v;
+    }
     if patch.snapshot_utc.is_some() && patch.snapshot_jd_utc.is_some() {
         return Err(ConfigError::InvalidConfig(
             "dasha snapshot config accepts only one of snapshot_utc or snapshot_jd_utc".to_string(),
@@ -1935,6 +2116,134 @@ max_iterations = 55
         assert_eq!(eff.value.max_iterations, 55);
     }
 
+    #[test]
+    fn resolve_sankranti_custom_ayanamsha() {
+        let text = r#"
+version = 1
+[common]
+reference_plane = "invariable"
+[operations.sankranti]
+custom_ayanamsha = { anchor_deg = 23.5, anchor_jd_tdb = 2451545.0 }
+"#;
+        let file: DhruvConfigFile = toml::from_str(text).unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_sankranti(None).unwrap();
+        assert_eq!(
+            eff.value.custom_ayanamsha,
+            Some(CustomAyanamsha::new(23.5, 2_451_545.0))
+        );
+        assert_eq!(eff.value.reference_plane, ReferencePlane::Ecliptic);
+        assert_eq!(
+            eff.source_by_field.get("custom_ayanamsha"),
+            Some(&ConfigSource::Operation)
+        );
+    }
+
+    #[test]
+    fn resolve_riseset_refraction_model() {
+        let file: DhruvConfigFile = toml::from_str("version = 1").unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_riseset(None).unwrap();
+        assert_eq!(eff.value.refraction_model, RefractionModel::Standard);
+
+        let text = r#"
+version = 1
+[operations.riseset]
+refraction_model = "bennett"
+refraction_pressure_hpa = 950.0
+"#;
+        let file: DhruvConfigFile = toml::from_str(text).unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_riseset(None).unwrap();
+        assert_eq!(
+            eff.value.refraction_model,
+            RefractionModel::Bennett {
+                pressure_hpa: 950.0,
+                temperature_c: 10.0,
+            }
+        );
+        assert_eq!(
+            eff.source_by_field.get("refraction_pressure_hpa"),
+            Some(&ConfigSource::Operation)
+        );
+
+        let explicit = RiseSetConfigPatch {
+            refraction_model: Some(EnumInput::Str("custom".to_string())),
+            refraction_arcmin: Some(36.0),
+            ..RiseSetConfigPatch::default()
+        };
+        let eff = resolver.resolve_riseset(Some(explicit)).unwrap();
+        assert_eq!(
+            eff.value.refraction_model,
+            RefractionModel::Custom { arcmin: 36.0 }
+        );
+
+        let bad = RiseSetConfigPatch {
+            refraction_model: Some(EnumInput::Str("laplace".to_string())),
+            ..RiseSetConfigPatch::default()
+        };
+        assert!(resolver.resolve_riseset(Some(bad)).is_err());
+    }
+
+    #[test]
+    fn resolve_riseset_day_fallback() {
+        let file: DhruvConfigFile = toml::from_str("version = 1").unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_riseset(None).unwrap();
+        assert_eq!(eff.value.day_fallback, DayDefinitionFallback::Error);
+
+        let text = r#"
+version = 1
+[operations.riseset]
+day_fallback = "civil_twilight"
+"#;
+        let file: DhruvConfigFile = toml::from_str(text).unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_riseset(None).unwrap();
+        assert_eq!(eff.value.day_fallback, DayDefinitionFallback::CivilTwilight);
+        assert_eq!(
+            eff.source_by_field.get("day_fallback"),
+            Some(&ConfigSource::Operation)
+        );
+
+        let bad = RiseSetConfigPatch {
+            day_fallback: Some(EnumInput::Str("noon".to_string())),
+            ..RiseSetConfigPatch::default()
+        };
+        assert!(resolver.resolve_riseset(Some(bad)).is_err());
+    }
+
+    #[test]
+    fn resolve_sankranti_samvatsara_scheme() {
+        let file: DhruvConfigFile = toml::from_str("version = 1").unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_sankranti(None).unwrap();
+        assert_eq!(eff.value.samvatsara_scheme, SamvatsaraScheme::SouthIndian);
+
+        let text = r#"
+version = 1
+[operations.sankranti]
+samvatsara_scheme = "north_indian"
+"#;
+        let file: DhruvConfigFile = toml::from_str(text).unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        let eff = resolver.resolve_sankranti(None).unwrap();
+        assert_eq!(eff.value.samvatsara_scheme, SamvatsaraScheme::NorthIndian);
+        assert_eq!(
+            eff.source_by_field.get("samvatsara_scheme"),
+            Some(&ConfigSource::Operation)
+        );
+
+        let text = r#"
+version = 1
+[operations.sankranti]
+samvatsara_scheme = 7
+"#;
+        let file: DhruvConfigFile = toml::from_str(text).unwrap();
+        let resolver = ConfigResolver::new(file, DefaultsMode::Recommended);
+        assert!(resolver.resolve_sankranti(None).is_err());
+    }
+
     #[test]
     fn resolve_bhava_sayanadi_rounding_defaults_floor_and_accepts_ceil() {
         let file: DhruvConfigFile = toml::from_str(
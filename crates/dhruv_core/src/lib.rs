@@ -4,11 +4,12 @@
 //! kernels and evaluates ephemeris queries by chaining SPK segments
 //! through the NAIF body hierarchy.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -160,6 +161,10 @@ impl Body {
 
 /// Observer used to evaluate relative state vectors.
 ///
+/// `Body(b)` accepts any loaded body, not just Earth or the Sun: target and
+/// observer are each resolved to the SSB and differenced, so e.g. the Moon
+/// relative to Jupiter works for visualization.
+///
 /// Topocentric observers (geographic lat/lon/alt) are a higher-level concept
 /// built on top of `Body(Earth)` queries — they do not belong in this enum.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -290,6 +295,133 @@ pub struct QueryStats {
     pub cache_hits: u32,
 }
 
+/// Snapshot of the engine-lifetime evaluation cache.
+///
+/// `hits` and `misses` count lookups since engine creation or the last
+/// [`Engine::clear_cache`]; `entries` is the current number of memoized
+/// segment evaluations (bounded by `EngineConfig::cache_capacity`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+/// Cache key: (generation, target, center, epoch_bits).
+type EvaluationKey = (u64, i32, i32, u64);
+
+/// Upper bound on the number of independently locked cache shards.
+const EVALUATION_CACHE_SHARDS: usize = 16;
+
+/// One FIFO shard of the engine-lifetime evaluation cache.
+struct EvaluationShard {
+    entries: HashMap<EvaluationKey, SpkEvaluation>,
+    order: VecDeque<EvaluationKey>,
+}
+
+/// Engine-lifetime FIFO memo of segment evaluations, shared across requests.
+///
+/// Keys include the SPK generation so entries from a replaced set can never
+/// be returned, even to a request racing with `replace_spk_paths`. The cache
+/// is split into shards behind their own `RwLock`, so concurrent lookups
+/// only read-lock one shard and unrelated misses do not contend. Hit/miss
+/// counters are atomics and take no lock.
+struct EvaluationCache {
+    shards: Vec<RwLock<EvaluationShard>>,
+    shard_capacity: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl EvaluationCache {
+    fn new(capacity: usize) -> Self {
+        let shard_count = capacity.clamp(1, EVALUATION_CACHE_SHARDS);
+        Self {
+            shards: (0..shard_count)
+                .map(|_| {
+                    RwLock::new(EvaluationShard {
+                        entries: HashMap::new(),
+                        order: VecDeque::new(),
+                    })
+                })
+                .collect(),
+            // Floor division keeps the total at or below `capacity`.
+            shard_capacity: (capacity / shard_count).max(1),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    fn shard(&self, key: &EvaluationKey) -> &RwLock<EvaluationShard> {
+        let mixed = key.3 ^ (key.1 as u64).rotate_left(40) ^ (key.2 as u64).rotate_left(20);
+        let hash = mixed.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 32;
+        &self.shards[hash as usize % self.shards.len()]
+    }
+
+    fn get(&self, key: &EvaluationKey) -> Option<SpkEvaluation> {
+        let found = self
+            .shard(key)
+            .read()
+            .expect("engine evaluation cache lock poisoned")
+            .entries
+            .get(key)
+            .copied();
+        let counter = if found.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
+    fn insert(&self, key: EvaluationKey, eval: SpkEvaluation) {
+        let mut shard = self
+            .shard(&key)
+            .write()
+            .expect("engine evaluation cache lock poisoned");
+        if shard.entries.insert(key, eval).is_some() {
+            return;
+        }
+        shard.order.push_back(key);
+        while shard.order.len() > self.shard_capacity {
+            if let Some(oldest) = shard.order.pop_front() {
+                shard.entries.remove(&oldest);
+            }
+        }
+    }
+
+    fn clear(&self) {
+        for shard in &self.shards {
+            let mut shard = shard
+                .write()
+                .expect("engine evaluation cache lock poisoned");
+            shard.entries.clear();
+            shard.order.clear();
+        }
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self
+                .shards
+                .iter()
+                .map(|shard| {
+                    shard
+                        .read()
+                        .expect("engine evaluation cache lock poisoned")
+                        .entries
+                        .len()
+                })
+                .sum(),
+        }
+    }
+}
+
 /// Per-request memoization context.
 ///
 /// Created at the start of each query/batch, threaded through chain
@@ -436,7 +568,8 @@ fn load_spk_set(
 ///
 /// `Engine` is [`Send`] + [`Sync`], so it can be shared across threads
 /// via `Arc<Engine>`. Each query creates its own short-lived
-/// [`ComputationContext`] for memoization — no cross-request locking.
+/// [`ComputationContext`] for memoization; the engine-lifetime cache behind
+/// it is sharded, so concurrent queries only contend on the same shard.
 ///
 /// ```rust,ignore
 /// let engine = Arc::new(Engine::new(config)?);
@@ -451,6 +584,7 @@ pub struct Engine {
     spk_set: RwLock<Arc<SpkSet>>,
     replace_lock: Mutex<()>,
     kernel_cache: Mutex<HashMap<SpkIdentity, Weak<SpkKernel>>>,
+    eval_cache: EvaluationCache,
     lsk: LeapSecondKernel,
 }
 
@@ -482,11 +616,13 @@ impl Engine {
         let (spk_set, cache, _, _) = load_spk_set(&config.spk_paths, 0, None, None)?;
         let lsk = LeapSecondKernel::load(&config.lsk_path)
             .map_err(|e| EngineError::KernelLoad(e.to_string()))?;
+        let eval_cache = EvaluationCache::new(config.cache_capacity);
         Ok(Self {
             config: RwLock::new(config),
             spk_set: RwLock::new(spk_set),
             replace_lock: Mutex::new(()),
             kernel_cache: Mutex::new(cache),
+            eval_cache,
             lsk,
         })
     }
@@ -562,6 +698,7 @@ impl Engine {
             cache_guard.retain(|_, weak| weak.strong_count() > 0);
             cache_guard.extend(new_cache_entries.drain());
         }
+        self.clear_cache();
 
         Ok(SpkReplaceReport {
             generation: new_set.generation,
@@ -571,6 +708,16 @@ impl Engine {
         })
     }
 
+    /// Hit/miss counters and entry count of the engine-lifetime evaluation cache.
+    pub fn cache_stats(&self) -> CacheStats {
+        self.eval_cache.stats()
+    }
+
+    /// Drop all memoized evaluations and reset the hit/miss counters.
+    pub fn clear_cache(&self) {
+        self.eval_cache.clear();
+    }
+
     /// Access the loaded LSK kernel.
     pub fn lsk(&self) -> &LeapSecondKernel {
         &self.lsk
@@ -581,7 +728,8 @@ impl Engine {
     }
 
     /// Evaluate (target, center) at epoch from the first kernel with a
-    /// matching segment. Uses the computation context for memoization.
+    /// matching segment. Uses the computation context for memoization,
+    /// falling back to the engine-lifetime cache before touching kernels.
     fn evaluate_across(
         &self,
         spk_set: &SpkSet,
@@ -596,11 +744,19 @@ impl Engine {
             return Ok(*cached);
         }
 
+        let engine_key = (spk_set.generation, target, center, key.2);
+        if let Some(eval) = self.eval_cache.get(&engine_key) {
+            ctx.cache_hits += 1;
+            ctx.cache.insert(key, eval);
+            return Ok(eval);
+        }
+
         for entry in &spk_set.entries {
             match entry.kernel.evaluate(target, center, epoch_tdb_s) {
                 Ok(eval) => {
                     ctx.evaluations += 1;
                     ctx.cache.insert(key, eval);
+                    self.eval_cache.insert(engine_key, eval);
                     return Ok(eval);
                 }
                 Err(KernelError::EpochOutOfRange { .. }) => continue,
@@ -633,25 +789,10 @@ impl Engine {
         epoch_tdb_s: f64,
         ctx: &mut ComputationContext,
     ) -> Result<[f64; 6], KernelError> {
-        let mut code = body_code;
+        let links = center_chain(body_code, |code| self.center_for_across(spk_set, code))?;
         let mut state = [0.0f64; 6];
 
-        while code != 0 {
-            let center = match self.center_for_across(spk_set, code) {
-                Some(c) => c,
-                None => {
-                    let bary = jpl_kernel::planet_body_to_barycenter(code);
-                    if bary != code {
-                        code = bary;
-                        continue;
-                    }
-                    return Err(KernelError::SegmentNotFound {
-                        target: code,
-                        center: -1,
-                    });
-                }
-            };
-
+        for (code, center) in links {
             let eval = self.evaluate_across(spk_set, code, center, epoch_tdb_s, ctx)?;
             state[0] += eval.position_km[0];
             state[1] += eval.position_km[1];
@@ -659,8 +800,6 @@ impl Engine {
             state[3] += eval.velocity_km_s[0];
             state[4] += eval.velocity_km_s[1];
             state[5] += eval.velocity_km_s[2];
-
-            code = center;
         }
 
         Ok(state)
@@ -807,6 +946,46 @@ impl Engine {
     }
 }
 
+/// `(target, center)` links from `body_code` to SSB (code 0).
+///
+/// A body with no segment falls back to its barycenter (x99 → x). Fails
+/// with [`KernelError::CircularChain`] if the walk revisits a code or runs
+/// past [`jpl_kernel::MAX_CHAIN_DEPTH`] bodies, so a malformed kernel set
+/// cannot hang the caller.
+fn center_chain(
+    body_code: i32,
+    center_for: impl Fn(i32) -> Option<i32>,
+) -> Result<Vec<(i32, i32)>, KernelError> {
+    let mut links = Vec::new();
+    let mut visited = Vec::new();
+    let mut code = body_code;
+
+    while code != 0 {
+        if visited.len() == jpl_kernel::MAX_CHAIN_DEPTH || visited.contains(&code) {
+            return Err(KernelError::CircularChain { body: body_code });
+        }
+        visited.push(code);
+        match center_for(code) {
+            Some(center) => {
+                links.push((code, center));
+                code = center;
+            }
+            None => {
+                let bary = jpl_kernel::planet_body_to_barycenter(code);
+                if bary == code {
+                    return Err(KernelError::SegmentNotFound {
+                        target: code,
+                        center: -1,
+                    });
+                }
+                code = bary;
+            }
+        }
+    }
+
+    Ok(links)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -816,6 +995,68 @@ mod tests {
         (base.join("de442s.bsp"), base.join("naif0012.tls"))
     }
 
+    fn eval_at(x: f64) -> SpkEvaluation {
+        SpkEvaluation {
+            position_km: [x, 0.0, 0.0],
+            velocity_km_s: [0.0; 3],
+        }
+    }
+
+    #[test]
+    fn evaluation_cache_counts_and_stays_within_capacity() {
+        let cache = EvaluationCache::new(40);
+        for i in 0..200u64 {
+            cache.insert((0, 499, 0, i), eval_at(i as f64));
+        }
+        let stats = cache.stats();
+        assert!(stats.entries > 0 && stats.entries <= 40, "{stats:?}");
+        assert_eq!((stats.hits, stats.misses), (0, 0));
+
+        cache.insert((0, 499, 0, 1_000), eval_at(1.0));
+        assert_eq!(cache.get(&(0, 499, 0, 1_000)), Some(eval_at(1.0)));
+        assert_eq!(cache.get(&(1, 499, 0, 1_000)), None);
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+
+        cache.clear();
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn evaluation_cache_capacity_below_shard_count() {
+        let cache = EvaluationCache::new(3);
+        for i in 0..50u64 {
+            cache.insert((0, 10, 0, i), eval_at(0.0));
+        }
+        assert!(cache.stats().entries <= 3);
+    }
+
+    #[test]
+    fn observer_codes_round_trip() {
+        let bodies = [
+            Body::Sun,
+            Body::Mercury,
+            Body::Venus,
+            Body::Earth,
+            Body::Moon,
+            Body::Mars,
+            Body::Jupiter,
+            Body::Saturn,
+            Body::Uranus,
+            Body::Neptune,
+            Body::Pluto,
+        ];
+        let observers = std::iter::once(Observer::SolarSystemBarycenter)
+            .chain(bodies.into_iter().map(Observer::Body));
+        for observer in observers {
+            assert_eq!(Observer::from_code(observer.code()), Some(observer));
+        }
+        // Unknown codes (including topocentric-style sentinels) are rejected.
+        for code in [-1, 1, 3, 300, 399_001] {
+            assert_eq!(Observer::from_code(code), None, "code {code}");
+        }
+    }
+
     #[test]
     fn engine_rejects_empty_spk_paths() {
         let (_, lsk) = kernel_paths();
@@ -893,4 +1134,48 @@ mod tests {
             assert_send_sync::<Engine>();
         }
     };
+
+    #[test]
+    fn center_chain_walks_to_ssb_with_barycenter_fallback() {
+        // Mars body 499 has no segment; its barycenter 4 is centered on SSB.
+        let links = center_chain(499, |code| match code {
+            4 => Some(0),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(links, vec![(4, 0)]);
+
+        let links = center_chain(301, |code| match code {
+            301 => Some(3),
+            3 => Some(0),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(links, vec![(301, 3), (3, 0)]);
+    }
+
+    #[test]
+    fn center_chain_rejects_cycles() {
+        let result = center_chain(-99, |code| match code {
+            -99 => Some(399),
+            399 => Some(-99),
+            _ => None,
+        });
+        assert_eq!(result, Err(KernelError::CircularChain { body: -99 }));
+
+        // A chain that never reaches SSB is cut off at the depth cap.
+        let result = center_chain(-1, |code| Some(code - 1));
+        assert_eq!(result, Err(KernelError::CircularChain { body: -1 }));
+    }
+
+    #[test]
+    fn center_chain_missing_segment_errors() {
+        assert_eq!(
+            center_chain(-99, |_| None),
+            Err(KernelError::SegmentNotFound {
+                target: -99,
+                center: -1
+            })
+        );
+    }
 }
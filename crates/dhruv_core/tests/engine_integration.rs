@@ -201,6 +201,39 @@ fn moon_relative_to_earth() {
     );
 }
 
+/// Any body can be the observer: Moon relative to Jupiter (its barycenter
+/// in de442s) is the difference of the two SSB-relative states.
+#[test]
+fn moon_relative_to_jupiter_is_ssb_difference() {
+    let engine = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let at = |target, observer| {
+        engine
+            .query(Query {
+                target,
+                observer,
+                frame: Frame::IcrfJ2000,
+                epoch_tdb_jd: 2_460_000.5,
+            })
+            .expect("should succeed")
+    };
+    let relative = at(Body::Moon, Observer::Body(Body::Jupiter));
+    let moon = at(Body::Moon, Observer::SolarSystemBarycenter);
+    let jupiter = at(Body::Jupiter, Observer::SolarSystemBarycenter);
+
+    for i in 0..3 {
+        let dp = moon.position_km[i] - jupiter.position_km[i];
+        let dv = moon.velocity_km_s[i] - jupiter.velocity_km_s[i];
+        assert!((relative.position_km[i] - dp).abs() < 1e-6, "position[{i}]");
+        assert!(
+            (relative.velocity_km_s[i] - dv).abs() < 1e-12,
+            "velocity[{i}]"
+        );
+    }
+}
+
 #[test]
 fn context_avoids_redundant_evaluations() {
     let engine = match real_engine() {
@@ -235,6 +268,36 @@ fn context_avoids_redundant_evaluations() {
     );
 }
 
+#[test]
+fn engine_cache_counts_hits_and_clears() {
+    let engine = match real_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    let query = Query {
+        target: Body::Mars,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: 2_460_000.5,
+    };
+    assert_eq!(engine.cache_stats(), CacheStats::default());
+
+    let first = engine.query(query).expect("first query");
+    let after_first = engine.cache_stats();
+    assert!(after_first.misses > 0);
+    assert!(after_first.entries > 0);
+
+    let second = engine.query(query).expect("second query");
+    let after_second = engine.cache_stats();
+    assert_eq!(first, second);
+    assert!(after_second.hits > after_first.hits);
+    assert_eq!(after_second.misses, after_first.misses);
+    assert_eq!(after_second.entries, after_first.entries);
+
+    engine.clear_cache();
+    assert_eq!(engine.cache_stats().entries, 0);
+}
+
 #[test]
 fn query_batch_matches_individual() {
     let engine = match real_engine() {
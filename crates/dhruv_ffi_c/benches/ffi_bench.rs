@@ -7,14 +7,14 @@ use dhruv_core::{Body, Frame, Observer};
 use dhruv_ffi_c::{
     DHRUV_AYANAMSHA_MODE_MEAN, DHRUV_AYANAMSHA_MODE_TRUE, DHRUV_AYANAMSHA_MODE_UNIFIED,
     DHRUV_AYANAMSHA_TIME_JD_TDB, DHRUV_AYANAMSHA_TIME_UTC, DHRUV_GRAHA_LONGITUDE_KIND_TROPICAL,
-    DHRUV_PANCHANG_INCLUDE_ALL, DHRUV_PANCHANG_TIME_UTC, DHRUV_QUERY_OUTPUT_SPHERICAL,
-    DHRUV_QUERY_TIME_UTC, DHRUV_TIME_POLICY_STRICT_LSK, DhruvAyanamshaComputeRequest,
-    DhruvBhinnaAshtakavarga, DhruvDrishtiEntry, DhruvEngineConfig, DhruvGrahaDrishtiMatrix,
-    DhruvGrahaLongitudes, DhruvKaranaPosition, DhruvNakshatra28Info, DhruvNakshatraInfo,
-    DhruvPanchangComputeRequest, DhruvPanchangNakshatraInfo, DhruvPanchangOperationResult,
-    DhruvQuery, DhruvQueryRequest, DhruvQueryResult, DhruvRashiInfo, DhruvSamvatsaraResult,
-    DhruvSarvaAshtakavarga, DhruvSphericalCoords, DhruvStateVector, DhruvStatus,
-    DhruvTimeConversionOptions, DhruvTimePolicy, DhruvTithiPosition, DhruvUtcTime,
+    DHRUV_PANCHANG_INCLUDE_ALL, DHRUV_PANCHANG_TIME_UTC, DHRUV_PRECESSION_MODEL_VONDRAK2011,
+    DHRUV_QUERY_OUTPUT_SPHERICAL, DHRUV_QUERY_TIME_UTC, DHRUV_TIME_POLICY_STRICT_LSK,
+    DhruvAyanamshaComputeRequest, DhruvBhinnaAshtakavarga, DhruvDrishtiEntry, DhruvEngineConfig,
+    DhruvGrahaDrishtiMatrix, DhruvGrahaLongitudes, DhruvKaranaPosition, DhruvNakshatra28Info,
+    DhruvNakshatraInfo, DhruvPanchangComputeRequest, DhruvPanchangNakshatraInfo,
+    DhruvPanchangOperationResult, DhruvQuery, DhruvQueryRequest, DhruvQueryResult, DhruvRashiInfo,
+    DhruvSamvatsaraResult, DhruvSarvaAshtakavarga, DhruvSphericalCoords, DhruvStateVector,
+    DhruvStatus, DhruvTimeConversionOptions, DhruvTimePolicy, DhruvTithiPosition, DhruvUtcTime,
     DhruvUtcToTdbRequest, DhruvUtcToTdbResult, DhruvYogaPosition,
     dhruv_ayana_from_sidereal_longitude, dhruv_ayanamsha_compute_ex, dhruv_calculate_all_bav,
     dhruv_calculate_bav, dhruv_calculate_sav, dhruv_cartesian_to_spherical,
@@ -41,8 +41,8 @@ use dhruv_search::{
 };
 use dhruv_time::{Epoch, UtcTime};
 use dhruv_vedic_base::{
-    AyanamshaSystem, Graha, LunarNode, NodeMode, Upagraha, Vaar,
-    ayana_from_sidereal_longitude as rust_ayana_from_sidereal_longitude,
+    AyanamshaSystem, Graha, LunarNode, NodeMode, SamvatsaraScheme, SiderealLongitude, Upagraha,
+    Vaar, ayana_from_sidereal_longitude as rust_ayana_from_sidereal_longitude,
     ayanamsha_deg as rust_ayanamsha_deg, ayanamsha_mean_deg as rust_ayanamsha_mean_deg,
     ayanamsha_true_deg as rust_ayanamsha_true_deg, calculate_all_bav as rust_calculate_all_bav,
     calculate_bav as rust_calculate_bav, calculate_sav as rust_calculate_sav,
@@ -385,6 +385,7 @@ fn ffi_vedic_primitives_bench(c: &mut Criterion) {
                 utc: zero_utc,
                 use_nutation: 0,
                 delta_psi_arcsec: 0.0,
+                precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
             };
             expect_ok(dhruv_ayanamsha_compute_ex(
                 std::ptr::null(),
@@ -410,6 +411,7 @@ fn ffi_vedic_primitives_bench(c: &mut Criterion) {
                 utc: zero_utc,
                 use_nutation: 0,
                 delta_psi_arcsec: dpsi,
+                precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
             };
             expect_ok(dhruv_ayanamsha_compute_ex(
                 std::ptr::null(),
@@ -434,6 +436,7 @@ fn ffi_vedic_primitives_bench(c: &mut Criterion) {
                 utc: zero_utc,
                 use_nutation: 0,
                 delta_psi_arcsec: 0.0,
+                precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
             };
             expect_ok(dhruv_ayanamsha_compute_ex(
                 std::ptr::null(),
@@ -621,6 +624,7 @@ fn ffi_vedic_primitives_bench(c: &mut Criterion) {
                     utc,
                     use_nutation: 0,
                     delta_psi_arcsec: 0.0,
+                    precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
                 };
                 expect_ok(dhruv_ffi_c::dhruv_ayanamsha_compute_ex(
                     black_box(&ctx.lsk as *const _),
@@ -645,6 +649,7 @@ fn ffi_vedic_primitives_bench(c: &mut Criterion) {
                     utc,
                     use_nutation: 0,
                     delta_psi_arcsec: dpsi,
+                    precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
                 };
                 expect_ok(dhruv_ffi_c::dhruv_ayanamsha_compute_ex(
                     black_box(&ctx.lsk as *const _),
@@ -669,6 +674,7 @@ fn ffi_vedic_primitives_bench(c: &mut Criterion) {
                     utc,
                     use_nutation: 0,
                     delta_psi_arcsec: 0.0,
+                    precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
                 };
                 expect_ok(dhruv_ffi_c::dhruv_ayanamsha_compute_ex(
                     black_box(&ctx.lsk as *const _),
@@ -863,11 +869,11 @@ fn ffi_classifier_bench(c: &mut Criterion) {
         &mut group,
         "samvatsara_from_year",
         || {
-            let (s, _) = rust_samvatsara_from_year(black_box(2024));
+            let (s, _) = rust_samvatsara_from_year(black_box(2024), SamvatsaraScheme::SouthIndian);
             s.index() as i32
         },
         || unsafe {
-            let _ = dhruv_samvatsara_from_year(black_box(2024), &mut samvatsara_out);
+            let _ = dhruv_samvatsara_from_year(black_box(2024), 0, &mut samvatsara_out);
             samvatsara_out.samvatsara_index
         },
     );
@@ -2199,12 +2205,15 @@ fn ffi_orchestrators_bench(c: &mut Criterion) {
         include_lagna: true,
         include_outer_planets: false,
         include_bhava: true,
+        include_flags: false,
+        time_anchor: dhruv_search::GrahaPositionsTimeAnchor::Exact,
     };
     let gp_cfg_ffi = dhruv_ffi_c::DhruvGrahaPositionsConfig {
         include_nakshatra: 1,
         include_lagna: 1,
         include_outer_planets: 0,
         include_bhava: 1,
+        include_flags: 0,
     };
     let mut gp_out: dhruv_ffi_c::DhruvGrahaPositions = zeroed();
     bench_pair(
@@ -2497,26 +2506,26 @@ fn ffi_orchestrators_bench(c: &mut Criterion) {
     );
 
     let sphuta_inputs = dhruv_vedic_base::SphutalInputs {
-        sun: 120.0,
-        moon: 45.0,
-        mars: 300.0,
-        jupiter: 210.0,
-        venus: 180.0,
-        rahu: 25.0,
-        lagna: 10.0,
-        eighth_lord: 95.0,
-        gulika: 130.0,
+        sun: SiderealLongitude(120.0),
+        moon: SiderealLongitude(45.0),
+        mars: SiderealLongitude(300.0),
+        jupiter: SiderealLongitude(210.0),
+        venus: SiderealLongitude(180.0),
+        rahu: SiderealLongitude(25.0),
+        lagna: SiderealLongitude(10.0),
+        eighth_lord: SiderealLongitude(95.0),
+        gulika: SiderealLongitude(130.0),
     };
     let ffi_sphuta_inputs = dhruv_ffi_c::DhruvSphutalInputs {
-        sun: sphuta_inputs.sun,
-        moon: sphuta_inputs.moon,
-        mars: sphuta_inputs.mars,
-        jupiter: sphuta_inputs.jupiter,
-        venus: sphuta_inputs.venus,
-        rahu: sphuta_inputs.rahu,
-        lagna: sphuta_inputs.lagna,
-        eighth_lord: sphuta_inputs.eighth_lord,
-        gulika: sphuta_inputs.gulika,
+        sun: sphuta_inputs.sun.deg(),
+        moon: sphuta_inputs.moon.deg(),
+        mars: sphuta_inputs.mars.deg(),
+        jupiter: sphuta_inputs.jupiter.deg(),
+        venus: sphuta_inputs.venus.deg(),
+        rahu: sphuta_inputs.rahu.deg(),
+        lagna: sphuta_inputs.lagna.deg(),
+        eighth_lord: sphuta_inputs.eighth_lord.deg(),
+        gulika: sphuta_inputs.gulika.deg(),
     };
     let mut ffi_sphuta_out: dhruv_ffi_c::DhruvSphutalResult = zeroed();
     bench_pair(
@@ -13,24 +13,29 @@ use dhruv_core::{
 use dhruv_frames::PrecessionModel;
 use dhruv_search::{
     ChandraGrahan, ChandraGrahanType, ConjunctionConfig, ConjunctionEvent, GrahaLongitudeKind,
-    GrahaLongitudesConfig, GrahanConfig, LunarPhase, MaxSpeedEvent, MaxSpeedType, SankrantiConfig,
+    GrahaLongitudesConfig, GrahanConfig, HeliacalEvent, HeliacalEventConfig, HeliacalKind,
+    HeliacalTarget, LunarPhase, MaxSpeedEvent, MaxSpeedType, PanchangLimb, SankrantiConfig,
     SearchError, StationType, StationaryConfig, StationaryEvent, SuryaGrahan, SuryaGrahanType,
-    amsha_charts_for_date, avastha_for_date, ayana_for_date, balas_for_date, bhavabala_for_date,
-    body_ecliptic_lon_lat, charakaraka_for_date, dasha_child_period_with_inputs,
-    dasha_children_with_inputs, dasha_complete_level_with_inputs, dasha_hierarchy_with_inputs,
-    dasha_level0_entity_with_inputs, dasha_level0_with_inputs, dasha_snapshot_with_inputs,
+    TransitAshtakavargaConfig, TransitHouseMode, amsha_charts_for_date, avastha_for_date,
+    ayana_for_date, balas_for_date, bhavabala_for_date, body_ecliptic_lon_lat,
+    charakaraka_for_date, dasha_child_period_with_inputs, dasha_children_with_inputs,
+    dasha_complete_level_with_inputs, dasha_hierarchy_with_inputs, dasha_level0_entity_with_inputs,
+    dasha_level0_with_inputs, dasha_snapshot_with_inputs, dasha_transitions_with_inputs,
     elongation_at, full_kundali_for_date, ghatika_for_date, ghatika_from_sunrises,
-    graha_longitudes, hora_for_date, hora_from_sunrises, karana_at, karana_for_date, masa_for_date,
-    moving_osculating_apogees_for_date, nakshatra_at, nakshatra_for_date, next_amavasya,
-    next_chandra_grahan, next_conjunction, next_max_speed, next_purnima, next_sankranti,
-    next_specific_sankranti, next_stationary, next_surya_grahan, prev_amavasya,
-    prev_chandra_grahan, prev_conjunction, prev_max_speed, prev_purnima, prev_sankranti,
-    prev_specific_sankranti, prev_stationary, prev_surya_grahan, search_amavasyas,
-    search_chandra_grahan, search_conjunctions, search_max_speed, search_purnimas,
-    search_sankrantis, search_stationary, search_surya_grahan, shadbala_for_date, sidereal_sum_at,
-    siderealize_bhava_result, special_lagnas_for_date, tithi_at, tithi_for_date,
-    tropical_to_sidereal_longitude, vaar_for_date, vaar_from_sunrises, varsha_for_date,
-    vedic_day_sunrises, vimsopaka_for_date, yoga_at, yoga_for_date,
+    graha_longitudes, graha_speeds, hora_for_date, hora_from_sunrises, karana_at, karana_for_date,
+    masa_for_date, moon_rashi_ingresses, moving_osculating_apogees_for_date, nakshatra_at,
+    nakshatra_for_date, next_amavasya, next_chandra_grahan, next_conjunction, next_heliacal_rising,
+    next_heliacal_setting, next_max_speed, next_nakshatra_ingress, next_node_station, next_purnima,
+    next_sankranti, next_specific_sankranti, next_stationary, next_surya_grahan, panchang_diff,
+    prev_amavasya, prev_chandra_grahan, prev_conjunction, prev_heliacal_rising,
+    prev_heliacal_setting, prev_max_speed, prev_purnima, prev_sankranti, prev_specific_sankranti,
+    prev_stationary, prev_surya_grahan, retrograde_intervals, search_amavasyas,
+    search_chandra_grahan, search_conjunctions, search_heliacal_events, search_max_speed,
+    search_purnimas, search_sankrantis, search_stationary, search_surya_grahan, shadbala_for_date,
+    sidereal_sum_at, siderealize_bhava_result, special_lagnas_for_date, tithi_at,
+    tithi_day_kind_for_date, tithi_for_date, transit_bindus, tropical_to_sidereal_longitude,
+    vaar_for_date, vaar_from_sunrises, varsha_for_date, vedic_day_sunrises, vimsopaka_for_date,
+    yoga_at, yoga_for_date,
 };
 use dhruv_tara::{TaraAccuracy, TaraCatalog, TaraConfig, TaraError, TaraId};
 use dhruv_time::{
@@ -41,17 +46,19 @@ use dhruv_time::{
 use dhruv_vedic_base::dasha::RashiDashaInputs;
 use dhruv_vedic_base::{
     Amsha, AmshaRequest, AmshaVariationCatalog, AmshaVariationInfo, AyanamshaSystem, BhavaConfig,
-    BhavaReferenceMode, BhavaStartingPoint, BhavaSystem, CharakarakaScheme,
-    DEFAULT_AMSHA_VARIATION_CODE, GeoLocation, LunarNode, NodeMode, RiseSetConfig, RiseSetEvent,
-    RiseSetResult, SunLimb, VedicError, amsha_longitude, amsha_rashi_info, amsha_variation_catalog,
-    approximate_local_noon_jd, ayana_from_sidereal_longitude, ayanamsha_deg_with_catalog,
-    ayanamsha_mean_deg_with_catalog, ayanamsha_true_deg, compute_all_events, compute_bhavas,
-    compute_rise_set, default_amsha_variation, deg_to_dms, is_valid_amsha_variation,
-    jd_tdb_to_centuries, karana_from_elongation, lunar_node_deg, lunar_node_deg_for_epoch,
-    masa_from_rashi_index, nakshatra_from_longitude, nakshatra_from_tropical,
-    nakshatra28_from_longitude, nakshatra28_from_tropical, nth_rashi_from, rashi_from_longitude,
+    BhavaReferenceMode, BhavaStartingPoint, BhavaSystem, CharakarakaScheme, CustomAyanamsha,
+    DEFAULT_AMSHA_VARIATION_CODE, DayDefinitionFallback, GeoLocation, LunarNode, NodeMode,
+    RefractionModel, RiseSetConfig, RiseSetEvent, RiseSetResult, SamvatsaraScheme, SunLimb,
+    TithiDayKind, VedicError, amsha_longitude, amsha_rashi_info, amsha_variation_catalog,
+    approximate_local_noon_jd, ayana_from_sidereal_longitude, ayanamsha_deg_with_catalog_and_model,
+    ayanamsha_mean_deg_with_catalog_and_model, ayanamsha_rate_deg_per_century,
+    ayanamsha_true_deg_with_model, compute_all_events, compute_bhavas, compute_rise_set,
+    default_amsha_variation, deg_to_dms, is_valid_amsha_variation, jd_tdb_to_centuries,
+    karana_from_elongation, lunar_node_deg, lunar_node_deg_for_epoch, masa_from_rashi_index,
+    nakshatra_from_longitude, nakshatra_from_tropical, nakshatra28_from_longitude,
+    nakshatra28_from_tropical, node_speed_deg_per_day, nth_rashi_from, rashi_from_longitude,
     rashi_from_tropical, samvatsara_from_year, tithi_from_elongation, utc_day_start_jd,
-    vaar_from_jd, yoga_from_sum,
+    vaar_from_jd, whole_sign_from_lagna, yoga_from_sum,
 };
 use dhruv_vedic_ops::{
     PANCHANG_INCLUDE_AYANA, PANCHANG_INCLUDE_GHATIKA, PANCHANG_INCLUDE_HORA,
@@ -61,7 +68,7 @@ use dhruv_vedic_ops::{
 };
 
 /// ABI version for downstream bindings.
-pub const DHRUV_API_VERSION: u32 = 70;
+pub const DHRUV_API_VERSION: u32 = 71;
 
 /// Fixed UTF-8 buffer size for path fields in C-compatible structs.
 pub const DHRUV_PATH_CAPACITY: usize = 512;
@@ -922,10 +929,14 @@ fn riseset_config_from_ffi(cfg: &DhruvRiseSetConfig) -> Result<RiseSetConfig, Dh
         Some(l) => l,
         None => return Err(DhruvStatus::InvalidQuery),
     };
+    let refraction_model = refraction_model_from_ffi(cfg).ok_or(DhruvStatus::InvalidQuery)?;
+    let day_fallback = day_fallback_from_code(cfg.day_fallback).ok_or(DhruvStatus::InvalidQuery)?;
     Ok(RiseSetConfig {
         use_refraction: cfg.use_refraction != 0,
+        refraction_model,
         sun_limb,
         altitude_correction: cfg.altitude_correction != 0,
+        day_fallback,
     })
 }
 
@@ -965,6 +976,8 @@ fn graha_positions_config_from_ffi(
         include_lagna: cfg.include_lagna != 0,
         include_outer_planets: cfg.include_outer_planets != 0,
         include_bhava: cfg.include_bhava != 0,
+        include_flags: cfg.include_flags != 0,
+        time_anchor: dhruv_search::GrahaPositionsTimeAnchor::Exact,
     }
 }
 
@@ -1028,6 +1041,8 @@ fn resolve_graha_positions_config_ptr(
         include_lagna: false,
         include_outer_planets: true,
         include_bhava: false,
+        include_flags: false,
+        time_anchor: dhruv_search::GrahaPositionsTimeAnchor::Exact,
     })
 }
 
@@ -1886,13 +1901,23 @@ pub struct DhruvGeoLocation {
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DhruvRiseSetConfig {
-    /// Apply standard atmospheric refraction (34 arcmin): 1 = yes, 0 = no.
+    /// Apply atmospheric refraction per `refraction_model`: 1 = yes, 0 = no.
     pub use_refraction: u8,
     /// Which solar limb defines sunrise/sunset.
     /// 0 = UpperLimb, 1 = Center, 2 = LowerLimb.
     pub sun_limb: i32,
     /// Apply altitude dip correction: 1 = true, 0 = false.
     pub altitude_correction: u8,
+    /// Refraction model code (`DHRUV_REFRACTION_MODEL_*`).
+    pub refraction_model: i32,
+    /// Air pressure in hPa for Bennett/Saemundsson (default 1010).
+    pub refraction_pressure_hpa: f64,
+    /// Air temperature in degrees Celsius for Bennett/Saemundsson (default 10).
+    pub refraction_temperature_c: f64,
+    /// Horizon refraction in arcminutes for the custom model (default 34).
+    pub refraction_arcmin: f64,
+    /// Vedic-day boundary when the Sun does not rise (`DHRUV_DAY_FALLBACK_*`).
+    pub day_fallback: i32,
 }
 
 /// Sun limb: upper limb defines sunrise/sunset (conventional).
@@ -1902,6 +1927,29 @@ pub const DHRUV_SUN_LIMB_CENTER: i32 = 1;
 /// Sun limb: lower limb defines sunrise/sunset.
 pub const DHRUV_SUN_LIMB_LOWER: i32 = 2;
 
+/// Refraction model: fixed 34 arcmin.
+pub const DHRUV_REFRACTION_MODEL_STANDARD: i32 = 0;
+/// Refraction model: Bennett (1982), scaled by pressure/temperature.
+pub const DHRUV_REFRACTION_MODEL_BENNETT: i32 = 1;
+/// Refraction model: Saemundsson (1986), scaled by pressure/temperature.
+pub const DHRUV_REFRACTION_MODEL_SAEMUNDSSON: i32 = 2;
+/// Refraction model: caller-supplied `refraction_arcmin`.
+pub const DHRUV_REFRACTION_MODEL_CUSTOM: i32 = 3;
+
+/// Day fallback: no sunrise is an error (traditional definition).
+pub const DHRUV_DAY_FALLBACK_ERROR: i32 = 0;
+/// Day fallback: start the day at civil dawn (Sun center at -6 deg).
+pub const DHRUV_DAY_FALLBACK_CIVIL_TWILIGHT: i32 = 1;
+/// Day fallback: start the day at 06:00 local mean solar time.
+pub const DHRUV_DAY_FALLBACK_LOCAL_SIX_AM: i32 = 2;
+
+/// Tithi day kind: the next sunrise falls in the following tithi.
+pub const DHRUV_TITHI_DAY_NORMAL: i32 = 0;
+/// Tithi day kind: a tithi is skipped between this sunrise and the next.
+pub const DHRUV_TITHI_DAY_KSHAYA: i32 = 1;
+/// Tithi day kind: the sunrise tithi is still current at the next sunrise.
+pub const DHRUV_TITHI_DAY_VRIDDHI: i32 = 2;
+
 /// C-compatible rise/set result.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -1990,6 +2038,11 @@ pub extern "C" fn dhruv_riseset_config_default() -> DhruvRiseSetConfig {
         use_refraction: 1,
         sun_limb: DHRUV_SUN_LIMB_UPPER,
         altitude_correction: 1,
+        refraction_model: DHRUV_REFRACTION_MODEL_STANDARD,
+        refraction_pressure_hpa: 1010.0,
+        refraction_temperature_c: 10.0,
+        refraction_arcmin: 34.0,
+        day_fallback: DHRUV_DAY_FALLBACK_ERROR,
     }
 }
 
@@ -2003,6 +2056,42 @@ fn sun_limb_from_code(code: i32) -> Option<SunLimb> {
     }
 }
 
+/// Convert C day_fallback code to Rust DayDefinitionFallback enum.
+fn day_fallback_from_code(code: i32) -> Option<DayDefinitionFallback> {
+    match code {
+        DHRUV_DAY_FALLBACK_ERROR => Some(DayDefinitionFallback::Error),
+        DHRUV_DAY_FALLBACK_CIVIL_TWILIGHT => Some(DayDefinitionFallback::CivilTwilight),
+        DHRUV_DAY_FALLBACK_LOCAL_SIX_AM => Some(DayDefinitionFallback::LocalSixAm),
+        _ => None,
+    }
+}
+
+/// Convert the C refraction fields to a Rust `RefractionModel`.
+///
+/// Returns `None` for an unknown code or non-finite parameters.
+fn refraction_model_from_ffi(cfg: &DhruvRiseSetConfig) -> Option<RefractionModel> {
+    let pressure_hpa = cfg.refraction_pressure_hpa;
+    let temperature_c = cfg.refraction_temperature_c;
+    let atmosphere_ok = pressure_hpa.is_finite() && temperature_c.is_finite();
+    match cfg.refraction_model {
+        DHRUV_REFRACTION_MODEL_STANDARD => Some(RefractionModel::Standard),
+        DHRUV_REFRACTION_MODEL_BENNETT if atmosphere_ok => Some(RefractionModel::Bennett {
+            pressure_hpa,
+            temperature_c,
+        }),
+        DHRUV_REFRACTION_MODEL_SAEMUNDSSON if atmosphere_ok => Some(RefractionModel::Saemundsson {
+            pressure_hpa,
+            temperature_c,
+        }),
+        DHRUV_REFRACTION_MODEL_CUSTOM if cfg.refraction_arcmin.is_finite() => {
+            Some(RefractionModel::Custom {
+                arcmin: cfg.refraction_arcmin,
+            })
+        }
+        _ => None,
+    }
+}
+
 /// Compute a single rise/set event.
 ///
 /// # Safety
@@ -2143,6 +2232,8 @@ pub const DHRUV_AYANAMSHA_MODE_MEAN: i32 = 0;
 pub const DHRUV_AYANAMSHA_MODE_TRUE: i32 = 1;
 /// Ayanamsha mode selector: unified model with `use_nutation`.
 pub const DHRUV_AYANAMSHA_MODE_UNIFIED: i32 = 2;
+/// Ayanamsha mode selector: mean-ayanamsha rate in degrees per Julian century.
+pub const DHRUV_AYANAMSHA_MODE_RATE: i32 = 3;
 
 /// Ayanamsha time input selector: JD TDB in `jd_tdb`.
 pub const DHRUV_AYANAMSHA_TIME_JD_TDB: i32 = 0;
@@ -2167,6 +2258,8 @@ pub struct DhruvAyanamshaComputeRequest {
     pub use_nutation: u8,
     /// Used by `mode=TRUE` (arcseconds).
     pub delta_psi_arcsec: f64,
+    /// Precession model code (`DHRUV_PRECESSION_MODEL_*`).
+    pub precession_model: i32,
 }
 
 /// Unified ayanamsha compute API covering mode + time-base variants.
@@ -2174,6 +2267,10 @@ pub struct DhruvAyanamshaComputeRequest {
 /// - `mode=MEAN` computes mean ayanamsha.
 /// - `mode=TRUE` computes true ayanamsha using `delta_psi_arcsec`.
 /// - `mode=UNIFIED` computes unified ayanamsha using `use_nutation`.
+/// - `mode=RATE` computes the mean-ayanamsha rate; `out_deg` then holds
+///   degrees per Julian century.
+///
+/// All modes carry the anchor to the epoch with `precession_model`.
 ///
 /// - `time_kind=JD_TDB` uses `jd_tdb`.
 /// - `time_kind=UTC` uses `utc` and requires `lsk`.
@@ -2201,6 +2298,10 @@ pub unsafe extern "C" fn dhruv_ayanamsha_compute_ex(
             Some(s) => s,
             None => return DhruvStatus::InvalidQuery,
         };
+        let model = match precession_model_from_code(req.precession_model) {
+            Some(m) => m,
+            None => return DhruvStatus::InvalidQuery,
+        };
 
         let jd_tdb = match req.time_kind {
             DHRUV_AYANAMSHA_TIME_JD_TDB => req.jd_tdb,
@@ -2222,11 +2323,20 @@ pub unsafe extern "C" fn dhruv_ayanamsha_compute_ex(
         };
 
         let deg = match req.mode {
-            DHRUV_AYANAMSHA_MODE_MEAN => ayanamsha_mean_deg_with_catalog(system, t, cat_opt),
-            DHRUV_AYANAMSHA_MODE_TRUE => ayanamsha_true_deg(system, t, req.delta_psi_arcsec),
-            DHRUV_AYANAMSHA_MODE_UNIFIED => {
-                ayanamsha_deg_with_catalog(system, t, req.use_nutation != 0, cat_opt)
+            DHRUV_AYANAMSHA_MODE_MEAN => {
+                ayanamsha_mean_deg_with_catalog_and_model(system, t, cat_opt, model)
+            }
+            DHRUV_AYANAMSHA_MODE_TRUE => {
+                ayanamsha_true_deg_with_model(system, t, req.delta_psi_arcsec, model)
             }
+            DHRUV_AYANAMSHA_MODE_UNIFIED => ayanamsha_deg_with_catalog_and_model(
+                system,
+                t,
+                req.use_nutation != 0,
+                cat_opt,
+                model,
+            ),
+            DHRUV_AYANAMSHA_MODE_RATE => ayanamsha_rate_deg_per_century(system, t, model),
             _ => return DhruvStatus::InvalidQuery,
         };
 
@@ -2375,6 +2485,7 @@ pub const DHRUV_BHAVA_CAMPANUS: i32 = 6;
 pub const DHRUV_BHAVA_AXIAL_ROTATION: i32 = 7;
 pub const DHRUV_BHAVA_TOPOCENTRIC: i32 = 8;
 pub const DHRUV_BHAVA_ALCABITUS: i32 = 9;
+pub const DHRUV_BHAVA_PORPHYRY: i32 = 10;
 
 pub const DHRUV_BHAVA_REF_START: i32 = 0;
 pub const DHRUV_BHAVA_REF_MIDDLE: i32 = 1;
@@ -2395,7 +2506,7 @@ pub const DHRUV_BHAVA_START_CUSTOM: i32 = -2;
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DhruvBhavaConfig {
-    /// House system code (0-9, see DHRUV_BHAVA_* constants).
+    /// House system code (0-10, see DHRUV_BHAVA_* constants).
     pub system: i32,
     /// Starting point: -1=Lagna, -2=custom deg, or positive NAIF body code.
     pub starting_point: i32,
@@ -2435,6 +2546,7 @@ pub struct DhruvBhava {
     pub cusp_deg: f64,
     pub start_deg: f64,
     pub end_deg: f64,
+    pub madhya_deg: f64,
 }
 
 /// C-compatible full bhava result.
@@ -2450,7 +2562,7 @@ pub struct DhruvBhavaResult {
     pub rashi_bhava_mc_deg: f64,
 }
 
-/// Map integer code 0..9 to BhavaSystem enum variant.
+/// Map integer code 0..10 to BhavaSystem enum variant.
 fn bhava_system_from_code(code: i32) -> Option<BhavaSystem> {
     let systems = BhavaSystem::all();
     let idx = usize::try_from(code).ok()?;
@@ -2558,6 +2670,7 @@ fn bhava_result_to_ffi_with_projection(
         cusp_deg: 0.0,
         start_deg: 0.0,
         end_deg: 0.0,
+        madhya_deg: 0.0,
     }; 12];
     for (i, b) in view.bhavas.iter().enumerate() {
         ffi_bhavas[i] = DhruvBhava {
@@ -2565,6 +2678,7 @@ fn bhava_result_to_ffi_with_projection(
             cusp_deg: b.cusp_deg,
             start_deg: b.start_deg,
             end_deg: b.end_deg,
+            madhya_deg: b.madhya_deg,
         };
     }
     DhruvBhavaResult {
@@ -2577,6 +2691,7 @@ fn bhava_result_to_ffi_with_projection(
             cusp_deg: 0.0,
             start_deg: 0.0,
             end_deg: 0.0,
+            madhya_deg: 0.0,
         }; 12],
         rashi_bhava_lagna_deg: 0.0,
         rashi_bhava_mc_deg: 0.0,
@@ -2592,6 +2707,7 @@ fn rashi_bhava_ffi_from_lagna(lagna_deg: f64) -> ([DhruvBhava; 12], f64, f64) {
         cusp_deg: 0.0,
         start_deg: 0.0,
         end_deg: 0.0,
+        madhya_deg: 0.0,
     }; 12];
     for i in 0..12 {
         let rashi = (lagna_rashi + i as u8) % 12;
@@ -2602,6 +2718,7 @@ fn rashi_bhava_ffi_from_lagna(lagna_deg: f64) -> ([DhruvBhava; 12], f64, f64) {
             cusp_deg: cusp,
             start_deg: start,
             end_deg: (start + 30.0).rem_euclid(360.0),
+            madhya_deg: start + 15.0,
         };
     }
     (bhavas, lagna, bhavas[9].cusp_deg)
@@ -2747,6 +2864,34 @@ pub unsafe extern "C" fn dhruv_compute_bhavas(
     })
 }
 
+/// Whole-sign bhavas from a known sidereal lagna longitude.
+///
+/// Pure math, no engine or EOP needed. Cusp `n` is the start of rashi
+/// `lagna_rashi + n - 1`; `mc_deg` is the 10th cusp. The rashi-bhava sibling
+/// fields are left unset.
+///
+/// # Safety
+/// `out_result` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_whole_sign_from_lagna(
+    lagna_sid_deg: f64,
+    out_result: *mut DhruvBhavaResult,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out_result.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        if !lagna_sid_deg.is_finite() {
+            return DhruvStatus::InvalidInput;
+        }
+
+        let result = whole_sign_from_lagna(lagna_sid_deg);
+        // SAFETY: Pointer checked for null.
+        unsafe { *out_result = bhava_result_to_ffi_with_projection(&result, None) };
+        DhruvStatus::Ok
+    })
+}
+
 /// Compute the Lagna (Ascendant) ecliptic longitude in degrees.
 ///
 /// Requires LSK, EOP, and location (no engine needed).
@@ -3077,6 +3222,43 @@ pub unsafe extern "C" fn dhruv_lunar_node_deg(
     })
 }
 
+/// Compute the Rahu longitude rate in degrees per day.
+///
+/// Pure math, no engine needed. Central difference of the pure-math node
+/// longitude; Ketu moves at the same rate. The mean node regresses at a
+/// near-constant ≈ −0.053 deg/day, while the true node briefly turns direct
+/// (positive) near its stations.
+///
+/// # Arguments
+/// * `mode_code` — 0 = Mean, 1 = True
+/// * `jd_tdb` — Julian Date in TDB
+/// * `out_deg_per_day` — output speed in degrees per day
+///
+/// # Safety
+/// `out_deg_per_day` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_node_speed_deg_per_day(
+    mode_code: i32,
+    jd_tdb: f64,
+    out_deg_per_day: *mut f64,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out_deg_per_day.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+
+        let mode = match node_mode_from_code(mode_code) {
+            Some(m) => m,
+            None => return DhruvStatus::InvalidQuery,
+        };
+
+        let t = jd_tdb_to_centuries(jd_tdb);
+        // SAFETY: Pointer is checked for null; write one value.
+        unsafe { *out_deg_per_day = node_speed_deg_per_day(t, mode) };
+        DhruvStatus::Ok
+    })
+}
+
 /// Compute lunar node longitude in degrees [0, 360), using an engine handle.
 ///
 /// For `mode_code=1` (True), this uses an osculating-node computation from
@@ -4394,119 +4576,581 @@ pub unsafe extern "C" fn dhruv_motion_search_ex(
     })
 }
 
-// ---------------------------------------------------------------------------
-// Rashi / Nakshatra
-// ---------------------------------------------------------------------------
-
-/// C-compatible DMS (degrees-minutes-seconds).
+/// C-compatible true-node station event.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DhruvDms {
-    pub degrees: u16,
-    pub minutes: u8,
-    pub seconds: f64,
+pub struct DhruvNodeStationEvent {
+    /// Event time as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// Event time as structured Gregorian UTC.
+    pub utc: DhruvUtcTime,
+    /// Osculating Rahu longitude at station in degrees [0, 360).
+    pub rahu_longitude_deg: f64,
+    /// Station type code (see DHRUV_STATION_* constants).
+    pub station_type: i32,
 }
 
-/// C-compatible rashi result.
-#[repr(C)]
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DhruvRashiInfo {
-    /// 0-based rashi index (0 = Mesha, 11 = Meena).
-    pub rashi_index: u8,
-    /// Position within rashi as DMS.
-    pub dms: DhruvDms,
-    /// Decimal degrees within the rashi [0.0, 30.0).
-    pub degrees_in_rashi: f64,
-}
+/// Find the next true (osculating) lunar node station after `jd_tdb`.
+///
+/// Ketu stations at the same instant as Rahu. Writes `*out_found = 0` when no
+/// station lies within the scan window.
+///
+/// # Safety
+/// `engine`, `out_event` and `out_found` must be valid, non-null pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_next_node_station(
+    engine: *const DhruvEngineHandle,
+    jd_tdb: f64,
+    out_event: *mut DhruvNodeStationEvent,
+    out_found: *mut u8,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if engine.is_null() || out_event.is_null() || out_found.is_null() {
+            return DhruvStatus::NullPointer;
+        }
 
-/// C-compatible nakshatra result (27-scheme).
-#[repr(C)]
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DhruvNakshatraInfo {
-    /// 0-based nakshatra index (0 = Ashwini, 26 = Revati).
-    pub nakshatra_index: u8,
-    /// Pada (1-4).
-    pub pada: u8,
-    /// Decimal degrees within the nakshatra.
-    pub degrees_in_nakshatra: f64,
-    /// Decimal degrees within the pada.
-    pub degrees_in_pada: f64,
+        // SAFETY: Pointer is checked for null above.
+        let engine_ref = unsafe { &*engine };
+        match next_node_station(engine_ref, jd_tdb) {
+            Ok(Some(event)) => {
+                // SAFETY: Pointers are checked for null above.
+                unsafe {
+                    *out_event = DhruvNodeStationEvent {
+                        jd_tdb: event.jd_tdb,
+                        utc: utc_time_to_ffi(&event.utc),
+                        rahu_longitude_deg: event.rahu_longitude_deg,
+                        station_type: station_type_to_code(event.station_type),
+                    };
+                    *out_found = 1;
+                }
+                DhruvStatus::Ok
+            }
+            Ok(None) => {
+                // SAFETY: Pointer is checked for null above.
+                unsafe { *out_found = 0 };
+                DhruvStatus::Ok
+            }
+            Err(e) => DhruvStatus::from(&e),
+        }
+    })
 }
 
-/// C-compatible nakshatra result (28-scheme, with Abhijit).
+/// C-compatible retrograde (vakra) interval between paired stations.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DhruvNakshatra28Info {
-    /// 0-based nakshatra index (0 = Ashwini, 21 = Abhijit, 27 = Revati).
-    pub nakshatra_index: u8,
-    /// Pada (1-4, or 0 for Abhijit).
-    pub pada: u8,
-    /// Decimal degrees within the nakshatra.
-    pub degrees_in_nakshatra: f64,
+pub struct DhruvRetrogradeInterval {
+    /// Retrograde station (or range start when `open_start`), JD TDB.
+    pub start_jd_tdb: f64,
+    /// Direct station (or range end when `open_end`), JD TDB.
+    pub end_jd_tdb: f64,
+    /// `start_jd_tdb` as structured Gregorian UTC.
+    pub start_utc: DhruvUtcTime,
+    /// `end_jd_tdb` as structured Gregorian UTC.
+    pub end_utc: DhruvUtcTime,
+    /// Tropical ecliptic longitude at the start in degrees [0, 360).
+    pub start_lon_deg: f64,
+    /// Tropical ecliptic longitude at the end in degrees [0, 360).
+    pub end_lon_deg: f64,
+    /// 1 when the body was already retrograde at the range start.
+    pub open_start: u8,
+    /// 1 when the body was still retrograde at the range end.
+    pub open_end: u8,
 }
 
-/// Convert decimal degrees to DMS.
+/// List the retrograde intervals of a body between two JD TDB instants.
+///
+/// Each interval pairs a retrograde station with the following direct
+/// station. Writes events to `out_intervals[..max_count]` and the written
+/// count to `out_count`; callers that fill the buffer should retry with more
+/// room.
 ///
 /// # Safety
-/// `out` must be a valid, non-null pointer.
+/// All pointer arguments must be valid and non-null; `out_intervals` must
+/// hold `max_count` elements.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dhruv_deg_to_dms(degrees: f64, out: *mut DhruvDms) -> DhruvStatus {
+pub unsafe extern "C" fn dhruv_retrograde_intervals(
+    engine: *const DhruvEngineHandle,
+    body_code: i32,
+    start_jd_tdb: f64,
+    end_jd_tdb: f64,
+    config: *const DhruvStationaryConfig,
+    out_intervals: *mut DhruvRetrogradeInterval,
+    max_count: u32,
+    out_count: *mut u32,
+) -> DhruvStatus {
     ffi_boundary(|| {
-        if out.is_null() {
+        if engine.is_null() || config.is_null() || out_intervals.is_null() || out_count.is_null() {
             return DhruvStatus::NullPointer;
         }
-        let d = deg_to_dms(degrees);
-        unsafe {
-            *out = DhruvDms {
-                degrees: d.degrees,
-                minutes: d.minutes,
-                seconds: d.seconds,
-            };
+        let Some(body) = Body::from_code(body_code) else {
+            return DhruvStatus::InvalidQuery;
+        };
+        let engine_ref = unsafe { &*engine };
+        let cfg = stationary_config_from_ffi(unsafe { &*config });
+        match retrograde_intervals(engine_ref, body, start_jd_tdb, end_jd_tdb, &cfg) {
+            Ok(intervals) => {
+                let count = intervals.len().min(max_count as usize);
+                let out_slice =
+                    unsafe { std::slice::from_raw_parts_mut(out_intervals, max_count as usize) };
+                for (slot, span) in out_slice.iter_mut().zip(intervals.iter().take(count)) {
+                    *slot = DhruvRetrogradeInterval {
+                        start_jd_tdb: span.start_jd,
+                        end_jd_tdb: span.end_jd,
+                        start_utc: utc_time_to_ffi(&UtcTime::from_jd_tdb(
+                            span.start_jd,
+                            engine_ref.lsk(),
+                        )),
+                        end_utc: utc_time_to_ffi(&UtcTime::from_jd_tdb(
+                            span.end_jd,
+                            engine_ref.lsk(),
+                        )),
+                        start_lon_deg: span.start_lon_deg,
+                        end_lon_deg: span.end_lon_deg,
+                        open_start: u8::from(span.open_start),
+                        open_end: u8::from(span.open_end),
+                    };
+                }
+                unsafe { *out_count = count as u32 };
+                DhruvStatus::Ok
+            }
+            Err(e) => DhruvStatus::from(&e),
         }
-        DhruvStatus::Ok
     })
 }
 
-/// Determine rashi from sidereal ecliptic longitude.
+// ---------------------------------------------------------------------------
+// Heliacal rising / setting
+// ---------------------------------------------------------------------------
+
+/// Heliacal target selector: solar-system body given by `body_code`.
+pub const DHRUV_HELIACAL_TARGET_BODY: i32 = 0;
+/// Heliacal target selector: catalog star given by `tara_id`.
+pub const DHRUV_HELIACAL_TARGET_TARA: i32 = 1;
+
+/// Heliacal rising: first dawn the object is visible after solar conjunction.
+pub const DHRUV_HELIACAL_RISING: i32 = 0;
+/// Heliacal setting: last dusk the object is visible before solar conjunction.
+pub const DHRUV_HELIACAL_SETTING: i32 = 1;
+
+/// Heliacal query mode: next event after `at_jd_tdb`.
+pub const DHRUV_HELIACAL_QUERY_MODE_NEXT: i32 = 0;
+/// Heliacal query mode: previous event before `at_jd_tdb`.
+pub const DHRUV_HELIACAL_QUERY_MODE_PREV: i32 = 1;
+/// Heliacal query mode: all risings and settings in [`start_jd_tdb`, `end_jd_tdb`].
+pub const DHRUV_HELIACAL_QUERY_MODE_RANGE: i32 = 2;
+
+/// C-compatible heliacal visibility configuration.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvHeliacalConfig {
+    /// Solar depression below the horizon at the test instant, in degrees.
+    pub arcus_visionis_deg: f64,
+    /// Minimum geocentric altitude of the object, in degrees.
+    pub min_altitude_deg: f64,
+    /// Minimum angular separation from the Sun, in degrees.
+    pub min_elongation_deg: f64,
+}
+
+/// C-compatible request for heliacal search.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvHeliacalSearchRequest {
+    /// Target selector (`DHRUV_HELIACAL_TARGET_*`).
+    pub target_kind: i32,
+    /// NAIF body code when `target_kind` is BODY (not Sun or Earth).
+    pub body_code: i32,
+    /// Tara id code when `target_kind` is TARA.
+    pub tara_id: i32,
+    /// Phenomenon for next/prev modes (`DHRUV_HELIACAL_RISING`/`SETTING`).
+    /// Ignored in range mode, which returns both.
+    pub event_kind: i32,
+    /// Query mode selector (`DHRUV_HELIACAL_QUERY_MODE_*`).
+    pub query_mode: i32,
+    /// Time selector (`DHRUV_SEARCH_TIME_*`).
+    pub time_kind: i32,
+    /// Anchor time for next/prev modes (JD TDB).
+    pub at_jd_tdb: f64,
+    /// Start of range window for range mode (JD TDB).
+    pub start_jd_tdb: f64,
+    /// End of range window for range mode (JD TDB).
+    pub end_jd_tdb: f64,
+    /// Anchor time for next/prev modes (UTC).
+    pub at_utc: DhruvUtcTime,
+    /// Start of range window for range mode (UTC).
+    pub start_utc: DhruvUtcTime,
+    /// End of range window for range mode (UTC).
+    pub end_utc: DhruvUtcTime,
+    /// Visibility configuration.
+    pub config: DhruvHeliacalConfig,
+}
+
+/// C-compatible heliacal event result.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvHeliacalEvent {
+    /// Twilight instant as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// Twilight instant as structured Gregorian UTC.
+    pub utc: DhruvUtcTime,
+    /// Phenomenon code (`DHRUV_HELIACAL_RISING`/`SETTING`).
+    pub event_kind: i32,
+    /// Geocentric altitude of the object at the twilight instant, in degrees.
+    pub altitude_deg: f64,
+    /// Angular separation between the object and the Sun, in degrees.
+    pub elongation_deg: f64,
+}
+
+fn heliacal_config_to_ffi(cfg: &HeliacalEventConfig) -> DhruvHeliacalConfig {
+    DhruvHeliacalConfig {
+        arcus_visionis_deg: cfg.arcus_visionis_deg,
+        min_altitude_deg: cfg.min_altitude_deg,
+        min_elongation_deg: cfg.min_elongation_deg,
+    }
+}
+
+fn heliacal_config_from_ffi(cfg: &DhruvHeliacalConfig) -> HeliacalEventConfig {
+    HeliacalEventConfig {
+        arcus_visionis_deg: cfg.arcus_visionis_deg,
+        min_altitude_deg: cfg.min_altitude_deg,
+        min_elongation_deg: cfg.min_elongation_deg,
+    }
+}
+
+impl From<&HeliacalEvent> for DhruvHeliacalEvent {
+    fn from(e: &HeliacalEvent) -> Self {
+        Self {
+            jd_tdb: e.jd_tdb,
+            utc: utc_time_to_ffi(&e.utc),
+            event_kind: match e.kind {
+                HeliacalKind::Rising => DHRUV_HELIACAL_RISING,
+                HeliacalKind::Setting => DHRUV_HELIACAL_SETTING,
+            },
+            altitude_deg: e.altitude_deg,
+            elongation_deg: e.elongation_deg,
+        }
+    }
+}
+
+/// Returns the default heliacal configuration (10° arcus visionis, suited to
+/// first-magnitude stars).
+#[unsafe(no_mangle)]
+pub extern "C" fn dhruv_heliacal_config_default() -> DhruvHeliacalConfig {
+    heliacal_config_to_ffi(&HeliacalEventConfig::default())
+}
+
+/// Write the traditional heliacal configuration for a planet.
+///
+/// Bodies without a traditional arcus visionis get the default configuration.
 ///
 /// # Safety
-/// `out` must be a valid, non-null pointer.
+/// `out_config` must be a valid, non-null pointer.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dhruv_rashi_from_longitude(
-    sidereal_lon_deg: f64,
-    out: *mut DhruvRashiInfo,
+pub unsafe extern "C" fn dhruv_heliacal_config_for_body(
+    body_code: i32,
+    out_config: *mut DhruvHeliacalConfig,
 ) -> DhruvStatus {
     ffi_boundary(|| {
-        if out.is_null() {
+        if out_config.is_null() {
             return DhruvStatus::NullPointer;
         }
-        let info = rashi_from_longitude(sidereal_lon_deg);
-        unsafe {
-            *out = DhruvRashiInfo {
-                rashi_index: info.rashi_index,
-                dms: DhruvDms {
-                    degrees: info.dms.degrees,
-                    minutes: info.dms.minutes,
-                    seconds: info.dms.seconds,
-                },
-                degrees_in_rashi: info.degrees_in_rashi,
-            };
-        }
+        let body = match Body::from_code(body_code) {
+            Some(b) => b,
+            None => return DhruvStatus::InvalidQuery,
+        };
+        // SAFETY: Pointer checked for null above.
+        unsafe { *out_config = heliacal_config_to_ffi(&HeliacalEventConfig::for_body(body)) };
         DhruvStatus::Ok
     })
 }
 
-/// Determine nakshatra from sidereal ecliptic longitude (27-scheme).
+/// Unified heliacal rising/setting search entrypoint.
+///
+/// Mode behavior:
+/// - `DHRUV_HELIACAL_QUERY_MODE_NEXT` / `DHRUV_HELIACAL_QUERY_MODE_PREV`:
+///   writes the single event of `event_kind` and the found flag.
+/// - `DHRUV_HELIACAL_QUERY_MODE_RANGE`:
+///   writes risings and settings in chronological order and the count.
+///
+/// `catalog` is only read for `DHRUV_HELIACAL_TARGET_TARA` and may be null
+/// for body targets.
 ///
 /// # Safety
-/// `out` must be a valid, non-null pointer.
+/// `engine`, `eop`, `location` and `request` must be valid and non-null.
+/// Output pointers required depend on `query_mode`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dhruv_nakshatra_from_longitude(
-    sidereal_lon_deg: f64,
-    out: *mut DhruvNakshatraInfo,
+pub unsafe extern "C" fn dhruv_heliacal_search_ex(
+    engine: *const DhruvEngineHandle,
+    eop: *const DhruvEopHandle,
+    catalog: *const DhruvTaraCatalogHandle,
+    location: *const DhruvGeoLocation,
+    request: *const DhruvHeliacalSearchRequest,
+    out_event: *mut DhruvHeliacalEvent,
+    out_found: *mut u8,
+    out_events: *mut DhruvHeliacalEvent,
+    max_count: u32,
+    out_count: *mut u32,
 ) -> DhruvStatus {
     ffi_boundary(|| {
-        if out.is_null() {
+        if engine.is_null() || eop.is_null() || location.is_null() || request.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+
+        // SAFETY: Pointers checked for null above.
+        let engine_ref = unsafe { &*engine };
+        let eop_ref = unsafe { &*eop };
+        let loc_ref = unsafe { &*location };
+        let req = unsafe { &*request };
+
+        let target = match req.target_kind {
+            DHRUV_HELIACAL_TARGET_BODY => match Body::from_code(req.body_code) {
+                Some(b) => HeliacalTarget::Body(b),
+                None => return DhruvStatus::InvalidQuery,
+            },
+            DHRUV_HELIACAL_TARGET_TARA => {
+                if catalog.is_null() {
+                    return DhruvStatus::NullPointer;
+                }
+                match TaraId::from_code(req.tara_id) {
+                    // SAFETY: Pointer checked for null above.
+                    Some(id) => HeliacalTarget::Tara(unsafe { &*catalog }, id),
+                    None => return DhruvStatus::InvalidQuery,
+                }
+            }
+            _ => return DhruvStatus::InvalidQuery,
+        };
+        if let Err(status) = validate_search_time_kind(req.time_kind) {
+            return status;
+        }
+        let geo = GeoLocation::new(
+            loc_ref.latitude_deg,
+            loc_ref.longitude_deg,
+            loc_ref.altitude_m,
+        );
+        let config = heliacal_config_from_ffi(&req.config);
+
+        if req.query_mode == DHRUV_HELIACAL_QUERY_MODE_RANGE {
+            if out_events.is_null() || out_count.is_null() {
+                return DhruvStatus::NullPointer;
+            }
+            let start = match search_time_to_jd_tdb(
+                engine_ref,
+                req.time_kind,
+                req.start_jd_tdb,
+                req.start_utc,
+            ) {
+                Ok(v) => v,
+                Err(status) => return status,
+            };
+            let end =
+                match search_time_to_jd_tdb(engine_ref, req.time_kind, req.end_jd_tdb, req.end_utc)
+                {
+                    Ok(v) => v,
+                    Err(status) => return status,
+                };
+            return match search_heliacal_events(
+                engine_ref, eop_ref, target, &geo, start, end, &config,
+            ) {
+                Ok(events) => {
+                    let count = events.len().min(max_count as usize);
+                    let out_slice =
+                        unsafe { std::slice::from_raw_parts_mut(out_events, max_count as usize) };
+                    for (i, e) in events.iter().take(count).enumerate() {
+                        out_slice[i] = DhruvHeliacalEvent::from(e);
+                    }
+                    unsafe { *out_count = count as u32 };
+                    DhruvStatus::Ok
+                }
+                Err(e) => DhruvStatus::from(&e),
+            };
+        }
+
+        if out_event.is_null() || out_found.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        let at = match search_time_to_jd_tdb(engine_ref, req.time_kind, req.at_jd_tdb, req.at_utc) {
+            Ok(v) => v,
+            Err(status) => return status,
+        };
+        let result = match (req.query_mode, req.event_kind) {
+            (DHRUV_HELIACAL_QUERY_MODE_NEXT, DHRUV_HELIACAL_RISING) => {
+                next_heliacal_rising(engine_ref, eop_ref, target, &geo, at, &config)
+            }
+            (DHRUV_HELIACAL_QUERY_MODE_PREV, DHRUV_HELIACAL_RISING) => {
+                prev_heliacal_rising(engine_ref, eop_ref, target, &geo, at, &config)
+            }
+            (DHRUV_HELIACAL_QUERY_MODE_NEXT, DHRUV_HELIACAL_SETTING) => {
+                next_heliacal_setting(engine_ref, eop_ref, target, &geo, at, &config)
+            }
+            (DHRUV_HELIACAL_QUERY_MODE_PREV, DHRUV_HELIACAL_SETTING) => {
+                prev_heliacal_setting(engine_ref, eop_ref, target, &geo, at, &config)
+            }
+            _ => return DhruvStatus::InvalidQuery,
+        };
+        match result {
+            Ok(Some(event)) => {
+                unsafe {
+                    *out_event = DhruvHeliacalEvent::from(&event);
+                    *out_found = 1;
+                }
+                DhruvStatus::Ok
+            }
+            Ok(None) => {
+                unsafe { *out_found = 0 };
+                DhruvStatus::Ok
+            }
+            Err(e) => DhruvStatus::from(&e),
+        }
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Rashi / Nakshatra
+// ---------------------------------------------------------------------------
+
+/// C-compatible DMS (degrees-minutes-seconds).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvDms {
+    pub degrees: u16,
+    pub minutes: u8,
+    pub seconds: f64,
+}
+
+/// C-compatible rashi result.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvRashiInfo {
+    /// 0-based rashi index (0 = Mesha, 11 = Meena).
+    pub rashi_index: u8,
+    /// Position within rashi as DMS.
+    pub dms: DhruvDms,
+    /// Decimal degrees within the rashi [0.0, 30.0).
+    pub degrees_in_rashi: f64,
+}
+
+/// C-compatible nakshatra result (27-scheme).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvNakshatraInfo {
+    /// 0-based nakshatra index (0 = Ashwini, 26 = Revati).
+    pub nakshatra_index: u8,
+    /// Pada (1-4).
+    pub pada: u8,
+    /// Decimal degrees within the nakshatra.
+    pub degrees_in_nakshatra: f64,
+    /// Decimal degrees within the pada.
+    pub degrees_in_pada: f64,
+}
+
+/// C-compatible nakshatra result (28-scheme, with Abhijit).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvNakshatra28Info {
+    /// 0-based nakshatra index (0 = Ashwini, 21 = Abhijit, 27 = Revati).
+    pub nakshatra_index: u8,
+    /// Pada (1-4, or 0 for Abhijit).
+    pub pada: u8,
+    /// Decimal degrees within the nakshatra.
+    pub degrees_in_nakshatra: f64,
+}
+
+/// C-compatible KP (Krishnamurti Paddhati) star and sub lord result.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvKpSublord {
+    /// 0-based nakshatra index (0 = Ashwini .. 26 = Revati).
+    pub nakshatra_index: u8,
+    /// Graha index (0-8) of the nakshatra's Vimshottari lord.
+    pub star_lord: u8,
+    /// Graha index (0-8) of the KP sub lord.
+    pub sub_lord: u8,
+}
+
+/// Gandanta junction: Meena → Mesha (0°).
+pub const DHRUV_GANDANTA_MEENA_MESHA: i32 = 0;
+/// Gandanta junction: Karka → Simha (120°).
+pub const DHRUV_GANDANTA_KARKA_SIMHA: i32 = 1;
+/// Gandanta junction: Vrischika → Dhanu (240°).
+pub const DHRUV_GANDANTA_VRISCHIKA_DHANU: i32 = 2;
+/// Gandanta half: last pada of the water rashi, before the junction.
+pub const DHRUV_GANDANTA_APPROACHING: i32 = 0;
+/// Gandanta half: first pada of the fire rashi, at or after the junction.
+pub const DHRUV_GANDANTA_LEAVING: i32 = 1;
+
+/// C-compatible gandanta placement of a sidereal longitude.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvGandantaStatus {
+    /// `DHRUV_GANDANTA_*` junction code.
+    pub kind: i32,
+    /// `DHRUV_GANDANTA_APPROACHING` or `DHRUV_GANDANTA_LEAVING`.
+    pub half: i32,
+    /// Distance from the exact junction in degrees, [0, 3°20').
+    pub offset_deg: f64,
+}
+
+/// Convert decimal degrees to DMS.
+///
+/// # Safety
+/// `out` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_deg_to_dms(degrees: f64, out: *mut DhruvDms) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        let d = deg_to_dms(degrees);
+        unsafe {
+            *out = DhruvDms {
+                degrees: d.degrees,
+                minutes: d.minutes,
+                seconds: d.seconds,
+            };
+        }
+        DhruvStatus::Ok
+    })
+}
+
+/// Determine rashi from sidereal ecliptic longitude.
+///
+/// # Safety
+/// `out` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_rashi_from_longitude(
+    sidereal_lon_deg: f64,
+    out: *mut DhruvRashiInfo,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        let info = rashi_from_longitude(sidereal_lon_deg);
+        unsafe {
+            *out = DhruvRashiInfo {
+                rashi_index: info.rashi_index,
+                dms: DhruvDms {
+                    degrees: info.dms.degrees,
+                    minutes: info.dms.minutes,
+                    seconds: info.dms.seconds,
+                },
+                degrees_in_rashi: info.degrees_in_rashi,
+            };
+        }
+        DhruvStatus::Ok
+    })
+}
+
+/// Determine nakshatra from sidereal ecliptic longitude (27-scheme).
+///
+/// # Safety
+/// `out` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_nakshatra_from_longitude(
+    sidereal_lon_deg: f64,
+    out: *mut DhruvNakshatraInfo,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out.is_null() {
             return DhruvStatus::NullPointer;
         }
         let info = nakshatra_from_longitude(sidereal_lon_deg);
@@ -4547,6 +5191,79 @@ pub unsafe extern "C" fn dhruv_nakshatra28_from_longitude(
     })
 }
 
+/// Determine the KP star lord and sub lord of a sidereal ecliptic longitude.
+///
+/// # Safety
+/// `out` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_kp_sublord(
+    sidereal_lon_deg: f64,
+    out: *mut DhruvKpSublord,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        if !sidereal_lon_deg.is_finite() {
+            return DhruvStatus::InvalidInput;
+        }
+        let (nakshatra, star_lord, sub_lord) = dhruv_vedic_base::kp_sublord(sidereal_lon_deg);
+        unsafe {
+            *out = DhruvKpSublord {
+                nakshatra_index: nakshatra.index(),
+                star_lord: star_lord.index(),
+                sub_lord: sub_lord.index(),
+            };
+        }
+        DhruvStatus::Ok
+    })
+}
+
+/// Gandanta junction, side, and offset of a sidereal ecliptic longitude.
+///
+/// Sets `*out_found` to 0 (leaving `*out` untouched) when the longitude is not
+/// in a gandanta zone.
+///
+/// # Safety
+/// `out` and `out_found` must be valid, non-null pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_gandanta_status(
+    sidereal_lon_deg: f64,
+    out: *mut DhruvGandantaStatus,
+    out_found: *mut u8,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if out.is_null() || out_found.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        if !sidereal_lon_deg.is_finite() {
+            return DhruvStatus::InvalidInput;
+        }
+        let Some(status) = dhruv_vedic_base::gandanta_status(sidereal_lon_deg) else {
+            unsafe { *out_found = 0 };
+            return DhruvStatus::Ok;
+        };
+        let kind = match status.kind {
+            dhruv_vedic_base::GandantaKind::MeenaMesha => DHRUV_GANDANTA_MEENA_MESHA,
+            dhruv_vedic_base::GandantaKind::KarkaSimha => DHRUV_GANDANTA_KARKA_SIMHA,
+            dhruv_vedic_base::GandantaKind::VrischikaDhanu => DHRUV_GANDANTA_VRISCHIKA_DHANU,
+        };
+        let half = match status.half {
+            dhruv_vedic_base::GandantaHalf::Approaching => DHRUV_GANDANTA_APPROACHING,
+            dhruv_vedic_base::GandantaHalf::Leaving => DHRUV_GANDANTA_LEAVING,
+        };
+        unsafe {
+            *out = DhruvGandantaStatus {
+                kind,
+                half,
+                offset_deg: status.offset_deg,
+            };
+            *out_found = 1;
+        }
+        DhruvStatus::Ok
+    })
+}
+
 /// Determine rashi from tropical longitude with ayanamsha subtraction.
 ///
 /// # Safety
@@ -4810,6 +5527,29 @@ pub const DHRUV_PANCHANG_TIME_JD_TDB: i32 = 0;
 /// Panchang time input selector: UTC calendar fields.
 pub const DHRUV_PANCHANG_TIME_UTC: i32 = 1;
 
+/// Panchang diff limb code: tithi.
+pub const DHRUV_PANCHANG_LIMB_TITHI: i32 = 0;
+/// Panchang diff limb code: karana.
+pub const DHRUV_PANCHANG_LIMB_KARANA: i32 = 1;
+/// Panchang diff limb code: yoga.
+pub const DHRUV_PANCHANG_LIMB_YOGA: i32 = 2;
+/// Panchang diff limb code: vaar.
+pub const DHRUV_PANCHANG_LIMB_VAAR: i32 = 3;
+/// Panchang diff limb code: hora.
+pub const DHRUV_PANCHANG_LIMB_HORA: i32 = 4;
+/// Panchang diff limb code: ghatika.
+pub const DHRUV_PANCHANG_LIMB_GHATIKA: i32 = 5;
+/// Panchang diff limb code: nakshatra.
+pub const DHRUV_PANCHANG_LIMB_NAKSHATRA: i32 = 6;
+/// Panchang diff limb code: masa.
+pub const DHRUV_PANCHANG_LIMB_MASA: i32 = 7;
+/// Panchang diff limb code: ayana.
+pub const DHRUV_PANCHANG_LIMB_AYANA: i32 = 8;
+/// Panchang diff limb code: varsha.
+pub const DHRUV_PANCHANG_LIMB_VARSHA: i32 = 9;
+/// Maximum number of limbs reported by `dhruv_panchang_diff`.
+pub const DHRUV_PANCHANG_LIMB_COUNT: usize = 10;
+
 /// Panchang include bit for tithi.
 pub const DHRUV_PANCHANG_INCLUDE_TITHI: u32 = 1 << 0;
 /// Panchang include bit for karana.
@@ -4893,6 +5633,15 @@ pub struct DhruvSankrantiConfig {
     pub step_size_days: f64,
     pub max_iterations: u32,
     pub convergence_days: f64,
+    /// Use the custom anchor below instead of `ayanamsha_system` (0=false, 1=true).
+    /// A custom anchor is always evaluated on the ecliptic.
+    pub use_custom_ayanamsha: u8,
+    /// Mean ayanamsha at the custom anchor epoch, in degrees.
+    pub custom_ayanamsha_anchor_deg: f64,
+    /// Custom anchor epoch as a Julian Date in TDB.
+    pub custom_ayanamsha_anchor_jd_tdb: f64,
+    /// Samvatsara naming scheme (0=SouthIndian, 1=NorthIndian, 2=Barhaspatya).
+    pub samvatsara_scheme: i32,
 }
 
 /// C-compatible Sankranti event.
@@ -4906,6 +5655,32 @@ pub struct DhruvSankrantiEvent {
     pub sun_tropical_longitude_deg: f64,
 }
 
+/// C-compatible rashi ingress event (Moon entering a sidereal rashi).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvIngressEvent {
+    pub utc: DhruvUtcTime,
+    /// 0-based rashi index (0=Mesha .. 11=Meena).
+    pub rashi_index: i32,
+    pub jd_tdb: f64,
+}
+
+/// C-compatible nakshatra ingress event (any body entering a sidereal nakshatra).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvNakshatraIngressEvent {
+    pub utc: DhruvUtcTime,
+    pub jd_tdb: f64,
+    /// NAIF code of the body that changed nakshatra.
+    pub body_code: i32,
+    /// 0-based nakshatra index entered (0=Ashwini .. 26=Revati).
+    pub nakshatra_index: i32,
+    /// Sidereal longitude of the boundary crossed in degrees.
+    pub boundary_deg: f64,
+    /// 1 when the body crossed backwards into the previous nakshatra.
+    pub retrograde: u8,
+}
+
 /// C-compatible request for unified sankranti search.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -4994,6 +5769,10 @@ pub struct DhruvPanchangComputeRequest {
 pub struct DhruvPanchangOperationResult {
     pub tithi_valid: u8,
     pub tithi: DhruvTithiInfo,
+    /// `DHRUV_TITHI_DAY_*` code (valid with `tithi_valid`).
+    pub tithi_day_kind: i32,
+    /// Skipped (kshaya) or repeated (vriddhi) tithi index 0-29; -1 when normal.
+    pub tithi_day_tithi_index: i32,
     pub karana_valid: u8,
     pub karana: DhruvKaranaInfo,
     pub yoga_valid: u8,
@@ -5099,9 +5878,20 @@ fn resolve_graha_longitudes_config_ptr(
             _ => dhruv_frames::ReferencePlane::Ecliptic,
         },
     };
+    let custom_ayanamsha = custom_ayanamsha_from_ffi(
+        raw.use_custom_ayanamsha,
+        raw.custom_ayanamsha_anchor_deg,
+        raw.custom_ayanamsha_anchor_jd_tdb,
+    )?;
+    let reference_plane = if custom_ayanamsha.is_some() && kind == GrahaLongitudeKind::Sidereal {
+        dhruv_frames::ReferencePlane::Ecliptic
+    } else {
+        reference_plane
+    };
     Ok(GrahaLongitudesConfig {
         kind,
         ayanamsha_system,
+        custom_ayanamsha,
         use_nutation: raw.use_nutation != 0,
         precession_model,
         reference_plane,
@@ -5109,16 +5899,46 @@ fn resolve_graha_longitudes_config_ptr(
     })
 }
 
+/// Custom ayanamsha anchor from C fields; `None` when the flag is clear.
+fn custom_ayanamsha_from_ffi(
+    use_custom: u8,
+    anchor_deg: f64,
+    anchor_jd_tdb: f64,
+) -> Result<Option<CustomAyanamsha>, DhruvStatus> {
+    if use_custom == 0 {
+        return Ok(None);
+    }
+    if !anchor_deg.is_finite() || !anchor_jd_tdb.is_finite() {
+        return Err(DhruvStatus::InvalidQuery);
+    }
+    Ok(Some(CustomAyanamsha::new(anchor_deg, anchor_jd_tdb)))
+}
+
 fn sankranti_config_from_ffi(cfg: &DhruvSankrantiConfig) -> Option<SankrantiConfig> {
     let system = ayanamsha_system_from_code(cfg.ayanamsha_system)?;
-    Some(SankrantiConfig {
+    let custom_ayanamsha = custom_ayanamsha_from_ffi(
+        cfg.use_custom_ayanamsha,
+        cfg.custom_ayanamsha_anchor_deg,
+        cfg.custom_ayanamsha_anchor_jd_tdb,
+    )
+    .ok()?;
+    let samvatsara_scheme = u8::try_from(cfg.samvatsara_scheme)
+        .ok()
+        .and_then(SamvatsaraScheme::from_u8)?;
+    let config = SankrantiConfig {
         ayanamsha_system: system,
+        custom_ayanamsha: None,
         use_nutation: cfg.use_nutation != 0,
         precession_model: dhruv_frames::DEFAULT_PRECESSION_MODEL,
         reference_plane: reference_plane_from_code(cfg.reference_plane, system),
         step_size_days: cfg.step_size_days,
         max_iterations: cfg.max_iterations,
         convergence_days: cfg.convergence_days,
+        samvatsara_scheme,
+    };
+    Some(match custom_ayanamsha {
+        Some(custom) => config.with_custom_ayanamsha(custom),
+        None => config,
     })
 }
 
@@ -5170,6 +5990,10 @@ pub extern "C" fn dhruv_sankranti_config_default() -> DhruvSankrantiConfig {
         step_size_days: 1.0,
         max_iterations: 50,
         convergence_days: 1e-8,
+        use_custom_ayanamsha: 0,
+        custom_ayanamsha_anchor_deg: 0.0,
+        custom_ayanamsha_anchor_jd_tdb: 0.0,
+        samvatsara_scheme: 0, // SouthIndian
     }
 }
 
@@ -5565,6 +6389,114 @@ pub unsafe extern "C" fn dhruv_sankranti_search_ex(
     })
 }
 
+/// List every Moon entry into a sidereal rashi between two UTC times.
+///
+/// Writes events to `out_events[..max_count]` and the written count to
+/// `out_count`; callers that fill the buffer should retry with more room.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null; `out_events` must hold
+/// `max_count` elements.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_moon_rashi_ingresses(
+    engine: *const DhruvEngineHandle,
+    start_utc: *const DhruvUtcTime,
+    end_utc: *const DhruvUtcTime,
+    config: *const DhruvSankrantiConfig,
+    out_events: *mut DhruvIngressEvent,
+    max_count: u32,
+    out_count: *mut u32,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if engine.is_null()
+            || start_utc.is_null()
+            || end_utc.is_null()
+            || config.is_null()
+            || out_events.is_null()
+            || out_count.is_null()
+        {
+            return DhruvStatus::NullPointer;
+        }
+        let engine_ref = unsafe { &*engine };
+        let start = ffi_to_utc_time(unsafe { &*start_utc });
+        let end = ffi_to_utc_time(unsafe { &*end_utc });
+        let cfg = match sankranti_config_from_ffi(unsafe { &*config }) {
+            Some(c) => c,
+            None => return DhruvStatus::InvalidQuery,
+        };
+        match moon_rashi_ingresses(engine_ref, &start, &end, &cfg) {
+            Ok(events) => {
+                let count = events.len().min(max_count as usize);
+                let out_slice =
+                    unsafe { std::slice::from_raw_parts_mut(out_events, max_count as usize) };
+                for (slot, event) in out_slice.iter_mut().zip(events.iter().take(count)) {
+                    *slot = DhruvIngressEvent {
+                        utc: utc_time_to_ffi(&event.utc),
+                        rashi_index: event.rashi_index as i32,
+                        jd_tdb: event.jd_tdb,
+                    };
+                }
+                unsafe { *out_count = count as u32 };
+                DhruvStatus::Ok
+            }
+            Err(e) => DhruvStatus::from(&e),
+        }
+    })
+}
+
+/// Find the next time a body enters a new sidereal nakshatra after `jd_tdb`.
+///
+/// Retrograde re-entries into the previous nakshatra count and set
+/// `retrograde`. Writes `*out_found = 0` when no boundary is crossed within
+/// the body's scan window.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_next_nakshatra_ingress(
+    engine: *const DhruvEngineHandle,
+    body_code: i32,
+    jd_tdb: f64,
+    config: *const DhruvSankrantiConfig,
+    out_event: *mut DhruvNakshatraIngressEvent,
+    out_found: *mut u8,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if engine.is_null() || config.is_null() || out_event.is_null() || out_found.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        let Some(body) = Body::from_code(body_code) else {
+            return DhruvStatus::InvalidQuery;
+        };
+        let cfg = match sankranti_config_from_ffi(unsafe { &*config }) {
+            Some(c) => c,
+            None => return DhruvStatus::InvalidQuery,
+        };
+        let engine_ref = unsafe { &*engine };
+        match next_nakshatra_ingress(engine_ref, body, jd_tdb, &cfg) {
+            Ok(Some(event)) => {
+                unsafe {
+                    *out_event = DhruvNakshatraIngressEvent {
+                        utc: utc_time_to_ffi(&event.utc),
+                        jd_tdb: event.jd_tdb,
+                        body_code: event.body.code(),
+                        nakshatra_index: event.nakshatra_index as i32,
+                        boundary_deg: event.boundary_deg,
+                        retrograde: u8::from(event.retrograde),
+                    };
+                    *out_found = 1;
+                }
+                DhruvStatus::Ok
+            }
+            Ok(None) => {
+                unsafe { *out_found = 0 };
+                DhruvStatus::Ok
+            }
+            Err(e) => DhruvStatus::from(&e),
+        }
+    })
+}
+
 /// Determine the Masa (lunar month) for a given UTC date.
 ///
 /// # Safety
@@ -5949,6 +6881,7 @@ pub struct DhruvSamvatsaraResult {
 
 /// Determine the Samvatsara (Jovian year) from a CE year.
 ///
+/// `scheme`: 0=SouthIndian, 1=NorthIndian, 2=Barhaspatya.
 /// Pure math — no engine or kernel needed.
 ///
 /// # Safety
@@ -5956,12 +6889,19 @@ pub struct DhruvSamvatsaraResult {
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dhruv_samvatsara_from_year(
     ce_year: i32,
+    scheme: i32,
     out: *mut DhruvSamvatsaraResult,
 ) -> DhruvStatus {
     if out.is_null() {
         return DhruvStatus::NullPointer;
     }
-    let (samvatsara, position) = samvatsara_from_year(ce_year);
+    let Some(scheme) = u8::try_from(scheme)
+        .ok()
+        .and_then(SamvatsaraScheme::from_u8)
+    else {
+        return DhruvStatus::InvalidQuery;
+    };
+    let (samvatsara, position) = samvatsara_from_year(ce_year, scheme);
     unsafe {
         *out = DhruvSamvatsaraResult {
             samvatsara_index: samvatsara.index() as i32,
@@ -6857,6 +7797,16 @@ pub struct DhruvYogaInfo {
     pub end: DhruvUtcTime,
 }
 
+/// C-compatible tithi day classification.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvTithiDayInfo {
+    /// `DHRUV_TITHI_DAY_*` code for the Vedic day.
+    pub kind: i32,
+    /// Skipped (kshaya) or repeated (vriddhi) tithi index 0-29; -1 when normal.
+    pub tithi_index: i32,
+}
+
 /// C-compatible Vaar info.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -6923,6 +7873,51 @@ pub unsafe extern "C" fn dhruv_tithi_for_date(
     })
 }
 
+/// Classify the Vedic day containing a UTC date as a normal, kshaya, or
+/// vriddhi tithi day.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_tithi_day_kind_for_date(
+    engine: *const DhruvEngineHandle,
+    eop: *const DhruvEopHandle,
+    utc: *const DhruvUtcTime,
+    location: *const DhruvGeoLocation,
+    riseset_config: *const DhruvRiseSetConfig,
+    out: *mut DhruvTithiDayInfo,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if engine.is_null() || eop.is_null() || utc.is_null() || location.is_null() || out.is_null()
+        {
+            return DhruvStatus::NullPointer;
+        }
+        let engine_ref = unsafe { &*engine };
+        let eop_ref = unsafe { &*eop };
+        let t = ffi_to_utc_time(unsafe { &*utc });
+        let loc_ref = unsafe { &*location };
+        let geo = GeoLocation::new(
+            loc_ref.latitude_deg,
+            loc_ref.longitude_deg,
+            loc_ref.altitude_m,
+        );
+        let rs_config = match resolve_riseset_config_ptr(riseset_config) {
+            Ok(c) => c,
+            Err(status) => return status,
+        };
+        match tithi_day_kind_for_date(engine_ref, eop_ref, &t, &geo, &rs_config) {
+            Ok(kind) => {
+                let (kind, tithi_index) = tithi_day_kind_to_ffi(kind);
+                unsafe {
+                    *out = DhruvTithiDayInfo { kind, tithi_index };
+                }
+                DhruvStatus::Ok
+            }
+            Err(e) => DhruvStatus::from(&e),
+        }
+    })
+}
+
 /// Determine the Karana for a given UTC date.
 ///
 /// # Safety
@@ -7197,6 +8192,10 @@ pub struct DhruvPanchangNakshatraInfo {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DhruvPanchangInfo {
     pub tithi: DhruvTithiInfo,
+    /// `DHRUV_TITHI_DAY_*` code for the Vedic day.
+    pub tithi_day_kind: i32,
+    /// Skipped (kshaya) or repeated (vriddhi) tithi index 0-29; -1 when normal.
+    pub tithi_day_tithi_index: i32,
     pub karana: DhruvKaranaInfo,
     pub yoga: DhruvYogaInfo,
     pub vaar: DhruvVaarInfo,
@@ -7210,6 +8209,128 @@ pub struct DhruvPanchangInfo {
     pub varsha: DhruvVarshaInfo,
 }
 
+/// C-compatible panchang element that differs between two configurations.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvPanchangLimbDiff {
+    /// `DHRUV_PANCHANG_LIMB_*` code.
+    pub limb: i32,
+    /// 1 if the element value differs, 0 if only its boundaries shift.
+    pub value_differs: u8,
+    /// Start-boundary shift in seconds (config B minus config A).
+    pub start_shift_s: f64,
+    /// End-boundary shift in seconds (config B minus config A).
+    pub end_shift_s: f64,
+}
+
+/// C-compatible side-by-side panchang under two ayanamsha configurations.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvPanchangDiff {
+    /// Panchang under config A.
+    pub a: DhruvPanchangInfo,
+    /// Panchang under config B.
+    pub b: DhruvPanchangInfo,
+    /// Number of valid entries in `limbs` (0 when the configs agree).
+    pub limb_count: u32,
+    pub limbs: [DhruvPanchangLimbDiff; DHRUV_PANCHANG_LIMB_COUNT],
+}
+
+fn panchang_limb_to_code(limb: PanchangLimb) -> i32 {
+    match limb {
+        PanchangLimb::Tithi => DHRUV_PANCHANG_LIMB_TITHI,
+        PanchangLimb::Karana => DHRUV_PANCHANG_LIMB_KARANA,
+        PanchangLimb::Yoga => DHRUV_PANCHANG_LIMB_YOGA,
+        PanchangLimb::Vaar => DHRUV_PANCHANG_LIMB_VAAR,
+        PanchangLimb::Hora => DHRUV_PANCHANG_LIMB_HORA,
+        PanchangLimb::Ghatika => DHRUV_PANCHANG_LIMB_GHATIKA,
+        PanchangLimb::Nakshatra => DHRUV_PANCHANG_LIMB_NAKSHATRA,
+        PanchangLimb::Masa => DHRUV_PANCHANG_LIMB_MASA,
+        PanchangLimb::Ayana => DHRUV_PANCHANG_LIMB_AYANA,
+        PanchangLimb::Varsha => DHRUV_PANCHANG_LIMB_VARSHA,
+    }
+}
+
+/// Compare the panchang for one date and location under two sankranti
+/// (ayanamsha) configurations.
+///
+/// `out->limbs[..limb_count]` lists the elements whose value differs or whose
+/// boundaries shift by more than `PANCHANG_DIFF_TOLERANCE_S` seconds.
+/// Null `riseset_config`, `config_a` or `config_b` use the resolved defaults.
+///
+/// # Safety
+/// `engine`, `eop`, `utc`, `location` and `out` must be valid non-null pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_panchang_diff(
+    engine: *const DhruvEngineHandle,
+    eop: *const DhruvEopHandle,
+    utc: *const DhruvUtcTime,
+    location: *const DhruvGeoLocation,
+    riseset_config: *const DhruvRiseSetConfig,
+    config_a: *const DhruvSankrantiConfig,
+    config_b: *const DhruvSankrantiConfig,
+    out: *mut DhruvPanchangDiff,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if engine.is_null() || eop.is_null() || utc.is_null() || location.is_null() || out.is_null()
+        {
+            return DhruvStatus::NullPointer;
+        }
+        // SAFETY: all required pointers were null-checked above.
+        let engine_ref = unsafe { &*engine };
+        let eop_ref = unsafe { &*eop };
+        let t = ffi_to_utc_time(unsafe { &*utc });
+        let loc_ref = unsafe { &*location };
+        let geo = GeoLocation::new(
+            loc_ref.latitude_deg,
+            loc_ref.longitude_deg,
+            loc_ref.altitude_m,
+        );
+        let rs_config = match resolve_riseset_config_ptr(riseset_config) {
+            Ok(c) => c,
+            Err(status) => return status,
+        };
+        let cfg_a = match resolve_sankranti_config_ptr(config_a) {
+            Ok(c) => c,
+            Err(status) => return status,
+        };
+        let cfg_b = match resolve_sankranti_config_ptr(config_b) {
+            Ok(c) => c,
+            Err(status) => return status,
+        };
+        match panchang_diff(engine_ref, eop_ref, &t, &geo, &rs_config, &cfg_a, &cfg_b) {
+            Ok(diff) => {
+                let empty = DhruvPanchangLimbDiff {
+                    limb: 0,
+                    value_differs: 0,
+                    start_shift_s: 0.0,
+                    end_shift_s: 0.0,
+                };
+                let mut limbs = [empty; DHRUV_PANCHANG_LIMB_COUNT];
+                for (slot, d) in limbs.iter_mut().zip(&diff.limbs) {
+                    *slot = DhruvPanchangLimbDiff {
+                        limb: panchang_limb_to_code(d.limb),
+                        value_differs: u8::from(d.value_differs),
+                        start_shift_s: d.start_shift_s,
+                        end_shift_s: d.end_shift_s,
+                    };
+                }
+                // SAFETY: `out` was null-checked above.
+                unsafe {
+                    *out = DhruvPanchangDiff {
+                        a: panchang_info_to_ffi(&diff.a),
+                        b: panchang_info_to_ffi(&diff.b),
+                        limb_count: diff.limbs.len().min(DHRUV_PANCHANG_LIMB_COUNT) as u32,
+                        limbs,
+                    };
+                }
+                DhruvStatus::Ok
+            }
+            Err(e) => DhruvStatus::from(&e),
+        }
+    })
+}
+
 /// Unified panchang compute entrypoint with include-mask control.
 ///
 /// `time_kind` controls whether input comes from `jd_tdb` or `utc`.
@@ -7251,6 +8372,7 @@ pub unsafe extern "C" fn dhruv_panchang_compute_ex(
             use_refraction: req.riseset_config.use_refraction != 0,
             sun_limb,
             altitude_correction: req.riseset_config.altitude_correction != 0,
+            ..RiseSetConfig::default()
         };
 
         let at_utc = match req.time_kind {
@@ -7481,6 +8603,15 @@ fn varsha_info_to_ffi(info: &dhruv_search::VarshaInfo) -> DhruvVarshaInfo {
     }
 }
 
+/// Split a `TithiDayKind` into its `DHRUV_TITHI_DAY_*` code and tithi index.
+fn tithi_day_kind_to_ffi(kind: TithiDayKind) -> (i32, i32) {
+    match kind {
+        TithiDayKind::Normal => (DHRUV_TITHI_DAY_NORMAL, -1),
+        TithiDayKind::Kshaya(t) => (DHRUV_TITHI_DAY_KSHAYA, t.index() as i32),
+        TithiDayKind::Vriddhi(t) => (DHRUV_TITHI_DAY_VRIDDHI, t.index() as i32),
+    }
+}
+
 /// Convert a Rust `PanchangInfo` to a C-compatible `DhruvPanchangInfo`.
 fn panchang_info_to_ffi(info: &dhruv_search::PanchangInfo) -> DhruvPanchangInfo {
     let (calendar_valid, masa_ffi, ayana_ffi, varsha_ffi) =
@@ -7498,8 +8629,11 @@ fn panchang_info_to_ffi(info: &dhruv_search::PanchangInfo) -> DhruvPanchangInfo
                 zeroed_varsha_info(),
             ),
         };
+    let (tithi_day_kind, tithi_day_tithi_index) = tithi_day_kind_to_ffi(info.tithi_day_kind);
     DhruvPanchangInfo {
         tithi: tithi_info_to_ffi(&info.tithi),
+        tithi_day_kind,
+        tithi_day_tithi_index,
         karana: karana_info_to_ffi(&info.karana),
         yoga: yoga_info_to_ffi(&info.yoga),
         vaar: vaar_info_to_ffi(&info.vaar),
@@ -7607,6 +8741,9 @@ fn panchang_result_to_ffi(info: &PanchangResult) -> DhruvPanchangOperationResult
         Some(v) => (1, tithi_info_to_ffi_ops(&v)),
         None => (0, zeroed_tithi_info()),
     };
+    let (tithi_day_kind, tithi_day_tithi_index) = info
+        .tithi_day_kind
+        .map_or((DHRUV_TITHI_DAY_NORMAL, -1), tithi_day_kind_to_ffi);
     let (karana_valid, karana) = match info.karana {
         Some(v) => (1, karana_info_to_ffi_ops(&v)),
         None => (0, zeroed_karana_info()),
@@ -7647,6 +8784,8 @@ fn panchang_result_to_ffi(info: &PanchangResult) -> DhruvPanchangOperationResult
     DhruvPanchangOperationResult {
         tithi_valid,
         tithi,
+        tithi_day_kind,
+        tithi_day_tithi_index,
         karana_valid,
         karana,
         yoga_valid,
@@ -8224,6 +9363,58 @@ pub extern "C" fn dhruv_rashi_lord(rashi_index: u32) -> i32 {
     }
 }
 
+/// Return the graha index (0-8) of the Vimshottari dasha lord of the nakshatra
+/// at `nakshatra_index` (0-26, Ashwini = 0). Returns -1 for invalid input.
+#[unsafe(no_mangle)]
+pub extern "C" fn dhruv_nakshatra_lord(nakshatra_index: u32) -> i32 {
+    match u8::try_from(nakshatra_index)
+        .ok()
+        .and_then(dhruv_vedic_base::dasha::nakshatra_dasha_lord)
+    {
+        Some(g) => g.index() as i32,
+        None => -1,
+    }
+}
+
+/// Return the navatara index (0-8, Janma = 0) of `target_nakshatra` counted from
+/// `start_nakshatra` (both 0-26, Ashwini = 0). Returns -1 for invalid input.
+#[unsafe(no_mangle)]
+pub extern "C" fn dhruv_navatara_from(start_nakshatra: u32, target_nakshatra: u32) -> i32 {
+    let nakshatra = |index: u32| {
+        dhruv_vedic_base::ALL_NAKSHATRAS_27
+            .get(index as usize)
+            .copied()
+    };
+    match (nakshatra(start_nakshatra), nakshatra(target_nakshatra)) {
+        (Some(start), Some(target)) => {
+            dhruv_vedic_base::navatara_from(start, target).index() as i32
+        }
+        _ => -1,
+    }
+}
+
+/// Return the name of a navatara by index (0-8). Returns null for invalid index.
+///
+/// The returned pointer is a NUL-terminated static string and must not be freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn dhruv_navatara_name(index: u32) -> *const std::ffi::c_char {
+    static NAMES: [&str; 9] = [
+        "Janma\0",
+        "Sampat\0",
+        "Vipat\0",
+        "Kshema\0",
+        "Pratyak\0",
+        "Sadhana\0",
+        "Naidhana\0",
+        "Mitra\0",
+        "Parama Mitra\0",
+    ];
+    if index as usize >= NAMES.len() {
+        return ptr::null();
+    }
+    NAMES[index as usize].as_ptr() as *const std::ffi::c_char
+}
+
 /// Return the graha index (0-8) of the hora lord for the given weekday and hora slot.
 /// Returns -1 for invalid input.
 #[unsafe(no_mangle)]
@@ -8304,15 +9495,15 @@ pub unsafe extern "C" fn dhruv_all_sphutas(
     }
     let inp = unsafe { &*inputs };
     let vedic_inputs = dhruv_vedic_base::SphutalInputs {
-        sun: inp.sun,
-        moon: inp.moon,
-        mars: inp.mars,
-        jupiter: inp.jupiter,
-        venus: inp.venus,
-        rahu: inp.rahu,
-        lagna: inp.lagna,
-        eighth_lord: inp.eighth_lord,
-        gulika: inp.gulika,
+        sun: inp.sun.into(),
+        moon: inp.moon.into(),
+        mars: inp.mars.into(),
+        jupiter: inp.jupiter.into(),
+        venus: inp.venus.into(),
+        rahu: inp.rahu.into(),
+        lagna: inp.lagna.into(),
+        eighth_lord: inp.eighth_lord.into(),
+        gulika: inp.gulika.into(),
     };
     let results = dhruv_vedic_base::all_sphutas(&vedic_inputs);
     let mut lons = [0.0f64; 16];
@@ -9365,6 +10556,120 @@ pub unsafe extern "C" fn dhruv_ekadhipatya_sodhana(
     DhruvStatus::Ok
 }
 
+/// Transit house mode: rashi offset from the natal lagna rashi.
+pub const DHRUV_TRANSIT_HOUSE_WHOLE_SIGN: i32 = 0;
+/// Transit house mode: natal cusps (bhava chalit).
+pub const DHRUV_TRANSIT_HOUSE_CHALIT: i32 = 1;
+
+/// C-compatible natal ashtakavarga bindus under a transiting graha.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DhruvTransitBindus {
+    /// Transiting graha index (0=Surya .. 8=Ketu).
+    pub graha_index: u8,
+    /// Sidereal transit longitude in degrees, as supplied.
+    pub transit_longitude_deg: f64,
+    /// Natal house (1-12) the graha is scored in.
+    pub house: u8,
+    /// 0-based rashi whose bindus were read.
+    pub rashi_index: u8,
+    /// 1 when `bav_bindus` is set; 0 for Rahu/Ketu, which have no BAV.
+    pub bav_valid: u8,
+    /// The graha's own BAV bindus in that rashi.
+    pub bav_bindus: u8,
+    /// SAV total bindus in that rashi.
+    pub sav_bindus: u8,
+}
+
+fn ashtakavarga_result_from_ffi(
+    result: &DhruvAshtakavargaResult,
+) -> dhruv_vedic_base::AshtakavargaResult {
+    dhruv_vedic_base::AshtakavargaResult {
+        bavs: result.bavs.map(|bav| dhruv_vedic_base::BhinnaAshtakavarga {
+            graha_index: bav.graha_index,
+            points: bav.points,
+            contributors: bav.contributors,
+        }),
+        sav: dhruv_vedic_base::SarvaAshtakavarga {
+            total_points: result.sav.total_points,
+            after_trikona: result.sav.after_trikona,
+            after_ekadhipatya: result.sav.after_ekadhipatya,
+        },
+    }
+}
+
+fn bhava_result_from_ffi(result: &DhruvBhavaResult) -> dhruv_vedic_base::BhavaResult {
+    dhruv_vedic_base::BhavaResult {
+        bhavas: result.bhavas.map(|b| dhruv_vedic_base::Bhava {
+            number: b.number,
+            cusp_deg: b.cusp_deg,
+            start_deg: b.start_deg,
+            end_deg: b.end_deg,
+            madhya_deg: b.madhya_deg,
+        }),
+        lagna_deg: result.lagna_deg,
+        mc_deg: result.mc_deg,
+    }
+}
+
+/// Read the natal BAV/SAV bindus under a transiting graha (pure math).
+///
+/// `natal_bhavas` must hold sidereal cusps; `house_mode` is a
+/// `DHRUV_TRANSIT_HOUSE_*` code. In chalit mode the house comes from the
+/// natal cusps, so a graha past a bhava sandhi is scored in the next house.
+///
+/// # Safety
+/// `natal`, `natal_bhavas` and `out` must be valid, non-null pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_transit_bindus(
+    natal: *const DhruvAshtakavargaResult,
+    natal_bhavas: *const DhruvBhavaResult,
+    graha_index: u32,
+    transit_lon_sid: f64,
+    house_mode: i32,
+    out: *mut DhruvTransitBindus,
+) -> DhruvStatus {
+    ffi_boundary(|| {
+        if natal.is_null() || natal_bhavas.is_null() || out.is_null() {
+            return DhruvStatus::NullPointer;
+        }
+        let Some(graha) = graha_from_index(graha_index) else {
+            return DhruvStatus::InvalidQuery;
+        };
+        let house_mode = match house_mode {
+            DHRUV_TRANSIT_HOUSE_WHOLE_SIGN => TransitHouseMode::WholeSign,
+            DHRUV_TRANSIT_HOUSE_CHALIT => TransitHouseMode::Chalit,
+            _ => return DhruvStatus::InvalidQuery,
+        };
+        if !transit_lon_sid.is_finite() {
+            return DhruvStatus::InvalidInput;
+        }
+        // SAFETY: both input pointers were null-checked above.
+        let natal = ashtakavarga_result_from_ffi(unsafe { &*natal });
+        let natal_bhavas = bhava_result_from_ffi(unsafe { &*natal_bhavas });
+        let result = transit_bindus(
+            &natal,
+            &natal_bhavas,
+            graha,
+            transit_lon_sid,
+            &TransitAshtakavargaConfig { house_mode },
+        );
+        // SAFETY: `out` was null-checked above.
+        unsafe {
+            *out = DhruvTransitBindus {
+                graha_index: result.graha.index(),
+                transit_longitude_deg: result.transit_longitude_deg,
+                house: result.house,
+                rashi_index: result.rashi_index,
+                bav_valid: u8::from(result.bav_bindus.is_some()),
+                bav_bindus: result.bav_bindus.unwrap_or(0),
+                sav_bindus: result.sav_bindus,
+            };
+        }
+        DhruvStatus::Ok
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Pure-math: graha drishti, ghatika, hora, ghatikas_since_sunrise
 // ---------------------------------------------------------------------------
@@ -9377,6 +10682,14 @@ pub struct DhruvGrahaDrishtiMatrix {
     pub entries: [[DhruvDrishtiEntry; 9]; 9],
 }
 
+/// C-compatible 12×12 Jaimini rashi drishti table.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvRashiDrishtiMatrix {
+    /// aspects\[from\]\[to\] by rashi index: 1 if `from` aspects `to`, else 0.
+    pub aspects: [[u8; 12]; 12],
+}
+
 /// Compute drishti from a single graha to a single sidereal point (pure math).
 ///
 /// `graha_index`: 0=Surya .. 8=Ketu.
@@ -9440,6 +10753,52 @@ pub unsafe extern "C" fn dhruv_graha_drishti_matrix(
     DhruvStatus::Ok
 }
 
+/// Whether rashi `from_rashi` aspects rashi `to_rashi` under Jaimini rashi
+/// drishti (pure math).
+///
+/// Both indices are 0-based (0 = Mesha .. 11 = Meena). Returns
+/// `InvalidQuery` for an index above 11.
+///
+/// # Safety
+/// `out_aspects` must be a valid, non-null pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_rashi_drishti(
+    from_rashi: u32,
+    to_rashi: u32,
+    out_aspects: *mut u8,
+) -> DhruvStatus {
+    if out_aspects.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+    let rashi = |index: u32| dhruv_vedic_base::ALL_RASHIS.get(index as usize).copied();
+    let (Some(from), Some(to)) = (rashi(from_rashi), rashi(to_rashi)) else {
+        return DhruvStatus::InvalidQuery;
+    };
+    unsafe { *out_aspects = u8::from(dhruv_vedic_base::rashi_drishti(from, to)) };
+    DhruvStatus::Ok
+}
+
+/// Fill the full 12×12 Jaimini rashi drishti table (pure math).
+///
+/// # Safety
+/// `out` must point to a valid `DhruvRashiDrishtiMatrix`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_rashi_drishti_matrix(
+    out: *mut DhruvRashiDrishtiMatrix,
+) -> DhruvStatus {
+    if out.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+    let matrix = dhruv_vedic_base::rashi_drishti_matrix();
+    let out = unsafe { &mut *out };
+    for (row, cells) in out.aspects.iter_mut().zip(matrix.iter()) {
+        for (cell, &aspects) in row.iter_mut().zip(cells.iter()) {
+            *cell = u8::from(aspects);
+        }
+    }
+    DhruvStatus::Ok
+}
+
 /// Determine the ghatika from elapsed seconds since sunrise (pure math).
 ///
 /// `seconds_since_sunrise`: seconds elapsed since the Vedic day's sunrise.
@@ -9489,6 +10848,55 @@ pub unsafe extern "C" fn dhruv_ghatikas_since_sunrise(
     DhruvStatus::Ok
 }
 
+/// Convert a ghati-vighati reading since sunrise to clock hours (pure math).
+///
+/// Uses the fixed 24-minute ghatika.
+///
+/// # Safety
+/// `out_hours` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_ghatika_to_duration(
+    ghati: f64,
+    vighati: f64,
+    out_hours: *mut f64,
+) -> DhruvStatus {
+    if out_hours.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+    if !ghati.is_finite() || !vighati.is_finite() {
+        return DhruvStatus::InvalidInput;
+    }
+    unsafe { *out_hours = dhruv_vedic_base::ghatika_to_duration(ghati, vighati) };
+    DhruvStatus::Ok
+}
+
+/// Convert clock hours since sunrise to whole ghatis and remaining vighatis
+/// (pure math).
+///
+/// Negative durations clamp to zero; durations past the day are not wrapped.
+///
+/// # Safety
+/// Both output pointers must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_duration_to_ghatika(
+    hours: f64,
+    out_ghati: *mut u32,
+    out_vighati: *mut f64,
+) -> DhruvStatus {
+    if out_ghati.is_null() || out_vighati.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+    if !hours.is_finite() {
+        return DhruvStatus::InvalidInput;
+    }
+    let (ghati, vighati) = dhruv_vedic_base::duration_to_ghatika(hours);
+    unsafe {
+        *out_ghati = ghati;
+        *out_vighati = vighati;
+    }
+    DhruvStatus::Ok
+}
+
 /// Determine the hora lord for a given weekday and hora position (pure math).
 ///
 /// `vaar_index`: 0=Sunday .. 6=Saturday.
@@ -9602,14 +11010,47 @@ pub unsafe extern "C" fn dhruv_moolatrikone_range(
     DhruvStatus::Ok
 }
 
+/// C-compatible combustion options.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvCombustionConfig {
+    /// Moon's combustion orb in degrees; 0 exempts the Moon.
+    pub moon_orb_deg: f64,
+}
+
+/// Default combustion options (Moon orb 12 degrees).
+#[unsafe(no_mangle)]
+pub extern "C" fn dhruv_combustion_config_default() -> DhruvCombustionConfig {
+    DhruvCombustionConfig {
+        moon_orb_deg: dhruv_vedic_base::MOON_COMBUSTION_DEG,
+    }
+}
+
+fn resolve_combustion_config_ptr(
+    config: *const DhruvCombustionConfig,
+) -> Result<dhruv_vedic_base::CombustionConfig, DhruvStatus> {
+    let raw = if config.is_null() {
+        dhruv_combustion_config_default()
+    } else {
+        unsafe { *config }
+    };
+    let config = dhruv_vedic_base::CombustionConfig {
+        moon_orb_deg: raw.moon_orb_deg,
+    };
+    config.validate().map_err(|_| DhruvStatus::InvalidQuery)?;
+    Ok(config)
+}
+
 /// Return the combustion threshold for a graha when defined.
 ///
 /// # Safety
-/// All output pointers must be valid and non-null.
+/// All output pointers must be valid and non-null. `config` may be null
+/// for the defaults.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dhruv_combustion_threshold(
     graha_index: u32,
     is_retrograde: u8,
+    config: *const DhruvCombustionConfig,
     out_has_value: *mut u8,
     out_threshold_deg: *mut f64,
 ) -> DhruvStatus {
@@ -9619,7 +11060,11 @@ pub unsafe extern "C" fn dhruv_combustion_threshold(
     let Some(graha) = graha_from_index(graha_index) else {
         return DhruvStatus::InvalidQuery;
     };
-    match dhruv_vedic_base::combustion_threshold(graha, is_retrograde != 0) {
+    let config = match resolve_combustion_config_ptr(config) {
+        Ok(config) => config,
+        Err(status) => return status,
+    };
+    match dhruv_vedic_base::combustion_threshold(graha, is_retrograde != 0, &config) {
         Some(value) => unsafe {
             *out_has_value = 1;
             *out_threshold_deg = value;
@@ -9635,13 +11080,15 @@ pub unsafe extern "C" fn dhruv_combustion_threshold(
 /// Determine whether a graha is combust.
 ///
 /// # Safety
-/// `out_is_combust` must be valid and non-null.
+/// `out_is_combust` must be valid and non-null. `config` may be null for
+/// the defaults.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dhruv_is_combust(
     graha_index: u32,
     graha_sid_lon: f64,
     sun_sid_lon: f64,
     is_retrograde: u8,
+    config: *const DhruvCombustionConfig,
     out_is_combust: *mut u8,
 ) -> DhruvStatus {
     if out_is_combust.is_null() {
@@ -9650,12 +11097,17 @@ pub unsafe extern "C" fn dhruv_is_combust(
     let Some(graha) = graha_from_index(graha_index) else {
         return DhruvStatus::InvalidQuery;
     };
+    let config = match resolve_combustion_config_ptr(config) {
+        Ok(config) => config,
+        Err(status) => return status,
+    };
     unsafe {
         *out_is_combust = u8::from(dhruv_vedic_base::is_combust(
             graha,
             graha_sid_lon,
             sun_sid_lon,
             is_retrograde != 0,
+            &config,
         ));
     }
     DhruvStatus::Ok
@@ -9664,16 +11116,22 @@ pub unsafe extern "C" fn dhruv_is_combust(
 /// Determine combustion status for all nine grahas.
 ///
 /// # Safety
-/// All pointers must be valid and point to 9 contiguous items.
+/// All array pointers must be valid and point to 9 contiguous items.
+/// `config` may be null for the defaults.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dhruv_all_combustion_status(
     sidereal_lons_9: *const f64,
     retrograde_flags_9: *const u8,
+    config: *const DhruvCombustionConfig,
     out_combust_flags_9: *mut u8,
 ) -> DhruvStatus {
     if sidereal_lons_9.is_null() || retrograde_flags_9.is_null() || out_combust_flags_9.is_null() {
         return DhruvStatus::NullPointer;
     }
+    let config = match resolve_combustion_config_ptr(config) {
+        Ok(config) => config,
+        Err(status) => return status,
+    };
     let sidereal_slice = unsafe { std::slice::from_raw_parts(sidereal_lons_9, 9) };
     let retrograde_slice = unsafe { std::slice::from_raw_parts(retrograde_flags_9, 9) };
     let mut sidereal = [0.0_f64; 9];
@@ -9682,7 +11140,7 @@ pub unsafe extern "C" fn dhruv_all_combustion_status(
     for (dst, src) in retrograde.iter_mut().zip(retrograde_slice.iter()) {
         *dst = *src != 0;
     }
-    let result = dhruv_vedic_base::all_combustion_status(&sidereal, &retrograde);
+    let result = dhruv_vedic_base::all_combustion_status(&sidereal, &retrograde, &config);
     let out = unsafe { std::slice::from_raw_parts_mut(out_combust_flags_9, 9) };
     for (dst, src) in out.iter_mut().zip(result.iter()) {
         *dst = u8::from(*src);
@@ -9765,6 +11223,35 @@ pub unsafe extern "C" fn dhruv_panchadha_maitri(
     DhruvStatus::Ok
 }
 
+/// Five-fold relationship of every graha toward every other.
+///
+/// `out_codes_81` is row-major: entry `[g * 9 + o]` is the `DHRUV_PANCHADHA_*`
+/// code for how graha `g` regards graha `o`. The diagonal is -1.
+///
+/// # Safety
+/// `sidereal_lons_9` must point to 9 contiguous `f64` and `out_codes_81` to
+/// 81 contiguous `i32`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_relationship_matrix(
+    sidereal_lons_9: *const f64,
+    out_codes_81: *mut i32,
+) -> DhruvStatus {
+    if sidereal_lons_9.is_null() || out_codes_81.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+    let mut sidereal = [0.0_f64; 9];
+    sidereal.copy_from_slice(unsafe { std::slice::from_raw_parts(sidereal_lons_9, 9) });
+    if sidereal.iter().any(|lon| !lon.is_finite()) {
+        return DhruvStatus::InvalidInput;
+    }
+    let matrix = dhruv_vedic_base::relationship_matrix(&sidereal);
+    let out = unsafe { std::slice::from_raw_parts_mut(out_codes_81, 81) };
+    for (dst, src) in out.iter_mut().zip(matrix.iter().flatten()) {
+        *dst = src.map_or(-1, panchadha_to_code);
+    }
+    DhruvStatus::Ok
+}
+
 /// Determine dignity in a rashi without temporal context.
 ///
 /// # Safety
@@ -9999,6 +11486,8 @@ pub struct DhruvGrahaPositionsConfig {
     pub include_lagna: u8,
     pub include_outer_planets: u8,
     pub include_bhava: u8,
+    /// Compute gandanta / rashi-sandhi / pushkara flags (0/1).
+    pub include_flags: u8,
 }
 
 /// C-compatible single graha entry.
@@ -10016,6 +11505,12 @@ pub struct DhruvGrahaEntry {
     pub bhava_number: u8,
     /// Rashi-bhava number (1-12), 0 if not computed.
     pub rashi_bhava_number: u8,
+    /// 1 if within a pada of a water/fire rashi junction, 0 otherwise or if not computed.
+    pub gandanta: u8,
+    /// 1 if within a degree of a rashi boundary, 0 otherwise or if not computed.
+    pub rashi_sandhi: u8,
+    /// 1 if in a pushkara navamsa or bhaga, 0 otherwise or if not computed.
+    pub pushkara: u8,
 }
 
 /// C-compatible graha positions result.
@@ -10038,6 +11533,9 @@ fn graha_entry_to_ffi(entry: &dhruv_search::GrahaEntry) -> DhruvGrahaEntry {
         pada: entry.pada,
         bhava_number: entry.bhava_number,
         rashi_bhava_number: entry.rashi_bhava_number,
+        gandanta: u8::from(entry.gandanta),
+        rashi_sandhi: u8::from(entry.rashi_sandhi),
+        pushkara: u8::from(entry.pushkara),
     }
 }
 
@@ -10366,6 +11864,8 @@ fn shadbala_result_to_ffi(result: &dhruv_search::ShadbalaResult) -> DhruvShadbal
             total_rupas: 0.0,
             required_strength: 0.0,
             is_strong: 0,
+            ishta: 0.0,
+            kashta: 0.0,
         }; 7],
     };
     for (i, e) in result.entries.iter().enumerate() {
@@ -10399,6 +11899,8 @@ fn shadbala_result_to_ffi(result: &dhruv_search::ShadbalaResult) -> DhruvShadbal
             total_rupas: e.total_rupas,
             required_strength: e.required_strength,
             is_strong: e.is_strong as u8,
+            ishta: e.ishta,
+            kashta: e.kashta,
         };
     }
     out
@@ -10980,6 +12482,10 @@ pub struct DhruvShadbalaEntry {
     pub total_rupas: f64,
     pub required_strength: f64,
     pub is_strong: u8,
+    /// Ishta phala (virupas, 0-60).
+    pub ishta: f64,
+    /// Kashta phala (virupas, 0-60).
+    pub kashta: f64,
 }
 
 /// C-compatible Shadbala result for all 7 sapta grahas.
@@ -11315,6 +12821,7 @@ pub extern "C" fn dhruv_full_kundali_config_default() -> DhruvFullKundaliConfig
             include_lagna: 1,
             include_outer_planets: 1,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -12441,7 +13948,7 @@ pub struct DhruvGrahaLongitudes {
 
 /// Configuration for graha longitude computation.
 #[repr(C)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DhruvGrahaLongitudesConfig {
     /// `DHRUV_GRAHA_LONGITUDE_KIND_*`
     pub kind: i32,
@@ -12453,6 +13960,13 @@ pub struct DhruvGrahaLongitudesConfig {
     pub precession_model: i32,
     /// `DhruvReferencePlane` or -1 for system default.
     pub reference_plane: i32,
+    /// Use the custom anchor below instead of `ayanamsha_system` (0=false, 1=true).
+    /// A custom anchor is always evaluated on the ecliptic.
+    pub use_custom_ayanamsha: u8,
+    /// Mean ayanamsha at the custom anchor epoch, in degrees.
+    pub custom_ayanamsha_anchor_deg: f64,
+    /// Custom anchor epoch as a Julian Date in TDB.
+    pub custom_ayanamsha_anchor_jd_tdb: f64,
 }
 
 /// One moving osculating apogee result entry.
@@ -12502,6 +14016,9 @@ pub extern "C" fn dhruv_graha_longitudes_config_default() -> DhruvGrahaLongitude
         use_nutation: 0,
         precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
         reference_plane: -1,
+        use_custom_ayanamsha: 0,
+        custom_ayanamsha_anchor_deg: 0.0,
+        custom_ayanamsha_anchor_jd_tdb: 0.0,
     }
 }
 
@@ -12537,6 +14054,46 @@ pub unsafe extern "C" fn dhruv_graha_longitudes(
     }
 }
 
+/// Longitude speeds (deg/day) for all 9 grahas, in the same Graha order and
+/// frame as [`DhruvGrahaLongitudes`]. Negative values mean retrograde motion.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvGrahaSpeeds {
+    pub speeds: [f64; 9],
+}
+
+/// Query longitude speeds (deg/day) of all 9 grahas at a given TDB epoch.
+///
+/// The speeds are the rates of the longitudes `dhruv_graha_longitudes`
+/// returns for the same config. A NULL config uses
+/// `dhruv_graha_longitudes_config_default()`.
+///
+/// # Safety
+/// `engine` and `out` must be valid pointers; `config` may be NULL.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_graha_speeds(
+    engine: *const Engine,
+    jd_tdb: f64,
+    config: *const DhruvGrahaLongitudesConfig,
+    out: *mut DhruvGrahaSpeeds,
+) -> DhruvStatus {
+    if engine.is_null() || out.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+    let engine = unsafe { &*engine };
+    let rust_config = match resolve_graha_longitudes_config_ptr(config) {
+        Ok(cfg) => cfg,
+        Err(status) => return status,
+    };
+    match graha_speeds(engine, jd_tdb, &rust_config) {
+        Ok(speeds) => {
+            unsafe { &mut *out }.speeds = speeds;
+            DhruvStatus::Ok
+        }
+        Err(e) => DhruvStatus::from(&e),
+    }
+}
+
 /// Compute heliocentric moving osculating apogees for requested grahas at UTC date.
 ///
 /// `graha_indices` uses Graha order: Mangal=2, Buddh=3, Guru=4, Shukra=5,
@@ -12728,6 +14285,7 @@ pub struct DhruvDashaSnapshotRequest {
 pub struct DhruvDashaLevel0Request {
     pub birth: DhruvDashaBirthContext,
     pub system: u8,
+    pub variation: DhruvDashaVariationConfig,
 }
 
 #[repr(C)]
@@ -12735,6 +14293,7 @@ pub struct DhruvDashaLevel0Request {
 pub struct DhruvDashaLevel0EntityRequest {
     pub birth: DhruvDashaBirthContext,
     pub system: u8,
+    pub variation: DhruvDashaVariationConfig,
     pub entity_type: u8,
     pub entity_index: u8,
 }
@@ -12768,6 +14327,21 @@ pub struct DhruvDashaCompleteLevelRequest {
     pub child_level: u8,
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvDashaTransitionsRequest {
+    pub birth: DhruvDashaBirthContext,
+    pub start_time_kind: i32,
+    pub start_jd: f64,
+    pub start_utc: DhruvUtcTime,
+    pub end_time_kind: i32,
+    pub end_jd: f64,
+    pub end_utc: DhruvUtcTime,
+    pub system: u8,
+    pub level: u8,
+    pub variation: DhruvDashaVariationConfig,
+}
+
 #[derive(Debug, Clone, Default)]
 struct OwnedDashaInputs {
     moon_sid_lon: Option<f64>,
@@ -12817,6 +14391,18 @@ pub struct DhruvDashaPeriod {
     pub parent_idx: u32,
 }
 
+/// C-compatible dasha period clipped to a query range.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DhruvDashaRangePeriod {
+    /// The period, with `start`/`end` clipped to the range.
+    pub period: DhruvDashaPeriod,
+    /// 1 if the period began before the range start.
+    pub clipped_start: u8,
+    /// 1 if the period ends after the range end.
+    pub clipped_end: u8,
+}
+
 /// Fixed-capacity snapshot for FFI (max 5 levels).
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
@@ -12837,6 +14423,8 @@ pub struct DhruvDashaSnapshot {
 pub type DhruvDashaHierarchyHandle = *mut std::ffi::c_void;
 /// Opaque handle for a heap-allocated period vector.
 pub type DhruvDashaPeriodListHandle = *mut std::ffi::c_void;
+/// Opaque handle for a heap-allocated range-period vector.
+pub type DhruvDashaRangePeriodListHandle = *mut std::ffi::c_void;
 
 fn dasha_period_to_ffi(p: &dhruv_vedic_base::dasha::DashaPeriod) -> DhruvDashaPeriod {
     DhruvDashaPeriod {
@@ -13044,6 +14632,63 @@ pub unsafe extern "C" fn dhruv_dasha_period_list_free(handle: DhruvDashaPeriodLi
     }
 }
 
+/// Get the number of periods in a range-period list handle.
+///
+/// # Safety
+/// `handle` must be a valid range-period list handle. `out` must be non-null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_dasha_range_period_list_count(
+    handle: DhruvDashaRangePeriodListHandle,
+    out: *mut u32,
+) -> DhruvStatus {
+    if handle.is_null() || out.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+    let periods = unsafe { &*(handle as *const Vec<dhruv_search::DashaRangePeriod>) };
+    unsafe { *out = periods.len() as u32 };
+    DhruvStatus::Ok
+}
+
+/// Read one period from a range-period list handle by index.
+///
+/// # Safety
+/// `handle` must be a valid range-period list handle. `out` must be non-null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_dasha_range_period_list_at(
+    handle: DhruvDashaRangePeriodListHandle,
+    idx: u32,
+    out: *mut DhruvDashaRangePeriod,
+) -> DhruvStatus {
+    if handle.is_null() || out.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+    let periods = unsafe { &*(handle as *const Vec<dhruv_search::DashaRangePeriod>) };
+    let Some(range_period) = periods.get(idx as usize) else {
+        return DhruvStatus::InvalidInput;
+    };
+    unsafe {
+        *out = DhruvDashaRangePeriod {
+            period: dasha_period_to_ffi(&range_period.period),
+            clipped_start: u8::from(range_period.clipped_start),
+            clipped_end: u8::from(range_period.clipped_end),
+        }
+    };
+    DhruvStatus::Ok
+}
+
+/// Free a dasha range-period list handle. Passing NULL is a no-op.
+///
+/// # Safety
+/// `handle` must come from `dhruv_dasha_transitions` or be NULL.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_dasha_range_period_list_free(
+    handle: DhruvDashaRangePeriodListHandle,
+) {
+    if !handle.is_null() {
+        let _ = unsafe { Box::from_raw(handle as *mut Vec<dhruv_search::DashaRangePeriod>) };
+    }
+}
+
 /// C-compatible dasha variation overrides.
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
@@ -13054,6 +14699,8 @@ pub struct DhruvDashaVariationConfig {
     pub yogini_scheme: u8,
     /// For Ashtottari: use Abhijit in birth-balance detection.
     pub use_abhijit: u8,
+    /// Dasha year length (0=Julian 365.25, 1=Savana 360, 2=Tropical, 3=Sidereal).
+    pub year_length: u8,
 }
 
 fn dasha_variation_from_ffi(
@@ -13071,10 +14718,13 @@ fn dasha_variation_from_ffi(
     }
     let yogini_scheme = dhruv_vedic_base::dasha::YoginiScheme::from_u8(cfg.yogini_scheme)
         .ok_or(DhruvStatus::InvalidSearchConfig)?;
+    let year_length = dhruv_vedic_base::dasha::DashaYearLength::from_u8(cfg.year_length)
+        .ok_or(DhruvStatus::InvalidSearchConfig)?;
     Ok(dhruv_vedic_base::dasha::DashaVariationConfig {
         level_methods,
         yogini_scheme,
         use_abhijit: cfg.use_abhijit != 0,
+        year_length,
     })
 }
 
@@ -13085,6 +14735,7 @@ pub extern "C" fn dhruv_dasha_variation_config_default() -> DhruvDashaVariationC
         level_methods: [0xFF; 5],
         yogini_scheme: 0,
         use_abhijit: 1,
+        year_length: 0,
     }
 }
 
@@ -13119,6 +14770,8 @@ pub struct DhruvDashaSelectionConfig {
     pub yogini_scheme: u8,
     /// Use Abhijit for Ashtottari (1=yes, 0=no).
     pub use_abhijit: u8,
+    /// Dasha year length (0=Julian 365.25, 1=Savana 360, 2=Tropical, 3=Sidereal).
+    pub year_length: u8,
     /// Optional discriminated snapshot-time selector.
     pub snapshot_time: DhruvDashaSnapshotTime,
 }
@@ -13149,6 +14802,7 @@ fn dasha_selection_from_ffi(
         level_methods: c.level_methods,
         yogini_scheme: c.yogini_scheme,
         use_abhijit: c.use_abhijit,
+        year_length: c.year_length,
         snapshot_time: dasha_snapshot_time_from_ffi(&c.snapshot_time)?,
     })
 }
@@ -13166,6 +14820,7 @@ pub extern "C" fn dhruv_dasha_selection_config_default() -> DhruvDashaSelectionC
         level_methods: [0xFF; 5],
         yogini_scheme: 0,
         use_abhijit: 1,
+        year_length: 0,
         snapshot_time: DhruvDashaSnapshotTime {
             time_kind: DHRUV_DASHA_TIME_NONE,
             jd_utc: 0.0,
@@ -13214,6 +14869,7 @@ fn dasha_system_is_rashi_based(system: dhruv_vedic_base::dasha::DashaSystem) ->
             | dhruv_vedic_base::dasha::DashaSystem::Kendradi
             | dhruv_vedic_base::dasha::DashaSystem::KarakaKendradi
             | dhruv_vedic_base::dasha::DashaSystem::KarakaKendradiGraha
+            | dhruv_vedic_base::dasha::DashaSystem::Narayana
     )
 }
 
@@ -13316,12 +14972,7 @@ fn resolve_dasha_birth_context(
         let graha_lons = graha_longitudes(
             engine,
             birth_jd_tdb,
-            &GrahaLongitudesConfig::sidereal_with_model(
-                sankranti_config.ayanamsha_system,
-                sankranti_config.use_nutation,
-                sankranti_config.precession_model,
-                sankranti_config.reference_plane,
-            ),
+            &GrahaLongitudesConfig::from_sankranti_config(&sankranti_config),
         )
         .map_err(|err| DhruvStatus::from(&err))?;
         let lagna_sid = dhruv_search::sidereal_lagna_for_date(
@@ -13502,13 +15153,17 @@ pub unsafe extern "C" fn dhruv_dasha_level0(
         Some(value) => value,
         None => return DhruvStatus::InvalidInput,
     };
+    let variation = match dasha_variation_from_ffi(&request.variation) {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
     let birth = match resolve_dasha_birth_context(engine, eop, request.birth, system) {
         Ok(value) => value,
         Err(status) => return status,
     };
     let inputs = birth.inputs.borrowed();
 
-    match dasha_level0_with_inputs(birth.birth_jd, system, &inputs) {
+    match dasha_level0_with_inputs(birth.birth_jd, system, &variation, &inputs) {
         Ok(periods) => {
             let boxed = Box::new(periods);
             unsafe { *out = Box::into_raw(boxed) as DhruvDashaPeriodListHandle };
@@ -13540,6 +15195,10 @@ pub unsafe extern "C" fn dhruv_dasha_level0_entity(
         Some(value) => value,
         None => return DhruvStatus::InvalidInput,
     };
+    let variation = match dasha_variation_from_ffi(&request.variation) {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
     let entity = match dasha_entity_from_ffi(request.entity_type, request.entity_index) {
         Ok(value) => value,
         Err(status) => return status,
@@ -13550,7 +15209,7 @@ pub unsafe extern "C" fn dhruv_dasha_level0_entity(
     };
     let inputs = birth.inputs.borrowed();
 
-    match dasha_level0_entity_with_inputs(birth.birth_jd, system, entity, &inputs) {
+    match dasha_level0_entity_with_inputs(birth.birth_jd, system, entity, &variation, &inputs) {
         Ok(Some(period)) => {
             unsafe {
                 *out_found = 1;
@@ -13732,6 +15391,72 @@ pub unsafe extern "C" fn dhruv_dasha_complete_level(
     }
 }
 
+/// Compute the periods at one level that overlap a date range, clipped to it.
+///
+/// # Safety
+/// `request` and `out` must be valid and non-null. `engine`/`eop` may be null only when
+/// `request->birth.has_inputs != 0`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dhruv_dasha_transitions(
+    engine: *const Engine,
+    eop: *const dhruv_time::EopKernel,
+    request: *const DhruvDashaTransitionsRequest,
+    out: *mut DhruvDashaRangePeriodListHandle,
+) -> DhruvStatus {
+    if request.is_null() || out.is_null() {
+        return DhruvStatus::NullPointer;
+    }
+
+    let request = unsafe { &*request };
+    let system = match dhruv_vedic_base::dasha::DashaSystem::from_u8(request.system) {
+        Some(value) => value,
+        None => return DhruvStatus::InvalidInput,
+    };
+    let level = match dhruv_vedic_base::dasha::DashaLevel::from_u8(request.level) {
+        Some(value) => value,
+        None => return DhruvStatus::InvalidInput,
+    };
+    let variation = match dasha_variation_from_ffi(&request.variation) {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let start_jd = match dasha_jd_utc_from_selector(
+        request.start_time_kind,
+        request.start_jd,
+        request.start_utc,
+    ) {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let end_jd =
+        match dasha_jd_utc_from_selector(request.end_time_kind, request.end_jd, request.end_utc) {
+            Ok(value) => value,
+            Err(status) => return status,
+        };
+    let birth = match resolve_dasha_birth_context(engine, eop, request.birth, system) {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let inputs = birth.inputs.borrowed();
+
+    match dasha_transitions_with_inputs(
+        birth.birth_jd,
+        start_jd,
+        end_jd,
+        system,
+        level,
+        &variation,
+        &inputs,
+    ) {
+        Ok(periods) => {
+            let boxed = Box::new(periods);
+            unsafe { *out = Box::into_raw(boxed) as DhruvDashaRangePeriodListHandle };
+            DhruvStatus::Ok
+        }
+        Err(err) => DhruvStatus::from(&err),
+    }
+}
+
 // ---- Fixed Star (Tara) types and functions ----
 
 impl From<&TaraError> for DhruvStatus {
@@ -14297,6 +16022,81 @@ mod tests {
         assert_eq!(cfg.use_refraction, 1);
         assert_eq!(cfg.sun_limb, DHRUV_SUN_LIMB_UPPER);
         assert_eq!(cfg.altitude_correction, 1);
+        assert_eq!(cfg.refraction_model, DHRUV_REFRACTION_MODEL_STANDARD);
+        assert_eq!(cfg.day_fallback, DHRUV_DAY_FALLBACK_ERROR);
+    }
+
+    #[test]
+    fn ffi_riseset_config_day_fallback() {
+        let six_am = DhruvRiseSetConfig {
+            day_fallback: DHRUV_DAY_FALLBACK_LOCAL_SIX_AM,
+            ..dhruv_riseset_config_default()
+        };
+        assert_eq!(
+            riseset_config_from_ffi(&six_am).unwrap().day_fallback,
+            DayDefinitionFallback::LocalSixAm
+        );
+
+        let bad = DhruvRiseSetConfig {
+            day_fallback: 3,
+            ..dhruv_riseset_config_default()
+        };
+        assert_eq!(
+            riseset_config_from_ffi(&bad),
+            Err(DhruvStatus::InvalidQuery)
+        );
+    }
+
+    #[test]
+    fn ffi_tithi_day_kind_codes() {
+        use dhruv_vedic_base::ALL_TITHIS;
+        assert_eq!(
+            tithi_day_kind_to_ffi(TithiDayKind::Normal),
+            (DHRUV_TITHI_DAY_NORMAL, -1)
+        );
+        assert_eq!(
+            tithi_day_kind_to_ffi(TithiDayKind::Kshaya(ALL_TITHIS[3])),
+            (DHRUV_TITHI_DAY_KSHAYA, 3)
+        );
+        assert_eq!(
+            tithi_day_kind_to_ffi(TithiDayKind::Vriddhi(ALL_TITHIS[29])),
+            (DHRUV_TITHI_DAY_VRIDDHI, 29)
+        );
+    }
+
+    #[test]
+    fn ffi_riseset_config_refraction_model() {
+        let bennett = DhruvRiseSetConfig {
+            refraction_model: DHRUV_REFRACTION_MODEL_BENNETT,
+            refraction_pressure_hpa: 900.0,
+            ..dhruv_riseset_config_default()
+        };
+        assert_eq!(
+            riseset_config_from_ffi(&bennett).unwrap().refraction_model,
+            RefractionModel::Bennett {
+                pressure_hpa: 900.0,
+                temperature_c: 10.0,
+            }
+        );
+
+        let custom = DhruvRiseSetConfig {
+            refraction_model: DHRUV_REFRACTION_MODEL_CUSTOM,
+            refraction_arcmin: 36.5,
+            ..dhruv_riseset_config_default()
+        };
+        assert_eq!(
+            riseset_config_from_ffi(&custom).unwrap().refraction_model,
+            RefractionModel::Custom { arcmin: 36.5 }
+        );
+
+        let bad = DhruvRiseSetConfig {
+            refraction_model: 9,
+            ..dhruv_riseset_config_default()
+        };
+        assert_eq!(
+            riseset_config_from_ffi(&bad),
+            Err(DhruvStatus::InvalidQuery)
+        );
     }
 
     #[test]
@@ -14325,6 +16125,7 @@ mod tests {
             },
             use_nutation: 0,
             delta_psi_arcsec: 0.0,
+            precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
         };
         // SAFETY: Null output pointer is intentional for validation.
         let status = unsafe {
@@ -14350,6 +16151,7 @@ mod tests {
             },
             use_nutation: 0,
             delta_psi_arcsec: 0.0,
+            precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
         };
         let mut out: f64 = 0.0;
         // SAFETY: Valid pointers with intentionally invalid selector fields.
@@ -14375,6 +16177,7 @@ mod tests {
             },
             use_nutation: 0,
             delta_psi_arcsec: 0.0,
+            precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
         };
         let mut out: f64 = 0.0;
         // SAFETY: Invalid system code is intentional for validation.
@@ -14383,6 +16186,39 @@ mod tests {
         assert_eq!(status, DhruvStatus::InvalidQuery);
     }
 
+    #[test]
+    fn ffi_ayanamsha_compute_ex_rate_uses_precession_model() {
+        let mut request = DhruvAyanamshaComputeRequest {
+            system_code: 0,
+            mode: DHRUV_AYANAMSHA_MODE_RATE,
+            time_kind: DHRUV_AYANAMSHA_TIME_JD_TDB,
+            jd_tdb: 2_451_545.0,
+            utc: DhruvUtcTime {
+                year: 2000,
+                month: 1,
+                day: 1,
+                hour: 12,
+                minute: 0,
+                second: 0.0,
+            },
+            use_nutation: 0,
+            delta_psi_arcsec: 0.0,
+            precession_model: DHRUV_PRECESSION_MODEL_NEWCOMB1895,
+        };
+        let mut out: f64 = 0.0;
+        // SAFETY: Valid pointers.
+        let status =
+            unsafe { dhruv_ayanamsha_compute_ex(ptr::null(), &request, ptr::null(), &mut out) };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert!((out * 36.0 - 50.2786).abs() < 1e-3, "rate = {out}");
+
+        request.precession_model = 99;
+        // SAFETY: Valid pointers with an intentionally invalid model code.
+        let status =
+            unsafe { dhruv_ayanamsha_compute_ex(ptr::null(), &request, ptr::null(), &mut out) };
+        assert_eq!(status, DhruvStatus::InvalidQuery);
+    }
+
     #[test]
     fn ffi_ayanamsha_compute_ex_mean_lahiri_at_j2000() {
         let request = DhruvAyanamshaComputeRequest {
@@ -14400,6 +16236,7 @@ mod tests {
             },
             use_nutation: 0,
             delta_psi_arcsec: 0.0,
+            precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
         };
         let mut out: f64 = 0.0;
         // SAFETY: Valid pointers.
@@ -14429,6 +16266,7 @@ mod tests {
             },
             use_nutation: 0,
             delta_psi_arcsec: 0.0,
+            precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
         };
         let with_nut = DhruvAyanamshaComputeRequest {
             use_nutation: 1,
@@ -14468,6 +16306,7 @@ mod tests {
             },
             use_nutation: 0,
             delta_psi_arcsec: 0.0,
+            precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
         };
         let mut out: f64 = 0.0;
         // SAFETY: Null LSK with UTC input is intentional for validation.
@@ -14733,8 +16572,8 @@ mod tests {
     }
 
     #[test]
-    fn ffi_bhava_system_count_is_10() {
-        assert_eq!(dhruv_bhava_system_count(), 10);
+    fn ffi_bhava_system_count_is_11() {
+        assert_eq!(dhruv_bhava_system_count(), 11);
     }
 
     #[test]
@@ -14745,6 +16584,7 @@ mod tests {
                 cusp_deg: 0.0,
                 start_deg: 0.0,
                 end_deg: 0.0,
+                madhya_deg: 0.0,
             }; 12],
             lagna_deg: 0.0,
             mc_deg: 0.0,
@@ -14754,6 +16594,7 @@ mod tests {
                 cusp_deg: 0.0,
                 start_deg: 0.0,
                 end_deg: 0.0,
+                madhya_deg: 0.0,
             }; 12],
             rashi_bhava_lagna_deg: 0.0,
             rashi_bhava_mc_deg: 0.0,
@@ -14773,6 +16614,24 @@ mod tests {
         assert_eq!(status, DhruvStatus::NullPointer);
     }
 
+    #[test]
+    fn ffi_whole_sign_from_lagna_cusps_at_rashi_starts() {
+        let mut out = std::mem::MaybeUninit::<DhruvBhavaResult>::uninit();
+        // SAFETY: Valid output pointer; fully written on Ok.
+        let status = unsafe { dhruv_whole_sign_from_lagna(48.25, out.as_mut_ptr()) };
+        assert_eq!(status, DhruvStatus::Ok);
+        // SAFETY: Status Ok means the result was written.
+        let out = unsafe { out.assume_init() };
+        assert_eq!(out.lagna_deg, 48.25);
+        assert_eq!(out.bhavas[0].cusp_deg, 30.0);
+        assert_eq!(out.mc_deg, 300.0);
+        assert_eq!(out.rashi_bhava_valid, 0);
+
+        // SAFETY: Null output pointer is intentional for validation.
+        let status = unsafe { dhruv_whole_sign_from_lagna(48.25, ptr::null_mut()) };
+        assert_eq!(status, DhruvStatus::NullPointer);
+    }
+
     #[test]
     fn ffi_bhava_config_invalid_system() {
         let cfg = DhruvBhavaConfig {
@@ -14921,6 +16780,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ffi_node_speed_mean_regresses() {
+        let mut out: f64 = 0.0;
+        // SAFETY: Valid output pointer.
+        let status =
+            unsafe { dhruv_node_speed_deg_per_day(DHRUV_NODE_MODE_MEAN, 2_451_545.0, &mut out) };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert!((out + 0.053).abs() < 0.001, "mean node speed = {out}");
+    }
+
+    #[test]
+    fn ffi_node_speed_rejects_invalid_mode_and_null() {
+        let mut out: f64 = 0.0;
+        // SAFETY: Valid output pointer, invalid mode code.
+        let status = unsafe { dhruv_node_speed_deg_per_day(99, 2_451_545.0, &mut out) };
+        assert_eq!(status, DhruvStatus::InvalidQuery);
+        // SAFETY: Null output pointer is intentional for validation.
+        let status = unsafe {
+            dhruv_node_speed_deg_per_day(DHRUV_NODE_MODE_MEAN, 2_451_545.0, ptr::null_mut())
+        };
+        assert_eq!(status, DhruvStatus::NullPointer);
+    }
+
+    #[test]
+    fn ffi_next_node_station_rejects_null_engine() {
+        let mut event = std::mem::MaybeUninit::<DhruvNodeStationEvent>::uninit();
+        let mut found: u8 = 0;
+        // SAFETY: Null engine is intentional for validation.
+        let status = unsafe {
+            dhruv_next_node_station(ptr::null(), 2_451_545.0, event.as_mut_ptr(), &mut found)
+        };
+        assert_eq!(status, DhruvStatus::NullPointer);
+    }
+
+    #[test]
+    fn ffi_retrograde_intervals_rejects_bad_input() {
+        let config = dhruv_stationary_config_default();
+        let mut intervals = std::mem::MaybeUninit::<[DhruvRetrogradeInterval; 4]>::uninit();
+        let mut count = 0u32;
+        // SAFETY: Null engine is intentional for validation.
+        let status = unsafe {
+            dhruv_retrograde_intervals(
+                ptr::null(),
+                499,
+                2_451_545.0,
+                2_452_545.0,
+                &config,
+                intervals.as_mut_ptr().cast(),
+                4,
+                &mut count,
+            )
+        };
+        assert_eq!(status, DhruvStatus::NullPointer);
+
+        let fake_engine = std::ptr::NonNull::<DhruvEngineHandle>::dangling().as_ptr();
+        // SAFETY: The body code is rejected before the engine is read.
+        let status = unsafe {
+            dhruv_retrograde_intervals(
+                fake_engine,
+                42,
+                2_451_545.0,
+                2_452_545.0,
+                &config,
+                intervals.as_mut_ptr().cast(),
+                4,
+                &mut count,
+            )
+        };
+        assert_eq!(status, DhruvStatus::InvalidQuery);
+    }
+
     #[test]
     fn ffi_lunar_node_count() {
         assert_eq!(dhruv_lunar_node_count(), 2);
@@ -14994,6 +16924,8 @@ mod tests {
         let mut out = DhruvPanchangOperationResult {
             tithi_valid: 0,
             tithi: zeroed_tithi_info(),
+            tithi_day_kind: DHRUV_TITHI_DAY_NORMAL,
+            tithi_day_tithi_index: -1,
             karana_valid: 0,
             karana: zeroed_karana_info(),
             yoga_valid: 0,
@@ -15015,7 +16947,26 @@ mod tests {
         };
         // SAFETY: Null request pointer is intentional for validation.
         let status = unsafe {
-            dhruv_panchang_compute_ex(ptr::null(), ptr::null(), ptr::null(), ptr::null(), &mut out)
+            dhruv_panchang_compute_ex(ptr::null(), ptr::null(), ptr::null(), ptr::null(), &mut out)
+        };
+        assert_eq!(status, DhruvStatus::NullPointer);
+    }
+
+    #[test]
+    fn ffi_panchang_diff_rejects_null_engine() {
+        let mut out = std::mem::MaybeUninit::<DhruvPanchangDiff>::uninit();
+        // SAFETY: Null engine is intentional for validation.
+        let status = unsafe {
+            dhruv_panchang_diff(
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                out.as_mut_ptr(),
+            )
         };
         assert_eq!(status, DhruvStatus::NullPointer);
     }
@@ -15047,6 +16998,8 @@ mod tests {
         let mut out = DhruvPanchangOperationResult {
             tithi_valid: 0,
             tithi: zeroed_tithi_info(),
+            tithi_day_kind: DHRUV_TITHI_DAY_NORMAL,
+            tithi_day_tithi_index: -1,
             karana_valid: 0,
             karana: zeroed_karana_info(),
             yoga_valid: 0,
@@ -15106,6 +17059,8 @@ mod tests {
         let mut out = DhruvPanchangOperationResult {
             tithi_valid: 0,
             tithi: zeroed_tithi_info(),
+            tithi_day_kind: DHRUV_TITHI_DAY_NORMAL,
+            tithi_day_tithi_index: -1,
             karana_valid: 0,
             karana: zeroed_karana_info(),
             yoga_valid: 0,
@@ -15529,6 +17484,68 @@ mod tests {
 
     // --- Stationary/max-speed FFI tests ---
 
+    #[test]
+    fn ffi_heliacal_config_for_body() {
+        let default = dhruv_heliacal_config_default();
+        assert!((default.arcus_visionis_deg - 10.0).abs() < 1e-12);
+
+        let mut cfg = default;
+        // SAFETY: Valid pointer to a local.
+        let status = unsafe { dhruv_heliacal_config_for_body(299, &mut cfg) };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert!((cfg.arcus_visionis_deg - 5.0).abs() < 1e-12);
+        assert!((cfg.min_elongation_deg - 5.0).abs() < 1e-12);
+
+        // SAFETY: Valid pointer to a local.
+        let status = unsafe { dhruv_heliacal_config_for_body(-7, &mut cfg) };
+        assert_eq!(status, DhruvStatus::InvalidQuery);
+        // SAFETY: Null pointer is intentional for validation.
+        let status = unsafe { dhruv_heliacal_config_for_body(299, ptr::null_mut()) };
+        assert_eq!(status, DhruvStatus::NullPointer);
+    }
+
+    #[test]
+    fn ffi_heliacal_search_rejects_null_engine() {
+        let request = DhruvHeliacalSearchRequest {
+            target_kind: DHRUV_HELIACAL_TARGET_BODY,
+            body_code: 299,
+            tara_id: 0,
+            event_kind: DHRUV_HELIACAL_RISING,
+            query_mode: DHRUV_HELIACAL_QUERY_MODE_NEXT,
+            time_kind: DHRUV_SEARCH_TIME_JD_TDB,
+            at_jd_tdb: 2_451_545.0,
+            start_jd_tdb: 0.0,
+            end_jd_tdb: 0.0,
+            at_utc: ZEROED_UTC,
+            start_utc: ZEROED_UTC,
+            end_utc: ZEROED_UTC,
+            config: dhruv_heliacal_config_default(),
+        };
+        let location = DhruvGeoLocation {
+            latitude_deg: 28.6,
+            longitude_deg: 77.2,
+            altitude_m: 0.0,
+        };
+        let mut event = std::mem::MaybeUninit::<DhruvHeliacalEvent>::uninit();
+        let mut found: u8 = 0;
+        // SAFETY: Null engine and EOP are intentional for validation.
+        let status = unsafe {
+            dhruv_heliacal_search_ex(
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                &location,
+                &request,
+                event.as_mut_ptr(),
+                &mut found,
+                ptr::null_mut(),
+                0,
+                ptr::null_mut(),
+            )
+        };
+        assert_eq!(status, DhruvStatus::NullPointer);
+    }
+
     #[test]
     fn ffi_stationary_config_default_values() {
         let cfg = dhruv_stationary_config_default();
@@ -15956,6 +17973,27 @@ mod tests {
         assert!((config.step_size_days - 1.0).abs() < 1e-10);
         assert_eq!(config.max_iterations, 50);
         assert!((config.convergence_days - 1e-8).abs() < 1e-15);
+        assert_eq!(config.use_custom_ayanamsha, 0);
+    }
+
+    #[test]
+    fn ffi_custom_ayanamsha_reaches_graha_longitudes_config() {
+        let mut raw = dhruv_sankranti_config_default();
+        raw.reference_plane = 1;
+        raw.use_custom_ayanamsha = 1;
+        raw.custom_ayanamsha_anchor_deg = 23.5;
+        raw.custom_ayanamsha_anchor_jd_tdb = 2_451_545.0;
+        let cfg = sankranti_config_from_ffi(&raw).unwrap();
+        let custom = CustomAyanamsha::new(23.5, 2_451_545.0);
+        assert_eq!(cfg.custom_ayanamsha, Some(custom));
+        assert_eq!(cfg.reference_plane, dhruv_frames::ReferencePlane::Ecliptic);
+        assert_eq!(
+            GrahaLongitudesConfig::from_sankranti_config(&cfg).custom_ayanamsha,
+            Some(custom)
+        );
+
+        raw.custom_ayanamsha_anchor_deg = f64::NAN;
+        assert!(sankranti_config_from_ffi(&raw).is_none());
     }
 
     #[test]
@@ -16173,6 +18211,90 @@ mod tests {
         assert_eq!(status, DhruvStatus::NullPointer);
     }
 
+    #[test]
+    fn ffi_moon_rashi_ingresses_rejects_bad_input() {
+        let utc = DhruvUtcTime {
+            year: 2024,
+            month: 3,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0.0,
+        };
+        let mut config = dhruv_sankranti_config_default();
+        let mut events = [DhruvIngressEvent {
+            utc,
+            rashi_index: 0,
+            jd_tdb: 0.0,
+        }; 4];
+        let mut count = 0u32;
+        let status = unsafe {
+            dhruv_moon_rashi_ingresses(
+                ptr::null(),
+                &utc,
+                &utc,
+                &config,
+                events.as_mut_ptr(),
+                4,
+                &mut count,
+            )
+        };
+        assert_eq!(status, DhruvStatus::NullPointer);
+
+        let fake_engine = std::ptr::NonNull::<DhruvEngineHandle>::dangling().as_ptr();
+        config.samvatsara_scheme = -1;
+        let status = unsafe {
+            dhruv_moon_rashi_ingresses(
+                fake_engine,
+                &utc,
+                &utc,
+                &config,
+                events.as_mut_ptr(),
+                4,
+                &mut count,
+            )
+        };
+        assert_eq!(status, DhruvStatus::InvalidQuery);
+    }
+
+    #[test]
+    fn ffi_next_nakshatra_ingress_rejects_bad_input() {
+        let config = dhruv_sankranti_config_default();
+        let mut event = DhruvNakshatraIngressEvent {
+            utc: ZEROED_UTC,
+            jd_tdb: 0.0,
+            body_code: 0,
+            nakshatra_index: 0,
+            boundary_deg: 0.0,
+            retrograde: 0,
+        };
+        let mut found = 0u8;
+        let status = unsafe {
+            dhruv_next_nakshatra_ingress(
+                ptr::null(),
+                301,
+                2_451_545.0,
+                &config,
+                &mut event,
+                &mut found,
+            )
+        };
+        assert_eq!(status, DhruvStatus::NullPointer);
+
+        let fake_engine = std::ptr::NonNull::<DhruvEngineHandle>::dangling().as_ptr();
+        let status = unsafe {
+            dhruv_next_nakshatra_ingress(
+                fake_engine,
+                42,
+                2_451_545.0,
+                &config,
+                &mut event,
+                &mut found,
+            )
+        };
+        assert_eq!(status, DhruvStatus::InvalidQuery);
+    }
+
     #[test]
     fn ffi_ayana_for_date_rejects_null() {
         let utc = DhruvUtcTime {
@@ -16367,20 +18489,35 @@ mod tests {
 
     #[test]
     fn ffi_samvatsara_from_year_rejects_null() {
-        let s = unsafe { dhruv_samvatsara_from_year(2024, ptr::null_mut()) };
+        let s = unsafe { dhruv_samvatsara_from_year(2024, 0, ptr::null_mut()) };
         assert_eq!(s, DhruvStatus::NullPointer);
     }
 
     #[test]
     fn ffi_samvatsara_from_year_2024() {
         let mut out = std::mem::MaybeUninit::<DhruvSamvatsaraResult>::uninit();
-        let s = unsafe { dhruv_samvatsara_from_year(2024, out.as_mut_ptr()) };
+        let s = unsafe { dhruv_samvatsara_from_year(2024, 0, out.as_mut_ptr()) };
         assert_eq!(s, DhruvStatus::Ok);
         let r = unsafe { out.assume_init() };
         assert!(r.samvatsara_index >= 0 && r.samvatsara_index < 60);
         assert!(r.cycle_position >= 1 && r.cycle_position <= 60);
     }
 
+    #[test]
+    fn ffi_samvatsara_from_year_rejects_bad_scheme() {
+        let mut out = std::mem::MaybeUninit::<DhruvSamvatsaraResult>::uninit();
+        let s = unsafe { dhruv_samvatsara_from_year(2024, 3, out.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::InvalidQuery);
+    }
+
+    #[test]
+    fn ffi_sankranti_config_rejects_bad_samvatsara_scheme() {
+        let mut cfg = dhruv_sankranti_config_default();
+        assert!(sankranti_config_from_ffi(&cfg).is_some());
+        cfg.samvatsara_scheme = -1;
+        assert!(sankranti_config_from_ffi(&cfg).is_none());
+    }
+
     #[test]
     fn ffi_nth_rashi_from_basic() {
         // 2nd from Mesha (0) = Vrishabha (1)
@@ -16710,6 +18847,97 @@ mod tests {
         assert_eq!(dhruv_rashi_lord(255), -1);
     }
 
+    #[test]
+    fn ffi_nakshatra_lord() {
+        // Ashwini (0) -> Ketu (8), Bharani (1) -> Shukra (5), Revati (26) -> Buddh (3)
+        assert_eq!(dhruv_nakshatra_lord(0), 8);
+        assert_eq!(dhruv_nakshatra_lord(1), 5);
+        assert_eq!(dhruv_nakshatra_lord(26), 3);
+        assert_eq!(dhruv_nakshatra_lord(27), -1);
+        assert_eq!(dhruv_nakshatra_lord(256), -1);
+    }
+
+    #[test]
+    fn ffi_navatara_from() {
+        // Counted from Ashwini: Ashwini is Janma, Bharani Sampat, Revati (27th) Parama Mitra.
+        assert_eq!(dhruv_navatara_from(0, 0), 0);
+        assert_eq!(dhruv_navatara_from(0, 1), 1);
+        assert_eq!(dhruv_navatara_from(0, 26), 8);
+        // Counting wraps past Revati: Krittika (2) is the 23rd from Pushya (7), Pratyak.
+        assert_eq!(dhruv_navatara_from(7, 2), 4);
+        assert_eq!(dhruv_navatara_from(27, 0), -1);
+        assert_eq!(dhruv_navatara_from(0, 27), -1);
+    }
+
+    #[test]
+    fn ffi_navatara_name() {
+        let name = unsafe { CStr::from_ptr(dhruv_navatara_name(8)) };
+        assert_eq!(name.to_str().unwrap(), "Parama Mitra");
+        assert!(dhruv_navatara_name(9).is_null());
+    }
+
+    #[test]
+    fn ffi_kp_sublord() {
+        let mut out = DhruvKpSublord {
+            nakshatra_index: 0,
+            star_lord: 0,
+            sub_lord: 0,
+        };
+        // Ashwini 3°00' starts the Surya sub of the Ketu star.
+        let status = unsafe { dhruv_kp_sublord(3.0, &mut out) };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert_eq!(
+            (out.nakshatra_index, out.star_lord, out.sub_lord),
+            (0, 8, 0)
+        );
+        assert_eq!(
+            unsafe { dhruv_kp_sublord(f64::NAN, &mut out) },
+            DhruvStatus::InvalidInput
+        );
+        assert_eq!(
+            unsafe { dhruv_kp_sublord(0.0, ptr::null_mut()) },
+            DhruvStatus::NullPointer
+        );
+    }
+
+    #[test]
+    fn ffi_gandanta_status() {
+        let mut out = DhruvGandantaStatus {
+            kind: -1,
+            half: -1,
+            offset_deg: 0.0,
+        };
+        let mut found = 0u8;
+        // 29°55' Meena, five arcminutes before the Meena/Mesha junction.
+        let status = unsafe { dhruv_gandanta_status(359.0 + 55.0 / 60.0, &mut out, &mut found) };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert_eq!(found, 1);
+        assert_eq!(out.kind, DHRUV_GANDANTA_MEENA_MESHA);
+        assert_eq!(out.half, DHRUV_GANDANTA_APPROACHING);
+        assert!((out.offset_deg - 5.0 / 60.0).abs() < 1e-9);
+
+        let status = unsafe { dhruv_gandanta_status(121.0, &mut out, &mut found) };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert_eq!(found, 1);
+        assert_eq!(
+            (out.kind, out.half),
+            (DHRUV_GANDANTA_KARKA_SIMHA, DHRUV_GANDANTA_LEAVING)
+        );
+
+        let status = unsafe { dhruv_gandanta_status(60.0, &mut out, &mut found) };
+        assert_eq!(status, DhruvStatus::Ok);
+        assert_eq!(found, 0);
+
+        assert_eq!(
+            unsafe { dhruv_gandanta_status(f64::NAN, &mut out, &mut found) },
+            DhruvStatus::InvalidInput
+        );
+        assert_eq!(
+            unsafe { dhruv_gandanta_status(0.0, ptr::null_mut(), &mut found) },
+            DhruvStatus::NullPointer
+        );
+    }
+
     #[test]
     fn ffi_sphuta_name_valid() {
         let name = dhruv_sphuta_name(0);
@@ -16912,6 +19140,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ffi_transit_bindus_whole_sign() {
+        let rashis = [0u8; 7];
+        let mut natal = std::mem::MaybeUninit::<DhruvAshtakavargaResult>::uninit();
+        let s = unsafe { dhruv_calculate_ashtakavarga(rashis.as_ptr(), 0, natal.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::Ok);
+        let natal = unsafe { natal.assume_init() };
+        let mut bhavas = std::mem::MaybeUninit::<DhruvBhavaResult>::uninit();
+        let s = unsafe { dhruv_whole_sign_from_lagna(20.0, bhavas.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::Ok);
+        let bhavas = unsafe { bhavas.assume_init() };
+
+        // Shani at 95 deg (Karka) is the 4th house from a Mesha lagna.
+        let mut out = std::mem::MaybeUninit::<DhruvTransitBindus>::uninit();
+        let s = unsafe {
+            dhruv_transit_bindus(
+                &natal,
+                &bhavas,
+                6,
+                95.0,
+                DHRUV_TRANSIT_HOUSE_WHOLE_SIGN,
+                out.as_mut_ptr(),
+            )
+        };
+        assert_eq!(s, DhruvStatus::Ok);
+        let out = unsafe { out.assume_init() };
+        assert_eq!(out.graha_index, 6);
+        assert_eq!(out.house, 4);
+        assert_eq!(out.rashi_index, 3);
+        assert_eq!(out.bav_valid, 1);
+        assert_eq!(out.bav_bindus, natal.bavs[6].points[3]);
+        assert_eq!(out.sav_bindus, natal.sav.total_points[3]);
+
+        // Rahu has no BAV of its own.
+        let mut out = std::mem::MaybeUninit::<DhruvTransitBindus>::uninit();
+        let s = unsafe {
+            dhruv_transit_bindus(
+                &natal,
+                &bhavas,
+                7,
+                95.0,
+                DHRUV_TRANSIT_HOUSE_WHOLE_SIGN,
+                out.as_mut_ptr(),
+            )
+        };
+        assert_eq!(s, DhruvStatus::Ok);
+        assert_eq!(unsafe { out.assume_init() }.bav_valid, 0);
+    }
+
+    #[test]
+    fn ffi_transit_bindus_rejects_bad_input() {
+        let natal = std::mem::MaybeUninit::<DhruvAshtakavargaResult>::zeroed();
+        let bhavas = std::mem::MaybeUninit::<DhruvBhavaResult>::zeroed();
+        let mut out = std::mem::MaybeUninit::<DhruvTransitBindus>::uninit();
+        let s = unsafe {
+            dhruv_transit_bindus(ptr::null(), bhavas.as_ptr(), 0, 0.0, 0, out.as_mut_ptr())
+        };
+        assert_eq!(s, DhruvStatus::NullPointer);
+        let s = unsafe {
+            dhruv_transit_bindus(natal.as_ptr(), bhavas.as_ptr(), 0, 0.0, 2, out.as_mut_ptr())
+        };
+        assert_eq!(s, DhruvStatus::InvalidQuery);
+        let s = unsafe {
+            dhruv_transit_bindus(natal.as_ptr(), bhavas.as_ptr(), 9, 0.0, 0, out.as_mut_ptr())
+        };
+        assert_eq!(s, DhruvStatus::InvalidQuery);
+        let s = unsafe {
+            dhruv_transit_bindus(
+                natal.as_ptr(),
+                bhavas.as_ptr(),
+                0,
+                f64::NAN,
+                0,
+                out.as_mut_ptr(),
+            )
+        };
+        assert_eq!(s, DhruvStatus::InvalidInput);
+    }
+
     #[test]
     fn ffi_ashtakavarga_for_date_null() {
         let mut out = std::mem::MaybeUninit::<DhruvAshtakavargaResult>::uninit();
@@ -17143,6 +19450,71 @@ mod tests {
         assert!(m.entries[0][6].base_virupa >= 59.0);
     }
 
+    // --- relationship_matrix ---
+
+    #[test]
+    fn ffi_relationship_matrix() {
+        // Surya and Shani both in Mesha: natural enmity stands in full.
+        let lons = [5.0, 70.0, 100.0, 130.0, 160.0, 190.0, 10.0, 220.0, 40.0];
+        let mut out = [0_i32; 81];
+        let s = unsafe { dhruv_relationship_matrix(lons.as_ptr(), out.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::Ok);
+        assert_eq!(out[6], DHRUV_PANCHADHA_ADHI_SHATRU);
+        assert_eq!(out[6 * 9], DHRUV_PANCHADHA_ADHI_SHATRU);
+        for i in 0..9 {
+            assert_eq!(out[i * 9 + i], -1);
+        }
+
+        let mut bad = lons;
+        bad[3] = f64::NAN;
+        let s = unsafe { dhruv_relationship_matrix(bad.as_ptr(), out.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::InvalidInput);
+        let s = unsafe { dhruv_relationship_matrix(ptr::null(), out.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::NullPointer);
+    }
+
+    // --- rashi_drishti ---
+
+    #[test]
+    fn ffi_rashi_drishti() {
+        let mut aspects = 0u8;
+        // Mesha (chara) aspects Simha (sthira) but not the adjacent Vrishabha.
+        assert_eq!(
+            unsafe { dhruv_rashi_drishti(0, 4, &mut aspects) },
+            DhruvStatus::Ok
+        );
+        assert_eq!(aspects, 1);
+        assert_eq!(
+            unsafe { dhruv_rashi_drishti(0, 1, &mut aspects) },
+            DhruvStatus::Ok
+        );
+        assert_eq!(aspects, 0);
+        assert_eq!(
+            unsafe { dhruv_rashi_drishti(12, 0, &mut aspects) },
+            DhruvStatus::InvalidQuery
+        );
+        assert_eq!(
+            unsafe { dhruv_rashi_drishti(0, 4, ptr::null_mut()) },
+            DhruvStatus::NullPointer
+        );
+    }
+
+    #[test]
+    fn ffi_rashi_drishti_matrix() {
+        let mut out = std::mem::MaybeUninit::<DhruvRashiDrishtiMatrix>::uninit();
+        let s = unsafe { dhruv_rashi_drishti_matrix(out.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::Ok);
+        let m = unsafe { out.assume_init() };
+        for (i, row) in m.aspects.iter().enumerate() {
+            assert_eq!(row[i], 0);
+            assert_eq!(row.iter().filter(|&&a| a == 1).count(), 3, "row {i}");
+        }
+        assert_eq!(
+            unsafe { dhruv_rashi_drishti_matrix(ptr::null_mut()) },
+            DhruvStatus::NullPointer
+        );
+    }
+
     // --- ghatika_from_elapsed ---
 
     #[test]
@@ -17167,6 +19539,26 @@ mod tests {
         assert_eq!(idx, 0);
     }
 
+    #[test]
+    fn ffi_ghatika_duration_round_trip() {
+        let mut hours = 0.0;
+        let s = unsafe { dhruv_ghatika_to_duration(12.0, 30.0, &mut hours) };
+        assert_eq!(s, DhruvStatus::Ok);
+        assert!((hours - 5.0).abs() < 1e-12);
+
+        let mut ghati = 0u32;
+        let mut vighati = 0.0;
+        let s = unsafe { dhruv_duration_to_ghatika(hours, &mut ghati, &mut vighati) };
+        assert_eq!(s, DhruvStatus::Ok);
+        assert_eq!(ghati, 12);
+        assert!((vighati - 30.0).abs() < 1e-9);
+
+        let s = unsafe { dhruv_ghatika_to_duration(f64::NAN, 0.0, &mut hours) };
+        assert_eq!(s, DhruvStatus::InvalidInput);
+        let s = unsafe { dhruv_duration_to_ghatika(1.0, ptr::null_mut(), &mut vighati) };
+        assert_eq!(s, DhruvStatus::NullPointer);
+    }
+
     // --- ghatikas_since_sunrise ---
 
     #[test]
@@ -17210,6 +19602,7 @@ mod tests {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         };
         let bhava_cfg = dhruv_bhava_config_default();
         let s = unsafe {
@@ -17228,6 +19621,26 @@ mod tests {
         assert_eq!(s, DhruvStatus::NullPointer);
     }
 
+    #[test]
+    fn ffi_graha_positions_flags_pass_through() {
+        let cfg = DhruvGrahaPositionsConfig {
+            include_nakshatra: 0,
+            include_lagna: 0,
+            include_outer_planets: 0,
+            include_bhava: 0,
+            include_flags: 1,
+        };
+        assert!(graha_positions_config_from_ffi(&cfg).include_flags);
+
+        let entry = dhruv_search::GrahaEntry {
+            gandanta: true,
+            pushkara: true,
+            ..dhruv_search::GrahaEntry::sentinel()
+        };
+        let out = graha_entry_to_ffi(&entry);
+        assert_eq!((out.gandanta, out.rashi_sandhi, out.pushkara), (1, 0, 1));
+    }
+
     #[test]
     fn ffi_core_bindus_rejects_null() {
         let mut out = std::mem::MaybeUninit::<DhruvBindusResult>::uninit();
@@ -17350,6 +19763,25 @@ mod tests {
         assert_eq!(s, DhruvStatus::NullPointer);
     }
 
+    #[test]
+    fn dasha_ffi_year_length_passes_through() {
+        let mut variation = dhruv_dasha_variation_config_default();
+        variation.year_length = 1;
+        assert_eq!(
+            dasha_variation_from_ffi(&variation).unwrap().year_length,
+            dhruv_vedic_base::dasha::DashaYearLength::Savana
+        );
+        variation.year_length = 4;
+        assert!(matches!(
+            dasha_variation_from_ffi(&variation),
+            Err(DhruvStatus::InvalidSearchConfig)
+        ));
+
+        let mut selection = dhruv_dasha_selection_config_default();
+        selection.year_length = 3;
+        assert_eq!(dasha_selection_from_ffi(&selection).unwrap().year_length, 3);
+    }
+
     #[test]
     fn dasha_ffi_periods_and_snapshots_include_utc() {
         let period = dhruv_vedic_base::dasha::DashaPeriod {
@@ -17390,6 +19822,53 @@ mod tests {
         assert_eq!(snapshot.query_utc.hour, 0);
     }
 
+    #[test]
+    fn dasha_ffi_transitions_from_raw_inputs() {
+        // SAFETY: every field of the birth context is plain data; with
+        // `has_inputs` set only the time selector and inputs are read.
+        let mut birth: DhruvDashaBirthContext = unsafe { std::mem::zeroed() };
+        birth.time_kind = DHRUV_DASHA_TIME_JD_UTC;
+        birth.birth_jd = 2_451_545.0;
+        birth.has_inputs = 1;
+        birth.inputs.has_moon_sid_lon = 1;
+        birth.inputs.moon_sid_lon = 100.0;
+        let mut request = DhruvDashaTransitionsRequest {
+            birth,
+            start_time_kind: DHRUV_DASHA_TIME_JD_UTC,
+            start_jd: 2_451_545.0 + 10.0 * 365.25,
+            start_utc: ZEROED_UTC,
+            end_time_kind: DHRUV_DASHA_TIME_JD_UTC,
+            end_jd: 2_451_545.0 + 30.0 * 365.25,
+            end_utc: ZEROED_UTC,
+            system: dhruv_vedic_base::dasha::DashaSystem::Vimshottari as u8,
+            level: 1,
+            variation: dhruv_dasha_variation_config_default(),
+        };
+
+        let mut handle: DhruvDashaRangePeriodListHandle = ptr::null_mut();
+        let s = unsafe { dhruv_dasha_transitions(ptr::null(), ptr::null(), &request, &mut handle) };
+        assert_eq!(s, DhruvStatus::Ok);
+        let mut count = 0u32;
+        let s = unsafe { dhruv_dasha_range_period_list_count(handle, &mut count) };
+        assert_eq!(s, DhruvStatus::Ok);
+        assert!(count > 1);
+        let mut first = std::mem::MaybeUninit::<DhruvDashaRangePeriod>::uninit();
+        let s = unsafe { dhruv_dasha_range_period_list_at(handle, 0, first.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::Ok);
+        let first = unsafe { first.assume_init() };
+        assert_eq!(first.clipped_start, 1);
+        assert_eq!(first.period.level, 1);
+        assert!((first.period.start_jd - request.start_jd).abs() < 1e-9);
+        let mut past_end = std::mem::MaybeUninit::<DhruvDashaRangePeriod>::uninit();
+        let s = unsafe { dhruv_dasha_range_period_list_at(handle, count, past_end.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::InvalidInput);
+        unsafe { dhruv_dasha_range_period_list_free(handle) };
+
+        request.end_jd = request.start_jd;
+        let s = unsafe { dhruv_dasha_transitions(ptr::null(), ptr::null(), &request, &mut handle) };
+        assert_eq!(s, DhruvStatus::InvalidSearchConfig);
+    }
+
     #[test]
     fn ffi_graha_longitudes_rejects_null_out() {
         // Use a non-null but invalid engine pointer would be UB, so just test null out
@@ -17424,6 +19903,14 @@ mod tests {
         assert_eq!(s, DhruvStatus::NullPointer);
     }
 
+    #[test]
+    fn ffi_graha_speeds_rejects_null() {
+        let mut out = std::mem::MaybeUninit::<DhruvGrahaSpeeds>::uninit();
+        let s =
+            unsafe { dhruv_graha_speeds(ptr::null(), 2451545.0, ptr::null(), out.as_mut_ptr()) };
+        assert_eq!(s, DhruvStatus::NullPointer);
+    }
+
     #[test]
     fn ffi_nakshatra_at_rejects_null() {
         let mut out = std::mem::MaybeUninit::<DhruvPanchangNakshatraInfo>::uninit();
@@ -17687,6 +20174,7 @@ mod tests {
             use_refraction: 1,
             sun_limb: 0,
             altitude_correction: 0,
+            ..dhruv_riseset_config_default()
         };
         let scope = DhruvAmshaChartScope {
             include_bhava_cusps: 0,
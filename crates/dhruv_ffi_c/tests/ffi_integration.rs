@@ -7,7 +7,7 @@ use std::f64::consts::{PI, TAU};
 
 use dhruv_core::{Body, Frame, Observer};
 use dhruv_ffi_c::*;
-use dhruv_time::{calendar_to_jd, gmst_rad, local_sidereal_time_rad};
+use dhruv_time::{apparent_local_sidereal_time_rad, calendar_to_jd};
 
 const ZEROED_UTC: DhruvUtcTime = DhruvUtcTime {
     year: 0,
@@ -636,6 +636,7 @@ fn ffi_ayanamsha_mean_lahiri_j2000() {
         },
         use_nutation: 0,
         delta_psi_arcsec: 0.0,
+        precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
     };
     // SAFETY: Valid output pointer.
     let status = unsafe { dhruv_ayanamsha_compute_ex(ptr::null(), &req, ptr::null(), &mut out) };
@@ -666,6 +667,7 @@ fn ffi_ayanamsha_all_systems_valid() {
             },
             use_nutation: 0,
             delta_psi_arcsec: 0.0,
+            precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
         };
         // SAFETY: Valid output pointer.
         let status =
@@ -1029,6 +1031,7 @@ fn ffi_ayanamsha_deg_unified_matches_mean() {
         },
         use_nutation: 0,
         delta_psi_arcsec: 0.0,
+        precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
     };
     let req_mean = DhruvAyanamshaComputeRequest {
         mode: DHRUV_AYANAMSHA_MODE_MEAN,
@@ -1084,6 +1087,7 @@ fn ffi_sunrise_lower_limb_later_than_upper() {
         use_refraction: 1,
         sun_limb: DHRUV_SUN_LIMB_UPPER,
         altitude_correction: 1,
+        ..dhruv_riseset_config_default()
     };
     let mut result_upper = DhruvRiseSetResult {
         result_type: -1,
@@ -1111,6 +1115,7 @@ fn ffi_sunrise_lower_limb_later_than_upper() {
         use_refraction: 1,
         sun_limb: DHRUV_SUN_LIMB_LOWER,
         altitude_correction: 1,
+        ..dhruv_riseset_config_default()
     };
     let mut result_lower = DhruvRiseSetResult {
         result_type: -1,
@@ -1190,6 +1195,7 @@ fn ffi_center_mode_between_limbs() {
             use_refraction: 1,
             sun_limb: *limb_code,
             altitude_correction: 1,
+            ..dhruv_riseset_config_default()
         };
         let mut result = DhruvRiseSetResult {
             result_type: -1,
@@ -1274,6 +1280,7 @@ fn ffi_bhava_equal_new_delhi() {
             cusp_deg: 0.0,
             start_deg: 0.0,
             end_deg: 0.0,
+            madhya_deg: 0.0,
         }; 12],
         lagna_deg: 0.0,
         mc_deg: 0.0,
@@ -1283,6 +1290,7 @@ fn ffi_bhava_equal_new_delhi() {
             cusp_deg: 0.0,
             start_deg: 0.0,
             end_deg: 0.0,
+            madhya_deg: 0.0,
         }; 12],
         rashi_bhava_lagna_deg: 0.0,
         rashi_bhava_mc_deg: 0.0,
@@ -1377,14 +1385,12 @@ fn ffi_lagna_deg_new_delhi() {
             dhruv_time::LeapSecondKernel::load(&kernel_base().join("naif0012.tls")).unwrap();
         let eop_rust = dhruv_time::EopKernel::load(&kernel_base().join("finals2000A.all")).unwrap();
         let jd_ut1 = eop_rust.utc_to_ut1_jd(jd_utc).expect("EOP lookup");
-        let gmst = gmst_rad(jd_ut1);
         let utc_s = dhruv_time::jd_to_tdb_seconds(jd_utc);
         let tdb_s = lsk_rust.utc_to_tdb(utc_s);
         let jd_tdb = dhruv_time::tdb_seconds_to_jd(tdb_s);
         let t = (jd_tdb - 2_451_545.0) / 36525.0;
-        let (ee, eps_true) = dhruv_frames::equation_of_equinoxes_and_true_obliquity(t);
-        let gast = gmst + ee;
-        let lst = local_sidereal_time_rad(gast, loc.longitude_deg.to_radians());
+        let (_, eps_true) = dhruv_frames::equation_of_equinoxes_and_true_obliquity(t);
+        let lst = apparent_local_sidereal_time_rad(jd_ut1, jd_tdb, loc.longitude_deg.to_radians());
 
         let lagna_rad = asc.to_radians();
         let ra = f64::atan2(lagna_rad.sin() * eps_true.cos(), lagna_rad.cos()).rem_euclid(TAU);
@@ -1509,6 +1515,7 @@ fn ffi_bhava_body_starting_point() {
             cusp_deg: 0.0,
             start_deg: 0.0,
             end_deg: 0.0,
+            madhya_deg: 0.0,
         }; 12],
         lagna_deg: 0.0,
         mc_deg: 0.0,
@@ -1518,6 +1525,7 @@ fn ffi_bhava_body_starting_point() {
             cusp_deg: 0.0,
             start_deg: 0.0,
             end_deg: 0.0,
+            madhya_deg: 0.0,
         }; 12],
         rashi_bhava_lagna_deg: 0.0,
         rashi_bhava_mc_deg: 0.0,
@@ -2094,6 +2102,7 @@ fn ffi_utc_ayanamsha_roundtrip() {
         },
         use_nutation: 0,
         delta_psi_arcsec: 0.0,
+        precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
     };
     let status =
         unsafe { dhruv_ayanamsha_compute_ex(ptr::null(), &req_jd, ptr::null(), &mut deg_jd) };
@@ -2117,6 +2126,7 @@ fn ffi_utc_ayanamsha_roundtrip() {
         utc,
         use_nutation: 0,
         delta_psi_arcsec: 0.0,
+        precession_model: DHRUV_PRECESSION_MODEL_VONDRAK2011,
     };
     let status =
         unsafe { dhruv_ayanamsha_compute_ex(lsk_ptr, &req_utc, ptr::null(), &mut deg_utc) };
@@ -2911,6 +2921,7 @@ fn ffi_full_kundali_result_free_double_free_same_pointer() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3010,6 +3021,7 @@ fn ffi_full_kundali_error_path_free_safety() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3099,6 +3111,7 @@ fn ffi_full_kundali_dasha_overflow_rejection() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3198,6 +3211,7 @@ fn ffi_full_kundali_dasha_partial_success_contract() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3308,6 +3322,7 @@ fn ffi_full_kundali_panchang_only() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3398,6 +3413,7 @@ fn ffi_full_kundali_panchang_disabled() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3484,6 +3500,7 @@ fn ffi_full_kundali_calendar_implies_panchang() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3577,6 +3594,7 @@ fn ffi_full_kundali_panchang_and_calendar() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3671,6 +3689,7 @@ fn ffi_full_kundali_bhava_cusps_enabled() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -3768,6 +3787,7 @@ fn ffi_full_kundali_bhava_cusps_disabled() {
             include_lagna: 0,
             include_outer_planets: 0,
             include_bhava: 0,
+            include_flags: 0,
         },
         bindus_config: DhruvBindusConfig {
             include_nakshatra: 0,
@@ -33,7 +33,7 @@ pub use precession::{
     precess_ecliptic_date_to_j2000_with_model, precess_ecliptic_j2000_to_date,
     precess_ecliptic_j2000_to_date_with_model,
 };
-pub use rotation::{ecliptic_to_icrf, icrf_to_ecliptic};
+pub use rotation::{apply_matrix, ecliptic_to_icrf, icrf_to_ecliptic, precession_nutation_matrix};
 pub use spherical::{
     SphericalCoords, SphericalState, cartesian_state_to_spherical_state, cartesian_to_spherical,
     spherical_to_cartesian,
@@ -1,9 +1,13 @@
 //! Frame rotation between ICRF/J2000 and Ecliptic J2000.
 //!
 //! The rotation is a single-axis rotation about the X axis by the
-//! J2000 obliquity of the ecliptic.
+//! J2000 obliquity of the ecliptic. Also provides the combined
+//! bias-precession-nutation matrix from ICRS to the true equator and
+//! equinox of date.
 
-use crate::obliquity::{COS_OBL, SIN_OBL};
+use crate::nutation::nutation_iau2000b;
+use crate::obliquity::{COS_OBL, SIN_OBL, mean_obliquity_of_date_rad};
+use crate::precession::precess_ecliptic_j2000_to_date;
 
 /// Rotate a 3-vector from ICRF/J2000 equatorial to Ecliptic J2000.
 ///
@@ -34,6 +38,84 @@ pub fn ecliptic_to_icrf(v: &[f64; 3]) -> [f64; 3] {
     ]
 }
 
+/// ICRS frame bias, IERS Conventions 2003 (arcseconds): the offsets of the
+/// J2000 mean pole in longitude and obliquity, and the ICRS RA origin.
+const BIAS_DPSI_ARCSEC: f64 = -0.041_775;
+const BIAS_DEPS_ARCSEC: f64 = -0.006_819_2;
+const BIAS_DRA0_ARCSEC: f64 = -0.014_6;
+
+/// Rotate a 3-vector from ICRS to the J2000 mean equator and equinox.
+///
+/// `B = R1(-δε₀) · R2(δψ₀ sin ε₀) · R3(δα₀)`, a rotation of about 23 mas.
+/// [`icrf_to_ecliptic`] leaves it out, treating ICRF and J2000 as the same
+/// frame; [`precession_nutation_matrix`] applies it.
+fn frame_bias(v: &[f64; 3]) -> [f64; 3] {
+    let as2r = |a: f64| (a / 3600.0).to_radians();
+    let (s3, c3) = as2r(BIAS_DRA0_ARCSEC).sin_cos();
+    let (s2, c2) = (as2r(BIAS_DPSI_ARCSEC) * SIN_OBL).sin_cos();
+    let (s1, c1) = as2r(-BIAS_DEPS_ARCSEC).sin_cos();
+    // R3(δα₀)
+    let x = c3 * v[0] + s3 * v[1];
+    let y = -s3 * v[0] + c3 * v[1];
+    let z = v[2];
+    // R2(δψ₀ sin ε₀)
+    let (x, z) = (c2 * x - s2 * z, s2 * x + c2 * z);
+    // R1(-δε₀)
+    [x, c1 * y + s1 * z, -s1 * y + c1 * z]
+}
+
+/// Combined bias-precession-nutation matrix: ICRS to true equator of date.
+///
+/// `M = R1(-ε_true) · R3(-Δψ) · P · R1(ε₀) · B`, where `B` is the IERS 2003
+/// frame bias, `ε₀` the IAU 2006 J2000 obliquity (84381.406"), `P` the
+/// ecliptic precession of [`precess_ecliptic_j2000_to_date`], `Δψ`/`Δε` come
+/// from IAU 2000B nutation, and `ε_true = ε_A + Δε`. Build it once per epoch
+/// and apply it to many vectors with [`apply_matrix`].
+///
+/// `t` = Julian centuries of TDB since J2000.0.
+pub fn precession_nutation_matrix(t: f64) -> [[f64; 3]; 3] {
+    let (dpsi_arcsec, deps_arcsec) = nutation_iau2000b(t);
+    let dpsi = (dpsi_arcsec / 3600.0).to_radians();
+    let eps_true = mean_obliquity_of_date_rad(t) + (deps_arcsec / 3600.0).to_radians();
+    let (s_psi, c_psi) = (-dpsi).sin_cos();
+    let (s_eps, c_eps) = (-eps_true).sin_cos();
+    let (s_eps0, c_eps0) = mean_obliquity_of_date_rad(0.0).sin_cos();
+
+    let mut m = [[0.0; 3]; 3];
+    for j in 0..3 {
+        let mut e = [0.0; 3];
+        e[j] = 1.0;
+        let b = frame_bias(&e);
+        // R1(ε₀)
+        let ecl = [
+            b[0],
+            c_eps0 * b[1] + s_eps0 * b[2],
+            -s_eps0 * b[1] + c_eps0 * b[2],
+        ];
+        let p = precess_ecliptic_j2000_to_date(&ecl, t);
+        // R3(-Δψ)
+        let x = c_psi * p[0] + s_psi * p[1];
+        let y = -s_psi * p[0] + c_psi * p[1];
+        let z = p[2];
+        // R1(-ε_true)
+        let col = [x, c_eps * y + s_eps * z, -s_eps * y + c_eps * z];
+        for i in 0..3 {
+            m[i][j] = col[i];
+        }
+    }
+    m
+}
+
+/// Apply a 3x3 rotation matrix to a 3-vector.
+#[inline]
+pub fn apply_matrix(m: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,4 +154,49 @@ mod tests {
         let r_ecl = (ecl[0] * ecl[0] + ecl[1] * ecl[1] + ecl[2] * ecl[2]).sqrt();
         assert!((r_orig - r_ecl).abs() < EPS * r_orig);
     }
+
+    #[test]
+    fn precession_nutation_matches_meeus_theta_persei() {
+        // Meeus, Astronomical Algorithms, examples 21.b and 23.a: theta Persei
+        // (J2000 place with proper motion to the date) on 2028 Nov 13.19 TD.
+        // Mean place of date plus nutation (dα = +15.843", dδ = +6.218"),
+        // before aberration. Meeus uses IAU 1976 precession; the IAU 2000
+        // rate corrections plus the frame bias move the place by about 0.1"
+        // over these 29 years, hence the 0.2" tolerance.
+        let t = (2_462_088.69 - 2_451_545.0) / 36_525.0;
+        let (ra0, dec0) = (41.054_063_f64.to_radians(), 49.227_750_f64.to_radians());
+        let v = [dec0.cos() * ra0.cos(), dec0.cos() * ra0.sin(), dec0.sin()];
+
+        let got = apply_matrix(&precession_nutation_matrix(t), &v);
+        let ra = got[1].atan2(got[0]).to_degrees();
+        let dec = got[2].asin().to_degrees();
+
+        let want_ra = 41.547_214 + 15.843 / 3600.0;
+        let want_dec = 49.348_483 + 6.218 / 3600.0;
+        let tol = 0.2 / 3600.0;
+        assert!((ra - want_ra).abs() < tol, "ra = {ra}, want {want_ra}");
+        assert!((dec - want_dec).abs() < tol, "dec = {dec}, want {want_dec}");
+    }
+
+    #[test]
+    fn precession_nutation_matrix_is_orthonormal() {
+        let m = precession_nutation_matrix(-1.5);
+        for i in 0..3 {
+            for j in 0..3 {
+                let dot: f64 = (0..3).map(|k| m[i][k] * m[j][k]).sum();
+                let want = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - want).abs() < 1e-14, "row {i}.{j} = {dot}");
+            }
+        }
+    }
+
+    #[test]
+    fn precession_moves_equinox_by_about_50_arcsec_per_year() {
+        // After a century the J2000 equinox has gained ~1.40 deg of ecliptic
+        // longitude, about 1.28 deg of right ascension, on the new equinox.
+        let m = precession_nutation_matrix(1.0);
+        let x = apply_matrix(&m, &[1.0, 0.0, 0.0]);
+        let ra_deg = x[1].atan2(x[0]).to_degrees();
+        assert!((ra_deg - 1.28).abs() < 0.02, "ra = {ra_deg}");
+    }
 }
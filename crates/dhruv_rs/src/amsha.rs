@@ -3,15 +3,18 @@ use dhruv_time::{EopKernel, UtcTime};
 pub use dhruv_vedic_base::{
     ALL_AMSHAS, Amsha, AmshaRequest, AmshaVariationCatalog, AmshaVariationCode, AmshaVariationInfo,
     D2_CANCER_LEO_ONLY_VARIATION_CODE, DEFAULT_AMSHA_VARIATION_CODE, RashiElement, RashiInfo,
-    SHODASHAVARGA, amsha_from_rashi_position, amsha_longitude, amsha_longitudes, amsha_rashi_info,
-    amsha_rashi_infos, amsha_variation_by_name, amsha_variation_catalog, amsha_variation_info,
-    amsha_variations, default_amsha_variation, rashi_element, rashi_position_to_longitude,
+    SHODASHAVARGA, VargottamaStatus, amsha_from_rashi_position, amsha_longitude, amsha_longitudes,
+    amsha_rashi_info, amsha_rashi_infos, amsha_variation_by_name, amsha_variation_catalog,
+    amsha_variation_info, amsha_variations, default_amsha_variation, rashi_element,
+    rashi_position_to_longitude, vargottama_status,
 };
 
 use crate::date::UtcDate;
 use crate::{AyanamshaSystem, BhavaConfig, DhruvContext, DhruvError, GeoLocation, RiseSetConfig};
 
-pub use dhruv_search::{AmshaChart, AmshaChartScope, AmshaResult, AmshaSelectionConfig};
+pub use dhruv_search::{
+    AmshaChart, AmshaChartScope, AmshaEntry, AmshaResult, AmshaSelectionConfig, NavamsaChart,
+};
 
 pub fn amsha_variations_many(amshas: &[Amsha]) -> Vec<AmshaVariationCatalog> {
     amshas
@@ -133,6 +136,26 @@ pub fn chart(
     )
 }
 
+/// Compute the navamsa (D9) chart: lagna and nine grahas with their rasi positions.
+pub fn navamsa_chart(
+    ctx: &DhruvContext,
+    eop: &EopKernel,
+    date: UtcDate,
+    location: &GeoLocation,
+    system: AyanamshaSystem,
+    use_nutation: bool,
+) -> Result<NavamsaChart, DhruvError> {
+    let utc: UtcTime = date.into();
+    let aya_config = SankrantiConfig::new(system, use_nutation);
+    Ok(dhruv_search::navamsa_chart(
+        ctx.engine(),
+        eop,
+        &utc,
+        location,
+        &aya_config,
+    )?)
+}
+
 pub use dhruv_vedic_base::{
     ALL_AMSHAS as ALL, SHODASHAVARGA as SHODASHA, amsha_longitude as longitude,
     amsha_longitudes as longitudes, amsha_rashi_info as rashi_info,
@@ -0,0 +1,106 @@
+//! Small UI-oriented helpers composed from the operation APIs.
+
+use dhruv_search::{
+    LimbTarget, LunarPhaseKind, LunarPhaseResult, SankrantiConfig, elongation_at,
+    next_elongation_target,
+};
+use dhruv_vedic_ops::SearchError;
+
+use crate::context::DhruvContext;
+use crate::date::UtcDate;
+use crate::error::DhruvError;
+use crate::ops::{LunarPhaseRequest, LunarPhaseRequestQuery, TimeInput, lunar_phase};
+
+/// Events this close to the query instant (days, one second) count as "now",
+/// so a query at an amavasya has age 0 and looks past it for the next phase.
+const PHASE_EPSILON_DAYS: f64 = 1.0 / 86_400.0;
+
+/// Principal (quarter) phases of the Moon, by Moon-Sun elongation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoonPhase {
+    /// Amavasya / new moon, elongation 0 deg.
+    Amavasya,
+    /// First quarter, elongation 90 deg.
+    FirstQuarter,
+    /// Purnima / full moon, elongation 180 deg.
+    Purnima,
+    /// Last quarter, elongation 270 deg.
+    LastQuarter,
+}
+
+impl MoonPhase {
+    const ALL: [Self; 4] = [
+        Self::Amavasya,
+        Self::FirstQuarter,
+        Self::Purnima,
+        Self::LastQuarter,
+    ];
+
+    /// Moon-Sun elongation at this phase, in degrees.
+    pub const fn elongation_deg(self) -> f64 {
+        match self {
+            Self::Amavasya => 0.0,
+            Self::FirstQuarter => 90.0,
+            Self::Purnima => 180.0,
+            Self::LastQuarter => 270.0,
+        }
+    }
+}
+
+/// Moon's age and the countdown to the next principal phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoonAge {
+    /// Days since the previous amavasya (new moon).
+    pub age_days: f64,
+    /// The next quarter phase after the query instant, with its time.
+    pub next_phase: (MoonPhase, UtcDate),
+}
+
+/// Moon's age in days at `date`, and the next quarter phase.
+///
+/// Built on the lunar-phase search: age is measured from the previous
+/// amavasya. The next phase is the next multiple of 90 deg that the Moon-Sun
+/// elongation reaches, so it may be a first or last quarter as well as an
+/// amavasya or purnima.
+pub fn moon_age(ctx: &DhruvContext, date: UtcDate) -> Result<MoonAge, DhruvError> {
+    let engine = ctx.engine();
+    let lsk = engine.lsk();
+    let at_jd = dhruv_time::UtcTime::from(date).to_jd_tdb(lsk);
+    let after = at_jd + PHASE_EPSILON_DAYS;
+
+    let prev_amavasya = match lunar_phase(
+        ctx,
+        &LunarPhaseRequest {
+            kind: LunarPhaseKind::Amavasya,
+            query: LunarPhaseRequestQuery::Prev {
+                at: TimeInput::JdTdb(after),
+            },
+        },
+    )? {
+        LunarPhaseResult::Single(Some(event)) => event.utc.to_jd_tdb(lsk),
+        _ => {
+            return Err(DhruvError::Search(SearchError::NoConvergence(
+                "moon_age: previous amavasya not found",
+            )));
+        }
+    };
+
+    let quarter = (elongation_at(engine, after)? / 90.0).floor() as usize;
+    let phase = MoonPhase::ALL[(quarter + 1) % 4];
+    // The tithi limb is Moon minus Sun, so the ayanamsha is not used.
+    let event = next_elongation_target(
+        engine,
+        LimbTarget::Tithi,
+        phase.elongation_deg(),
+        after,
+        &SankrantiConfig::default_lahiri(),
+    )?
+    .ok_or(DhruvError::Search(SearchError::NoConvergence(
+        "moon_age: next quarter phase not found",
+    )))?;
+
+    Ok(MoonAge {
+        age_days: (at_jd - prev_amavasya).max(0.0),
+        next_phase: (phase, event.utc.into()),
+    })
+}
@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use dhruv_time::UtcTime;
+use dhruv_time::{UtcTime, parse_utc_offset};
 
 use crate::DhruvError;
 
@@ -43,61 +43,96 @@ impl Display for UtcDate {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let whole = self.sec as u32;
         let frac = self.sec - whole as f64;
+        if self.year < 0 {
+            write!(f, "-{:04}", self.year.unsigned_abs())?;
+        } else {
+            write!(f, "{:04}", self.year)?;
+        }
         if frac.abs() < 1e-9 {
             write!(
                 f,
-                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-                self.year, self.month, self.day, self.hour, self.min, whole
+                "-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+                self.month, self.day, self.hour, self.min, whole
             )
         } else {
             write!(
                 f,
-                "{:04}-{:02}-{:02}T{:02}:{:02}:{:09.6}Z",
-                self.year, self.month, self.day, self.hour, self.min, self.sec
+                "-{:02}-{:02}T{:02}:{:02}:{:09.6}Z",
+                self.month, self.day, self.hour, self.min, self.sec
             )
         }
     }
 }
 
-/// Parse ISO 8601 subset: `YYYY-MM-DDTHH:MM:SS[.f]Z`
+/// Parse ISO 8601 subset: `YYYY-MM-DDTHH:MM:SS[.f]Z` or
+/// `YYYY-MM-DDTHH:MM:SS[.f]±HH:MM`
 ///
-/// Supports fractional seconds. The trailing `Z` is required.
+/// The year may carry a sign and more than four digits (ISO 8601 expanded
+/// form), using astronomical numbering: `0000` is 1 BCE, `-0001` is 2 BCE.
+///
+/// Supports fractional seconds. A zone designator is required: either `Z`,
+/// or a numeric offset, in which case the local time is normalized to UTC
+/// (rolling the date as needed).
 impl FromStr for UtcDate {
     type Err = DhruvError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let err = || DhruvError::DateParse(format!("invalid ISO 8601 date: {s}"));
 
-        let bytes = s.as_bytes();
+        if !s.is_ascii() {
+            return Err(err());
+        }
 
-        // Minimum: "YYYY-MM-DDTHH:MM:SSZ" = 20 chars
-        if bytes.len() < 20 {
+        // Year: optional sign, then digits up to the month separator
+        // (astronomical numbering, so "-0001" is 2 BCE).
+        let (sign, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let year_len = unsigned.find('-').ok_or_else(err)?;
+        let year_digits = &unsigned[..year_len];
+        if year_digits.is_empty() || !year_digits.bytes().all(|b| b.is_ascii_digit()) {
             return Err(err());
         }
+        let year: i32 = sign * parse_int(year_digits).ok_or_else(err)?;
 
-        // Must end with 'Z'
-        if bytes[bytes.len() - 1] != b'Z' {
+        // Remainder: "-MM-DDTHH:MM:SS[.f]" plus the zone, at least 16 chars
+        let rest = &unsigned[year_len..];
+        let bytes = rest.as_bytes();
+        if bytes.len() < 16 {
             return Err(err());
         }
 
         // Fixed separators
-        if bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' || bytes[16] != b':' {
+        if bytes[3] != b'-' || bytes[9] != b':' || bytes[12] != b':' {
             return Err(err());
         }
 
         // 'T' or ' ' separator between date and time
-        if bytes[10] != b'T' && bytes[10] != b' ' {
+        if bytes[6] != b'T' && bytes[6] != b' ' {
             return Err(err());
         }
 
-        let year: i32 = parse_int(&s[0..4]).ok_or_else(err)?;
-        let month: u32 = parse_uint(&s[5..7]).ok_or_else(err)?;
-        let day: u32 = parse_uint(&s[8..10]).ok_or_else(err)?;
-        let hour: u32 = parse_uint(&s[11..13]).ok_or_else(err)?;
-        let min: u32 = parse_uint(&s[14..16]).ok_or_else(err)?;
-
-        // Seconds: everything between index 17 and the trailing 'Z'
-        let sec_str = &s[17..bytes.len() - 1];
+        // Zone designator: 'Z' or a '+'/'-' offset after the seconds
+        let zone_at = rest[14..]
+            .find(['Z', '+', '-'])
+            .map(|i| i + 14)
+            .ok_or_else(err)?;
+        let offset_minutes = match &rest[zone_at..] {
+            "Z" => 0,
+            zone if zone.starts_with(['+', '-']) => {
+                parse_utc_offset(zone).map_err(|e| DhruvError::DateParse(format!("{s}: {e}")))?
+            }
+            _ => return Err(err()),
+        };
+
+        let month: u32 = parse_uint(&rest[1..3]).ok_or_else(err)?;
+        let day: u32 = parse_uint(&rest[4..6]).ok_or_else(err)?;
+        let hour: u32 = parse_uint(&rest[7..9]).ok_or_else(err)?;
+        let min: u32 = parse_uint(&rest[10..12]).ok_or_else(err)?;
+
+        // Seconds: everything between the minute separator and the zone designator
+        let sec_str = &rest[13..zone_at];
         let sec: f64 = sec_str.parse().map_err(|_| err())?;
 
         if !(1..=12).contains(&month) {
@@ -110,14 +145,8 @@ impl FromStr for UtcDate {
             return Err(err());
         }
 
-        Ok(UtcDate {
-            year,
-            month,
-            day,
-            hour,
-            min,
-            sec,
-        })
+        let local = UtcTime::new(year, month, day, hour, min, sec);
+        Ok(local.local_to_utc(offset_minutes).into())
     }
 }
 
@@ -176,11 +205,58 @@ mod tests {
         assert_eq!(d.year, -500);
     }
 
+    #[test]
+    fn parse_signed_variable_width_years() {
+        let d: UtcDate = "-0001-12-31T12:00:00Z".parse().unwrap();
+        assert_eq!((d.year, d.month, d.day, d.hour), (-1, 12, 31, 12));
+        assert_eq!(d.to_string(), "-0001-12-31T12:00:00Z");
+        assert_eq!("0000-01-01T00:00:00Z".parse::<UtcDate>().unwrap().year, 0);
+        assert_eq!(
+            "+12000-01-01T00:00:00Z".parse::<UtcDate>().unwrap().year,
+            12000
+        );
+        let d: UtcDate = "-13000-06-15T06:30:00+05:30".parse().unwrap();
+        assert_eq!((d.year, d.month, d.day, d.hour), (-13000, 6, 15, 1));
+        assert!("--0001-01-01T00:00:00Z".parse::<UtcDate>().is_err());
+        assert!("-01-01T00:00:00Z".parse::<UtcDate>().is_err());
+    }
+
     #[test]
     fn rejects_missing_z() {
         assert!("2024-03-20T12:00:00".parse::<UtcDate>().is_err());
     }
 
+    #[test]
+    fn parse_positive_offset() {
+        let d: UtcDate = "2024-03-20T12:00:00+05:30".parse().unwrap();
+        assert_eq!(d, UtcDate::new(2024, 3, 20, 6, 30, 0.0));
+    }
+
+    #[test]
+    fn parse_negative_offset_rolls_forward() {
+        let d: UtcDate = "2024-03-20T20:00:00.5-08:00".parse().unwrap();
+        assert_eq!(d, UtcDate::new(2024, 3, 21, 4, 0, 0.5));
+    }
+
+    #[test]
+    fn parse_offset_rolls_back_across_month() {
+        let d: UtcDate = "2024-03-01T03:00:00+05:30".parse().unwrap();
+        assert_eq!(d, UtcDate::new(2024, 2, 29, 21, 30, 0.0));
+    }
+
+    #[test]
+    fn rejects_malformed_offset() {
+        for bad in [
+            "2024-03-20T12:00:00+5:30",
+            "2024-03-20T12:00:00+05:30Z",
+            "2024-03-20T12:00:00+24:00",
+            "2024-03-20T12:00:00-08",
+        ] {
+            let e = bad.parse::<UtcDate>().unwrap_err();
+            assert!(e.to_string().contains("UTC offset"), "{bad}: {e}");
+        }
+    }
+
     #[test]
     fn rejects_too_short() {
         assert!("2024Z".parse::<UtcDate>().is_err());
@@ -5,33 +5,36 @@
 
 pub mod amsha;
 pub mod context;
+pub mod convenience;
 pub mod date;
 pub mod error;
 pub mod ops;
 
 pub use amsha::{
-    ALL, ALL_AMSHAS, Amsha, AmshaChart, AmshaChartScope, AmshaRequest, AmshaResult,
+    ALL, ALL_AMSHAS, Amsha, AmshaChart, AmshaChartScope, AmshaEntry, AmshaRequest, AmshaResult,
     AmshaSelectionConfig, AmshaVariationCatalog, AmshaVariationCode, AmshaVariationInfo,
-    D2_CANCER_LEO_ONLY_VARIATION_CODE, DEFAULT_AMSHA_VARIATION_CODE, RashiElement, SHODASHA,
-    SHODASHAVARGA, amsha_from_rashi_position, amsha_longitude, amsha_longitudes, amsha_rashi_info,
-    amsha_rashi_infos, amsha_variation_by_name, amsha_variation_catalog, amsha_variation_info,
-    amsha_variations, amsha_variations_many, chart as amsha_chart,
-    chart_for_date as amsha_chart_for_date, charts as amsha_charts,
+    D2_CANCER_LEO_ONLY_VARIATION_CODE, DEFAULT_AMSHA_VARIATION_CODE, NavamsaChart, RashiElement,
+    SHODASHA, SHODASHAVARGA, VargottamaStatus, amsha_from_rashi_position, amsha_longitude,
+    amsha_longitudes, amsha_rashi_info, amsha_rashi_infos, amsha_variation_by_name,
+    amsha_variation_catalog, amsha_variation_info, amsha_variations, amsha_variations_many,
+    chart as amsha_chart, chart_for_date as amsha_chart_for_date, charts as amsha_charts,
     charts_for_date as amsha_charts_for_date, default_amsha_variation, longitude as amsha_lon,
-    longitudes as amsha_lons, rashi_element, rashi_info as amsha_rashi,
-    rashi_position_to_longitude,
+    longitudes as amsha_lons, navamsa_chart, rashi_element, rashi_info as amsha_rashi,
+    rashi_position_to_longitude, vargottama_status,
 };
 pub use context::DhruvContext;
+pub use convenience::{MoonAge, MoonPhase, moon_age};
 pub use date::UtcDate;
 pub use error::DhruvError;
 pub use ops::{
     AvasthaRequest, AvasthaResult, AvasthaTarget, AyanamshaRequest, AyanamshaRequestMode,
     CharakarakaRequest, ConjunctionRequest, ConjunctionRequestQuery, FullKundaliRequest,
-    GrahanRequest, GrahanRequestQuery, LunarPhaseRequest, LunarPhaseRequestQuery, MotionRequest,
-    MotionRequestQuery, NodeRequest, PanchangRequest, SankrantiRequest, SankrantiRequestQuery,
-    TaraRequest, TimeInput, UpagrahaRequest, avastha_op, ayanamsha_op, charakaraka, conjunction,
-    full_kundali, grahan, lunar_node_op, lunar_phase, motion, panchang_op, sankranti, tara_op,
-    upagraha_op,
+    GrahaSpeedsRequest, GrahanRequest, GrahanRequestQuery, LunarPhaseRequest,
+    LunarPhaseRequestQuery, MotionRequest, MotionRequestQuery, NodeRequest, PanchangRequest,
+    SankrantiRequest, SankrantiRequestQuery, SphutaRequest, TaraRequest, TimeInput,
+    UpagrahaRequest, avastha_op, ayanamsha_op, charakaraka, conjunction, full_kundali,
+    graha_speeds_op, grahan, lunar_node_op, lunar_phase, motion, panchang_op, sankranti, sphuta_op,
+    tara_op, upagraha_op,
 };
 
 // Re-export core types so callers don't need to depend on dhruv_core directly.
@@ -40,54 +43,72 @@ pub use dhruv_core::{
 };
 
 // Re-export commonly used config/result types.
-pub use dhruv_frames::{ReferencePlane, SphericalCoords, SphericalState};
+pub use dhruv_frames::{
+    DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane, SphericalCoords, SphericalState,
+};
 pub use dhruv_search::conjunction_types::{ConjunctionConfig, ConjunctionEvent};
 pub use dhruv_search::grahan_types::{
     ChandraGrahan, ChandraGrahanType, GrahanConfig, SuryaGrahan, SuryaGrahanType,
 };
-pub use dhruv_search::sankranti_types::{SankrantiConfig, SankrantiEvent};
+pub use dhruv_search::heliacal_types::{
+    HeliacalEvent, HeliacalEventConfig, HeliacalKind, HeliacalTarget,
+};
+pub use dhruv_search::sankranti_types::{NakshatraIngressEvent, SankrantiConfig, SankrantiEvent};
 pub use dhruv_search::stationary_types::{
-    MaxSpeedEvent, MaxSpeedType, StationType, StationaryConfig, StationaryEvent,
+    MaxSpeedEvent, MaxSpeedType, NodeStationEvent, RetrogradeInterval, StationType,
+    StationaryConfig, StationaryEvent,
 };
 pub use dhruv_search::{
     BalaBundleResult, DashaSelectionConfig, DashaSnapshotTime, FullKundaliConfig,
-    FullKundaliResult, MovingOsculatingApogeeEntry, MovingOsculatingApogees, ShadbalaEntry,
-    ShadbalaResult, VimsopakaEntry, VimsopakaResult, avastha_for_date, avastha_for_graha,
-    balas_for_date, bhavabala_for_bhava, bhavabala_for_date, full_kundali_for_date,
-    moving_osculating_apogees, moving_osculating_apogees_for_date, shadbala_for_date,
-    shadbala_for_graha, vimsopaka_for_date, vimsopaka_for_graha,
+    FullKundaliResult, GrahaLongitudeKind, GrahaLongitudes, GrahaLongitudesConfig, IngressEvent,
+    MovingOsculatingApogeeEntry, MovingOsculatingApogees, PANCHANG_DIFF_TOLERANCE_S, PanchangDiff,
+    PanchangInfo, PanchangLimb, PanchangLimbDiff, ShadbalaEntry, ShadbalaResult,
+    TransitAshtakavargaConfig, TransitBindus, TransitHouseMode, VimsopakaEntry, VimsopakaResult,
+    avastha_for_date, avastha_for_graha, balas_for_date, bhavabala_for_bhava, bhavabala_for_date,
+    full_kundali_for_date, graha_longitudes, moon_rashi_ingresses, moving_osculating_apogees,
+    moving_osculating_apogees_for_date, next_nakshatra_ingress, next_node_station, panchang_diff,
+    retrograde_intervals, shadbala_for_date, shadbala_for_graha, transit_bindus,
+    vimsopaka_for_date, vimsopaka_for_graha,
 };
 pub use dhruv_search::{
     ConjunctionResult, GrahanKind, GrahanResult, LunarPhaseKind, LunarPhaseResult, MotionKind,
     MotionResult, SankrantiResult, SankrantiTarget,
 };
+pub use dhruv_search::{
+    next_heliacal_rising, next_heliacal_setting, prev_heliacal_rising, prev_heliacal_setting,
+    search_heliacal_events,
+};
 pub use dhruv_tara::{
     EarthState, EquatorialPosition, TaraAccuracy, TaraCatalog, TaraConfig, TaraError, TaraId,
 };
-pub use dhruv_time::{EopKernel, TimeConversionOptions, TimeConversionPolicy, UtcTime};
+pub use dhruv_time::{EopKernel, LocalTime, TimeConversionOptions, TimeConversionPolicy, UtcTime};
 pub use dhruv_vedic_base::dasha::{
     DashaEntity, DashaHierarchy, DashaLevel, DashaPeriod, DashaSnapshot, DashaSystem,
-    DashaVariationConfig, SubPeriodMethod, YoginiScheme,
+    DashaVariationConfig, SubPeriodMethod, YoginiScheme, nakshatra_dasha_lord,
 };
 pub use dhruv_vedic_base::riseset_types::{
     GeoLocation, RiseSetConfig, RiseSetEvent, RiseSetResult,
 };
 pub use dhruv_vedic_base::{
     AllGrahaAvasthas, AllUpagrahas, AshtakavargaResult, AyanamshaSystem, BhavaBalaBirthPeriod,
-    BhavaBalaEntry, BhavaBalaInputs, BhavaBalaResult, BhavaConfig, BhinnaAshtakavarga,
-    CharakarakaEntry, CharakarakaResult, CharakarakaRole, CharakarakaScheme, Graha, GrahaAvasthas,
-    GulikaMaandiPlanet, LunarNode, NodeDignityPolicy, NodeMode, SarvaAshtakavarga,
+    BhavaBalaEntry, BhavaBalaInputs, BhavaBalaResult, BhavaConfig, BhavaResult, BhinnaAshtakavarga,
+    CharakarakaEntry, CharakarakaResult, CharakarakaRole, CharakarakaScheme, CustomAyanamsha,
+    GandantaHalf, GandantaKind, GandantaStatus, Graha, GrahaAvasthas, GulikaMaandiPlanet,
+    LunarNode, NavataraGroup, NodeDignityPolicy, NodeMode, PanchadhaMaitri, SarvaAshtakavarga,
     TimeUpagrahaConfig, TimeUpagrahaPoint, calculate_all_bav, calculate_ashtakavarga,
-    calculate_bav, calculate_bhava_bala, calculate_sav,
+    calculate_bav, calculate_bhava_bala, calculate_sav, duration_to_ghatika, gandanta_status,
+    ghatika_to_duration, kp_sublord, navatara_from, node_speed_deg_per_day, rashi_drishti,
+    rashi_drishti_matrix, relationship_matrix, whole_sign_from_lagna,
 };
 pub use dhruv_vedic_ops::{
-    NodeBackend, PANCHANG_INCLUDE_ALL, PANCHANG_INCLUDE_ALL_CALENDAR, PANCHANG_INCLUDE_ALL_CORE,
-    PANCHANG_INCLUDE_AYANA, PANCHANG_INCLUDE_GHATIKA, PANCHANG_INCLUDE_HORA,
-    PANCHANG_INCLUDE_KARANA, PANCHANG_INCLUDE_MASA, PANCHANG_INCLUDE_NAKSHATRA,
-    PANCHANG_INCLUDE_TITHI, PANCHANG_INCLUDE_VAAR, PANCHANG_INCLUDE_VARSHA, PANCHANG_INCLUDE_YOGA,
-    PanchangResult, SphutalResult, TaraOutputKind, TaraResult, dasha_child_period_for_birth,
-    dasha_children_for_birth, dasha_complete_level_for_birth, dasha_hierarchy_for_birth,
-    dasha_level0_entity_for_birth, dasha_level0_for_birth, dasha_snapshot_at,
+    DashaRangePeriod, NodeBackend, PANCHANG_INCLUDE_ALL, PANCHANG_INCLUDE_ALL_CALENDAR,
+    PANCHANG_INCLUDE_ALL_CORE, PANCHANG_INCLUDE_AYANA, PANCHANG_INCLUDE_GHATIKA,
+    PANCHANG_INCLUDE_HORA, PANCHANG_INCLUDE_KARANA, PANCHANG_INCLUDE_MASA,
+    PANCHANG_INCLUDE_NAKSHATRA, PANCHANG_INCLUDE_TITHI, PANCHANG_INCLUDE_VAAR,
+    PANCHANG_INCLUDE_VARSHA, PANCHANG_INCLUDE_YOGA, PanchangResult, SphutalResult, TaraOutputKind,
+    TaraResult, dasha_child_period_for_birth, dasha_children_for_birth,
+    dasha_complete_level_for_birth, dasha_hierarchy_for_birth, dasha_level0_entity_for_birth,
+    dasha_level0_for_birth, dasha_snapshot_at, dasha_transitions_in_range,
 };
 
 fn utc_from_jd_utc(jd_utc: f64) -> UtcTime {
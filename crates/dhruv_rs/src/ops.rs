@@ -4,25 +4,27 @@
 //! operations map to `dhruv_vedic_ops`.
 
 use dhruv_core::Body;
+use dhruv_frames::PrecessionModel;
 use dhruv_search::{
     AmshaSelectionConfig, ConjunctionConfig, ConjunctionOperation, ConjunctionQuery,
-    ConjunctionResult, GrahanConfig, GrahanKind, GrahanOperation, GrahanQuery, GrahanResult,
-    LunarPhaseKind, LunarPhaseOperation, LunarPhaseQuery, LunarPhaseResult, MotionKind,
-    MotionOperation, MotionQuery, MotionResult, SankrantiConfig, SankrantiOperation,
-    SankrantiQuery, SankrantiResult, SankrantiTarget, StationaryConfig,
+    ConjunctionResult, GrahaLongitudesConfig, GrahanConfig, GrahanKind, GrahanOperation,
+    GrahanQuery, GrahanResult, LunarPhaseKind, LunarPhaseOperation, LunarPhaseQuery,
+    LunarPhaseResult, MotionKind, MotionOperation, MotionQuery, MotionResult, SankrantiConfig,
+    SankrantiOperation, SankrantiQuery, SankrantiResult, SankrantiTarget, StationaryConfig,
     all_upagrahas_for_date_with_config, avastha_for_date, avastha_for_graha, full_kundali_for_date,
+    graha_speeds, sphuta_inputs_for_date,
 };
 use dhruv_search::{FullKundaliConfig, FullKundaliResult};
 use dhruv_tara::{EarthState, TaraCatalog, TaraConfig, TaraId};
 use dhruv_time::{EopKernel, UtcTime, calendar_to_jd, jd_to_tdb_seconds, tdb_seconds_to_jd};
 use dhruv_vedic_base::{
     AllGrahaAvasthas, AllUpagrahas, AyanamshaSystem, BhavaConfig, CharakarakaResult,
-    CharakarakaScheme, GeoLocation, Graha, GrahaAvasthas, LunarNode, NodeDignityPolicy, NodeMode,
-    RiseSetConfig, TimeUpagrahaConfig,
+    CharakarakaScheme, CustomAyanamsha, GeoLocation, Graha, GrahaAvasthas, LunarNode,
+    NodeDignityPolicy, NodeMode, RiseSetConfig, TimeUpagrahaConfig,
 };
 use dhruv_vedic_ops::{
     AyanamshaMode, AyanamshaOperation, NodeBackend, NodeOperation, PanchangOperation,
-    PanchangResult, TaraOperation, TaraOutputKind, TaraResult,
+    PanchangResult, SphutalResult, TaraOperation, TaraOutputKind, TaraResult,
 };
 
 use crate::context::DhruvContext;
@@ -374,17 +376,25 @@ pub enum AyanamshaRequestMode {
     True { delta_psi_arcsec: f64 },
     /// Unified ayanamsha with `use_nutation`.
     Unified { use_nutation: bool },
+    /// Instantaneous mean-ayanamsha rate, in degrees per Julian century.
+    Rate,
 }
 
 /// Unified ayanamsha request.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct AyanamshaRequest {
     pub system: AyanamshaSystem,
+    /// User-defined anchor that overrides `system` when set.
+    pub custom_ayanamsha: Option<CustomAyanamsha>,
     pub at: TimeInput,
     pub mode: AyanamshaRequestMode,
+    /// Precession model used to carry the anchor to the epoch.
+    pub precession_model: PrecessionModel,
 }
 
 /// Execute a unified ayanamsha operation.
+///
+/// Returns degrees, or degrees per Julian century for `Rate`.
 pub fn ayanamsha_op(ctx: &DhruvContext, request: &AyanamshaRequest) -> Result<f64, DhruvError> {
     let (mode, use_nutation, delta_psi_arcsec) = match request.mode {
         AyanamshaRequestMode::Mean => (AyanamshaMode::Mean, false, 0.0),
@@ -394,13 +404,16 @@ pub fn ayanamsha_op(ctx: &DhruvContext, request: &AyanamshaRequest) -> Result<f6
         AyanamshaRequestMode::Unified { use_nutation } => {
             (AyanamshaMode::Unified, use_nutation, 0.0)
         }
+        AyanamshaRequestMode::Rate => (AyanamshaMode::Rate, false, 0.0),
     };
     let op = AyanamshaOperation {
         system: request.system,
+        custom_ayanamsha: request.custom_ayanamsha,
         mode,
         at_jd_tdb: time_input_to_jd_tdb(ctx, request.at),
         use_nutation,
         delta_psi_arcsec,
+        precession_model: request.precession_model,
     };
     Ok(dhruv_vedic_ops::ayanamsha(&op)?)
 }
@@ -426,6 +439,25 @@ pub fn lunar_node_op(ctx: &DhruvContext, request: &NodeRequest) -> Result<f64, D
     Ok(dhruv_vedic_ops::lunar_node(eng, &op)?)
 }
 
+/// Graha speed request.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrahaSpeedsRequest {
+    pub at: TimeInput,
+    /// Frame of the speeds; the same config `graha_longitudes` takes.
+    pub config: GrahaLongitudesConfig,
+}
+
+/// Longitude speeds (deg/day) of all 9 grahas, in Graha order.
+///
+/// Negative values mean retrograde motion; Rahu/Ketu are normally negative.
+pub fn graha_speeds_op(
+    ctx: &DhruvContext,
+    request: &GrahaSpeedsRequest,
+) -> Result<[f64; 9], DhruvError> {
+    let jd_tdb = time_input_to_jd_tdb(ctx, request.at);
+    Ok(graha_speeds(ctx.engine(), jd_tdb, &request.config)?)
+}
+
 /// Unified panchang request.
 #[derive(Debug, Clone, PartialEq)]
 pub struct PanchangRequest {
@@ -566,6 +598,8 @@ pub fn tara_op(
 pub struct CharakarakaRequest {
     pub at: TimeInput,
     pub system: AyanamshaSystem,
+    /// User-defined anchor that overrides `system` when set.
+    pub custom_ayanamsha: Option<CustomAyanamsha>,
     pub use_nutation: bool,
     pub scheme: CharakarakaScheme,
 }
@@ -577,7 +611,10 @@ pub fn charakaraka(
     request: &CharakarakaRequest,
 ) -> Result<CharakarakaResult, DhruvError> {
     let utc = time_input_to_utc_for_context(ctx, request.at);
-    let aya_cfg = SankrantiConfig::new(request.system, request.use_nutation);
+    let mut aya_cfg = SankrantiConfig::new(request.system, request.use_nutation);
+    if let Some(custom) = request.custom_ayanamsha {
+        aya_cfg = aya_cfg.with_custom_ayanamsha(custom);
+    }
     Ok(dhruv_vedic_ops::charakaraka_for_date(
         ctx.engine(),
         eop,
@@ -618,6 +655,45 @@ pub fn upagraha_op(
     )?)
 }
 
+/// Unified sphuta request.
+#[derive(Debug, Clone, Copy)]
+pub struct SphutaRequest {
+    pub at: TimeInput,
+    pub location: GeoLocation,
+    pub riseset_config: Option<RiseSetConfig>,
+    pub sankranti_config: Option<SankrantiConfig>,
+    pub upagraha_config: Option<TimeUpagrahaConfig>,
+}
+
+/// Compute all 16 sphutas, with Gulika taken from the upagraha computation.
+pub fn sphuta_op(
+    ctx: &DhruvContext,
+    eop: &EopKernel,
+    request: &SphutaRequest,
+) -> Result<SphutalResult, DhruvError> {
+    let utc = time_input_to_utc_for_context(ctx, request.at);
+    let riseset_config = resolve_riseset_config(ctx, request.riseset_config)?;
+    let sankranti_config = resolve_sankranti_config(ctx, request.sankranti_config)?;
+    let upagraha_config = resolve_upagraha_config(ctx, request.upagraha_config)?;
+    let inputs = sphuta_inputs_for_date(
+        ctx.engine(),
+        eop,
+        &utc,
+        &request.location,
+        &riseset_config,
+        &sankranti_config,
+        &upagraha_config,
+    )?;
+    let mut longitudes = [0.0; 16];
+    for (slot, (_, lon)) in longitudes
+        .iter_mut()
+        .zip(dhruv_vedic_base::all_sphutas(&inputs))
+    {
+        *slot = lon;
+    }
+    Ok(SphutalResult { longitudes })
+}
+
 /// Which avastha output the caller wants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AvasthaTarget {
@@ -189,6 +189,27 @@ fn upagraha_op_runs() {
     assert!((0.0..360.0).contains(&out.maandi));
 }
 
+#[test]
+fn sphuta_op_runs() {
+    let Some(ctx) = make_context() else {
+        return;
+    };
+    let Some(eop) = load_eop() else {
+        return;
+    };
+
+    let request = SphutaRequest {
+        at: TimeInput::Utc(UtcDate::new(2024, 1, 15, 12, 0, 0.0)),
+        location: GeoLocation::new(28.6139, 77.2090, 0.0),
+        riseset_config: None,
+        sankranti_config: Some(SankrantiConfig::default_lahiri()),
+        upagraha_config: None,
+    };
+
+    let out = sphuta_op(&ctx, &eop, &request).expect("sphuta op should run");
+    assert!(out.longitudes.iter().all(|lon| (0.0..360.0).contains(lon)));
+}
+
 #[test]
 fn avastha_op_runs_for_single_graha() {
     let Some(ctx) = make_context() else {
@@ -261,3 +282,56 @@ fn full_kundali_op_runs() {
     assert!(out.avastha.is_some());
     assert!(out.graha_positions.is_none());
 }
+
+#[test]
+fn moon_age_counts_down_through_quarter_phases() {
+    let Some(ctx) = make_context() else {
+        return;
+    };
+    let phase_at = |kind, at| match lunar_phase(
+        &ctx,
+        &LunarPhaseRequest {
+            kind,
+            query: LunarPhaseRequestQuery::Next {
+                at: TimeInput::Utc(at),
+            },
+        },
+    )
+    .expect("lunar phase op should run")
+    {
+        LunarPhaseResult::Single(Some(event)) => UtcDate::from(event.utc),
+        other => panic!("expected single lunar phase, got {other:?}"),
+    };
+
+    let amavasya = phase_at(
+        LunarPhaseKind::Amavasya,
+        UtcDate::new(2024, 1, 1, 0, 0, 0.0),
+    );
+    let at_new = moon_age(&ctx, amavasya).expect("moon age at amavasya");
+    assert!(at_new.age_days < 1e-3, "age = {}", at_new.age_days);
+    assert_eq!(at_new.next_phase.0, MoonPhase::FirstQuarter);
+
+    let at_first = moon_age(&ctx, at_new.next_phase.1).expect("moon age at first quarter");
+    assert!(
+        (at_first.age_days - 7.4).abs() < 1.0,
+        "age = {}",
+        at_first.age_days
+    );
+    assert_eq!(at_first.next_phase.0, MoonPhase::Purnima);
+
+    let purnima = phase_at(LunarPhaseKind::Purnima, amavasya);
+    assert_eq!(at_first.next_phase.1.day, purnima.day);
+    let at_full = moon_age(&ctx, purnima).expect("moon age at purnima");
+    assert!(
+        (at_full.age_days - 14.75).abs() < 1.0,
+        "age = {}",
+        at_full.age_days
+    );
+    assert_eq!(at_full.next_phase.0, MoonPhase::LastQuarter);
+
+    let at_last = moon_age(&ctx, at_full.next_phase.1).expect("moon age at last quarter");
+    assert_eq!(at_last.next_phase.0, MoonPhase::Amavasya);
+    let next_amavasya = phase_at(LunarPhaseKind::Amavasya, purnima);
+    assert_eq!(at_last.next_phase.1.month, next_amavasya.month);
+    assert_eq!(at_last.next_phase.1.day, next_amavasya.day);
+}
@@ -1,14 +1,16 @@
 use std::path::{Path, PathBuf};
 
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use dhruv_core::{Engine, EngineConfig};
+use dhruv_core::{Body, Engine, EngineConfig, Frame, Observer, Query};
 use dhruv_search::{
-    AmshaChartScope, AmshaSelectionConfig, SankrantiConfig, next_purnima, next_sankranti,
+    AmshaChartScope, AmshaSelectionConfig, GrahaLongitudesConfig, SankrantiConfig,
+    bodies_lon_lat_on_plane, body_lon_lat_on_plane, graha_longitudes, next_purnima, next_sankranti,
 };
 use dhruv_time::{EopKernel, UtcTime};
 use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
 use dhruv_vedic_base::{
-    AmshaRequest, AyanamshaSystem, BhavaConfig, NodeDignityPolicy, SHODASHAVARGA,
+    AmshaRequest, AyanamshaSystem, BhavaConfig, GrahaBodyExt, NodeDignityPolicy, SAPTA_GRAHAS,
+    SHODASHAVARGA,
 };
 
 fn load_engine() -> Option<Engine> {
@@ -343,6 +345,79 @@ fn dasha_hierarchy_bench(c: &mut Criterion) {
     group.finish();
 }
 
+fn graha_longitudes_bench(c: &mut Criterion) {
+    let engine = match load_engine() {
+        Some(v) => v,
+        None => return,
+    };
+    let jd_tdb = 2_460_400.5;
+    let config = GrahaLongitudesConfig::sidereal(AyanamshaSystem::Lahiri, false);
+    let bodies: Vec<Body> = SAPTA_GRAHAS
+        .iter()
+        .map(|g| g.to_body().expect("sapta graha has body"))
+        .collect();
+
+    // Segment evaluations per chart: one query per body vs one shared batch.
+    let queries: Vec<Query> = bodies
+        .iter()
+        .map(|&target| Query {
+            target,
+            observer: Observer::Body(Body::Earth),
+            frame: Frame::IcrfJ2000,
+            epoch_tdb_jd: jd_tdb,
+        })
+        .collect();
+    let sequential: u32 = queries
+        .iter()
+        .map(|&q| {
+            let (_, stats) = engine.query_with_stats(q).expect("query should succeed");
+            stats.evaluations
+        })
+        .sum();
+    let (_, batched) = engine.query_batch_with_stats(&queries);
+    eprintln!(
+        "graha_longitudes segment evaluations per chart: sequential {sequential}, batched {}",
+        batched.evaluations
+    );
+
+    let mut group = c.benchmark_group("search_graha_longitudes");
+    group.bench_function("sapta_graha_batched_query", |b| {
+        b.iter(|| {
+            bodies_lon_lat_on_plane(
+                black_box(&engine),
+                &bodies,
+                black_box(jd_tdb),
+                config.precession_model,
+                config.reference_plane,
+            )
+            .expect("should succeed")
+        })
+    });
+    group.bench_function("graha_longitudes", |b| {
+        b.iter(|| {
+            graha_longitudes(black_box(&engine), black_box(jd_tdb), black_box(&config))
+                .expect("should succeed")
+        })
+    });
+    group.bench_function("sapta_graha_per_body_queries", |b| {
+        b.iter(|| {
+            for &body in &bodies {
+                black_box(
+                    body_lon_lat_on_plane(
+                        black_box(&engine),
+                        body,
+                        black_box(jd_tdb),
+                        config.precession_model,
+                        config.reference_plane,
+                    )
+                    .expect("should succeed"),
+                );
+            }
+        })
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     lunar_phase_bench,
@@ -351,6 +426,7 @@ criterion_group!(
     shadbala_for_date_bench,
     vimsopaka_for_date_bench,
     avastha_for_date_bench,
-    dasha_hierarchy_bench
+    dasha_hierarchy_bench,
+    graha_longitudes_bench
 );
 criterion_main!(benches);
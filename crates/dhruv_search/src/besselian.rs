@@ -0,0 +1,186 @@
+//! Besselian-element geometry for solar eclipse maps.
+//!
+//! Pure functions over published (or externally computed) Besselian
+//! elements: whether a site is inside the umbra/antumbra, and where the
+//! shadow axis meets the Earth (the central line). No ephemeris access.
+//!
+//! Sources: Explanatory Supplement to the Astronomical Almanac (1992)
+//! ch. 8; Meeus, "Elements of Solar Eclipses 1951-2200". See
+//! docs/clean_room_grahan.md.
+
+use crate::grahan_types::GeoLocation;
+
+/// Earth's first eccentricity squared (IAU 1976 ellipsoid, as used in
+/// published Besselian elements).
+const EARTH_E2: f64 = 0.006_694_38;
+
+/// Polar-to-equatorial radius ratio, sqrt(1 - e²).
+const EARTH_B_OVER_A: f64 = 0.996_647_19;
+
+/// Equatorial radius in meters, the unit of the fundamental plane.
+const EARTH_RADIUS_M: f64 = 6_378_140.0;
+
+/// Earth rotation in degrees per second of ΔT (1.002738 × 360 / 86400).
+const DEG_PER_DELTA_T_SEC: f64 = 0.004_178_07;
+
+/// Besselian elements of one solar eclipse.
+///
+/// Each polynomial is in `t` = hours from `t0_jd_tt`, lowest power first.
+/// Distances are in Earth equatorial radii, angles in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BesselianElements {
+    /// Reference epoch `t0` as a Julian Date in TT.
+    pub t0_jd_tt: f64,
+    /// ΔT = TT − UT1 in seconds, used to turn μ into a geographic longitude.
+    pub delta_t_s: f64,
+    /// Shadow axis x coordinate on the fundamental plane.
+    pub x: [f64; 4],
+    /// Shadow axis y coordinate on the fundamental plane.
+    pub y: [f64; 4],
+    /// Declination of the shadow axis (degrees).
+    pub d: [f64; 3],
+    /// Greenwich hour angle of the shadow axis (degrees).
+    pub mu: [f64; 3],
+    /// Penumbral radius on the fundamental plane.
+    pub l1: [f64; 3],
+    /// Umbral radius on the fundamental plane (negative for a total eclipse).
+    pub l2: [f64; 3],
+    /// Tangent of the penumbral cone half-angle.
+    pub tan_f1: f64,
+    /// Tangent of the umbral cone half-angle.
+    pub tan_f2: f64,
+}
+
+/// Elements evaluated at one instant.
+struct ElementsAt {
+    x: f64,
+    y: f64,
+    d_rad: f64,
+    mu_deg: f64,
+    l2: f64,
+}
+
+fn poly(coeffs: &[f64], t: f64) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, &c| acc * t + c)
+}
+
+impl BesselianElements {
+    fn at(&self, jd_tt: f64) -> ElementsAt {
+        let t = (jd_tt - self.t0_jd_tt) * 24.0;
+        ElementsAt {
+            x: poly(&self.x, t),
+            y: poly(&self.y, t),
+            d_rad: poly(&self.d, t).to_radians(),
+            mu_deg: poly(&self.mu, t),
+            l2: poly(&self.l2, t),
+        }
+    }
+}
+
+/// Whether `location` is inside the umbral (or antumbral) shadow at `jd_tt`.
+///
+/// Returns false when the Sun is below the site's horizon.
+pub fn is_in_umbra(besselian: &BesselianElements, location: &GeoLocation, jd_tt: f64) -> bool {
+    let e = besselian.at(jd_tt);
+    let phi = location.latitude_rad();
+    let u = (EARTH_B_OVER_A * phi.tan()).atan();
+    let h = location.altitude_m / EARTH_RADIUS_M;
+    let rho_sin = EARTH_B_OVER_A * u.sin() + h * phi.sin();
+    let rho_cos = u.cos() + h * phi.cos();
+
+    let hour_angle = (e.mu_deg + location.longitude_deg
+        - DEG_PER_DELTA_T_SEC * besselian.delta_t_s)
+        .to_radians();
+    let (sin_d, cos_d) = e.d_rad.sin_cos();
+    let xi = rho_cos * hour_angle.sin();
+    let eta = rho_sin * cos_d - rho_cos * hour_angle.cos() * sin_d;
+    let zeta = rho_sin * sin_d + rho_cos * hour_angle.cos() * cos_d;
+    if zeta <= 0.0 {
+        return false;
+    }
+
+    let radius = (e.l2 - zeta * besselian.tan_f2).abs();
+    (e.x - xi).hypot(e.y - eta) < radius
+}
+
+/// Geodetic (latitude, longitude) in degrees, east positive, where the
+/// shadow axis meets the Earth at `jd_tt`.
+///
+/// `None` when the axis misses the Earth.
+pub fn umbra_center_location(besselian: &BesselianElements, jd_tt: f64) -> Option<(f64, f64)> {
+    let e = besselian.at(jd_tt);
+    let (sin_d, cos_d) = e.d_rad.sin_cos();
+    let rho1 = (1.0 - EARTH_E2 * cos_d * cos_d).sqrt();
+    let sin_d1 = sin_d / rho1;
+    let cos_d1 = EARTH_B_OVER_A * cos_d / rho1;
+    let y1 = e.y / rho1;
+
+    let b = 1.0 - e.x * e.x - y1 * y1;
+    if b < 0.0 {
+        return None;
+    }
+    let zeta1 = b.sqrt();
+
+    let sin_phi1 = y1 * cos_d1 + zeta1 * sin_d1;
+    let theta = e.x.atan2(zeta1 * cos_d1 - y1 * sin_d1).to_degrees();
+    let lat = (sin_phi1.asin().tan() / EARTH_B_OVER_A).atan().to_degrees();
+    let lon_west = e.mu_deg - theta - DEG_PER_DELTA_T_SEC * besselian.delta_t_s;
+    let lon = (-lon_west + 180.0).rem_euclid(360.0) - 180.0;
+    Some((lat, lon))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2017 Aug 21 total solar eclipse (Espenak, NASA), t0 = 18:00 TT.
+    fn eclipse_2017() -> BesselianElements {
+        BesselianElements {
+            t0_jd_tt: 2_457_987.25,
+            delta_t_s: 70.3,
+            x: [-0.129_571, 0.540_642_6, -2.94e-5, -8.1e-6],
+            y: [0.485_416, -0.141_64, -9.05e-5, 2.05e-6],
+            d: [11.866_96, -0.013_622, -2.0e-6],
+            mu: [89.245_44, 15.003_93, 0.0],
+            l1: [0.542_093, 1.241e-4, -1.18e-5],
+            l2: [-0.004_025, 1.234e-4, -1.17e-5],
+            tan_f1: 0.004_622_2,
+            tan_f2: 0.004_599_2,
+        }
+    }
+
+    /// Greatest eclipse, 18:25:32 TT.
+    fn greatest_jd() -> f64 {
+        2_457_987.25 + (25.0 * 60.0 + 32.0) / 86_400.0
+    }
+
+    #[test]
+    fn center_matches_published_greatest_eclipse() {
+        // Published: 36°58'N, 87°40'W.
+        let (lat, lon) = umbra_center_location(&eclipse_2017(), greatest_jd()).unwrap();
+        assert!((lat - 36.97).abs() < 1.0, "lat = {lat}");
+        assert!((lon + 87.67).abs() < 1.0, "lon = {lon}");
+    }
+
+    #[test]
+    fn center_point_is_in_umbra() {
+        let be = eclipse_2017();
+        let jd = greatest_jd();
+        let (lat, lon) = umbra_center_location(&be, jd).unwrap();
+        assert!(is_in_umbra(&be, &GeoLocation::new(lat, lon, 0.0), jd));
+        // A few degrees off the path is outside the ~115 km wide umbra.
+        assert!(!is_in_umbra(
+            &be,
+            &GeoLocation::new(lat + 3.0, lon, 0.0),
+            jd
+        ));
+        // Night side.
+        assert!(!is_in_umbra(&be, &GeoLocation::new(28.6, 77.2, 0.0), jd));
+    }
+
+    #[test]
+    fn axis_misses_earth_before_first_contact() {
+        // Five hours before t0 the axis is well off the disk.
+        assert!(umbra_center_location(&eclipse_2017(), 2_457_987.25 - 5.0 / 24.0).is_none());
+    }
+}
@@ -0,0 +1,269 @@
+//! Stable cache key for chart inputs.
+//!
+//! `chart_key` hashes canonicalized chart inputs with 64-bit FNV-1a so the
+//! value is identical across runs, platforms, and compiler versions (unlike
+//! `std::hash::DefaultHasher`). Floating inputs are rounded first, so inputs
+//! that differ below the stated precision share a key:
+//!
+//! - time: whole milliseconds since 0000-03-01 (proleptic Gregorian, UTC);
+//!   overflowing fields such as `second = 60.0` fold into the next minute
+//! - latitude/longitude: micro-degrees (~0.1 m)
+//! - altitude: whole meters
+//! - ayanamsha/house system: fixed names listed in this module, so
+//!   reordering or adding enum variants leaves existing keys unchanged
+//! - custom ayanamsha anchor, when set: replaces the system name; the anchor
+//!   value in nano-degrees and the anchor epoch in whole milliseconds of JD
+//!
+//! The key is versioned by a fixed prefix; any change to this scheme must
+//! change the prefix.
+
+use dhruv_time::UtcTime;
+use dhruv_vedic_base::bhava_types::BhavaSystem;
+use dhruv_vedic_base::riseset_types::GeoLocation;
+use dhruv_vedic_base::{AyanamshaSystem, CustomAyanamsha};
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Prefix mixed into every key; bump when the canonical form changes.
+const KEY_VERSION: &[u8] = b"dhruv-chart-key-v3";
+
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_i64(&mut self, v: i64) {
+        self.write(&v.to_le_bytes());
+    }
+
+    /// Length-prefixed so adjacent strings cannot run together.
+    fn write_str(&mut self, s: &str) {
+        self.write_i64(s.len() as i64);
+        self.write(s.as_bytes());
+    }
+}
+
+/// Stable hash name of an ayanamsha system. Never rename an entry.
+fn ayanamsha_key_name(system: AyanamshaSystem) -> &'static str {
+    match system {
+        AyanamshaSystem::Lahiri => "lahiri",
+        AyanamshaSystem::TrueLahiri => "true_lahiri",
+        AyanamshaSystem::KP => "kp",
+        AyanamshaSystem::Raman => "raman",
+        AyanamshaSystem::FaganBradley => "fagan_bradley",
+        AyanamshaSystem::PushyaPaksha => "pushya_paksha",
+        AyanamshaSystem::RohiniPaksha => "rohini_paksha",
+        AyanamshaSystem::DeLuce => "deluce",
+        AyanamshaSystem::DjwalKhul => "djwal_khul",
+        AyanamshaSystem::Hipparchos => "hipparchos",
+        AyanamshaSystem::Sassanian => "sassanian",
+        AyanamshaSystem::DevaDutta => "deva_dutta",
+        AyanamshaSystem::UshaShashi => "usha_shashi",
+        AyanamshaSystem::Yukteshwar => "yukteshwar",
+        AyanamshaSystem::JnBhasin => "jn_bhasin",
+        AyanamshaSystem::ChandraHari => "chandra_hari",
+        AyanamshaSystem::Jagganatha => "jagganatha",
+        AyanamshaSystem::SuryaSiddhanta => "surya_siddhanta",
+        AyanamshaSystem::GalacticCenter0Sag => "galactic_center_0_sag",
+        AyanamshaSystem::Aldebaran15Tau => "aldebaran_15_tau",
+    }
+}
+
+/// Stable hash name of a bhava system. Never rename an entry.
+fn bhava_key_name(system: BhavaSystem) -> &'static str {
+    match system {
+        BhavaSystem::Equal => "equal",
+        BhavaSystem::SuryaSiddhanta => "surya_siddhanta",
+        BhavaSystem::Sripati => "sripati",
+        BhavaSystem::KP => "kp",
+        BhavaSystem::Koch => "koch",
+        BhavaSystem::Regiomontanus => "regiomontanus",
+        BhavaSystem::Campanus => "campanus",
+        BhavaSystem::AxialRotation => "axial_rotation",
+        BhavaSystem::Topocentric => "topocentric",
+        BhavaSystem::Alcabitus => "alcabitus",
+        BhavaSystem::Porphyry => "porphyry",
+    }
+}
+
+/// Days from 0000-03-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = year as i64 - i64::from(month <= 2);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe
+}
+
+/// Stable 64-bit key over canonicalized chart inputs, for memoizing
+/// `full_kundali_for_date` results.
+///
+/// `custom_ayanamsha`, when set, overrides `ayanamsha` as it does in
+/// `SankrantiConfig`. See the module docs for the rounding applied to each
+/// input.
+pub fn chart_key(
+    utc: &UtcTime,
+    location: &GeoLocation,
+    ayanamsha: AyanamshaSystem,
+    custom_ayanamsha: Option<CustomAyanamsha>,
+    house_system: BhavaSystem,
+    use_nutation: bool,
+) -> u64 {
+    let millis = days_from_civil(utc.year, utc.month, utc.day) * 86_400_000
+        + utc.hour as i64 * 3_600_000
+        + utc.minute as i64 * 60_000
+        + (utc.second * 1000.0).round() as i64;
+    let mut h = Fnv1a(FNV_OFFSET);
+    h.write(KEY_VERSION);
+    h.write_i64(millis);
+    h.write_i64((location.latitude_deg * 1e6).round() as i64);
+    h.write_i64((location.longitude_deg * 1e6).round() as i64);
+    h.write_i64(location.altitude_m.round() as i64);
+    match custom_ayanamsha {
+        Some(custom) => {
+            h.write_str("custom");
+            h.write_i64((custom.anchor_deg * 1e9).round() as i64);
+            h.write_i64((custom.anchor_jd_tdb * 86_400_000.0).round() as i64);
+        }
+        None => h.write_str(ayanamsha_key_name(ayanamsha)),
+    }
+    h.write_str(bhava_key_name(house_system));
+    h.write(&[use_nutation as u8]);
+    h.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PINNED_KEY: u64 = 0xb32a_5682_bc06_4e7c;
+
+    fn key_at(second: f64) -> u64 {
+        chart_key(
+            &UtcTime::new(1990, 5, 17, 6, 30, second),
+            &GeoLocation::new(28.6139, 77.2090, 216.0),
+            AyanamshaSystem::Lahiri,
+            None,
+            BhavaSystem::Equal,
+            true,
+        )
+    }
+
+    #[test]
+    fn identical_inputs_hash_equal() {
+        assert_eq!(key_at(12.0), key_at(12.0));
+        // Below the millisecond rounding.
+        assert_eq!(key_at(12.0), key_at(12.0001));
+    }
+
+    #[test]
+    fn key_is_pinned() {
+        // Changing this value breaks every stored key; bump KEY_VERSION.
+        assert_eq!(key_at(12.0), PINNED_KEY);
+    }
+
+    #[test]
+    fn system_names_are_unique() {
+        let mut aya: Vec<_> = AyanamshaSystem::all()
+            .iter()
+            .map(|&s| ayanamsha_key_name(s))
+            .collect();
+        aya.sort_unstable();
+        aya.dedup();
+        assert_eq!(aya.len(), AyanamshaSystem::all().len());
+        let mut bhava: Vec<_> = BhavaSystem::all()
+            .iter()
+            .map(|&s| bhava_key_name(s))
+            .collect();
+        bhava.sort_unstable();
+        bhava.dedup();
+        assert_eq!(bhava.len(), BhavaSystem::all().len());
+    }
+
+    #[test]
+    fn one_second_changes_key() {
+        assert_ne!(key_at(12.0), key_at(13.0));
+    }
+
+    #[test]
+    fn other_inputs_change_key() {
+        let base = key_at(0.0);
+        let utc = UtcTime::new(1990, 5, 17, 6, 30, 0.0);
+        let loc = GeoLocation::new(28.6139, 77.2090, 216.0);
+        let with = |aya, house, nut| chart_key(&utc, &loc, aya, None, house, nut);
+        assert_ne!(base, with(AyanamshaSystem::Raman, BhavaSystem::Equal, true));
+        assert_ne!(
+            base,
+            with(AyanamshaSystem::Lahiri, BhavaSystem::Sripati, true)
+        );
+        assert_ne!(
+            base,
+            with(AyanamshaSystem::Lahiri, BhavaSystem::Equal, false)
+        );
+    }
+
+    #[test]
+    fn custom_anchor_changes_key() {
+        let utc = UtcTime::new(1990, 5, 17, 6, 30, 0.0);
+        let loc = GeoLocation::new(28.6139, 77.2090, 216.0);
+        let with = |custom| {
+            chart_key(
+                &utc,
+                &loc,
+                AyanamshaSystem::Lahiri,
+                custom,
+                BhavaSystem::Equal,
+                true,
+            )
+        };
+        let anchor = CustomAyanamsha::new(23.85, 2_451_545.0);
+        assert_ne!(with(None), with(Some(anchor)));
+        assert_ne!(
+            with(Some(anchor)),
+            with(Some(CustomAyanamsha::new(23.86, 2_451_545.0)))
+        );
+        assert_ne!(
+            with(Some(anchor)),
+            with(Some(CustomAyanamsha::new(23.85, 2_451_546.0)))
+        );
+        // The anchor replaces the system, as in SankrantiConfig.
+        let raman = chart_key(
+            &utc,
+            &loc,
+            AyanamshaSystem::Raman,
+            Some(anchor),
+            BhavaSystem::Equal,
+            true,
+        );
+        assert_eq!(with(Some(anchor)), raman);
+    }
+
+    #[test]
+    fn overflowing_seconds_fold_into_next_minute() {
+        let a = chart_key(
+            &UtcTime::new(2000, 2, 29, 23, 59, 60.0),
+            &GeoLocation::new(0.0, 0.0, 0.0),
+            AyanamshaSystem::Lahiri,
+            None,
+            BhavaSystem::Equal,
+            false,
+        );
+        let b = chart_key(
+            &UtcTime::new(2000, 3, 1, 0, 0, 0.0),
+            &GeoLocation::new(0.0, 0.0, 0.0),
+            AyanamshaSystem::Lahiri,
+            None,
+            BhavaSystem::Equal,
+            false,
+        );
+        assert_eq!(a, b);
+    }
+}
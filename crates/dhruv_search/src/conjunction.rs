@@ -7,7 +7,7 @@
 //! The normalize function wraps to [-180, +180] so zero-crossings correspond to
 //! the target separation. Standard numerical root-finding; no external code referenced.
 
-use dhruv_core::{Body, Engine, Frame, Observer, Query};
+use dhruv_core::{Body, Engine, Frame, Observer, Query, StateVector};
 use dhruv_frames::{
     DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane, cartesian_to_spherical,
     icrf_to_ecliptic, icrf_to_invariable, precess_ecliptic_j2000_to_date_with_model,
@@ -16,7 +16,9 @@ use dhruv_time::UtcTime;
 
 use crate::conjunction_types::{ConjunctionConfig, ConjunctionEvent, SearchDirection};
 use crate::error::SearchError;
-use crate::search_util::{is_genuine_crossing, normalize_to_pm180};
+use crate::search_util::{
+    find_zero_crossing, is_genuine_crossing, iter_events, normalize_to_pm180, refine_bracket,
+};
 
 /// Maximum scan range in days (~800 days covers all synodic periods).
 const MAX_SCAN_DAYS: f64 = 800.0;
@@ -41,18 +43,13 @@ pub fn body_ecliptic_lon_lat_with_model(
     jd_tdb: f64,
     precession_model: PrecessionModel,
 ) -> Result<(f64, f64), SearchError> {
-    let query = Query {
-        target: body,
-        observer: Observer::Body(Body::Earth),
-        frame: Frame::IcrfJ2000,
-        epoch_tdb_jd: jd_tdb,
-    };
-    let state = engine.query(query)?;
-    let ecl_j2000 = icrf_to_ecliptic(&state.position_km);
-    let t = (jd_tdb - 2_451_545.0) / 36525.0;
-    let ecl_date = precess_ecliptic_j2000_to_date_with_model(&ecl_j2000, t, precession_model);
-    let sph = cartesian_to_spherical(&ecl_date);
-    Ok((sph.lon_deg.rem_euclid(360.0), sph.lat_deg))
+    body_lon_lat_on_plane(
+        engine,
+        body,
+        jd_tdb,
+        precession_model,
+        ReferencePlane::Ecliptic,
+    )
 }
 
 /// Query a body's longitude and latitude on the specified reference plane.
@@ -66,23 +63,149 @@ pub fn body_lon_lat_on_plane(
     precession_model: PrecessionModel,
     plane: ReferencePlane,
 ) -> Result<(f64, f64), SearchError> {
-    match plane {
+    let state = engine.query(geocentric_query(body, jd_tdb))?;
+    Ok(icrf_to_lon_lat_on_plane(
+        &state.position_km,
+        jd_tdb,
+        precession_model,
+        plane,
+    ))
+}
+
+/// [`body_lon_lat_on_plane`] for several bodies at one epoch.
+///
+/// Issues a single `Engine::query_batch`, so chain segments shared by the
+/// bodies (Earth, Earth-Moon barycenter) are evaluated once. Results are
+/// identical to per-body calls and are returned in `bodies` order.
+pub fn bodies_lon_lat_on_plane(
+    engine: &Engine,
+    bodies: &[Body],
+    jd_tdb: f64,
+    precession_model: PrecessionModel,
+    plane: ReferencePlane,
+) -> Result<Vec<(f64, f64)>, SearchError> {
+    let queries: Vec<Query> = bodies
+        .iter()
+        .map(|&body| geocentric_query(body, jd_tdb))
+        .collect();
+    engine
+        .query_batch(&queries)
+        .into_iter()
+        .map(|state| {
+            Ok(icrf_to_lon_lat_on_plane(
+                &state?.position_km,
+                jd_tdb,
+                precession_model,
+                plane,
+            ))
+        })
+        .collect()
+}
+
+/// Longitude speeds (deg/day) of several bodies on `plane` at one epoch.
+///
+/// Taken from the ephemeris velocity of a single `Engine::query_batch`, so
+/// the speed is the kernel's own rather than a difference of positions. On
+/// the ecliptic of date the plane itself turns with precession; that term is
+/// evaluated on the fixed position vector and needs no further queries.
+/// Returned in `bodies` order.
+pub(crate) fn bodies_lon_speed_on_plane(
+    engine: &Engine,
+    bodies: &[Body],
+    jd_tdb: f64,
+    precession_model: PrecessionModel,
+    plane: ReferencePlane,
+) -> Result<Vec<f64>, SearchError> {
+    let queries: Vec<Query> = bodies
+        .iter()
+        .map(|&body| geocentric_query(body, jd_tdb))
+        .collect();
+    engine
+        .query_batch(&queries)
+        .into_iter()
+        .map(|state| {
+            Ok(icrf_state_lon_speed_on_plane(
+                &state?,
+                jd_tdb,
+                precession_model,
+                plane,
+            ))
+        })
+        .collect()
+}
+
+fn geocentric_query(body: Body, jd_tdb: f64) -> Query {
+    Query {
+        target: body,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: jd_tdb,
+    }
+}
+
+/// Geocentric ICRF position → (lon, lat) in degrees on `plane`.
+fn icrf_to_lon_lat_on_plane(
+    position_km: &[f64; 3],
+    jd_tdb: f64,
+    precession_model: PrecessionModel,
+    plane: ReferencePlane,
+) -> (f64, f64) {
+    let sph = match plane {
         ReferencePlane::Ecliptic => {
-            body_ecliptic_lon_lat_with_model(engine, body, jd_tdb, precession_model)
+            let ecl_j2000 = icrf_to_ecliptic(position_km);
+            let t = (jd_tdb - 2_451_545.0) / 36525.0;
+            let ecl_date =
+                precess_ecliptic_j2000_to_date_with_model(&ecl_j2000, t, precession_model);
+            cartesian_to_spherical(&ecl_date)
         }
-        ReferencePlane::Invariable => {
-            let query = Query {
-                target: body,
-                observer: Observer::Body(Body::Earth),
-                frame: Frame::IcrfJ2000,
-                epoch_tdb_jd: jd_tdb,
+        ReferencePlane::Invariable => cartesian_to_spherical(&icrf_to_invariable(position_km)),
+    };
+    (sph.lon_deg.rem_euclid(360.0), sph.lat_deg)
+}
+
+/// Geocentric ICRF state → longitude speed in deg/day on `plane`.
+fn icrf_state_lon_speed_on_plane(
+    state: &StateVector,
+    jd_tdb: f64,
+    precession_model: PrecessionModel,
+    plane: ReferencePlane,
+) -> f64 {
+    let v_km_day = state.velocity_km_s.map(|c| c * 86_400.0);
+    let (r, v) = match plane {
+        ReferencePlane::Ecliptic => {
+            let t = (jd_tdb - 2_451_545.0) / 36525.0;
+            (
+                precess_ecliptic_j2000_to_date_with_model(
+                    &icrf_to_ecliptic(&state.position_km),
+                    t,
+                    precession_model,
+                ),
+                precess_ecliptic_j2000_to_date_with_model(
+                    &icrf_to_ecliptic(&v_km_day),
+                    t,
+                    precession_model,
+                ),
+            )
+        }
+        ReferencePlane::Invariable => (
+            icrf_to_invariable(&state.position_km),
+            icrf_to_invariable(&v_km_day),
+        ),
+    };
+    // dλ/dt = (x·vy − y·vx) / (x² + y²) for the body's own motion.
+    let body_rate = ((r[0] * v[1] - r[1] * v[0]) / (r[0] * r[0] + r[1] * r[1])).to_degrees();
+    let frame_rate = match plane {
+        ReferencePlane::Ecliptic => {
+            // Rotation of the ecliptic of date under a fixed position: P(t±dt)·r.
+            const DT: f64 = 1.0 / 1440.0;
+            let lon = |jd: f64| {
+                icrf_to_lon_lat_on_plane(&state.position_km, jd, precession_model, plane).0
             };
-            let state = engine.query(query)?;
-            let inv = icrf_to_invariable(&state.position_km);
-            let sph = cartesian_to_spherical(&inv);
-            Ok((sph.lon_deg.rem_euclid(360.0), sph.lat_deg))
+            normalize_to_pm180(lon(jd_tdb + DT) - lon(jd_tdb - DT)) / (2.0 * DT)
         }
-    }
+        ReferencePlane::Invariable => 0.0,
+    };
+    body_rate + frame_rate
 }
 
 /// Query a body's ecliptic-of-date longitude, latitude, and longitude speed.
@@ -137,53 +260,21 @@ fn compute_actual_separation(lon1: f64, lon2: f64, target_deg: f64) -> f64 {
     target_deg + delta
 }
 
-/// Bisect to refine the zero crossing between t_a and t_b.
-#[allow(clippy::too_many_arguments)]
-fn bisect_refinement(
+/// Build the event record at a refined crossing time.
+fn conjunction_event_at(
     engine: &Engine,
     body1: Body,
     body2: Body,
     target_deg: f64,
-    mut t_a: f64,
-    mut f_a: f64,
-    mut t_b: f64,
-    _f_b: f64,
-    config: &ConjunctionConfig,
+    jd_tdb: f64,
 ) -> Result<ConjunctionEvent, SearchError> {
-    let mut lon1 = 0.0;
-    let mut lon2 = 0.0;
-    let mut lat1 = 0.0;
-    let mut lat2 = 0.0;
-
-    for _ in 0..config.max_iterations {
-        let t_mid = 0.5 * (t_a + t_b);
-        let (f_mid, l1, l2, la1, la2) =
-            separation_function(engine, body1, body2, target_deg, t_mid)?;
-
-        lon1 = l1;
-        lon2 = l2;
-        lat1 = la1;
-        lat2 = la2;
-
-        if f_a * f_mid <= 0.0 {
-            t_b = t_mid;
-        } else {
-            t_a = t_mid;
-            f_a = f_mid;
-        }
-
-        if (t_b - t_a).abs() < config.convergence_days {
-            break;
-        }
-    }
-
-    let t_final = 0.5 * (t_a + t_b);
-    let actual_sep = compute_actual_separation(lon1, lon2, target_deg);
+    let (_, lon1, lon2, lat1, lat2) =
+        separation_function(engine, body1, body2, target_deg, jd_tdb)?;
 
     Ok(ConjunctionEvent {
-        jd_tdb: t_final,
-        utc: UtcTime::from_jd_tdb(t_final, engine.lsk()),
-        actual_separation_deg: actual_sep,
+        jd_tdb,
+        utc: UtcTime::from_jd_tdb(jd_tdb, engine.lsk()),
+        actual_separation_deg: compute_actual_separation(lon1, lon2, target_deg),
         body1_longitude_deg: lon1,
         body2_longitude_deg: lon2,
         body1_latitude_deg: lat1,
@@ -210,43 +301,22 @@ fn find_event(
     };
 
     let max_steps = (MAX_SCAN_DAYS / config.step_size_days).ceil() as usize;
+    let target = config.target_separation_deg;
+    let f = |t: f64| -> Result<f64, SearchError> {
+        Ok(separation_function(engine, body1, body2, target, t)?.0)
+    };
 
-    let (mut f_prev, _, _, _, _) =
-        separation_function(engine, body1, body2, config.target_separation_deg, jd_start)?;
-    let mut t_prev = jd_start;
-
-    for _ in 0..max_steps {
-        let t_curr = t_prev + step;
-        let (f_curr, _, _, _, _) =
-            separation_function(engine, body1, body2, config.target_separation_deg, t_curr)?;
-
-        // Check for genuine zero crossing (not a wrap-around discontinuity)
-        if is_genuine_crossing(f_prev, f_curr) {
-            // Ensure t_a < t_b for bisection
-            let (t_a, f_a, t_b, f_b) = if t_prev < t_curr {
-                (t_prev, f_prev, t_curr, f_curr)
-            } else {
-                (t_curr, f_curr, t_prev, f_prev)
-            };
-            let event = bisect_refinement(
-                engine,
-                body1,
-                body2,
-                config.target_separation_deg,
-                t_a,
-                f_a,
-                t_b,
-                f_b,
-                config,
-            )?;
-            return Ok(Some(event));
-        }
-
-        t_prev = t_curr;
-        f_prev = f_curr;
+    match find_zero_crossing(
+        &f,
+        jd_start,
+        step,
+        max_steps,
+        config.max_iterations,
+        config.convergence_days,
+    )? {
+        Some(t) => Ok(Some(conjunction_event_at(engine, body1, body2, target, t)?)),
+        None => Ok(None),
     }
-
-    Ok(None)
 }
 
 /// Find the next conjunction/aspect event after `jd_tdb`.
@@ -302,30 +372,29 @@ pub fn search_conjunctions(
 
     let mut events = Vec::new();
     let step = config.step_size_days;
+    let target = config.target_separation_deg;
+    let f = |t: f64| -> Result<f64, SearchError> {
+        Ok(separation_function(engine, body1, body2, target, t)?.0)
+    };
 
-    let (mut f_prev, _, _, _, _) =
-        separation_function(engine, body1, body2, config.target_separation_deg, jd_start)?;
+    let mut f_prev = f(jd_start)?;
     let mut t_prev = jd_start;
 
     loop {
         let t_curr = (t_prev + step).min(jd_end);
-        let (f_curr, _, _, _, _) =
-            separation_function(engine, body1, body2, config.target_separation_deg, t_curr)?;
+        let f_curr = f(t_curr)?;
 
         if is_genuine_crossing(f_prev, f_curr) {
-            let event = bisect_refinement(
-                engine,
-                body1,
-                body2,
-                config.target_separation_deg,
+            let t = refine_bracket(
+                &f,
                 t_prev,
                 f_prev,
                 t_curr,
-                f_curr,
-                config,
+                config.max_iterations,
+                config.convergence_days,
             )?;
-            if event.jd_tdb >= jd_start && event.jd_tdb <= jd_end {
-                events.push(event);
+            if t >= jd_start && t <= jd_end {
+                events.push(conjunction_event_at(engine, body1, body2, target, t)?);
             }
         }
 
@@ -340,10 +409,63 @@ pub fn search_conjunctions(
     Ok(events)
 }
 
+/// Lazily stream conjunction/aspect events in a time range.
+///
+/// Same events as [`search_conjunctions`], produced one at a time by chaining
+/// [`next_conjunction`], so `take(n)` stops the search after `n` events.
+pub fn iter_conjunctions<'a>(
+    engine: &'a Engine,
+    body1: Body,
+    body2: Body,
+    jd_start: f64,
+    jd_end: f64,
+    config: &'a ConjunctionConfig,
+) -> impl Iterator<Item = Result<ConjunctionEvent, SearchError>> + 'a {
+    iter_events(
+        jd_start,
+        jd_end,
+        move |t| next_conjunction(engine, body1, body2, t, config),
+        |e| e.jd_tdb,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn state_speed_matches_propagated_longitude() {
+        // Straight-line motion; the longitude rate from the velocity must match
+        // the difference of longitudes of the propagated position.
+        let state = StateVector {
+            position_km: [1.2e8, 8.0e7, 3.0e7],
+            velocity_km_s: [-18.0, 25.0, 9.0],
+        };
+        let jd = 2_460_000.5;
+        let at = |dt: f64| {
+            state
+                .position_km
+                .iter()
+                .zip(state.velocity_km_s)
+                .map(|(r, v)| r + v * dt * 86_400.0)
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap()
+        };
+        let h = 1.0 / 1440.0;
+        for plane in [ReferencePlane::Ecliptic, ReferencePlane::Invariable] {
+            let lon = |dt: f64| {
+                icrf_to_lon_lat_on_plane(&at(dt), jd + dt, DEFAULT_PRECESSION_MODEL, plane).0
+            };
+            let expected = normalize_to_pm180(lon(h) - lon(-h)) / (2.0 * h);
+            let speed = icrf_state_lon_speed_on_plane(&state, jd, DEFAULT_PRECESSION_MODEL, plane);
+            assert!(
+                (speed - expected).abs() < 1e-7,
+                "{plane:?}: speed {speed}, expected {expected}"
+            );
+        }
+    }
+
     #[test]
     fn actual_sep_near_zero() {
         // lon1 slightly less than lon2 → raw ≈ 359.999°, target=0 → report ~0
@@ -0,0 +1,234 @@
+//! Export a dasha hierarchy to iCalendar (RFC 5545) text.
+//!
+//! One VEVENT per period at the chosen level, with DTSTART/DTEND in UTC
+//! (`YYYYMMDDTHHMMSSZ`, rounded to the nearest second). Dates are split with
+//! `dhruv_time::jd_to_calendar` (proleptic Gregorian). RFC 5545 date-times
+//! carry a four-digit year, so periods outside years 1..=9999 are rejected.
+
+use dhruv_time::jd_to_calendar;
+use dhruv_vedic_base::dasha::{DashaHierarchy, DashaLevel};
+
+use crate::error::SearchError;
+
+/// Serialize one level of a hierarchy as a VCALENDAR string.
+///
+/// Events are named `"<system> <level>: <entity>"`, e.g.
+/// `"Vimshottari Mahadasha: Surya"`. UIDs embed the birth JD so calendars
+/// exported for different charts can be imported side by side. A level the
+/// hierarchy does not contain yields a calendar with no events. Lines end
+/// with CRLF as the RFC requires.
+pub fn dasha_to_ics(hierarchy: &DashaHierarchy, level: u8) -> Result<String, SearchError> {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//ctara-dhruv//dasha//EN");
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+
+    let system = hierarchy.system;
+    let level_name = DashaLevel::from_u8(level).map_or("Dasha", DashaLevel::name);
+    let stamp = jd_to_ics_utc(hierarchy.birth_jd)?;
+    let periods = hierarchy
+        .levels
+        .get(level as usize)
+        .map_or(&[][..], Vec::as_slice);
+
+    for (i, period) in periods.iter().enumerate() {
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(
+            &mut out,
+            &format!(
+                "UID:dhruv-{:.6}-{}-{level}-{i}@ctara-dhruv",
+                hierarchy.birth_jd, system as u8
+            ),
+        );
+        push_line(&mut out, &format!("DTSTAMP:{stamp}"));
+        push_line(
+            &mut out,
+            &format!("DTSTART:{}", jd_to_ics_utc(period.start_jd)?),
+        );
+        push_line(
+            &mut out,
+            &format!("DTEND:{}", jd_to_ics_utc(period.end_jd)?),
+        );
+        let summary = format!("{} {level_name}: {}", system.name(), period.entity.name());
+        push_line(&mut out, &format!("SUMMARY:{}", escape_text(&summary)));
+        push_line(&mut out, "END:VEVENT");
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    Ok(out)
+}
+
+fn push_line(out: &mut String, line: &str) {
+    out.push_str(line);
+    out.push_str("\r\n");
+}
+
+/// Escape TEXT values per RFC 5545 §3.3.11.
+fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Format a JD UTC as an iCalendar UTC date-time (`YYYYMMDDTHHMMSSZ`).
+///
+/// Rounding to the second happens before the calendar split so 23:59:59.6
+/// carries into the next day instead of printing second 60.
+fn jd_to_ics_utc(jd: f64) -> Result<String, SearchError> {
+    if !jd.is_finite() {
+        return Err(SearchError::InvalidConfig("dasha period JD must be finite"));
+    }
+    let total_secs = ((jd + 0.5) * 86_400.0).round() as i64;
+    let days = total_secs.div_euclid(86_400);
+    let secs_of_day = total_secs.rem_euclid(86_400);
+
+    // Midnight of the rounded day, so the calendar day is a whole number.
+    let (year, month, day) = jd_to_calendar(days as f64 - 0.5);
+    if !(1..=9999).contains(&year) {
+        return Err(SearchError::InvalidConfig(
+            "iCalendar export supports years 1..=9999 only",
+        ));
+    }
+
+    Ok(format!(
+        "{year:04}{month:02}{:02}T{:02}{:02}{:02}Z",
+        day as u32,
+        secs_of_day / 3600,
+        (secs_of_day % 3600) / 60,
+        secs_of_day % 60
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dhruv_vedic_base::dasha::{
+        DashaSystem, DashaVariationConfig, nakshatra_hierarchy, vimshottari_config,
+    };
+
+    fn parse_ics_utc(s: &str) -> (i32, u32, u32, u32, u32, u32) {
+        assert_eq!(s.len(), 16, "bad timestamp {s}");
+        assert_eq!(&s[8..9], "T");
+        assert_eq!(&s[15..16], "Z");
+        let num = |r: std::ops::Range<usize>| s[r].parse::<u32>().expect("digits");
+        let ts = (
+            s[0..4].parse::<i32>().expect("year"),
+            num(4..6),
+            num(6..8),
+            num(9..11),
+            num(11..13),
+            num(13..15),
+        );
+        assert!((1..=12).contains(&ts.1) && (1..=31).contains(&ts.2));
+        assert!(ts.3 < 24 && ts.4 < 60 && ts.5 < 60);
+        ts
+    }
+
+    fn vimshottari(birth_jd: f64, max_level: u8) -> DashaHierarchy {
+        nakshatra_hierarchy(
+            birth_jd,
+            100.0,
+            &vimshottari_config(),
+            max_level,
+            &DashaVariationConfig::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn j2000_formats_as_noon() {
+        assert_eq!(jd_to_ics_utc(2_451_545.0).unwrap(), "20000101T120000Z");
+        assert_eq!(jd_to_ics_utc(2_451_544.5).unwrap(), "20000101T000000Z");
+        // 23:59:59.8 rounds into the next day.
+        assert_eq!(
+            jd_to_ics_utc(2_451_545.5 - 0.2 / 86_400.0).unwrap(),
+            "20000102T000000Z"
+        );
+    }
+
+    #[test]
+    fn rejects_years_outside_four_digits() {
+        // 0001-01-01T00:00Z and 9999-12-31T23:59:59Z are the bounds.
+        assert_eq!(jd_to_ics_utc(1_721_425.5).unwrap(), "00010101T000000Z");
+        assert_eq!(
+            jd_to_ics_utc(5_373_484.5 - 1.0 / 86_400.0).unwrap(),
+            "99991231T235959Z"
+        );
+        assert!(jd_to_ics_utc(1_721_425.5 - 1.0 / 86_400.0).is_err());
+        assert!(jd_to_ics_utc(5_373_484.5).is_err());
+        assert!(jd_to_ics_utc(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn event_per_period_with_valid_timestamps() {
+        let h = vimshottari(2_451_545.0, 1);
+        assert_eq!(h.system, DashaSystem::Vimshottari);
+
+        for level in 0..=1u8 {
+            let ics = dasha_to_ics(&h, level).unwrap();
+            assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+            assert!(ics.ends_with("END:VCALENDAR\r\n"));
+            assert_eq!(
+                ics.matches("BEGIN:VEVENT").count(),
+                h.levels[level as usize].len()
+            );
+
+            let starts: Vec<_> = ics
+                .lines()
+                .filter_map(|l| l.strip_prefix("DTSTART:"))
+                .map(parse_ics_utc)
+                .collect();
+            let ends: Vec<_> = ics
+                .lines()
+                .filter_map(|l| l.strip_prefix("DTEND:"))
+                .map(parse_ics_utc)
+                .collect();
+            assert_eq!(starts.len(), h.levels[level as usize].len());
+            assert_eq!(ends.len(), starts.len());
+            for (s, e) in starts.iter().zip(&ends) {
+                assert!(s < e, "DTSTART {s:?} not before DTEND {e:?}");
+            }
+        }
+
+        let ics = dasha_to_ics(&h, 0).unwrap();
+        assert!(ics.contains("SUMMARY:Vimshottari Mahadasha: "));
+    }
+
+    #[test]
+    fn uids_differ_between_charts() {
+        let uids = |h: &DashaHierarchy| -> Vec<String> {
+            dasha_to_ics(h, 0)
+                .unwrap()
+                .lines()
+                .filter_map(|l| l.strip_prefix("UID:").map(str::to_string))
+                .collect()
+        };
+        let a = uids(&vimshottari(2_451_545.0, 0));
+        let b = uids(&vimshottari(2_451_546.25, 0));
+        assert!(!a.is_empty());
+        assert!(a.iter().all(|uid| !b.contains(uid)));
+    }
+
+    #[test]
+    fn missing_level_has_no_events() {
+        let h = vimshottari(2_451_545.0, 0);
+        let ics = dasha_to_ics(&h, 3).unwrap();
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 0);
+        assert!(ics.contains("END:VCALENDAR"));
+    }
+
+    #[test]
+    fn text_escaping() {
+        assert_eq!(escape_text("a,b;c\\d"), "a\\,b\\;c\\\\d");
+    }
+}
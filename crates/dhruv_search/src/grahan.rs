@@ -24,12 +24,17 @@ use dhruv_core::{Body, Engine, Frame, Observer, Query};
 use dhruv_frames::{cartesian_to_spherical, icrf_to_ecliptic, precess_ecliptic_j2000_to_date};
 use dhruv_time::UtcTime;
 
-use crate::conjunction::{next_conjunction, prev_conjunction, search_conjunctions};
+use dhruv_vedic_base::{LunarNode, NodeMode, jd_tdb_to_centuries, lunar_node_deg};
+
+use crate::conjunction::{
+    body_ecliptic_lon_lat, next_conjunction, prev_conjunction, search_conjunctions,
+};
 use crate::conjunction_types::ConjunctionConfig;
 use crate::error::SearchError;
 use crate::grahan_types::{
-    ChandraGrahan, ChandraGrahanType, GrahanConfig, SuryaGrahan, SuryaGrahanType,
+    ChandraGrahan, ChandraGrahanType, EclipseSeason, GrahanConfig, SuryaGrahan, SuryaGrahanType,
 };
+use crate::search_util::{find_zero_crossing, normalize_to_pm180, refine_bracket};
 
 // ---------------------------------------------------------------------------
 // Constants (IAU 2015 nominal values)
@@ -57,6 +62,20 @@ const GRAHAN_LAT_THRESHOLD_DEG: f64 = 2.0;
 /// so 0.5 day step safely brackets all crossings.
 const MOON_STEP_DAYS: f64 = 0.5;
 
+/// Half-width of an eclipse season: the major solar ecliptic limit (degrees).
+///
+/// A surya grahan needs the Sun within ~18.5 deg of a node at new moon; the
+/// chandra grahan limit (~12.2 deg) is narrower, so this bounds both kinds.
+pub const ECLIPSE_SEASON_LIMIT_DEG: f64 = 18.5;
+
+/// Scan step for eclipse season edges (days). The Sun gains ~1.04 deg/day
+/// on the node, so a season spans ~35 steps.
+const SEASON_STEP_DAYS: f64 = 1.0;
+
+/// Scan length for eclipse season edges: half an eclipse year (~173 days)
+/// plus margin.
+const SEASON_MAX_STEPS: usize = 200;
+
 /// Bisection convergence for contact times (days). ~0.86 ms precision.
 const CONTACT_CONVERGENCE_DAYS: f64 = 1e-8;
 
@@ -226,27 +245,14 @@ fn find_chandra_contact(
         Ok(offset + limb_sign * moon_r - boundary_radius_deg)
     };
 
-    let mut ta = t_a;
-    let mut tb = t_b;
-    let mut fa = f(ta)?;
-
-    for _ in 0..CONTACT_MAX_ITER {
-        let tm = 0.5 * (ta + tb);
-        let fm = f(tm)?;
-
-        if fa * fm <= 0.0 {
-            tb = tm;
-        } else {
-            ta = tm;
-            fa = fm;
-        }
-
-        if (tb - ta).abs() < CONTACT_CONVERGENCE_DAYS {
-            break;
-        }
-    }
-
-    Ok(0.5 * (ta + tb))
+    refine_bracket(
+        &f,
+        t_a,
+        f(t_a)?,
+        t_b,
+        CONTACT_MAX_ITER,
+        CONTACT_CONVERGENCE_DAYS,
+    )
 }
 
 /// Compute a single chandra grahan from a full moon event.
@@ -499,27 +505,14 @@ fn find_surya_contact(
         Ok(sep - target_sep_deg)
     };
 
-    let mut ta = t_a;
-    let mut tb = t_b;
-    let mut fa = f(ta)?;
-
-    for _ in 0..CONTACT_MAX_ITER {
-        let tm = 0.5 * (ta + tb);
-        let fm = f(tm)?;
-
-        if fa * fm <= 0.0 {
-            tb = tm;
-        } else {
-            ta = tm;
-            fa = fm;
-        }
-
-        if (tb - ta).abs() < CONTACT_CONVERGENCE_DAYS {
-            break;
-        }
-    }
-
-    Ok(0.5 * (ta + tb))
+    refine_bracket(
+        &f,
+        t_a,
+        f(t_a)?,
+        t_b,
+        CONTACT_MAX_ITER,
+        CONTACT_CONVERGENCE_DAYS,
+    )
 }
 
 /// Compute a single geocentric surya grahan from a new moon event.
@@ -672,6 +665,74 @@ pub fn search_surya_grahan(
     Ok(results)
 }
 
+// ---------------------------------------------------------------------------
+// Eclipse seasons
+// ---------------------------------------------------------------------------
+
+/// Sun's angular distance from the nearer true lunar node, in [0, 90] deg.
+fn sun_node_distance_deg(engine: &Engine, jd_tdb: f64) -> Result<(f64, LunarNode), SearchError> {
+    let (sun_lon, _) = body_ecliptic_lon_lat(engine, Body::Sun, jd_tdb)?;
+    let rahu = lunar_node_deg(LunarNode::Rahu, jd_tdb_to_centuries(jd_tdb), NodeMode::True);
+    let d = normalize_to_pm180(sun_lon - rahu).abs();
+    if d <= 90.0 {
+        Ok((d, LunarNode::Rahu))
+    } else {
+        Ok((180.0 - d, LunarNode::Ketu))
+    }
+}
+
+/// Find the eclipse season in progress at `jd_tdb`, or the next one to begin.
+///
+/// A season is the ~36-day window while the Sun is within
+/// [`ECLIPSE_SEASON_LIMIT_DEG`] of Rahu or Ketu. Every grahan falls inside a
+/// season, so callers can skip the new/full-moon geometry outside them.
+pub fn next_eclipse_season(
+    engine: &Engine,
+    jd_tdb: f64,
+) -> Result<Option<EclipseSeason>, SearchError> {
+    let excess = |t: f64| -> Result<f64, SearchError> {
+        Ok(sun_node_distance_deg(engine, t)?.0 - ECLIPSE_SEASON_LIMIT_DEG)
+    };
+
+    let step = if excess(jd_tdb)? <= 0.0 {
+        -SEASON_STEP_DAYS
+    } else {
+        SEASON_STEP_DAYS
+    };
+    let Some(start_jd) = find_zero_crossing(
+        &excess,
+        jd_tdb,
+        step,
+        SEASON_MAX_STEPS,
+        50,
+        CONTACT_CONVERGENCE_DAYS,
+    )?
+    else {
+        return Ok(None);
+    };
+    // Step just inside the window before scanning for its exit.
+    let Some(end_jd) = find_zero_crossing(
+        &excess,
+        start_jd + 1e-3,
+        SEASON_STEP_DAYS,
+        SEASON_MAX_STEPS,
+        50,
+        CONTACT_CONVERGENCE_DAYS,
+    )?
+    else {
+        return Ok(None);
+    };
+
+    let (_, node) = sun_node_distance_deg(engine, 0.5 * (start_jd + end_jd))?;
+    Ok(Some(EclipseSeason {
+        start_jd,
+        start_utc: UtcTime::from_jd_tdb(start_jd, engine.lsk()),
+        end_jd,
+        end_utc: UtcTime::from_jd_tdb(end_jd, engine.lsk()),
+        node,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
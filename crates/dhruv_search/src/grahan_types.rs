@@ -1,6 +1,7 @@
 //! Types for grahan (eclipse) computation.
 
 use dhruv_time::UtcTime;
+use dhruv_vedic_base::LunarNode;
 
 /// Geographic location on Earth's surface.
 ///
@@ -152,3 +153,19 @@ pub struct SuryaGrahan {
     /// Angular separation between Sun and Moon centers at greatest grahan, in degrees.
     pub angular_separation_deg: f64,
 }
+
+/// Eclipse season: the window during which the Sun is within the ecliptic
+/// limit of a lunar node, so new and full moons may produce grahan.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EclipseSeason {
+    /// Season start (JD TDB): Sun enters the ecliptic limit.
+    pub start_jd: f64,
+    /// Season start as structured Gregorian UTC.
+    pub start_utc: UtcTime,
+    /// Season end (JD TDB): Sun leaves the ecliptic limit.
+    pub end_jd: f64,
+    /// Season end as structured Gregorian UTC.
+    pub end_utc: UtcTime,
+    /// Node the Sun passes during this season.
+    pub node: LunarNode,
+}
@@ -0,0 +1,456 @@
+//! Heliacal rising and setting search.
+//!
+//! Each day is sampled once at the twilight instant when the Sun's
+//! geocentric altitude equals `-arcus_visionis_deg`: dawn for risings, dusk
+//! for settings. The object counts as visible that day when its geocentric
+//! altitude is at least `min_altitude_deg` and its elongation from the Sun
+//! at least `min_elongation_deg`. A heliacal rising is the first visible
+//! dawn after an invisible one; a heliacal setting is the last visible dusk
+//! before an invisible one.
+//!
+//! Positions are on the mean equator and equinox of date, as in sunrise
+//! computation. Refraction and atmospheric extinction are not modelled
+//! separately; they are folded into the arcus visionis.
+//!
+//! Standard spherical astronomy; no external code referenced.
+//! See docs/clean_room_heliacal.md for provenance.
+
+use std::f64::consts::TAU;
+
+use dhruv_core::{Body, Engine, Frame, Observer, Query};
+use dhruv_frames::{icrf_to_ecliptic, mean_obliquity_of_date_rad, precess_ecliptic_j2000_to_date};
+use dhruv_tara::position_equatorial;
+use dhruv_time::{
+    EopKernel, UtcTime, gmst_rad, jd_to_tdb_seconds, local_sidereal_time_rad, tdb_seconds_to_jd,
+};
+use dhruv_vedic_base::riseset_types::GeoLocation;
+
+use crate::conjunction_types::SearchDirection;
+use crate::error::SearchError;
+use crate::heliacal_types::{HeliacalEvent, HeliacalEventConfig, HeliacalKind, HeliacalTarget};
+use crate::search_util::refine_bracket;
+
+/// Maximum scan range in days (covers Mars' ~780-day synodic period).
+const MAX_SCAN_DAYS: usize = 800;
+
+/// Bisection cap for locating the twilight instant within half a day.
+const TWILIGHT_MAX_ITERATIONS: u32 = 50;
+
+/// Twilight instant convergence threshold in days (~0.9 s).
+const TWILIGHT_CONVERGENCE_DAYS: f64 = 1e-5;
+
+/// The object's state at one day's twilight instant.
+#[derive(Debug, Clone, Copy)]
+struct Sighting {
+    jd_tdb: f64,
+    altitude_deg: f64,
+    elongation_deg: f64,
+    visible: bool,
+}
+
+/// Shared inputs for evaluating sightings.
+struct HeliacalSky<'a> {
+    engine: &'a Engine,
+    eop: &'a EopKernel,
+    target: HeliacalTarget<'a>,
+    location: &'a GeoLocation,
+    config: &'a HeliacalEventConfig,
+}
+
+fn validate_target(target: &HeliacalTarget<'_>) -> Result<(), SearchError> {
+    match target {
+        HeliacalTarget::Body(Body::Sun | Body::Earth) => Err(SearchError::InvalidConfig(
+            "heliacal phenomena are undefined for the Sun and Earth",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// ICRF vector → unit vector on the mean equator and equinox of date.
+fn icrf_to_equatorial_of_date(v: &[f64; 3], jd_tdb: f64) -> [f64; 3] {
+    let t = (jd_tdb - 2_451_545.0) / 36525.0;
+    let ecl_date = precess_ecliptic_j2000_to_date(&icrf_to_ecliptic(v), t);
+    let (se, ce) = mean_obliquity_of_date_rad(t).sin_cos();
+    let eq = [
+        ecl_date[0],
+        ce * ecl_date[1] - se * ecl_date[2],
+        se * ecl_date[1] + ce * ecl_date[2],
+    ];
+    let r = (eq[0] * eq[0] + eq[1] * eq[1] + eq[2] * eq[2]).sqrt();
+    [eq[0] / r, eq[1] / r, eq[2] / r]
+}
+
+fn body_direction(engine: &Engine, body: Body, jd_tdb: f64) -> Result<[f64; 3], SearchError> {
+    let state = engine.query(Query {
+        target: body,
+        observer: Observer::Body(Body::Earth),
+        frame: Frame::IcrfJ2000,
+        epoch_tdb_jd: jd_tdb,
+    })?;
+    Ok(icrf_to_equatorial_of_date(&state.position_km, jd_tdb))
+}
+
+/// Geocentric altitude in degrees of a unit equatorial-of-date direction.
+fn altitude_deg(dir: &[f64; 3], lst_rad: f64, latitude_rad: f64) -> f64 {
+    let ra = dir[1].atan2(dir[0]);
+    let dec = dir[2].clamp(-1.0, 1.0).asin();
+    let ha = (lst_rad - ra).rem_euclid(TAU);
+    let (sp, cp) = latitude_rad.sin_cos();
+    (sp * dec.sin() + cp * dec.cos() * ha.cos())
+        .clamp(-1.0, 1.0)
+        .asin()
+        .to_degrees()
+}
+
+/// Approximate local noon for the civil day containing `jd`.
+fn local_noon(jd: f64, longitude_deg: f64) -> f64 {
+    (jd + 0.5).floor() - longitude_deg / 360.0
+}
+
+impl HeliacalSky<'_> {
+    fn target_direction(&self, jd_tdb: f64) -> Result<[f64; 3], SearchError> {
+        match self.target {
+            HeliacalTarget::Body(body) => body_direction(self.engine, body, jd_tdb),
+            HeliacalTarget::Tara(catalog, id) => {
+                let pos = position_equatorial(catalog, id, jd_tdb)
+                    .map_err(|_| SearchError::InvalidConfig("star not found in catalog"))?;
+                let (sd, cd) = pos.dec_deg.to_radians().sin_cos();
+                let (sr, cr) = pos.ra_deg.to_radians().sin_cos();
+                Ok(icrf_to_equatorial_of_date(&[cd * cr, cd * sr, sd], jd_tdb))
+            }
+        }
+    }
+
+    fn local_sidereal_time(&self, jd_tdb: f64) -> Result<f64, SearchError> {
+        let utc_s = self.engine.lsk().tdb_to_utc(jd_to_tdb_seconds(jd_tdb));
+        let jd_ut1 = self
+            .eop
+            .utc_to_ut1_jd(tdb_seconds_to_jd(utc_s))
+            .map_err(|_| SearchError::InvalidConfig("UT1 unavailable for requested epoch"))?;
+        Ok(local_sidereal_time_rad(
+            gmst_rad(jd_ut1),
+            self.location.longitude_rad(),
+        ))
+    }
+
+    fn sun_altitude_deg(&self, jd_tdb: f64) -> Result<f64, SearchError> {
+        let sun = body_direction(self.engine, Body::Sun, jd_tdb)?;
+        let lst = self.local_sidereal_time(jd_tdb)?;
+        Ok(altitude_deg(&sun, lst, self.location.latitude_rad()))
+    }
+
+    /// Sample the object at dawn (rising) or dusk (setting) of the day whose
+    /// local noon is `noon`. `None` when the Sun never reaches the arcus
+    /// visionis depression on that side of the day.
+    fn sighting(&self, noon: f64, kind: HeliacalKind) -> Result<Option<Sighting>, SearchError> {
+        let (t_dark, t_noon) = match kind {
+            HeliacalKind::Rising => (noon - 0.5, noon),
+            HeliacalKind::Setting => (noon + 0.5, noon),
+        };
+        let depression = self.config.arcus_visionis_deg;
+        let f = |t: f64| -> Result<f64, SearchError> { Ok(self.sun_altitude_deg(t)? + depression) };
+        let f_dark = f(t_dark)?;
+        if f_dark >= 0.0 || f(t_noon)? <= 0.0 {
+            return Ok(None);
+        }
+        let jd_tdb = refine_bracket(
+            &f,
+            t_dark,
+            f_dark,
+            t_noon,
+            TWILIGHT_MAX_ITERATIONS,
+            TWILIGHT_CONVERGENCE_DAYS,
+        )?;
+
+        let sun = body_direction(self.engine, Body::Sun, jd_tdb)?;
+        let obj = self.target_direction(jd_tdb)?;
+        let lst = self.local_sidereal_time(jd_tdb)?;
+        let altitude_deg = altitude_deg(&obj, lst, self.location.latitude_rad());
+        let cos_elong = sun[0] * obj[0] + sun[1] * obj[1] + sun[2] * obj[2];
+        let elongation_deg = cos_elong.clamp(-1.0, 1.0).acos().to_degrees();
+        Ok(Some(Sighting {
+            jd_tdb,
+            altitude_deg,
+            elongation_deg,
+            visible: altitude_deg >= self.config.min_altitude_deg
+                && elongation_deg >= self.config.min_elongation_deg,
+        }))
+    }
+
+    fn event(&self, s: Sighting, kind: HeliacalKind) -> HeliacalEvent {
+        HeliacalEvent {
+            jd_tdb: s.jd_tdb,
+            utc: UtcTime::from_jd_tdb(s.jd_tdb, self.engine.lsk()),
+            kind,
+            altitude_deg: s.altitude_deg,
+            elongation_deg: s.elongation_deg,
+        }
+    }
+}
+
+fn is_visible(s: &Option<Sighting>) -> bool {
+    s.is_some_and(|s| s.visible)
+}
+
+/// Heliacal event between two consecutive days, if any.
+///
+/// A rising is dated to the later (first visible) day, a setting to the
+/// earlier (last visible) day.
+fn transition(
+    earlier: &Option<Sighting>,
+    later: &Option<Sighting>,
+    kind: HeliacalKind,
+) -> Option<Sighting> {
+    match kind {
+        HeliacalKind::Rising if !is_visible(earlier) => later.filter(|s| s.visible),
+        HeliacalKind::Setting if !is_visible(later) => earlier.filter(|s| s.visible),
+        _ => None,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn find_heliacal_event(
+    engine: &Engine,
+    eop: &EopKernel,
+    target: HeliacalTarget<'_>,
+    location: &GeoLocation,
+    jd_tdb: f64,
+    kind: HeliacalKind,
+    direction: SearchDirection,
+    config: &HeliacalEventConfig,
+) -> Result<Option<HeliacalEvent>, SearchError> {
+    config.validate().map_err(SearchError::InvalidConfig)?;
+    validate_target(&target)?;
+    let sky = HeliacalSky {
+        engine,
+        eop,
+        target,
+        location,
+        config,
+    };
+
+    let step = match direction {
+        SearchDirection::Forward => 1.0,
+        SearchDirection::Backward => -1.0,
+    };
+    let noon0 = local_noon(jd_tdb, location.longitude_deg);
+    let mut prev = sky.sighting(noon0 - step, kind)?;
+
+    for k in 0..=MAX_SCAN_DAYS {
+        let cur = sky.sighting(noon0 + step * k as f64, kind)?;
+        let (earlier, later) = match direction {
+            SearchDirection::Forward => (&prev, &cur),
+            SearchDirection::Backward => (&cur, &prev),
+        };
+        if let Some(s) = transition(earlier, later, kind) {
+            let in_direction = match direction {
+                SearchDirection::Forward => s.jd_tdb > jd_tdb,
+                SearchDirection::Backward => s.jd_tdb < jd_tdb,
+            };
+            if in_direction {
+                return Ok(Some(sky.event(s, kind)));
+            }
+        }
+        prev = cur;
+    }
+
+    Ok(None)
+}
+
+/// Find the next heliacal rising after `jd_tdb`.
+///
+/// Returns `Ok(None)` if none occurs within ~800 days (e.g. a circumpolar
+/// star or one that never clears the horizon at this latitude).
+pub fn next_heliacal_rising(
+    engine: &Engine,
+    eop: &EopKernel,
+    target: HeliacalTarget<'_>,
+    location: &GeoLocation,
+    jd_tdb: f64,
+    config: &HeliacalEventConfig,
+) -> Result<Option<HeliacalEvent>, SearchError> {
+    find_heliacal_event(
+        engine,
+        eop,
+        target,
+        location,
+        jd_tdb,
+        HeliacalKind::Rising,
+        SearchDirection::Forward,
+        config,
+    )
+}
+
+/// Find the previous heliacal rising before `jd_tdb`.
+pub fn prev_heliacal_rising(
+    engine: &Engine,
+    eop: &EopKernel,
+    target: HeliacalTarget<'_>,
+    location: &GeoLocation,
+    jd_tdb: f64,
+    config: &HeliacalEventConfig,
+) -> Result<Option<HeliacalEvent>, SearchError> {
+    find_heliacal_event(
+        engine,
+        eop,
+        target,
+        location,
+        jd_tdb,
+        HeliacalKind::Rising,
+        SearchDirection::Backward,
+        config,
+    )
+}
+
+/// Find the next heliacal setting after `jd_tdb`.
+pub fn next_heliacal_setting(
+    engine: &Engine,
+    eop: &EopKernel,
+    target: HeliacalTarget<'_>,
+    location: &GeoLocation,
+    jd_tdb: f64,
+    config: &HeliacalEventConfig,
+) -> Result<Option<HeliacalEvent>, SearchError> {
+    find_heliacal_event(
+        engine,
+        eop,
+        target,
+        location,
+        jd_tdb,
+        HeliacalKind::Setting,
+        SearchDirection::Forward,
+        config,
+    )
+}
+
+/// Find the previous heliacal setting before `jd_tdb`.
+pub fn prev_heliacal_setting(
+    engine: &Engine,
+    eop: &EopKernel,
+    target: HeliacalTarget<'_>,
+    location: &GeoLocation,
+    jd_tdb: f64,
+    config: &HeliacalEventConfig,
+) -> Result<Option<HeliacalEvent>, SearchError> {
+    find_heliacal_event(
+        engine,
+        eop,
+        target,
+        location,
+        jd_tdb,
+        HeliacalKind::Setting,
+        SearchDirection::Backward,
+        config,
+    )
+}
+
+/// Search for all heliacal risings and settings in `[jd_start, jd_end]`,
+/// in chronological order.
+pub fn search_heliacal_events(
+    engine: &Engine,
+    eop: &EopKernel,
+    target: HeliacalTarget<'_>,
+    location: &GeoLocation,
+    jd_start: f64,
+    jd_end: f64,
+    config: &HeliacalEventConfig,
+) -> Result<Vec<HeliacalEvent>, SearchError> {
+    config.validate().map_err(SearchError::InvalidConfig)?;
+    validate_target(&target)?;
+    if jd_end <= jd_start {
+        return Err(SearchError::InvalidConfig("jd_end must be after jd_start"));
+    }
+    let sky = HeliacalSky {
+        engine,
+        eop,
+        target,
+        location,
+        config,
+    };
+
+    let mut events = Vec::new();
+    let mut noon = local_noon(jd_start, location.longitude_deg) - 1.0;
+    let mut prev_dawn = sky.sighting(noon, HeliacalKind::Rising)?;
+    let mut prev_dusk = sky.sighting(noon, HeliacalKind::Setting)?;
+    while noon <= jd_end {
+        noon += 1.0;
+        let dawn = sky.sighting(noon, HeliacalKind::Rising)?;
+        let dusk = sky.sighting(noon, HeliacalKind::Setting)?;
+        for (s, kind) in [
+            (
+                transition(&prev_dawn, &dawn, HeliacalKind::Rising),
+                HeliacalKind::Rising,
+            ),
+            (
+                transition(&prev_dusk, &dusk, HeliacalKind::Setting),
+                HeliacalKind::Setting,
+            ),
+        ] {
+            if let Some(s) = s.filter(|s| s.jd_tdb >= jd_start && s.jd_tdb <= jd_end) {
+                events.push(sky.event(s, kind));
+            }
+        }
+        prev_dawn = dawn;
+        prev_dusk = dusk;
+    }
+
+    events.sort_by(|a, b| a.jd_tdb.total_cmp(&b.jd_tdb));
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seen(jd_tdb: f64) -> Option<Sighting> {
+        Some(Sighting {
+            jd_tdb,
+            altitude_deg: 2.0,
+            elongation_deg: 15.0,
+            visible: true,
+        })
+    }
+
+    fn hidden(jd_tdb: f64) -> Option<Sighting> {
+        seen(jd_tdb).map(|s| Sighting {
+            visible: false,
+            ..s
+        })
+    }
+
+    #[test]
+    fn rising_dated_to_first_visible_dawn() {
+        let s = transition(&hidden(1.0), &seen(2.0), HeliacalKind::Rising).unwrap();
+        assert_eq!(s.jd_tdb, 2.0);
+        assert!(transition(&seen(1.0), &seen(2.0), HeliacalKind::Rising).is_none());
+        // A day without twilight counts as not visible.
+        assert!(transition(&None, &seen(2.0), HeliacalKind::Rising).is_some());
+    }
+
+    #[test]
+    fn setting_dated_to_last_visible_dusk() {
+        let s = transition(&seen(1.0), &hidden(2.0), HeliacalKind::Setting).unwrap();
+        assert_eq!(s.jd_tdb, 1.0);
+        assert!(transition(&hidden(1.0), &hidden(2.0), HeliacalKind::Setting).is_none());
+        assert!(transition(&seen(1.0), &None, HeliacalKind::Setting).is_some());
+    }
+
+    #[test]
+    fn altitude_at_meridian_is_colatitude_plus_dec() {
+        // Object on the celestial equator at RA 0, observer at 30°N with
+        // LST 0: upper culmination at altitude 60°.
+        let alt = altitude_deg(&[1.0, 0.0, 0.0], 0.0, 30f64.to_radians());
+        assert!((alt - 60.0).abs() < 1e-9);
+        // Twelve sidereal hours later it is 60° below the horizon.
+        let alt = altitude_deg(&[1.0, 0.0, 0.0], std::f64::consts::PI, 30f64.to_radians());
+        assert!((alt + 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn local_noon_tracks_longitude() {
+        // JD 2451545.3 is 2000-01-01 19:12 UT; Greenwich noon that day is JD
+        // 2451545.0, and 90°E reaches noon six hours earlier.
+        assert!((local_noon(2_451_545.3, 0.0) - 2_451_545.0).abs() < 1e-12);
+        assert!((local_noon(2_451_545.3, 90.0) - 2_451_544.75).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,149 @@
+//! Types for heliacal rising and setting search.
+
+use dhruv_core::Body;
+use dhruv_tara::{TaraCatalog, TaraId};
+use dhruv_time::UtcTime;
+
+/// Object whose heliacal phenomena are searched.
+#[derive(Debug, Clone, Copy)]
+pub enum HeliacalTarget<'a> {
+    /// A solar-system body from the ephemeris (not Sun or Earth).
+    Body(Body),
+    /// A catalog star, e.g. [`TaraId::Agastya`] for Canopus.
+    Tara(&'a TaraCatalog, TaraId),
+}
+
+/// Heliacal phenomenon type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HeliacalKind {
+    /// First morning the object is seen in dawn twilight after its
+    /// conjunction with the Sun.
+    Rising,
+    /// Last evening the object is seen in dusk twilight before its
+    /// conjunction with the Sun.
+    Setting,
+}
+
+/// A heliacal rising or setting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeliacalEvent {
+    /// Twilight instant (Sun at `-arcus_visionis_deg`) as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// Twilight instant as structured Gregorian UTC.
+    pub utc: UtcTime,
+    /// Rising (morning) or setting (evening).
+    pub kind: HeliacalKind,
+    /// Geocentric altitude of the object at the twilight instant, in degrees.
+    pub altitude_deg: f64,
+    /// Angular separation between the object and the Sun, in degrees.
+    pub elongation_deg: f64,
+}
+
+/// Configuration for heliacal searches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeliacalEventConfig {
+    /// Arcus visionis: depression of the Sun below the horizon, in degrees,
+    /// at which the object is tested for visibility.
+    pub arcus_visionis_deg: f64,
+    /// Minimum geocentric altitude of the object, in degrees, at that moment.
+    pub min_altitude_deg: f64,
+    /// Minimum angular separation from the Sun, in degrees.
+    pub min_elongation_deg: f64,
+}
+
+impl Default for HeliacalEventConfig {
+    /// 10° arcus visionis with the object on the horizon, typical for
+    /// first-magnitude stars.
+    fn default() -> Self {
+        Self {
+            arcus_visionis_deg: 10.0,
+            min_altitude_deg: 0.0,
+            min_elongation_deg: 10.0,
+        }
+    }
+}
+
+impl HeliacalEventConfig {
+    /// Traditional arcus visionis for a planet (Venus 5°, Jupiter 9°,
+    /// Mercury 10°, Saturn 11°, Mars 11.5°); other bodies use the default.
+    pub fn for_body(body: Body) -> Self {
+        let arcus_visionis_deg = match body {
+            Body::Venus => 5.0,
+            Body::Jupiter => 9.0,
+            Body::Mercury => 10.0,
+            Body::Saturn => 11.0,
+            Body::Mars => 11.5,
+            _ => return Self::default(),
+        };
+        Self {
+            arcus_visionis_deg,
+            min_elongation_deg: arcus_visionis_deg,
+            ..Self::default()
+        }
+    }
+
+    /// Validate the configuration.
+    pub(crate) fn validate(&self) -> Result<(), &'static str> {
+        if !self.arcus_visionis_deg.is_finite()
+            || self.arcus_visionis_deg <= 0.0
+            || self.arcus_visionis_deg >= 90.0
+        {
+            return Err("arcus_visionis_deg must be in (0, 90)");
+        }
+        if !self.min_altitude_deg.is_finite() || self.min_altitude_deg.abs() >= 90.0 {
+            return Err("min_altitude_deg must be in (-90, 90)");
+        }
+        if !self.min_elongation_deg.is_finite() || !(0.0..180.0).contains(&self.min_elongation_deg)
+        {
+            return Err("min_elongation_deg must be in [0, 180)");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(HeliacalEventConfig::default().validate().is_ok());
+        assert!(
+            HeliacalEventConfig::for_body(Body::Venus)
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn venus_needs_least_depression() {
+        let venus = HeliacalEventConfig::for_body(Body::Venus);
+        let mars = HeliacalEventConfig::for_body(Body::Mars);
+        assert!(venus.arcus_visionis_deg < mars.arcus_visionis_deg);
+        assert_eq!(
+            HeliacalEventConfig::for_body(Body::Moon),
+            HeliacalEventConfig::default()
+        );
+    }
+
+    #[test]
+    fn rejects_bad_values() {
+        let c = HeliacalEventConfig {
+            arcus_visionis_deg: 0.0,
+            ..Default::default()
+        };
+        assert!(c.validate().is_err());
+
+        let c = HeliacalEventConfig {
+            min_elongation_deg: -1.0,
+            ..Default::default()
+        };
+        assert!(c.validate().is_err());
+
+        let c = HeliacalEventConfig {
+            min_altitude_deg: f64::NAN,
+            ..Default::default()
+        };
+        assert!(c.validate().is_err());
+    }
+}
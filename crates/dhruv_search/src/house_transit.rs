@@ -0,0 +1,104 @@
+//! Transit of a body through natal house cusps.
+//!
+//! Scans the body's ecliptic-of-date longitude forward in time, classifying
+//! each sample with the cusp-based [`house_of_longitude`]. When the house
+//! changes, the crossed cusp is chosen from the direction of motion and the
+//! crossing time is refined by bisection on the signed cusp distance.
+
+use dhruv_core::{Body, Engine};
+use dhruv_time::UtcTime;
+use dhruv_vedic_base::house_of_longitude;
+
+use crate::conjunction::body_ecliptic_lon_lat;
+use crate::error::SearchError;
+use crate::house_transit_types::{HouseTransitConfig, HouseTransitEvent};
+use crate::search_util::{normalize_to_pm180, refine_bracket};
+
+/// Find when `body` next crosses into a different natal house after `jd_tdb`.
+///
+/// `natal_cusps` are the 12 house cusps as tropical ecliptic-of-date
+/// longitudes in degrees (the frame of `BhavaResult`), with house `n`
+/// spanning cusp `n` to cusp `n + 1`. Retrograde motion back across a cusp
+/// counts as a transit into the preceding house.
+///
+/// Returns `Ok(None)` if no crossing occurs within `config.max_search_days`.
+pub fn house_transit(
+    engine: &Engine,
+    body: Body,
+    natal_cusps: &[f64; 12],
+    jd_tdb: f64,
+    config: &HouseTransitConfig,
+) -> Result<Option<HouseTransitEvent>, SearchError> {
+    config.validate().map_err(SearchError::InvalidConfig)?;
+    if body == Body::Earth {
+        return Err(SearchError::InvalidConfig(
+            "Earth cannot be searched from Earth observer",
+        ));
+    }
+    if natal_cusps.iter().any(|c| !c.is_finite()) {
+        return Err(SearchError::InvalidConfig("natal cusps must be finite"));
+    }
+
+    let max_steps = (config.max_search_days / config.step_size_days).ceil() as usize;
+
+    let (mut lon_prev, _) = body_ecliptic_lon_lat(engine, body, jd_tdb)?;
+    let mut house_prev = house_of_longitude(lon_prev, natal_cusps);
+    let mut t_prev = jd_tdb;
+
+    for _ in 0..max_steps {
+        let t_curr = t_prev + config.step_size_days;
+        let (lon_curr, _) = body_ecliptic_lon_lat(engine, body, t_curr)?;
+        let house_curr = house_of_longitude(lon_curr, natal_cusps);
+
+        if house_curr != house_prev {
+            // Direct motion leaves through the next cusp, retrograde motion
+            // back through the house's own cusp.
+            let direct = normalize_to_pm180(lon_curr - lon_prev) >= 0.0;
+            let (cusp, house_entered) = if direct {
+                (natal_cusps[house_prev as usize % 12], house_prev % 12 + 1)
+            } else {
+                (
+                    natal_cusps[house_prev as usize - 1],
+                    (house_prev + 10) % 12 + 1,
+                )
+            };
+
+            let cusp_distance = |t: f64| -> Result<f64, SearchError> {
+                let (lon, _) = body_ecliptic_lon_lat(engine, body, t)?;
+                Ok(normalize_to_pm180(lon - cusp))
+            };
+            let f_prev = normalize_to_pm180(lon_prev - cusp);
+            let f_curr = normalize_to_pm180(lon_curr - cusp);
+
+            if f_prev * f_curr <= 0.0 {
+                let t_event = if f_prev == 0.0 {
+                    t_prev
+                } else {
+                    refine_bracket(
+                        &cusp_distance,
+                        t_prev,
+                        f_prev,
+                        t_curr,
+                        config.max_iterations,
+                        config.convergence_days,
+                    )?
+                };
+                let (lon, _) = body_ecliptic_lon_lat(engine, body, t_event)?;
+                return Ok(Some(HouseTransitEvent {
+                    jd_tdb: t_event,
+                    utc: UtcTime::from_jd_tdb(t_event, engine.lsk()),
+                    body,
+                    longitude_deg: lon,
+                    house_exited: house_prev,
+                    house_entered,
+                }));
+            }
+        }
+
+        t_prev = t_curr;
+        lon_prev = lon_curr;
+        house_prev = house_curr;
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,95 @@
+//! Types for transits of a body through natal house cusps.
+
+use dhruv_core::Body;
+use dhruv_time::UtcTime;
+
+/// A transiting body crossing a natal house cusp.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HouseTransitEvent {
+    /// Event time as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// Event time as structured Gregorian UTC.
+    pub utc: UtcTime,
+    /// Which body.
+    pub body: Body,
+    /// Ecliptic-of-date longitude at the crossing in degrees [0, 360).
+    pub longitude_deg: f64,
+    /// Natal house (1-12) the body was in before the crossing.
+    pub house_exited: u8,
+    /// Natal house (1-12) the body entered.
+    pub house_entered: u8,
+}
+
+/// Configuration for house transit search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HouseTransitConfig {
+    /// Coarse scan step size in days. Must be short enough that the body
+    /// cannot cross a whole house between samples.
+    pub step_size_days: f64,
+    /// Maximum span to scan before giving up, in days.
+    pub max_search_days: f64,
+    /// Maximum bisection iterations (default 50).
+    pub max_iterations: u32,
+    /// Convergence threshold in days (default 1e-8, ~0.86 ms).
+    pub convergence_days: f64,
+}
+
+impl HouseTransitConfig {
+    /// Default config sized to the body's typical speed.
+    ///
+    /// The scan window covers at least one full sidereal revolution (or
+    /// ~90 years for Uranus, Neptune, and Pluto), so a transit is found for
+    /// any non-degenerate set of cusps.
+    pub fn for_body(body: Body) -> Self {
+        let (step_size_days, max_search_days) = match body {
+            Body::Moon => (0.25, 60.0),
+            Body::Sun | Body::Mercury | Body::Venus => (1.0, 800.0),
+            Body::Mars => (2.0, 1_600.0),
+            Body::Jupiter => (5.0, 4_400.0),
+            Body::Saturn => (10.0, 11_000.0),
+            _ => (10.0, 33_000.0),
+        };
+        Self {
+            step_size_days,
+            max_search_days,
+            max_iterations: 50,
+            convergence_days: 1e-8,
+        }
+    }
+
+    /// Validate the configuration.
+    pub(crate) fn validate(&self) -> Result<(), &'static str> {
+        if !self.step_size_days.is_finite() || self.step_size_days <= 0.0 {
+            return Err("step_size_days must be positive");
+        }
+        if !self.max_search_days.is_finite() || self.max_search_days <= 0.0 {
+            return Err("max_search_days must be positive");
+        }
+        if self.max_iterations == 0 {
+            return Err("max_iterations must be > 0");
+        }
+        if !self.convergence_days.is_finite() || self.convergence_days <= 0.0 {
+            return Err("convergence_days must be positive");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moon_defaults_scan_quarter_days() {
+        let c = HouseTransitConfig::for_body(Body::Moon);
+        assert!((c.step_size_days - 0.25).abs() < 1e-10);
+        assert!(c.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_zero_window() {
+        let mut c = HouseTransitConfig::for_body(Body::Mars);
+        c.max_search_days = 0.0;
+        assert!(c.validate().is_err());
+    }
+}
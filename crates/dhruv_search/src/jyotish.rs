@@ -14,6 +14,7 @@ use dhruv_time::{EopKernel, UtcTime, jd_to_tdb_seconds, tdb_seconds_to_jd};
 use dhruv_vedic_base::arudha::all_arudha_padas;
 use dhruv_vedic_base::riseset::compute_rise_set;
 use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig, RiseSetEvent, RiseSetResult};
+use dhruv_vedic_base::sandhi::{is_gandanta, is_pushkara, is_rashi_sandhi};
 use dhruv_vedic_base::special_lagna::all_special_lagnas;
 use dhruv_vedic_base::upagraha::TIME_BASED_UPAGRAHAS;
 use dhruv_vedic_base::vaar::vaar_from_jd;
@@ -26,24 +27,28 @@ use dhruv_vedic_base::{
     ALL_GRAHAS, AllGrahaAvasthas, AllSpecialLagnas, AllUpagrahas, Amsha, AmshaRequest,
     ArudhaResult, AshtakavargaResult, AvasthaInputs, Bhava, BhavaBalaBirthPeriod, BhavaBalaInputs,
     BhavaBalaResult, BhavaConfig, BhavaResult, CharakarakaResult, CharakarakaScheme,
-    DIG_BALA_BHAVA, Dignity, DrishtiEntry, Graha, GrahaAvasthas, GrahaDrishtiMatrix,
-    KalaBalaInputs, LajjitadiInputs, LunarNode, NodeDignityPolicy, NodeMode, SAPTA_GRAHAS,
-    SayanadiInputs, SayanadiResult, ShadbalaInputs, TimeUpagrahaConfig, all_avasthas,
+    CombustionConfig, DIG_BALA_BHAVA, Dignity, DrishtiEntry, Graha, GrahaAvasthas, GrahaBodyExt,
+    GrahaDrishtiMatrix, KalaBalaInputs, LajjitadiInputs, LunarNode, NodeDignityPolicy, NodeMode,
+    SAPTA_GRAHAS, SayanadiInputs, SayanadiResult, ShadbalaInputs, TimeUpagrahaConfig, all_avasthas,
     all_combustion_status, all_shadbalas_from_inputs, all_sphutas, amsha_longitude, baladi_avastha,
     bhava_bala_entry, bhrigu_bindu, calculate_ashtakavarga, calculate_bhava_bala,
     charakarakas_from_longitudes, compound_dignity_in_rashi, compute_bhavas,
     deeptadi_avasthas_with_dynamic_nature, default_amsha_variation,
     dignity_in_rashi_with_positions, ghati_lagna, ghatikas_since_sunrise, graha_drishti,
     graha_drishti_matrix, hora_lagna, hora_lord as graha_hora_lord, is_valid_amsha_variation,
-    jagradadi_avastha, jd_tdb_to_centuries, kala_abda_lord, kala_masa_lord, lagna_longitude_rad,
-    lajjitadi_avasthas_with_dynamic_nature, lost_planetary_war, lunar_node_deg_for_epoch_on_plane,
-    nakshatra_from_longitude, node_dignity_in_rashi, node_dignity_in_rashi_with_temporal_context,
-    normalize_360, nth_rashi_from, own_signs, pranapada_lagna, rashi_from_longitude,
-    rashi_lord_by_index, sayanadi_all_sub_states, sayanadi_avastha, shadbala_from_inputs,
-    sree_lagna, sun_based_upagrahas, time_upagraha_jd_with_config, vaar_lord as graha_vaar_lord,
+    jagradadi_avastha, jd_tdb_to_centuries, kala_abda_lord, kala_masa_lord, ketu_from_rahu,
+    lagna_longitude_rad, lajjitadi_avasthas_with_dynamic_nature, lost_planetary_war,
+    lunar_node_deg_for_epoch_on_plane, nakshatra_from_longitude, node_dignity_in_rashi,
+    node_dignity_in_rashi_with_temporal_context, normalize_360, nth_rashi_from, own_signs,
+    pranapada_lagna, rashi_from_longitude, rashi_lord_by_index, sayanadi_all_sub_states,
+    sayanadi_avastha, shadbala_from_inputs, sree_lagna, sun_based_upagrahas,
+    time_upagraha_jd_with_config, vaar_lord as graha_vaar_lord,
 };
 
-use crate::conjunction::{body_ecliptic_lon_lat, body_ecliptic_state, body_lon_lat_on_plane};
+use crate::conjunction::{
+    bodies_lon_lat_on_plane, bodies_lon_speed_on_plane, body_ecliptic_lon_lat,
+    body_lon_lat_on_plane,
+};
 use crate::dasha::{
     DashaInputs, dasha_hierarchy_with_inputs, dasha_snapshot_with_inputs, is_rashi_system,
     needs_moon_lon, needs_sunrise_sunset,
@@ -54,8 +59,9 @@ use crate::jyotish_types::{
     BhavaResultSet, BindusConfig, BindusResult, DashaSelectionConfig, DashaSnapshotTime,
     DrishtiConfig, DrishtiResult, FullKundaliConfig, FullKundaliResult, GrahaEntry,
     GrahaLongitudeKind, GrahaLongitudes, GrahaLongitudesConfig, GrahaPositions,
-    GrahaPositionsConfig, MAX_AMSHA_REQUESTS, MovingOsculatingApogeeEntry, MovingOsculatingApogees,
-    ShadbalaEntry, ShadbalaResult, SphutalResult, VimsopakaEntry, VimsopakaResult,
+    GrahaPositionsConfig, GrahaPositionsTimeAnchor, MAX_AMSHA_REQUESTS,
+    MovingOsculatingApogeeEntry, MovingOsculatingApogees, NavamsaChart, ShadbalaEntry,
+    ShadbalaResult, SphutalResult, VimsopakaEntry, VimsopakaResult,
 };
 use crate::panchang::{
     hora_from_sunrises, masa_for_date_with_eop, panchang_for_date, varsha_for_date_with_eop,
@@ -63,6 +69,7 @@ use crate::panchang::{
 };
 use crate::panchang_types::{MasaInfo, VarshaInfo};
 use crate::sankranti_types::SankrantiConfig;
+use crate::search_util::normalize_to_pm180;
 
 const BHAVABALA_TWILIGHT_HALF_DAYS: f64 = 2.5 / 60.0;
 /// IAU 2015 nominal solar gravitational parameter, km^3/s^2.
@@ -206,20 +213,6 @@ impl ResolvedAmshaPlan {
     }
 }
 
-/// Map a Graha to its dhruv_core::Body for engine queries.
-fn graha_to_body(graha: Graha) -> Option<Body> {
-    match graha {
-        Graha::Surya => Some(Body::Sun),
-        Graha::Chandra => Some(Body::Moon),
-        Graha::Mangal => Some(Body::Mars),
-        Graha::Buddh => Some(Body::Mercury),
-        Graha::Guru => Some(Body::Jupiter),
-        Graha::Shukra => Some(Body::Venus),
-        Graha::Shani => Some(Body::Saturn),
-        Graha::Rahu | Graha::Ketu => None,
-    }
-}
-
 fn outer_planet_longitudes_for_config(
     engine: &Engine,
     jd_tdb: f64,
@@ -237,13 +230,7 @@ fn outer_planet_longitudes_for_config(
         0.0
     };
     let aya = if config.kind == GrahaLongitudeKind::Sidereal {
-        dhruv_vedic_base::ayanamsha_deg_on_plane(
-            config.ayanamsha_system,
-            jd_tdb_to_centuries(jd_tdb),
-            config.use_nutation,
-            config.precession_model,
-            config.reference_plane,
-        )
+        config.ayanamsha_deg_at_centuries(jd_tdb_to_centuries(jd_tdb))
     } else {
         0.0
     };
@@ -299,6 +286,8 @@ pub fn siderealize_bhava_result(
             tropical_to_sidereal_longitude(bhava.start_deg, ayanamsha_deg, reference_plane);
         bhava.end_deg =
             tropical_to_sidereal_longitude(bhava.end_deg, ayanamsha_deg, reference_plane);
+        bhava.madhya_deg =
+            tropical_to_sidereal_longitude(bhava.madhya_deg, ayanamsha_deg, reference_plane);
     }
     BhavaResult {
         bhavas,
@@ -333,6 +322,7 @@ fn rashi_bhava_result_from_lagna(lagna_sid: f64) -> BhavaResult {
         cusp_deg: 0.0,
         start_deg: 0.0,
         end_deg: 0.0,
+        madhya_deg: 0.0,
     }; 12];
     for (i, bhava) in bhavas.iter_mut().enumerate() {
         let rashi = (lagna_rashi + i as u8) % 12;
@@ -344,6 +334,7 @@ fn rashi_bhava_result_from_lagna(lagna_sid: f64) -> BhavaResult {
             cusp_deg: cusp,
             start_deg: cusp,
             end_deg: end,
+            madhya_deg: normalize(cusp + 15.0),
         };
     }
     BhavaResult {
@@ -473,13 +464,7 @@ impl JyotishContext {
             let lons = graha_longitudes(
                 engine,
                 self.jd_tdb,
-                &GrahaLongitudesConfig::sidereal_with_model(
-                    aya_config.ayanamsha_system,
-                    aya_config.use_nutation,
-                    aya_config.precession_model,
-                    aya_config.reference_plane,
-                )
-                .with_outer_planets(false),
+                &GrahaLongitudesConfig::from_sankranti_config(aya_config).with_outer_planets(false),
             )?;
             self.graha_lons = Some(lons);
         }
@@ -494,12 +479,7 @@ impl JyotishContext {
         if let Some(lons) = self.outer_planet_lons {
             return Ok(lons);
         }
-        let config = GrahaLongitudesConfig::sidereal_with_model(
-            aya_config.ayanamsha_system,
-            aya_config.use_nutation,
-            aya_config.precession_model,
-            aya_config.reference_plane,
-        );
+        let config = GrahaLongitudesConfig::from_sankranti_config(aya_config);
         let lons = outer_planet_longitudes_for_config(engine, self.jd_tdb, &config)?;
         self.outer_planet_lons = Some(lons);
         Ok(lons)
@@ -710,20 +690,25 @@ impl JyotishContext {
         Ok(pair)
     }
 
+    /// Sunset of the Vedic day that contains the query instant.
+    ///
+    /// Anchored on the Vedic-day sunrise rather than the UTC civil day, so a
+    /// pre-dawn moment pairs with the previous evening's sunset.
     fn sunset_jd(
         &mut self,
         engine: &Engine,
         eop: &EopKernel,
+        utc: &UtcTime,
         location: &GeoLocation,
         riseset_config: &RiseSetConfig,
     ) -> Result<f64, SearchError> {
         if let Some(jd) = self.sunset_jd {
             return Ok(jd);
         }
-        let noon_jd = dhruv_vedic_base::approximate_local_noon_jd(
-            dhruv_vedic_base::utc_day_start_jd(self.jd_utc),
-            location.longitude_deg,
-        );
+        let (jd_sunrise, _) = self.sunrise_pair(engine, eop, utc, location, riseset_config)?;
+        // compute_rise_set snaps to the transit nearest its anchor; a quarter
+        // day after sunrise is always within half a day of that day's transit.
+        let noon_jd = jd_sunrise + 0.25;
         let sunset_result = compute_rise_set(
             engine,
             engine.lsk(),
@@ -794,7 +779,8 @@ impl JyotishContext {
         if let Some(speeds) = self.graha_speeds {
             return Ok(speeds);
         }
-        let speeds = query_sapta_graha_speeds(engine, self.jd_tdb)?;
+        let all = graha_speeds(engine, self.jd_tdb, &GrahaLongitudesConfig::tropical(false))?;
+        let speeds = std::array::from_fn(|i| all[i]);
         self.graha_speeds = Some(speeds);
         Ok(speeds)
     }
@@ -949,7 +935,7 @@ impl JyotishContext {
         let plane = self.reference_plane;
         let (jd_sunrise, jd_next_sunrise) =
             self.sunrise_pair(engine, eop, utc, location, riseset_config)?;
-        let jd_sunset = self.sunset_jd(engine, eop, location, riseset_config)?;
+        let jd_sunset = self.sunset_jd(engine, eop, utc, location, riseset_config)?;
         let is_day = jd_tdb >= jd_sunrise && jd_tdb < jd_sunset;
         let weekday = vaar_from_jd(jd_sunrise).index();
 
@@ -1069,20 +1055,6 @@ impl JyotishContext {
     }
 }
 
-/// Query ecliptic-of-date longitude speed (deg/day) for all 7 sapta grahas.
-///
-/// Uses `body_ecliptic_state` which finite-differences fully-precessed
-/// of-date longitudes to capture the complete velocity frame correction.
-fn query_sapta_graha_speeds(engine: &Engine, jd_tdb: f64) -> Result<[f64; 7], SearchError> {
-    let mut speeds = [0.0f64; 7];
-    for graha in SAPTA_GRAHAS {
-        let body = graha_to_body(graha).expect("sapta graha has body");
-        let (_, _, lon_speed) = body_ecliptic_state(engine, body, jd_tdb)?;
-        speeds[graha.index() as usize] = lon_speed;
-    }
-    Ok(speeds)
-}
-
 /// Query ecliptic declination (deg) for all 7 sapta grahas.
 ///
 /// Declination = arcsin(sin(lat)*cos(eps) + cos(lat)*sin(eps)*sin(lon))
@@ -1095,7 +1067,7 @@ fn query_sapta_graha_declinations(engine: &Engine, jd_tdb: f64) -> Result<[f64;
     let cos_eps = eps.cos();
     let mut decls = [0.0f64; 7];
     for graha in SAPTA_GRAHAS {
-        let body = graha_to_body(graha).expect("sapta graha has body");
+        let body = graha.to_body().expect("sapta graha has body");
         let (lon_deg, lat_deg) = body_ecliptic_lon_lat(engine, body, jd_tdb)?;
         let lon_rad = lon_deg.to_radians();
         let lat_rad = lat_deg.to_radians();
@@ -1118,7 +1090,7 @@ fn query_sapta_graha_longitude_only_krantis(
     let sin_eps = eps.sin();
     let mut krantis = [0.0f64; 7];
     for graha in SAPTA_GRAHAS {
-        let body = graha_to_body(graha).expect("sapta graha has body");
+        let body = graha.to_body().expect("sapta graha has body");
         let (lon_deg, _) = body_ecliptic_lon_lat(engine, body, jd_tdb)?;
         let sin_dec = sin_eps * lon_deg.to_radians().sin();
         krantis[graha.index() as usize] = sin_dec.clamp(-1.0, 1.0).asin().to_degrees();
@@ -1245,13 +1217,7 @@ fn osculating_longitudes_from_heliocentric_state(
     let aphelion_reference_plane_longitude =
         normalize_360(periapsis_reference_plane_longitude + 180.0);
     let t = jd_tdb_to_centuries(jd_tdb);
-    let ayanamsha_deg = dhruv_vedic_base::ayanamsha_deg_on_plane(
-        config.ayanamsha_system,
-        t,
-        config.use_nutation,
-        config.precession_model,
-        config.reference_plane,
-    );
+    let ayanamsha_deg = config.ayanamsha_deg_at_centuries(t);
 
     Ok(OsculatingLongitudes {
         mean_sidereal_longitude: normalize_360(
@@ -1414,6 +1380,52 @@ pub fn graha_longitudes(
     }
 }
 
+/// Longitude of one graha in the frame of [`graha_longitudes`], querying only
+/// that body (Ketu is derived from the Rahu node).
+pub(crate) fn graha_longitude(
+    engine: &Engine,
+    jd_tdb: f64,
+    graha: Graha,
+    config: &GrahaLongitudesConfig,
+) -> Result<f64, SearchError> {
+    let on_plane = match graha.to_body() {
+        Some(body) => {
+            body_lon_lat_on_plane(
+                engine,
+                body,
+                jd_tdb,
+                config.precession_model,
+                config.reference_plane,
+            )?
+            .0
+        }
+        None => lunar_node_deg_for_epoch_on_plane(
+            engine,
+            LunarNode::Rahu,
+            jd_tdb,
+            NodeMode::True,
+            config.precession_model,
+            config.reference_plane,
+        )?,
+    };
+    let t = jd_tdb_to_centuries(jd_tdb);
+    let offset = match config.kind {
+        GrahaLongitudeKind::Sidereal => -config.ayanamsha_deg_at_centuries(t),
+        GrahaLongitudeKind::Tropical
+            if config.use_nutation && config.reference_plane == ReferencePlane::Ecliptic =>
+        {
+            dhruv_frames::nutation_iau2000b(t).0 / 3600.0
+        }
+        GrahaLongitudeKind::Tropical => 0.0,
+    };
+    let lon = normalize(on_plane + offset);
+    Ok(if graha == Graha::Ketu {
+        ketu_from_rahu(lon)
+    } else {
+        lon
+    })
+}
+
 /// Query Uranus, Neptune, and Pluto longitudes in the same frame as `graha_longitudes`.
 ///
 /// Returned order is `[Uranus, Neptune, Pluto]`.
@@ -1425,19 +1437,73 @@ pub fn outer_planet_longitudes(
     outer_planet_longitudes_for_config(engine, jd_tdb, config)
 }
 
+/// Query longitude speeds (deg/day) for all 9 grahas at a given TDB epoch.
+///
+/// Speeds are the rate of the longitudes returned by `graha_longitudes` for
+/// the same config. Surya..Shani come from the ephemeris state velocity of
+/// one batched query. Rahu/Ketu follow the true node model, whose rate needs
+/// the Moon's acceleration, so the node is differenced at t±1 min; their
+/// speeds are normally negative. The rate of the ayanamsha (sidereal) or
+/// nutation (tropical with `use_nutation`) offset is included. Outer planets
+/// are not computed.
+pub fn graha_speeds(
+    engine: &Engine,
+    jd_tdb: f64,
+    config: &GrahaLongitudesConfig,
+) -> Result<[f64; 9], SearchError> {
+    const DT: f64 = 1.0 / 1440.0; // 1-minute step in JD days
+    // Offset `graha_longitudes` adds to reference-plane longitudes.
+    let offset_deg = |jd: f64| {
+        let t = jd_tdb_to_centuries(jd);
+        match config.kind {
+            GrahaLongitudeKind::Sidereal => -config.ayanamsha_deg_at_centuries(t),
+            GrahaLongitudeKind::Tropical
+                if config.use_nutation && config.reference_plane == ReferencePlane::Ecliptic =>
+            {
+                dhruv_frames::nutation_iau2000b(t).0 / 3600.0
+            }
+            GrahaLongitudeKind::Tropical => 0.0,
+        }
+    };
+    let offset_rate = (offset_deg(jd_tdb + DT) - offset_deg(jd_tdb - DT)) / (2.0 * DT);
+
+    let bodies = SAPTA_GRAHAS.map(|graha| graha.to_body().expect("sapta graha has body"));
+    let plane_speeds = bodies_lon_speed_on_plane(
+        engine,
+        &bodies,
+        jd_tdb,
+        config.precession_model,
+        config.reference_plane,
+    )?;
+
+    let rahu_at = |jd: f64| {
+        lunar_node_deg_for_epoch_on_plane(
+            engine,
+            LunarNode::Rahu,
+            jd,
+            NodeMode::True,
+            config.precession_model,
+            config.reference_plane,
+        )
+    };
+    let rahu_speed = normalize_to_pm180(rahu_at(jd_tdb + DT)? - rahu_at(jd_tdb - DT)?) / (2.0 * DT);
+
+    let mut speeds = [0.0f64; 9];
+    for (speed, plane_speed) in speeds.iter_mut().zip(plane_speeds) {
+        *speed = plane_speed + offset_rate;
+    }
+    speeds[Graha::Rahu.index() as usize] = rahu_speed + offset_rate;
+    speeds[Graha::Ketu.index() as usize] = rahu_speed + offset_rate;
+    Ok(speeds)
+}
+
 fn graha_sidereal_longitudes_for_config(
     engine: &Engine,
     jd_tdb: f64,
     config: &GrahaLongitudesConfig,
 ) -> Result<GrahaLongitudes, SearchError> {
     let t = jd_tdb_to_centuries(jd_tdb);
-    let aya = dhruv_vedic_base::ayanamsha_deg_on_plane(
-        config.ayanamsha_system,
-        t,
-        config.use_nutation,
-        config.precession_model,
-        config.reference_plane,
-    );
+    let aya = config.ayanamsha_deg_at_centuries(t);
     let rahu_on_plane = lunar_node_deg_for_epoch_on_plane(
         engine,
         LunarNode::Rahu,
@@ -1446,32 +1512,17 @@ fn graha_sidereal_longitudes_for_config(
         config.precession_model,
         config.reference_plane,
     )?;
-    let ketu_on_plane = normalize(rahu_on_plane + 180.0);
+    let rahu = normalize(rahu_on_plane - aya);
 
     let mut longitudes = [0.0f64; 9];
-
-    for graha in ALL_GRAHAS {
-        let idx = graha.index() as usize;
-        match graha {
-            Graha::Rahu => {
-                longitudes[idx] = normalize(rahu_on_plane - aya);
-            }
-            Graha::Ketu => {
-                longitudes[idx] = normalize(ketu_on_plane - aya);
-            }
-            _ => {
-                let body = graha_to_body(graha).expect("sapta graha has body");
-                let (lon, _lat) = body_lon_lat_on_plane(
-                    engine,
-                    body,
-                    jd_tdb,
-                    config.precession_model,
-                    config.reference_plane,
-                )?;
-                longitudes[idx] = normalize(lon - aya);
-            }
-        }
+    for (idx, lon) in sapta_graha_plane_longitudes(engine, jd_tdb, config)?
+        .into_iter()
+        .enumerate()
+    {
+        longitudes[idx] = normalize(lon - aya);
     }
+    longitudes[Graha::Rahu.index() as usize] = rahu;
+    longitudes[Graha::Ketu.index() as usize] = ketu_from_rahu(rahu);
 
     let outer_planets = if config.include_outer_planets {
         Some(outer_planet_longitudes_for_config(engine, jd_tdb, config)?)
@@ -1485,6 +1536,24 @@ fn graha_sidereal_longitudes_for_config(
     })
 }
 
+/// Surya..Shani longitudes on `config.reference_plane` (indexed like
+/// `SAPTA_GRAHAS`), from one batched engine query at `jd_tdb`.
+fn sapta_graha_plane_longitudes(
+    engine: &Engine,
+    jd_tdb: f64,
+    config: &GrahaLongitudesConfig,
+) -> Result<[f64; 7], SearchError> {
+    let bodies = SAPTA_GRAHAS.map(|graha| graha.to_body().expect("sapta graha has body"));
+    let lon_lat = bodies_lon_lat_on_plane(
+        engine,
+        &bodies,
+        jd_tdb,
+        config.precession_model,
+        config.reference_plane,
+    )?;
+    Ok(std::array::from_fn(|i| lon_lat[i].0))
+}
+
 fn graha_reference_plane_longitudes(
     engine: &Engine,
     jd_tdb: f64,
@@ -1506,27 +1575,17 @@ fn graha_reference_plane_longitudes(
         config.precession_model,
         config.reference_plane,
     )?;
-    let ketu_tropical = normalize(rahu_tropical + 180.0);
+    let rahu = normalize(rahu_tropical + dpsi_deg);
 
     let mut longitudes = [0.0f64; 9];
-    for graha in ALL_GRAHAS {
-        let idx = graha.index() as usize;
-        match graha {
-            Graha::Rahu => longitudes[idx] = normalize(rahu_tropical + dpsi_deg),
-            Graha::Ketu => longitudes[idx] = normalize(ketu_tropical + dpsi_deg),
-            _ => {
-                let body = graha_to_body(graha).expect("sapta graha has body");
-                let (lon, _lat) = body_lon_lat_on_plane(
-                    engine,
-                    body,
-                    jd_tdb,
-                    config.precession_model,
-                    config.reference_plane,
-                )?;
-                longitudes[idx] = normalize(lon + dpsi_deg);
-            }
-        }
+    for (idx, lon) in sapta_graha_plane_longitudes(engine, jd_tdb, config)?
+        .into_iter()
+        .enumerate()
+    {
+        longitudes[idx] = normalize(lon + dpsi_deg);
     }
+    longitudes[Graha::Rahu.index() as usize] = rahu;
+    longitudes[Graha::Ketu.index() as usize] = ketu_from_rahu(rahu);
     let outer_planets = if config.include_outer_planets {
         Some(outer_planet_longitudes_for_config(engine, jd_tdb, config)?)
     } else {
@@ -1782,7 +1841,8 @@ fn all_upagrahas_for_date_with_ctx(
 ///
 /// Central orchestration function: computes sidereal longitudes for all 9 grahas,
 /// optionally adding nakshatra/pada, lagna, outer planets (Uranus/Neptune/Pluto),
-/// and bhava placement.
+/// and bhava placement. `config.time_anchor` selects whether the chart is cast
+/// for `utc` itself or for the sunrise starting its Vedic day.
 pub fn graha_positions(
     engine: &Engine,
     eop: &EopKernel,
@@ -1792,7 +1852,14 @@ pub fn graha_positions(
     aya_config: &SankrantiConfig,
     config: &GrahaPositionsConfig,
 ) -> Result<GrahaPositions, SearchError> {
-    let mut ctx = JyotishContext::new(engine, Some(eop), utc, aya_config);
+    let anchor_utc = match config.time_anchor {
+        GrahaPositionsTimeAnchor::Exact => *utc,
+        GrahaPositionsTimeAnchor::Sunrise(riseset_config) => {
+            let (sunrise_jd, _) = vedic_day_sunrises(engine, eop, utc, location, &riseset_config)?;
+            UtcTime::from_jd_tdb(sunrise_jd, engine.lsk())
+        }
+    };
+    let mut ctx = JyotishContext::new(engine, Some(eop), &anchor_utc, aya_config);
     graha_positions_with_ctx(
         engine,
         eop,
@@ -1915,6 +1982,15 @@ fn make_graha_entry(
     let rashi_bhava_number = rashi_bhava_lagna_sid
         .map(|lagna_sid| rashi_bhava_number_from_lagna(lagna_sid, sid_lon))
         .unwrap_or(0);
+    let (gandanta, rashi_sandhi, pushkara) = if config.include_flags {
+        (
+            is_gandanta(sid_lon),
+            is_rashi_sandhi(sid_lon),
+            is_pushkara(sid_lon),
+        )
+    } else {
+        (false, false, false)
+    };
     GrahaEntry {
         sidereal_longitude: sid_lon,
         rashi: rashi_info.rashi,
@@ -1924,6 +2000,9 @@ fn make_graha_entry(
         pada,
         bhava_number,
         rashi_bhava_number,
+        gandanta,
+        rashi_sandhi,
+        pushkara,
     }
 }
 
@@ -1974,6 +2053,8 @@ fn ashtakavarga_with_ctx(
         include_lagna: true,
         include_outer_planets: false,
         include_bhava: false,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
     let bhava_config = BhavaConfig::default();
     let positions = graha_positions_with_ctx(
@@ -2062,6 +2143,8 @@ fn core_bindus_with_ctx(
         include_lagna: false,
         include_outer_planets: false,
         include_bhava: config.include_bhava,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let graha_lons = *ctx.graha_lons(engine, aya_config)?;
@@ -2380,6 +2463,11 @@ pub fn full_kundali_for_date(
     aya_config: &SankrantiConfig,
     config: &FullKundaliConfig,
 ) -> Result<FullKundaliResult, SearchError> {
+    if config.graha_positions_config.time_anchor != GrahaPositionsTimeAnchor::Exact {
+        return Err(SearchError::InvalidConfig(
+            "full kundali graha positions must use the exact time anchor",
+        ));
+    }
     let mut ctx = JyotishContext::new(engine, Some(eop), utc, aya_config);
     let amsha_plan = resolve_amsha_plan(
         &config.amsha_selection,
@@ -2881,7 +2969,7 @@ fn build_dasha_inputs_from_ctx<'a>(
 
     let sunrise_sunset = if needs_sunrise_sunset(system) {
         let (sunrise, _) = ctx.sunrise_pair(engine, eop, utc, location, riseset_config)?;
-        let sunset = ctx.sunset_jd(engine, eop, location, riseset_config)?;
+        let sunset = ctx.sunset_jd(engine, eop, utc, location, riseset_config)?;
         Some((sunrise, sunset))
     } else {
         None
@@ -2903,8 +2991,34 @@ type KundaliDashaResults = (
     Option<Vec<dhruv_vedic_base::DashaSnapshot>>,
 );
 
+/// Assemble sphuta inputs for a given date and location.
+///
+/// Gulika comes from the time-based upagraha computation (sunrise/sunset
+/// portions) under `riseset_config` and `upagraha_config`.
+pub fn sphuta_inputs_for_date(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+    aya_config: &SankrantiConfig,
+    upagraha_config: &TimeUpagrahaConfig,
+) -> Result<dhruv_vedic_base::SphutalInputs, SearchError> {
+    let mut ctx = JyotishContext::new(engine, Some(eop), utc, aya_config);
+    sphuta_inputs_with_ctx(
+        engine,
+        eop,
+        utc,
+        location,
+        riseset_config,
+        aya_config,
+        upagraha_config,
+        &mut ctx,
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
-fn all_sphuta_lons_with_ctx(
+fn sphuta_inputs_with_ctx(
     engine: &Engine,
     eop: &EopKernel,
     utc: &UtcTime,
@@ -2913,14 +3027,8 @@ fn all_sphuta_lons_with_ctx(
     aya_config: &SankrantiConfig,
     upagraha_config: &TimeUpagrahaConfig,
     ctx: &mut JyotishContext,
-) -> Result<[f64; 16], SearchError> {
+) -> Result<dhruv_vedic_base::SphutalInputs, SearchError> {
     let gl = *ctx.graha_lons(engine, aya_config)?;
-    let sun_sid = gl.longitude(Graha::Surya);
-    let moon_sid = gl.longitude(Graha::Chandra);
-    let rahu_sid = gl.longitude(Graha::Rahu);
-    let mars_sid = gl.longitude(Graha::Mangal);
-    let jupiter_sid = gl.longitude(Graha::Guru);
-    let venus_sid = gl.longitude(Graha::Shukra);
     let lagna_sid = ctx.lagna_sid(engine, eop, location)?;
 
     let upagrahas = *ctx.upagrahas(
@@ -2936,19 +3044,41 @@ fn all_sphuta_lons_with_ctx(
     let eighth_cusp_sid = normalize(lagna_sid + 210.0);
     let eighth_rashi_idx = (eighth_cusp_sid / 30.0).floor().min(11.0) as u8;
     let eighth_lord = rashi_lord_by_index(eighth_rashi_idx).unwrap_or(Graha::Surya);
-    let eighth_lord_lon = gl.longitude(eighth_lord);
-
-    let inputs = dhruv_vedic_base::SphutalInputs {
-        sun: sun_sid,
-        moon: moon_sid,
-        mars: mars_sid,
-        jupiter: jupiter_sid,
-        venus: venus_sid,
-        rahu: rahu_sid,
-        lagna: lagna_sid,
-        eighth_lord: eighth_lord_lon,
-        gulika: upagrahas.gulika,
-    };
+
+    Ok(dhruv_vedic_base::SphutalInputs {
+        sun: gl.longitude(Graha::Surya).into(),
+        moon: gl.longitude(Graha::Chandra).into(),
+        mars: gl.longitude(Graha::Mangal).into(),
+        jupiter: gl.longitude(Graha::Guru).into(),
+        venus: gl.longitude(Graha::Shukra).into(),
+        rahu: gl.longitude(Graha::Rahu).into(),
+        lagna: lagna_sid.into(),
+        eighth_lord: gl.longitude(eighth_lord).into(),
+        gulika: upagrahas.gulika.into(),
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn all_sphuta_lons_with_ctx(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+    aya_config: &SankrantiConfig,
+    upagraha_config: &TimeUpagrahaConfig,
+    ctx: &mut JyotishContext,
+) -> Result<[f64; 16], SearchError> {
+    let inputs = sphuta_inputs_with_ctx(
+        engine,
+        eop,
+        utc,
+        location,
+        riseset_config,
+        aya_config,
+        upagraha_config,
+        ctx,
+    )?;
     let all = all_sphutas(&inputs);
     let mut lons = [0.0f64; 16];
     for (i, (_sphuta, lon)) in all.iter().enumerate() {
@@ -3402,12 +3532,7 @@ fn assemble_shadbala_inputs(
     }
 
     // 3. Correction-model motion inputs for Cheshta Bala (Surya/Chandra remain 0).
-    let cheshta_config = GrahaLongitudesConfig::sidereal_with_model(
-        aya_config.ayanamsha_system,
-        aya_config.use_nutation,
-        aya_config.precession_model,
-        aya_config.reference_plane,
-    );
+    let cheshta_config = GrahaLongitudesConfig::from_sankranti_config(aya_config);
     let mut cheshta_madhyama_lons = [0.0f64; 7];
     let mut cheshta_chaloccha_lons = [0.0f64; 7];
     for graha in [
@@ -3428,9 +3553,40 @@ fn assemble_shadbala_inputs(
         assemble_varga_data(engine, aya_config, ctx, amsha_plan)?;
 
     // 5. Kala Bala inputs
+    let kala =
+        assemble_kala_bala_inputs(engine, eop, utc, location, riseset_config, aya_config, ctx)?;
+
+    Ok(ShadbalaInputs {
+        sidereal_lons,
+        bhava_numbers,
+        dig_bala_max_cusp_lons,
+        cheshta_madhyama_lons,
+        cheshta_chaloccha_lons,
+        kala,
+        include_node_aspects_for_drik_bala: bhava_config.include_node_aspects_for_drik_bala,
+        divide_guru_buddh_drishti_by_4_for_drik_bala: bhava_config
+            .divide_guru_buddh_drishti_by_4_for_drik_bala,
+        chandra_benefic_rule: bhava_config.chandra_benefic_rule,
+        varga_rashi_indices,
+        varga_longitudes,
+    })
+}
+
+/// Assemble sunrise-anchored KalaBalaInputs for the context instant.
+fn assemble_kala_bala_inputs(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+    aya_config: &SankrantiConfig,
+    ctx: &mut JyotishContext,
+) -> Result<KalaBalaInputs, SearchError> {
+    let sidereal_lons = ctx.graha_lons(engine, aya_config)?.longitudes;
+
     let (jd_sunrise, jd_next_sunrise) =
         ctx.sunrise_pair(engine, eop, utc, location, riseset_config)?;
-    let jd_sunset = ctx.sunset_jd(engine, eop, location, riseset_config)?;
+    let jd_sunset = ctx.sunset_jd(engine, eop, utc, location, riseset_config)?;
 
     let is_daytime = ctx.jd_tdb >= jd_sunrise && ctx.jd_tdb < jd_sunset;
     let local_day_fraction = (ctx.jd_tdb + 0.5 + location.longitude_deg / 360.0).rem_euclid(1.0);
@@ -3472,39 +3628,51 @@ fn assemble_shadbala_inputs(
     let (ayana_krantis, ayana_obliquity_deg) = ctx.ayana_krantis(engine)?;
     let graha_declinations = ctx.graha_declinations(engine)?;
 
-    Ok(ShadbalaInputs {
-        sidereal_lons,
-        bhava_numbers,
-        dig_bala_max_cusp_lons,
-        cheshta_madhyama_lons,
-        cheshta_chaloccha_lons,
-        kala: KalaBalaInputs {
-            is_daytime,
-            day_night_fraction,
-            local_day_fraction,
-            moon_sun_elongation,
-            year_lord,
-            month_lord,
-            weekday_lord,
-            hora_lord: hora_lord_graha,
-            ayana_krantis,
-            ayana_obliquity_deg,
-            graha_declinations,
-            sidereal_lons: {
-                let mut s7 = [0.0f64; 7];
-                s7.copy_from_slice(&sidereal_lons[..7]);
-                s7
-            },
-        },
-        include_node_aspects_for_drik_bala: bhava_config.include_node_aspects_for_drik_bala,
-        divide_guru_buddh_drishti_by_4_for_drik_bala: bhava_config
-            .divide_guru_buddh_drishti_by_4_for_drik_bala,
-        chandra_benefic_rule: bhava_config.chandra_benefic_rule,
-        varga_rashi_indices,
-        varga_longitudes,
+    let mut sapta_lons = [0.0f64; 7];
+    sapta_lons.copy_from_slice(&sidereal_lons[..7]);
+    Ok(KalaBalaInputs {
+        is_daytime,
+        day_night_fraction,
+        local_day_fraction,
+        moon_sun_elongation,
+        year_lord,
+        month_lord,
+        weekday_lord,
+        hora_lord: hora_lord_graha,
+        ayana_krantis,
+        ayana_obliquity_deg,
+        graha_declinations,
+        sidereal_lons: sapta_lons,
     })
 }
 
+/// Sunrise-anchored Kala Bala inputs for a birth instant.
+///
+/// Day/night and the tribhaga fraction are measured from the Vedic-day
+/// sunrise, sunset, and next sunrise at `location`; the weekday and hora
+/// lords count from that same sunrise, and the year/month lords use the
+/// kala day-count formula at the varsha and masa starts. This is the input
+/// block `shadbala_for_date` feeds to `kala_bala`.
+pub fn kala_bala_inputs_for_date(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+    aya_config: &SankrantiConfig,
+) -> Result<KalaBalaInputs, SearchError> {
+    let mut ctx = JyotishContext::new(engine, Some(eop), utc, aya_config);
+    assemble_kala_bala_inputs(
+        engine,
+        eop,
+        utc,
+        location,
+        riseset_config,
+        aya_config,
+        &mut ctx,
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 fn assemble_bhavabala_inputs(
     engine: &Engine,
@@ -3545,7 +3713,7 @@ fn assemble_bhavabala_inputs(
 
     let (vedic_sunrise, _next_sunrise) =
         ctx.sunrise_pair(engine, eop, utc, location, riseset_config)?;
-    let vedic_sunset = ctx.sunset_jd(engine, eop, location, riseset_config)?;
+    let vedic_sunset = ctx.sunset_jd(engine, eop, utc, location, riseset_config)?;
 
     let birth_period = classify_bhavabala_birth_period(ctx.jd_tdb, vedic_sunrise, vedic_sunset);
     Ok(BhavaBalaInputs {
@@ -3838,8 +4006,9 @@ fn assemble_avastha_inputs(
             node_dignity_in_rashi(graha, rashi_indices[idx], &rashi_indices, node_policy);
     }
 
-    // 6. Combustion
-    let is_combust = all_combustion_status(&sidereal_lons, &is_retrograde);
+    // 6. Combustion (avasthas use the default BPHS Moon orb)
+    let is_combust =
+        all_combustion_status(&sidereal_lons, &is_retrograde, &CombustionConfig::default());
 
     // 7. War detection (indices 2-6 only)
     let mut lost_war = [false; 9];
@@ -4169,6 +4338,29 @@ fn build_amsha_chart(
     }
 }
 
+/// Navamsa (D9) chart: lagna and nine grahas in one call.
+///
+/// A lighter alternative to [`amsha_charts_for_date`] for the most-used
+/// varga: no bhava, upagraha, or sphuta work, and the rasi positions the
+/// navamsa derives from are returned alongside.
+pub fn navamsa_chart(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    aya_config: &SankrantiConfig,
+) -> Result<NavamsaChart, SearchError> {
+    let mut ctx = JyotishContext::new(engine, Some(eop), utc, aya_config);
+    let graha_lons = ctx.graha_lons(engine, aya_config)?.longitudes;
+    let lagna_sid = ctx.lagna_sid(engine, eop, location)?;
+    Ok(NavamsaChart {
+        rasi_lagna: make_amsha_entry(lagna_sid),
+        lagna: transform_to_amsha_entry(lagna_sid, Amsha::D9, None),
+        rasi_grahas: graha_lons.map(make_amsha_entry),
+        grahas: graha_lons.map(|lon| transform_to_amsha_entry(lon, Amsha::D9, None)),
+    })
+}
+
 /// Compute amsha charts for all entities at a given date.
 #[allow(clippy::too_many_arguments)]
 pub fn amsha_charts_for_date(
@@ -4190,12 +4382,7 @@ pub fn amsha_charts_for_date(
     // Get D1 graha longitudes
     let graha_lons = *ctx.graha_lons(engine, aya_config)?;
     let outer_planet_lons = if scope.include_outer_planets {
-        let config = GrahaLongitudesConfig::sidereal_with_model(
-            aya_config.ayanamsha_system,
-            aya_config.use_nutation,
-            aya_config.precession_model,
-            aya_config.reference_plane,
-        );
+        let config = GrahaLongitudesConfig::from_sankranti_config(aya_config);
         Some(outer_planet_longitudes_for_config(
             engine, ctx.jd_tdb, &config,
         )?)
@@ -4617,19 +4804,6 @@ mod tests {
     use super::*;
     use dhruv_vedic_base::DEFAULT_AMSHA_VARIATION_CODE;
 
-    #[test]
-    fn graha_to_body_mapping() {
-        assert_eq!(graha_to_body(Graha::Surya), Some(Body::Sun));
-        assert_eq!(graha_to_body(Graha::Chandra), Some(Body::Moon));
-        assert_eq!(graha_to_body(Graha::Mangal), Some(Body::Mars));
-        assert_eq!(graha_to_body(Graha::Buddh), Some(Body::Mercury));
-        assert_eq!(graha_to_body(Graha::Guru), Some(Body::Jupiter));
-        assert_eq!(graha_to_body(Graha::Shukra), Some(Body::Venus));
-        assert_eq!(graha_to_body(Graha::Shani), Some(Body::Saturn));
-        assert_eq!(graha_to_body(Graha::Rahu), None);
-        assert_eq!(graha_to_body(Graha::Ketu), None);
-    }
-
     #[test]
     fn bhavabala_birth_period_uses_two_point_five_ghati_sandhya_windows() {
         let sunrise = 100.25;
@@ -4903,4 +5077,30 @@ mod tests {
         assert_eq!(rashi_bhava_number_from_lagna(lagna, 78.0), 2);
         assert_eq!(rashi_bhava_number_from_lagna(lagna, 17.0), 12);
     }
+
+    #[test]
+    fn graha_entry_flags_at_karka_simha_junction() {
+        let config = GrahaPositionsConfig {
+            include_flags: true,
+            time_anchor: GrahaPositionsTimeAnchor::Exact,
+            ..GrahaPositionsConfig::default()
+        };
+        // 29°50' Karka.
+        let lon = 90.0 + 29.0 + 50.0 / 60.0;
+        let entry = make_graha_entry(lon, &config, None, None, 0.0, ReferencePlane::Ecliptic);
+        assert!(entry.rashi_sandhi);
+        assert!(entry.gandanta);
+        // 9th navamsa of Karka is not pushkara for a water rashi.
+        assert!(!entry.pushkara);
+
+        let off = make_graha_entry(
+            lon,
+            &GrahaPositionsConfig::default(),
+            None,
+            None,
+            0.0,
+            ReferencePlane::Ecliptic,
+        );
+        assert!(!off.rashi_sandhi && !off.gandanta && !off.pushkara);
+    }
 }
@@ -11,7 +11,9 @@ use dhruv_time::UtcTime;
 use crate::conjunction::{next_conjunction, prev_conjunction, search_conjunctions};
 use crate::conjunction_types::ConjunctionConfig;
 use crate::error::SearchError;
-use crate::lunar_phase_types::{LunarPhase, LunarPhaseEvent};
+use crate::lunar_phase_types::{LunarPhase, LunarPhaseEvent, MoonInvisibilityWindow};
+use crate::panchang::elongation_at;
+use crate::search_util::{bracket_and_refine, normalize_to_pm180};
 
 /// Step size for lunar phase search (days).
 /// Half a day gives reliable detection of the ~29.53-day synodic cycle.
@@ -115,3 +117,78 @@ pub fn search_amavasyas(
         .map(|e| conjunction_to_phase(e, LunarPhase::NewMoon, engine.lsk()))
         .collect())
 }
+
+/// Step size for the invisibility-window edge scan (days).
+const INVISIBILITY_STEP_DAYS: f64 = 0.25;
+
+/// Find the Moon's invisibility window around the Amavasya nearest `utc`.
+///
+/// The window runs from the moment the Moon closes to within `threshold_deg`
+/// of the Sun before the conjunction until it separates past `threshold_deg`
+/// afterwards. Pass [`dhruv_vedic_base::MOON_COMBUSTION_DEG`] for the
+/// standard Moon combustion arc, or another arc for traditions that use a
+/// different one.
+pub fn moon_invisibility_window(
+    engine: &Engine,
+    utc: &UtcTime,
+    threshold_deg: f64,
+) -> Result<Option<MoonInvisibilityWindow>, SearchError> {
+    if !(threshold_deg > 0.0 && threshold_deg < 90.0) {
+        return Err(SearchError::InvalidConfig(
+            "threshold_deg must be in (0, 90)",
+        ));
+    }
+    let jd = crate::search_util::utc_to_jd_tdb(engine, utc);
+    let config = make_config(0.0);
+    let prev = prev_conjunction(engine, Body::Sun, Body::Moon, jd, &config)?;
+    let next = next_conjunction(engine, Body::Sun, Body::Moon, jd, &config)?;
+    let amavasya_jd = match (prev, next) {
+        (Some(p), Some(n)) => {
+            if jd - p.jd_tdb <= n.jd_tdb - jd {
+                p.jd_tdb
+            } else {
+                n.jd_tdb
+            }
+        }
+        (Some(p), None) => p.jd_tdb,
+        (None, Some(n)) => n.jd_tdb,
+        (None, None) => return Ok(None),
+    };
+
+    // Signed elongation is negative while waning towards the conjunction
+    // and positive after it; each edge is where it crosses ±threshold.
+    let edge = |target: f64| {
+        move |t: f64| -> Result<f64, SearchError> {
+            Ok(normalize_to_pm180(elongation_at(engine, t)?) - target)
+        }
+    };
+    // Elongation changes by >10 deg/day, so each edge lies within this span.
+    let span = 2.0 * threshold_deg / 10.0 + 4.0 * INVISIBILITY_STEP_DAYS;
+    let start_jd = bracket_and_refine(
+        &edge(-threshold_deg),
+        amavasya_jd,
+        amavasya_jd - span,
+        INVISIBILITY_STEP_DAYS,
+        1e-8,
+    )?;
+    let end_jd = bracket_and_refine(
+        &edge(threshold_deg),
+        amavasya_jd,
+        amavasya_jd + span,
+        INVISIBILITY_STEP_DAYS,
+        1e-8,
+    )?;
+    let (Some(start_jd), Some(end_jd)) = (start_jd, end_jd) else {
+        return Err(SearchError::NoConvergence(
+            "moon invisibility window edge not found",
+        ));
+    };
+
+    let lsk = engine.lsk();
+    Ok(Some(MoonInvisibilityWindow {
+        start: UtcTime::from_jd_tdb(start_jd, lsk),
+        amavasya: UtcTime::from_jd_tdb(amavasya_jd, lsk),
+        end: UtcTime::from_jd_tdb(end_jd, lsk),
+        threshold_deg,
+    }))
+}
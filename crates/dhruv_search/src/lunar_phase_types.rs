@@ -32,3 +32,17 @@ pub struct LunarPhaseEvent {
     /// Tropical ecliptic longitude of the Sun at the event (degrees).
     pub sun_longitude_deg: f64,
 }
+
+/// Span around an Amavasya during which the Moon is within a given arc of
+/// the Sun and therefore treated as combust/invisible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoonInvisibilityWindow {
+    /// UTC time the Moon closes to within the arc (waning side).
+    pub start: UtcTime,
+    /// UTC time of the Amavasya (Sun-Moon conjunction) inside the window.
+    pub amavasya: UtcTime,
+    /// UTC time the Moon moves beyond the arc again (waxing side).
+    pub end: UtcTime,
+    /// Arc from the Sun used for the window (degrees).
+    pub threshold_deg: f64,
+}
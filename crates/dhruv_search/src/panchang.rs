@@ -7,26 +7,30 @@
 //! Clean-room implementation from standard Vedic panchang conventions.
 
 use dhruv_core::{Body, Engine};
-use dhruv_time::{EopKernel, LeapSecondKernel, UtcTime, calendar_to_jd};
+use dhruv_time::{
+    EopKernel, LeapSecondKernel, UtcTime, calendar_to_jd, jd_to_tdb_seconds, tdb_seconds_to_jd,
+};
 use dhruv_vedic_base::{
-    Ayana, GeoLocation, HORA_COUNT, KARANA_SEGMENT_DEG, NAKSHATRA_SPAN_27, Rashi, RiseSetConfig,
-    RiseSetEvent, RiseSetResult, TITHI_SEGMENT_DEG, YOGA_SEGMENT_DEG, approximate_local_noon_jd,
+    ALL_RASHIS, Ayana, DayDefinitionFallback, GeoLocation, HORA_COUNT, KARANA_SEGMENT_DEG, Masa,
+    NAKSHATRA_SPAN_27, Paksha, Rashi, RiseSetConfig, RiseSetEvent, RiseSetResult,
+    TITHI_SEGMENT_DEG, TithiDayKind, YOGA_SEGMENT_DEG, approximate_local_noon_jd,
     ayana_from_sidereal_longitude, compute_rise_set, ghatika_from_elapsed, hora_at,
     jd_tdb_to_centuries, karana_from_elongation, masa_from_rashi_index, nakshatra_from_longitude,
-    rashi_from_longitude, samvatsara_from_year, tithi_from_elongation, utc_day_start_jd,
-    vaar_from_jd, yoga_from_sum,
+    rashi_from_longitude, samvatsara_from_year, tithi_day_kind, tithi_from_elongation,
+    utc_day_start_jd, vaar_from_jd, yoga_from_sum,
 };
 
 use crate::conjunction::{body_ecliptic_lon_lat, body_lon_lat_on_plane};
 use crate::error::SearchError;
-use crate::lunar_phase::{next_amavasya, prev_amavasya};
+use crate::lunar_phase::{next_amavasya, prev_amavasya, search_amavasyas};
 use crate::panchang_types::{
-    AyanaInfo, GhatikaInfo, HoraInfo, KaranaInfo, MasaInfo, PanchangInfo, PanchangNakshatraInfo,
-    TithiInfo, VaarInfo, VarshaInfo, YogaInfo,
+    AyanaInfo, GhatikaInfo, HoraInfo, IngressEvent, KaranaInfo, LimbTarget, LimbTargetEvent,
+    MasaInfo, PANCHANG_DIFF_TOLERANCE_S, PanchangDiff, PanchangInfo, PanchangLimb,
+    PanchangLimbDiff, PanchangNakshatraInfo, TithiInfo, VaarInfo, VarshaInfo, YogaInfo,
 };
 use crate::sankranti::{next_specific_sankranti, prev_specific_sankranti};
 use crate::sankranti_types::SankrantiConfig;
-use crate::search_util::{find_zero_crossing, normalize_to_pm180};
+use crate::search_util::{find_zero_crossing, iter_events, normalize_to_pm180};
 
 fn utc_to_jd_tdb_maybe_eop(engine: &Engine, eop: Option<&EopKernel>, utc: &UtcTime) -> f64 {
     crate::search_util::utc_to_jd_tdb_with_eop(engine, eop, utc)
@@ -69,6 +73,18 @@ fn sun_sidereal_longitude(
     Ok((lon - aya).rem_euclid(360.0))
 }
 
+/// Name an Amanta month from the Sun's sidereal rashi at its bounding new moons.
+fn masa_from_new_moon_rashis(rashi_at_prev: u8, rashi_at_next: u8) -> (Masa, bool) {
+    if rashi_at_prev != rashi_at_next {
+        // Normal month: named after rashi at next new moon
+        (masa_from_rashi_index(rashi_at_next), false)
+    } else {
+        // Adhika month: Sun stayed in the same rashi
+        // Named after the next rashi (unchanged_rashi + 1)
+        (masa_from_rashi_index((rashi_at_prev + 1) % 12), true)
+    }
+}
+
 /// Determine the Masa (lunar month, Amanta system) for a given date.
 ///
 /// Amanta: month runs from new moon to new moon.
@@ -102,14 +118,7 @@ pub(crate) fn masa_for_date_with_eop(
     let rashi_at_prev = sun_sidereal_rashi_index(engine, prev_nm_jd, config)?;
     let rashi_at_next = sun_sidereal_rashi_index(engine, next_nm_jd, config)?;
 
-    let (masa, adhika) = if rashi_at_prev != rashi_at_next {
-        // Normal month: named after rashi at next new moon
-        (masa_from_rashi_index(rashi_at_next), false)
-    } else {
-        // Adhika month: Sun stayed in the same rashi
-        // Named after the next rashi (unchanged_rashi + 1)
-        (masa_from_rashi_index((rashi_at_prev + 1) % 12), true)
-    };
+    let (masa, adhika) = masa_from_new_moon_rashis(rashi_at_prev, rashi_at_next);
 
     Ok(MasaInfo {
         masa,
@@ -167,7 +176,8 @@ pub(crate) fn ayana_for_date_with_eop(
 /// Determine the Varsha (60-year samvatsara cycle position) for a given date.
 ///
 /// The Vedic year starts at Chaitra Pratipada: the first new moon after Mesha Sankranti
-/// (Sun entering sidereal 0 deg / Mesha rashi).
+/// (Sun entering sidereal 0 deg / Mesha rashi). The samvatsara is named per
+/// `config.samvatsara_scheme`.
 pub fn varsha_for_date(
     engine: &Engine,
     utc: &UtcTime,
@@ -211,7 +221,7 @@ pub(crate) fn varsha_for_date_with_eop(
     };
 
     // Use the calendar year of the start to determine the samvatsara
-    let (samvatsara, order) = samvatsara_from_year(actual_start.year);
+    let (samvatsara, order) = samvatsara_from_year(actual_start.year, config.samvatsara_scheme);
 
     Ok(VarshaInfo {
         samvatsara,
@@ -374,6 +384,174 @@ fn find_angle_boundary(
     find_zero_crossing(&wrapped, jd_start, step, max_steps, 50, 1e-8)
 }
 
+/// Find the next instant after `jd_tdb` when a panchang limb equals `target_deg`.
+///
+/// Generalizes the tithi/yoga/nakshatra/sankranti boundary searches to any
+/// angle: e.g. `LimbTarget::Tithi` at 180 deg is Purnima, and
+/// `LimbTarget::Nakshatra` at 40 deg is the Moon at 10 deg Vrishabha.
+/// Sidereal limbs use `aya_config`; the tithi limb ignores it.
+///
+/// Returns `Ok(None)` if the target is not reached within one full cycle of
+/// the limb (~33 days for lunar limbs, ~370 days for the Sun).
+pub fn next_elongation_target(
+    engine: &Engine,
+    which: LimbTarget,
+    target_deg: f64,
+    jd_tdb: f64,
+    aya_config: &SankrantiConfig,
+) -> Result<Option<LimbTargetEvent>, SearchError> {
+    if !target_deg.is_finite() {
+        return Err(SearchError::InvalidConfig("target_deg must be finite"));
+    }
+    let target_deg = target_deg.rem_euclid(360.0);
+    let limb_fn = |t: f64| -> Result<f64, SearchError> {
+        match which {
+            LimbTarget::Tithi => elongation_at(engine, t),
+            LimbTarget::Yoga => sidereal_sum_at(engine, t, aya_config),
+            LimbTarget::Nakshatra => moon_sidereal_longitude_at(engine, t, aya_config),
+            LimbTarget::Sankranti => sun_sidereal_longitude(engine, t, aya_config),
+        }
+    };
+    // Lunar limbs move 10-16 deg/day, the Sun ~1 deg/day; steps stay well
+    // under the 90 deg per step that crossing detection tolerates.
+    let (step, max_steps) = match which {
+        LimbTarget::Sankranti => (1.0, 370),
+        _ => (0.25, 132),
+    };
+    let found = find_angle_boundary(&limb_fn, jd_tdb, target_deg, step, max_steps)?;
+    Ok(found.map(|jd| LimbTargetEvent {
+        limb: which,
+        target_deg,
+        jd_tdb: jd,
+        utc: UtcTime::from_jd_tdb(jd, engine.lsk()),
+    }))
+}
+
+/// Upper bound on the Moon's geocentric speed (deg/day); its true maximum is
+/// about 15.4 deg/day near perigee.
+const MOON_MAX_SPEED_DEG_PER_DAY: f64 = 15.5;
+
+/// Every Moon ingress into a sidereal rashi between `start` and `end`.
+///
+/// The Moon changes sign roughly every 2.25 days. Each search skips ahead by
+/// the remaining distance to the next cusp at the Moon's maximum speed —
+/// never past the crossing — then brackets the cusp on the sidereal Moon
+/// longitude.
+pub fn moon_rashi_ingresses(
+    engine: &Engine,
+    start: &UtcTime,
+    end: &UtcTime,
+    aya_config: &SankrantiConfig,
+) -> Result<Vec<IngressEvent>, SearchError> {
+    aya_config.validate().map_err(SearchError::InvalidConfig)?;
+    let jd_start = crate::search_util::utc_to_jd_tdb(engine, start);
+    let jd_end = crate::search_util::utc_to_jd_tdb(engine, end);
+
+    let moon_lon = |t: f64| moon_sidereal_longitude_at(engine, t, aya_config);
+    let next = |jd: f64| -> Result<Option<IngressEvent>, SearchError> {
+        let lon = moon_lon(jd)?;
+        let rashi_index = ((lon / 30.0).floor() as u8 + 1) % 12;
+        let cusp = rashi_index as f64 * 30.0;
+        let skip_days = (cusp - lon).rem_euclid(360.0) / MOON_MAX_SPEED_DEG_PER_DAY;
+        let found = find_angle_boundary(&moon_lon, jd + skip_days, cusp, 0.25, 132)?;
+        Ok(found.map(|jd_tdb| IngressEvent {
+            rashi: ALL_RASHIS[rashi_index as usize],
+            rashi_index,
+            jd_tdb,
+            utc: UtcTime::from_jd_tdb(jd_tdb, engine.lsk()),
+        }))
+    };
+    iter_events(jd_start, jd_end, next, |e| e.jd_tdb).collect()
+}
+
+/// Resolve the sunrise on which a tithi of a lunar month prevails in `year`.
+///
+/// Months are Amanta and named as in [`masa_for_date`]. When `masa` repeats
+/// as an adhika month, `adhika` selects the intercalary month (`true`) or
+/// the regular nija month (`false`); `adhika = true` in a year without that
+/// adhika month yields `None`. `tithi_in_paksha` is 1-15, so Purnima is
+/// Shukla 15 and Amavasya is Krishna 15.
+///
+/// The festival day is the first sunrise inside the tithi (udaya tithi). A
+/// kshaya tithi, which spans no sunrise, resolves to the sunrise of the
+/// Vedic day on which it begins. Sunrise follows `riseset_config`. Returns
+/// the first such sunrise whose UTC date falls in `year`, or `None` if the
+/// month does not occur that year.
+#[allow(clippy::too_many_arguments)]
+pub fn festival_date(
+    engine: &Engine,
+    eop: &EopKernel,
+    year: i32,
+    masa: Masa,
+    paksha: Paksha,
+    tithi_in_paksha: u8,
+    adhika: bool,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+    aya_config: &SankrantiConfig,
+) -> Result<Option<UtcTime>, SearchError> {
+    if !(1..=15).contains(&tithi_in_paksha) {
+        return Err(SearchError::InvalidConfig("tithi_in_paksha must be 1-15"));
+    }
+    let tithi_index = match paksha {
+        Paksha::Shukla => 0,
+        Paksha::Krishna => 15,
+    } + (tithi_in_paksha - 1) as u32;
+
+    // Months starting up to a lunation before the year can still place the
+    // tithi in January; months starting in December can end in January.
+    let new_moons = search_amavasyas(
+        engine,
+        &UtcTime::new(year - 1, 12, 1, 0, 0, 0.0),
+        &UtcTime::new(year + 1, 1, 31, 0, 0, 0.0),
+    )?;
+    for pair in new_moons.windows(2) {
+        let start_jd = crate::search_util::utc_to_jd_tdb(engine, &pair[0].utc);
+        let end_jd = crate::search_util::utc_to_jd_tdb(engine, &pair[1].utc);
+        let rashi_at_prev = sun_sidereal_rashi_index(engine, start_jd, aya_config)?;
+        let rashi_at_next = sun_sidereal_rashi_index(engine, end_jd, aya_config)?;
+        if masa_from_new_moon_rashis(rashi_at_prev, rashi_at_next) != (masa, adhika) {
+            continue;
+        }
+
+        // Start slightly before the new moon so Shukla Pratipada (target 0)
+        // finds the month's own conjunction.
+        let tithi_start = next_elongation_target(
+            engine,
+            LimbTarget::Tithi,
+            tithi_index as f64 * TITHI_SEGMENT_DEG,
+            start_jd - 0.1,
+            aya_config,
+        )?
+        .ok_or(SearchError::NoConvergence(
+            "could not find festival tithi start",
+        ))?;
+        let tithi_end = next_elongation_target(
+            engine,
+            LimbTarget::Tithi,
+            (tithi_index + 1) as f64 * TITHI_SEGMENT_DEG,
+            tithi_start.jd_tdb,
+            aya_config,
+        )?
+        .ok_or(SearchError::NoConvergence(
+            "could not find festival tithi end",
+        ))?;
+
+        let (sunrise, next_sunrise) =
+            vedic_day_sunrises(engine, eop, &tithi_start.utc, location, riseset_config)?;
+        let day_jd = if next_sunrise < tithi_end.jd_tdb {
+            next_sunrise
+        } else {
+            sunrise
+        };
+        let day = UtcTime::from_jd_tdb(day_jd, engine.lsk());
+        if day.year == year {
+            return Ok(Some(day));
+        }
+    }
+    Ok(None)
+}
+
 /// Convert UtcTime to JD UTC (calendar-only, no LSK).
 fn utc_to_jd_utc(utc: &UtcTime) -> f64 {
     let day_frac = utc.day as f64
@@ -426,6 +604,28 @@ pub fn tithi_at(
     })
 }
 
+/// Classify the Vedic day containing `utc` as a normal, kshaya, or vriddhi
+/// tithi day.
+///
+/// Companion to [`tithi_for_date`]: the day kind needs the sunrise
+/// boundaries, so it takes the location and rise/set config. Compares the
+/// tithi current at the day's sunrise with the one current at the next
+/// sunrise; see [`tithi_day_kind`].
+pub fn tithi_day_kind_for_date(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+) -> Result<TithiDayKind, SearchError> {
+    let (sunrise_jd, next_sunrise_jd) =
+        vedic_day_sunrises(engine, eop, utc, location, riseset_config)?;
+    Ok(tithi_day_kind(
+        elongation_at(engine, sunrise_jd)?,
+        elongation_at(engine, next_sunrise_jd)?,
+    )?)
+}
+
 /// Determine the Karana (half-tithi) for a given date.
 ///
 /// Karana = which of 60 segments of Moon-Sun elongation (6 deg each) the
@@ -514,11 +714,60 @@ pub fn yoga_at(
 // Category B: Vaar, Hora, Ghatika (sunrise-based)
 // ---------------------------------------------------------------------------
 
+/// Vedic day boundary near `jd_noon` (JD UTC of approximate local noon).
+///
+/// Returns the sunrise, or applies `riseset_config.day_fallback` when the Sun
+/// does not rise that day. `missing` is the error reported when no boundary
+/// can be established.
+fn vedic_day_start_jd(
+    engine: &Engine,
+    eop: &EopKernel,
+    location: &GeoLocation,
+    jd_noon: f64,
+    riseset_config: &RiseSetConfig,
+    missing: &'static str,
+) -> Result<f64, SearchError> {
+    let event_jd = |event: RiseSetEvent| -> Result<Option<f64>, SearchError> {
+        let result = compute_rise_set(
+            engine,
+            engine.lsk(),
+            eop,
+            location,
+            event,
+            jd_noon,
+            riseset_config,
+        )
+        .map_err(|_| SearchError::NoConvergence("sunrise computation failed"))?;
+        Ok(match result {
+            RiseSetResult::Event { jd_tdb, .. } => Some(jd_tdb),
+            RiseSetResult::NeverRises | RiseSetResult::NeverSets => None,
+        })
+    };
+
+    if let Some(jd) = event_jd(RiseSetEvent::Sunrise)? {
+        return Ok(jd);
+    }
+    match riseset_config.day_fallback {
+        DayDefinitionFallback::Error => Err(SearchError::NoConvergence(missing)),
+        DayDefinitionFallback::CivilTwilight => {
+            event_jd(RiseSetEvent::CivilDawn)?.ok_or(SearchError::NoConvergence(missing))
+        }
+        DayDefinitionFallback::LocalSixAm => {
+            // Local mean solar 06:00 is six hours before local mean noon.
+            let utc_s = jd_to_tdb_seconds(jd_noon - 0.25);
+            Ok(tdb_seconds_to_jd(engine.lsk().utc_to_tdb(utc_s)))
+        }
+    }
+}
+
 /// Compute the Vedic day sunrise bracket for a given UTC moment.
 ///
 /// Returns (sunrise_jd_tdb, next_sunrise_jd_tdb) defining the Vedic day
 /// that contains the given moment. If the moment is before today's sunrise,
 /// uses yesterday's sunrise as the start.
+///
+/// When the Sun does not rise (polar night or midnight sun), the boundary
+/// follows `riseset_config.day_fallback`; the default is an error.
 pub fn vedic_day_sunrises(
     engine: &Engine,
     eop: &EopKernel,
@@ -533,62 +782,36 @@ pub fn vedic_day_sunrises(
     let jd_midnight = utc_day_start_jd(jd_utc);
     let jd_noon = approximate_local_noon_jd(jd_midnight, location.longitude_deg);
 
-    // Today's sunrise
-    let today_result = compute_rise_set(
+    let today_sunrise_jd = vedic_day_start_jd(
         engine,
-        engine.lsk(),
         eop,
         location,
-        RiseSetEvent::Sunrise,
         jd_noon,
         riseset_config,
-    )
-    .map_err(|_| SearchError::NoConvergence("sunrise computation failed"))?;
-
-    let today_sunrise_jd = match today_result {
-        RiseSetResult::Event { jd_tdb, .. } => jd_tdb,
-        _ => {
-            return Err(SearchError::NoConvergence(
-                "sun never rises at this location",
-            ));
-        }
-    };
+        "sun never rises at this location",
+    )?;
 
     if jd_tdb >= today_sunrise_jd {
         // Moment is after today's sunrise → vedic day = today sunrise to tomorrow sunrise
-        let tomorrow_noon = jd_noon + 1.0;
-        let tomorrow_result = compute_rise_set(
+        let next_sunrise_jd = vedic_day_start_jd(
             engine,
-            engine.lsk(),
             eop,
             location,
-            RiseSetEvent::Sunrise,
-            tomorrow_noon,
+            jd_noon + 1.0,
             riseset_config,
-        )
-        .map_err(|_| SearchError::NoConvergence("next sunrise computation failed"))?;
-        let next_sunrise_jd = match tomorrow_result {
-            RiseSetResult::Event { jd_tdb, .. } => jd_tdb,
-            _ => return Err(SearchError::NoConvergence("sun never rises next day")),
-        };
+            "sun never rises next day",
+        )?;
         Ok((today_sunrise_jd, next_sunrise_jd))
     } else {
         // Moment is before today's sunrise → vedic day = yesterday sunrise to today sunrise
-        let yesterday_noon = jd_noon - 1.0;
-        let yesterday_result = compute_rise_set(
+        let yesterday_sunrise_jd = vedic_day_start_jd(
             engine,
-            engine.lsk(),
             eop,
             location,
-            RiseSetEvent::Sunrise,
-            yesterday_noon,
+            jd_noon - 1.0,
             riseset_config,
-        )
-        .map_err(|_| SearchError::NoConvergence("previous sunrise computation failed"))?;
-        let yesterday_sunrise_jd = match yesterday_result {
-            RiseSetResult::Event { jd_tdb, .. } => jd_tdb,
-            _ => return Err(SearchError::NoConvergence("sun never rose yesterday")),
-        };
+            "sun never rose yesterday",
+        )?;
         Ok((yesterday_sunrise_jd, today_sunrise_jd))
     }
 }
@@ -770,6 +993,10 @@ pub fn panchang_for_date(
     let (sunrise_jd, next_sunrise_jd) =
         vedic_day_sunrises(engine, eop, utc, location, riseset_config)?;
 
+    let tithi_day_kind = tithi_day_kind(
+        elongation_at(engine, sunrise_jd)?,
+        elongation_at(engine, next_sunrise_jd)?,
+    )?;
     let vaar = vaar_from_sunrises(sunrise_jd, next_sunrise_jd, engine.lsk());
     let hora = hora_from_sunrises(jd, sunrise_jd, next_sunrise_jd, engine.lsk());
     let ghatika = ghatika_from_sunrises(jd, sunrise_jd, next_sunrise_jd, engine.lsk());
@@ -786,6 +1013,7 @@ pub fn panchang_for_date(
 
     Ok(PanchangInfo {
         tithi,
+        tithi_day_kind,
         karana,
         yoga,
         vaar,
@@ -797,3 +1025,115 @@ pub fn panchang_for_date(
         varsha,
     })
 }
+
+/// Compare the panchang for one moment under two `SankrantiConfig`s.
+///
+/// Both sides share `riseset_config` and include calendar elements.
+/// Each limb is reported when its value differs or either boundary shifts by
+/// more than `PANCHANG_DIFF_TOLERANCE_S`; shifts are B minus A in seconds.
+/// Tithi, karana, and the sunrise-based limbs do not depend on the ayanamsha,
+/// so ayanamsha differences normally surface in yoga, nakshatra, and the
+/// calendar elements.
+pub fn panchang_diff(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+    config_a: &SankrantiConfig,
+    config_b: &SankrantiConfig,
+) -> Result<PanchangDiff, SearchError> {
+    let a = panchang_for_date(engine, eop, utc, location, riseset_config, config_a, true)?;
+    let b = panchang_for_date(engine, eop, utc, location, riseset_config, config_b, true)?;
+
+    let lsk = engine.lsk();
+    let mut limbs = Vec::new();
+    let mut compare = |limb: PanchangLimb,
+                       value_differs: bool,
+                       (start_a, end_a): (&UtcTime, &UtcTime),
+                       (start_b, end_b): (&UtcTime, &UtcTime)| {
+        let start_shift_s = (start_b.to_jd_tdb(lsk) - start_a.to_jd_tdb(lsk)) * 86_400.0;
+        let end_shift_s = (end_b.to_jd_tdb(lsk) - end_a.to_jd_tdb(lsk)) * 86_400.0;
+        if value_differs
+            || start_shift_s.abs() > PANCHANG_DIFF_TOLERANCE_S
+            || end_shift_s.abs() > PANCHANG_DIFF_TOLERANCE_S
+        {
+            limbs.push(PanchangLimbDiff {
+                limb,
+                value_differs,
+                start_shift_s,
+                end_shift_s,
+            });
+        }
+    };
+
+    compare(
+        PanchangLimb::Tithi,
+        a.tithi.tithi_index != b.tithi.tithi_index,
+        (&a.tithi.start, &a.tithi.end),
+        (&b.tithi.start, &b.tithi.end),
+    );
+    compare(
+        PanchangLimb::Karana,
+        a.karana.karana_index != b.karana.karana_index,
+        (&a.karana.start, &a.karana.end),
+        (&b.karana.start, &b.karana.end),
+    );
+    compare(
+        PanchangLimb::Yoga,
+        a.yoga.yoga_index != b.yoga.yoga_index,
+        (&a.yoga.start, &a.yoga.end),
+        (&b.yoga.start, &b.yoga.end),
+    );
+    compare(
+        PanchangLimb::Vaar,
+        a.vaar.vaar != b.vaar.vaar,
+        (&a.vaar.start, &a.vaar.end),
+        (&b.vaar.start, &b.vaar.end),
+    );
+    compare(
+        PanchangLimb::Hora,
+        a.hora.hora_index != b.hora.hora_index,
+        (&a.hora.start, &a.hora.end),
+        (&b.hora.start, &b.hora.end),
+    );
+    compare(
+        PanchangLimb::Ghatika,
+        a.ghatika.value != b.ghatika.value,
+        (&a.ghatika.start, &a.ghatika.end),
+        (&b.ghatika.start, &b.ghatika.end),
+    );
+    compare(
+        PanchangLimb::Nakshatra,
+        a.nakshatra.nakshatra_index != b.nakshatra.nakshatra_index
+            || a.nakshatra.pada != b.nakshatra.pada,
+        (&a.nakshatra.start, &a.nakshatra.end),
+        (&b.nakshatra.start, &b.nakshatra.end),
+    );
+    if let (Some(ma), Some(mb)) = (&a.masa, &b.masa) {
+        compare(
+            PanchangLimb::Masa,
+            ma.masa != mb.masa || ma.adhika != mb.adhika,
+            (&ma.start, &ma.end),
+            (&mb.start, &mb.end),
+        );
+    }
+    if let (Some(aa), Some(ab)) = (&a.ayana, &b.ayana) {
+        compare(
+            PanchangLimb::Ayana,
+            aa.ayana != ab.ayana,
+            (&aa.start, &aa.end),
+            (&ab.start, &ab.end),
+        );
+    }
+    if let (Some(va), Some(vb)) = (&a.varsha, &b.varsha) {
+        compare(
+            PanchangLimb::Varsha,
+            va.order != vb.order,
+            (&va.start, &va.end),
+            (&vb.start, &vb.end),
+        );
+    }
+
+    Ok(PanchangDiff { a, b, limbs })
+}
@@ -2,7 +2,8 @@
 
 use dhruv_time::UtcTime;
 use dhruv_vedic_base::{
-    Ayana, Hora, Karana, Masa, Nakshatra, Paksha, Samvatsara, Tithi, Vaar, Yoga,
+    Ayana, Hora, Karana, Masa, Nakshatra, Paksha, Rashi, Samvatsara, Tithi, TithiDayKind, Vaar,
+    Yoga,
 };
 
 /// Masa (lunar month) classification result.
@@ -65,6 +66,8 @@ pub struct KaranaInfo {
     /// The karana name.
     pub karana: Karana,
     /// 0-based karana sequence index within the synodic month (0..59).
+    /// The 1-based half-tithi slot is `karana_index + 1`; see
+    /// `karana_from_half_tithi`.
     pub karana_index: u8,
     /// Start of this karana (UTC).
     pub start: UtcTime,
@@ -141,6 +144,9 @@ pub struct PanchangNakshatraInfo {
 pub struct PanchangInfo {
     /// Tithi (lunar day).
     pub tithi: TithiInfo,
+    /// Whether the Vedic day skips (kshaya) or repeats (vriddhi) a tithi
+    /// between its sunrise and the next.
+    pub tithi_day_kind: TithiDayKind,
     /// Karana (half-tithi).
     pub karana: KaranaInfo,
     /// Yoga (luni-solar yoga).
@@ -160,3 +166,102 @@ pub struct PanchangInfo {
     /// Varsha (60-year samvatsara). Present when `include_calendar` is true.
     pub varsha: Option<VarshaInfo>,
 }
+
+/// Panchang limb whose angle is targeted by `next_elongation_target`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimbTarget {
+    /// Moon minus Sun elongation (tithi limb). Ayanamsha cancels.
+    Tithi,
+    /// Sidereal Moon plus Sun (yoga limb).
+    Yoga,
+    /// Sidereal Moon longitude (nakshatra limb).
+    Nakshatra,
+    /// Sidereal Sun longitude (sankranti limb).
+    Sankranti,
+}
+
+/// Instant at which a panchang limb reaches a target angle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LimbTargetEvent {
+    /// Which limb was searched.
+    pub limb: LimbTarget,
+    /// Target angle in degrees [0, 360).
+    pub target_deg: f64,
+    /// Event time as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// Event time as structured Gregorian UTC.
+    pub utc: UtcTime,
+}
+
+/// A body crossing into a new sidereal rashi.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IngressEvent {
+    /// The rashi being entered.
+    pub rashi: Rashi,
+    /// 0-based rashi index (0=Mesha .. 11=Meena).
+    pub rashi_index: u8,
+    /// Event time as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// Event time as structured Gregorian UTC.
+    pub utc: UtcTime,
+}
+
+/// Panchang element compared by `panchang_diff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PanchangLimb {
+    /// Lunar day.
+    Tithi,
+    /// Half-tithi.
+    Karana,
+    /// Luni-solar yoga.
+    Yoga,
+    /// Vedic weekday (sunrise to sunrise).
+    Vaar,
+    /// Planetary hour.
+    Hora,
+    /// 1-60 division of the Vedic day.
+    Ghatika,
+    /// Moon's nakshatra.
+    Nakshatra,
+    /// Lunar month (calendar element).
+    Masa,
+    /// Solstice period (calendar element).
+    Ayana,
+    /// 60-year samvatsara (calendar element).
+    Varsha,
+}
+
+/// One panchang element that differs between two configurations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PanchangLimbDiff {
+    /// Which element differs.
+    pub limb: PanchangLimb,
+    /// Whether the element value (name/index) differs, not just its boundaries.
+    pub value_differs: bool,
+    /// Start-boundary shift in seconds (config B minus config A).
+    pub start_shift_s: f64,
+    /// End-boundary shift in seconds (config B minus config A).
+    pub end_shift_s: f64,
+}
+
+/// Side-by-side panchang under two configurations, with the differing limbs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanchangDiff {
+    /// Panchang under config A.
+    pub a: PanchangInfo,
+    /// Panchang under config B.
+    pub b: PanchangInfo,
+    /// Elements whose value differs or whose boundaries shift by more than
+    /// `PANCHANG_DIFF_TOLERANCE_S`. Empty when the configs agree.
+    pub limbs: Vec<PanchangLimbDiff>,
+}
+
+impl PanchangDiff {
+    /// True when no element differs between the two configurations.
+    pub fn is_empty(&self) -> bool {
+        self.limbs.is_empty()
+    }
+}
+
+/// Boundary shifts at or below this many seconds are not reported.
+pub const PANCHANG_DIFF_TOLERANCE_S: f64 = 1e-3;
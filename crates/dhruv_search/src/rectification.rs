@@ -0,0 +1,134 @@
+//! Birth-time rectification scan.
+//!
+//! Samples candidate birth times around a nominal time and reports the
+//! chart factors astrologers compare while rectifying: lagna sign, navamsa
+//! lagna sign, and the janma dasha chain down to pratyantardasha.
+
+use dhruv_core::Engine;
+use dhruv_time::{EopKernel, UtcTime};
+use dhruv_vedic_base::dasha::{DashaEntity, DashaSnapshot, DashaSystem, DashaVariationConfig};
+use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
+use dhruv_vedic_base::{Amsha, BhavaConfig, amsha_longitude, rashi_from_longitude};
+
+use crate::dasha::dasha_snapshot_at;
+use crate::error::SearchError;
+use crate::jyotish::sidereal_lagna_for_date;
+use crate::rectification_types::{MAX_RECTIFICATION_SAMPLES, RectificationSample};
+use crate::sankranti_types::SankrantiConfig;
+
+/// Entity ruling the active period at `level` of a snapshot.
+fn period_entity(snapshot: &DashaSnapshot, level: usize) -> Result<DashaEntity, SearchError> {
+    snapshot
+        .periods
+        .get(level)
+        .map(|p| p.entity)
+        .ok_or(SearchError::InvalidConfig(
+            "dasha system has fewer than three levels at this time",
+        ))
+}
+
+/// Scan candidate birth times centred on `utc`.
+///
+/// Samples run from `utc - time_window_minutes / 2` to
+/// `utc + time_window_minutes / 2` inclusive, every `step_minutes`. The
+/// ayanamsha system and reference plane come from `aya_config`. Dasha
+/// periods of `system` are those running at each candidate's own birth
+/// instant, computed with the given rise/set, bhava and variation configs.
+#[allow(clippy::too_many_arguments)]
+pub fn rectification_scan(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    time_window_minutes: f64,
+    step_minutes: f64,
+    location: &GeoLocation,
+    system: DashaSystem,
+    bhava_config: &BhavaConfig,
+    riseset_config: &RiseSetConfig,
+    aya_config: &SankrantiConfig,
+    variation: &DashaVariationConfig,
+) -> Result<Vec<RectificationSample>, SearchError> {
+    if !time_window_minutes.is_finite() || time_window_minutes < 0.0 {
+        return Err(SearchError::InvalidConfig(
+            "time_window_minutes must be non-negative",
+        ));
+    }
+    if !step_minutes.is_finite() || step_minutes <= 0.0 {
+        return Err(SearchError::InvalidConfig("step_minutes must be positive"));
+    }
+    let steps = (time_window_minutes / step_minutes + 1e-9).floor();
+    if steps >= MAX_RECTIFICATION_SAMPLES as f64 {
+        return Err(SearchError::InvalidConfig(
+            "too many rectification samples for window and step",
+        ));
+    }
+
+    let nominal_jd = utc.to_jd_tdb(engine.lsk());
+
+    let mut samples = Vec::with_capacity(steps as usize + 1);
+    for i in 0..=steps as usize {
+        let offset_minutes = -time_window_minutes / 2.0 + i as f64 * step_minutes;
+        let jd_tdb = nominal_jd + offset_minutes / 1440.0;
+        let candidate = UtcTime::from_jd_tdb(jd_tdb, engine.lsk());
+
+        let lagna_deg = sidereal_lagna_for_date(engine, eop, &candidate, location, aya_config)?;
+        let navamsa_lagna = amsha_longitude(lagna_deg, Amsha::D9, None);
+
+        let snapshot = dasha_snapshot_at(
+            engine,
+            eop,
+            &candidate,
+            &candidate,
+            location,
+            system,
+            2,
+            bhava_config,
+            riseset_config,
+            aya_config,
+            variation,
+        )?;
+        samples.push(RectificationSample {
+            offset_minutes,
+            jd_tdb,
+            utc: candidate,
+            lagna_deg,
+            lagna_rashi: rashi_from_longitude(lagna_deg).rashi,
+            navamsa_lagna_rashi: rashi_from_longitude(navamsa_lagna).rashi,
+            mahadasha: period_entity(&snapshot, 0)?,
+            antardasha: period_entity(&snapshot, 1)?,
+            pratyantardasha: period_entity(&snapshot, 2)?,
+        });
+    }
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dhruv_vedic_base::Graha;
+    use dhruv_vedic_base::dasha::{DashaLevel, DashaPeriod};
+
+    #[test]
+    fn short_snapshot_is_an_error_not_a_panic() {
+        let snapshot = DashaSnapshot {
+            system: DashaSystem::Vimshottari,
+            query_jd: 2_451_545.0,
+            periods: vec![DashaPeriod {
+                entity: DashaEntity::Graha(Graha::Shukra),
+                start_jd: 2_451_000.0,
+                end_jd: 2_452_000.0,
+                level: DashaLevel::Mahadasha,
+                order: 1,
+                parent_idx: 0,
+            }],
+        };
+        assert_eq!(
+            period_entity(&snapshot, 0),
+            Ok(DashaEntity::Graha(Graha::Shukra))
+        );
+        assert!(matches!(
+            period_entity(&snapshot, 2),
+            Err(SearchError::InvalidConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,31 @@
+//! Types for birth-time rectification scans.
+
+use dhruv_time::UtcTime;
+use dhruv_vedic_base::Rashi;
+use dhruv_vedic_base::dasha::DashaEntity;
+
+/// Upper bound on samples per scan, to keep accidental tiny steps bounded.
+pub const MAX_RECTIFICATION_SAMPLES: usize = 10_000;
+
+/// Chart factors at one candidate birth time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RectificationSample {
+    /// Candidate time offset from the nominal birth time, in minutes.
+    pub offset_minutes: f64,
+    /// Candidate time as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// Candidate time as structured Gregorian UTC.
+    pub utc: UtcTime,
+    /// Sidereal lagna longitude in degrees [0, 360).
+    pub lagna_deg: f64,
+    /// Rashi of the lagna (D1).
+    pub lagna_rashi: Rashi,
+    /// Rashi of the navamsa (D9) lagna.
+    pub navamsa_lagna_rashi: Rashi,
+    /// Mahadasha running at the candidate birth time.
+    pub mahadasha: DashaEntity,
+    /// Antardasha running at the candidate birth time.
+    pub antardasha: DashaEntity,
+    /// Pratyantardasha running at the candidate birth time.
+    pub pratyantardasha: DashaEntity,
+}
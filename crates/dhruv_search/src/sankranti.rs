@@ -4,16 +4,19 @@
 //! Uses coarse scan + bisection on the sidereal longitude difference function.
 //!
 //! Algorithm: numerical root-finding on f(t) = normalize(sun_sid(t) - boundary).
+//! The same scan + bisection finds nakshatra ingresses of any body.
 //! Clean-room implementation from standard astronomical conventions.
 
 use dhruv_core::{Body, Engine};
 use dhruv_time::UtcTime;
-use dhruv_vedic_base::{ALL_RASHIS, Rashi, jd_tdb_to_centuries};
+use dhruv_vedic_base::{
+    ALL_NAKSHATRAS_27, ALL_RASHIS, NAKSHATRA_SPAN_27, Rashi, jd_tdb_to_centuries,
+};
 
 use crate::conjunction::{body_ecliptic_lon_lat, body_lon_lat_on_plane};
 use crate::error::SearchError;
-use crate::sankranti_types::{SankrantiConfig, SankrantiEvent};
-use crate::search_util::{find_zero_crossing, normalize_to_pm180};
+use crate::sankranti_types::{NakshatraIngressEvent, SankrantiConfig, SankrantiEvent};
+use crate::search_util::{find_zero_crossing, normalize_to_pm180, refine_bracket};
 
 /// Maximum scan range in days (~400 days covers more than a full year).
 const MAX_SCAN_DAYS: f64 = 400.0;
@@ -26,10 +29,20 @@ fn sun_sidereal_longitude(
     engine: &Engine,
     jd_tdb: f64,
     config: &SankrantiConfig,
+) -> Result<f64, SearchError> {
+    body_sidereal_longitude(engine, Body::Sun, jd_tdb, config)
+}
+
+/// Get a body's sidereal longitude at a given JD TDB on the configured plane.
+fn body_sidereal_longitude(
+    engine: &Engine,
+    body: Body,
+    jd_tdb: f64,
+    config: &SankrantiConfig,
 ) -> Result<f64, SearchError> {
     let (lon, _lat) = body_lon_lat_on_plane(
         engine,
-        Body::Sun,
+        body,
         jd_tdb,
         config.precession_model,
         config.reference_plane,
@@ -230,6 +243,33 @@ pub fn search_sankrantis(
     Ok(events)
 }
 
+/// Compute the twelve solar sidereal ingresses (monthly sankranti calendar)
+/// of a calendar year.
+///
+/// Starts from the first Sankranti on or after 00:00 UTC on 1 January of
+/// `year` and returns twelve consecutive events in chronological order.
+pub fn sun_sign_ingresses_for_year(
+    engine: &Engine,
+    year: i32,
+    config: &SankrantiConfig,
+) -> Result<[SankrantiEvent; 12], SearchError> {
+    let mut cursor = UtcTime::new(year, 1, 1, 0, 0, 0.0);
+    let next = |cursor: &UtcTime| {
+        next_sankranti(engine, cursor, config)?.ok_or(SearchError::NoConvergence(
+            "sankranti not found within scan range",
+        ))
+    };
+
+    let first = next(&cursor)?;
+    let mut events = [first; 12];
+    for i in 1..12 {
+        let prev_jd = crate::search_util::utc_to_jd_tdb(engine, &events[i - 1].utc);
+        cursor = UtcTime::from_jd_tdb(prev_jd + 0.01, engine.lsk());
+        events[i] = next(&cursor)?;
+    }
+    Ok(events)
+}
+
 /// Find the next time the Sun enters a specific rashi.
 pub fn next_specific_sankranti(
     engine: &Engine,
@@ -298,6 +338,102 @@ pub fn prev_specific_sankranti(
     }
 }
 
+/// Coarse scan step and maximum scan span (days) for nakshatra ingresses.
+///
+/// The Moon crosses a 13°20' nakshatra in about a day, so it is sampled
+/// every 6 hours over a two-day window. Other bodies use the configured
+/// step; the window covers the longest stay, retrograde loops included.
+fn nakshatra_scan_window(body: Body, config: &SankrantiConfig) -> (f64, f64) {
+    match body {
+        Body::Moon => (config.step_size_days.min(0.25), 2.0),
+        Body::Sun => (config.step_size_days, 16.0),
+        Body::Mercury | Body::Venus | Body::Mars => (config.step_size_days, MAX_SCAN_DAYS),
+        Body::Jupiter => (config.step_size_days, 2.0 * MAX_SCAN_DAYS),
+        _ => (config.step_size_days.max(5.0), 20.0 * 365.25),
+    }
+}
+
+/// 0-based nakshatra index (0 = Ashwini .. 26 = Revati) of a sidereal longitude.
+fn nakshatra_index_of(sidereal_lon: f64) -> u8 {
+    ((sidereal_lon.rem_euclid(360.0) / NAKSHATRA_SPAN_27).floor() as u8).min(26)
+}
+
+/// Nakshatra entered and boundary crossed when leaving `from_index`.
+///
+/// Direct motion enters the next nakshatra at its starting edge; retrograde
+/// motion re-enters the previous one across the start of `from_index`.
+fn nakshatra_crossing(from_index: u8, direct: bool) -> (u8, f64) {
+    if direct {
+        let entered = (from_index + 1) % 27;
+        (entered, entered as f64 * NAKSHATRA_SPAN_27)
+    } else {
+        (
+            (from_index + 26) % 27,
+            from_index as f64 * NAKSHATRA_SPAN_27,
+        )
+    }
+}
+
+/// Find the next time `body` enters a new sidereal nakshatra after `jd_tdb`.
+///
+/// Mirrors the Sankranti search with 27 boundaries of 13°20' instead of 12
+/// of 30°. A retrograde body re-entering the previous nakshatra also counts
+/// and is flagged in the event. The Moon changes nakshatra about once a day,
+/// so its search window is only two days; slower bodies scan longer.
+/// Returns `Ok(None)` if no boundary is crossed within the window.
+pub fn next_nakshatra_ingress(
+    engine: &Engine,
+    body: Body,
+    jd_tdb: f64,
+    aya_config: &SankrantiConfig,
+) -> Result<Option<NakshatraIngressEvent>, SearchError> {
+    aya_config.validate().map_err(SearchError::InvalidConfig)?;
+    if body == Body::Earth {
+        return Err(SearchError::InvalidConfig(
+            "nakshatra ingress is undefined for the Earth",
+        ));
+    }
+
+    let sid = |t: f64| body_sidereal_longitude(engine, body, t, aya_config);
+    let (step, max_days) = nakshatra_scan_window(body, aya_config);
+    let max_steps = (max_days / step).ceil() as usize;
+
+    let mut t_prev = jd_tdb;
+    let mut lon_prev = sid(t_prev)?;
+    for _ in 0..max_steps {
+        let t_curr = t_prev + step;
+        let lon_curr = sid(t_curr)?;
+        let from_index = nakshatra_index_of(lon_prev);
+        if nakshatra_index_of(lon_curr) != from_index {
+            let retrograde = normalize_to_pm180(lon_curr - lon_prev) < 0.0;
+            let (entered, boundary_deg) = nakshatra_crossing(from_index, !retrograde);
+            let f = |t: f64| -> Result<f64, SearchError> {
+                Ok(normalize_to_pm180(sid(t)? - boundary_deg))
+            };
+            let jd = refine_bracket(
+                &f,
+                t_prev,
+                f(t_prev)?,
+                t_curr,
+                aya_config.max_iterations,
+                aya_config.convergence_days,
+            )?;
+            return Ok(Some(NakshatraIngressEvent {
+                jd_tdb: jd,
+                utc: UtcTime::from_jd_tdb(jd, engine.lsk()),
+                body,
+                nakshatra: ALL_NAKSHATRAS_27[entered as usize],
+                nakshatra_index: entered,
+                boundary_deg,
+                retrograde,
+            }));
+        }
+        t_prev = t_curr;
+        lon_prev = lon_curr;
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,6 +447,35 @@ mod tests {
         assert!((next_boundary(350.0) - 0.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn nakshatra_index_wraps_at_revati() {
+        assert_eq!(nakshatra_index_of(0.0), 0);
+        assert_eq!(nakshatra_index_of(NAKSHATRA_SPAN_27 + 0.1), 1);
+        assert_eq!(nakshatra_index_of(359.99), 26);
+        assert_eq!(nakshatra_index_of(-0.01), 26);
+    }
+
+    #[test]
+    fn nakshatra_crossing_direct_and_retrograde() {
+        let (entered, boundary) = nakshatra_crossing(0, true);
+        assert_eq!(entered, 1);
+        assert!((boundary - NAKSHATRA_SPAN_27).abs() < 1e-10);
+
+        // Revati -> Ashwini crosses 0 deg.
+        let (entered, boundary) = nakshatra_crossing(26, true);
+        assert_eq!(entered, 0);
+        assert!(boundary.abs() < 1e-10);
+
+        // Retrograde out of Ashwini re-enters Revati across 0 deg.
+        let (entered, boundary) = nakshatra_crossing(0, false);
+        assert_eq!(entered, 26);
+        assert!(boundary.abs() < 1e-10);
+
+        let (entered, boundary) = nakshatra_crossing(5, false);
+        assert_eq!(entered, 4);
+        assert!((boundary - 5.0 * NAKSHATRA_SPAN_27).abs() < 1e-10);
+    }
+
     #[test]
     fn prev_boundary_basic() {
         assert!((prev_boundary(10.0) - 0.0).abs() < 1e-10);
@@ -338,6 +503,15 @@ mod tests {
         assert!(c.validate().is_err());
     }
 
+    #[test]
+    fn config_rejects_custom_anchor_on_invariable_plane() {
+        let mut c = SankrantiConfig::default_lahiri()
+            .with_custom_ayanamsha(dhruv_vedic_base::CustomAyanamsha::new(23.85, 2_451_545.0));
+        assert!(c.validate().is_ok());
+        c.reference_plane = dhruv_frames::ReferencePlane::Invariable;
+        assert!(c.validate().is_err());
+    }
+
     #[test]
     fn default_lahiri_config() {
         let c = SankrantiConfig::default_lahiri();
@@ -1,14 +1,20 @@
 //! Types for Sankranti search results.
 
+use dhruv_core::Body;
 use dhruv_frames::{DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane};
 use dhruv_time::UtcTime;
-use dhruv_vedic_base::{AyanamshaSystem, Rashi, ayanamsha_deg_on_plane, ayanamsha_deg_with_model};
+use dhruv_vedic_base::{
+    AyanamshaSystem, CustomAyanamsha, Nakshatra, Rashi, SamvatsaraScheme, ayanamsha_deg_on_plane,
+    ayanamsha_deg_with_model,
+};
 
 /// Configuration for Sankranti search.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SankrantiConfig {
     /// Ayanamsha system for sidereal longitude.
     pub ayanamsha_system: AyanamshaSystem,
+    /// User-defined anchor that overrides `ayanamsha_system` when set.
+    pub custom_ayanamsha: Option<CustomAyanamsha>,
     /// Whether to apply nutation correction to the ayanamsha.
     pub use_nutation: bool,
     /// Precession model used by ayanamsha propagation.
@@ -24,6 +30,9 @@ pub struct SankrantiConfig {
     pub max_iterations: u32,
     /// Convergence threshold in days (default: 1e-8).
     pub convergence_days: f64,
+    /// Regional naming of the 60-year cycle used for varsha/samvatsara
+    /// (default: South Indian).
+    pub samvatsara_scheme: SamvatsaraScheme,
 }
 
 impl SankrantiConfig {
@@ -40,12 +49,14 @@ impl SankrantiConfig {
     ) -> Self {
         Self {
             ayanamsha_system,
+            custom_ayanamsha: None,
             use_nutation,
             precession_model,
             reference_plane: ayanamsha_system.default_reference_plane(),
             step_size_days: 1.0,
             max_iterations: 50,
             convergence_days: 1e-8,
+            samvatsara_scheme: SamvatsaraScheme::SouthIndian,
         }
     }
 
@@ -53,21 +64,37 @@ impl SankrantiConfig {
     pub fn default_lahiri() -> Self {
         Self {
             ayanamsha_system: AyanamshaSystem::Lahiri,
+            custom_ayanamsha: None,
             use_nutation: false,
             precession_model: DEFAULT_PRECESSION_MODEL,
             reference_plane: ReferencePlane::Ecliptic,
             step_size_days: 1.0,
             max_iterations: 50,
             convergence_days: 1e-8,
+            samvatsara_scheme: SamvatsaraScheme::SouthIndian,
         }
     }
 
+    /// Use a user-defined ayanamsha anchor instead of a named system.
+    ///
+    /// Custom anchors are defined on the ecliptic, so the reference plane is
+    /// reset to `Ecliptic`.
+    pub fn with_custom_ayanamsha(mut self, custom: CustomAyanamsha) -> Self {
+        self.custom_ayanamsha = Some(custom);
+        self.reference_plane = ReferencePlane::Ecliptic;
+        self
+    }
+
     /// Ayanamsha at `t_centuries`, using this configuration's model and plane settings.
     ///
     /// For `Ecliptic` plane, this returns the standard ecliptic ayanamsha.
     /// For `Invariable` plane, returns the ayanamsha computed on the invariable plane
     /// (nutation is not applied — it's an ecliptic concept).
+    /// A custom anchor, when set, is always evaluated on the ecliptic.
     pub fn ayanamsha_deg_at_centuries(&self, t_centuries: f64) -> f64 {
+        if let Some(custom) = self.custom_ayanamsha {
+            return custom.deg_with_model(t_centuries, self.use_nutation, self.precession_model);
+        }
         ayanamsha_deg_on_plane(
             self.ayanamsha_system,
             t_centuries,
@@ -81,6 +108,9 @@ impl SankrantiConfig {
     ///
     /// Used by code paths that always need ecliptic ayanamsha (e.g. tithi, elongation).
     pub fn ayanamsha_deg_ecliptic(&self, t_centuries: f64) -> f64 {
+        if let Some(custom) = self.custom_ayanamsha {
+            return custom.deg_with_model(t_centuries, self.use_nutation, self.precession_model);
+        }
         ayanamsha_deg_with_model(
             self.ayanamsha_system,
             t_centuries,
@@ -99,6 +129,9 @@ impl SankrantiConfig {
         if self.convergence_days <= 0.0 {
             return Err("convergence_days must be positive");
         }
+        if self.custom_ayanamsha.is_some() && self.reference_plane == ReferencePlane::Invariable {
+            return Err("custom_ayanamsha is defined on the ecliptic, not the invariable plane");
+        }
         Ok(())
     }
 }
@@ -117,3 +150,22 @@ pub struct SankrantiEvent {
     /// Sun's tropical longitude at the event (degrees).
     pub sun_tropical_longitude_deg: f64,
 }
+
+/// A body entering a new sidereal nakshatra.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NakshatraIngressEvent {
+    /// Event time as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// UTC time of the event.
+    pub utc: UtcTime,
+    /// Body that changed nakshatra.
+    pub body: Body,
+    /// The nakshatra being entered.
+    pub nakshatra: Nakshatra,
+    /// 0-based nakshatra index (0=Ashwini .. 26=Revati).
+    pub nakshatra_index: u8,
+    /// Sidereal longitude of the boundary crossed (degrees, N*13.333).
+    pub boundary_deg: f64,
+    /// True when the body crossed backwards into the previous nakshatra.
+    pub retrograde: bool,
+}
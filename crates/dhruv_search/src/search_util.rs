@@ -114,6 +114,101 @@ pub(crate) fn is_genuine_crossing(f_a: f64, f_b: f64) -> bool {
     f_a * f_b < 0.0 && (f_a - f_b).abs() < 270.0
 }
 
+/// Bisection iteration cap for [`bracket_and_refine`].
+///
+/// Each iteration halves the bracket, so 100 iterations reach any practical
+/// tolerance; the loop normally stops earlier on `tol`.
+const BRACKET_MAX_ITERATIONS: u32 = 100;
+
+/// Refine a sign change of `f` between `t_a` and `t_b` by bisection.
+///
+/// `f_a` is `f(t_a)`; the caller guarantees `f(t_b)` has the opposite sign.
+/// The bracket may be given in either order. Stops after `max_iterations`
+/// or once the bracket is narrower than `convergence_days`, and returns its
+/// midpoint. This is the single bisection loop shared by all event searches.
+pub(crate) fn refine_bracket(
+    f: &dyn Fn(f64) -> Result<f64, SearchError>,
+    mut t_a: f64,
+    mut f_a: f64,
+    mut t_b: f64,
+    max_iterations: u32,
+    convergence_days: f64,
+) -> Result<f64, SearchError> {
+    for _ in 0..max_iterations {
+        let t_mid = 0.5 * (t_a + t_b);
+        let f_mid = f(t_mid)?;
+
+        if f_a * f_mid <= 0.0 {
+            t_b = t_mid;
+        } else {
+            t_a = t_mid;
+            f_a = f_mid;
+        }
+
+        if (t_b - t_a).abs() < convergence_days {
+            break;
+        }
+    }
+
+    Ok(0.5 * (t_a + t_b))
+}
+
+/// Coarse scan from `start` toward `end`, refining the first genuine crossing.
+///
+/// The last step is clamped to `end`; `step` must point from `start` to `end`.
+fn scan_and_refine(
+    f: &dyn Fn(f64) -> Result<f64, SearchError>,
+    start: f64,
+    end: f64,
+    step: f64,
+    max_iterations: u32,
+    convergence_days: f64,
+) -> Result<Option<f64>, SearchError> {
+    let forward = step > 0.0;
+    let mut f_prev = f(start)?;
+    let mut t_prev = start;
+
+    while (forward && t_prev < end) || (!forward && t_prev > end) {
+        let t_curr = if forward {
+            (t_prev + step).min(end)
+        } else {
+            (t_prev + step).max(end)
+        };
+        let f_curr = f(t_curr)?;
+
+        if is_genuine_crossing(f_prev, f_curr) {
+            let t = refine_bracket(f, t_prev, f_prev, t_curr, max_iterations, convergence_days)?;
+            return Ok(Some(t));
+        }
+
+        t_prev = t_curr;
+        f_prev = f_curr;
+    }
+
+    Ok(None)
+}
+
+/// Find the first root of `f` between `start` and `end` (either order).
+///
+/// Scans in steps of `coarse_step` (magnitude only; direction follows
+/// `end - start`), skips wrap-around jumps via [`is_genuine_crossing`], and
+/// bisects the first bracket down to `tol` days. Angular targets should be
+/// wrapped with [`normalize_to_pm180`] so their 360-degree jump is rejected.
+/// Returns `None` if no crossing is found.
+pub(crate) fn bracket_and_refine(
+    f: &dyn Fn(f64) -> Result<f64, SearchError>,
+    start: f64,
+    end: f64,
+    coarse_step: f64,
+    tol: f64,
+) -> Result<Option<f64>, SearchError> {
+    let step = coarse_step.abs().copysign(end - start);
+    if step == 0.0 || !step.is_finite() {
+        return Ok(None);
+    }
+    scan_and_refine(f, start, end, step, BRACKET_MAX_ITERATIONS, tol)
+}
+
 /// Generic zero-crossing finder using coarse scan + bisection.
 ///
 /// Scans from `jd_start` with the given `step` (positive = forward, negative = backward),
@@ -129,45 +224,45 @@ pub(crate) fn find_zero_crossing(
     max_iterations: u32,
     convergence_days: f64,
 ) -> Result<Option<f64>, SearchError> {
-    let mut f_prev = f(jd_start)?;
-    let mut t_prev = jd_start;
+    let jd_end = jd_start + step * max_steps as f64;
+    scan_and_refine(f, jd_start, jd_end, step, max_iterations, convergence_days)
+}
 
-    for _ in 0..max_steps {
-        let t_curr = t_prev + step;
-        let f_curr = f(t_curr)?;
+/// Offset (days) past each event before the next `next_*` call in
+/// [`iter_events`], so the same crossing is not found twice.
+const EVENT_ITER_RESTART_DAYS: f64 = 1e-4;
 
-        if is_genuine_crossing(f_prev, f_curr) {
-            // Ensure t_a < t_b for bisection
-            let (mut t_a, mut f_a, mut t_b, _f_b) = if t_prev < t_curr {
-                (t_prev, f_prev, t_curr, f_curr)
-            } else {
-                (t_curr, f_curr, t_prev, f_prev)
-            };
-
-            for _ in 0..max_iterations {
-                let t_mid = 0.5 * (t_a + t_b);
-                let f_mid = f(t_mid)?;
-
-                if f_a * f_mid <= 0.0 {
-                    t_b = t_mid;
-                } else {
-                    t_a = t_mid;
-                    f_a = f_mid;
-                }
-
-                if (t_b - t_a).abs() < convergence_days {
-                    break;
-                }
+/// Lazy event stream over `[jd_start, jd_end]` driven by a `next_*` search.
+///
+/// Each item calls `next` from just past the previous event, so callers that
+/// stop early (e.g. `take(3)`) never scan the rest of the range. The stream
+/// ends at the first event after `jd_end`, when `next` finds nothing, or
+/// after yielding an error. An empty or inverted range yields one
+/// `InvalidConfig` error, matching the `search_*` functions.
+pub(crate) fn iter_events<'a, E: 'a>(
+    jd_start: f64,
+    jd_end: f64,
+    mut next: impl FnMut(f64) -> Result<Option<E>, SearchError> + 'a,
+    jd_of: fn(&E) -> f64,
+) -> impl Iterator<Item = Result<E, SearchError>> + 'a {
+    let mut range_error =
+        (jd_end <= jd_start).then_some(SearchError::InvalidConfig("jd_end must be after jd_start"));
+    let mut cursor = Some(jd_start);
+    std::iter::from_fn(move || {
+        if let Some(err) = range_error.take() {
+            cursor = None;
+            return Some(Err(err));
+        }
+        let t = cursor.take()?;
+        match next(t) {
+            Ok(Some(event)) if jd_of(&event) <= jd_end => {
+                cursor = Some(jd_of(&event) + EVENT_ITER_RESTART_DAYS);
+                Some(Ok(event))
             }
-
-            return Ok(Some(0.5 * (t_a + t_b)));
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
         }
-
-        t_prev = t_curr;
-        f_prev = f_curr;
-    }
-
-    Ok(None)
+    })
 }
 
 #[cfg(test)]
@@ -221,4 +316,72 @@ mod tests {
         let t = result.unwrap();
         assert!((t - 5.7).abs() < 1e-8, "got {t}");
     }
+
+    #[test]
+    fn bracket_and_refine_monotone() {
+        let f = |t: f64| -> Result<f64, SearchError> { Ok(2.0 * t - 7.4) };
+        let t = bracket_and_refine(&f, 0.0, 10.0, 1.0, 1e-10)
+            .unwrap()
+            .expect("root in range");
+        assert!((t - 3.7).abs() < 1e-9, "got {t}");
+
+        // Same root scanning backward.
+        let t = bracket_and_refine(&f, 10.0, 0.0, 1.0, 1e-10)
+            .unwrap()
+            .expect("root in range");
+        assert!((t - 3.7).abs() < 1e-9, "got {t}");
+
+        // Root beyond `end` is not reported.
+        assert!(
+            bracket_and_refine(&f, 0.0, 3.0, 1.0, 1e-10)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn bracket_and_refine_wrapping_angle() {
+        // Angle advancing 13 deg/day (Moon-like) from 350 deg, target 0 deg.
+        // The wrapped error jumps from +180 to -180 at 180 deg, which must
+        // not be mistaken for the root.
+        let f = |t: f64| -> Result<f64, SearchError> {
+            let lon = (350.0 + 13.0 * t).rem_euclid(360.0);
+            Ok(normalize_to_pm180(lon - 0.0))
+        };
+        let t = bracket_and_refine(&f, 1.0, 40.0, 0.5, 1e-10)
+            .unwrap()
+            .expect("root in range");
+        let expected = 370.0 / 13.0;
+        assert!((t - expected).abs() < 1e-8, "got {t}, want {expected}");
+    }
+
+    #[test]
+    fn iter_events_is_lazy_and_bounded() {
+        use std::cell::Cell;
+        // Events every 10 days starting at 5; range ends at 40.
+        let calls = Cell::new(0);
+        let next = |t: f64| -> Result<Option<f64>, SearchError> {
+            calls.set(calls.get() + 1);
+            Ok(Some(5.0 + 10.0 * ((t - 5.0) / 10.0).max(0.0).ceil()))
+        };
+        let first: Vec<f64> = iter_events(0.0, 40.0, next, |e| *e)
+            .take(2)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(first, vec![5.0, 15.0]);
+        assert_eq!(calls.get(), 2);
+
+        let all: Vec<f64> = iter_events(0.0, 40.0, next, |e| *e)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(all, vec![5.0, 15.0, 25.0, 35.0]);
+    }
+
+    #[test]
+    fn iter_events_rejects_inverted_range() {
+        let next = |_: f64| -> Result<Option<f64>, SearchError> { Ok(Some(1.0)) };
+        let items: Vec<_> = iter_events(10.0, 5.0, next, |e| *e).collect();
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(SearchError::InvalidConfig(_))));
+    }
 }
@@ -7,6 +7,8 @@
 //! reaches a local extremum (acceleration crosses zero).
 //!
 //! Both use the same coarse-scan + bisection pattern as the conjunction engine.
+//! Retrograde (vakra) intervals pair each retrograde station with the
+//! following direct station.
 //!
 //! Algorithm: pure numerical bisection on f(t) = lon_speed(t) for stationary,
 //! and g(t) = (lon_speed(t+h) - lon_speed(t-h)) / (2h) for max speed.
@@ -15,17 +17,26 @@
 
 use dhruv_core::{Body, Engine};
 use dhruv_time::UtcTime;
+use dhruv_vedic_base::{LunarNode, NodeMode, lunar_node_deg_for_epoch};
 
 use crate::conjunction::body_ecliptic_state;
 use crate::conjunction_types::SearchDirection;
 use crate::error::SearchError;
+use crate::search_util::{iter_events, normalize_to_pm180, refine_bracket};
 use crate::stationary_types::{
-    MaxSpeedEvent, MaxSpeedType, StationType, StationaryConfig, StationaryEvent,
+    MaxSpeedEvent, MaxSpeedType, NodeStationEvent, RetrogradeInterval, StationType,
+    StationaryConfig, StationaryEvent,
 };
 
 /// Maximum scan range in days (~800 days covers all synodic periods).
 const MAX_SCAN_DAYS: f64 = 800.0;
 
+/// Coarse scan step for true-node stations (direct phases last a few days).
+const NODE_STATION_STEP_DAYS: f64 = 0.25;
+
+/// Maximum scan range for true-node stations in days.
+const NODE_STATION_MAX_SCAN_DAYS: f64 = 400.0;
+
 // ---------------------------------------------------------------------------
 // Body validation
 // ---------------------------------------------------------------------------
@@ -55,45 +66,6 @@ fn validate_max_speed_body(body: Body) -> Result<(), SearchError> {
     }
 }
 
-// ---------------------------------------------------------------------------
-// Generic bisection solver
-// ---------------------------------------------------------------------------
-
-/// Bisect to find the zero crossing of a scalar function between t_a and t_b.
-///
-/// `f_at` evaluates the function at a given time.
-/// Returns the refined time of the zero crossing.
-fn bisect_zero<F>(
-    mut t_a: f64,
-    mut f_a: f64,
-    mut t_b: f64,
-    _f_b: f64,
-    max_iter: u32,
-    convergence_days: f64,
-    f_at: &F,
-) -> Result<f64, SearchError>
-where
-    F: Fn(f64) -> Result<f64, SearchError>,
-{
-    for _ in 0..max_iter {
-        let t_mid = 0.5 * (t_a + t_b);
-        let f_mid = f_at(t_mid)?;
-
-        if f_a * f_mid <= 0.0 {
-            t_b = t_mid;
-        } else {
-            t_a = t_mid;
-            f_a = f_mid;
-        }
-
-        if (t_b - t_a).abs() < convergence_days {
-            break;
-        }
-    }
-
-    Ok(0.5 * (t_a + t_b))
-}
-
 // ---------------------------------------------------------------------------
 // Stationary point search (velocity = 0)
 // ---------------------------------------------------------------------------
@@ -125,11 +97,11 @@ fn find_stationary_event(
 
         // Check for sign change in velocity
         if v_prev * v_curr < 0.0 {
-            // Ensure t_a < t_b for bisection
-            let (t_a, v_a, t_b, v_b) = if t_prev < t_curr {
-                (t_prev, v_prev, t_curr, v_curr)
+            // Order chronologically: v_a classifies the station type.
+            let (t_a, v_a, t_b) = if t_prev < t_curr {
+                (t_prev, v_prev, t_curr)
             } else {
-                (t_curr, v_curr, t_prev, v_prev)
+                (t_curr, v_curr, t_prev)
             };
 
             let speed_at = |t: f64| -> Result<f64, SearchError> {
@@ -137,14 +109,13 @@ fn find_stationary_event(
                 Ok(v)
             };
 
-            let t_station = bisect_zero(
+            let t_station = refine_bracket(
+                &speed_at,
                 t_a,
                 v_a,
                 t_b,
-                v_b,
                 config.max_iterations,
                 config.convergence_days,
-                &speed_at,
             )?;
 
             let (lon, lat, _) = body_ecliptic_state(engine, body, t_station)?;
@@ -193,6 +164,25 @@ pub fn prev_stationary(
     find_stationary_event(engine, body, jd_tdb, SearchDirection::Backward, config)
 }
 
+/// Lazily stream stationary points in a time range.
+///
+/// Same events as [`search_stationary`], produced one at a time by chaining
+/// [`next_stationary`], so `take(n)` stops the search after `n` stations.
+pub fn iter_stationary<'a>(
+    engine: &'a Engine,
+    body: Body,
+    jd_start: f64,
+    jd_end: f64,
+    config: &'a StationaryConfig,
+) -> impl Iterator<Item = Result<StationaryEvent, SearchError>> + 'a {
+    iter_events(
+        jd_start,
+        jd_end,
+        move |t| next_stationary(engine, body, t, config),
+        |e| e.jd_tdb,
+    )
+}
+
 /// Search for all stationary points in a time range.
 pub fn search_stationary(
     engine: &Engine,
@@ -224,14 +214,13 @@ pub fn search_stationary(
                 Ok(v)
             };
 
-            let t_station = bisect_zero(
+            let t_station = refine_bracket(
+                &speed_at,
                 t_prev,
                 v_prev,
                 t_curr,
-                v_curr,
                 config.max_iterations,
                 config.convergence_days,
-                &speed_at,
             )?;
 
             if t_station >= jd_start && t_station <= jd_end {
@@ -265,6 +254,66 @@ pub fn search_stationary(
     Ok(events)
 }
 
+// ---------------------------------------------------------------------------
+// Retrograde intervals (station retrograde -> station direct)
+// ---------------------------------------------------------------------------
+
+/// List the retrograde (vakra) spans of `body` in a time range.
+///
+/// Each interval runs from a retrograde station to the following direct
+/// station, found with [`search_stationary`]. A span already in progress at
+/// `jd_start` starts there with `open_start` set; one still ongoing at
+/// `jd_end` is cut off there with `open_end` set. Longitudes are tropical
+/// ecliptic-of-date, as in [`StationaryEvent`].
+pub fn retrograde_intervals(
+    engine: &Engine,
+    body: Body,
+    jd_start: f64,
+    jd_end: f64,
+    config: &StationaryConfig,
+) -> Result<Vec<RetrogradeInterval>, SearchError> {
+    let stations = search_stationary(engine, body, jd_start, jd_end, config)?;
+
+    // (start_jd, start_lon_deg, open_start) of the span being built.
+    let (lon, _, speed) = body_ecliptic_state(engine, body, jd_start)?;
+    let mut current = (speed < 0.0).then_some((jd_start, lon, true));
+
+    let mut intervals = Vec::new();
+    for station in &stations {
+        match station.station_type {
+            StationType::StationRetrograde => {
+                current = Some((station.jd_tdb, station.longitude_deg, false));
+            }
+            StationType::StationDirect => {
+                if let Some((start_jd, start_lon_deg, open_start)) = current.take() {
+                    intervals.push(RetrogradeInterval {
+                        start_jd,
+                        end_jd: station.jd_tdb,
+                        start_lon_deg,
+                        end_lon_deg: station.longitude_deg,
+                        open_start,
+                        open_end: false,
+                    });
+                }
+            }
+        }
+    }
+
+    if let Some((start_jd, start_lon_deg, open_start)) = current {
+        let (end_lon_deg, _, _) = body_ecliptic_state(engine, body, jd_end)?;
+        intervals.push(RetrogradeInterval {
+            start_jd,
+            end_jd: jd_end,
+            start_lon_deg,
+            end_lon_deg,
+            open_start,
+            open_end: true,
+        });
+    }
+
+    Ok(intervals)
+}
+
 // ---------------------------------------------------------------------------
 // Max speed search (acceleration = 0, i.e. velocity extremum)
 // ---------------------------------------------------------------------------
@@ -303,23 +352,16 @@ fn find_max_speed_event(
         let a_curr = numerical_acceleration(engine, body, t_curr, h)?;
 
         if a_prev * a_curr < 0.0 {
-            let (t_a, a_a, t_b, a_b) = if t_prev < t_curr {
-                (t_prev, a_prev, t_curr, a_curr)
-            } else {
-                (t_curr, a_curr, t_prev, a_prev)
-            };
-
             let accel_at =
                 |t: f64| -> Result<f64, SearchError> { numerical_acceleration(engine, body, t, h) };
 
-            let t_peak = bisect_zero(
-                t_a,
-                a_a,
-                t_b,
-                a_b,
+            let t_peak = refine_bracket(
+                &accel_at,
+                t_prev,
+                a_prev,
+                t_curr,
                 config.max_iterations,
                 config.convergence_days,
-                &accel_at,
             )?;
 
             let (lon, lat, speed) = body_ecliptic_state(engine, body, t_peak)?;
@@ -398,14 +440,13 @@ pub fn search_max_speed(
             let accel_at =
                 |t: f64| -> Result<f64, SearchError> { numerical_acceleration(engine, body, t, h) };
 
-            let t_peak = bisect_zero(
+            let t_peak = refine_bracket(
+                &accel_at,
                 t_prev,
                 a_prev,
                 t_curr,
-                a_curr,
                 config.max_iterations,
                 config.convergence_days,
-                &accel_at,
             )?;
 
             if t_peak >= jd_start && t_peak <= jd_end {
@@ -440,6 +481,61 @@ pub fn search_max_speed(
     Ok(events)
 }
 
+// ---------------------------------------------------------------------------
+// True-node station search
+// ---------------------------------------------------------------------------
+
+/// Osculating Rahu longitude and speed (deg/day) by central difference.
+fn true_node_state(engine: &Engine, jd_tdb: f64) -> Result<(f64, f64), SearchError> {
+    const DT: f64 = 1.0 / 1440.0; // 1-minute step in JD days
+    let node_at = |t: f64| lunar_node_deg_for_epoch(engine, LunarNode::Rahu, t, NodeMode::True);
+    let lon = node_at(jd_tdb)?;
+    let plus = node_at(jd_tdb + DT)?;
+    let minus = node_at(jd_tdb - DT)?;
+    Ok((lon, normalize_to_pm180(plus - minus) / (2.0 * DT)))
+}
+
+/// Find the next true (osculating) node station after `jd_tdb`.
+///
+/// Scans the osculating Rahu speed at 6-hour steps for a sign change, then
+/// bisects to the zero crossing. Ketu stations at the same instant.
+pub fn next_node_station(
+    engine: &Engine,
+    jd_tdb: f64,
+) -> Result<Option<NodeStationEvent>, SearchError> {
+    let speed_at = |t: f64| -> Result<f64, SearchError> { Ok(true_node_state(engine, t)?.1) };
+
+    let max_steps = (NODE_STATION_MAX_SCAN_DAYS / NODE_STATION_STEP_DAYS).ceil() as usize;
+    let mut t_prev = jd_tdb;
+    let mut v_prev = speed_at(t_prev)?;
+
+    for _ in 0..max_steps {
+        let t_curr = t_prev + NODE_STATION_STEP_DAYS;
+        let v_curr = speed_at(t_curr)?;
+
+        if v_prev * v_curr < 0.0 {
+            let t_station = refine_bracket(&speed_at, t_prev, v_prev, t_curr, 50, 1e-8)?;
+            let (lon, _) = true_node_state(engine, t_station)?;
+            let station_type = if v_prev > 0.0 {
+                StationType::StationRetrograde
+            } else {
+                StationType::StationDirect
+            };
+            return Ok(Some(NodeStationEvent {
+                jd_tdb: t_station,
+                utc: UtcTime::from_jd_tdb(t_station, engine.lsk()),
+                rahu_longitude_deg: lon,
+                station_type,
+            }));
+        }
+
+        t_prev = t_curr;
+        v_prev = v_curr;
+    }
+
+    Ok(None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -29,6 +29,39 @@ pub struct StationaryEvent {
     pub station_type: StationType,
 }
 
+/// A retrograde (vakra) span between a retrograde and a direct station.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetrogradeInterval {
+    /// Retrograde station (or search start when `open_start`), JD TDB.
+    pub start_jd: f64,
+    /// Direct station (or search end when `open_end`), JD TDB.
+    pub end_jd: f64,
+    /// Ecliptic longitude at `start_jd` in degrees [0, 360).
+    pub start_lon_deg: f64,
+    /// Ecliptic longitude at `end_jd` in degrees [0, 360).
+    pub end_lon_deg: f64,
+    /// The body was already retrograde at the search start.
+    pub open_start: bool,
+    /// The body was still retrograde at the search end.
+    pub open_end: bool,
+}
+
+/// A true-node station (osculating Rahu's longitude velocity crosses zero).
+///
+/// The true node normally regresses; `StationDirect` marks the start of a
+/// brief direct phase and `StationRetrograde` its end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeStationEvent {
+    /// Event time as Julian Date (TDB).
+    pub jd_tdb: f64,
+    /// Event time as structured Gregorian UTC.
+    pub utc: UtcTime,
+    /// True Rahu ecliptic-of-date longitude at station in degrees [0, 360).
+    pub rahu_longitude_deg: f64,
+    /// Whether the node turns retrograde or direct.
+    pub station_type: StationType,
+}
+
 /// Max speed type classification.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MaxSpeedType {
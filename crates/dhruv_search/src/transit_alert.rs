@@ -0,0 +1,113 @@
+//! Daily transit alerts: aspects from transiting grahas to natal points.
+//!
+//! Samples all nine graha longitudes hourly across one UTC day. For each
+//! graha, natal point, and aspect, the signed distance from exact aspect is
+//! tracked; an alert is raised when it comes within orb, and a sign change
+//! is refined by bisection to the moment of exactness, evaluating only the
+//! transiting graha at each step.
+
+use dhruv_core::Engine;
+use dhruv_time::UtcTime;
+use dhruv_vedic_base::ALL_GRAHAS;
+
+use crate::error::SearchError;
+use crate::jyotish::{graha_longitude, graha_longitudes};
+use crate::jyotish_types::GrahaLongitudesConfig;
+use crate::search_util::{is_genuine_crossing, normalize_to_pm180, refine_bracket};
+use crate::transit_alert_types::{TRANSIT_ALERT_STEP_DAYS, TransitAlert};
+
+/// Report every transit aspect to a natal point within orb on `date`.
+///
+/// The day is the UTC calendar day of `date` (its time of day is ignored).
+/// `natal_points` are `(name, longitude)` pairs in the frame selected by
+/// `config` (sidereal or tropical), e.g. natal grahas, lagna, or bindus.
+/// Each aspect angle is measured forward from the natal point to the
+/// transiting graha, so pass both 90 and 270 for the two squares.
+///
+/// Alerts are ordered by graha, then natal point, then aspect.
+pub fn daily_transit_alerts(
+    engine: &Engine,
+    natal_points: &[(String, f64)],
+    date: &UtcTime,
+    orb_deg: f64,
+    aspects: &[f64],
+    config: &GrahaLongitudesConfig,
+) -> Result<Vec<TransitAlert>, SearchError> {
+    if !orb_deg.is_finite() || !(0.0..90.0).contains(&orb_deg) {
+        return Err(SearchError::InvalidConfig("orb_deg must be in [0, 90)"));
+    }
+    if aspects.iter().any(|a| !a.is_finite()) {
+        return Err(SearchError::InvalidConfig("aspect angles must be finite"));
+    }
+    if natal_points.iter().any(|(_, lon)| !lon.is_finite()) {
+        return Err(SearchError::InvalidConfig(
+            "natal point longitudes must be finite",
+        ));
+    }
+
+    let day_start = UtcTime::new(date.year, date.month, date.day, 0, 0, 0.0);
+    let jd_start = crate::search_util::utc_to_jd_tdb(engine, &day_start);
+    let steps = (1.0 / TRANSIT_ALERT_STEP_DAYS).round() as usize;
+    let times: Vec<f64> = (0..=steps)
+        .map(|i| jd_start + i as f64 * TRANSIT_ALERT_STEP_DAYS)
+        .collect();
+    let samples = times
+        .iter()
+        .map(|&t| graha_longitudes(engine, t, config))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut alerts = Vec::new();
+    for graha in ALL_GRAHAS {
+        for (name, natal_lon) in natal_points {
+            for &aspect in aspects {
+                let target = natal_lon + aspect;
+                let offsets: Vec<f64> = samples
+                    .iter()
+                    .map(|s| normalize_to_pm180(s.longitude(graha) - target))
+                    .collect();
+                let min_orb_deg = offsets.iter().fold(f64::INFINITY, |m, f| m.min(f.abs()));
+
+                let mut exact_jd_tdb = None;
+                for i in 0..steps {
+                    let (f_a, f_b) = (offsets[i], offsets[i + 1]);
+                    if f_a == 0.0 {
+                        exact_jd_tdb = Some(times[i]);
+                        break;
+                    }
+                    if is_genuine_crossing(f_a, f_b) {
+                        let offset = |t: f64| -> Result<f64, SearchError> {
+                            let lon = graha_longitude(engine, t, graha, config)?;
+                            Ok(normalize_to_pm180(lon - target))
+                        };
+                        exact_jd_tdb = Some(refine_bracket(
+                            &offset,
+                            times[i],
+                            f_a,
+                            times[i + 1],
+                            50,
+                            1e-8,
+                        )?);
+                        break;
+                    }
+                }
+
+                if min_orb_deg <= orb_deg || exact_jd_tdb.is_some() {
+                    alerts.push(TransitAlert {
+                        graha,
+                        natal_point: name.clone(),
+                        natal_longitude_deg: *natal_lon,
+                        aspect_deg: aspect,
+                        min_orb_deg: if exact_jd_tdb.is_some() {
+                            0.0
+                        } else {
+                            min_orb_deg
+                        },
+                        exact_jd_tdb,
+                        exact_utc: exact_jd_tdb.map(|jd| UtcTime::from_jd_tdb(jd, engine.lsk())),
+                    });
+                }
+            }
+        }
+    }
+    Ok(alerts)
+}
@@ -0,0 +1,29 @@
+//! Types for daily transit alerts against natal points.
+
+use dhruv_time::UtcTime;
+use dhruv_vedic_base::Graha;
+
+/// Coarse sampling step for the daily scan, in days (one hour).
+///
+/// The Moon moves ~0.55 deg per hour, so an aspect crossing cannot hide
+/// between samples.
+pub const TRANSIT_ALERT_STEP_DAYS: f64 = 1.0 / 24.0;
+
+/// A transiting graha within orb of an aspect to a natal point on one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitAlert {
+    /// Transiting graha.
+    pub graha: Graha,
+    /// Caller-supplied name of the natal point (e.g. "Lagna", "Moon").
+    pub natal_point: String,
+    /// Natal point longitude in degrees, as supplied.
+    pub natal_longitude_deg: f64,
+    /// Aspect angle in degrees, measured forward from the natal point.
+    pub aspect_deg: f64,
+    /// Smallest distance from exact aspect over the day, in degrees.
+    pub min_orb_deg: f64,
+    /// Time of exactness as Julian Date (TDB), if it falls within the day.
+    pub exact_jd_tdb: Option<f64>,
+    /// Time of exactness as structured Gregorian UTC, if within the day.
+    pub exact_utc: Option<UtcTime>,
+}
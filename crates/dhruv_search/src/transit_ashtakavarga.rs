@@ -0,0 +1,134 @@
+//! Transit (gochara) scoring against natal ashtakavarga.
+//!
+//! Looks up the natal BAV/SAV bindus for the rashi a transiting graha
+//! occupies, with an optional bhava-chalit house assignment from the natal
+//! cusps. Pure function: pass the natal `AshtakavargaResult`, sidereal natal
+//! `BhavaResult`, and the transit's sidereal longitude.
+
+use dhruv_vedic_base::{AshtakavargaResult, BhavaResult, Graha, house_of_longitude};
+
+use crate::transit_ashtakavarga_types::{
+    TransitAshtakavargaConfig, TransitBindus, TransitHouseMode,
+};
+
+/// Score a transiting graha against natal ashtakavarga.
+///
+/// `natal_bhavas` must be sidereal (e.g. from `sidereal_bhavas_for_date`);
+/// its `lagna_deg` fixes the whole-sign houses and its bhava bounds
+/// (`start_deg`, the sandhis) the chalit houses. Either way the bindus are
+/// read from the rashi of the chosen house counted from the lagna rashi, so
+/// in `Chalit` mode a graha just past a bhava sandhi is scored in the next
+/// house's rashi.
+pub fn transit_bindus(
+    natal: &AshtakavargaResult,
+    natal_bhavas: &BhavaResult,
+    graha: Graha,
+    transit_lon_sid: f64,
+    config: &TransitAshtakavargaConfig,
+) -> TransitBindus {
+    let lagna_rashi = rashi_index_of(natal_bhavas.lagna_deg);
+    let house = match config.house_mode {
+        TransitHouseMode::WholeSign => {
+            (rashi_index_of(transit_lon_sid) + 12 - lagna_rashi) % 12 + 1
+        }
+        TransitHouseMode::Chalit => {
+            house_of_longitude(transit_lon_sid, &natal_bhavas.bhavas.map(|b| b.start_deg))
+        }
+    };
+    let rashi_index = (lagna_rashi + house - 1) % 12;
+    let bav_bindus = natal
+        .bavs
+        .get(graha.index() as usize)
+        .map(|bav| bav.points[rashi_index as usize]);
+
+    TransitBindus {
+        graha,
+        transit_longitude_deg: transit_lon_sid,
+        house,
+        rashi_index,
+        bav_bindus,
+        sav_bindus: natal.sav.total_points[rashi_index as usize],
+    }
+}
+
+fn rashi_index_of(lon_deg: f64) -> u8 {
+    (lon_deg.rem_euclid(360.0) / 30.0).floor().min(11.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dhruv_vedic_base::{Bhava, calculate_ashtakavarga};
+
+    /// Natal lagna at 20 deg Mesha with equal cusps from the lagna degree.
+    fn natal_bhavas() -> BhavaResult {
+        let mut bhavas = [Bhava {
+            number: 0,
+            cusp_deg: 0.0,
+            start_deg: 0.0,
+            end_deg: 0.0,
+            madhya_deg: 0.0,
+        }; 12];
+        for (i, bhava) in bhavas.iter_mut().enumerate() {
+            let cusp = (20.0 + 30.0 * i as f64) % 360.0;
+            *bhava = Bhava {
+                number: i as u8 + 1,
+                cusp_deg: cusp,
+                start_deg: cusp,
+                end_deg: (cusp + 30.0) % 360.0,
+                madhya_deg: (cusp + 15.0) % 360.0,
+            };
+        }
+        BhavaResult {
+            bhavas,
+            lagna_deg: 20.0,
+            mc_deg: 290.0,
+        }
+    }
+
+    #[test]
+    fn chalit_scores_graha_before_sandhi_in_previous_house() {
+        let natal = calculate_ashtakavarga(&[4, 8, 2, 5, 10, 1, 7], 0);
+        let bhavas = natal_bhavas();
+        // 35 deg = 5 deg Vrishabha: whole-sign house 2, but still before the
+        // 2nd cusp at 50 deg, so chalit house 1 (Mesha).
+        let whole = transit_bindus(
+            &natal,
+            &bhavas,
+            Graha::Guru,
+            35.0,
+            &TransitAshtakavargaConfig::default(),
+        );
+        let chalit = transit_bindus(
+            &natal,
+            &bhavas,
+            Graha::Guru,
+            35.0,
+            &TransitAshtakavargaConfig {
+                house_mode: TransitHouseMode::Chalit,
+            },
+        );
+
+        assert_eq!((whole.house, whole.rashi_index), (2, 1));
+        assert_eq!((chalit.house, chalit.rashi_index), (1, 0));
+        let guru = Graha::Guru.index() as usize;
+        assert_eq!(whole.bav_bindus, Some(natal.bavs[guru].points[1]));
+        assert_eq!(chalit.bav_bindus, Some(natal.bavs[guru].points[0]));
+        assert_eq!(chalit.sav_bindus, natal.sav.total_points[0]);
+    }
+
+    #[test]
+    fn nodes_have_sav_only() {
+        let natal = calculate_ashtakavarga(&[4, 8, 2, 5, 10, 1, 7], 0);
+        let scored = transit_bindus(
+            &natal,
+            &natal_bhavas(),
+            Graha::Rahu,
+            200.0,
+            &TransitAshtakavargaConfig::default(),
+        );
+        assert_eq!(scored.bav_bindus, None);
+        assert_eq!(scored.house, 7);
+        assert_eq!(scored.sav_bindus, natal.sav.total_points[6]);
+    }
+}
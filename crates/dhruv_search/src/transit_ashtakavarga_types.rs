@@ -0,0 +1,40 @@
+//! Types for scoring transiting grahas against natal ashtakavarga.
+
+use dhruv_vedic_base::Graha;
+
+/// How a transiting graha's house is chosen for the bindu lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransitHouseMode {
+    /// House is the rashi offset from the natal lagna rashi (classical).
+    #[default]
+    WholeSign,
+    /// House comes from the natal cusps via `house_of_longitude` (bhava
+    /// chalit), so a graha past a bhava sandhi is scored in the next house.
+    Chalit,
+}
+
+/// Configuration for transit ashtakavarga scoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TransitAshtakavargaConfig {
+    /// House assignment for the transiting graha.
+    pub house_mode: TransitHouseMode,
+}
+
+/// Natal ashtakavarga bindus under a transiting graha.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitBindus {
+    /// Transiting graha.
+    pub graha: Graha,
+    /// Sidereal transit longitude in degrees, as supplied.
+    pub transit_longitude_deg: f64,
+    /// Natal house (1-12) the graha is scored in.
+    pub house: u8,
+    /// 0-based rashi whose bindus were read: the rashi of `house` counted
+    /// from the natal lagna rashi.
+    pub rashi_index: u8,
+    /// The graha's own BAV bindus in that rashi (`None` for Rahu/Ketu,
+    /// which have no BAV).
+    pub bav_bindus: Option<u8>,
+    /// SAV total bindus in that rashi.
+    pub sav_bindus: u8,
+}
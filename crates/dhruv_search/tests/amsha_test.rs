@@ -8,12 +8,13 @@ use dhruv_core::{Engine, EngineConfig};
 use dhruv_search::sankranti_types::SankrantiConfig;
 use dhruv_search::{
     AmshaChartScope, AmshaSelectionConfig, FullKundaliConfig, GrahaPositionsConfig,
-    amsha_charts_for_date, amsha_charts_from_kundali, full_kundali_for_date,
+    amsha_charts_for_date, amsha_charts_from_kundali, full_kundali_for_date, navamsa_chart,
+    sidereal_lagna_for_date,
 };
 use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
 use dhruv_vedic_base::{
-    Amsha, AmshaRequest, BhavaConfig, D2_CANCER_LEO_ONLY_VARIATION_CODE,
-    DEFAULT_AMSHA_VARIATION_CODE,
+    ALL_RASHIS, Amsha, AmshaRequest, BhavaConfig, D2_CANCER_LEO_ONLY_VARIATION_CODE,
+    DEFAULT_AMSHA_VARIATION_CODE, amsha_longitude, rashi_from_longitude,
 };
 
 use dhruv_time::{EopKernel, UtcTime};
@@ -627,3 +628,28 @@ fn validation_unknown_amsha_code() {
     );
     assert!(result.is_err());
 }
+
+#[test]
+fn navamsa_chart_derives_from_rasi_positions() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = utc_2024_jan_15();
+    let aya_config = default_aya_config();
+    let chart = navamsa_chart(&engine, &eop, &utc, &new_delhi(), &aya_config)
+        .expect("navamsa_chart should succeed");
+
+    for entry in chart.grahas.iter().chain([&chart.lagna]) {
+        assert!(entry.rashi_index < 12);
+        assert_eq!(entry.rashi, ALL_RASHIS[entry.rashi_index as usize]);
+    }
+
+    let lagna = sidereal_lagna_for_date(&engine, &eop, &utc, &new_delhi(), &aya_config).unwrap();
+    assert!((chart.rasi_lagna.sidereal_longitude - lagna).abs() < 1e-9);
+    let d9_lagna = amsha_longitude(lagna, Amsha::D9, None);
+    assert_eq!(chart.lagna.rashi, rashi_from_longitude(d9_lagna).rashi);
+
+    for (rasi, d9) in chart.rasi_grahas.iter().zip(&chart.grahas) {
+        let expected = amsha_longitude(rasi.sidereal_longitude, Amsha::D9, None);
+        assert!((d9.sidereal_longitude - expected).abs() < 1e-12);
+    }
+}
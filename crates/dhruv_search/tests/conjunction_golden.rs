@@ -6,7 +6,9 @@
 use std::path::Path;
 
 use dhruv_core::{Body, Engine, EngineConfig};
-use dhruv_search::{ConjunctionConfig, next_conjunction, prev_conjunction, search_conjunctions};
+use dhruv_search::{
+    ConjunctionConfig, iter_conjunctions, next_conjunction, prev_conjunction, search_conjunctions,
+};
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
 const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
@@ -175,3 +177,29 @@ fn prev_new_moon() {
     let diff_days = (event.jd_tdb - expected_jd).abs();
     assert!(diff_days < 2.0, "prev new moon off by {diff_days:.1} days");
 }
+
+/// The lazy iterator yields the same leading events as the Vec search.
+#[test]
+fn iter_conjunctions_matches_search_prefix() {
+    let Some(engine) = load_engine() else { return };
+    let jd_start = jd_from_date(2024, 1, 1.0);
+    let jd_end = jd_from_date(2025, 1, 1.0);
+    let config = ConjunctionConfig::conjunction(0.5);
+    let events = search_conjunctions(&engine, Body::Sun, Body::Moon, jd_start, jd_end, &config)
+        .expect("search should succeed");
+    let streamed: Vec<_> =
+        iter_conjunctions(&engine, Body::Sun, Body::Moon, jd_start, jd_end, &config)
+            .take(3)
+            .collect::<Result<_, _>>()
+            .expect("iteration should succeed");
+
+    assert_eq!(streamed.len(), 3);
+    for (a, b) in streamed.iter().zip(&events) {
+        assert!(
+            (a.jd_tdb - b.jd_tdb).abs() < 1e-6,
+            "iter {} vs search {}",
+            a.jd_tdb,
+            b.jd_tdb
+        );
+    }
+}
@@ -12,11 +12,11 @@ use dhruv_search::{
     dasha_hierarchy_with_inputs, dasha_snapshot_at, full_kundali_for_date, graha_longitudes,
 };
 use dhruv_time::{EopKernel, UtcTime, jd_to_tdb_seconds, tdb_seconds_to_jd};
-use dhruv_vedic_base::BhavaConfig;
 use dhruv_vedic_base::dasha::{
     DashaEntity, DashaLevel, DashaSystem, DashaVariationConfig, RashiDashaInputs,
 };
 use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
+use dhruv_vedic_base::{BhavaConfig, Graha, nakshatra_from_longitude};
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
 const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
@@ -199,6 +199,58 @@ fn vimshottari_snapshot_valid() {
     assert_eq!(snapshot.periods[2].level, DashaLevel::Pratyantardasha);
 }
 
+/// Querying the snapshot at the birth instant itself returns the janma
+/// mahadasha: the Vimshottari lord of the birth Moon's nakshatra.
+#[test]
+fn snapshot_at_birth_returns_janma_mahadasha_lord() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let birth = birth_utc();
+    let aya_config = default_aya_config();
+
+    let snapshot = dasha_snapshot_at(
+        &engine,
+        &eop,
+        &birth,
+        &birth,
+        &new_delhi(),
+        DashaSystem::Vimshottari,
+        2,
+        &BhavaConfig::default(),
+        &RiseSetConfig::default(),
+        &aya_config,
+        &DashaVariationConfig::default(),
+    )
+    .expect("snapshot at birth should succeed");
+    assert_eq!(snapshot.periods.len(), 3);
+
+    let moon_sid = dhruv_search::moon_sidereal_longitude_at(
+        &engine,
+        birth.to_jd_tdb(engine.lsk()),
+        &aya_config,
+    )
+    .expect("moon lon should succeed");
+    let nakshatra_index = nakshatra_from_longitude(moon_sid).nakshatra_index as usize;
+    const VIMSHOTTARI_LORDS: [Graha; 9] = [
+        Graha::Ketu,
+        Graha::Shukra,
+        Graha::Surya,
+        Graha::Chandra,
+        Graha::Mangal,
+        Graha::Rahu,
+        Graha::Guru,
+        Graha::Shani,
+        Graha::Buddh,
+    ];
+    let janma_lord = VIMSHOTTARI_LORDS[nakshatra_index % 9];
+
+    let maha = &snapshot.periods[0];
+    assert_eq!(maha.level, DashaLevel::Mahadasha);
+    assert_eq!(maha.entity, DashaEntity::Graha(janma_lord));
+    // The janma mahadasha started before birth (balance only remains).
+    assert!(maha.start_jd <= snapshot.query_jd && snapshot.query_jd < maha.end_jd);
+}
+
 /// All 10 nakshatra-based systems should produce valid hierarchies.
 #[test]
 fn all_nakshatra_systems_hierarchy_valid() {
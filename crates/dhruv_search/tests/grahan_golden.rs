@@ -7,8 +7,9 @@ use std::path::Path;
 
 use dhruv_core::{Engine, EngineConfig};
 use dhruv_search::{
-    ChandraGrahanType, GrahanConfig, next_chandra_grahan, next_surya_grahan, prev_chandra_grahan,
-    prev_surya_grahan, search_chandra_grahan, search_surya_grahan,
+    ChandraGrahanType, EclipseSeason, GrahanConfig, next_chandra_grahan, next_eclipse_season,
+    next_surya_grahan, prev_chandra_grahan, prev_surya_grahan, search_chandra_grahan,
+    search_surya_grahan,
 };
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
@@ -229,3 +230,61 @@ fn prev_surya_grahan_from_2024() {
         assert!(grahan.greatest_grahan_jd < c4);
     }
 }
+
+// ---------------------------------------------------------------------------
+// Eclipse seasons
+// ---------------------------------------------------------------------------
+
+fn seasons_between(engine: &Engine, jd_start: f64, jd_end: f64) -> Vec<EclipseSeason> {
+    let mut seasons = Vec::new();
+    let mut jd = jd_start;
+    while jd < jd_end {
+        let season = next_eclipse_season(engine, jd)
+            .expect("season search should succeed")
+            .expect("should find season");
+        jd = season.end_jd + 1.0;
+        seasons.push(season);
+    }
+    seasons
+}
+
+/// Every grahan in 2024-2026 falls inside a predicted eclipse season, and
+/// seasons are ~5 weeks long, ~173 days apart.
+#[test]
+fn eclipse_seasons_contain_all_grahan() {
+    let Some(engine) = load_engine() else { return };
+    let jd_start = jd_from_date(2024, 1, 1.0);
+    let jd_end = jd_from_date(2027, 1, 1.0);
+    let config = GrahanConfig::default();
+    let seasons = seasons_between(&engine, jd_start - 40.0, jd_end);
+
+    for s in &seasons {
+        let width = s.end_jd - s.start_jd;
+        assert!((30.0..42.0).contains(&width), "season width {width:.1} d");
+    }
+    for pair in seasons.windows(2) {
+        let gap = pair[1].start_jd - pair[0].start_jd;
+        assert!((160.0..190.0).contains(&gap), "season spacing {gap:.1} d");
+        assert_ne!(pair[0].node, pair[1].node, "seasons should alternate nodes");
+    }
+
+    let in_season = |jd: f64| seasons.iter().any(|s| s.start_jd <= jd && jd <= s.end_jd);
+    let surya = search_surya_grahan(&engine, jd_start, jd_end, &config).unwrap();
+    assert!(!surya.is_empty());
+    for g in &surya {
+        assert!(
+            in_season(g.greatest_grahan_jd),
+            "surya grahan at {} outside eclipse seasons",
+            g.greatest_grahan_utc
+        );
+    }
+    let chandra = search_chandra_grahan(&engine, jd_start, jd_end, &config).unwrap();
+    assert!(!chandra.is_empty());
+    for g in &chandra {
+        assert!(
+            in_season(g.greatest_grahan_jd),
+            "chandra grahan at {} outside eclipse seasons",
+            g.greatest_grahan_utc
+        );
+    }
+}
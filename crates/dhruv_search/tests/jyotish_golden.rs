@@ -9,10 +9,17 @@ use std::path::Path;
 
 use dhruv_core::{Engine, EngineConfig};
 use dhruv_search::sankranti_types::SankrantiConfig;
-use dhruv_search::{GrahaPositionsConfig, graha_positions};
-use dhruv_time::{EopKernel, LeapSecondKernel, UtcTime, gmst_rad, local_sidereal_time_rad};
-use dhruv_vedic_base::riseset_types::GeoLocation;
-use dhruv_vedic_base::{BhavaConfig, Rashi};
+use dhruv_search::{
+    FullKundaliConfig, GrahaPositionsConfig, GrahaPositionsTimeAnchor, SearchError,
+    full_kundali_for_date, graha_positions,
+};
+use dhruv_time::{
+    EopKernel, LeapSecondKernel, UtcTime, apparent_local_sidereal_time_rad, calendar_to_jd,
+};
+use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig, RiseSetEvent, RiseSetResult};
+use dhruv_vedic_base::{
+    BhavaConfig, Rashi, approximate_local_noon_jd, compute_rise_set, utc_day_start_jd,
+};
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
 const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
@@ -64,6 +71,8 @@ fn base_all_flags_off() {
         include_lagna: false,
         include_outer_planets: false,
         include_bhava: false,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -130,6 +139,8 @@ fn include_nakshatra_populates_nak_and_pada() {
         include_lagna: false,
         include_outer_planets: false,
         include_bhava: false,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -186,6 +197,8 @@ fn include_lagna_populates_lagna_entry() {
         include_lagna: true,
         include_outer_planets: false,
         include_bhava: false,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -243,6 +256,8 @@ fn include_outer_planets_populates_uranus_neptune_pluto() {
         include_lagna: false,
         include_outer_planets: true,
         include_bhava: false,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -305,6 +320,8 @@ fn include_bhava_populates_bhava_numbers() {
         include_lagna: false,
         include_outer_planets: false,
         include_bhava: true,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -349,6 +366,8 @@ fn all_flags_on() {
         include_lagna: true,
         include_outer_planets: true,
         include_bhava: true,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -432,6 +451,8 @@ fn nakshatra_and_lagna_combined() {
         include_lagna: true,
         include_outer_planets: false,
         include_bhava: false,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -557,6 +578,8 @@ fn graha_positions_july_2024() {
         include_lagna: true,
         include_outer_planets: true,
         include_bhava: true,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -615,6 +638,8 @@ fn bhava_and_outer_planets_combined() {
         include_lagna: false,
         include_outer_planets: true,
         include_bhava: true,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -694,6 +719,57 @@ fn graha_positions_matches_sidereal_longitudes() {
     }
 }
 
+#[test]
+fn graha_speeds_sun_direct_rahu_retrograde() {
+    let Some(engine) = load_engine() else { return };
+    let jd_tdb = utc_2024_jan_15().to_jd_tdb(engine.lsk());
+    let speeds = dhruv_search::graha_speeds(
+        &engine,
+        jd_tdb,
+        &dhruv_search::GrahaLongitudesConfig::sidereal(
+            dhruv_vedic_base::AyanamshaSystem::Lahiri,
+            false,
+        ),
+    )
+    .expect("graha_speeds should succeed");
+
+    let sun = speeds[dhruv_vedic_base::Graha::Surya.index() as usize];
+    // Sun is near perihelion in January, so slightly faster than the mean 0.9856.
+    assert!((sun - 0.985).abs() < 0.04, "Sun speed = {sun}");
+    let moon = speeds[dhruv_vedic_base::Graha::Chandra.index() as usize];
+    assert!((11.5..15.5).contains(&moon), "Moon speed = {moon}");
+    let rahu = speeds[dhruv_vedic_base::Graha::Rahu.index() as usize];
+    let ketu = speeds[dhruv_vedic_base::Graha::Ketu.index() as usize];
+    assert!(rahu < 0.0, "Rahu should be retrograde, got {rahu}");
+    assert!((rahu - ketu).abs() < 1e-9, "Rahu/Ketu speeds differ");
+}
+
+#[test]
+fn graha_speeds_match_longitude_differences() {
+    let Some(engine) = load_engine() else { return };
+    let jd_tdb = utc_2024_jan_15().to_jd_tdb(engine.lsk());
+    let dt = 1.0 / 1440.0;
+    for config in [
+        dhruv_search::GrahaLongitudesConfig::sidereal(
+            dhruv_vedic_base::AyanamshaSystem::Lahiri,
+            true,
+        ),
+        dhruv_search::GrahaLongitudesConfig::tropical(true),
+    ] {
+        let speeds = dhruv_search::graha_speeds(&engine, jd_tdb, &config).unwrap();
+        let plus = dhruv_search::graha_longitudes(&engine, jd_tdb + dt, &config).unwrap();
+        let minus = dhruv_search::graha_longitudes(&engine, jd_tdb - dt, &config).unwrap();
+        for (i, speed) in speeds.iter().enumerate() {
+            let diff = (plus.longitudes[i] - minus.longitudes[i] + 540.0).rem_euclid(360.0) - 180.0;
+            let expected = diff / (2.0 * dt);
+            assert!(
+                (speed - expected).abs() < 1e-5,
+                "graha[{i}]: speed {speed}, differenced {expected}"
+            );
+        }
+    }
+}
+
 // ===== Lagna rising-condition check =====
 
 fn load_lsk() -> Option<LeapSecondKernel> {
@@ -1014,6 +1090,8 @@ fn lagna_is_rising_in_graha_positions() {
         include_lagna: true,
         include_outer_planets: false,
         include_bhava: false,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
     };
 
     let result = graha_positions(
@@ -1045,14 +1123,12 @@ fn lagna_is_rising_in_graha_positions() {
         utc.day as f64 + utc.hour as f64 / 24.0 + utc.minute as f64 / 1440.0 + utc.second / 86400.0,
     );
     let jd_ut1 = eop.utc_to_ut1_jd(jd_utc).expect("EOP lookup");
-    let gmst = gmst_rad(jd_ut1);
     let utc_s = dhruv_time::jd_to_tdb_seconds(jd_utc);
     let tdb_s = _lsk.utc_to_tdb(utc_s);
     let jd_tdb_v = dhruv_time::tdb_seconds_to_jd(tdb_s);
     let t_v = (jd_tdb_v - 2_451_545.0) / 36525.0;
-    let (ee, eps_true) = dhruv_frames::equation_of_equinoxes_and_true_obliquity(t_v);
-    let gast = gmst + ee;
-    let lst = local_sidereal_time_rad(gast, location.longitude_rad());
+    let (_, eps_true) = dhruv_frames::equation_of_equinoxes_and_true_obliquity(t_v);
+    let lst = apparent_local_sidereal_time_rad(jd_ut1, jd_tdb_v, location.longitude_rad());
 
     // Rising condition: hour angle H < 0 (eastern horizon)
     let ra = f64::atan2(
@@ -1071,3 +1147,108 @@ fn lagna_is_rising_in_graha_positions() {
         h.to_degrees()
     );
 }
+
+// ===== Sunrise chart =====
+
+#[test]
+fn positions_at_sunrise_match_independent_sunrise() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = utc_2024_jan_15();
+    let location = new_delhi();
+    let bhava_config = BhavaConfig::default();
+    let riseset_config = RiseSetConfig::default();
+    let aya_config = default_aya_config();
+    let config = GrahaPositionsConfig {
+        include_nakshatra: true,
+        include_lagna: true,
+        include_outer_planets: false,
+        include_bhava: true,
+        include_flags: false,
+        time_anchor: GrahaPositionsTimeAnchor::Exact,
+    };
+    let sunrise_config = GrahaPositionsConfig {
+        time_anchor: GrahaPositionsTimeAnchor::Sunrise(riseset_config),
+        ..config
+    };
+
+    let at_sunrise = graha_positions(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &bhava_config,
+        &aya_config,
+        &sunrise_config,
+    )
+    .expect("graha_positions at the sunrise anchor should succeed");
+
+    // 12:00 UTC is after sunrise in New Delhi, so the chart uses that day's sunrise.
+    let noon_jd =
+        approximate_local_noon_jd(utc_day_start_jd(calendar_to_jd(2024, 1, 15.5)), 77.2090);
+    let sunrise_jd = match compute_rise_set(
+        &engine,
+        engine.lsk(),
+        &eop,
+        &location,
+        RiseSetEvent::Sunrise,
+        noon_jd,
+        &riseset_config,
+    )
+    .expect("sunrise should succeed")
+    {
+        RiseSetResult::Event { jd_tdb, .. } => jd_tdb,
+        other => panic!("expected sunrise event, got {other:?}"),
+    };
+    let manual = graha_positions(
+        &engine,
+        &eop,
+        &UtcTime::from_jd_tdb(sunrise_jd, engine.lsk()),
+        &location,
+        &bhava_config,
+        &aya_config,
+        &config,
+    )
+    .expect("graha_positions at sunrise should succeed");
+
+    for (a, b) in at_sunrise.grahas.iter().zip(manual.grahas.iter()) {
+        assert!((a.sidereal_longitude - b.sidereal_longitude).abs() < 1e-9);
+        assert_eq!(a.bhava_number, b.bhava_number);
+    }
+    assert!((at_sunrise.lagna.sidereal_longitude - manual.lagna.sidereal_longitude).abs() < 1e-9);
+
+    // The Moon moves several degrees between sunrise and noon.
+    let at_noon = graha_positions(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &bhava_config,
+        &aya_config,
+        &config,
+    )
+    .expect("graha_positions at noon should succeed");
+    let moon_shift = at_noon.grahas[1].sidereal_longitude - at_sunrise.grahas[1].sidereal_longitude;
+    assert!(
+        moon_shift.rem_euclid(360.0) > 2.0,
+        "moon shift {moon_shift}"
+    );
+
+    // Full kundali sections share one instant, so a sunrise anchor is rejected.
+    let kundali_config = FullKundaliConfig {
+        graha_positions_config: sunrise_config,
+        ..FullKundaliConfig::default()
+    };
+    let err = full_kundali_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &bhava_config,
+        &riseset_config,
+        &aya_config,
+        &kundali_config,
+    )
+    .expect_err("sunrise anchor should be rejected in full kundali");
+    assert!(matches!(err, SearchError::InvalidConfig(_)));
+}
@@ -7,9 +7,11 @@ use std::path::Path;
 
 use dhruv_core::{Engine, EngineConfig};
 use dhruv_search::{
-    next_amavasya, next_purnima, prev_amavasya, prev_purnima, search_amavasyas, search_purnimas,
+    moon_invisibility_window, next_amavasya, next_purnima, prev_amavasya, prev_purnima,
+    search_amavasyas, search_purnimas,
 };
 use dhruv_time::UtcTime;
+use dhruv_vedic_base::MOON_COMBUSTION_DEG;
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
 const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
@@ -166,3 +168,36 @@ fn amavasya_longitude_conjunction() {
     let sep = if diff > 180.0 { 360.0 - diff } else { diff };
     assert!(sep < 1.0, "expected ~0 deg separation, got {sep:.2}");
 }
+
+/// The invisibility window around the 2024-Jan-11 new moon brackets the
+/// conjunction, with roughly equal halves of about a day each at 12 deg.
+#[test]
+fn moon_invisibility_window_brackets_amavasya() {
+    let Some(engine) = load_engine() else { return };
+    let utc = UtcTime::new(2024, 1, 10, 0, 0, 0.0);
+    let window = moon_invisibility_window(&engine, &utc, MOON_COMBUSTION_DEG)
+        .unwrap()
+        .expect("should find window");
+    let amavasya = next_amavasya(&engine, &utc).unwrap().unwrap();
+    let lsk = engine.lsk();
+    let start = window.start.to_jd_tdb(lsk);
+    let mid = window.amavasya.to_jd_tdb(lsk);
+    let end = window.end.to_jd_tdb(lsk);
+    assert!((mid - amavasya.utc.to_jd_tdb(lsk)).abs() < 1e-6);
+    assert!(start < mid && mid < end);
+    let before = mid - start;
+    let after = end - mid;
+    assert!((0.7..1.3).contains(&before), "waning half {before:.3} d");
+    assert!((0.7..1.3).contains(&after), "waxing half {after:.3} d");
+    assert!(
+        (before - after).abs() < 0.15,
+        "asymmetric window: {before:.3} d vs {after:.3} d"
+    );
+}
+
+#[test]
+fn moon_invisibility_window_rejects_bad_threshold() {
+    let Some(engine) = load_engine() else { return };
+    let utc = UtcTime::new(2024, 1, 10, 0, 0, 0.0);
+    assert!(moon_invisibility_window(&engine, &utc, 0.0).is_err());
+}
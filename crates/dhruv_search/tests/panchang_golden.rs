@@ -10,14 +10,17 @@ use dhruv_core::{Engine, EngineConfig};
 use dhruv_search::panchang_types::{AyanaInfo, MasaInfo, VarshaInfo};
 use dhruv_search::sankranti_types::SankrantiConfig;
 use dhruv_search::{
-    ayana_for_date, elongation_at, ghatika_for_date, ghatika_from_sunrises, hora_for_date,
-    hora_from_sunrises, karana_at, karana_for_date, masa_for_date, moon_sidereal_longitude_at,
-    nakshatra_at, nakshatra_for_date, panchang_for_date, sidereal_sum_at, tithi_at, tithi_for_date,
-    vaar_for_date, vaar_from_sunrises, varsha_for_date, vedic_day_sunrises, yoga_at, yoga_for_date,
+    LimbTarget, PanchangLimb, SearchError, ayana_for_date, elongation_at, festival_date,
+    ghatika_for_date, ghatika_from_sunrises, hora_for_date, hora_from_sunrises, karana_at,
+    karana_for_date, masa_for_date, moon_rashi_ingresses, moon_sidereal_longitude_at, nakshatra_at,
+    nakshatra_for_date, next_elongation_target, next_purnima, next_specific_sankranti,
+    panchang_diff, panchang_for_date, sidereal_sum_at, tithi_at, tithi_day_kind_for_date,
+    tithi_for_date, vaar_for_date, vaar_from_sunrises, varsha_for_date, vedic_day_sunrises,
+    yoga_at, yoga_for_date,
 };
 use dhruv_time::{EopKernel, UtcTime};
-use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
-use dhruv_vedic_base::{Ayana, Masa};
+use dhruv_vedic_base::riseset_types::{DayDefinitionFallback, GeoLocation, RiseSetConfig};
+use dhruv_vedic_base::{Ayana, AyanamshaSystem, Masa, Paksha, Rashi};
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
 const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
@@ -310,8 +313,13 @@ fn panchang_combined_matches_individual() {
     let vaar = vaar_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
     let hora = hora_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
     let ghatika = ghatika_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
+    let tithi_day_kind = tithi_day_kind_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
 
     assert_eq!(combined.tithi, tithi, "tithi mismatch");
+    assert_eq!(
+        combined.tithi_day_kind, tithi_day_kind,
+        "tithi day kind mismatch"
+    );
     assert_eq!(combined.karana, karana, "karana mismatch");
     assert_eq!(combined.yoga, yoga, "yoga mismatch");
     assert_eq!(combined.nakshatra, nakshatra, "nakshatra mismatch");
@@ -358,3 +366,278 @@ fn panchang_with_calendar() {
     assert_eq!(ayana, ayana_direct, "ayana mismatch");
     assert_eq!(varsha, varsha_direct, "varsha mismatch");
 }
+
+/// At 70N in midwinter the Sun never rises: each day fallback behaves as documented.
+#[test]
+fn polar_night_day_fallbacks() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let lsk = engine.lsk();
+    let utc = UtcTime::new(2024, 12, 21, 12, 0, 0.0);
+    let loc = GeoLocation::new(70.0, 25.0, 0.0);
+    let with = |day_fallback| RiseSetConfig {
+        day_fallback,
+        ..RiseSetConfig::default()
+    };
+
+    // Error (default): no sunrise, no Vedic day.
+    let err = vaar_for_date(&engine, &eop, &utc, &loc, &RiseSetConfig::default()).unwrap_err();
+    assert!(matches!(err, SearchError::NoConvergence(_)), "got {err:?}");
+
+    // CivilTwilight: the Sun still climbs above -6 deg, so civil dawn bounds the day.
+    let rs = with(DayDefinitionFallback::CivilTwilight);
+    let (sr, nsr) = vedic_day_sunrises(&engine, &eop, &utc, &loc, &rs).unwrap();
+    let jd = utc.to_jd_tdb(lsk);
+    assert!(sr <= jd && jd < nsr);
+    assert!((nsr - sr - 1.0).abs() < 0.02, "day length {} d", nsr - sr);
+    let vaar = vaar_for_date(&engine, &eop, &utc, &loc, &rs).unwrap();
+    assert_eq!(vaar, vaar_from_sunrises(sr, nsr, lsk));
+
+    // LocalSixAm: 06:00 local mean time at 25E is 04:20 UTC.
+    let rs = with(DayDefinitionFallback::LocalSixAm);
+    let (sr, nsr) = vedic_day_sunrises(&engine, &eop, &utc, &loc, &rs).unwrap();
+    let start = UtcTime::from_jd_tdb(sr, lsk);
+    let start_min = start.hour as f64 * 60.0 + start.minute as f64 + start.second / 60.0;
+    assert_eq!(start.day, 21);
+    assert!((start_min - 260.0).abs() < 0.1, "start {start}");
+    assert!((nsr - sr - 1.0).abs() < 1e-6);
+}
+
+// ===== Limb target search =====
+
+#[test]
+fn tithi_limb_at_180_matches_purnima() {
+    let Some(engine) = load_engine() else { return };
+    let utc = UtcTime::new(2024, 3, 1, 0, 0, 0.0);
+    let jd = utc.to_jd_tdb(engine.lsk());
+    let event = next_elongation_target(&engine, LimbTarget::Tithi, 180.0, jd, &default_config())
+        .expect("search should succeed")
+        .expect("purnima within a month");
+    let purnima = next_purnima(&engine, &utc)
+        .expect("purnima search should succeed")
+        .expect("purnima within a month");
+    let purnima_jd = purnima.utc.to_jd_tdb(engine.lsk());
+    assert!(
+        (event.jd_tdb - purnima_jd).abs() < 1e-5,
+        "limb {} vs purnima {}",
+        event.jd_tdb,
+        purnima_jd
+    );
+    let elong = elongation_at(&engine, event.jd_tdb).unwrap();
+    assert!((elong - 180.0).abs() < 1e-4);
+}
+
+#[test]
+fn sidereal_limbs_hit_arbitrary_targets() {
+    let Some(engine) = load_engine() else { return };
+    let config = default_config();
+    let utc = UtcTime::new(2024, 3, 1, 0, 0, 0.0);
+    let jd = utc.to_jd_tdb(engine.lsk());
+
+    // Moon at 10 deg Vrishabha and yoga sum at 0 deg, within one lunar cycle.
+    for (limb, target) in [(LimbTarget::Nakshatra, 40.0), (LimbTarget::Yoga, 0.0)] {
+        let event = next_elongation_target(&engine, limb, target, jd, &config)
+            .expect("search should succeed")
+            .expect("target reached within one cycle");
+        assert!(event.jd_tdb > jd && event.jd_tdb - jd < 28.0);
+        let value = match limb {
+            LimbTarget::Nakshatra => moon_sidereal_longitude_at(&engine, event.jd_tdb, &config),
+            _ => sidereal_sum_at(&engine, event.jd_tdb, &config),
+        }
+        .unwrap();
+        let diff = (value - target + 180.0).rem_euclid(360.0) - 180.0;
+        assert!(diff.abs() < 1e-4, "{limb:?}: {value}");
+    }
+
+    // Sun at 0 deg sidereal is Mesha Sankranti.
+    let event = next_elongation_target(&engine, LimbTarget::Sankranti, 0.0, jd, &config)
+        .expect("search should succeed")
+        .expect("sankranti within a year");
+    let sankranti = next_specific_sankranti(&engine, &utc, Rashi::Mesha, &config)
+        .expect("sankranti search should succeed")
+        .expect("sankranti within a year");
+    let sankranti_jd = sankranti.utc.to_jd_tdb(engine.lsk());
+    assert!((event.jd_tdb - sankranti_jd).abs() < 1e-5);
+}
+
+/// Moon ingresses over one month: each enters the next rashi, spaced by the
+/// Moon's ~13 deg/day motion through a 30 deg sign.
+#[test]
+fn moon_rashi_ingresses_march_2024() {
+    let Some(engine) = load_engine() else { return };
+    let config = default_config();
+    let start = UtcTime::new(2024, 3, 1, 0, 0, 0.0);
+    let end = UtcTime::new(2024, 3, 31, 0, 0, 0.0);
+    let events = moon_rashi_ingresses(&engine, &start, &end, &config).unwrap();
+    assert!(
+        (12..=14).contains(&events.len()),
+        "got {} ingresses",
+        events.len()
+    );
+    for event in &events {
+        let lon = moon_sidereal_longitude_at(&engine, event.jd_tdb, &config).unwrap();
+        let diff = (lon - event.rashi_index as f64 * 30.0 + 180.0).rem_euclid(360.0) - 180.0;
+        assert!(diff.abs() < 1e-4, "{:?}: {diff}", event.rashi);
+    }
+    for pair in events.windows(2) {
+        assert_eq!(pair[1].rashi_index, (pair[0].rashi_index + 1) % 12);
+        let gap = pair[1].jd_tdb - pair[0].jd_tdb;
+        assert!(
+            (2.0..2.6).contains(&gap),
+            "gap {gap:.3} d before {:?}",
+            pair[1].rashi
+        );
+    }
+}
+
+fn festival_day(
+    engine: &Engine,
+    eop: &EopKernel,
+    year: i32,
+    masa: Masa,
+    paksha: Paksha,
+    tithi_in_paksha: u8,
+    adhika: bool,
+) -> Option<UtcTime> {
+    let loc = GeoLocation::new(28.6139, 77.2090, 0.0);
+    festival_date(
+        engine,
+        eop,
+        year,
+        masa,
+        paksha,
+        tithi_in_paksha,
+        adhika,
+        &loc,
+        &RiseSetConfig::default(),
+        &default_config(),
+    )
+    .expect("festival_date should succeed")
+}
+
+/// Prabodhini Ekadashi 2024 (Kartika Shukla 11) and Ganesh Chaturthi 2024
+/// (Bhadrapada Shukla 4) at New Delhi.
+#[test]
+fn festival_date_known_2024() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+
+    let ekadashi = festival_day(
+        &engine,
+        &eop,
+        2024,
+        Masa::Kartika,
+        Paksha::Shukla,
+        11,
+        false,
+    )
+    .expect("Kartika Shukla Ekadashi occurs in 2024");
+    assert_eq!(
+        (ekadashi.year, ekadashi.month, ekadashi.day),
+        (2024, 11, 12)
+    );
+
+    let chaturthi = festival_day(
+        &engine,
+        &eop,
+        2024,
+        Masa::Bhadrapada,
+        Paksha::Shukla,
+        4,
+        false,
+    )
+    .expect("Bhadrapada Shukla Chaturthi occurs in 2024");
+    assert_eq!(
+        (chaturthi.year, chaturthi.month, chaturthi.day),
+        (2024, 9, 7)
+    );
+
+    let tithi = tithi_for_date(&engine, &chaturthi).unwrap();
+    assert_eq!((tithi.paksha, tithi.tithi_in_paksha), (Paksha::Shukla, 4));
+}
+
+/// 2023 had adhika Shravana: Nag Panchami fell in the nija month (21 Aug),
+/// one lunation after the adhika month's Shukla Panchami.
+#[test]
+fn festival_date_adhika_flag() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+
+    let nija = festival_day(
+        &engine,
+        &eop,
+        2023,
+        Masa::Shravana,
+        Paksha::Shukla,
+        5,
+        false,
+    )
+    .expect("nija Shravana occurs in 2023");
+    assert_eq!((nija.year, nija.month, nija.day), (2023, 8, 21));
+
+    let adhika = festival_day(&engine, &eop, 2023, Masa::Shravana, Paksha::Shukla, 5, true)
+        .expect("adhika Shravana occurs in 2023");
+    let gap = nija.to_jd_tdb(engine.lsk()) - adhika.to_jd_tdb(engine.lsk());
+    assert!((28.0..=31.0).contains(&gap), "gap {gap} days");
+
+    assert!(festival_day(&engine, &eop, 2024, Masa::Shravana, Paksha::Shukla, 5, true).is_none());
+}
+
+#[test]
+fn festival_date_rejects_bad_tithi() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let loc = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let result = festival_date(
+        &engine,
+        &eop,
+        2024,
+        Masa::Chaitra,
+        Paksha::Shukla,
+        16,
+        false,
+        &loc,
+        &RiseSetConfig::default(),
+        &default_config(),
+    );
+    assert!(matches!(result, Err(SearchError::InvalidConfig(_))));
+}
+
+#[test]
+fn panchang_diff_identical_configs_is_empty() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let loc = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let utc = UtcTime::new(2024, 3, 15, 6, 0, 0.0);
+    let rs = RiseSetConfig::default();
+    let config = default_config();
+    let diff = panchang_diff(&engine, &eop, &utc, &loc, &rs, &config, &config).unwrap();
+    assert!(diff.is_empty(), "unexpected diff: {:?}", diff.limbs);
+    assert_eq!(diff.a, diff.b);
+}
+
+/// Lahiri vs Raman (~1.4 deg apart) shifts the sidereal limbs only.
+#[test]
+fn panchang_diff_ayanamsha_shifts_sidereal_limbs() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let loc = GeoLocation::new(28.6139, 77.2090, 0.0);
+    let utc = UtcTime::new(2024, 3, 15, 6, 0, 0.0);
+    let rs = RiseSetConfig::default();
+    let lahiri = default_config();
+    let raman = SankrantiConfig::new(AyanamshaSystem::Raman, false);
+    let diff = panchang_diff(&engine, &eop, &utc, &loc, &rs, &lahiri, &raman).unwrap();
+
+    let limbs: Vec<PanchangLimb> = diff.limbs.iter().map(|d| d.limb).collect();
+    assert!(limbs.contains(&PanchangLimb::Nakshatra), "{limbs:?}");
+    for limb in [
+        PanchangLimb::Tithi,
+        PanchangLimb::Karana,
+        PanchangLimb::Vaar,
+    ] {
+        assert!(
+            !limbs.contains(&limb),
+            "{limb:?} should not depend on ayanamsha"
+        );
+    }
+}
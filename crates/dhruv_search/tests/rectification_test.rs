@@ -0,0 +1,144 @@
+//! Integration tests for the birth-time rectification scan.
+//!
+//! Requires kernel files (de442s.bsp, naif0012.tls, finals2000A.all).
+//! Skips gracefully if absent.
+
+use std::path::Path;
+
+use dhruv_core::{Engine, EngineConfig};
+use dhruv_search::sankranti_types::SankrantiConfig;
+use dhruv_search::{SearchError, rectification_scan};
+use dhruv_time::{EopKernel, UtcTime};
+use dhruv_vedic_base::BhavaConfig;
+use dhruv_vedic_base::dasha::{DashaEntity, DashaSystem, DashaVariationConfig};
+use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
+
+const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
+const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
+const EOP_PATH: &str = "../../kernels/data/finals2000A.all";
+
+fn load_engine() -> Option<Engine> {
+    if !Path::new(SPK_PATH).exists() || !Path::new(LSK_PATH).exists() {
+        eprintln!("Skipping rectification_test: kernel files not found");
+        return None;
+    }
+    let config = EngineConfig::with_single_spk(SPK_PATH.into(), LSK_PATH.into(), 1024, false);
+    Engine::new(config).ok()
+}
+
+fn load_eop() -> Option<EopKernel> {
+    if !Path::new(EOP_PATH).exists() {
+        eprintln!("Skipping rectification_test: EOP file not found");
+        return None;
+    }
+    EopKernel::load(Path::new(EOP_PATH)).ok()
+}
+
+fn new_delhi() -> GeoLocation {
+    GeoLocation::new(28.6139, 77.2090, 0.0)
+}
+
+#[test]
+fn samples_span_window_and_lagna_changes_about_every_two_hours() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let utc = UtcTime::new(2024, 1, 15, 6, 0, 0.0);
+    let samples = rectification_scan(
+        &engine,
+        &eop,
+        &utc,
+        360.0,
+        5.0,
+        &new_delhi(),
+        DashaSystem::Vimshottari,
+        &BhavaConfig::default(),
+        &RiseSetConfig::default(),
+        &SankrantiConfig::default_lahiri(),
+        &DashaVariationConfig::default(),
+    )
+    .expect("rectification_scan should succeed");
+
+    assert_eq!(samples.len(), 73);
+    assert!((samples[0].offset_minutes + 180.0).abs() < 1e-9);
+    assert!((samples[72].offset_minutes - 180.0).abs() < 1e-9);
+    let nominal = samples[36].jd_tdb;
+    assert!((samples[0].jd_tdb - (nominal - 0.125)).abs() < 1e-9);
+
+    let mut change_offsets = Vec::new();
+    let mut navamsa_changes = 0;
+    for pair in samples.windows(2) {
+        if pair[1].lagna_rashi != pair[0].lagna_rashi {
+            assert_eq!(
+                pair[1].lagna_rashi.index(),
+                (pair[0].lagna_rashi.index() + 1) % 12
+            );
+            change_offsets.push(pair[1].offset_minutes);
+        }
+        if pair[1].navamsa_lagna_rashi != pair[0].navamsa_lagna_rashi {
+            navamsa_changes += 1;
+        }
+    }
+    // Six hours covers two to four lagna signs at this latitude.
+    assert!(change_offsets.len() >= 2, "changes at {change_offsets:?}");
+    for gap in change_offsets.windows(2) {
+        let minutes = gap[1] - gap[0];
+        assert!(
+            (70.0..=170.0).contains(&minutes),
+            "lagna held {minutes} min"
+        );
+    }
+    // A navamsa spans 3deg20', so the D9 lagna changes far more often.
+    assert!(navamsa_changes > 3 * change_offsets.len());
+    // Vimshottari periods are ruled by grahas.
+    assert!(
+        samples
+            .iter()
+            .all(|s| matches!(s.pratyantardasha, DashaEntity::Graha(_)))
+    );
+}
+
+#[test]
+fn rashi_based_system_reports_rashi_periods() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let samples = rectification_scan(
+        &engine,
+        &eop,
+        &UtcTime::new(2024, 1, 15, 6, 0, 0.0),
+        60.0,
+        30.0,
+        &new_delhi(),
+        DashaSystem::Chara,
+        &BhavaConfig::default(),
+        &RiseSetConfig::default(),
+        &SankrantiConfig::default_lahiri(),
+        &DashaVariationConfig::default(),
+    )
+    .expect("rectification_scan should succeed");
+    assert_eq!(samples.len(), 3);
+    assert!(
+        samples
+            .iter()
+            .all(|s| matches!(s.mahadasha, DashaEntity::Rashi(_)))
+    );
+}
+
+#[test]
+fn rejects_non_positive_step() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let result = rectification_scan(
+        &engine,
+        &eop,
+        &UtcTime::new(2024, 1, 15, 6, 0, 0.0),
+        60.0,
+        0.0,
+        &new_delhi(),
+        DashaSystem::Vimshottari,
+        &BhavaConfig::default(),
+        &RiseSetConfig::default(),
+        &SankrantiConfig::default_lahiri(),
+        &DashaVariationConfig::default(),
+    );
+    assert!(matches!(result, Err(SearchError::InvalidConfig(_))));
+}
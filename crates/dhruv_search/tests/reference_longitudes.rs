@@ -0,0 +1,132 @@
+//! Cross-check of sidereal graha longitudes against a bundled reference
+//! table (`tests/data/reference_longitudes_lahiri.csv`).
+//!
+//! The table is reduced from JPL Horizons vectors by an independent script
+//! (see the CSV header), so it guards the ephemeris → precession → ayanamsha
+//! pipeline against errors, not just regressions. Every listed longitude
+//! must match within `TOLERANCE_ARCSEC`.
+//! Requires kernel files. Skips gracefully if absent.
+
+use std::path::Path;
+
+use dhruv_core::{Engine, EngineConfig};
+use dhruv_search::{GrahaLongitudesConfig, graha_longitudes};
+use dhruv_vedic_base::{ALL_GRAHAS, AyanamshaSystem};
+
+const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
+const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
+const REFERENCE_PATH: &str = "tests/data/reference_longitudes_lahiri.csv";
+
+/// Maximum allowed difference from the reference, in arcseconds.
+const TOLERANCE_ARCSEC: f64 = 1.0;
+
+fn load_engine() -> Option<Engine> {
+    if !Path::new(SPK_PATH).exists() || !Path::new(LSK_PATH).exists() {
+        eprintln!("Skipping reference_longitudes: kernel files not found");
+        return None;
+    }
+    let config = EngineConfig::with_single_spk(SPK_PATH.into(), LSK_PATH.into(), 1024, false);
+    Engine::new(config).ok()
+}
+
+fn config() -> GrahaLongitudesConfig {
+    GrahaLongitudesConfig::sidereal(AyanamshaSystem::Lahiri, false)
+}
+
+/// One table row: epoch and the nine longitudes in `ALL_GRAHAS` order.
+/// `None` marks a graha the source has no vector for at this epoch.
+struct ReferenceRow {
+    jd_tdb: f64,
+    longitudes: [Option<f64>; 9],
+}
+
+fn parse_field(i: usize, field: &str) -> f64 {
+    field
+        .parse()
+        .unwrap_or_else(|e| panic!("row {i}: bad field {field:?}: {e}"))
+}
+
+/// Parse the reference CSV, skipping `#` comments, blank lines, and the
+/// column header. Empty longitude cells parse as `None`.
+fn parse_reference(text: &str) -> Vec<ReferenceRow> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with("jd_tdb"))
+        .enumerate()
+        .map(|(i, line)| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            assert_eq!(fields.len(), 10, "row {i}: expected 10 fields");
+            let mut longitudes = [None; 9];
+            for (slot, field) in longitudes.iter_mut().zip(&fields[1..]) {
+                *slot = (!field.is_empty()).then(|| parse_field(i, field));
+            }
+            ReferenceRow {
+                jd_tdb: parse_field(i, fields[0]),
+                longitudes,
+            }
+        })
+        .collect()
+}
+
+/// Smallest angular distance between two longitudes, in arcseconds.
+fn separation_arcsec(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d) * 3600.0
+}
+
+#[test]
+fn separation_wraps_at_zero() {
+    assert!((separation_arcsec(359.9999, 0.0001) - 0.72).abs() < 1e-6);
+    assert_eq!(separation_arcsec(10.0, 10.0), 0.0);
+}
+
+#[test]
+fn parser_skips_comments_and_header() {
+    let rows = parse_reference(
+        "# note\njd_tdb,a,b,c,d,e,f,g,h,i\n\n1.5,1,2,3,4,5,6,7,8,9\n2.5,,2,,,,,,8,9\n",
+    );
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].jd_tdb, 1.5);
+    assert_eq!(rows[0].longitudes[8], Some(9.0));
+    assert_eq!(rows[1].longitudes[0], None);
+    assert_eq!(rows[1].longitudes[1], Some(2.0));
+}
+
+#[test]
+fn reference_table_is_populated() {
+    let text = std::fs::read_to_string(REFERENCE_PATH).expect("read reference table");
+    let rows = parse_reference(&text);
+    assert!(!rows.is_empty(), "reference table has no rows");
+    assert!(
+        rows.iter()
+            .any(|row| row.longitudes.iter().all(Option::is_some)),
+        "reference table has no complete row"
+    );
+}
+
+#[test]
+fn graha_longitudes_match_reference_table() {
+    let Some(engine) = load_engine() else { return };
+
+    let text = std::fs::read_to_string(REFERENCE_PATH).expect("read reference table");
+    let rows = parse_reference(&text);
+
+    let mut failures = Vec::new();
+    for row in &rows {
+        let lons = graha_longitudes(&engine, row.jd_tdb, &config()).expect("graha_longitudes");
+        for graha in ALL_GRAHAS {
+            let i = graha.index() as usize;
+            let Some(reference) = row.longitudes[i] else {
+                continue;
+            };
+            let diff = separation_arcsec(lons.longitudes[i], reference);
+            if diff > TOLERANCE_ARCSEC {
+                failures.push(format!(
+                    "jd {} {graha:?}: got {:.7}, reference {reference:.7} ({diff:.3}\")",
+                    row.jd_tdb, lons.longitudes[i]
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
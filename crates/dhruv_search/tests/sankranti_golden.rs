@@ -5,11 +5,11 @@
 
 use std::path::Path;
 
-use dhruv_core::{Engine, EngineConfig};
+use dhruv_core::{Body, Engine, EngineConfig};
 use dhruv_search::sankranti_types::SankrantiConfig;
 use dhruv_search::{
-    next_sankranti, next_specific_sankranti, prev_sankranti, prev_specific_sankranti,
-    search_sankrantis,
+    next_nakshatra_ingress, next_sankranti, next_specific_sankranti, prev_sankranti,
+    prev_specific_sankranti, search_sankrantis, sun_sign_ingresses_for_year,
 };
 use dhruv_time::UtcTime;
 use dhruv_vedic_base::Rashi;
@@ -152,3 +152,81 @@ fn prev_karka_sankranti_from_aug_2024() {
     assert_eq!(event.utc.month, 7);
     assert_eq!(event.rashi, Rashi::Karka);
 }
+
+/// Monthly sankranti calendar 2024: twelve ordered ingresses, each into the
+/// sign after the previous one, starting with Makara in mid-January.
+#[test]
+fn sun_sign_ingresses_2024() {
+    let Some(engine) = load_engine() else { return };
+    let events = sun_sign_ingresses_for_year(&engine, 2024, &default_config()).unwrap();
+    assert_eq!(events[0].rashi, Rashi::Makara);
+    assert_eq!((events[0].utc.year, events[0].utc.month), (2024, 1));
+    for pair in events.windows(2) {
+        let jd0 = pair[0].utc.to_jd_tdb(engine.lsk());
+        let jd1 = pair[1].utc.to_jd_tdb(engine.lsk());
+        assert!(
+            jd1 > jd0,
+            "events out of order: {} then {}",
+            pair[0].utc,
+            pair[1].utc
+        );
+        assert_eq!(pair[1].rashi_index, (pair[0].rashi_index + 1) % 12);
+    }
+    for event in &events {
+        assert_eq!(event.utc.year, 2024);
+        let boundary = event.rashi_index as f64 * 30.0;
+        let diff = (event.sun_sidereal_longitude_deg - boundary + 180.0).rem_euclid(360.0) - 180.0;
+        assert!(diff.abs() < 0.01, "{:?}: {:.4}", event.rashi, diff);
+    }
+}
+
+/// A custom anchor pinned to Lahiri's 1956 value reproduces Lahiri sankrantis.
+#[test]
+fn custom_anchor_matches_lahiri_sankranti() {
+    let Some(engine) = load_engine() else { return };
+    let anchor_jd = 2_435_553.5;
+    let anchor_deg = dhruv_vedic_base::ayanamsha_mean_deg(
+        dhruv_vedic_base::AyanamshaSystem::Lahiri,
+        dhruv_vedic_base::jd_tdb_to_centuries(anchor_jd),
+    );
+    let custom = default_config().with_custom_ayanamsha(dhruv_vedic_base::CustomAyanamsha::new(
+        anchor_deg, anchor_jd,
+    ));
+    let utc = UtcTime::new(2024, 1, 1, 0, 0, 0.0);
+    let lahiri = next_specific_sankranti(&engine, &utc, Rashi::Makara, &default_config())
+        .unwrap()
+        .expect("Lahiri Makar Sankranti");
+    let event = next_specific_sankranti(&engine, &utc, Rashi::Makara, &custom)
+        .unwrap()
+        .expect("custom Makar Sankranti");
+    let diff = (event.sun_tropical_longitude_deg - lahiri.sun_tropical_longitude_deg).abs();
+    assert!(diff < 1e-5, "custom vs Lahiri Sun differ by {diff} deg");
+}
+
+/// The Moon changes nakshatra about once a day (13°20' at 11.8-15.4 deg/day).
+#[test]
+fn consecutive_moon_nakshatra_ingresses_about_a_day_apart() {
+    let Some(engine) = load_engine() else { return };
+    let config = default_config();
+    let mut jd = 2_460_310.5; // 2024-01-01 00:00 TDB
+    let mut prev: Option<(f64, u8)> = None;
+    for _ in 0..6 {
+        let event = next_nakshatra_ingress(&engine, Body::Moon, jd, &config)
+            .unwrap()
+            .expect("Moon should change nakshatra within two days");
+        assert_eq!(event.body, Body::Moon);
+        assert!(!event.retrograde);
+        let expected_boundary = event.nakshatra_index as f64 * (360.0 / 27.0);
+        assert!((event.boundary_deg - expected_boundary).abs() < 1e-9);
+        if let Some((prev_jd, prev_index)) = prev {
+            assert_eq!(event.nakshatra_index, (prev_index + 1) % 27);
+            let gap = event.jd_tdb - prev_jd;
+            assert!(
+                (0.8..1.25).contains(&gap),
+                "ingress gap {gap:.3} days, expected ~1 day"
+            );
+        }
+        prev = Some((event.jd_tdb, event.nakshatra_index));
+        jd = event.jd_tdb + 1e-3;
+    }
+}
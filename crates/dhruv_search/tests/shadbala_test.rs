@@ -8,11 +8,14 @@ use dhruv_core::{Engine, EngineConfig};
 use dhruv_search::sankranti_types::SankrantiConfig;
 use dhruv_search::{
     AmshaSelectionConfig, FullKundaliConfig, GrahaPositionsConfig, balas_for_date,
-    bhavabala_for_bhava, bhavabala_for_date, shadbala_for_date, shadbala_for_graha,
-    vimsopaka_for_date, vimsopaka_for_graha,
+    bhavabala_for_bhava, bhavabala_for_date, kala_bala_inputs_for_date, shadbala_for_date,
+    shadbala_for_graha, vimsopaka_for_date, vimsopaka_for_graha,
 };
 use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig};
-use dhruv_vedic_base::{BhavaConfig, ChandraBeneficRule, Graha, NodeDignityPolicy, cheshta_bala};
+use dhruv_vedic_base::{
+    BhavaConfig, ChandraBeneficRule, Graha, NodeDignityPolicy, cheshta_bala, hora_lord, kala_bala,
+    vaar_lord,
+};
 
 use dhruv_time::{EopKernel, UtcTime};
 
@@ -392,6 +395,84 @@ fn shadbala_chandra_benefic_rule_is_configurable() {
     );
 }
 
+#[test]
+fn shadbala_vara_and_hora_follow_real_sunrise() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let bhava_config = BhavaConfig::default();
+    let rs_config = RiseSetConfig::default();
+    let aya_config = default_aya_config();
+
+    // New York, 04:00 EST (09:00 UTC): before local sunrise, so the Vedic
+    // day (and its vaar/hora lords) still belong to the previous civil day
+    // even though the UTC date has already rolled over.
+    let new_york = GeoLocation::new(40.7128, -74.0060, 0.0);
+    let cases = [
+        (UtcTime::new(2024, 1, 15, 9, 0, 0.0), new_york, false),
+        (UtcTime::new(2024, 1, 15, 18, 0, 0.0), new_york, true),
+        (UtcTime::new(2024, 1, 15, 14, 0, 0.0), new_delhi(), false),
+        (UtcTime::new(2024, 1, 15, 6, 30, 0.0), new_delhi(), true),
+    ];
+
+    for (utc, location, expect_day) in cases {
+        let result = shadbala_for_date(
+            &engine,
+            &eop,
+            &utc,
+            &location,
+            &bhava_config,
+            &rs_config,
+            &aya_config,
+            &default_amsha_selection(),
+        )
+        .expect("shadbala_for_date should succeed");
+
+        let vaar = dhruv_search::vaar_for_date(&engine, &eop, &utc, &location, &rs_config)
+            .expect("vaar_for_date should succeed");
+        let hora = dhruv_search::hora_for_date(&engine, &eop, &utc, &location, &rs_config)
+            .expect("hora_for_date should succeed");
+        let weekday_lord = vaar_lord(vaar.vaar);
+        let prevailing_hora_lord = hora_lord(vaar.vaar, hora.hora_index);
+
+        for entry in &result.entries {
+            let expected_vara = if entry.graha == weekday_lord {
+                45.0
+            } else {
+                0.0
+            };
+            let expected_hora = if entry.graha == prevailing_hora_lord {
+                60.0
+            } else {
+                0.0
+            };
+            assert_eq!(
+                entry.kala.vara, expected_vara,
+                "vara {:?} at {utc}",
+                entry.graha
+            );
+            assert_eq!(
+                entry.kala.hora, expected_hora,
+                "hora {:?} at {utc}",
+                entry.graha
+            );
+        }
+
+        // Tribhaga lords must come from the matching half of the Vedic day.
+        let day_lords = [Graha::Buddh, Graha::Surya, Graha::Shani];
+        let day_tribhaga: f64 = result
+            .entries
+            .iter()
+            .filter(|e| day_lords.contains(&e.graha))
+            .map(|e| e.kala.tribhaga)
+            .sum();
+        if expect_day {
+            assert_eq!(day_tribhaga, 60.0, "day tribhaga lord at {utc}");
+        } else {
+            assert_eq!(day_tribhaga, 0.0, "night tribhaga lord at {utc}");
+        }
+    }
+}
+
 #[test]
 fn shadbala_naisargika_matches_constants() {
     let Some(engine) = load_engine() else { return };
@@ -1208,3 +1289,54 @@ fn full_kundali_uses_same_node_aspect_config_for_shadbala_and_bhavabala() {
         "expected shared config to affect full-kundali Bhava Bala Drishti"
     );
 }
+
+/// New Delhi, 2024-03-20 (sunrise ~00:55 UTC, sunset ~13:00 UTC): 07:00 UTC
+/// is the middle third of the day (Surya) and 18:00 UTC the middle third of
+/// the night (Shukra).
+#[test]
+fn kala_bala_inputs_tribhaga_lord_follows_sunrise_thirds() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+    let location = new_delhi();
+    let rs_config = RiseSetConfig::default();
+    let aya_config = default_aya_config();
+    let cases = [
+        (UtcTime::new(2024, 3, 20, 7, 0, 0.0), true, Graha::Surya),
+        (UtcTime::new(2024, 3, 20, 18, 0, 0.0), false, Graha::Shukra),
+    ];
+
+    for (utc, expect_day, expected_lord) in cases {
+        let inputs =
+            kala_bala_inputs_for_date(&engine, &eop, &utc, &location, &rs_config, &aya_config)
+                .expect("kala_bala_inputs_for_date should succeed");
+        assert_eq!(inputs.is_daytime, expect_day, "day/night at {utc}");
+        assert!(
+            (1.0 / 3.0..2.0 / 3.0).contains(&inputs.day_night_fraction),
+            "fraction {} at {utc}",
+            inputs.day_night_fraction
+        );
+        for graha in [
+            Graha::Surya,
+            Graha::Chandra,
+            Graha::Mangal,
+            Graha::Buddh,
+            Graha::Shukra,
+            Graha::Shani,
+        ] {
+            let expected = if graha == expected_lord { 60.0 } else { 0.0 };
+            assert_eq!(
+                kala_bala(graha, &inputs).tribhaga,
+                expected,
+                "{graha:?} at {utc}"
+            );
+        }
+        assert_eq!(kala_bala(Graha::Guru, &inputs).tribhaga, 60.0);
+
+        let vaar = dhruv_search::vaar_for_date(&engine, &eop, &utc, &location, &rs_config)
+            .expect("vaar_for_date should succeed");
+        let hora = dhruv_search::hora_for_date(&engine, &eop, &utc, &location, &rs_config)
+            .expect("hora_for_date should succeed");
+        assert_eq!(inputs.weekday_lord, vaar_lord(vaar.vaar));
+        assert_eq!(inputs.hora_lord, hora_lord(vaar.vaar, hora.hora_index));
+    }
+}
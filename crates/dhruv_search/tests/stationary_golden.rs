@@ -7,8 +7,8 @@ use std::path::Path;
 
 use dhruv_core::{Body, Engine, EngineConfig};
 use dhruv_search::{
-    MaxSpeedType, SearchError, StationType, StationaryConfig, next_max_speed, next_stationary,
-    prev_stationary, search_stationary,
+    MaxSpeedType, SearchError, StationType, StationaryConfig, next_max_speed, next_node_station,
+    next_stationary, prev_stationary, retrograde_intervals, search_stationary,
 };
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
@@ -257,3 +257,64 @@ fn earth_rejected_for_max_speed() {
     let result = next_max_speed(&engine, Body::Earth, jd_from_date(2024, 1, 1.0), &config);
     assert!(matches!(result, Err(SearchError::InvalidConfig(_))));
 }
+
+/// True node stations bracket a direct phase: speed flips sign at each one.
+#[test]
+fn true_node_station_flips_speed() {
+    let Some(engine) = load_engine() else { return };
+    let jd_start = jd_from_date(2024, 1, 1.0);
+    let first = next_node_station(&engine, jd_start)
+        .expect("search should succeed")
+        .expect("should find a node station");
+    assert!(first.jd_tdb > jd_start);
+    assert!(first.rahu_longitude_deg >= 0.0 && first.rahu_longitude_deg < 360.0);
+
+    let second = next_node_station(&engine, first.jd_tdb + 1e-3)
+        .expect("search should succeed")
+        .expect("should find a following node station");
+    assert_ne!(first.station_type, second.station_type);
+}
+
+/// Mars retrograde 2024-Dec-06 to 2025-Feb-24 is the only one between
+/// mid-2024 and mid-2026 (the next begins January 2027).
+#[test]
+fn mars_one_retrograde_interval_in_two_years() {
+    let Some(engine) = load_engine() else { return };
+    let config = StationaryConfig::inner_planet();
+    let intervals = retrograde_intervals(
+        &engine,
+        Body::Mars,
+        jd_from_date(2024, 6, 1.0),
+        jd_from_date(2026, 6, 1.0),
+        &config,
+    )
+    .expect("search should succeed");
+    assert_eq!(intervals.len(), 1, "got {intervals:?}");
+
+    let iv = intervals[0];
+    assert!(!iv.open_start && !iv.open_end);
+    assert!((iv.start_jd - jd_from_date(2024, 12, 6.0)).abs() < 3.0);
+    assert!((iv.end_jd - jd_from_date(2025, 2, 24.0)).abs() < 3.0);
+    // Mars backs up from ~6 deg Leo to ~17 deg Cancer (tropical).
+    assert!(iv.end_lon_deg < iv.start_lon_deg);
+    assert!((iv.start_lon_deg - iv.end_lon_deg) > 15.0);
+}
+
+/// A window starting mid-retrograde yields an open-left interval.
+#[test]
+fn mars_retrograde_in_progress_at_start() {
+    let Some(engine) = load_engine() else { return };
+    let config = StationaryConfig::inner_planet();
+    let jd_start = jd_from_date(2025, 1, 1.0);
+    let intervals = retrograde_intervals(
+        &engine,
+        Body::Mars,
+        jd_start,
+        jd_from_date(2025, 6, 1.0),
+        &config,
+    )
+    .expect("search should succeed");
+    assert_eq!(intervals.len(), 1);
+    assert!(intervals[0].open_start && !intervals[0].open_end);
+    assert_eq!(intervals[0].start_jd, jd_start);
+}
@@ -0,0 +1,92 @@
+//! Integration tests for daily transit alerts.
+//!
+//! Requires kernel files (de442s.bsp, naif0012.tls). Skips gracefully if absent.
+
+use std::path::Path;
+
+use dhruv_core::{Engine, EngineConfig};
+use dhruv_search::{GrahaLongitudesConfig, SearchError, daily_transit_alerts, graha_longitudes};
+use dhruv_time::UtcTime;
+use dhruv_vedic_base::{AyanamshaSystem, Graha};
+
+const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
+const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
+
+fn load_engine() -> Option<Engine> {
+    if !Path::new(SPK_PATH).exists() || !Path::new(LSK_PATH).exists() {
+        eprintln!("Skipping transit_alert_test: kernel files not found");
+        return None;
+    }
+    let config = EngineConfig::with_single_spk(SPK_PATH.into(), LSK_PATH.into(), 1024, false);
+    Engine::new(config).ok()
+}
+
+fn config() -> GrahaLongitudesConfig {
+    GrahaLongitudesConfig::sidereal(AyanamshaSystem::Lahiri, false)
+}
+
+/// A natal point placed at the Sun's 15:00 UTC longitude is hit by the
+/// transiting Sun's conjunction at 15:00 that day, and not a week later.
+#[test]
+fn sun_conjunction_flagged_at_exact_time() {
+    let Some(engine) = load_engine() else { return };
+    let exact = UtcTime::new(2024, 3, 20, 15, 0, 0.0);
+    let exact_jd = exact.to_jd_tdb(engine.lsk());
+    let sun = graha_longitudes(&engine, exact_jd, &config())
+        .unwrap()
+        .longitude(Graha::Surya);
+    let natal = vec![("Natal Sun".to_string(), sun)];
+
+    let alerts = daily_transit_alerts(&engine, &natal, &exact, 1.0, &[0.0, 180.0], &config())
+        .expect("alert scan should succeed");
+    let hit = alerts
+        .iter()
+        .find(|a| a.graha == Graha::Surya)
+        .expect("Sun conjunction should be flagged");
+    assert_eq!(hit.natal_point, "Natal Sun");
+    assert_eq!(hit.aspect_deg, 0.0);
+    let jd = hit.exact_jd_tdb.expect("conjunction is exact that day");
+    assert!((jd - exact_jd).abs() < 1e-5, "exact {jd} vs {exact_jd}");
+    assert!(hit.min_orb_deg.abs() < 1e-12);
+
+    let later = UtcTime::new(2024, 3, 27, 0, 0, 0.0);
+    let alerts = daily_transit_alerts(&engine, &natal, &later, 1.0, &[0.0], &config()).unwrap();
+    assert!(alerts.iter().all(|a| a.graha != Graha::Surya));
+}
+
+/// Exactness for the Moon and Ketu lands where `graha_longitudes` puts them,
+/// so the single-body refinement shares its frame (Ketu via the Rahu node).
+#[test]
+fn moon_and_ketu_exact_times_match_graha_longitudes() {
+    let Some(engine) = load_engine() else { return };
+    let exact = UtcTime::new(2024, 3, 20, 10, 0, 0.0);
+    let exact_jd = exact.to_jd_tdb(engine.lsk());
+    let lons = graha_longitudes(&engine, exact_jd, &config()).unwrap();
+    for graha in [Graha::Chandra, Graha::Ketu] {
+        let natal = vec![("Natal".to_string(), lons.longitude(graha))];
+        let alerts = daily_transit_alerts(&engine, &natal, &exact, 0.5, &[0.0], &config()).unwrap();
+        let hit = alerts
+            .iter()
+            .find(|a| a.graha == graha)
+            .unwrap_or_else(|| panic!("{graha:?} conjunction should be flagged"));
+        let jd = hit.exact_jd_tdb.expect("conjunction is exact that day");
+        assert!(
+            (jd - exact_jd).abs() < 1e-5,
+            "{graha:?}: {jd} vs {exact_jd}"
+        );
+    }
+}
+
+#[test]
+fn rejects_negative_orb() {
+    let Some(engine) = load_engine() else { return };
+    let result = daily_transit_alerts(
+        &engine,
+        &[("Lagna".to_string(), 10.0)],
+        &UtcTime::new(2024, 3, 20, 0, 0, 0.0),
+        -1.0,
+        &[0.0],
+        &config(),
+    );
+    assert!(matches!(result, Err(SearchError::InvalidConfig(_))));
+}
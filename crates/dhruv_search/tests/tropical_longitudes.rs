@@ -5,8 +5,15 @@
 use std::path::Path;
 
 use dhruv_core::{Engine, EngineConfig};
-use dhruv_search::{GrahaLongitudesConfig, graha_longitudes, moving_osculating_apogees};
-use dhruv_vedic_base::{ALL_GRAHAS, AyanamshaSystem, Graha, ayanamsha_deg, jd_tdb_to_centuries};
+use dhruv_frames::ReferencePlane;
+use dhruv_search::{
+    GrahaLongitudesConfig, body_lon_lat_on_plane, graha_longitudes, moving_osculating_apogees,
+};
+use dhruv_vedic_base::ayanamsha::ayanamsha_deg_on_plane;
+use dhruv_vedic_base::{
+    ALL_GRAHAS, AyanamshaSystem, Graha, GrahaBodyExt, LunarNode, NodeMode, SAPTA_GRAHAS,
+    ayanamsha_deg, jd_tdb_to_centuries, ketu_from_rahu, lunar_node_deg_for_epoch,
+};
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
 const LSK_PATH: &str = "../../kernels/data/naif0012.tls";
@@ -158,3 +165,63 @@ fn graha_longitudes_outer_planets_can_be_disabled_in_rust_config() {
 
     assert!(result.outer_planets.is_none());
 }
+
+#[test]
+fn ketu_is_exactly_rahu_plus_180_across_configs() {
+    let Some(engine) = load_engine() else { return };
+    let configs = [
+        GrahaLongitudesConfig::sidereal(AyanamshaSystem::Lahiri, false),
+        GrahaLongitudesConfig::sidereal(AyanamshaSystem::Lahiri, true),
+        GrahaLongitudesConfig::sidereal(AyanamshaSystem::Raman, true),
+        GrahaLongitudesConfig::sidereal(AyanamshaSystem::Jagganatha, false),
+        GrahaLongitudesConfig::tropical(false),
+        GrahaLongitudesConfig::tropical(true),
+    ];
+    for jd_tdb in [2_415_020.5, 2_451_545.0, 2_460_000.25, 2_469_807.5] {
+        for config in &configs {
+            let lons = graha_longitudes(&engine, jd_tdb, config)
+                .expect("graha_longitudes should succeed")
+                .longitudes;
+            let rahu = lons[Graha::Rahu.index() as usize];
+            let ketu = lons[Graha::Ketu.index() as usize];
+            assert_eq!(ketu, ketu_from_rahu(rahu), "jd {jd_tdb}, {config:?}");
+        }
+        for mode in [NodeMode::Mean, NodeMode::True] {
+            let rahu = lunar_node_deg_for_epoch(&engine, LunarNode::Rahu, jd_tdb, mode).unwrap();
+            let ketu = lunar_node_deg_for_epoch(&engine, LunarNode::Ketu, jd_tdb, mode).unwrap();
+            assert_eq!(ketu, ketu_from_rahu(rahu), "jd {jd_tdb}, {mode:?}");
+        }
+    }
+}
+
+#[test]
+fn batched_graha_longitudes_match_per_body_queries() {
+    let engine = match load_engine() {
+        Some(e) => e,
+        None => return,
+    };
+    for plane in [ReferencePlane::Ecliptic, ReferencePlane::Invariable] {
+        let jd_tdb = 2_460_400.5;
+        let config = GrahaLongitudesConfig {
+            reference_plane: plane,
+            ..GrahaLongitudesConfig::sidereal(AyanamshaSystem::Lahiri, true)
+        };
+        let batched = graha_longitudes(&engine, jd_tdb, &config).expect("should succeed");
+        let aya = ayanamsha_deg_on_plane(
+            config.ayanamsha_system,
+            jd_tdb_to_centuries(jd_tdb),
+            config.use_nutation,
+            config.precession_model,
+            plane,
+        );
+        for graha in SAPTA_GRAHAS {
+            let body = graha.to_body().expect("sapta graha has body");
+            let (lon, _lat) =
+                body_lon_lat_on_plane(&engine, body, jd_tdb, config.precession_model, plane)
+                    .expect("should succeed");
+            let sequential = (lon - aya).rem_euclid(360.0);
+            let diff = (batched.longitudes[graha.index() as usize] - sequential).abs();
+            assert!(diff < 1e-10, "{graha:?} on {plane:?}: diff = {diff}");
+        }
+    }
+}
@@ -7,13 +7,13 @@ use std::path::Path;
 
 use dhruv_core::{Engine, EngineConfig};
 use dhruv_search::sankranti_types::SankrantiConfig;
-use dhruv_search::{all_upagrahas_for_date, vedic_day_sunrises};
+use dhruv_search::{all_upagrahas_for_date, sphuta_inputs_for_date, vedic_day_sunrises};
 use dhruv_time::{EopKernel, UtcTime, calendar_to_jd, jd_to_tdb_seconds, tdb_seconds_to_jd};
 use dhruv_vedic_base::{
     GeoLocation, GulikaMaandiPlanet, RiseSetConfig, RiseSetEvent, RiseSetResult,
     TimeUpagrahaConfig, TimeUpagrahaPoint, Upagraha, approximate_local_noon_jd, compute_rise_set,
     jd_tdb_to_centuries, lagna_longitude_rad, time_upagraha_jd, time_upagraha_jd_with_config,
-    utc_day_start_jd, vaar_from_jd,
+    trisphuta, utc_day_start_jd, vaar_from_jd,
 };
 
 const SPK_PATH: &str = "../../kernels/data/de442s.bsp";
@@ -116,6 +116,44 @@ fn time_based_upagrahas_honor_custom_period_selection() {
     }
 }
 
+#[test]
+fn gulika_point_conventions_give_distinct_longitudes() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+
+    let utc = UtcTime::new(2026, 3, 17, 9, 0, 0.0);
+    let location = bengaluru();
+    let riseset_config = RiseSetConfig::default();
+    let aya_config = SankrantiConfig::default_lahiri();
+
+    let gulika = |point: TimeUpagrahaPoint| {
+        let config = TimeUpagrahaConfig {
+            gulika_point: point,
+            ..TimeUpagrahaConfig::default()
+        };
+        dhruv_search::all_upagrahas_for_date_with_config(
+            &engine,
+            &eop,
+            &utc,
+            &location,
+            &riseset_config,
+            &aya_config,
+            &config,
+        )
+        .expect("all_upagrahas_for_date_with_config should succeed")
+        .gulika
+    };
+
+    let start = gulika(TimeUpagrahaPoint::Start);
+    let middle = gulika(TimeUpagrahaPoint::Middle);
+    let end = gulika(TimeUpagrahaPoint::End);
+    let sep = |a: f64, b: f64| (a - b).rem_euclid(360.0).min((b - a).rem_euclid(360.0));
+    // A portion lasts ~1.5 h, during which the lagna moves well over 10 deg.
+    assert!(sep(start, middle) > 5.0, "start={start}, middle={middle}");
+    assert!(sep(middle, end) > 5.0, "middle={middle}, end={end}");
+    assert!(sep(start, end) > 10.0, "start={start}, end={end}");
+}
+
 fn load_eop() -> Option<EopKernel> {
     if !Path::new(EOP_PATH).exists() {
         eprintln!("Skipping upagraha_regression: EOP file not found");
@@ -225,3 +263,43 @@ fn time_based_upagrahas_match_manual_night_portions_after_noon_utc() {
         );
     }
 }
+
+#[test]
+fn sphuta_inputs_carry_computed_gulika() {
+    let Some(engine) = load_engine() else { return };
+    let Some(eop) = load_eop() else { return };
+
+    let utc = UtcTime::new(2026, 3, 17, 15, 6, 19.0);
+    let location = bengaluru();
+    let aya_config = SankrantiConfig::default_lahiri();
+
+    let inputs = sphuta_inputs_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &RiseSetConfig::default(),
+        &aya_config,
+        &TimeUpagrahaConfig::default(),
+    )
+    .expect("sphuta_inputs_for_date should succeed");
+    let upagrahas = all_upagrahas_for_date(
+        &engine,
+        &eop,
+        &utc,
+        &location,
+        &RiseSetConfig::default(),
+        &aya_config,
+    )
+    .expect("all_upagrahas_for_date should succeed");
+
+    assert!((0.0..360.0).contains(&inputs.gulika.deg()));
+    assert!((inputs.gulika.deg() - upagrahas.gulika).abs() < 1e-9);
+
+    let with_gulika = trisphuta(inputs.lagna.deg(), inputs.moon.deg(), inputs.gulika.deg());
+    let placeholder = trisphuta(inputs.lagna.deg(), inputs.moon.deg(), 0.0);
+    assert!(
+        (with_gulika - placeholder).abs() > 1e-6,
+        "TriSphuta should depend on the computed Gulika"
+    );
+}
@@ -19,6 +19,8 @@ pub enum TimeError {
     Pre1972Utc,
     /// UTC calendar instant is invalid.
     InvalidUtc(String),
+    /// Textual epoch could not be parsed.
+    EpochParse(String),
 }
 
 impl Display for TimeError {
@@ -30,6 +32,7 @@ impl Display for TimeError {
             Self::Io(msg) => write!(f, "I/O error: {msg}"),
             Self::Pre1972Utc => write!(f, "UTC before 1972-Jan-01 is not supported"),
             Self::InvalidUtc(msg) => write!(f, "invalid UTC instant: {msg}"),
+            Self::EpochParse(msg) => write!(f, "epoch parse error: {msg}"),
         }
     }
 }
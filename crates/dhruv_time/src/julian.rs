@@ -2,6 +2,10 @@
 //!
 //! Algorithms from Meeus, "Astronomical Algorithms" (2nd ed.), chapter 7.
 //! Implementation is original.
+//!
+//! Years use astronomical numbering throughout: year 0 is 1 BCE, year −1 is
+//! 2 BCE, and so on. Year 0 is a leap year in the proleptic Gregorian (and
+//! Julian) calendar.
 
 /// J2000.0 epoch as Julian Date (2000-Jan-01 12:00:00 TDB).
 pub const J2000_JD: f64 = 2_451_545.0;
@@ -14,8 +18,11 @@ pub const SECONDS_PER_DAY: f64 = 86_400.0;
 pub enum CalendarPolicy {
     /// Extend Gregorian calendar rules to all epochs.
     ProlepticGregorian,
-    /// Use historical Gregorian cutover (1582-10-15).
+    /// Use historical Gregorian cutover (1582-10-15): Julian calendar for
+    /// earlier dates, Gregorian from the cutover onwards.
     GregorianCutover1582,
+    /// Extend Julian calendar rules to all epochs.
+    ProlepticJulian,
 }
 
 impl Default for CalendarPolicy {
@@ -67,8 +74,8 @@ impl TwoPartJd {
 
 /// Convert a Gregorian calendar date to Julian Date.
 ///
-/// `day` may be fractional (e.g. 1.5 = noon on the 1st).
-/// Valid for dates after 1582-Oct-15 (Gregorian calendar adoption).
+/// `day` may be fractional (e.g. 1.5 = noon on the 1st). `year` is
+/// astronomical (0 = 1 BCE); the Gregorian rules are applied proleptically.
 pub fn calendar_to_jd(year: i32, month: u32, day: f64) -> f64 {
     calendar_to_jd_with_policy(year, month, day, CalendarPolicy::default())
 }
@@ -81,16 +88,22 @@ pub fn calendar_to_jd_with_policy(year: i32, month: u32, day: f64, policy: Calen
         (year, month)
     };
 
-    let a = y / 100;
+    // Floor division so century rules hold for negative years.
+    let a = y.div_euclid(100);
     let b = match policy {
-        CalendarPolicy::ProlepticGregorian => 2 - a + a / 4,
+        CalendarPolicy::ProlepticGregorian => 2 - a + a.div_euclid(4),
         CalendarPolicy::GregorianCutover1582 => {
             // Julian calendar before 1582-10-15.
             let is_gregorian = year > 1582
                 || (year == 1582 && month > 10)
                 || (year == 1582 && month == 10 && day >= 15.0);
-            if is_gregorian { 2 - a + a / 4 } else { 0 }
+            if is_gregorian {
+                2 - a + a.div_euclid(4)
+            } else {
+                0
+            }
         }
+        CalendarPolicy::ProlepticJulian => 0,
     };
 
     let jd_int = (365.25 * (y as f64 + 4716.0)).floor();
@@ -101,7 +114,8 @@ pub fn calendar_to_jd_with_policy(year: i32, month: u32, day: f64, policy: Calen
 
 /// Convert a Julian Date to Gregorian calendar date.
 ///
-/// Returns `(year, month, day)` where `day` is fractional.
+/// Returns `(year, month, day)` where `day` is fractional and `year` is
+/// astronomical (0 = 1 BCE).
 pub fn jd_to_calendar(jd: f64) -> (i32, u32, f64) {
     jd_to_calendar_with_policy(jd, CalendarPolicy::default())
 }
@@ -115,16 +129,17 @@ pub fn jd_to_calendar_with_policy(jd: f64, policy: CalendarPolicy) -> (i32, u32,
     let a = match policy {
         CalendarPolicy::ProlepticGregorian => {
             let alpha = ((z as f64 - 1_867_216.25) / 36_524.25).floor() as i64;
-            z + 1 + alpha - alpha / 4
+            z + 1 + alpha - alpha.div_euclid(4)
         }
         CalendarPolicy::GregorianCutover1582 => {
             if z < 2_299_161 {
                 z
             } else {
                 let alpha = ((z as f64 - 1_867_216.25) / 36_524.25).floor() as i64;
-                z + 1 + alpha - alpha / 4
+                z + 1 + alpha - alpha.div_euclid(4)
             }
         }
+        CalendarPolicy::ProlepticJulian => z,
     };
 
     let b = a + 1524;
@@ -241,4 +256,82 @@ mod tests {
         let p = TwoPartJd::from_jd(jd);
         assert!((p.to_jd() - jd).abs() < 1e-12);
     }
+
+    #[test]
+    fn astronomical_year_numbering_near_epoch() {
+        // 1 CE, 1 BCE (year 0), and 2 BCE (year -1), proleptic Gregorian.
+        let cases = [
+            (1, 1, 1.0, 1_721_425.5),
+            (0, 1, 1.0, 1_721_059.5),
+            (-1, 1, 1.0, 1_720_694.5),
+            (0, 12, 31.0, 1_721_424.5),
+            (0, 2, 29.0, 1_721_118.5),
+        ];
+        for (y, m, d, expected) in cases {
+            let jd = calendar_to_jd(y, m, d);
+            assert!((jd - expected).abs() < EPS, "({y}, {m}, {d}): got {jd}");
+            assert_eq!(jd_to_calendar(jd), (y, m, d), "roundtrip ({y}, {m}, {d})");
+        }
+    }
+
+    #[test]
+    fn negative_century_years_follow_gregorian_rule() {
+        // -100 is a common year, -400 a leap year.
+        let mar_1 = |y| calendar_to_jd(y, 3, 1.0);
+        let feb_28 = |y| calendar_to_jd(y, 2, 28.0);
+        assert!((mar_1(-100) - feb_28(-100) - 1.0).abs() < EPS);
+        assert!((mar_1(-400) - feb_28(-400) - 2.0).abs() < EPS);
+        for y in [-100, -101, -400, -1200, -4000] {
+            let jd = calendar_to_jd(y, 3, 1.0);
+            assert_eq!(jd_to_calendar(jd), (y, 3, 1.0), "year {y}");
+        }
+    }
+
+    #[test]
+    fn julian_calendar_year_zero() {
+        let jd = calendar_to_jd_with_policy(1, 1, 1.0, CalendarPolicy::GregorianCutover1582);
+        assert!((jd - 1_721_423.5).abs() < EPS);
+        let jd = calendar_to_jd_with_policy(0, 1, 1.0, CalendarPolicy::GregorianCutover1582);
+        assert!((jd - 1_721_057.5).abs() < EPS);
+        assert_eq!(
+            jd_to_calendar_with_policy(jd, CalendarPolicy::GregorianCutover1582),
+            (0, 1, 1.0)
+        );
+    }
+
+    #[test]
+    fn proleptic_julian_known_dates() {
+        // 1500-01-01 Julian = JD 2268932.5; 1582-10-04 Julian = JD 2299159.5.
+        for (y, m, d, expected) in [(1500, 1, 1.0, 2_268_932.5), (1582, 10, 4.0, 2_299_159.5)] {
+            for policy in [
+                CalendarPolicy::ProlepticJulian,
+                CalendarPolicy::GregorianCutover1582,
+            ] {
+                let jd = calendar_to_jd_with_policy(y, m, d, policy);
+                assert!(
+                    (jd - expected).abs() < EPS,
+                    "{policy:?} ({y}, {m}, {d}): got {jd}"
+                );
+                assert_eq!(jd_to_calendar_with_policy(jd, policy), (y, m, d));
+            }
+        }
+    }
+
+    #[test]
+    fn proleptic_julian_after_cutover() {
+        // 1582-10-15 Gregorian is 1582-10-05 Julian; 2000-01-01 Gregorian is
+        // 1999-12-19 Julian.
+        let cutover =
+            calendar_to_jd_with_policy(1582, 10, 15.0, CalendarPolicy::GregorianCutover1582);
+        assert_eq!(
+            jd_to_calendar_with_policy(cutover, CalendarPolicy::ProlepticJulian),
+            (1582, 10, 5.0)
+        );
+        let jd = calendar_to_jd_with_policy(1999, 12, 19.0, CalendarPolicy::ProlepticJulian);
+        assert!((jd - calendar_to_jd(2000, 1, 1.0)).abs() < EPS);
+        // Julian century years are always leap years.
+        let mar_1 = calendar_to_jd_with_policy(1900, 3, 1.0, CalendarPolicy::ProlepticJulian);
+        let feb_28 = calendar_to_jd_with_policy(1900, 2, 28.0, CalendarPolicy::ProlepticJulian);
+        assert!((mar_1 - feb_28 - 2.0).abs() < EPS);
+    }
 }
@@ -16,7 +16,10 @@ pub mod scales;
 pub mod sidereal;
 pub mod utc_time;
 
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
 use std::path::Path;
+use std::str::FromStr;
 
 pub use delta_t::{
     DeltaTModel, DeltaTSegment, Smh2016Reconstruction, SmhFutureParabolaFamily, delta_t_seconds,
@@ -32,12 +35,15 @@ pub use julian::{
     calendar_to_jd_with_policy, jd_to_calendar, jd_to_calendar_with_policy, jd_to_tdb_seconds,
     tdb_seconds_to_jd,
 };
-pub use lsk::LskData;
+pub use lsk::{LskData, STANDARD_DELTET};
 pub use scales::{
     FutureDeltaTTransition, TimeConversionOptions, TimeConversionPolicy, UtcToTdbResult,
 };
-pub use sidereal::{earth_rotation_angle_rad, gmst_rad, local_sidereal_time_rad};
-pub use utc_time::UtcTime;
+pub use sidereal::{
+    apparent_local_sidereal_time_rad, earth_rotation_angle_rad, gast_rad, gmst_rad,
+    local_sidereal_time_rad,
+};
+pub use utc_time::{LocalTime, UtcTime, parse_utc_offset};
 
 /// A loaded leap-second kernel, ready for time conversions.
 #[derive(Debug, Clone)]
@@ -143,6 +149,138 @@ impl Epoch {
     pub fn as_jd_tdb(self) -> f64 {
         tdb_seconds_to_jd(self.tdb_seconds)
     }
+
+    /// TT seconds past J2000.0 (TDB minus the periodic TDB−TT term).
+    ///
+    /// Uses [`STANDARD_DELTET`]; no leap-second kernel is needed.
+    pub fn as_tt_seconds(self) -> f64 {
+        scales::tdb_to_tt(self.tdb_seconds, &STANDARD_DELTET)
+    }
+
+    /// Julian Date in TT.
+    pub fn as_tt_jd(self) -> f64 {
+        tdb_seconds_to_jd(self.as_tt_seconds())
+    }
+
+    /// TAI seconds past J2000.0 (TT − 32.184 s).
+    pub fn as_tai_seconds(self) -> f64 {
+        scales::tt_to_tai(self.as_tt_seconds(), &STANDARD_DELTET)
+    }
+
+    /// This epoch shifted by `days` (TDB days of 86 400 s; may be negative).
+    pub fn shift_days(self, days: f64) -> Self {
+        self + days * SECONDS_PER_DAY
+    }
+}
+
+/// TDB seconds from J2000.0 (noon) back to 2000-01-01T00:00:00 TDB.
+const J2000_MIDNIGHT_OFFSET_S: f64 = 43_200.0;
+
+/// JD of 2000-01-01T00:00:00.
+const J2000_MIDNIGHT_JD: f64 = J2000_JD - 0.5;
+
+/// Formats the TDB calendar datetime, e.g. `2000-01-01T12:00:00.000000Z TDB`.
+///
+/// Years use astronomical numbering (proleptic Gregorian); negative years are
+/// written as `-YYYY`. No leap-second kernel is involved. Parsed back by
+/// [`FromStr`].
+impl Display for Epoch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Split into whole days and microseconds of day before touching the
+        // calendar, so the fraction keeps full f64 precision.
+        let s = self.tdb_seconds + J2000_MIDNIGHT_OFFSET_S;
+        let mut days = (s / SECONDS_PER_DAY).floor();
+        let mut sod_us = ((s - days * SECONDS_PER_DAY) * 1e6).round();
+        if sod_us >= SECONDS_PER_DAY * 1e6 {
+            days += 1.0;
+            sod_us -= SECONDS_PER_DAY * 1e6;
+        }
+        let (year, month, day) = jd_to_calendar(J2000_MIDNIGHT_JD + days);
+        let sod_us = sod_us as u64;
+        let hour = sod_us / 3_600_000_000;
+        let minute = sod_us / 60_000_000 % 60;
+        let micros = sod_us % 60_000_000;
+        if year < 0 {
+            write!(f, "-{:04}", year.unsigned_abs())?;
+        } else {
+            write!(f, "{year:04}")?;
+        }
+        write!(
+            f,
+            "-{month:02}-{:02}T{hour:02}:{minute:02}:{:02}.{:06}Z TDB",
+            day as u32,
+            micros / 1_000_000,
+            micros % 1_000_000
+        )
+    }
+}
+
+/// Parses `YYYY-MM-DDThh:mm:ss[.f]Z TDB`, the format written by [`Display`].
+impl FromStr for Epoch {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |why: &str| TimeError::EpochParse(format!("{s:?}: {why}"));
+        let body = s
+            .strip_suffix("Z TDB")
+            .ok_or_else(|| err("expected trailing \"Z TDB\""))?;
+        let (date, time) = body
+            .split_once('T')
+            .ok_or_else(|| err("expected YYYY-MM-DDThh:mm:ss"))?;
+        let (sign, date) = match date.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, date),
+        };
+        let date_parts: Vec<&str> = date.split('-').collect();
+        let time_parts: Vec<&str> = time.split(':').collect();
+        if date_parts.len() != 3 || time_parts.len() != 3 {
+            return Err(err("expected YYYY-MM-DDThh:mm:ss"));
+        }
+        let field = |v: &str, name: &str| v.parse::<u32>().map_err(|_| err(name));
+        let year = sign * field(date_parts[0], "bad year")? as i32;
+        let month = field(date_parts[1], "bad month")?;
+        let day = field(date_parts[2], "bad day")?;
+        let hour = field(time_parts[0], "bad hour")?;
+        let minute = field(time_parts[1], "bad minute")?;
+        let second: f64 = time_parts[2].parse().map_err(|_| err("bad second"))?;
+        // TDB has no leap seconds, so the UTC calendar checks (without LSK)
+        // are exactly the right ones.
+        UtcTime::try_new(year, month, day, hour, minute, second, None)
+            .map_err(|e| err(&e.to_string()))?;
+
+        let days = calendar_to_jd(year, month, day as f64) - J2000_MIDNIGHT_JD;
+        let sod = hour as f64 * 3600.0 + minute as f64 * 60.0 + second;
+        Ok(Self::from_tdb_seconds(
+            days * SECONDS_PER_DAY + sod - J2000_MIDNIGHT_OFFSET_S,
+        ))
+    }
+}
+
+/// Epoch shifted forward by TDB seconds.
+impl Add<f64> for Epoch {
+    type Output = Epoch;
+
+    fn add(self, seconds: f64) -> Epoch {
+        Epoch::from_tdb_seconds(self.tdb_seconds + seconds)
+    }
+}
+
+/// Epoch shifted backward by TDB seconds.
+impl Sub<f64> for Epoch {
+    type Output = Epoch;
+
+    fn sub(self, seconds: f64) -> Epoch {
+        Epoch::from_tdb_seconds(self.tdb_seconds - seconds)
+    }
+}
+
+/// Difference of two epochs in TDB seconds.
+impl Sub<Epoch> for Epoch {
+    type Output = f64;
+
+    fn sub(self, other: Epoch) -> f64 {
+        self.tdb_seconds - other.tdb_seconds
+    }
 }
 
 #[cfg(test)]
@@ -161,4 +299,85 @@ mod tests {
         let epoch = Epoch::from_jd_tdb(J2000_JD);
         assert_eq!(epoch.as_tdb_seconds(), 0.0);
     }
+
+    #[test]
+    fn epoch_arithmetic() {
+        let epoch = Epoch::from_jd_tdb(2_460_000.5);
+        assert_eq!((epoch + 86_400.0) - epoch, 86_400.0);
+        assert_eq!(epoch - (epoch - 60.0), 60.0);
+        assert_eq!(epoch.shift_days(1.0), epoch + SECONDS_PER_DAY);
+        assert_eq!(epoch.shift_days(-2.5), epoch - 2.5 * SECONDS_PER_DAY);
+        // Operators take `self` by value; the original stays usable.
+        let later = epoch + 1.0;
+        assert!(later > epoch);
+    }
+
+    #[test]
+    fn epoch_display() {
+        assert_eq!(
+            Epoch::from_tdb_seconds(0.0).to_string(),
+            "2000-01-01T12:00:00.000000Z TDB"
+        );
+        assert_eq!(
+            Epoch::from_tdb_seconds(-43_200.000_001).to_string(),
+            "1999-12-31T23:59:59.999999Z TDB"
+        );
+        assert_eq!(
+            Epoch::from_jd_tdb(1_720_694.5).to_string(),
+            "-0001-01-01T00:00:00.000000Z TDB"
+        );
+    }
+
+    #[test]
+    fn epoch_display_fromstr_roundtrip() {
+        for s in [
+            -1.0e11,
+            -3.0e9,
+            -43_200.5,
+            0.0,
+            0.123_456,
+            7.7e8 + 0.987_654_3,
+            3.0e9,
+        ] {
+            let epoch = Epoch::from_tdb_seconds(s);
+            let text = epoch.to_string();
+            let back: Epoch = text.parse().unwrap();
+            assert!((back - epoch).abs() <= 1e-6, "{text}: {}", back - epoch);
+        }
+        let e: Epoch = "2000-01-01T12:00:30Z TDB".parse().unwrap();
+        assert_eq!(e.as_tdb_seconds(), 30.0);
+    }
+
+    #[test]
+    fn epoch_fromstr_rejects_malformed() {
+        for bad in [
+            "2000-01-01T12:00:00Z",
+            "2000-01-01T12:00:00.0Z UTC",
+            "2000-01-01 12:00:00Z TDB",
+            "2000-02-30T00:00:00Z TDB",
+            "2000-01-01T12:00Z TDB",
+            "2000-01-01T12:00:60Z TDB",
+        ] {
+            let e = bad.parse::<Epoch>().unwrap_err();
+            assert!(matches!(e, TimeError::EpochParse(_)), "{bad}: {e}");
+        }
+    }
+
+    #[test]
+    fn tt_tdb_tt_roundtrip() {
+        for tt in [-3.0e9, -1.0e8, 0.0, 1.0e7, 7.7e8, 3.0e9] {
+            let epoch = Epoch::from_tdb_seconds(scales::tt_to_tdb(tt, &STANDARD_DELTET));
+            assert!((epoch.as_tt_seconds() - tt).abs() < 1e-9, "tt = {tt}");
+        }
+    }
+
+    #[test]
+    fn tai_and_tt_offsets() {
+        let epoch = Epoch::from_jd_tdb(2_460_000.5);
+        let tt = epoch.as_tt_seconds();
+        // |TDB − TT| stays under 2 ms.
+        assert!((epoch.as_tdb_seconds() - tt).abs() < 2e-3);
+        assert!((tt - epoch.as_tai_seconds() - 32.184).abs() < 1e-6);
+        assert!((epoch.as_tt_jd() - tdb_seconds_to_jd(tt)).abs() < 1e-12);
+    }
 }
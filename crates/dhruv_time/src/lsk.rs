@@ -28,6 +28,20 @@ pub struct LskData {
     pub leap_seconds: Vec<(f64, f64)>,
 }
 
+/// DELTET constants shared by all NAIF LSKs (naif0012 and earlier), with
+/// an empty leap-second table.
+///
+/// Sufficient for the TAI ↔ TT ↔ TDB conversions, which do not depend on
+/// leap seconds.
+pub const STANDARD_DELTET: LskData = LskData {
+    delta_t_a: 32.184,
+    k: 1.657e-3,
+    eb: 1.671e-2,
+    m0: 6.239996,
+    m1: 1.99096871e-7,
+    leap_seconds: Vec::new(),
+};
+
 impl LskData {
     /// UTC-second coverage range of DELTA_AT table, if present.
     pub fn delta_at_range(&self) -> Option<(f64, f64)> {
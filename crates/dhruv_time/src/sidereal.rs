@@ -1,17 +1,21 @@
 //! Greenwich Mean Sidereal Time and Earth Rotation Angle.
 //!
-//! Provides the ERA and GMST needed for converting between celestial
+//! Provides the ERA, GMST, and GAST needed for converting between celestial
 //! (RA/Dec) and terrestrial (hour angle) coordinate systems.
 //!
-//! All functions take UT1 Julian Dates. Callers convert UTC→UT1 using
+//! All functions take UT1 Julian Dates; the apparent (GAST) variants also
+//! take the matching TDB Julian Date for nutation. Callers convert UTC→UT1 using
 //! [`crate::EopKernel::utc_to_ut1_jd`] before calling these functions.
 //!
 //! Sources:
 //! - ERA: IERS Conventions 2010, Eq. 5.15. Public domain.
 //! - GMST polynomial: Capitaine et al. 2003, Table 2. Public domain.
+//! - Equation of equinoxes: IERS Conventions 2010, Section 5.5.3.
 
 use std::f64::consts::{PI, TAU};
 
+use dhruv_frames::equation_of_equinoxes_and_true_obliquity;
+
 use crate::julian::J2000_JD;
 
 /// Arcseconds to radians: 1″ = π / (180 × 3600).
@@ -67,6 +71,25 @@ pub fn local_sidereal_time_rad(gmst: f64, longitude_east_rad: f64) -> f64 {
     (gmst + longitude_east_rad).rem_euclid(TAU)
 }
 
+/// Greenwich Apparent Sidereal Time.
+///
+/// GAST = GMST(UT1) + equation of equinoxes, where the equation of
+/// equinoxes Δψ·cos(ε_mean) uses IAU 2000B nutation evaluated at TDB.
+///
+/// Returns radians in [0, 2π).
+pub fn gast_rad(jd_ut1: f64, jd_tdb: f64) -> f64 {
+    let t = (jd_tdb - J2000_JD) / 36525.0;
+    let (ee, _eps_true) = equation_of_equinoxes_and_true_obliquity(t);
+    (gmst_rad(jd_ut1) + ee).rem_euclid(TAU)
+}
+
+/// Apparent Local Sidereal Time: [`gast_rad`] plus observer east longitude.
+///
+/// Returns radians in [0, 2π).
+pub fn apparent_local_sidereal_time_rad(jd_ut1: f64, jd_tdb: f64, longitude_east_rad: f64) -> f64 {
+    local_sidereal_time_rad(gast_rad(jd_ut1, jd_tdb), longitude_east_rad)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +151,30 @@ mod tests {
             assert!((0.0..TAU).contains(&g), "GMST out of range: {g}");
         }
     }
+
+    #[test]
+    fn gast_adds_equation_of_equinoxes() {
+        for &jd in &[2_451_545.0, 2_455_197.5, 2_460_000.5] {
+            // TDB − UT1 of ~69 s, as around 2023.
+            let jd_tdb = jd + 69.0 / 86_400.0;
+            let ee = (gast_rad(jd, jd_tdb) - gmst_rad(jd) + PI).rem_euclid(TAU) - PI;
+            let t = (jd_tdb - J2000_JD) / 36525.0;
+            let (expected, _) = equation_of_equinoxes_and_true_obliquity(t);
+            assert!(
+                (ee - expected).abs() < 1e-12,
+                "jd {jd}: ee {ee}, expected {expected}"
+            );
+            // |EE| never exceeds ~1.2 s of time (18″).
+            assert!(ee.abs() < 18.0 * ARCSEC_TO_RAD, "jd {jd}: ee {ee}");
+        }
+    }
+
+    #[test]
+    fn apparent_lst_east_offset() {
+        let (jd_ut1, jd_tdb) = (2_460_000.5, 2_460_000.500_8);
+        let last = apparent_local_sidereal_time_rad(jd_ut1, jd_tdb, PI / 2.0);
+        let expected = (gast_rad(jd_ut1, jd_tdb) + PI / 2.0).rem_euclid(TAU);
+        assert!((last - expected).abs() < 1e-15);
+        assert!((0.0..TAU).contains(&last));
+    }
 }
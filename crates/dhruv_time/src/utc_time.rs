@@ -1,13 +1,18 @@
 //! UTC calendar date/time with sub-second precision.
 //!
 //! Provides `UtcTime`, the canonical UTC representation used throughout
-//! the engine. Conversion to/from JD TDB requires a [`LeapSecondKernel`].
+//! the engine, and `LocalTime`, a wall-clock reading at a fixed UTC offset.
+//! Conversion to/from JD TDB requires a [`LeapSecondKernel`].
 
 use crate::LeapSecondKernel;
 use crate::error::TimeError;
 use crate::julian::{calendar_to_jd, jd_to_calendar, jd_to_tdb_seconds, tdb_seconds_to_jd};
 
 /// UTC calendar date with sub-second precision.
+///
+/// `year` uses astronomical numbering (0 = 1 BCE, −1 = 2 BCE) in the
+/// proleptic Gregorian calendar. `Display` writes negative years in ISO 8601
+/// expanded form, e.g. `-0001-03-01T00:00:00Z`.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct UtcTime {
     pub year: i32,
@@ -167,6 +172,31 @@ impl UtcTime {
         ))
     }
 
+    /// Interpret `self` as local civil time at `offset_minutes` east of UTC
+    /// and return the corresponding UTC time.
+    ///
+    /// Rolls the date across day, month, and year boundaries as needed.
+    /// `offset_minutes` must lie strictly within ±24 h.
+    pub fn local_to_utc(&self, offset_minutes: i32) -> Self {
+        let mut total = (self.hour * 60 + self.minute) as i32 - offset_minutes;
+        let (mut y, mut m, mut d) = (self.year, self.month, self.day);
+        if total < 0 {
+            total += 1440;
+            (y, m, d) = prev_day(y, m, d);
+        } else if total >= 1440 {
+            total -= 1440;
+            (y, m, d) = next_day(y, m, d);
+        }
+        Self::new(
+            y,
+            m,
+            d,
+            (total / 60) as u32,
+            (total % 60) as u32,
+            self.second,
+        )
+    }
+
     /// Convert to Julian Date TDB using leap-second data.
     pub fn to_jd_tdb(&self, lsk: &LeapSecondKernel) -> f64 {
         let day_frac = self.day as f64
@@ -203,6 +233,76 @@ impl UtcTime {
     }
 }
 
+/// Local civil date and time at a fixed offset from UTC.
+///
+/// Kept apart from [`UtcTime`] so a wall-clock reading cannot be mistaken
+/// for (or converted again as) a UTC instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: f64,
+    /// Offset from UTC in hours, east-positive.
+    pub utc_offset_hours: f64,
+}
+
+impl LocalTime {
+    /// Wall-clock reading of `utc` at `utc_offset_hours` (east-positive).
+    ///
+    /// The offset is rounded to the second and applied to the clock fields,
+    /// so a leap second (`second` in 60..61) stays a leap second.
+    pub fn from_utc(utc: &UtcTime, utc_offset_hours: f64) -> Self {
+        let offset_s = (utc_offset_hours * 3600.0).round() as i64;
+        let whole = utc.second.floor();
+        let leap = (whole as i64 - 59).max(0);
+        let clock_s = i64::from(utc.hour * 3600 + utc.minute * 60) + whole as i64 - leap + offset_s;
+        let day_shift = clock_s.div_euclid(86_400);
+        let secs_of_day = clock_s.rem_euclid(86_400);
+        let (year, month, day) = jd_to_calendar(
+            calendar_to_jd(utc.year, utc.month, f64::from(utc.day)) + day_shift as f64,
+        );
+        Self {
+            year,
+            month,
+            day: day.round() as u32,
+            hour: (secs_of_day / 3600) as u32,
+            minute: (secs_of_day % 3600 / 60) as u32,
+            second: (secs_of_day % 60 + leap) as f64 + (utc.second - whole),
+            utc_offset_hours,
+        }
+    }
+}
+
+/// Parse an ISO 8601 UTC offset of the form `+HH:MM` or `-HH:MM`.
+///
+/// Returns the offset in minutes east of UTC. Hours must be 00..23 and
+/// minutes 00..59.
+pub fn parse_utc_offset(s: &str) -> Result<i32, TimeError> {
+    let err = |why: &str| TimeError::InvalidUtc(format!("invalid UTC offset {s:?}: {why}"));
+    let (sign, rest) = match s.as_bytes().first() {
+        Some(b'+') => (1, &s[1..]),
+        Some(b'-') => (-1, &s[1..]),
+        _ => return Err(err("expected leading '+' or '-'")),
+    };
+    let bytes = rest.as_bytes();
+    if bytes.len() != 5 || bytes[2] != b':' || !rest.chars().all(|c| c.is_ascii_digit() || c == ':')
+    {
+        return Err(err("expected +HH:MM or -HH:MM"));
+    }
+    let hours: i32 = rest[0..2].parse().map_err(|_| err("bad hours"))?;
+    let minutes: i32 = rest[3..5].parse().map_err(|_| err("bad minutes"))?;
+    if hours > 23 {
+        return Err(err("hours out of range 00..23"));
+    }
+    if minutes > 59 {
+        return Err(err("minutes out of range 00..59"));
+    }
+    Ok(sign * (hours * 60 + minutes))
+}
+
 fn is_leap_year(year: i32) -> bool {
     (year % 4 == 0) && ((year % 100 != 0) || (year % 400 == 0))
 }
@@ -238,17 +338,22 @@ impl std::fmt::Display for UtcTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let whole = self.second as u32;
         let frac = self.second - whole as f64;
+        if self.year < 0 {
+            write!(f, "-{:04}", self.year.unsigned_abs())?;
+        } else {
+            write!(f, "{:04}", self.year)?;
+        }
         if frac.abs() < 1e-9 {
             write!(
                 f,
-                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-                self.year, self.month, self.day, self.hour, self.minute, whole
+                "-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+                self.month, self.day, self.hour, self.minute, whole
             )
         } else {
             write!(
                 f,
-                "{:04}-{:02}-{:02}T{:02}:{:02}:{:09.6}Z",
-                self.year, self.month, self.day, self.hour, self.minute, self.second
+                "-{:02}-{:02}T{:02}:{:02}:{:09.6}Z",
+                self.month, self.day, self.hour, self.minute, self.second
             )
         }
     }
@@ -282,6 +387,56 @@ mod tests {
         assert!(s.contains("12:30:"), "got: {s}");
     }
 
+    #[test]
+    fn display_bce_years() {
+        assert_eq!(
+            UtcTime::new(0, 1, 1, 0, 0, 0.0).to_string(),
+            "0000-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            UtcTime::new(-1, 3, 1, 6, 0, 0.0).to_string(),
+            "-0001-03-01T06:00:00Z"
+        );
+    }
+
+    #[test]
+    fn try_new_accepts_year_zero_leap_day() {
+        assert!(UtcTime::try_new(0, 2, 29, 0, 0, 0.0, None).is_ok());
+        assert!(UtcTime::try_new(-1, 2, 29, 0, 0, 0.0, None).is_err());
+        assert!(UtcTime::try_new(-4, 2, 29, 0, 0, 0.0, None).is_ok());
+    }
+
+    #[test]
+    fn parse_utc_offset_forms() {
+        assert_eq!(parse_utc_offset("+05:30").unwrap(), 330);
+        assert_eq!(parse_utc_offset("-08:00").unwrap(), -480);
+        assert_eq!(parse_utc_offset("+00:00").unwrap(), 0);
+        for bad in [
+            "05:30",
+            "+5:30",
+            "+05:3",
+            "+0530",
+            "+24:00",
+            "+05:60",
+            "+0a:00",
+            "+05:30:00",
+        ] {
+            assert!(parse_utc_offset(bad).is_err(), "{bad} should be rejected");
+        }
+    }
+
+    #[test]
+    fn local_to_utc_rolls_dates() {
+        let t = UtcTime::new(2024, 3, 20, 12, 0, 0.0).local_to_utc(330);
+        assert_eq!(t, UtcTime::new(2024, 3, 20, 6, 30, 0.0));
+        let t = UtcTime::new(2024, 3, 20, 20, 15, 1.5).local_to_utc(-480);
+        assert_eq!(t, UtcTime::new(2024, 3, 21, 4, 15, 1.5));
+        let t = UtcTime::new(2024, 3, 1, 2, 0, 0.0).local_to_utc(330);
+        assert_eq!(t, UtcTime::new(2024, 2, 29, 20, 30, 0.0));
+        let t = UtcTime::new(2023, 12, 31, 23, 0, 0.0).local_to_utc(-120);
+        assert_eq!(t, UtcTime::new(2024, 1, 1, 1, 0, 0.0));
+    }
+
     fn test_lsk() -> LeapSecondKernel {
         let content = r#"
 \begindata
@@ -329,4 +484,28 @@ DELTET/DELTA_AT  = ( 10, @1972-JAN-1
         assert_eq!(t.minute, 0);
         assert!((t.second - 0.2).abs() < 1e-9);
     }
+
+    #[test]
+    fn local_time_carries_month_and_year() {
+        let utc = UtcTime::new(2023, 12, 31, 22, 15, 7.25);
+        let local = LocalTime::from_utc(&utc, 5.75);
+        assert_eq!(
+            (local.year, local.month, local.day, local.hour, local.minute),
+            (2024, 1, 1, 4, 0)
+        );
+        assert!((local.second - 7.25).abs() < 1e-12);
+        let back = LocalTime::from_utc(&UtcTime::new(2024, 3, 1, 1, 0, 0.0), -3.0);
+        assert_eq!((back.month, back.day, back.hour), (2, 29, 22));
+    }
+
+    #[test]
+    fn local_time_keeps_leap_second() {
+        let utc = UtcTime::new(2016, 12, 31, 23, 59, 60.5);
+        let local = LocalTime::from_utc(&utc, 1.0);
+        assert_eq!(
+            (local.year, local.month, local.day, local.hour, local.minute),
+            (2017, 1, 1, 0, 59)
+        );
+        assert!((local.second - 60.5).abs() < 1e-12);
+    }
 }
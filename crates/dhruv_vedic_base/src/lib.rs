@@ -54,18 +54,33 @@ pub mod graha_relationships {
 pub mod hora {
     pub use dhruv_vedic_math::hora::*;
 }
+pub mod jaimini {
+    pub use dhruv_vedic_math::jaimini::*;
+}
 pub mod karana {
     pub use dhruv_vedic_math::karana::*;
 }
+pub mod kp {
+    pub use dhruv_vedic_math::kp::*;
+}
+pub mod longevity {
+    pub use dhruv_vedic_math::longevity::*;
+}
 pub mod lagna {
     pub use dhruv_vedic_engine::lagna::*;
 }
+pub mod longitude {
+    pub use dhruv_vedic_math::longitude::*;
+}
 pub mod lunar_nodes {
     pub use dhruv_vedic_engine::lunar_nodes::*;
 }
 pub mod masa {
     pub use dhruv_vedic_math::masa::*;
 }
+pub mod muhurta {
+    pub use dhruv_vedic_math::muhurta::*;
+}
 pub mod nakshatra {
     pub use dhruv_vedic_math::nakshatra::*;
 
@@ -118,6 +133,9 @@ pub mod riseset_types {
 pub mod samvatsara {
     pub use dhruv_vedic_math::samvatsara::*;
 }
+pub mod sandhi {
+    pub use dhruv_vedic_math::sandhi::*;
+}
 pub mod shadbala {
     pub use dhruv_vedic_math::shadbala::*;
 }
@@ -7,7 +7,7 @@ use std::f64::consts::{PI, TAU};
 use std::path::Path;
 
 use dhruv_core::{Body, Engine, EngineConfig};
-use dhruv_time::{EopKernel, LeapSecondKernel, gmst_rad, local_sidereal_time_rad};
+use dhruv_time::{EopKernel, LeapSecondKernel, apparent_local_sidereal_time_rad};
 use dhruv_vedic_base::{
     BhavaConfig, BhavaReferenceMode, BhavaStartingPoint, BhavaSystem, GeoLocation, compute_bhavas,
     lagna_and_mc_rad, lagna_longitude_rad,
@@ -203,7 +203,7 @@ fn kp_placidus_valid_cusps() {
 }
 
 #[test]
-fn all_10_systems_produce_valid_results() {
+fn all_systems_produce_valid_results() {
     let Some((engine, lsk, eop)) = load_test_resources() else {
         return;
     };
@@ -345,14 +345,12 @@ fn lagna_is_rising_point() {
 
     // Reconstruct apparent LST (GAST) and true obliquity — matching production
     let jd_ut1 = eop.utc_to_ut1_jd(jd_utc).expect("EOP lookup");
-    let gmst = gmst_rad(jd_ut1);
     let utc_s = dhruv_time::jd_to_tdb_seconds(jd_utc);
     let tdb_s = _lsk.utc_to_tdb(utc_s);
     let jd_tdb = dhruv_time::tdb_seconds_to_jd(tdb_s);
     let t = (jd_tdb - 2_451_545.0) / 36525.0;
-    let (ee, eps_true) = dhruv_frames::equation_of_equinoxes_and_true_obliquity(t);
-    let gast = gmst + ee;
-    let lst = local_sidereal_time_rad(gast, loc.longitude_rad());
+    let (_, eps_true) = dhruv_frames::equation_of_equinoxes_and_true_obliquity(t);
+    let lst = apparent_local_sidereal_time_rad(jd_ut1, jd_tdb, loc.longitude_rad());
 
     let tropical_lagna_rad = result.lagna_deg.to_radians();
     assert_lagna_is_rising(tropical_lagna_rad, lst, eps_true);
@@ -8,8 +8,8 @@ use std::path::Path;
 use dhruv_core::{Engine, EngineConfig};
 use dhruv_time::{EopKernel, LeapSecondKernel};
 use dhruv_vedic_base::{
-    GeoLocation, RiseSetConfig, RiseSetEvent, RiseSetResult, approximate_local_noon_jd,
-    compute_rise_set,
+    GeoLocation, RefractionModel, RiseSetConfig, RiseSetEvent, RiseSetResult,
+    approximate_local_noon_jd, compute_rise_set,
 };
 
 const SPK_PATH: &str = "../../data/de442s.bsp";
@@ -116,6 +116,87 @@ fn new_delhi_equinox_sunset() {
     }
 }
 
+/// Same lat/lon/date at 3000 m: horizon dip (~1.76 deg) outweighs the
+/// ~8.8" solar parallax, so sunrise comes several minutes earlier.
+#[test]
+fn sunrise_at_altitude_is_earlier() {
+    let Some((engine, lsk, eop)) = load_test_resources() else {
+        return;
+    };
+    let config = RiseSetConfig::default();
+    let sunrise_at = |altitude_m: f64| {
+        let loc = GeoLocation::new(34.1526, 77.5771, altitude_m);
+        let noon = approximate_local_noon_jd(jd_0h_utc(2024, 3, 20), loc.longitude_deg);
+        match compute_rise_set(
+            &engine,
+            &lsk,
+            &eop,
+            &loc,
+            RiseSetEvent::Sunrise,
+            noon,
+            &config,
+        )
+        .unwrap()
+        {
+            RiseSetResult::Event { jd_tdb, .. } => jd_tdb,
+            other => panic!("Expected sunrise event, got {other:?}"),
+        }
+    };
+
+    let earlier_min = (sunrise_at(0.0) - sunrise_at(3000.0)) * 1440.0;
+    assert!(
+        (2.0..15.0).contains(&earlier_min),
+        "sunrise at 3000 m should be a few minutes earlier, got {earlier_min:.2} min"
+    );
+}
+
+#[test]
+fn new_delhi_sunrise_refraction_models() {
+    let Some((engine, lsk, eop)) = load_test_resources() else {
+        return;
+    };
+    let loc = GeoLocation::new(28.6139, 77.209, 0.0);
+    let noon = approximate_local_noon_jd(jd_0h_utc(2024, 3, 20), loc.longitude_deg);
+    let sunrise = |config: RiseSetConfig| match compute_rise_set(
+        &engine,
+        &lsk,
+        &eop,
+        &loc,
+        RiseSetEvent::Sunrise,
+        noon,
+        &config,
+    )
+    .unwrap()
+    {
+        RiseSetResult::Event { jd_tdb, .. } => jd_tdb,
+        other => panic!("Expected sunrise event, got {other:?}"),
+    };
+
+    let standard = sunrise(RiseSetConfig::default());
+    let bennett = sunrise(RiseSetConfig {
+        refraction_model: RefractionModel::bennett(),
+        ..RiseSetConfig::default()
+    });
+    // Bennett's ~34.5' lifts the Sun ~0.5' earlier: a couple of seconds.
+    let shift_s = (standard - bennett) * 86_400.0;
+    assert!(
+        (0.5..10.0).contains(&shift_s),
+        "Bennett shifted sunrise by {shift_s:.2}s"
+    );
+
+    // Without refraction the centre crosses the geometric horizon later,
+    // by ~34' of altitude (over two minutes near the equator).
+    let geometric = sunrise(RiseSetConfig {
+        use_refraction: false,
+        ..RiseSetConfig::default()
+    });
+    let geometric_shift_s = (geometric - standard) * 86_400.0;
+    assert!(
+        (120.0..200.0).contains(&geometric_shift_s),
+        "geometric horizon shift = {geometric_shift_s:.1}s"
+    );
+}
+
 #[test]
 fn tromso_summer_never_sets() {
     let Some((engine, lsk, eop)) = load_test_resources() else {
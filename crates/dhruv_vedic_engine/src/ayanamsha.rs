@@ -9,6 +9,20 @@
 //! computed by precessing that direction to the ecliptic-of-date using the
 //! full 3D ecliptic precession matrix and reading off the longitude.
 //!
+//! Nutation source per path:
+//!
+//! - `ayanamsha_mean_deg*`, `ayanamsha_rate_deg_per_century` and
+//!   `ayanamsha_spread`: none (mean equinox).
+//! - `ayanamsha_deg*`, `ayanamsha_deg_with_catalog*` and
+//!   [`CustomAyanamsha::deg`]: Δψ from IAU 2000B when `use_nutation` is set.
+//! - `ayanamsha_true_deg*`: Δψ supplied by the caller.
+//! - `*_on_plane` with `ReferencePlane::Invariable`: none, whatever
+//!   `use_nutation` says; nutation moves the equinox, not the invariable plane.
+//!
+//! True minus mean is exactly Δψ, with no `cos ε` factor: the ayanamsha is
+//! measured along the ecliptic from the equinox of date, and Δψ is itself an
+//! ecliptic longitude.
+//!
 //! Clean-room implementation: all reference values derived independently from
 //! published system definitions. See `docs/clean_room_ayanamsha.md`.
 
@@ -18,7 +32,7 @@ use crate::ayanamsha_anchor::{
 use crate::ayanamsha_tara::{tara_anchor_ayanamsha_deg, tara_anchor_ayanamsha_deg_on_plane};
 use dhruv_frames::{
     DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane, nutation_iau2000b,
-    precess_ecliptic_j2000_to_date_with_model,
+    precess_ecliptic_date_to_j2000_with_model, precess_ecliptic_j2000_to_date_with_model,
 };
 use dhruv_tara::TaraCatalog;
 use dhruv_time::J2000_JD;
@@ -434,6 +448,118 @@ pub fn ayanamsha_deg_with_model(
     }
 }
 
+/// Half-width of the central difference in [`ayanamsha_rate_deg_per_century`]
+/// (0.001 century ≈ 36.5 days).
+const RATE_STEP_CENTURIES: f64 = 1e-3;
+
+/// Instantaneous rate of the mean ayanamsha, in degrees per Julian century.
+///
+/// Central difference of [`ayanamsha_mean_deg_with_model`] over ±36.5 days
+/// under the selected precession model; nutation is excluded since its
+/// short-period terms are not useful for interpolation. About
+/// 1.397 deg/century (≈ 50.3″/year) near J2000.
+pub fn ayanamsha_rate_deg_per_century(
+    system: AyanamshaSystem,
+    t_centuries: f64,
+    model: PrecessionModel,
+) -> f64 {
+    let h = RATE_STEP_CENTURIES;
+    let ahead = ayanamsha_mean_deg_with_model(system, t_centuries + h, model);
+    let behind = ayanamsha_mean_deg_with_model(system, t_centuries - h, model);
+    (ahead - behind) / (2.0 * h)
+}
+
+/// User-defined ayanamsha fixed by its mean value at one anchor epoch.
+///
+/// The sidereal zero point is taken to lie on the ecliptic-of-date at the
+/// anchor epoch, `anchor_deg` east of the mean equinox. It is then carried
+/// to other epochs with the same 3D ecliptic precession used by the named
+/// systems, so two custom anchors differ only in their zero point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomAyanamsha {
+    /// Mean ayanamsha at the anchor epoch, in degrees.
+    pub anchor_deg: f64,
+    /// Anchor epoch as a Julian Date in TDB.
+    pub anchor_jd_tdb: f64,
+}
+
+impl CustomAyanamsha {
+    /// Create a custom ayanamsha from its mean value at `anchor_jd_tdb`.
+    pub const fn new(anchor_deg: f64, anchor_jd_tdb: f64) -> Self {
+        Self {
+            anchor_deg,
+            anchor_jd_tdb,
+        }
+    }
+
+    /// Mean ayanamsha in degrees at `t_centuries` (TDB since J2000.0).
+    pub fn mean_deg(&self, t_centuries: f64) -> f64 {
+        self.mean_deg_with_model(t_centuries, DEFAULT_PRECESSION_MODEL)
+    }
+
+    /// Mean ayanamsha in degrees for the selected precession model.
+    pub fn mean_deg_with_model(&self, t_centuries: f64, model: PrecessionModel) -> f64 {
+        let t_anchor = jd_tdb_to_centuries(self.anchor_jd_tdb);
+        let lon = self.anchor_deg.to_radians();
+        let v_anchor = [lon.cos(), lon.sin(), 0.0];
+        let v_j2000 = precess_ecliptic_date_to_j2000_with_model(&v_anchor, t_anchor, model);
+        let v_date = precess_ecliptic_j2000_to_date_with_model(&v_j2000, t_centuries, model);
+        v_date[1].atan2(v_date[0]).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Ayanamsha in degrees, optionally with nutation in longitude (IAU 2000B).
+    pub fn deg(&self, t_centuries: f64, use_nutation: bool) -> f64 {
+        self.deg_with_model(t_centuries, use_nutation, DEFAULT_PRECESSION_MODEL)
+    }
+
+    /// Ayanamsha in degrees with optional nutation and a selected precession model.
+    pub fn deg_with_model(
+        &self,
+        t_centuries: f64,
+        use_nutation: bool,
+        model: PrecessionModel,
+    ) -> f64 {
+        let mean = self.mean_deg_with_model(t_centuries, model);
+        if use_nutation {
+            let (delta_psi_arcsec, _) = nutation_iau2000b(t_centuries);
+            mean + delta_psi_arcsec / 3600.0
+        } else {
+            mean
+        }
+    }
+
+    /// Instantaneous mean-ayanamsha rate in degrees per Julian century,
+    /// using the same central difference as [`ayanamsha_rate_deg_per_century`].
+    pub fn rate_deg_per_century(&self, t_centuries: f64, model: PrecessionModel) -> f64 {
+        let h = RATE_STEP_CENTURIES;
+        let ahead = self.mean_deg_with_model(t_centuries + h, model);
+        let behind = self.mean_deg_with_model(t_centuries - h, model);
+        (ahead - behind) / (2.0 * h)
+    }
+}
+
+/// Ayanamsha in degrees for a user-defined anchor.
+///
+/// Shorthand for [`CustomAyanamsha::deg`]: `anchor_deg` is the mean
+/// ayanamsha at `anchor_jd_tdb`, and `t_centuries` is the target epoch.
+pub fn custom_ayanamsha_deg(
+    anchor_deg: f64,
+    anchor_jd_tdb: f64,
+    t_centuries: f64,
+    use_nutation: bool,
+) -> f64 {
+    CustomAyanamsha::new(anchor_deg, anchor_jd_tdb).deg(t_centuries, use_nutation)
+}
+
+/// Mean ayanamsha of every system at one epoch, in enum order.
+///
+/// Diagnostic for comparing charts built with different systems. All values
+/// are on the ecliptic (including Jagganatha, whose default plane is the
+/// invariable plane) so they can be compared directly.
+pub fn ayanamsha_spread(t_centuries: f64) -> [(AyanamshaSystem, f64); 20] {
+    ALL_SYSTEMS.map(|system| (system, ayanamsha_mean_deg(system, t_centuries)))
+}
+
 /// Mean ayanamsha using star catalog for proper-motion-corrected anchors.
 ///
 /// When `catalog` is `Some`, star-anchored systems use the provided catalog.
@@ -587,6 +713,19 @@ mod tests {
         assert_eq!(AyanamshaSystem::all().len(), 20);
     }
 
+    #[test]
+    fn spread_covers_all_systems_with_lahiri_mid_range() {
+        let spread = ayanamsha_spread(0.25);
+        assert_eq!(spread.len(), AyanamshaSystem::all().len());
+        for (entry, &system) in spread.iter().zip(AyanamshaSystem::all()) {
+            assert_eq!(entry.0, system);
+        }
+        let lahiri = spread[0].1;
+        let min = spread.iter().map(|e| e.1).fold(f64::INFINITY, f64::min);
+        let max = spread.iter().map(|e| e.1).fold(f64::NEG_INFINITY, f64::max);
+        assert!(min < lahiri && lahiri < max, "{min} < {lahiri} < {max}");
+    }
+
     #[test]
     fn lahiri_at_j2000() {
         let val = ayanamsha_mean_deg(AyanamshaSystem::Lahiri, 0.0);
@@ -605,6 +744,99 @@ mod tests {
         assert!((diff - 1.397).abs() < 0.01, "one century drift = {diff}");
     }
 
+    #[test]
+    fn kp_at_j2000_near_lahiri() {
+        let kp = ayanamsha_mean_deg(AyanamshaSystem::KP, 0.0);
+        let lahiri = ayanamsha_mean_deg(AyanamshaSystem::Lahiri, 0.0);
+        assert!((kp - 23.85).abs() < 1e-9, "KP at J2000 = {kp}");
+        // KP sits a few arcminutes below Lahiri.
+        assert!(kp < lahiri && lahiri - kp < 0.2, "KP {kp}, Lahiri {lahiri}");
+    }
+
+    #[test]
+    fn rate_is_about_fifty_arcsec_per_year() {
+        for system in [AyanamshaSystem::Lahiri, AyanamshaSystem::KP] {
+            for t in [-1.0, 0.0, 0.24] {
+                let arcsec_per_year =
+                    ayanamsha_rate_deg_per_century(system, t, DEFAULT_PRECESSION_MODEL) * 36.0;
+                assert!(
+                    (arcsec_per_year - 50.3).abs() < 0.3,
+                    "{system:?} at t={t}: {arcsec_per_year}\"/yr"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn kp_rate_matches_published_kp_rate() {
+        // Krishnamurti's published rate: 50.2388475″/yr at 1900, growing by
+        // 0.000222″/yr each year. Under Newcomb the engine's base rate is
+        // 50.2564″/yr, so the two differ by a constant ~0.0176″/yr while the
+        // secular growth must agree.
+        let kp_rate = |year: f64| {
+            ayanamsha_rate_deg_per_century(
+                AyanamshaSystem::KP,
+                (year - 2000.0) / 100.0,
+                PrecessionModel::Newcomb1895,
+            ) * 36.0
+        };
+        for year in [1900.0_f64, 1950.0, 2000.0, 2050.0] {
+            let published = 50.238_847_5 + 0.000_222 * (year - 1900.0);
+            let rate = kp_rate(year);
+            assert!(
+                (rate - published).abs() < 0.02,
+                "{year}: {rate}\"/yr vs published {published}\"/yr"
+            );
+        }
+        let growth = (kp_rate(2050.0) - kp_rate(1900.0)) / 150.0;
+        assert!((growth - 0.000_222).abs() < 2e-6, "growth = {growth}");
+    }
+
+    #[test]
+    fn rate_matches_finite_drift() {
+        let rate =
+            ayanamsha_rate_deg_per_century(AyanamshaSystem::KP, 0.5, DEFAULT_PRECESSION_MODEL);
+        let drift = ayanamsha_mean_deg(AyanamshaSystem::KP, 0.51)
+            - ayanamsha_mean_deg(AyanamshaSystem::KP, 0.49);
+        assert!((rate - drift / 0.02).abs() < 1e-6);
+    }
+
+    #[test]
+    fn custom_anchor_at_lahiri_reproduces_lahiri() {
+        // Lahiri's defining epoch: 1956-03-21 00:00 TDT.
+        let anchor_jd = 2_435_553.5;
+        let anchor_deg =
+            ayanamsha_mean_deg(AyanamshaSystem::Lahiri, jd_tdb_to_centuries(anchor_jd));
+        let custom = CustomAyanamsha::new(anchor_deg, anchor_jd);
+        for t in [-20.0, -5.0, -1.0, 0.0, 0.24, 3.0] {
+            let lahiri = ayanamsha_mean_deg(AyanamshaSystem::Lahiri, t);
+            let diff = (custom.mean_deg(t) - lahiri).abs();
+            assert!(
+                diff < 1e-7,
+                "t={t}: custom {} vs Lahiri {lahiri}",
+                custom.mean_deg(t)
+            );
+            let with_nut = custom_ayanamsha_deg(anchor_deg, anchor_jd, t, true);
+            let lahiri_nut = ayanamsha_deg(AyanamshaSystem::Lahiri, t, true);
+            assert!((with_nut - lahiri_nut).abs() < 1e-7);
+        }
+    }
+
+    #[test]
+    fn custom_anchor_returns_anchor_value_at_anchor_epoch() {
+        let custom = CustomAyanamsha::new(21.5, 2_415_020.0);
+        let t = jd_tdb_to_centuries(custom.anchor_jd_tdb);
+        assert!((custom.mean_deg(t) - 21.5).abs() < 1e-12);
+        // Same precession rate as the named systems.
+        let drift = custom.mean_deg(1.0) - custom.mean_deg(0.0);
+        let lahiri_drift = ayanamsha_mean_deg(AyanamshaSystem::Lahiri, 1.0)
+            - ayanamsha_mean_deg(AyanamshaSystem::Lahiri, 0.0);
+        assert!(
+            (drift - lahiri_drift).abs() < 1e-4,
+            "{drift} vs {lahiri_drift}"
+        );
+    }
+
     #[test]
     fn precession_backward() {
         let at_0 = ayanamsha_mean_deg(AyanamshaSystem::Lahiri, 0.0);
@@ -712,6 +944,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn true_minus_mean_is_delta_psi_on_every_path() {
+        for t in [-2.0, -0.5, 0.0, 0.24, 1.5] {
+            let dpsi_deg = nutation_iau2000b(t).0 / 3600.0;
+            for &sys in AyanamshaSystem::all() {
+                let mean = ayanamsha_mean_deg(sys, t);
+                let paths = [
+                    ayanamsha_deg(sys, t, true),
+                    ayanamsha_true_deg(sys, t, dpsi_deg * 3600.0),
+                    ayanamsha_deg_on_plane(
+                        sys,
+                        t,
+                        true,
+                        DEFAULT_PRECESSION_MODEL,
+                        ReferencePlane::Ecliptic,
+                    ),
+                ];
+                for true_val in paths {
+                    assert!(
+                        (true_val - mean - dpsi_deg).abs() < 1e-12,
+                        "{sys:?} t={t}: true-mean={}, dpsi={dpsi_deg}",
+                        true_val - mean
+                    );
+                }
+                let invariable_true = ayanamsha_deg_on_plane(
+                    sys,
+                    t,
+                    true,
+                    DEFAULT_PRECESSION_MODEL,
+                    ReferencePlane::Invariable,
+                );
+                let invariable_mean = ayanamsha_deg_on_plane(
+                    sys,
+                    t,
+                    false,
+                    DEFAULT_PRECESSION_MODEL,
+                    ReferencePlane::Invariable,
+                );
+                assert_eq!(invariable_true, invariable_mean, "{sys:?} t={t}");
+            }
+            let custom = CustomAyanamsha::new(24.0, 2_433_282.5);
+            assert!((custom.deg(t, true) - custom.mean_deg(t) - dpsi_deg).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn with_model_wrappers_match_default() {
         let t = 0.37;
@@ -1,8 +1,8 @@
-//! Bhava (house) system computation for 10 house division methods.
+//! Bhava (house) system computation for 11 house division methods.
 //!
-//! Implements Equal, Surya Siddhanta, Sripati (Porphyry), KP (Placidus),
-//! Koch, Regiomontanus, Campanus, Axial Rotation, Topocentric (Polich-Page),
-//! and Alcabitus house systems.
+//! Implements Equal, Surya Siddhanta, Sripati, KP (Placidus), Koch,
+//! Regiomontanus, Campanus, Axial Rotation, Topocentric (Polich-Page),
+//! Alcabitus, and Porphyry house systems.
 //!
 //! Sources: standard spherical astronomy (Meeus, Montenbruck & Pfleger),
 //! Polich/Page original 1961 methodology, true obliquity
@@ -64,7 +64,7 @@ pub fn compute_bhavas(
     // Compute raw cusps (12 ecliptic longitudes in degrees)
     let cusps = match config.system {
         BhavaSystem::Equal | BhavaSystem::SuryaSiddhanta => compute_equal(start_deg),
-        BhavaSystem::Sripati => compute_sripati(asc_deg, mc_deg),
+        BhavaSystem::Sripati | BhavaSystem::Porphyry => compute_porphyry(asc_deg, mc_deg),
         BhavaSystem::KP => {
             check_latitude(location)?;
             compute_placidus(asc_deg, mc_deg, ramc, lat_rad, eps_true)?
@@ -98,7 +98,7 @@ pub fn compute_bhavas(
         cusps
     };
 
-    let bhavas = build_bhavas(&final_cusps, config.reference_mode);
+    let bhavas = bhavas_for_system(config.system, &final_cusps, config.reference_mode);
 
     Ok(BhavaResult {
         bhavas,
@@ -107,6 +107,23 @@ pub fn compute_bhavas(
     })
 }
 
+/// Whole-sign bhavas from a known sidereal lagna longitude.
+///
+/// Bhava 1 is the lagna's whole rashi and each later bhava the next rashi,
+/// so cusp `n` is the start of rashi `lagna_rashi + n - 1`. Needs no engine,
+/// EOP, or location, which makes it usable on stored chart positions.
+/// There is no MC without sidereal time; `mc_deg` is the 10th cusp.
+pub fn whole_sign_from_lagna(lagna_sid_deg: f64) -> BhavaResult {
+    let lagna_deg = normalize_deg(lagna_sid_deg);
+    let sign_start = (lagna_deg / 30.0).floor().min(11.0) * 30.0;
+    let bhavas = build_bhavas(&compute_equal(sign_start), BhavaReferenceMode::StartOfFirst);
+    BhavaResult {
+        bhavas,
+        lagna_deg,
+        mc_deg: bhavas[9].cusp_deg,
+    }
+}
+
 /// Check that latitude is within the limit for time-based systems.
 fn check_latitude(location: &GeoLocation) -> Result<(), VedicError> {
     if location.latitude_deg.abs() > MAX_LATITUDE_DEG {
@@ -173,12 +190,13 @@ fn compute_equal(start_deg: f64) -> [f64; 12] {
     cusps
 }
 
-/// Sripati (Porphyry): trisect the four quadrant arcs between Asc/IC/Desc/MC.
+/// Porphyry: trisect the four quadrant arcs between Asc/IC/Desc/MC.
 ///
 /// Cusp 1 = Asc, Cusp 4 = IC, Cusp 7 = Desc, Cusp 10 = MC.
 /// Cusps 2,3 trisect (Asc→IC); Cusps 5,6 trisect (IC→Desc);
 /// Cusps 8,9 trisect (Desc→MC); Cusps 11,12 trisect (MC→Asc).
-fn compute_sripati(asc_deg: f64, mc_deg: f64) -> [f64; 12] {
+/// Sripati uses the same points as bhava madhyas.
+fn compute_porphyry(asc_deg: f64, mc_deg: f64) -> [f64; 12] {
     let desc_deg = normalize_deg(asc_deg + 180.0);
     let ic_deg = normalize_deg(mc_deg + 180.0);
 
@@ -211,6 +229,39 @@ fn compute_sripati(asc_deg: f64, mc_deg: f64) -> [f64; 12] {
     cusps
 }
 
+/// Build bhavas from raw cusps. Sripati always takes the Porphyry points as
+/// madhyas (the reference mode does not apply); every other system goes
+/// through `build_bhavas`.
+fn bhavas_for_system(
+    system: BhavaSystem,
+    cusps_deg: &[f64; 12],
+    reference_mode: BhavaReferenceMode,
+) -> [Bhava; 12] {
+    if system == BhavaSystem::Sripati {
+        build_sripati_bhavas(cusps_deg)
+    } else {
+        build_bhavas(cusps_deg, reference_mode)
+    }
+}
+
+/// Sripati bhavas with the Porphyry points as bhava madhyas.
+///
+/// Each bhava starts at the sandhi midway from the previous madhya and ends
+/// at the sandhi midway to the next one, so placement uses the sandhis.
+fn build_sripati_bhavas(madhyas: &[f64; 12]) -> [Bhava; 12] {
+    let sandhis: [f64; 12] = std::array::from_fn(|i| {
+        let prev = (i + 11) % 12;
+        normalize_deg(madhyas[prev] + arc_forward(madhyas[prev], madhyas[i]) / 2.0)
+    });
+    std::array::from_fn(|i| Bhava {
+        number: (i as u8) + 1,
+        cusp_deg: sandhis[i],
+        start_deg: sandhis[i],
+        end_deg: sandhis[(i + 1) % 12],
+        madhya_deg: madhyas[i],
+    })
+}
+
 /// Placidus (KP) house system: time-based semi-arc trisection.
 ///
 /// Cusps 1 = Asc, 4 = IC, 7 = Desc, 10 = MC.
@@ -591,6 +642,7 @@ fn build_bhavas(cusps_deg: &[f64; 12], reference_mode: BhavaReferenceMode) -> [B
         cusp_deg: 0.0,
         start_deg: 0.0,
         end_deg: 0.0,
+        madhya_deg: 0.0,
     }; 12];
 
     for i in 0..12 {
@@ -600,12 +652,52 @@ fn build_bhavas(cusps_deg: &[f64; 12], reference_mode: BhavaReferenceMode) -> [B
             cusp_deg: adjusted[i],
             start_deg: adjusted[i],
             end_deg: adjusted[next],
+            madhya_deg: normalize_deg(adjusted[i] + arc_forward(adjusted[i], adjusted[next]) / 2.0),
         };
     }
 
     bhavas
 }
 
+/// House number (1-12) containing a longitude, from cusp longitudes.
+///
+/// House `n` spans the forward arc from `cusps_deg[n-1]` (inclusive) to
+/// `cusps_deg[n % 12]` (exclusive), so unequal and wrapping houses are
+/// handled. `lon_deg` and the cusps must share a frame (tropical or sidereal).
+pub fn house_of_longitude(lon_deg: f64, cusps_deg: &[f64; 12]) -> u8 {
+    let lon = normalize_deg(lon_deg);
+    for i in 0..12 {
+        let width = arc_forward(cusps_deg[i], cusps_deg[(i + 1) % 12]);
+        if arc_forward(cusps_deg[i], lon) < width {
+            return (i as u8) + 1;
+        }
+    }
+    // Degenerate cusps (all coincident): assign to house 1.
+    1
+}
+
+/// Bhava strength of a planet, from 0.0 to 1.0.
+///
+/// The planet is placed by the bhava bounds (`start_deg`..`end_deg`, the
+/// sandhis). Strength is zero at either bound and full at `madhya_deg`,
+/// rising and falling linearly over each half, so a madhya off the midpoint
+/// (Sripati) gives unequal halves.
+/// `planet_lon` must share the bhavas' frame (tropical or sidereal).
+pub fn planet_bhava_strength(result: &BhavaResult, planet_lon: f64) -> f64 {
+    let starts = result.bhavas.map(|b| b.start_deg);
+    let bhava = &result.bhavas[house_of_longitude(planet_lon, &starts) as usize - 1];
+    let offset = arc_forward(bhava.start_deg, planet_lon);
+    let rising = arc_forward(bhava.start_deg, bhava.madhya_deg);
+    let falling = arc_forward(bhava.madhya_deg, bhava.end_deg);
+    if offset <= rising {
+        if rising <= 0.0 { 1.0 } else { offset / rising }
+    } else if falling <= 0.0 {
+        1.0
+    } else {
+        (1.0 - (offset - rising) / falling).max(0.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -624,6 +716,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn house_of_longitude_unequal_and_wrapping() {
+        let mut cusps = compute_equal(350.0);
+        cusps[1] = 35.0; // widen house 1, shrink house 2
+        assert_eq!(house_of_longitude(350.0, &cusps), 1);
+        assert_eq!(house_of_longitude(5.0, &cusps), 1);
+        assert_eq!(house_of_longitude(34.999, &cusps), 1);
+        assert_eq!(house_of_longitude(35.0, &cusps), 2);
+        assert_eq!(house_of_longitude(349.999, &cusps), 12);
+        assert_eq!(house_of_longitude(-10.0, &cusps), 1);
+    }
+
     #[test]
     fn equal_cusps_wrap_around() {
         let cusps = compute_equal(350.0);
@@ -633,8 +737,8 @@ mod tests {
     }
 
     #[test]
-    fn sripati_angular_cusps() {
-        let cusps = compute_sripati(90.0, 0.0);
+    fn porphyry_angular_cusps() {
+        let cusps = compute_porphyry(90.0, 0.0);
         // cusp 1 = Asc = 90
         assert!((cusps[0] - 90.0).abs() < 1e-10);
         // cusp 10 = MC = 0
@@ -646,10 +750,10 @@ mod tests {
     }
 
     #[test]
-    fn sripati_trisection() {
+    fn porphyry_trisection() {
         let asc = 90.0;
         let mc = 0.0;
-        let cusps = compute_sripati(asc, mc);
+        let cusps = compute_porphyry(asc, mc);
 
         // Asc -> IC = 90 deg arc, trisected = 30 deg each
         let arc = arc_forward(asc, 180.0);
@@ -658,6 +762,169 @@ mod tests {
         assert!((cusps[2] - 150.0).abs() < 1e-10);
     }
 
+    fn bhava_result(cusps: &[f64; 12]) -> BhavaResult {
+        BhavaResult {
+            bhavas: build_bhavas(cusps, BhavaReferenceMode::StartOfFirst),
+            lagna_deg: cusps[0],
+            mc_deg: cusps[9],
+        }
+    }
+
+    #[test]
+    fn bhava_strength_zero_on_bounds_full_halfway() {
+        let result = bhava_result(&compute_equal(100.0));
+        assert!(planet_bhava_strength(&result, 130.0).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 145.0) - 1.0).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 137.5) - 0.5).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 152.5) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bhava_strength_unequal_houses_peak_at_madhya() {
+        // House 1 spans 100..140 (madhya 120), house 2 spans 140..160.
+        let mut cusps = compute_equal(100.0);
+        cusps[1] = 140.0;
+        let result = bhava_result(&cusps);
+        assert!((planet_bhava_strength(&result, 120.0) - 1.0).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 110.0) - 0.5).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 130.0) - 0.5).abs() < 1e-12);
+        assert!(planet_bhava_strength(&result, 140.0).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 150.0) - 1.0).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 155.0) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bhava_strength_follows_reference_mode_bounds() {
+        let cusps = compute_equal(100.0);
+        let start = bhava_result(&cusps);
+        let middle = BhavaResult {
+            bhavas: build_bhavas(&cusps, BhavaReferenceMode::MiddleOfFirst),
+            ..start
+        };
+        // StartOfFirst: the cusp is a sandhi. MiddleOfFirst: it is the madhya.
+        assert!(planet_bhava_strength(&start, 100.0).abs() < 1e-12);
+        assert!((planet_bhava_strength(&middle, 100.0) - 1.0).abs() < 1e-12);
+        assert!((planet_bhava_strength(&start, 115.0) - 1.0).abs() < 1e-12);
+        assert!(planet_bhava_strength(&middle, 115.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bhava_strength_off_center_sripati_madhya() {
+        let bhava = Bhava {
+            number: 1,
+            cusp_deg: 100.0,
+            start_deg: 100.0,
+            end_deg: 130.0,
+            madhya_deg: 110.0,
+        };
+        let mut bhavas = [bhava; 12];
+        for (i, b) in bhavas.iter_mut().enumerate().skip(1) {
+            b.number = i as u8 + 1;
+            b.start_deg = normalize_deg(100.0 + 30.0 * i as f64);
+            b.cusp_deg = b.start_deg;
+            b.end_deg = normalize_deg(b.start_deg + 30.0);
+            b.madhya_deg = normalize_deg(b.start_deg + 15.0);
+        }
+        let result = BhavaResult {
+            bhavas,
+            lagna_deg: 110.0,
+            mc_deg: 20.0,
+        };
+        assert!((planet_bhava_strength(&result, 110.0) - 1.0).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 105.0) - 0.5).abs() < 1e-12);
+        assert!((planet_bhava_strength(&result, 120.0) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bhava_strength_places_by_sripati_sandhis() {
+        // Asc 100, MC 10: unequal Porphyry spans on either side of each angle.
+        let madhyas = compute_porphyry(100.0, 10.0);
+        let result = BhavaResult {
+            bhavas: build_sripati_bhavas(&madhyas),
+            lagna_deg: 100.0,
+            mc_deg: 10.0,
+        };
+        for bhava in &result.bhavas {
+            assert!(planet_bhava_strength(&result, bhava.start_deg).abs() < 1e-9);
+        }
+        // Just past madhya 1 toward madhya 2 is still bhava 1 under sandhis.
+        let starts = result.bhavas.map(|b| b.start_deg);
+        let past = madhyas[0] + arc_forward(madhyas[0], madhyas[1]) * 0.4;
+        assert_eq!(house_of_longitude(past, &starts), 1);
+        assert!(planet_bhava_strength(&result, past) > 0.0);
+    }
+
+    #[test]
+    fn sripati_differs_from_porphyry_by_default() {
+        let cusps = compute_porphyry(100.0, 10.0);
+        let start = BhavaReferenceMode::default();
+        let middle = BhavaReferenceMode::MiddleOfFirst;
+        let sripati = bhavas_for_system(BhavaSystem::Sripati, &cusps, start);
+        let porphyry = bhavas_for_system(BhavaSystem::Porphyry, &cusps, start);
+        assert_ne!(sripati, porphyry);
+        // Porphyry cusps start each bhava; Sripati places them at the madhya.
+        assert_eq!(porphyry.map(|b| b.cusp_deg), cusps);
+        assert_eq!(sripati.map(|b| b.madhya_deg), cusps);
+        for (bhava, &point) in sripati.iter().zip(&cusps) {
+            assert_eq!(bhava.cusp_deg, bhava.start_deg);
+            assert!(arc_forward(bhava.start_deg, point) > 0.0);
+        }
+        // The reference mode only shifts Porphyry.
+        assert_eq!(
+            sripati,
+            bhavas_for_system(BhavaSystem::Sripati, &cusps, middle)
+        );
+        assert_eq!(
+            bhavas_for_system(BhavaSystem::Porphyry, &cusps, middle),
+            build_bhavas(&cusps, middle)
+        );
+    }
+
+    #[test]
+    fn quadrant_systems_angular_cusps_at_mid_latitude() {
+        use crate::lagna::lagna_mc_ramc_from_lst;
+        let lat = 45.0_f64.to_radians();
+        for lst_deg in [10.0_f64, 100.0, 200.0, 300.0] {
+            let (asc, mc, _) =
+                lagna_mc_ramc_from_lst(lst_deg.to_radians(), lat, OBLIQUITY_J2000_RAD);
+            let asc = normalize_deg(asc.to_degrees());
+            let mc = normalize_deg(mc.to_degrees());
+            let cusps = compute_porphyry(asc, mc);
+            let porphyry = build_bhavas(&cusps, BhavaReferenceMode::StartOfFirst);
+            let sripati = build_sripati_bhavas(&cusps);
+
+            let angles = [
+                asc,
+                normalize_deg(mc + 180.0),
+                normalize_deg(asc + 180.0),
+                mc,
+            ];
+            // Angles start Porphyry bhavas and are Sripati madhyas.
+            let points = [porphyry.map(|b| b.cusp_deg), sripati.map(|b| b.madhya_deg)];
+            for (bhavas, points) in [&porphyry, &sripati].into_iter().zip(&points) {
+                for (&house, &angle) in [0, 3, 6, 9].iter().zip(&angles) {
+                    let d = arc_forward(angle, points[house]);
+                    assert!(d.min(360.0 - d) < 1e-9, "lst {lst_deg}: cusp {}", house + 1);
+                }
+                // Monotonic: consecutive cusps advance and close the circle once.
+                let total: f64 = (0..12)
+                    .map(|i| arc_forward(bhavas[i].cusp_deg, bhavas[(i + 1) % 12].cusp_deg))
+                    .sum();
+                assert!((total - 360.0).abs() < 1e-9, "lst {lst_deg}: total {total}");
+            }
+            for i in 0..12 {
+                assert_eq!(porphyry[i].start_deg, porphyry[i].cusp_deg);
+                assert_eq!(sripati[i].start_deg, sripati[i].cusp_deg);
+                let next = (i + 1) % 12;
+                assert_eq!(sripati[i].end_deg, sripati[next].start_deg);
+                let half = arc_forward(cusps[i], cusps[next]) / 2.0;
+                assert!(
+                    (arc_forward(sripati[i].madhya_deg, sripati[i].end_deg) - half).abs() < 1e-9
+                );
+            }
+        }
+    }
+
     #[test]
     fn build_bhavas_continuity() {
         let cusps = compute_equal(0.0);
@@ -789,4 +1056,28 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn whole_sign_from_lagna_starts_at_lagna_rashi() {
+        let result = whole_sign_from_lagna(48.25);
+        assert_eq!(result.lagna_deg, 48.25);
+        for (i, bhava) in result.bhavas.iter().enumerate() {
+            let expected = normalize_deg(30.0 + (i as f64) * 30.0);
+            assert_eq!(bhava.number, (i as u8) + 1);
+            assert!((bhava.cusp_deg - expected).abs() < 1e-10);
+            assert!((bhava.end_deg - normalize_deg(expected + 30.0)).abs() < 1e-10);
+        }
+        assert!((result.mc_deg - 300.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn whole_sign_from_lagna_wraps_in_meena() {
+        let result = whole_sign_from_lagna(-0.5);
+        assert!((result.bhavas[0].cusp_deg - 330.0).abs() < 1e-10);
+        assert!(result.bhavas[1].cusp_deg.abs() < 1e-10);
+        assert_eq!(
+            house_of_longitude(359.9, &result.bhavas.map(|b| b.cusp_deg)),
+            1
+        );
+    }
 }
@@ -1,19 +1,20 @@
 //! Types for bhava (house) system computation.
 //!
-//! Provides enums for 10 house systems, configuration types, and result types
+//! Provides enums for 11 house systems, configuration types, and result types
 //! used by the bhava computation module.
 
 use dhruv_core::Body;
 use dhruv_vedic_math::ChandraBeneficRule;
 
-/// The 10 supported house division systems.
+/// The 11 supported house division systems.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BhavaSystem {
     /// Equal houses: each house spans exactly 30 degrees.
     Equal,
     /// Surya Siddhanta equal houses (same algorithm as Equal, traditional name).
     SuryaSiddhanta,
-    /// Sripati (Porphyry): trisect the four quadrant arcs.
+    /// Sripati: the Porphyry points are bhava madhyas and the sandhis (bhava
+    /// boundaries) fall midway between them, whatever the reference mode.
     Sripati,
     /// KP (Krishnamurti Paddhati), uses Placidus algorithm.
     KP,
@@ -29,10 +30,12 @@ pub enum BhavaSystem {
     Topocentric,
     /// Alcabitus: semi-arc equator division + projection.
     Alcabitus,
+    /// Porphyry: trisect the four quadrant arcs; each cusp starts its bhava.
+    Porphyry,
 }
 
-/// All 10 bhava systems in enum order, for FFI indexing.
-pub const ALL_BHAVA_SYSTEMS: [BhavaSystem; 10] = [
+/// All 11 bhava systems in enum order, for FFI indexing.
+pub const ALL_BHAVA_SYSTEMS: [BhavaSystem; 11] = [
     BhavaSystem::Equal,
     BhavaSystem::SuryaSiddhanta,
     BhavaSystem::Sripati,
@@ -43,10 +46,11 @@ pub const ALL_BHAVA_SYSTEMS: [BhavaSystem; 10] = [
     BhavaSystem::AxialRotation,
     BhavaSystem::Topocentric,
     BhavaSystem::Alcabitus,
+    BhavaSystem::Porphyry,
 ];
 
 impl BhavaSystem {
-    /// All 10 defined bhava systems.
+    /// All 11 defined bhava systems.
     pub const fn all() -> &'static [BhavaSystem] {
         &ALL_BHAVA_SYSTEMS
     }
@@ -90,7 +94,8 @@ pub enum BhavaReferenceMode {
     /// The starting point is at the cusp (beginning) of bhava 1.
     #[default]
     StartOfFirst,
-    /// The starting point is at the middle of bhava 1.
+    /// The starting point is at the middle of bhava 1. Sripati already has
+    /// its points at the madhyas and ignores this setting.
     MiddleOfFirst,
 }
 
@@ -161,12 +166,15 @@ impl Default for BhavaConfig {
 pub struct Bhava {
     /// House number, 1-12.
     pub number: u8,
-    /// Ecliptic longitude of the cusp in degrees, [0, 360).
+    /// Ecliptic longitude of the cusp in degrees, [0, 360). Equals `start_deg`.
     pub cusp_deg: f64,
     /// Start of this bhava in degrees, [0, 360).
     pub start_deg: f64,
     /// End of this bhava in degrees, [0, 360). Equals next bhava's start.
     pub end_deg: f64,
+    /// Bhava madhya (middle) in degrees, [0, 360). Halfway from start to end,
+    /// except for Sripati, where it is the Porphyry point.
+    pub madhya_deg: f64,
 }
 
 /// Full result of a bhava computation: 12 bhavas plus Lagna/MC.
@@ -191,7 +199,7 @@ mod tests {
 
     #[test]
     fn all_systems_count() {
-        assert_eq!(BhavaSystem::all().len(), 10);
+        assert_eq!(BhavaSystem::all().len(), 11);
     }
 
     #[test]
@@ -228,6 +236,7 @@ mod tests {
         assert!(BhavaSystem::Equal.is_equal_division());
         assert!(BhavaSystem::SuryaSiddhanta.is_equal_division());
         assert!(!BhavaSystem::Sripati.is_equal_division());
+        assert!(!BhavaSystem::Porphyry.is_equal_division());
         assert!(!BhavaSystem::KP.is_equal_division());
     }
 
@@ -239,6 +248,7 @@ mod tests {
         assert!(BhavaSystem::Alcabitus.latitude_dependent());
         assert!(!BhavaSystem::Equal.latitude_dependent());
         assert!(!BhavaSystem::Sripati.latitude_dependent());
+        assert!(!BhavaSystem::Porphyry.latitude_dependent());
         assert!(!BhavaSystem::Regiomontanus.latitude_dependent());
         assert!(!BhavaSystem::Campanus.latitude_dependent());
         assert!(!BhavaSystem::AxialRotation.latitude_dependent());
@@ -0,0 +1,76 @@
+//! Mapping between Vedic grahas and ephemeris bodies.
+//!
+//! `Graha` lives in the engine-free math crate, so the bridge to
+//! [`dhruv_core::Body`] is provided here as an extension trait. Rahu and
+//! Ketu are computed lunar nodes, not ephemeris bodies, and map to `None`.
+
+use dhruv_core::Body;
+use dhruv_vedic_math::Graha;
+
+/// Conversions between [`Graha`] and [`Body`] for the nine grahas.
+pub trait GrahaBodyExt: Sized {
+    /// Ephemeris body for this graha (`None` for Rahu/Ketu).
+    fn to_body(self) -> Option<Body>;
+
+    /// Graha for an ephemeris body (`None` for bodies outside the sapta grahas).
+    fn from_body(body: Body) -> Option<Self>;
+}
+
+impl GrahaBodyExt for Graha {
+    fn to_body(self) -> Option<Body> {
+        match self {
+            Graha::Surya => Some(Body::Sun),
+            Graha::Chandra => Some(Body::Moon),
+            Graha::Mangal => Some(Body::Mars),
+            Graha::Buddh => Some(Body::Mercury),
+            Graha::Guru => Some(Body::Jupiter),
+            Graha::Shukra => Some(Body::Venus),
+            Graha::Shani => Some(Body::Saturn),
+            Graha::Rahu | Graha::Ketu => None,
+        }
+    }
+
+    fn from_body(body: Body) -> Option<Self> {
+        match body {
+            Body::Sun => Some(Graha::Surya),
+            Body::Moon => Some(Graha::Chandra),
+            Body::Mars => Some(Graha::Mangal),
+            Body::Mercury => Some(Graha::Buddh),
+            Body::Jupiter => Some(Graha::Guru),
+            Body::Venus => Some(Graha::Shukra),
+            Body::Saturn => Some(Graha::Shani),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dhruv_vedic_math::ALL_GRAHAS;
+
+    #[test]
+    fn luminaries_round_trip() {
+        assert_eq!(Graha::Surya.to_body(), Some(Body::Sun));
+        assert_eq!(Graha::Chandra.to_body(), Some(Body::Moon));
+        assert_eq!(Graha::from_body(Body::Sun), Some(Graha::Surya));
+        assert_eq!(Graha::from_body(Body::Moon), Some(Graha::Chandra));
+    }
+
+    #[test]
+    fn nodes_have_no_body() {
+        assert_eq!(Graha::Rahu.to_body(), None);
+        assert_eq!(Graha::Ketu.to_body(), None);
+    }
+
+    #[test]
+    fn sapta_grahas_round_trip() {
+        for graha in ALL_GRAHAS {
+            if let Some(body) = graha.to_body() {
+                assert_eq!(Graha::from_body(body), Some(graha));
+            }
+        }
+        assert_eq!(Graha::from_body(Body::Earth), None);
+        assert_eq!(Graha::from_body(Body::Pluto), None);
+    }
+}
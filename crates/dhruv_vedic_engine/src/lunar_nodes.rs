@@ -87,9 +87,17 @@ pub fn mean_rahu_deg(t: f64) -> f64 {
     normalize_deg(args[4].to_degrees())
 }
 
+/// Ketu longitude from a Rahu longitude in the same frame, in degrees [0, 360).
+///
+/// Single definition of Ketu: every path derives it from its final Rahu
+/// value (after ayanamsha/nutation), so Ketu is exactly Rahu + 180 deg.
+pub fn ketu_from_rahu(rahu_deg: f64) -> f64 {
+    normalize_deg(rahu_deg + 180.0)
+}
+
 /// Mean Ketu (descending node) ecliptic longitude in degrees [0, 360).
 pub fn mean_ketu_deg(t: f64) -> f64 {
-    normalize_deg(mean_rahu_deg(t) + 180.0)
+    ketu_from_rahu(mean_rahu_deg(t))
 }
 
 /// Short-period perturbation correction for the true (osculating) node, in
@@ -188,7 +196,28 @@ pub fn true_rahu_deg(t: f64) -> f64 {
 
 /// True Ketu (descending node) ecliptic longitude in degrees [0, 360).
 pub fn true_ketu_deg(t: f64) -> f64 {
-    normalize_deg(true_rahu_deg(t) + 180.0)
+    ketu_from_rahu(true_rahu_deg(t))
+}
+
+/// Rahu longitude rate in degrees per day.
+///
+/// `t` = Julian centuries of TDB since J2000.0. Central difference of the
+/// pure-math node longitude over ±1 minute. The mean node regresses at a
+/// near-constant ≈ −0.053 deg/day; the true node oscillates around that rate
+/// and briefly turns direct (positive) near its stations. Ketu moves at the
+/// same rate.
+pub fn node_speed_deg_per_day(t: f64, mode: NodeMode) -> f64 {
+    const DT_DAYS: f64 = 1.0 / 1440.0;
+    let dt = DT_DAYS / 36525.0;
+    let plus = lunar_node_deg(LunarNode::Rahu, t + dt, mode);
+    let minus = lunar_node_deg(LunarNode::Rahu, t - dt, mode);
+    let mut diff = (plus - minus) % 360.0;
+    if diff > 180.0 {
+        diff -= 360.0;
+    } else if diff < -180.0 {
+        diff += 360.0;
+    }
+    diff / (2.0 * DT_DAYS)
 }
 
 fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
@@ -317,7 +346,7 @@ pub fn lunar_node_deg_for_epoch_with_model(
 
     let out = match node {
         LunarNode::Rahu => rahu,
-        LunarNode::Ketu => normalize_deg(rahu + 180.0),
+        LunarNode::Ketu => ketu_from_rahu(rahu),
     };
     Ok(out)
 }
@@ -346,7 +375,7 @@ pub fn lunar_node_deg_for_epoch_on_plane(
 
     let out = match node {
         LunarNode::Rahu => rahu,
-        LunarNode::Ketu => normalize_deg(rahu + 180.0),
+        LunarNode::Ketu => ketu_from_rahu(rahu),
     };
     Ok(out)
 }
@@ -477,4 +506,35 @@ mod tests {
             true_ketu_deg(t)
         );
     }
+
+    #[test]
+    fn mean_node_speed_is_constant_negative() {
+        let v0 = node_speed_deg_per_day(0.0, NodeMode::Mean);
+        assert!((v0 - (-0.0529)).abs() < 0.001, "mean node speed = {v0}");
+        for &t in &[-1.0, -0.3, 0.24, 1.0] {
+            let v = node_speed_deg_per_day(t, NodeMode::Mean);
+            assert!((v - v0).abs() < 1e-5, "t={t}: mean speed {v} vs {v0}");
+        }
+    }
+
+    #[test]
+    fn true_node_speed_oscillates_around_mean() {
+        // Sample one year at half-day steps starting 2024-01-01.
+        let t0 = 0.24;
+        let mean = node_speed_deg_per_day(t0, NodeMode::Mean);
+        let samples: Vec<f64> = (0..730)
+            .map(|i| node_speed_deg_per_day(t0 + i as f64 * 0.5 / 36525.0, NodeMode::True))
+            .collect();
+        let avg = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!(
+            (avg - mean).abs() < 0.01,
+            "avg true speed {avg} vs mean {mean}"
+        );
+        assert!(samples.iter().any(|&v| v > mean), "never faster than mean");
+        assert!(samples.iter().any(|&v| v < mean), "never slower than mean");
+        assert!(
+            samples.iter().any(|&v| v > 0.0),
+            "true node never turned direct"
+        );
+    }
 }
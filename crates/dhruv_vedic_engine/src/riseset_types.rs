@@ -5,6 +5,8 @@
 
 use std::f64::consts::PI;
 
+use dhruv_time::{LeapSecondKernel, LocalTime, UtcTime};
+
 /// Mean Earth radius in meters (IAU nominal, for geometric dip).
 const EARTH_RADIUS_M: f64 = 6_371_000.0;
 
@@ -106,37 +108,154 @@ pub enum SunLimb {
     LowerLimb,
 }
 
+/// How Vedic-day computations (vaar, hora, ghatika, panchang) bound the day
+/// when the Sun does not rise, as in polar night or midnight sun.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DayDefinitionFallback {
+    /// Report an error: no sunrise means no Vedic day. This is the
+    /// traditional definition and the default.
+    #[default]
+    Error,
+    /// Start the day at civil dawn (Sun center at -6 deg). Still an error
+    /// when the Sun stays below -6 deg all day or never sets.
+    CivilTwilight,
+    /// Start the day at 06:00 local mean solar time, derived from longitude.
+    /// Always defined.
+    LocalSixAm,
+}
+
+/// Atmospheric refraction model for the sunrise/sunset horizon.
+///
+/// Each model yields the refraction at the horizon in arcminutes. Bennett
+/// and Saemundsson scale by `(P / 1010 hPa) * (283 K / (273 + T))`, so the
+/// standard 1010 hPa / 10 C gives their textbook horizon values.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RefractionModel {
+    /// Fixed 34 arcmin, the conventional almanac value.
+    #[default]
+    Standard,
+    /// Bennett (1982) at apparent altitude 0: about 34.5 arcmin at standard
+    /// conditions.
+    Bennett {
+        /// Air pressure in hectopascals.
+        pressure_hpa: f64,
+        /// Air temperature in degrees Celsius.
+        temperature_c: f64,
+    },
+    /// Saemundsson (1986), evaluated at the true altitude `-R` of an object
+    /// on the apparent horizon: about 34.4 arcmin at standard conditions.
+    Saemundsson {
+        /// Air pressure in hectopascals.
+        pressure_hpa: f64,
+        /// Air temperature in degrees Celsius.
+        temperature_c: f64,
+    },
+    /// Caller-supplied horizon refraction in arcminutes.
+    Custom {
+        /// Refraction in arcminutes.
+        arcmin: f64,
+    },
+}
+
+/// Standard atmospheric refraction at the horizon in arcminutes.
+const STANDARD_REFRACTION_ARCMIN: f64 = 34.0;
+/// Reference pressure for the Bennett/Saemundsson formulas.
+const STANDARD_PRESSURE_HPA: f64 = 1010.0;
+/// Reference temperature for the Bennett/Saemundsson formulas.
+const STANDARD_TEMPERATURE_C: f64 = 10.0;
+/// Ratio of solar horizontal parallax to solar semidiameter (8.794148" /
+/// 959.63" at 1 AU). Both scale as 1/distance, so the ratio is constant.
+const SOLAR_PARALLAX_PER_SEMIDIAMETER: f64 = 8.794_148 / 959.63;
+
+impl RefractionModel {
+    /// Bennett model at standard pressure and temperature.
+    pub fn bennett() -> Self {
+        Self::Bennett {
+            pressure_hpa: STANDARD_PRESSURE_HPA,
+            temperature_c: STANDARD_TEMPERATURE_C,
+        }
+    }
+
+    /// Saemundsson model at standard pressure and temperature.
+    pub fn saemundsson() -> Self {
+        Self::Saemundsson {
+            pressure_hpa: STANDARD_PRESSURE_HPA,
+            temperature_c: STANDARD_TEMPERATURE_C,
+        }
+    }
+
+    /// Refraction at the horizon in arcminutes.
+    pub fn horizon_refraction_arcmin(self) -> f64 {
+        match self {
+            Self::Standard => STANDARD_REFRACTION_ARCMIN,
+            Self::Bennett {
+                pressure_hpa,
+                temperature_c,
+            } => {
+                // R = cot(h + 7.31 / (h + 4.4)) with apparent h = 0.
+                let r = 1.0 / (7.31_f64 / 4.4).to_radians().tan();
+                r * atmosphere_factor(pressure_hpa, temperature_c)
+            }
+            Self::Saemundsson {
+                pressure_hpa,
+                temperature_c,
+            } => {
+                // R = 1.02 cot(h + 10.3 / (h + 5.11)) with true h = -R;
+                // the fixed point converges in a few steps.
+                let mut r = STANDARD_REFRACTION_ARCMIN;
+                for _ in 0..8 {
+                    let h = -r / 60.0;
+                    r = 1.02 / (h + 10.3 / (h + 5.11)).to_radians().tan();
+                }
+                r * atmosphere_factor(pressure_hpa, temperature_c)
+            }
+            Self::Custom { arcmin } => arcmin,
+        }
+    }
+}
+
+fn atmosphere_factor(pressure_hpa: f64, temperature_c: f64) -> f64 {
+    (pressure_hpa / STANDARD_PRESSURE_HPA) * (283.0 / (273.0 + temperature_c))
+}
+
 /// Configurable parameters for rise/set computation.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RiseSetConfig {
-    /// Apply standard atmospheric refraction (34 arcmin). Default: true.
+    /// Apply atmospheric refraction per `refraction_model`. `false` puts
+    /// the limb on the geometric horizon. Default: true.
     pub use_refraction: bool,
+    /// Refraction model used when `use_refraction` is set.
+    /// Default: `Standard` (34 arcmin).
+    pub refraction_model: RefractionModel,
     /// Which solar limb defines sunrise/sunset. Default: UpperLimb.
     pub sun_limb: SunLimb,
     /// Whether to apply geometric dip correction for observer altitude.
     /// Dip angle = arccos(R / (R + h)) where R = Earth radius, h = altitude.
     /// Default: true.
     pub altitude_correction: bool,
+    /// Vedic-day boundary used when the Sun does not rise. Not used by
+    /// `compute_rise_set` itself. Default: `Error`.
+    pub day_fallback: DayDefinitionFallback,
 }
 
 impl Default for RiseSetConfig {
     fn default() -> Self {
         Self {
             use_refraction: true,
+            refraction_model: RefractionModel::Standard,
             sun_limb: SunLimb::UpperLimb,
             altitude_correction: true,
+            day_fallback: DayDefinitionFallback::Error,
         }
     }
 }
 
-/// Standard atmospheric refraction at the horizon in arcminutes.
-const STANDARD_REFRACTION_ARCMIN: f64 = 34.0;
-
 impl RiseSetConfig {
     /// Target altitude of the Sun's center at the event, in degrees.
     ///
     /// For sunrise/sunset events, combines refraction, solar semidiameter
-    /// (passed in from the ephemeris), and geometric dip from altitude.
+    /// (passed in from the ephemeris), solar horizontal parallax, and — for
+    /// observers above sea level — geometric horizon dip.
     ///
     /// For twilight events, returns the standard IAU depression angle
     /// (negative altitude), ignoring refraction/semidiameter/limb.
@@ -158,7 +277,7 @@ impl RiseSetConfig {
         }
 
         let refraction = if self.use_refraction {
-            STANDARD_REFRACTION_ARCMIN
+            self.refraction_model.horizon_refraction_arcmin()
         } else {
             0.0
         };
@@ -173,10 +292,12 @@ impl RiseSetConfig {
             SunLimb::LowerLimb => -semidiameter_arcmin,
         };
 
-        // Base depression in degrees (negative altitude)
-        let base = -(refraction + sd_contrib) / 60.0;
+        // Parallax: the target is geocentric, so the Sun's center must sit
+        // slightly higher than the topocentric horizon target at any height.
+        let parallax_deg = semidiameter_arcmin * SOLAR_PARALLAX_PER_SEMIDIAMETER / 60.0;
+        let base = -(refraction + sd_contrib) / 60.0 + parallax_deg;
 
-        // Geometric dip correction
+        // Geometric dip lowers the visible horizon for elevated observers.
         if self.altitude_correction && altitude_m > 0.0 {
             let dip_rad = (2.0 * altitude_m / EARTH_RADIUS_M).sqrt();
             base - dip_rad * (180.0 / PI)
@@ -197,10 +318,80 @@ pub enum RiseSetResult {
     NeverSets,
 }
 
+impl RiseSetResult {
+    /// Event instant as UTC, or `None` for `NeverRises`/`NeverSets`.
+    pub fn to_utc(&self, lsk: &LeapSecondKernel) -> Option<UtcTime> {
+        match *self {
+            Self::Event { jd_tdb, .. } => Some(UtcTime::from_jd_tdb(jd_tdb, lsk)),
+            Self::NeverRises | Self::NeverSets => None,
+        }
+    }
+
+    /// Event instant as local civil time at a fixed offset from UTC.
+    ///
+    /// `utc_offset_hours` is east-positive (e.g. `5.5` for IST, `-5.0` for
+    /// EST). Returns `None` for `NeverRises`/`NeverSets`.
+    pub fn to_local(&self, lsk: &LeapSecondKernel, utc_offset_hours: f64) -> Option<LocalTime> {
+        self.to_utc(lsk)
+            .map(|utc| LocalTime::from_utc(&utc, utc_offset_hours))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn test_lsk() -> LeapSecondKernel {
+        let content = r#"
+\begindata
+DELTET/DELTA_T_A = 32.184
+DELTET/K         = 1.657D-3
+DELTET/EB        = 1.671D-2
+DELTET/M         = ( 6.239996 1.99096871D-7 )
+DELTET/DELTA_AT  = ( 10, @1972-JAN-1
+                     37, @2017-JAN-1 )
+\begintext
+"#;
+        LeapSecondKernel::parse(content).unwrap()
+    }
+
+    /// New Delhi sunrise on 2024-03-20 at 00:54:30 UTC (06:24:30 IST).
+    fn delhi_equinox_sunrise() -> RiseSetResult {
+        let jd_utc = 2_460_389.5 + (54.0 * 60.0 + 30.0) / 86_400.0;
+        // TT - UTC = 69.184 s in 2024; TDB - TT is below 2 ms.
+        RiseSetResult::Event {
+            jd_tdb: jd_utc + 69.184 / 86_400.0,
+            event: RiseSetEvent::Sunrise,
+        }
+    }
+
+    #[test]
+    fn event_to_utc_minute() {
+        let utc = delhi_equinox_sunrise().to_utc(&test_lsk()).unwrap();
+        assert_eq!((utc.year, utc.month, utc.day), (2024, 3, 20));
+        assert_eq!((utc.hour, utc.minute), (0, 54));
+        assert!((utc.second - 30.0).abs() < 0.01, "second = {}", utc.second);
+    }
+
+    #[test]
+    fn event_to_local_minute() {
+        let lsk = test_lsk();
+        let ist = delhi_equinox_sunrise().to_local(&lsk, 5.5).unwrap();
+        assert_eq!((ist.day, ist.hour, ist.minute), (20, 6, 24));
+        assert_eq!(ist.utc_offset_hours, 5.5);
+        assert!((ist.second - 30.0).abs() < 0.01, "second = {}", ist.second);
+        // Negative offsets roll back across the date line.
+        let est = delhi_equinox_sunrise().to_local(&lsk, -5.0).unwrap();
+        assert_eq!((est.day, est.hour, est.minute), (19, 19, 54));
+    }
+
+    #[test]
+    fn polar_results_have_no_time() {
+        let lsk = test_lsk();
+        assert!(RiseSetResult::NeverRises.to_utc(&lsk).is_none());
+        assert!(RiseSetResult::NeverSets.to_local(&lsk, 1.0).is_none());
+    }
+
     #[test]
     fn depression_civil() {
         assert_eq!(RiseSetEvent::CivilDawn.depression_deg(), 6.0);
@@ -250,13 +441,16 @@ mod tests {
         assert!(c.altitude_correction);
     }
 
+    /// Solar horizontal parallax in degrees for a 16' semidiameter (~8.8").
+    const PARALLAX_16: f64 = 16.0 * SOLAR_PARALLAX_PER_SEMIDIAMETER / 60.0;
+
     #[test]
     fn target_altitude_upper_limb_rising() {
         let c = RiseSetConfig::default();
         // Typical semidiameter ~16 arcmin
         let h = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 0.0);
-        // -(34 + 16) / 60 = -0.8333 deg
-        let expected = -(34.0 + 16.0) / 60.0;
+        // -(34 + 16) / 60 = -0.8333 deg, raised by parallax
+        let expected = -(34.0 + 16.0) / 60.0 + PARALLAX_16;
         assert!(
             (h - expected).abs() < 1e-10,
             "upper limb rising: {h}, expected {expected}"
@@ -267,8 +461,8 @@ mod tests {
     fn target_altitude_upper_limb_setting() {
         let c = RiseSetConfig::default();
         let h = c.target_altitude_deg(RiseSetEvent::Sunset, 16.0, 0.0);
-        // Same as rising for UpperLimb: -(34 + 16) / 60
-        let expected = -(34.0 + 16.0) / 60.0;
+        // Same as rising for UpperLimb: -(34 + 16) / 60, plus parallax
+        let expected = -(34.0 + 16.0) / 60.0 + PARALLAX_16;
         assert!(
             (h - expected).abs() < 1e-10,
             "upper limb setting: {h}, expected {expected}"
@@ -282,8 +476,8 @@ mod tests {
             ..Default::default()
         };
         let h = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 0.0);
-        // Center: -(34 + 0) / 60 = -0.5667 deg
-        let expected = -34.0 / 60.0;
+        // Center: -(34 + 0) / 60 = -0.5667 deg, plus parallax
+        let expected = -34.0 / 60.0 + PARALLAX_16;
         assert!(
             (h - expected).abs() < 1e-10,
             "center: {h}, expected {expected}"
@@ -297,8 +491,8 @@ mod tests {
             ..Default::default()
         };
         let h = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 0.0);
-        // LowerLimb rising: -(34 + (-16)) / 60 = -(18)/60 = -0.3 deg
-        let expected = -(34.0 - 16.0) / 60.0;
+        // LowerLimb rising: -(34 + (-16)) / 60 = -(18)/60 = -0.3 deg, plus parallax
+        let expected = -(34.0 - 16.0) / 60.0 + PARALLAX_16;
         assert!(
             (h - expected).abs() < 1e-10,
             "lower limb: {h}, expected {expected}"
@@ -312,14 +506,53 @@ mod tests {
             ..Default::default()
         };
         let h = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 0.0);
-        // No refraction, UpperLimb: -(0 + 16) / 60 = -0.2667 deg
-        let expected = -16.0 / 60.0;
+        // No refraction, UpperLimb: -(0 + 16) / 60 = -0.2667 deg, plus parallax
+        let expected = -16.0 / 60.0 + PARALLAX_16;
         assert!(
             (h - expected).abs() < 1e-10,
             "no refraction: {h}, expected {expected}"
         );
     }
 
+    #[test]
+    fn refraction_models_at_standard_conditions() {
+        let bennett = RefractionModel::bennett().horizon_refraction_arcmin();
+        let saem = RefractionModel::saemundsson().horizon_refraction_arcmin();
+        assert!((bennett - 34.48).abs() < 0.05, "bennett = {bennett}");
+        assert!((saem - 34.4).abs() < 0.1, "saemundsson = {saem}");
+        let custom = RefractionModel::Custom { arcmin: 35.4 };
+        assert_eq!(custom.horizon_refraction_arcmin(), 35.4);
+    }
+
+    #[test]
+    fn refraction_scales_with_pressure_and_temperature() {
+        let thin = RefractionModel::Bennett {
+            pressure_hpa: 505.0,
+            temperature_c: 10.0,
+        };
+        let cold = RefractionModel::Bennett {
+            pressure_hpa: 1010.0,
+            temperature_c: -20.0,
+        };
+        let std = RefractionModel::bennett().horizon_refraction_arcmin();
+        assert!((thin.horizon_refraction_arcmin() - std / 2.0).abs() < 1e-10);
+        assert!(cold.horizon_refraction_arcmin() > std);
+    }
+
+    #[test]
+    fn no_refraction_center_is_geometric_horizon_plus_parallax() {
+        let c = RiseSetConfig {
+            use_refraction: false,
+            refraction_model: RefractionModel::bennett(),
+            sun_limb: SunLimb::Center,
+            ..Default::default()
+        };
+        assert_eq!(
+            c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 0.0),
+            PARALLAX_16
+        );
+    }
+
     #[test]
     fn target_altitude_twilight_ignores_config() {
         let c = RiseSetConfig {
@@ -335,7 +568,7 @@ mod tests {
     fn target_altitude_with_dip_1000m() {
         let c = RiseSetConfig::default();
         let h = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 1000.0);
-        let base = -(34.0 + 16.0) / 60.0;
+        let base = -(34.0 + 16.0) / 60.0 + PARALLAX_16;
         // Dip at 1000m ≈ 1.015 deg
         assert!(
             h < base - 0.9,
@@ -345,6 +578,31 @@ mod tests {
         assert!(h > base - 1.2, "1000m altitude: {h} too negative");
     }
 
+    #[test]
+    fn target_altitude_dip_and_parallax_3000m() {
+        let c = RiseSetConfig::default();
+        let h = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 3000.0);
+        let dip = (2.0 * 3000.0 / EARTH_RADIUS_M).sqrt().to_degrees();
+        // Parallax for a 16' semidiameter ≈ 8.8"
+        assert!((PARALLAX_16 * 3600.0 - 8.8).abs() < 0.1);
+        let expected = -(34.0 + 16.0) / 60.0 - dip + PARALLAX_16;
+        assert!(
+            (h - expected).abs() < 1e-12,
+            "3000m: {h}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn target_altitude_parallax_at_sea_level() {
+        let c = RiseSetConfig::default();
+        let sea = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 0.0);
+        assert!((sea - (-(34.0 + 16.0) / 60.0 + PARALLAX_16)).abs() < 1e-12);
+        // No parallax jump between sea level and 1 m: only the tiny dip.
+        let one_m = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 1.0);
+        let dip_1m = (2.0 / EARTH_RADIUS_M).sqrt().to_degrees();
+        assert!((sea - one_m - dip_1m).abs() < 1e-12);
+    }
+
     #[test]
     fn target_altitude_no_altitude_correction() {
         let c = RiseSetConfig {
@@ -352,7 +610,7 @@ mod tests {
             ..Default::default()
         };
         let h = c.target_altitude_deg(RiseSetEvent::Sunrise, 16.0, 10000.0);
-        let expected = -(34.0 + 16.0) / 60.0;
+        let expected = -(34.0 + 16.0) / 60.0 + PARALLAX_16;
         assert!(
             (h - expected).abs() < 1e-10,
             "no altitude correction: {h}, expected {expected}"
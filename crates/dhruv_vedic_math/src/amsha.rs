@@ -7,7 +7,8 @@
 //! Clean-room implementation from BPHS Shodashavarga definitions.
 //! See `docs/clean_room_amsha.md`.
 
-use crate::rashi::{RashiInfo, rashi_from_longitude};
+use crate::graha::{Graha, rashi_lord_by_index};
+use crate::rashi::{Rashi, RashiInfo, rashi_from_longitude};
 use crate::util::normalize_360;
 
 // ---------------------------------------------------------------------------
@@ -577,19 +578,8 @@ fn amsha_target_rashi(
         Amsha::D7 => increment_start(natal_rashi_idx, div_idx, 6),
         Amsha::D10 => increment_start(natal_rashi_idx, div_idx, 8),
 
-        // D24: odd signs start from Simha(4), even signs from Karka(3)
-        Amsha::D24 => {
-            let is_odd_rashi = natal_rashi_idx.is_multiple_of(2);
-            let start: u16 = if is_odd_rashi { 4 } else { 3 };
-            ((start + div_idx) % 12) as u8
-        }
-
-        // D40: odd signs start from Mesha(0), even signs from Tula(6)
-        Amsha::D40 => {
-            let is_odd_rashi = natal_rashi_idx.is_multiple_of(2);
-            let start: u16 = if is_odd_rashi { 0 } else { 6 };
-            ((start + div_idx) % 12) as u8
-        }
+        Amsha::D24 => ((chaturvimsamsa_start(natal_rashi_idx) + div_idx) % 12) as u8,
+        Amsha::D40 => ((khavedamsa_start(natal_rashi_idx) + div_idx) % 12) as u8,
 
         // FEAW amshas: element-based fixed starting rashi
         Amsha::D9 | Amsha::D27 => {
@@ -670,6 +660,24 @@ fn amsha_target_rashi(
     }
 }
 
+/// D24 starting rashi: Simha(4) for odd signs, Karka(3) for even signs.
+fn chaturvimsamsa_start(natal_rashi_idx: u8) -> u16 {
+    if natal_rashi_idx.is_multiple_of(2) {
+        4
+    } else {
+        3
+    }
+}
+
+/// D40 starting rashi: Mesha(0) for odd signs, Tula(6) for even signs.
+fn khavedamsa_start(natal_rashi_idx: u8) -> u16 {
+    if natal_rashi_idx.is_multiple_of(2) {
+        0
+    } else {
+        6
+    }
+}
+
 /// Helper for INCREMENT amshas: odd rashi starts from natal, even from natal+offset.
 fn increment_start(natal_rashi_idx: u8, div_idx: u16, even_offset: u16) -> u8 {
     // 0-indexed: 0,2,4,6,8,10 are odd rashis (1-based 1,3,5,7,9,11)
@@ -715,6 +723,76 @@ fn d30_target_and_scaled_pos(pos_in_rashi: f64, is_odd_rashi: bool) -> (u8, f64)
     unreachable!("D30 segment resolution should always match within 0..30");
 }
 
+/// Deity presiding over a trimsamsa (D30) segment.
+///
+/// Follows the segment lord: Agni (Mangal), Vayu (Shani), Indra (Guru),
+/// Kubera (Buddh), Varuna (Shukra). Odd rashis run Agni to Varuna; even
+/// rashis run the reverse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrimsamsaDeity {
+    Agni,
+    Vayu,
+    Indra,
+    Kubera,
+    Varuna,
+}
+
+impl TrimsamsaDeity {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Agni => "Agni",
+            Self::Vayu => "Vayu",
+            Self::Indra => "Indra",
+            Self::Kubera => "Kubera",
+            Self::Varuna => "Varuna",
+        }
+    }
+}
+
+/// Trimsamsa (D30) lord of a sidereal longitude.
+///
+/// Uses the BPHS unequal spans: odd rashis Mangal 0-5, Shani 5-10,
+/// Guru 10-18, Buddh 18-25, Shukra 25-30; even rashis reverse both the
+/// lords and the spans (Shukra 0-5, Buddh 5-12, Guru 12-20, Shani 20-25,
+/// Mangal 25-30). The lord is that of the D30 target rashi.
+pub fn trimsamsa_lord(sidereal_lon: f64) -> Graha {
+    let lon = normalize_360(sidereal_lon);
+    let rashi_idx = (lon / 30.0).floor().min(11.0) as u8;
+    let pos_in_rashi = lon - rashi_idx as f64 * 30.0;
+    let (target_rashi_idx, _) =
+        d30_target_and_scaled_pos(pos_in_rashi, rashi_idx.is_multiple_of(2));
+    rashi_lord_by_index(target_rashi_idx).expect("D30 target rashi index is within 0..12")
+}
+
+/// Trimsamsa (D30) deity of a sidereal longitude.
+pub fn trimsamsa_deity(sidereal_lon: f64) -> TrimsamsaDeity {
+    match trimsamsa_lord(sidereal_lon) {
+        Graha::Mangal => TrimsamsaDeity::Agni,
+        Graha::Shani => TrimsamsaDeity::Vayu,
+        Graha::Guru => TrimsamsaDeity::Indra,
+        Graha::Buddh => TrimsamsaDeity::Kubera,
+        Graha::Shukra => TrimsamsaDeity::Varuna,
+        _ => unreachable!("D30 targets are ruled by the five taragrahas"),
+    }
+}
+
+/// Chaturvimsamsa (D24) rashi of a sidereal longitude.
+///
+/// The 24 parts of 1.25 deg count forward from Simha in odd rashis and from
+/// Karka in even rashis.
+pub fn chaturvimsamsa_sign(sidereal_lon: f64) -> Rashi {
+    rashi_from_longitude(amsha_longitude(sidereal_lon, Amsha::D24, None)).rashi
+}
+
+/// Khavedamsa (D40) rashi of a sidereal longitude.
+///
+/// The 40 parts of 0.75 deg count forward from Mesha in odd rashis and from
+/// Tula in even rashis. Parity alternates the starting rashi; the
+/// direction of counting is forward for both.
+pub fn khavedamsa_sign(sidereal_lon: f64) -> Rashi {
+    rashi_from_longitude(amsha_longitude(sidereal_lon, Amsha::D40, None)).rashi
+}
+
 // ---------------------------------------------------------------------------
 // Core transformation
 // ---------------------------------------------------------------------------
@@ -808,6 +886,35 @@ pub fn amsha_rashi_infos(sidereal_lon: f64, requests: &[AmshaRequest]) -> Vec<Ra
         .collect()
 }
 
+// ---------------------------------------------------------------------------
+// Vargottama
+// ---------------------------------------------------------------------------
+
+/// Where a longitude repeats its rasi (D1) sign across the Shodashavarga.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VargottamaStatus {
+    /// D9 sign equals the D1 sign (classical vargottama).
+    pub navamsa_vargottama: bool,
+    /// Shodashavarga charts (excluding D1) whose sign equals the D1 sign,
+    /// in [`SHODASHAVARGA`] order.
+    pub vargas_matching_rasi: Vec<Amsha>,
+}
+
+/// Vargottama status of a sidereal longitude, using default variations.
+pub fn vargottama_status(graha_lon: f64) -> VargottamaStatus {
+    let rasi = rashi_from_longitude(graha_lon).rashi_index;
+    let vargas_matching_rasi: Vec<Amsha> = SHODASHAVARGA
+        .iter()
+        .copied()
+        .filter(|&amsha| amsha != Amsha::D1)
+        .filter(|&amsha| amsha_rashi_info(graha_lon, amsha, None).rashi_index == rasi)
+        .collect();
+    VargottamaStatus {
+        navamsa_vargottama: vargas_matching_rasi.contains(&Amsha::D9),
+        vargas_matching_rasi,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Unit tests
 // ---------------------------------------------------------------------------
@@ -992,6 +1099,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn trimsamsa_lord_odd_sign_segments() {
+        // Mesha (odd): Mangal, Shani, Guru, Buddh, Shukra.
+        assert_eq!(trimsamsa_lord(2.5), Graha::Mangal);
+        assert_eq!(trimsamsa_lord(7.5), Graha::Shani);
+        assert_eq!(trimsamsa_lord(14.0), Graha::Guru);
+        assert_eq!(trimsamsa_lord(20.0), Graha::Buddh);
+        assert_eq!(trimsamsa_lord(29.9), Graha::Shukra);
+        assert_eq!(trimsamsa_deity(2.5), TrimsamsaDeity::Agni);
+        assert_eq!(trimsamsa_deity(29.9), TrimsamsaDeity::Varuna);
+    }
+
+    #[test]
+    fn trimsamsa_lord_even_sign_reversed() {
+        // Vrishabha (even): Shukra, Buddh, Guru, Shani, Mangal.
+        assert_eq!(trimsamsa_lord(32.5), Graha::Shukra);
+        assert_eq!(trimsamsa_lord(41.0), Graha::Buddh);
+        assert_eq!(trimsamsa_lord(46.0), Graha::Guru);
+        assert_eq!(trimsamsa_lord(52.0), Graha::Shani);
+        assert_eq!(trimsamsa_lord(57.0), Graha::Mangal);
+        assert_eq!(trimsamsa_deity(32.5), TrimsamsaDeity::Varuna);
+        assert_eq!(trimsamsa_deity(57.0), TrimsamsaDeity::Agni);
+    }
+
     #[test]
     fn d30_uses_even_segment_table() {
         let result_first = amsha_longitude(32.5, Amsha::D30, None);
@@ -1315,6 +1446,36 @@ mod tests {
         assert!((result.degrees_in_rashi - 18.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn chaturvimsamsa_counts_from_simha_for_odd_and_karka_for_even() {
+        for rashi_idx in 0..12u8 {
+            let base = rashi_idx as f64 * 30.0;
+            let (first, second) = if rashi_idx.is_multiple_of(2) {
+                (Rashi::Simha, Rashi::Kanya)
+            } else {
+                (Rashi::Karka, Rashi::Simha)
+            };
+            assert_eq!(chaturvimsamsa_sign(base + 0.5), first, "rashi {rashi_idx}");
+            assert_eq!(chaturvimsamsa_sign(base + 1.5), second, "rashi {rashi_idx}");
+        }
+    }
+
+    #[test]
+    fn khavedamsa_alternates_start_by_parity_and_counts_forward() {
+        for rashi_idx in 0..12u8 {
+            let base = rashi_idx as f64 * 30.0;
+            let start: u8 = if rashi_idx.is_multiple_of(2) { 0 } else { 6 };
+            for part in 0..40u8 {
+                let lon = base + part as f64 * 0.75 + 0.1;
+                assert_eq!(
+                    khavedamsa_sign(lon).index(),
+                    (start + part) % 12,
+                    "rashi {rashi_idx} part {part}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn d27_uses_fire_start_for_mesha() {
         let natal_lon = 0.6;
@@ -1382,4 +1543,18 @@ mod tests {
         let individual_d9 = amsha_rashi_info(lon, Amsha::D9, None);
         assert_eq!(results[0].rashi, individual_d9.rashi);
     }
+
+    #[test]
+    fn vargottama_first_navamsa_of_movable_sign() {
+        // Mesha 1°: the first navamsa of a movable sign starts from itself.
+        let status = vargottama_status(1.0);
+        assert!(status.navamsa_vargottama);
+        assert!(status.vargas_matching_rasi.contains(&Amsha::D9));
+        assert!(!status.vargas_matching_rasi.contains(&Amsha::D1));
+
+        // Mesha 5° falls in the Vrishabha navamsa.
+        let status = vargottama_status(5.0);
+        assert!(!status.navamsa_vargottama);
+        assert!(!status.vargas_matching_rasi.contains(&Amsha::D9));
+    }
 }
@@ -10,8 +10,9 @@
 //! Clean-room implementation from BPHS Ch.45 and standard Vedic texts.
 //! See `docs/clean_room_avastha.md`.
 
+use crate::combustion::graha_yuddha;
 use crate::drishti::GrahaDrishtiMatrix;
-use crate::graha::{ALL_GRAHAS, Graha};
+use crate::graha::{ALL_GRAHAS, Graha, SAPTA_GRAHAS};
 use crate::graha_relationships::{
     BeneficNature, ChandraBeneficRule, Dignity, NaisargikaMaitri,
     buddh_association_nature_with_chandra_rule, moon_benefic_nature_with_rule, naisargika_maitri,
@@ -996,7 +997,8 @@ fn aspecting_grahas(target_index: usize, drishti_matrix: &GrahaDrishtiMatrix) ->
 
 /// Planetary war loser detection.
 ///
-/// Two planets are at war when within 1 degree; the loser has lower declination.
+/// Two planets are at war when within 1 degree; the winner is decided by
+/// [`graha_yuddha`] (northernmost declination, then brightness).
 /// Only Mars/Mercury/Jupiter/Venus/Saturn participate (graha indices 2-6).
 /// `sidereal_lons` and `declinations` are for the 7 sapta grahas (indices 0-6).
 pub fn lost_planetary_war(
@@ -1004,26 +1006,20 @@ pub fn lost_planetary_war(
     sidereal_lons: &[f64; 7],
     declinations: &[f64; 7],
 ) -> bool {
-    // Only indices 2-6 (Mars, Mercury, Jupiter, Venus, Saturn) participate
-    if !(2..=6).contains(&graha_index) {
+    let Some(&graha) = SAPTA_GRAHAS.get(graha_index) else {
         return false;
-    }
-    for other_idx in 2..=6 {
-        if other_idx == graha_index {
-            continue;
-        }
-        let diff = (normalize_360(sidereal_lons[graha_index])
-            - normalize_360(sidereal_lons[other_idx]))
-        .abs();
-        let angular_distance = if diff > 180.0 { 360.0 - diff } else { diff };
-        if angular_distance < 1.0 {
-            // At war: loser has lower absolute declination
-            if declinations[graha_index].abs() < declinations[other_idx].abs() {
-                return true;
-            }
-        }
-    }
-    false
+    };
+    SAPTA_GRAHAS.iter().enumerate().any(|(other_idx, other)| {
+        graha_yuddha(
+            graha,
+            sidereal_lons[graha_index],
+            Some(declinations[graha_index]),
+            *other,
+            sidereal_lons[other_idx],
+            Some(declinations[other_idx]),
+        )
+        .is_some_and(|war| war.loser == graha)
+    })
 }
 
 // ---------------------------------------------------------------------------
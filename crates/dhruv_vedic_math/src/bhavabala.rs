@@ -423,6 +423,55 @@ mod tests {
         assert!((entry.total_rupas * 60.0 - entry.total_virupas).abs() < EPS);
     }
 
+    #[test]
+    fn strong_exalted_lord_outscores_empty_dusthana() {
+        // Equal houses from 15 Mesha. Shani, lord of the 10th (Makara), sits
+        // exalted in Tula with high Shadbala; the 6th (Kanya) is empty and its
+        // lord Buddh is weak.
+        let cusp_sidereal_lons: [f64; 12] = std::array::from_fn(|i| 15.0 + 30.0 * i as f64);
+        let mut sapta_strengths = [360.0; 7];
+        sapta_strengths[Graha::Shani.index() as usize] = 480.0;
+        sapta_strengths[Graha::Buddh.index() as usize] = 240.0;
+        let house_lord_strengths = cusp_sidereal_lons.map(|lon| {
+            let rashi = rashi_from_longitude(lon).rashi_index;
+            sapta_strengths[rashi_lord_by_index(rashi).unwrap().index() as usize]
+        });
+
+        let mut graha_sidereal_lons = default_dynamic_lons();
+        graha_sidereal_lons[Graha::Shani.index() as usize] = 200.0;
+        let mut graha_bhava_numbers = [1u8; 9];
+        graha_bhava_numbers[Graha::Shani.index() as usize] = 7;
+        // The 6th also carries a full malefic Mangal aspect.
+        let mut aspect_virupas = [[0.0; 12]; 9];
+        aspect_virupas[Graha::Mangal.index() as usize][5] = 60.0;
+
+        let result = calculate_bhava_bala(&BhavaBalaInputs {
+            cusp_sidereal_lons,
+            ascendant_sidereal_lon: 15.0,
+            meridian_sidereal_lon: 285.0,
+            graha_bhava_numbers,
+            graha_sidereal_lons,
+            house_lord_strengths,
+            aspect_virupas,
+            include_node_aspects: false,
+            include_special_rules: true,
+            chandra_benefic_rule: ChandraBeneficRule::Brightness72,
+            birth_period: BhavaBalaBirthPeriod::Day,
+        });
+
+        let tenth = result.entries[9];
+        let sixth = result.entries[5];
+        assert_eq!(tenth.lord, Graha::Shani);
+        assert_eq!(sixth.lord, Graha::Buddh);
+        assert!(tenth.bhavadhipati > sixth.bhavadhipati);
+        assert!(
+            tenth.total_virupas > sixth.total_virupas,
+            "10th {} vs 6th {}",
+            tenth.total_virupas,
+            sixth.total_virupas
+        );
+    }
+
     #[test]
     fn calculate_bhava_bala_can_exclude_special_rules_from_total() {
         let mut graha_bhava_numbers = [0u8; 9];
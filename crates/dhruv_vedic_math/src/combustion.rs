@@ -3,19 +3,59 @@
 //! A graha is combust when it is too close to the Sun, with thresholds
 //! varying by planet and retrograde status per BPHS.
 //!
+//! Graha yuddha (planetary war) is the analogous close approach between two
+//! of the five tara grahas (Mangal, Buddh, Guru, Shukra, Shani).
+//!
 //! Clean-room implementation from BPHS combustion thresholds.
 
 use crate::graha::{ALL_GRAHAS, Graha};
 use crate::util::normalize_360;
 
+/// Moon's combustion arc (degrees from the Sun).
+///
+/// Traditions that treat the Moon near Amavasya as combust/invisible use
+/// this arc; it also bounds the new-moon invisibility window.
+pub const MOON_COMBUSTION_DEG: f64 = 12.0;
+
+/// Combustion options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CombustionConfig {
+    /// Moon's combustion orb in degrees. `0.0` exempts the Moon, for
+    /// traditions that apply combustion to the tara grahas only.
+    pub moon_orb_deg: f64,
+}
+
+impl Default for CombustionConfig {
+    fn default() -> Self {
+        Self {
+            moon_orb_deg: MOON_COMBUSTION_DEG,
+        }
+    }
+}
+
+impl CombustionConfig {
+    /// Validate the orb: finite and within 0..=180.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if !self.moon_orb_deg.is_finite() || !(0.0..=180.0).contains(&self.moon_orb_deg) {
+            return Err("moon_orb_deg must be finite and within 0..=180");
+        }
+        Ok(())
+    }
+}
+
 /// BPHS combustion threshold (degrees from Sun) for a graha.
 ///
-/// Returns `None` for Sun, Rahu, and Ketu (not applicable).
-/// For Mercury and Venus, retrograde thresholds are tighter.
-pub fn combustion_threshold(graha: Graha, is_retrograde: bool) -> Option<f64> {
+/// Returns `None` for Sun, Rahu, and Ketu (not applicable), and for the Moon
+/// when `config.moon_orb_deg` is zero. The Moon's orb is the same whatever
+/// its motion. For Mercury and Venus, retrograde thresholds are tighter.
+pub fn combustion_threshold(
+    graha: Graha,
+    is_retrograde: bool,
+    config: &CombustionConfig,
+) -> Option<f64> {
     match graha {
         Graha::Surya | Graha::Rahu | Graha::Ketu => None,
-        Graha::Chandra => Some(12.0),
+        Graha::Chandra => (config.moon_orb_deg > 0.0).then_some(config.moon_orb_deg),
         Graha::Mangal => Some(17.0),
         Graha::Buddh => {
             if is_retrograde {
@@ -40,9 +80,15 @@ pub fn combustion_threshold(graha: Graha, is_retrograde: bool) -> Option<f64> {
 ///
 /// Uses angular distance on the ecliptic circle. A graha at exactly the
 /// threshold distance is **not** combust (strict less-than).
-/// Returns `false` for Sun, Rahu, and Ketu.
-pub fn is_combust(graha: Graha, graha_sid_lon: f64, sun_sid_lon: f64, is_retrograde: bool) -> bool {
-    let threshold = match combustion_threshold(graha, is_retrograde) {
+/// Returns `false` for Sun, Rahu, and Ketu, and for an exempt Moon.
+pub fn is_combust(
+    graha: Graha,
+    graha_sid_lon: f64,
+    sun_sid_lon: f64,
+    is_retrograde: bool,
+    config: &CombustionConfig,
+) -> bool {
+    let threshold = match combustion_threshold(graha, is_retrograde, config) {
         Some(t) => t,
         None => return false,
     };
@@ -56,128 +102,373 @@ pub fn is_combust(graha: Graha, graha_sid_lon: f64, sun_sid_lon: f64, is_retrogr
 /// `sidereal_lons[0]` = Sun. Indices follow `Graha::index()`.
 /// `is_retrograde` flags: only meaningful for indices 0-6 (sapta grahas);
 /// Rahu/Ketu always return false.
-pub fn all_combustion_status(sidereal_lons: &[f64; 9], is_retrograde: &[bool; 9]) -> [bool; 9] {
+pub fn all_combustion_status(
+    sidereal_lons: &[f64; 9],
+    is_retrograde: &[bool; 9],
+    config: &CombustionConfig,
+) -> [bool; 9] {
     let sun_lon = sidereal_lons[Graha::Surya.index() as usize];
     let mut result = [false; 9];
     for graha in ALL_GRAHAS {
         let idx = graha.index() as usize;
-        result[idx] = is_combust(graha, sidereal_lons[idx], sun_lon, is_retrograde[idx]);
+        result[idx] = is_combust(
+            graha,
+            sidereal_lons[idx],
+            sun_lon,
+            is_retrograde[idx],
+            config,
+        );
     }
     result
 }
 
+/// Maximum longitude separation (degrees) at which two grahas are at war.
+///
+/// Strict less-than.
+pub const GRAHA_YUDDHA_MAX_SEPARATION_DEG: f64 = 1.0;
+
+/// Winner, loser, and separation of a planetary war.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrahaYuddhaResult {
+    pub winner: Graha,
+    pub loser: Graha,
+    /// Angular separation in longitude, in [0, 1).
+    pub separation_deg: f64,
+}
+
+/// Brightness rank of a war participant (0 = brightest), by mean apparent
+/// magnitude: Shukra, Guru, Buddh, Shani, Mangal. `None` for non-participants.
+fn yuddha_brightness_rank(graha: Graha) -> Option<u8> {
+    match graha {
+        Graha::Shukra => Some(0),
+        Graha::Guru => Some(1),
+        Graha::Buddh => Some(2),
+        Graha::Shani => Some(3),
+        Graha::Mangal => Some(4),
+        _ => None,
+    }
+}
+
+/// Separation of two war participants, or `None` when either is exempt
+/// (Sun, Moon, nodes), they are the same graha, or they are too far apart.
+fn yuddha_separation(a: Graha, a_lon: f64, b: Graha, b_lon: f64) -> Option<f64> {
+    if a == b || yuddha_brightness_rank(a).is_none() || yuddha_brightness_rank(b).is_none() {
+        return None;
+    }
+    let diff = (normalize_360(a_lon) - normalize_360(b_lon)).abs();
+    let separation = if diff > 180.0 { 360.0 - diff } else { diff };
+    (separation < GRAHA_YUDDHA_MAX_SEPARATION_DEG).then_some(separation)
+}
+
+/// Planetary war between two grahas.
+///
+/// Returns `None` unless both are tara grahas within
+/// [`GRAHA_YUDDHA_MAX_SEPARATION_DEG`]. When both declinations are given the
+/// northernmost graha wins; otherwise, or on equal declinations, the brighter
+/// graha wins (Shukra is victorious in almost every war). This is the single
+/// winner rule behind `yuddha_bala` and `lost_planetary_war`.
+pub fn graha_yuddha(
+    a: Graha,
+    a_lon: f64,
+    a_dec: Option<f64>,
+    b: Graha,
+    b_lon: f64,
+    b_dec: Option<f64>,
+) -> Option<GrahaYuddhaResult> {
+    let separation_deg = yuddha_separation(a, a_lon, b, b_lon)?;
+    let a_wins = match (a_dec, b_dec) {
+        (Some(a_dec), Some(b_dec)) if a_dec != b_dec => a_dec > b_dec,
+        _ => yuddha_brightness_rank(a) < yuddha_brightness_rank(b),
+    };
+    let (winner, loser) = if a_wins { (a, b) } else { (b, a) };
+    Some(GrahaYuddhaResult {
+        winner,
+        loser,
+        separation_deg,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn threshold_sun_none() {
-        assert!(combustion_threshold(Graha::Surya, false).is_none());
+        assert!(combustion_threshold(Graha::Surya, false, &CombustionConfig::default()).is_none());
     }
 
     #[test]
     fn threshold_rahu_none() {
-        assert!(combustion_threshold(Graha::Rahu, false).is_none());
+        assert!(combustion_threshold(Graha::Rahu, false, &CombustionConfig::default()).is_none());
     }
 
     #[test]
     fn threshold_ketu_none() {
-        assert!(combustion_threshold(Graha::Ketu, false).is_none());
+        assert!(combustion_threshold(Graha::Ketu, false, &CombustionConfig::default()).is_none());
     }
 
     #[test]
     fn threshold_moon() {
-        assert_eq!(combustion_threshold(Graha::Chandra, false), Some(12.0));
-        assert_eq!(combustion_threshold(Graha::Chandra, true), Some(12.0));
+        assert_eq!(
+            combustion_threshold(Graha::Chandra, false, &CombustionConfig::default()),
+            Some(12.0)
+        );
+        assert_eq!(
+            combustion_threshold(Graha::Chandra, true, &CombustionConfig::default()),
+            Some(12.0)
+        );
     }
 
     #[test]
     fn threshold_mars() {
-        assert_eq!(combustion_threshold(Graha::Mangal, false), Some(17.0));
-        assert_eq!(combustion_threshold(Graha::Mangal, true), Some(17.0));
+        assert_eq!(
+            combustion_threshold(Graha::Mangal, false, &CombustionConfig::default()),
+            Some(17.0)
+        );
+        assert_eq!(
+            combustion_threshold(Graha::Mangal, true, &CombustionConfig::default()),
+            Some(17.0)
+        );
     }
 
     #[test]
     fn threshold_mercury_direct_vs_retrograde() {
-        assert_eq!(combustion_threshold(Graha::Buddh, false), Some(14.0));
-        assert_eq!(combustion_threshold(Graha::Buddh, true), Some(12.0));
+        assert_eq!(
+            combustion_threshold(Graha::Buddh, false, &CombustionConfig::default()),
+            Some(14.0)
+        );
+        assert_eq!(
+            combustion_threshold(Graha::Buddh, true, &CombustionConfig::default()),
+            Some(12.0)
+        );
     }
 
     #[test]
     fn threshold_jupiter() {
-        assert_eq!(combustion_threshold(Graha::Guru, false), Some(11.0));
+        assert_eq!(
+            combustion_threshold(Graha::Guru, false, &CombustionConfig::default()),
+            Some(11.0)
+        );
     }
 
     #[test]
     fn threshold_venus_direct_vs_retrograde() {
-        assert_eq!(combustion_threshold(Graha::Shukra, false), Some(10.0));
-        assert_eq!(combustion_threshold(Graha::Shukra, true), Some(8.0));
+        assert_eq!(
+            combustion_threshold(Graha::Shukra, false, &CombustionConfig::default()),
+            Some(10.0)
+        );
+        assert_eq!(
+            combustion_threshold(Graha::Shukra, true, &CombustionConfig::default()),
+            Some(8.0)
+        );
     }
 
     #[test]
     fn threshold_saturn() {
-        assert_eq!(combustion_threshold(Graha::Shani, false), Some(15.0));
+        assert_eq!(
+            combustion_threshold(Graha::Shani, false, &CombustionConfig::default()),
+            Some(15.0)
+        );
     }
 
     #[test]
     fn is_combust_sun_always_false() {
-        assert!(!is_combust(Graha::Surya, 100.0, 100.0, false));
+        assert!(!is_combust(
+            Graha::Surya,
+            100.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
     fn is_combust_rahu_always_false() {
-        assert!(!is_combust(Graha::Rahu, 100.0, 100.0, false));
+        assert!(!is_combust(
+            Graha::Rahu,
+            100.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
     fn is_combust_ketu_always_false() {
-        assert!(!is_combust(Graha::Ketu, 100.0, 100.0, false));
+        assert!(!is_combust(
+            Graha::Ketu,
+            100.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
     fn is_combust_moon_within_threshold() {
         // Moon at 5 deg from Sun: 5 < 12 → combust
-        assert!(is_combust(Graha::Chandra, 105.0, 100.0, false));
+        assert!(is_combust(
+            Graha::Chandra,
+            105.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
     fn is_combust_moon_outside_threshold() {
         // Moon at 15 deg from Sun: 15 >= 12 → not combust
-        assert!(!is_combust(Graha::Chandra, 115.0, 100.0, false));
+        assert!(!is_combust(
+            Graha::Chandra,
+            115.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
+    }
+
+    #[test]
+    fn moon_orb_around_amavasya() {
+        assert_eq!(
+            combustion_threshold(Graha::Chandra, false, &CombustionConfig::default()),
+            Some(MOON_COMBUSTION_DEG)
+        );
+        // Waning Moon 11.5 deg behind a Sun at 5 deg, across 0 deg.
+        assert!(is_combust(
+            Graha::Chandra,
+            353.5,
+            5.0,
+            false,
+            &CombustionConfig::default()
+        ));
+        // Exactly 12 deg ahead: strict less-than, not combust.
+        assert!(!is_combust(
+            Graha::Chandra,
+            17.0,
+            5.0,
+            false,
+            &CombustionConfig::default()
+        ));
+    }
+
+    #[test]
+    fn moon_orb_is_configurable() {
+        let wide = CombustionConfig { moon_orb_deg: 15.0 };
+        assert_eq!(
+            combustion_threshold(Graha::Chandra, false, &wide),
+            Some(15.0)
+        );
+        assert!(is_combust(Graha::Chandra, 113.0, 100.0, false, &wide));
+        assert!(!is_combust(
+            Graha::Chandra,
+            113.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
+
+        let exempt = CombustionConfig { moon_orb_deg: 0.0 };
+        assert_eq!(combustion_threshold(Graha::Chandra, false, &exempt), None);
+        let mut lons = [200.0; 9];
+        lons[0] = 100.0;
+        lons[1] = 100.0;
+        assert!(!all_combustion_status(&lons, &[false; 9], &exempt)[1]);
+        // The tara grahas keep their BPHS orbs.
+        assert_eq!(
+            combustion_threshold(Graha::Mangal, false, &exempt),
+            Some(17.0)
+        );
+    }
+
+    #[test]
+    fn combustion_config_validation() {
+        assert!(CombustionConfig::default().validate().is_ok());
+        assert!(CombustionConfig { moon_orb_deg: 0.0 }.validate().is_ok());
+        assert!(CombustionConfig { moon_orb_deg: -1.0 }.validate().is_err());
+        assert!(
+            CombustionConfig {
+                moon_orb_deg: f64::NAN
+            }
+            .validate()
+            .is_err()
+        );
     }
 
     #[test]
     fn boundary_exactly_at_threshold_not_combust() {
         // Mars at exactly 17 deg from Sun: 17 is NOT < 17 → not combust
-        assert!(!is_combust(Graha::Mangal, 117.0, 100.0, false));
+        assert!(!is_combust(
+            Graha::Mangal,
+            117.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
     fn boundary_just_inside_combust() {
         // Mars at 16.999 deg from Sun: < 17 → combust
-        assert!(is_combust(Graha::Mangal, 116.999, 100.0, false));
+        assert!(is_combust(
+            Graha::Mangal,
+            116.999,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
     fn is_combust_wraparound() {
         // Sun at 355, Mars at 5 → distance = 10 < 17 → combust
-        assert!(is_combust(Graha::Mangal, 5.0, 355.0, false));
+        assert!(is_combust(
+            Graha::Mangal,
+            5.0,
+            355.0,
+            false,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
     fn mercury_retrograde_tighter_threshold() {
         // Mercury at 13 deg from Sun, direct: 13 < 14 → combust
-        assert!(is_combust(Graha::Buddh, 113.0, 100.0, false));
+        assert!(is_combust(
+            Graha::Buddh,
+            113.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
         // Mercury at 13 deg from Sun, retrograde: 13 >= 12 → NOT combust
-        assert!(!is_combust(Graha::Buddh, 113.0, 100.0, true));
+        assert!(!is_combust(
+            Graha::Buddh,
+            113.0,
+            100.0,
+            true,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
     fn venus_retrograde_tighter_threshold() {
         // Venus at 9 deg from Sun, direct: 9 < 10 → combust
-        assert!(is_combust(Graha::Shukra, 109.0, 100.0, false));
+        assert!(is_combust(
+            Graha::Shukra,
+            109.0,
+            100.0,
+            false,
+            &CombustionConfig::default()
+        ));
         // Venus at 9 deg from Sun, retrograde: 9 >= 8 → NOT combust
-        assert!(!is_combust(Graha::Shukra, 109.0, 100.0, true));
+        assert!(!is_combust(
+            Graha::Shukra,
+            109.0,
+            100.0,
+            true,
+            &CombustionConfig::default()
+        ));
     }
 
     #[test]
@@ -195,7 +486,7 @@ mod tests {
         lons[8] = 100.0; // Ketu, always false
 
         let retro = [false; 9];
-        let result = all_combustion_status(&lons, &retro);
+        let result = all_combustion_status(&lons, &retro, &CombustionConfig::default());
 
         assert!(!result[0]); // Sun
         assert!(result[1]); // Moon
@@ -207,4 +498,64 @@ mod tests {
         assert!(!result[7]); // Rahu
         assert!(!result[8]); // Ketu
     }
+
+    #[test]
+    fn mars_saturn_within_half_degree_at_war() {
+        let war = graha_yuddha(Graha::Mangal, 120.3, None, Graha::Shani, 120.7, None).unwrap();
+        assert_eq!((war.winner, war.loser), (Graha::Shani, Graha::Mangal));
+        assert!((war.separation_deg - 0.4).abs() < 1e-9);
+
+        // Argument order does not change the outcome.
+        let swapped = graha_yuddha(Graha::Shani, 120.7, None, Graha::Mangal, 120.3, None).unwrap();
+        assert_eq!(swapped, war);
+
+        // With declinations, the northern graha wins regardless of brightness.
+        let north = graha_yuddha(
+            Graha::Mangal,
+            120.3,
+            Some(18.0),
+            Graha::Shani,
+            120.7,
+            Some(17.5),
+        )
+        .unwrap();
+        assert_eq!((north.winner, north.loser), (Graha::Mangal, Graha::Shani));
+
+        // Signed declination: the less southern graha is the northernmost.
+        let south = graha_yuddha(
+            Graha::Mangal,
+            120.3,
+            Some(-18.0),
+            Graha::Shani,
+            120.7,
+            Some(-17.5),
+        )
+        .unwrap();
+        assert_eq!(south.winner, Graha::Shani);
+
+        // Equal declinations fall back to brightness.
+        let tie = graha_yuddha(
+            Graha::Mangal,
+            120.3,
+            Some(5.0),
+            Graha::Shani,
+            120.7,
+            Some(5.0),
+        )
+        .unwrap();
+        assert_eq!(tie.winner, Graha::Shani);
+    }
+
+    #[test]
+    fn pair_two_degrees_apart_not_at_war() {
+        assert!(graha_yuddha(Graha::Mangal, 120.0, None, Graha::Shani, 122.0, None).is_none());
+        assert!(graha_yuddha(Graha::Guru, 359.8, None, Graha::Shukra, 0.3, None).is_some());
+    }
+
+    #[test]
+    fn luminaries_and_nodes_exempt_from_war() {
+        assert!(graha_yuddha(Graha::Surya, 10.0, None, Graha::Mangal, 10.2, None).is_none());
+        assert!(graha_yuddha(Graha::Chandra, 10.0, None, Graha::Shani, 10.2, None).is_none());
+        assert!(graha_yuddha(Graha::Rahu, 10.0, None, Graha::Guru, 10.2, None).is_none());
+    }
 }
@@ -3,7 +3,11 @@
 //! - Nakshatra-based: computed from Moon's position within its nakshatra.
 //! - Rashi-based: computed from lagna's position within its rashi.
 
-use crate::nakshatra::NAKSHATRA_SPAN_27;
+use std::fmt;
+
+use super::nakshatra_data::NakshatraDashaConfig;
+use super::variation::DashaYearLength;
+use crate::nakshatra::{NAKSHATRA_SPAN_27, nakshatra_28_boundaries, nakshatra28_from_longitude};
 use crate::util::normalize_360;
 
 /// Compute nakshatra birth balance for a nakshatra-based dasha system.
@@ -22,6 +26,41 @@ pub fn nakshatra_birth_balance(moon_sidereal_lon: f64, entry_period_days: f64) -
     (nak_idx, balance_days, elapsed_fraction)
 }
 
+/// Compute nakshatra birth balance on the 28-nakshatra (Abhijit) scheme.
+///
+/// Same contract as [`nakshatra_birth_balance`], but the index is 0-27
+/// (21 = Abhijit) and the elapsed fraction is measured against the unequal
+/// 28-scheme spans (Uttara Ashadha 10deg, Abhijit 4deg13'20", Shravana 12deg26'40").
+///
+/// Adjacent nakshatras that `config` gives the same lord form one arc: with
+/// Abhijit under Surya, Uttara Ashadha and Abhijit together span
+/// 266deg40'-280deg53'20", so Surya's balance runs down continuously across
+/// 276deg40'.
+pub fn nakshatra28_birth_balance(
+    moon_sidereal_lon: f64,
+    entry_period_days: f64,
+    config: &NakshatraDashaConfig,
+) -> (u8, f64, f64) {
+    let info = nakshatra28_from_longitude(moon_sidereal_lon);
+    let bounds = nakshatra_28_boundaries();
+    let idx = info.nakshatra_index;
+    let lord = config.starting_graha_idx_28(idx);
+    let mut first = idx;
+    while first > 0 && config.starting_graha_idx_28(first - 1) == lord {
+        first -= 1;
+    }
+    let mut last = idx;
+    while last < 27 && config.starting_graha_idx_28(last + 1) == lord {
+        last += 1;
+    }
+    let (start, _) = bounds[first as usize];
+    let (_, end) = bounds[last as usize];
+    let traversed = normalize_360(moon_sidereal_lon) - start;
+    let elapsed_fraction = (traversed / (end - start)).clamp(0.0, 1.0);
+    let balance_days = entry_period_days * (1.0 - elapsed_fraction);
+    (info.nakshatra_index, balance_days, elapsed_fraction)
+}
+
 /// Compute rashi birth balance for a rashi-based dasha system.
 ///
 /// Returns `(balance_days, elapsed_fraction)`:
@@ -40,9 +79,69 @@ pub fn rashi_birth_balance(lagna_sidereal_lon: f64, entry_period_days: f64) -> (
     (balance_days, elapsed_fraction)
 }
 
+/// Dasha balance broken into calendar-style years, months, and days.
+///
+/// Months are twelfths of the dasha year (30.4375 days for the Julian year),
+/// matching the year length used for period generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DashaBalance {
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+}
+
+impl fmt::Display for DashaBalance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}y {}m {}d", self.years, self.months, self.days)
+    }
+}
+
+/// Convert a fractional-year dasha balance into years/months/days.
+///
+/// `remaining_years` counts years of `year_length`, the same year the
+/// periods were generated with. The total is rounded to whole days before
+/// it is split, so a residue within half a day of a full month or year
+/// rolls over into that unit instead of printing as "30d" or "12m".
+/// Negative or non-finite input yields a zero balance.
+pub fn format_balance(remaining_years: f64, year_length: DashaYearLength) -> DashaBalance {
+    if !remaining_years.is_finite() || remaining_years <= 0.0 {
+        return DashaBalance {
+            years: 0,
+            months: 0,
+            days: 0,
+        };
+    }
+    let year_days = year_length.days();
+    let month_days = year_days / 12.0;
+    let total_days = (remaining_years * year_days).round();
+    let mut years = (total_days / year_days).floor();
+    let mut rem_days = total_days - years * year_days;
+    if rem_days + 0.5 >= year_days {
+        years += 1.0;
+        rem_days = 0.0;
+    }
+    let mut months = (rem_days / month_days).floor();
+    let mut days = rem_days - months * month_days;
+    if days + 0.5 >= month_days {
+        months += 1.0;
+        days = 0.0;
+    }
+    if months >= 12.0 {
+        years += 1.0;
+        months = 0.0;
+    }
+    DashaBalance {
+        years: years as u32,
+        months: months as u32,
+        days: days.round() as u32,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dasha::nakshatra_data::vimshottari28_config;
+    use crate::dasha::types::DAYS_PER_YEAR;
 
     #[test]
     fn balance_at_start_of_nakshatra() {
@@ -90,6 +189,41 @@ mod tests {
         assert_eq!(idx, 26);
     }
 
+    #[test]
+    fn balance28_abhijit_shares_surya_arc_with_uttara_ashadha() {
+        let cfg = vimshottari28_config();
+        let ua_start = 266.0 + 40.0 / 60.0;
+        let end = 280.0 + 53.0 / 60.0 + 20.0 / 3600.0;
+        // The arc's midpoint still lies in Uttara Ashadha.
+        let (idx, balance, frac) = nakshatra28_birth_balance((ua_start + end) / 2.0, 2191.5, &cfg);
+        assert_eq!(idx, 20);
+        assert!((frac - 0.5).abs() < 1e-10);
+        assert!((balance - 2191.5 * 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn balance28_continuous_across_abhijit_start() {
+        let cfg = vimshottari28_config();
+        let boundary = 276.0 + 40.0 / 60.0;
+        let (before_idx, before, _) = nakshatra28_birth_balance(boundary - 1e-9, 2191.5, &cfg);
+        let (after_idx, after, _) = nakshatra28_birth_balance(boundary + 1e-9, 2191.5, &cfg);
+        assert_eq!(before_idx, 20);
+        assert_eq!(after_idx, 21);
+        assert!((before - after).abs() < 1e-6, "{before} vs {after}");
+        // Surya still has the Abhijit arc left to run at the boundary.
+        let arc = 14.0 + 13.0 / 60.0 + 20.0 / 3600.0;
+        let abhijit = 4.0 + 13.0 / 60.0 + 20.0 / 3600.0;
+        assert!((after - 2191.5 * abhijit / arc).abs() < 1e-6);
+    }
+
+    #[test]
+    fn balance28_matches_27_outside_abhijit_region() {
+        let (idx28, bal28, _) = nakshatra28_birth_balance(45.0, 3652.5, &vimshottari28_config());
+        let (idx27, bal27, _) = nakshatra_birth_balance(45.0, 3652.5);
+        assert_eq!(idx28, idx27);
+        assert!((bal28 - bal27).abs() < 1e-9);
+    }
+
     // ── Rashi birth balance tests ──
 
     #[test]
@@ -130,4 +264,78 @@ mod tests {
         // -1 → 359 deg → Meena (index 11), position 29 deg → small balance
         assert!(balance < 100.0);
     }
+
+    // ── Balance formatting tests ──
+
+    #[test]
+    fn format_balance_7_29_years() {
+        // 0.29 y = 105.9225 d = 3 months (91.3125 d) + 14.61 d
+        let b = format_balance(7.29, DashaYearLength::Julian);
+        assert_eq!(
+            b,
+            DashaBalance {
+                years: 7,
+                months: 3,
+                days: 15
+            }
+        );
+        assert_eq!(b.to_string(), "7y 3m 15d");
+        let rebuilt =
+            b.years as f64 * DAYS_PER_YEAR + b.months as f64 * DAYS_PER_YEAR / 12.0 + b.days as f64;
+        assert!((rebuilt - 7.29 * DAYS_PER_YEAR).abs() < 1.0);
+    }
+
+    #[test]
+    fn format_balance_rolls_over_units() {
+        // Just under eight years: 2922 whole days = 8 * 365.25.
+        assert_eq!(
+            format_balance(7.999_999_9, DashaYearLength::Julian).to_string(),
+            "8y 0m 0d"
+        );
+        // 30 days is within half a day of a 30.4375-day month.
+        assert_eq!(
+            format_balance(30.0 / DAYS_PER_YEAR, DashaYearLength::Julian).to_string(),
+            "0y 1m 0d"
+        );
+        // 365 days is within half a day of a year.
+        assert_eq!(
+            format_balance(365.0 / DAYS_PER_YEAR, DashaYearLength::Julian).to_string(),
+            "1y 0m 0d"
+        );
+        assert_eq!(
+            format_balance(29.0 / DAYS_PER_YEAR, DashaYearLength::Julian).to_string(),
+            "0y 0m 29d"
+        );
+    }
+
+    #[test]
+    fn format_balance_uses_year_length() {
+        // 0.5 Savana year = 180 days = exactly 6 Savana months.
+        assert_eq!(
+            format_balance(7.5, DashaYearLength::Savana).to_string(),
+            "7y 6m 0d"
+        );
+        // A Julian month is 30.4375 days, so 180 days is 5m 28d.
+        assert_eq!(
+            format_balance(180.0 / DAYS_PER_YEAR, DashaYearLength::Julian).to_string(),
+            "0y 5m 28d"
+        );
+        // 360 days is a whole Savana year but five days short of a Julian one.
+        assert_eq!(
+            format_balance(1.0, DashaYearLength::Savana).to_string(),
+            "1y 0m 0d"
+        );
+    }
+
+    #[test]
+    fn format_balance_non_positive_is_zero() {
+        assert_eq!(
+            format_balance(-1.0, DashaYearLength::Julian).to_string(),
+            "0y 0m 0d"
+        );
+        assert_eq!(
+            format_balance(f64::NAN, DashaYearLength::Julian).to_string(),
+            "0y 0m 0d"
+        );
+    }
 }
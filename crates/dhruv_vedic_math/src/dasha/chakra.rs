@@ -99,7 +99,9 @@ pub fn chakra_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = chakra_level0(birth_jd, inputs, birth_period);
+    let level0 = variation
+        .year_length
+        .rescale_level0(chakra_level0(birth_jd, inputs, birth_period), birth_jd);
     rashi_hierarchy(
         DashaSystem::Chakra,
         birth_jd,
@@ -121,7 +123,9 @@ pub fn chakra_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = chakra_level0(birth_jd, inputs, birth_period);
+    let level0 = variation
+        .year_length
+        .rescale_level0(chakra_level0(birth_jd, inputs, birth_period), birth_jd);
     rashi_snapshot(
         DashaSystem::Chakra,
         level0,
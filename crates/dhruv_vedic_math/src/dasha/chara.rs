@@ -281,7 +281,9 @@ pub fn chara_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = chara_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(chara_level0(birth_jd, inputs), birth_jd);
     let total = chara_total_years(inputs);
     let period_fn = |r: u8| chara_period_years(r, inputs);
     rashi_hierarchy(
@@ -304,7 +306,9 @@ pub fn chara_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = chara_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(chara_level0(birth_jd, inputs), birth_jd);
     let total = chara_total_years(inputs);
     let period_fn = |r: u8| chara_period_years(r, inputs);
     rashi_snapshot(
@@ -121,7 +121,9 @@ pub fn driga_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = driga_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(driga_level0(birth_jd, inputs), birth_jd);
     rashi_hierarchy(
         DashaSystem::Driga,
         birth_jd,
@@ -142,7 +144,9 @@ pub fn driga_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = driga_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(driga_level0(birth_jd, inputs), birth_jd);
     rashi_snapshot(
         DashaSystem::Driga,
         level0,
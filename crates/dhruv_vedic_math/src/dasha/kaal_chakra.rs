@@ -164,7 +164,9 @@ pub fn kaal_chakra_hierarchy(
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
     let max_level = max_level.min(MAX_DASHA_LEVEL);
-    let level0 = kaal_chakra_level0(birth_jd, moon_sidereal_lon);
+    let level0 = variation
+        .year_length
+        .rescale_level0(kaal_chakra_level0(birth_jd, moon_sidereal_lon), birth_jd);
     let mut levels: Vec<Vec<DashaPeriod>> = vec![level0];
 
     for depth in 1..=max_level {
@@ -196,7 +198,9 @@ pub fn kaal_chakra_snapshot(
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
     let max_level = max_level.min(MAX_DASHA_LEVEL);
-    let level0 = kaal_chakra_level0(birth_jd, moon_sidereal_lon);
+    let level0 = variation
+        .year_length
+        .rescale_level0(kaal_chakra_level0(birth_jd, moon_sidereal_lon), birth_jd);
     let mut active_periods: Vec<DashaPeriod> = Vec::with_capacity((max_level + 1) as usize);
 
     let active_idx = match find_active_period(&level0, query_jd) {
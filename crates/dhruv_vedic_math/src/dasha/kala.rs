@@ -144,7 +144,9 @@ pub fn kala_hierarchy(
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
     let max_level = max_level.min(MAX_DASHA_LEVEL);
-    let level0 = kala_level0(birth_jd, sunrise_jd, sunset_jd);
+    let level0 = variation
+        .year_length
+        .rescale_level0(kala_level0(birth_jd, sunrise_jd, sunset_jd), birth_jd);
     let mut levels: Vec<Vec<DashaPeriod>> = vec![level0];
 
     for depth in 1..=max_level {
@@ -177,7 +179,9 @@ pub fn kala_snapshot(
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
     let max_level = max_level.min(MAX_DASHA_LEVEL);
-    let level0 = kala_level0(birth_jd, sunrise_jd, sunset_jd);
+    let level0 = variation
+        .year_length
+        .rescale_level0(kala_level0(birth_jd, sunrise_jd, sunset_jd), birth_jd);
     let mut active_periods: Vec<DashaPeriod> = Vec::with_capacity((max_level + 1) as usize);
 
     let active_idx = match find_active_period(&level0, query_jd) {
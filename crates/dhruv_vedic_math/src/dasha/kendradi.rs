@@ -130,7 +130,9 @@ pub fn kendradi_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = kendradi_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(kendradi_level0(birth_jd, inputs), birth_jd);
     let total = kendradi_total_years(inputs);
     let period_fn = |r: u8| chara_period_years(r, inputs);
     rashi_hierarchy(
@@ -153,7 +155,9 @@ pub fn kendradi_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = kendradi_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(kendradi_level0(birth_jd, inputs), birth_jd);
     let total = kendradi_total_years(inputs);
     let period_fn = |r: u8| chara_period_years(r, inputs);
     rashi_snapshot(
@@ -183,7 +187,9 @@ pub fn karaka_kendradi_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = karaka_kendradi_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(karaka_kendradi_level0(birth_jd, inputs), birth_jd);
     let total = kendradi_total_years(inputs);
     let period_fn = |r: u8| chara_period_years(r, inputs);
     rashi_hierarchy(
@@ -206,7 +212,9 @@ pub fn karaka_kendradi_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = karaka_kendradi_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(karaka_kendradi_level0(birth_jd, inputs), birth_jd);
     let total = kendradi_total_years(inputs);
     let period_fn = |r: u8| chara_period_years(r, inputs);
     rashi_snapshot(
@@ -238,7 +246,9 @@ pub fn karaka_kendradi_graha_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = karaka_kendradi_graha_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(karaka_kendradi_graha_level0(birth_jd, inputs), birth_jd);
     let total = kendradi_total_years(inputs);
     let period_fn = |r: u8| chara_period_years(r, inputs);
     rashi_hierarchy(
@@ -261,7 +271,9 @@ pub fn karaka_kendradi_graha_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = karaka_kendradi_graha_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(karaka_kendradi_graha_level0(birth_jd, inputs), birth_jd);
     let total = kendradi_total_years(inputs);
     let period_fn = |r: u8| chara_period_years(r, inputs);
     rashi_snapshot(
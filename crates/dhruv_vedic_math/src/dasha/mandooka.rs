@@ -184,7 +184,9 @@ pub fn mandooka_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = mandooka_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(mandooka_level0(birth_jd, inputs), birth_jd);
     let max_level = max_level.min(MAX_DASHA_LEVEL);
     let mut levels: Vec<Vec<DashaPeriod>> = vec![level0];
 
@@ -213,7 +215,9 @@ pub fn mandooka_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = mandooka_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(mandooka_level0(birth_jd, inputs), birth_jd);
     let max_level = max_level.min(MAX_DASHA_LEVEL);
     let mut active_periods: Vec<DashaPeriod> = Vec::with_capacity((max_level + 1) as usize);
 
@@ -1,9 +1,9 @@
 //! Dasha (planetary period) calculations for Vedic astrology.
 //!
-//! Implements 23 dasha systems from BPHS across 4 categories:
-//! - Nakshatra-based (10 systems): Vimshottari, Ashtottari, etc.
+//! Implements 25 dasha systems from BPHS across 4 categories:
+//! - Nakshatra-based (11 systems): Vimshottari (27- and 28-nakshatra), Ashtottari, etc.
 //! - Yogini (1 system)
-//! - Rashi-based (10 systems): Chara, Sthira, Kendradi, etc.
+//! - Rashi-based (11 systems): Chara, Sthira, Kendradi, Narayana, etc.
 //! - Special (2 systems): Kala, Kaal Chakra
 //!
 //! Each system supports 5 hierarchical levels (Mahadasha through Pranadasha)
@@ -29,6 +29,7 @@ pub mod chara;
 pub mod driga;
 pub mod kendradi;
 pub mod mandooka;
+pub mod narayana;
 pub mod rashi_dasha;
 pub mod rashi_strength;
 pub mod shoola;
@@ -41,13 +42,19 @@ pub mod kaal_chakra_data;
 pub mod kala;
 pub mod kala_data;
 
-pub use balance::{nakshatra_birth_balance, rashi_birth_balance};
+pub use balance::{
+    DashaBalance, format_balance, nakshatra_birth_balance, nakshatra28_birth_balance,
+    rashi_birth_balance,
+};
 pub use nakshatra::{
     nakshatra_child_period, nakshatra_children, nakshatra_complete_level, nakshatra_hierarchy,
     nakshatra_level0, nakshatra_level0_entity, nakshatra_snapshot,
 };
-pub use nakshatra_data::{NakshatraDashaConfig, nakshatra_config_for_system, vimshottari_config};
-pub use query::{find_active_period, snapshot_from_hierarchy};
+pub use nakshatra_data::{
+    NakshatraDashaConfig, VIMSHOTTARI_SEQUENCE, nakshatra_config_for_system, nakshatra_dasha_lord,
+    vimshottari_config, vimshottari28_config,
+};
+pub use query::{find_active_period, period_boundary_jd, snapshot_from_hierarchy};
 pub use rashi_util::{
     SignType, count_signs_forward, count_signs_reverse, is_odd_sign, jump_rashi, next_rashi,
     sign_type,
@@ -60,7 +67,7 @@ pub use types::{
     DashaPeriod, DashaSnapshot, DashaSystem, MAX_DASHA_LEVEL, MAX_DASHA_SYSTEMS,
     MAX_PERIODS_PER_LEVEL,
 };
-pub use variation::{DashaVariationConfig, SubPeriodMethod, YoginiScheme};
+pub use variation::{DashaVariationConfig, DashaYearLength, SubPeriodMethod, YoginiScheme};
 pub use yogini::{
     yogini_child_period, yogini_children, yogini_complete_level, yogini_hierarchy, yogini_level0,
     yogini_level0_entity, yogini_snapshot,
@@ -79,6 +86,9 @@ pub use mandooka::{
     mandooka_children, mandooka_complete_level, mandooka_hierarchy, mandooka_level0,
     mandooka_snapshot,
 };
+pub use narayana::{
+    narayana_hierarchy, narayana_level0, narayana_sequence, narayana_snapshot, narayana_total_years,
+};
 pub use rashi_strength::RashiDashaInputs;
 pub use shoola::{shoola_hierarchy, shoola_level0, shoola_snapshot};
 pub use sthira::{sthira_hierarchy, sthira_level0, sthira_snapshot};
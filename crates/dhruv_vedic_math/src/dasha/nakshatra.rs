@@ -1,4 +1,4 @@
-//! Generic nakshatra-based dasha engine (serves all 10 nakshatra systems).
+//! Generic nakshatra-based dasha engine (serves all 11 nakshatra systems).
 //!
 //! Implements 6 computation tiers:
 //! - Tier 0: Level-0 (mahadasha) generation
@@ -10,7 +10,7 @@
 
 use crate::error::VedicError;
 
-use super::balance::nakshatra_birth_balance;
+use super::balance::{nakshatra_birth_balance, nakshatra28_birth_balance};
 use super::nakshatra_data::NakshatraDashaConfig;
 use super::query::find_active_period;
 use super::subperiod::generate_children;
@@ -23,20 +23,32 @@ use super::variation::{DashaVariationConfig, SubPeriodMethod};
 // ── Tier 0: Level-0 (Mahadasha) generation ───────────────────────────
 
 /// Generate all level-0 (mahadasha) periods from birth inputs.
+///
+/// Period lengths follow `variation.year_length`.
 pub fn nakshatra_level0(
     birth_jd: f64,
     moon_sidereal_lon: f64,
     config: &NakshatraDashaConfig,
+    variation: &DashaVariationConfig,
 ) -> Vec<DashaPeriod> {
-    let nak_idx = {
-        let lon = crate::util::normalize_360(moon_sidereal_lon);
-        (lon / crate::nakshatra::NAKSHATRA_SPAN_27).floor() as u8
-    }
-    .min(26);
-
-    let start_graha_idx = config.starting_graha_idx(nak_idx) as usize;
-    let entry_period = config.entry_period_days(nak_idx);
-    let (_nak, balance_days, _frac) = nakshatra_birth_balance(moon_sidereal_lon, entry_period);
+    let (start_graha_idx, balance_days) = if config.abhijit_graha_idx.is_some() {
+        let nak28_idx =
+            crate::nakshatra::nakshatra28_from_longitude(moon_sidereal_lon).nakshatra_index;
+        let gi = config.starting_graha_idx_28(nak28_idx);
+        let entry_period = config.periods_days[gi as usize];
+        let (_nak, balance_days, _frac) =
+            nakshatra28_birth_balance(moon_sidereal_lon, entry_period, config);
+        (gi as usize, balance_days)
+    } else {
+        let nak_idx = {
+            let lon = crate::util::normalize_360(moon_sidereal_lon);
+            (lon / crate::nakshatra::NAKSHATRA_SPAN_27).floor() as u8
+        }
+        .min(26);
+        let entry_period = config.entry_period_days(nak_idx);
+        let (_nak, balance_days, _frac) = nakshatra_birth_balance(moon_sidereal_lon, entry_period);
+        (config.starting_graha_idx(nak_idx) as usize, balance_days)
+    };
 
     let n = config.graha_sequence.len();
     let total_entries = n * config.cycle_count as usize;
@@ -66,7 +78,7 @@ pub fn nakshatra_level0(
         cursor = end;
     }
 
-    periods
+    variation.year_length.rescale_level0(periods, birth_jd)
 }
 
 /// Get the level-0 period for a specific entity.
@@ -75,14 +87,18 @@ pub fn nakshatra_level0_entity(
     moon_sidereal_lon: f64,
     config: &NakshatraDashaConfig,
     entity: DashaEntity,
+    variation: &DashaVariationConfig,
 ) -> Option<DashaPeriod> {
-    let periods = nakshatra_level0(birth_jd, moon_sidereal_lon, config);
+    let periods = nakshatra_level0(birth_jd, moon_sidereal_lon, config, variation);
     periods.into_iter().find(|p| p.entity == entity)
 }
 
 // ── Tier 1: Single child period ──────────────────────────────────────
 
 /// Calculate one specific entity's sub-period within a parent period.
+///
+/// Child durations are fractions of the parent, so they inherit the year
+/// length the parent was built with.
 pub fn nakshatra_child_period(
     parent: &DashaPeriod,
     child_entity: DashaEntity,
@@ -165,7 +181,7 @@ pub fn nakshatra_hierarchy(
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
     let max_level = max_level.min(MAX_DASHA_LEVEL);
-    let level0 = nakshatra_level0(birth_jd, moon_sidereal_lon, config);
+    let level0 = nakshatra_level0(birth_jd, moon_sidereal_lon, config, variation);
     let mut levels: Vec<Vec<DashaPeriod>> = vec![level0];
 
     for depth in 1..=max_level {
@@ -201,7 +217,7 @@ pub fn nakshatra_snapshot(
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
     let max_level = max_level.min(MAX_DASHA_LEVEL);
-    let level0 = nakshatra_level0(birth_jd, moon_sidereal_lon, config);
+    let level0 = nakshatra_level0(birth_jd, moon_sidereal_lon, config, variation);
     let mut active_periods: Vec<DashaPeriod> = Vec::with_capacity((max_level + 1) as usize);
 
     // Find active mahadasha
@@ -243,6 +259,7 @@ mod tests {
     use super::*;
     use crate::dasha::nakshatra_data::vimshottari_config;
     use crate::dasha::types::DAYS_PER_YEAR;
+    use crate::dasha::variation::DashaYearLength;
     use crate::graha::Graha;
 
     #[test]
@@ -250,7 +267,7 @@ mod tests {
         // Moon at 0 deg (Ashwini start) → Ketu mahadasha, full 7y, no balance deduction
         let cfg = vimshottari_config();
         let birth_jd = 2451545.0; // J2000
-        let periods = nakshatra_level0(birth_jd, 0.0, &cfg);
+        let periods = nakshatra_level0(birth_jd, 0.0, &cfg, &DashaVariationConfig::default());
 
         assert_eq!(periods.len(), 9);
         assert_eq!(periods[0].entity, DashaEntity::Graha(Graha::Ketu));
@@ -268,7 +285,7 @@ mod tests {
         // Moon at 40 deg = start of Rohini → Chandra mahadasha with full balance
         let cfg = vimshottari_config();
         let birth_jd = 2451545.0;
-        let periods = nakshatra_level0(birth_jd, 40.0, &cfg);
+        let periods = nakshatra_level0(birth_jd, 40.0, &cfg, &DashaVariationConfig::default());
 
         assert_eq!(periods[0].entity, DashaEntity::Graha(Graha::Chandra));
         // Rohini starts at exactly 40.0 deg, so full 10y balance
@@ -282,17 +299,67 @@ mod tests {
         let cfg = vimshottari_config();
         let birth_jd = 2451545.0;
         let mid_rohini = 40.0 + crate::nakshatra::NAKSHATRA_SPAN_27 / 2.0;
-        let periods = nakshatra_level0(birth_jd, mid_rohini, &cfg);
+        let periods =
+            nakshatra_level0(birth_jd, mid_rohini, &cfg, &DashaVariationConfig::default());
 
         assert_eq!(periods[0].entity, DashaEntity::Graha(Graha::Chandra));
         let chandra_years = periods[0].duration_days() / DAYS_PER_YEAR;
         assert!((chandra_years - 5.0).abs() < 0.1);
     }
 
+    #[test]
+    fn vimshottari28_moon_in_abhijit_runs_abhijit_lord() {
+        use crate::dasha::nakshatra_data::vimshottari28_config;
+
+        // 280.5 deg: Shravana (Chandra) in the 27-scheme, Abhijit in the 28-scheme.
+        let cfg28 = vimshottari28_config();
+        let periods = nakshatra_level0(2451545.0, 280.5, &cfg28, &DashaVariationConfig::default());
+        assert_eq!(periods[0].entity, DashaEntity::Graha(Graha::Surya));
+        assert_eq!(periods[1].entity, DashaEntity::Graha(Graha::Chandra));
+        // Balance measured across Surya's whole arc, Uttara Ashadha plus
+        // Abhijit (266deg40' to 280deg53'20").
+        let arc_start = 266.0 + 40.0 / 60.0;
+        let arc = 14.0 + 13.0 / 60.0 + 20.0 / 3600.0;
+        let expected_years = 6.0 * (1.0 - (280.5 - arc_start) / arc);
+        let surya_years = periods[0].duration_days() / DAYS_PER_YEAR;
+        assert!((surya_years - expected_years).abs() < 1e-9);
+
+        let periods27 = nakshatra_level0(
+            2451545.0,
+            280.5,
+            &vimshottari_config(),
+            &DashaVariationConfig::default(),
+        );
+        assert_eq!(periods27[0].entity, DashaEntity::Graha(Graha::Chandra));
+    }
+
+    #[test]
+    fn vimshottari28_matches_27_outside_abhijit_region() {
+        use crate::dasha::nakshatra_data::vimshottari28_config;
+
+        let p27 = nakshatra_level0(
+            2451545.0,
+            100.0,
+            &vimshottari_config(),
+            &DashaVariationConfig::default(),
+        );
+        let p28 = nakshatra_level0(
+            2451545.0,
+            100.0,
+            &vimshottari28_config(),
+            &DashaVariationConfig::default(),
+        );
+        assert_eq!(p27.len(), p28.len());
+        for (a, b) in p27.iter().zip(&p28) {
+            assert_eq!(a.entity, b.entity);
+            assert!((a.end_jd - b.end_jd).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn vimshottari_adjacent_periods_no_gaps() {
         let cfg = vimshottari_config();
-        let periods = nakshatra_level0(2451545.0, 100.0, &cfg);
+        let periods = nakshatra_level0(2451545.0, 100.0, &cfg, &DashaVariationConfig::default());
         for i in 1..periods.len() {
             assert!(
                 (periods[i].start_jd - periods[i - 1].end_jd).abs() < 1e-10,
@@ -306,7 +373,7 @@ mod tests {
     #[test]
     fn vimshottari_children_count() {
         let cfg = vimshottari_config();
-        let periods = nakshatra_level0(2451545.0, 0.0, &cfg);
+        let periods = nakshatra_level0(2451545.0, 0.0, &cfg, &DashaVariationConfig::default());
         let children =
             nakshatra_children(&periods[0], &cfg, SubPeriodMethod::ProportionalFromParent);
         assert_eq!(children.len(), 9);
@@ -317,7 +384,7 @@ mod tests {
     #[test]
     fn vimshottari_children_sum_to_parent() {
         let cfg = vimshottari_config();
-        let periods = nakshatra_level0(2451545.0, 0.0, &cfg);
+        let periods = nakshatra_level0(2451545.0, 0.0, &cfg, &DashaVariationConfig::default());
         let parent = &periods[0];
         let children = nakshatra_children(parent, &cfg, SubPeriodMethod::ProportionalFromParent);
 
@@ -386,7 +453,13 @@ mod tests {
     fn vimshottari_level0_entity_lookup() {
         let cfg = vimshottari_config();
         let birth_jd = 2451545.0;
-        let result = nakshatra_level0_entity(birth_jd, 0.0, &cfg, DashaEntity::Graha(Graha::Ketu));
+        let result = nakshatra_level0_entity(
+            birth_jd,
+            0.0,
+            &cfg,
+            DashaEntity::Graha(Graha::Ketu),
+            &DashaVariationConfig::default(),
+        );
         assert!(result.is_some());
         let period = result.unwrap();
         assert_eq!(period.entity, DashaEntity::Graha(Graha::Ketu));
@@ -402,4 +475,102 @@ mod tests {
         let h = nakshatra_hierarchy(2451545.0, 0.0, &cfg, 4, &var).unwrap();
         assert_eq!(h.levels.len(), 5); // levels 0-4
     }
+
+    #[test]
+    fn savana_year_shortens_mahadasha() {
+        // Moon at 0 deg: Ketu mahadasha runs a full 7 years from birth.
+        let cfg = vimshottari_config();
+        let birth_jd = 2451545.0;
+        let julian = DashaVariationConfig::default();
+        let savana = DashaVariationConfig {
+            year_length: DashaYearLength::Savana,
+            ..julian
+        };
+        let hj = nakshatra_hierarchy(birth_jd, 0.0, &cfg, 1, &julian).unwrap();
+        let hs = nakshatra_hierarchy(birth_jd, 0.0, &cfg, 1, &savana).unwrap();
+
+        let ketu_j = hj.levels[0][0];
+        let ketu_s = hs.levels[0][0];
+        assert!((ketu_j.end_jd - birth_jd - 7.0 * 365.25).abs() < 1e-6);
+        assert!((ketu_s.end_jd - birth_jd - 7.0 * 360.0).abs() < 1e-6);
+        // 7 years x 5.25 days.
+        assert!((ketu_j.end_jd - ketu_s.end_jd - 36.75).abs() < 1e-6);
+        // Antardashas still tile the shortened mahadasha.
+        let last_ad = hs.levels[1].iter().rfind(|p| p.parent_idx == 0).unwrap();
+        assert!((last_ad.end_jd - ketu_s.end_jd).abs() < 1e-6);
+
+        // Snapshot agrees with the rescaled hierarchy.
+        let query_jd = ketu_s.end_jd + 1.0;
+        let snap = nakshatra_snapshot(birth_jd, 0.0, &cfg, query_jd, 0, &savana);
+        assert_eq!(snap.periods[0].entity, DashaEntity::Graha(Graha::Shukra));
+    }
+
+    #[test]
+    fn surya_mahadasha_savana_vs_julian() {
+        // Moon at 0 deg Krittika: Surya mahadasha runs its full 6 years first.
+        let cfg = vimshottari_config();
+        let birth_jd = 2451545.0;
+        let moon = crate::nakshatra::NAKSHATRA_SPAN_27 * 2.0;
+        let julian = DashaVariationConfig::default();
+        let savana = DashaVariationConfig {
+            year_length: DashaYearLength::Savana,
+            ..julian
+        };
+        let hj = nakshatra_hierarchy(birth_jd, moon, &cfg, 0, &julian).unwrap();
+        let hs = nakshatra_hierarchy(birth_jd, moon, &cfg, 0, &savana).unwrap();
+
+        let surya_j = hj.levels[0][0];
+        let surya_s = hs.levels[0][0];
+        assert_eq!(surya_j.entity, DashaEntity::Graha(Graha::Surya));
+        assert_eq!(surya_s.entity, DashaEntity::Graha(Graha::Surya));
+        assert!((surya_j.duration_days() - 6.0 * 365.25).abs() < 1e-6);
+        assert!((surya_s.duration_days() - 6.0 * 360.0).abs() < 1e-6);
+
+        // Every later mahadasha shrinks by the same 360/365.25 ratio.
+        for (j, s) in hj.levels[0].iter().zip(&hs.levels[0]).skip(1) {
+            let ratio = s.duration_days() / j.duration_days();
+            assert!((ratio - 360.0 / 365.25).abs() < 1e-12);
+        }
+    }
+    #[test]
+    fn level0_and_child_follow_year_length() {
+        let cfg = vimshottari_config();
+        let birth_jd = 2451545.0;
+        let savana = DashaVariationConfig {
+            year_length: DashaYearLength::Savana,
+            ..DashaVariationConfig::default()
+        };
+        let level0 = nakshatra_level0(birth_jd, 0.0, &cfg, &savana);
+        let hierarchy = nakshatra_hierarchy(birth_jd, 0.0, &cfg, 1, &savana).unwrap();
+        for (a, b) in level0.iter().zip(&hierarchy.levels[0]) {
+            assert_eq!(a.entity, b.entity);
+            assert_eq!((a.start_jd, a.end_jd), (b.start_jd, b.end_jd));
+        }
+
+        let shukra = nakshatra_level0_entity(
+            birth_jd,
+            0.0,
+            &cfg,
+            DashaEntity::Graha(Graha::Shukra),
+            &savana,
+        )
+        .unwrap();
+        assert!((shukra.duration_days() - 20.0 * 360.0).abs() < 1e-6);
+
+        let child = nakshatra_child_period(
+            &level0[0],
+            DashaEntity::Graha(Graha::Shukra),
+            &cfg,
+            cfg.default_method,
+        )
+        .unwrap();
+        let from_hierarchy = hierarchy.levels[1]
+            .iter()
+            .find(|p| p.parent_idx == 0 && p.entity == DashaEntity::Graha(Graha::Shukra))
+            .unwrap();
+        assert_eq!(
+            (child.start_jd, child.end_jd),
+            (from_hierarchy.start_jd, from_hierarchy.end_jd)
+        );
+    }
 }
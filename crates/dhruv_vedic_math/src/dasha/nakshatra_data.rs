@@ -1,7 +1,8 @@
 //! Const configuration data for nakshatra-based dasha systems.
 //!
 //! Each system defines a graha sequence, period lengths, and a mapping
-//! from the 27 nakshatras to graha sequence positions.
+//! from the 27 nakshatras to graha sequence positions. Systems that run on
+//! the 28-nakshatra scheme additionally assign Abhijit a sequence position.
 //!
 //! Provenance: BPHS chapters on dasha systems. See docs/clean_room_dasha.md.
 
@@ -30,6 +31,10 @@ pub struct NakshatraDashaConfig {
     /// If true, the birth-balance entry period is the graha's full period
     /// divided by how many nakshatras map to that graha. Used by Shashtihayani.
     pub divide_period_by_nakshatra_count: bool,
+    /// Graha sequence index for Abhijit. `Some` runs the system on the
+    /// 28-nakshatra scheme (birth nakshatra and balance from
+    /// `nakshatra28_from_longitude`); `None` keeps the 27-nakshatra scheme.
+    pub abhijit_graha_idx: Option<u8>,
 }
 
 impl NakshatraDashaConfig {
@@ -47,6 +52,20 @@ impl NakshatraDashaConfig {
         self.nakshatra_to_graha_idx[nakshatra_index.min(26) as usize]
     }
 
+    /// Get the starting graha index for a 28-scheme nakshatra (21 = Abhijit).
+    ///
+    /// Falls back to the 27-scheme mapping (Abhijit sharing Uttara Ashadha's
+    /// graha) when the config has no Abhijit assignment.
+    pub fn starting_graha_idx_28(&self, nakshatra28_index: u8) -> u8 {
+        match nakshatra28_index.min(27) {
+            i @ 0..=20 => self.starting_graha_idx(i),
+            21 => self
+                .abhijit_graha_idx
+                .unwrap_or_else(|| self.starting_graha_idx(20)),
+            i => self.starting_graha_idx(i - 1),
+        }
+    }
+
     /// Get the entry period in days for the starting graha of a nakshatra.
     ///
     /// For most systems, this is the full graha period. For Shashtihayani,
@@ -76,22 +95,21 @@ impl NakshatraDashaConfig {
 // Vimshottari Dasha (120 years, 9 grahas)
 // ---------------------------------------------------------------------------
 
-/// Vimshottari graha sequence: Ketu, Shukra, Surya, Chandra, Mangal, Rahu, Guru, Shani, Buddh.
-const VIMSHOTTARI_GRAHAS: [Graha; 9] = [
-    Graha::Ketu,
-    Graha::Shukra,
-    Graha::Surya,
-    Graha::Chandra,
-    Graha::Mangal,
-    Graha::Rahu,
-    Graha::Guru,
-    Graha::Shani,
-    Graha::Buddh,
+/// Vimshottari mahadasha lords and their periods in years, in dasha order:
+/// Ketu 7, Shukra 20, Surya 6, Chandra 10, Mangal 7, Rahu 18, Guru 16,
+/// Shani 19, Buddh 17 (120 years in all).
+pub const VIMSHOTTARI_SEQUENCE: [(Graha, f64); 9] = [
+    (Graha::Ketu, 7.0),
+    (Graha::Shukra, 20.0),
+    (Graha::Surya, 6.0),
+    (Graha::Chandra, 10.0),
+    (Graha::Mangal, 7.0),
+    (Graha::Rahu, 18.0),
+    (Graha::Guru, 16.0),
+    (Graha::Shani, 19.0),
+    (Graha::Buddh, 17.0),
 ];
 
-/// Vimshottari periods in years.
-const VIMSHOTTARI_YEARS: [f64; 9] = [7.0, 20.0, 6.0, 10.0, 7.0, 18.0, 16.0, 19.0, 17.0];
-
 /// Nakshatra-to-graha mapping for Vimshottari (every 3rd nakshatra shares a graha).
 const VIMSHOTTARI_NAK_MAP: [u8; 27] = [
     0, 1, 2, 3, 4, 5, 6, 7, 8, // Ashwini..Ashlesha
@@ -99,22 +117,53 @@ const VIMSHOTTARI_NAK_MAP: [u8; 27] = [
     0, 1, 2, 3, 4, 5, 6, 7, 8, // Mula..Revati
 ];
 
+/// Vimshottari dasha lord of a nakshatra (0-26, Ashwini = 0).
+///
+/// Returns `None` for indices above 26.
+pub fn nakshatra_dasha_lord(nakshatra_index: u8) -> Option<Graha> {
+    let gi = VIMSHOTTARI_NAK_MAP.get(nakshatra_index as usize)?;
+    Some(VIMSHOTTARI_SEQUENCE[*gi as usize].0)
+}
+
 /// Create the Vimshottari dasha configuration.
 pub fn vimshottari_config() -> NakshatraDashaConfig {
-    let periods_days: Vec<f64> = VIMSHOTTARI_YEARS
+    let periods_days: Vec<f64> = VIMSHOTTARI_SEQUENCE
         .iter()
-        .map(|&y| y * DAYS_PER_YEAR)
+        .map(|&(_, y)| y * DAYS_PER_YEAR)
         .collect();
     let total = periods_days.iter().sum();
     NakshatraDashaConfig {
         system: DashaSystem::Vimshottari,
-        graha_sequence: VIMSHOTTARI_GRAHAS.to_vec(),
+        graha_sequence: VIMSHOTTARI_SEQUENCE.iter().map(|&(g, _)| g).collect(),
         periods_days,
         total_period_days: total,
         nakshatra_to_graha_idx: VIMSHOTTARI_NAK_MAP,
         cycle_count: 1,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Vimshottari on the 28-nakshatra scheme (Abhijit inserted)
+// ---------------------------------------------------------------------------
+
+/// Abhijit's position in the Vimshottari sequence: Surya (index 2).
+///
+/// Abhijit is carved from the last pada of Uttara Ashadha, so it keeps that
+/// nakshatra's lord and the Surya -> Chandra succession into Shravana.
+const VIMSHOTTARI28_ABHIJIT_IDX: u8 = 2;
+
+/// Create the 28-nakshatra Vimshottari configuration.
+///
+/// Same grahas and periods as [`vimshottari_config`]; only the birth
+/// nakshatra and balance are taken from the 28-scheme spans.
+pub fn vimshottari28_config() -> NakshatraDashaConfig {
+    NakshatraDashaConfig {
+        system: DashaSystem::Vimshottari28,
+        abhijit_graha_idx: Some(VIMSHOTTARI28_ABHIJIT_IDX),
+        ..vimshottari_config()
     }
 }
 
@@ -157,6 +206,7 @@ pub fn ashtottari_config() -> NakshatraDashaConfig {
         cycle_count: 1,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -198,6 +248,7 @@ pub fn shodsottari_config() -> NakshatraDashaConfig {
         cycle_count: 1,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -239,6 +290,7 @@ pub fn dwadashottari_config() -> NakshatraDashaConfig {
         cycle_count: 1,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -279,6 +331,7 @@ pub fn panchottari_config() -> NakshatraDashaConfig {
         cycle_count: 1,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -319,6 +372,7 @@ pub fn shatabdika_config() -> NakshatraDashaConfig {
         cycle_count: 1,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -359,6 +413,7 @@ pub fn chaturashiti_config() -> NakshatraDashaConfig {
         cycle_count: 2,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -400,6 +455,7 @@ pub fn dwisaptati_config() -> NakshatraDashaConfig {
         cycle_count: 2,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -442,6 +498,7 @@ pub fn shashtihayani_config() -> NakshatraDashaConfig {
         cycle_count: 2,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: true,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -484,6 +541,7 @@ pub fn shat_trimsha_config() -> NakshatraDashaConfig {
         cycle_count: 3,
         default_method: SubPeriodMethod::ProportionalFromParent,
         divide_period_by_nakshatra_count: false,
+        abhijit_graha_idx: None,
     }
 }
 
@@ -506,6 +564,7 @@ pub fn nakshatra_config_for_system(system: DashaSystem) -> Option<NakshatraDasha
         DashaSystem::DwisaptatiSama => Some(dwisaptati_config()),
         DashaSystem::Shashtihayani => Some(shashtihayani_config()),
         DashaSystem::ShatTrimshaSama => Some(shat_trimsha_config()),
+        DashaSystem::Vimshottari28 => Some(vimshottari28_config()),
         _ => None,
     }
 }
@@ -544,6 +603,22 @@ mod tests {
         verify_config(&vimshottari_config(), 120.0, 9);
     }
 
+    #[test]
+    fn vimshottari_sequence_years_sum_to_120() {
+        let total: f64 = VIMSHOTTARI_SEQUENCE.iter().map(|&(_, y)| y).sum();
+        assert!((total - 120.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn nakshatra_dasha_lords() {
+        assert_eq!(nakshatra_dasha_lord(0), Some(Graha::Ketu));
+        assert_eq!(nakshatra_dasha_lord(1), Some(Graha::Shukra));
+        assert_eq!(nakshatra_dasha_lord(9), Some(Graha::Ketu));
+        assert_eq!(nakshatra_dasha_lord(26), Some(Graha::Buddh));
+        assert_eq!(nakshatra_dasha_lord(27), None);
+        assert_eq!(nakshatra_dasha_lord(u8::MAX), None);
+    }
+
     #[test]
     fn vimshottari_ashwini_starts_ketu() {
         let cfg = vimshottari_config();
@@ -557,6 +632,19 @@ mod tests {
         assert_eq!(cfg.starting_graha_idx(9), 0);
     }
 
+    #[test]
+    fn vimshottari28_maps_abhijit_and_shifts_later_nakshatras() {
+        let cfg = vimshottari28_config();
+        verify_config(&cfg, 120.0, 9);
+        assert_eq!(cfg.starting_graha_idx_28(20), 2); // Uttara Ashadha -> Surya
+        assert_eq!(
+            cfg.graha_sequence[cfg.starting_graha_idx_28(21) as usize],
+            Graha::Surya
+        );
+        assert_eq!(cfg.starting_graha_idx_28(22), 3); // Shravana -> Chandra
+        assert_eq!(cfg.starting_graha_idx_28(27), 8); // Revati -> Buddh
+    }
+
     #[test]
     fn ashtottari_108_years_8_grahas() {
         verify_config(&ashtottari_config(), 108.0, 8);
@@ -650,6 +738,7 @@ mod tests {
             DashaSystem::DwisaptatiSama,
             DashaSystem::Shashtihayani,
             DashaSystem::ShatTrimshaSama,
+            DashaSystem::Vimshottari28,
         ];
         for sys in systems {
             assert!(
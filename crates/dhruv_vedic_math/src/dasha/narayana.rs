@@ -0,0 +1,231 @@
+//! Narayana (Padakrama) dasha — Jaimini rashi dasha, variable periods.
+//!
+//! Starting rashi: stronger of lagna or 7th house sign.
+//! Direction: odd start = forward, even start = reverse.
+//! Progression (padakrama) depends on the starting sign type:
+//! - Chara (movable): consecutive signs
+//! - Sthira (fixed): every 6th sign
+//! - Dvisvabhava (dual): kendras, then panaparas, then apoklimas
+//!
+//! Periods use Chara period years (distance from rashi to its lord).
+//! Sub-period method: EqualFromSame (÷12).
+
+use super::balance::rashi_birth_balance;
+use super::chara::chara_period_years;
+use super::rashi_dasha::{rashi_hierarchy, rashi_snapshot};
+use super::rashi_strength::{RashiDashaInputs, stronger_rashi};
+use super::rashi_util::{SignType, is_odd_sign, sign_type};
+use super::types::{
+    DAYS_PER_YEAR, DashaEntity, DashaHierarchy, DashaLevel, DashaPeriod, DashaSnapshot, DashaSystem,
+};
+use super::variation::{DashaVariationConfig, SubPeriodMethod};
+use crate::error::VedicError;
+
+/// Default sub-period method for Narayana dasha.
+pub const NARAYANA_DEFAULT_METHOD: SubPeriodMethod = SubPeriodMethod::EqualFromSame;
+
+/// Total Narayana cycle years (chart-dependent, same as Chara total).
+pub fn narayana_total_years(inputs: &RashiDashaInputs) -> f64 {
+    (0..12).map(|r| chara_period_years(r, inputs)).sum()
+}
+
+/// Offsets from the starting rashi for each padakrama progression.
+const CONSECUTIVE_OFFSETS: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+const SIXTH_OFFSETS: [u8; 12] = [0, 5, 10, 3, 8, 1, 6, 11, 4, 9, 2, 7];
+const KENDRADI_OFFSETS: [u8; 12] = [0, 3, 6, 9, 1, 4, 7, 10, 2, 5, 8, 11];
+
+/// Generate the 12-rashi padakrama sequence from a starting rashi.
+pub fn narayana_sequence(start: u8) -> [u8; 12] {
+    let start = start % 12;
+    let forward = is_odd_sign(start);
+    let offsets = match sign_type(start) {
+        SignType::Chara => &CONSECUTIVE_OFFSETS,
+        SignType::Sthira => &SIXTH_OFFSETS,
+        SignType::Dvisvabhava => &KENDRADI_OFFSETS,
+    };
+    let mut seq = [0u8; 12];
+    for (slot, &offset) in seq.iter_mut().zip(offsets.iter()) {
+        *slot = if forward {
+            (start + offset) % 12
+        } else {
+            (start + 12 - offset) % 12
+        };
+    }
+    seq
+}
+
+/// Generate level-0 (mahadasha) periods for Narayana dasha.
+///
+/// Starting rashi: stronger of lagna or 7th house sign.
+pub fn narayana_level0(birth_jd: f64, inputs: &RashiDashaInputs) -> Vec<DashaPeriod> {
+    let lagna = inputs.bhava_rashi_indices[0];
+    let seventh = inputs.bhava_rashi_indices[6];
+    let start = stronger_rashi(lagna, seventh, inputs);
+    let seq = narayana_sequence(start);
+
+    let first_period_days = chara_period_years(start, inputs) * DAYS_PER_YEAR;
+    let (balance_days, _frac) = rashi_birth_balance(inputs.lagna_sidereal_lon, first_period_days);
+
+    let mut periods = Vec::with_capacity(12);
+    let mut cursor = birth_jd;
+
+    for (i, &rashi) in seq.iter().enumerate() {
+        let full_period_days = chara_period_years(rashi, inputs) * DAYS_PER_YEAR;
+        let duration = if i == 0 {
+            balance_days
+        } else {
+            full_period_days
+        };
+
+        let end = cursor + duration;
+        periods.push(DashaPeriod {
+            entity: DashaEntity::Rashi(rashi),
+            start_jd: cursor,
+            end_jd: end,
+            level: DashaLevel::Mahadasha,
+            order: (i as u16) + 1,
+            parent_idx: 0,
+        });
+        cursor = end;
+    }
+
+    periods
+}
+
+/// Full hierarchy for Narayana dasha.
+pub fn narayana_hierarchy(
+    birth_jd: f64,
+    inputs: &RashiDashaInputs,
+    max_level: u8,
+    variation: &DashaVariationConfig,
+) -> Result<DashaHierarchy, VedicError> {
+    let level0 = variation
+        .year_length
+        .rescale_level0(narayana_level0(birth_jd, inputs), birth_jd);
+    let total = narayana_total_years(inputs);
+    let period_fn = |r: u8| chara_period_years(r, inputs);
+    rashi_hierarchy(
+        DashaSystem::Narayana,
+        birth_jd,
+        level0,
+        &period_fn,
+        total,
+        NARAYANA_DEFAULT_METHOD,
+        max_level,
+        variation,
+    )
+}
+
+/// Snapshot for Narayana dasha.
+pub fn narayana_snapshot(
+    birth_jd: f64,
+    inputs: &RashiDashaInputs,
+    query_jd: f64,
+    max_level: u8,
+    variation: &DashaVariationConfig,
+) -> DashaSnapshot {
+    let level0 = variation
+        .year_length
+        .rescale_level0(narayana_level0(birth_jd, inputs), birth_jd);
+    let total = narayana_total_years(inputs);
+    let period_fn = |r: u8| chara_period_years(r, inputs);
+    rashi_snapshot(
+        DashaSystem::Narayana,
+        level0,
+        &period_fn,
+        total,
+        NARAYANA_DEFAULT_METHOD,
+        query_jd,
+        max_level,
+        variation,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_test_inputs(lagna_lon: f64) -> RashiDashaInputs {
+        // Sun Vrishabha, Moon Mithuna, Mars Tula, Mercury Kanya, Jupiter Dhanu,
+        // Venus Kumbha, Saturn Karka, Rahu Mesha, Ketu Tula.
+        let lons = [40.0, 75.0, 195.0, 160.0, 250.0, 310.0, 100.0, 10.0, 190.0];
+        RashiDashaInputs::new(lons, lagna_lon)
+    }
+
+    #[test]
+    fn sequence_covers_all_rashis() {
+        for start in 0..12u8 {
+            let mut seq = narayana_sequence(start).to_vec();
+            assert_eq!(seq[0], start);
+            seq.sort_unstable();
+            assert_eq!(seq, (0..12u8).collect::<Vec<_>>(), "start {start}");
+        }
+    }
+
+    #[test]
+    fn sequence_by_sign_type() {
+        // Mesha (movable, odd): consecutive forward.
+        assert_eq!(narayana_sequence(0)[..3], [0, 1, 2]);
+        // Vrishabha (fixed, even): every 6th in reverse.
+        assert_eq!(narayana_sequence(1)[..3], [1, 8, 3]);
+        // Mithuna (dual, odd): kendras forward.
+        assert_eq!(narayana_sequence(2)[..5], [2, 5, 8, 11, 3]);
+    }
+
+    #[test]
+    fn level0_starts_from_stronger_of_lagna_and_seventh() {
+        let inputs = make_test_inputs(15.0);
+        let periods = narayana_level0(2451545.0, &inputs);
+        let expected = stronger_rashi(0, 6, &inputs);
+        assert_eq!(periods[0].entity, DashaEntity::Rashi(expected));
+    }
+
+    #[test]
+    fn mahadasha_span_equals_cycle_length() {
+        // Lagna at 0° of its sign: no birth balance is consumed.
+        let inputs = make_test_inputs(0.0);
+        let birth_jd = 2451545.0;
+        let periods = narayana_level0(birth_jd, &inputs);
+        assert_eq!(periods.len(), 12);
+        let span: f64 = periods.iter().map(|p| p.end_jd - p.start_jd).sum();
+        let expected = narayana_total_years(&inputs) * DAYS_PER_YEAR;
+        assert!((span - expected).abs() < 1e-6, "span {span} vs {expected}");
+        assert!((periods[11].end_jd - birth_jd - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn mahadasha_span_less_birth_balance() {
+        // Lagna at 15° Mesha: half of the first period has elapsed.
+        let inputs = make_test_inputs(15.0);
+        let periods = narayana_level0(2451545.0, &inputs);
+        let first_full = match periods[0].entity {
+            DashaEntity::Rashi(r) => chara_period_years(r, &inputs),
+            _ => unreachable!(),
+        };
+        let span: f64 = periods.iter().map(|p| p.end_jd - p.start_jd).sum();
+        let expected = (narayana_total_years(&inputs) - first_full / 2.0) * DAYS_PER_YEAR;
+        assert!((span - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn hierarchy_and_snapshot_agree() {
+        let inputs = make_test_inputs(15.0);
+        let var = DashaVariationConfig::default();
+        let birth_jd = 2451545.0;
+        let query_jd = birth_jd + 5000.0;
+
+        let h = narayana_hierarchy(birth_jd, &inputs, 2, &var).unwrap();
+        assert_eq!(h.system, DashaSystem::Narayana);
+        assert_eq!(h.levels[1].len(), 144);
+
+        let snap = narayana_snapshot(birth_jd, &inputs, query_jd, 2, &var);
+        assert_eq!(snap.periods.len(), 3);
+        for (level, sp) in snap.periods.iter().enumerate() {
+            let active = h.levels[level]
+                .iter()
+                .find(|p| p.start_jd <= query_jd && query_jd < p.end_jd)
+                .expect("should find active");
+            assert_eq!(sp.entity, active.entity);
+        }
+    }
+}
@@ -3,7 +3,7 @@
 //! Interval convention: [start_jd, end_jd) — start is inclusive, end is exclusive.
 //! Adjacent periods: period[n].end_jd == period[n+1].start_jd (no gaps, no overlaps).
 
-use super::types::{DashaHierarchy, DashaPeriod, DashaSnapshot};
+use super::types::{DashaHierarchy, DashaLevel, DashaPeriod, DashaSnapshot};
 
 /// Binary search for the active period at query_jd within a sorted level.
 ///
@@ -62,6 +62,22 @@ pub fn snapshot_from_hierarchy(hierarchy: &DashaHierarchy, query_jd: f64) -> Das
     }
 }
 
+/// JD UTC at which period `index` ends and period `index + 1` begins at `level`.
+///
+/// Sub-period generation snaps each parent's last child end to the parent end,
+/// so boundaries are exact across parents: the boundary after a parent's last
+/// child equals the parent's own boundary at the level above.
+/// Returns None if the level was not computed or `index + 1` is out of range.
+pub fn period_boundary_jd(
+    hierarchy: &DashaHierarchy,
+    level: DashaLevel,
+    index: usize,
+) -> Option<f64> {
+    let periods = hierarchy.levels.get(level as usize)?;
+    periods.get(index + 1)?;
+    Some(periods[index].end_jd)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,4 +151,46 @@ mod tests {
     fn empty_level() {
         assert_eq!(find_active_period(&[], 100.0), None);
     }
+
+    #[test]
+    fn boundaries_coincide_across_levels() {
+        use crate::dasha::{DashaVariationConfig, nakshatra_hierarchy, vimshottari_config};
+
+        let h = nakshatra_hierarchy(
+            2451545.0,
+            123.4,
+            &vimshottari_config(),
+            2,
+            &DashaVariationConfig::default(),
+        )
+        .unwrap();
+        for level in [DashaLevel::Antardasha, DashaLevel::Pratyantardasha] {
+            let periods = &h.levels[level as usize];
+            let parents = &h.levels[level as usize - 1];
+            for (i, period) in periods.iter().enumerate() {
+                let parent = &parents[period.parent_idx as usize];
+                match periods.get(i + 1) {
+                    Some(next) => {
+                        let boundary = period_boundary_jd(&h, level, i).unwrap();
+                        assert_eq!(boundary, next.start_jd);
+                        if next.parent_idx != period.parent_idx {
+                            // Last child of its parent: no gap or overlap.
+                            assert_eq!(period.end_jd, parent.end_jd);
+                            let parent_boundary = period_boundary_jd(
+                                &h,
+                                DashaLevel::from_u8(level as u8 - 1).unwrap(),
+                                period.parent_idx as usize,
+                            );
+                            assert_eq!(parent_boundary, Some(boundary));
+                        }
+                    }
+                    None => {
+                        assert_eq!(period.end_jd, parent.end_jd);
+                        assert_eq!(period_boundary_jd(&h, level, i), None);
+                    }
+                }
+            }
+        }
+        assert_eq!(period_boundary_jd(&h, DashaLevel::Sookshmadasha, 0), None);
+    }
 }
@@ -87,7 +87,7 @@ const EXALTATION_DEG: [f64; 7] = [10.0, 3.0, 28.0, 15.0, 5.0, 27.0, 20.0];
 ///
 /// Rules applied in order (first decisive rule wins):
 /// 1. More planets present (count occupants)
-/// 2. Lord aspected by Jupiter or Mercury (natural benefics)
+/// 2. Lord conjoined (same rashi) with Jupiter or Mercury (natural benefics)
 /// 3. Lord has higher exaltation-point closeness
 /// 4. Odd-sign preference for odd pair, even for even
 /// 5. Rashi whose lord has higher longitude (fallback)
@@ -106,7 +106,7 @@ pub fn stronger_rashi(a: u8, b: u8, inputs: &RashiDashaInputs) -> u8 {
         return if occ_a > occ_b { a } else { b };
     }
 
-    // Rule 2: Lord aspected by Jupiter/Mercury (benefic association)
+    // Rule 2: Lord conjoined with Jupiter/Mercury (benefic association)
     let lord_a_benefic = lord_has_benefic_association(a, inputs);
     let lord_b_benefic = lord_has_benefic_association(b, inputs);
     if lord_a_benefic != lord_b_benefic {
@@ -81,7 +81,9 @@ pub fn shoola_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = shoola_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(shoola_level0(birth_jd, inputs), birth_jd);
     rashi_hierarchy(
         DashaSystem::Shoola,
         birth_jd,
@@ -102,7 +104,9 @@ pub fn shoola_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = shoola_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(shoola_level0(birth_jd, inputs), birth_jd);
     rashi_snapshot(
         DashaSystem::Shoola,
         level0,
@@ -80,7 +80,9 @@ pub fn sthira_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = sthira_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(sthira_level0(birth_jd, inputs), birth_jd);
     rashi_hierarchy(
         DashaSystem::Sthira,
         birth_jd,
@@ -101,7 +103,9 @@ pub fn sthira_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = sthira_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(sthira_level0(birth_jd, inputs), birth_jd);
     rashi_snapshot(
         DashaSystem::Sthira,
         level0,
@@ -2,7 +2,7 @@
 //!
 //! Dashas are hierarchical time-period systems from Vedic astrology (BPHS).
 //! This module defines the fundamental data structures shared across all
-//! 23 dasha systems.
+//! 25 dasha systems.
 
 use crate::{graha::Graha, rashi::Rashi};
 
@@ -157,7 +157,7 @@ impl DashaPeriod {
     }
 }
 
-/// All 23 dasha systems.
+/// All 25 dasha systems.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum DashaSystem {
@@ -190,10 +190,14 @@ pub enum DashaSystem {
     Kendradi = 20,
     KarakaKendradi = 21,
     KarakaKendradiGraha = 22,
+    // 28-nakshatra variant (1)
+    Vimshottari28 = 23,
+    // Jaimini padakrama (1)
+    Narayana = 24,
 }
 
-/// All 23 dasha systems in order.
-pub const ALL_DASHA_SYSTEMS: [DashaSystem; 23] = [
+/// All 25 dasha systems in order.
+pub const ALL_DASHA_SYSTEMS: [DashaSystem; 25] = [
     DashaSystem::Vimshottari,
     DashaSystem::Ashtottari,
     DashaSystem::Shodsottari,
@@ -217,6 +221,8 @@ pub const ALL_DASHA_SYSTEMS: [DashaSystem; 23] = [
     DashaSystem::Kendradi,
     DashaSystem::KarakaKendradi,
     DashaSystem::KarakaKendradiGraha,
+    DashaSystem::Vimshottari28,
+    DashaSystem::Narayana,
 ];
 
 impl DashaSystem {
@@ -255,6 +261,8 @@ impl DashaSystem {
             Self::Kendradi => "Kendradi",
             Self::KarakaKendradi => "Karaka Kendradi",
             Self::KarakaKendradiGraha => "Karaka Kendradi Graha",
+            Self::Vimshottari28 => "Vimshottari (28 Nakshatra)",
+            Self::Narayana => "Narayana",
         }
     }
 }
@@ -308,12 +316,14 @@ mod tests {
             DashaSystem::from_u8(22),
             Some(DashaSystem::KarakaKendradiGraha)
         );
-        assert_eq!(DashaSystem::from_u8(23), None);
+        assert_eq!(DashaSystem::from_u8(23), Some(DashaSystem::Vimshottari28));
+        assert_eq!(DashaSystem::from_u8(24), Some(DashaSystem::Narayana));
+        assert_eq!(DashaSystem::from_u8(25), None);
     }
 
     #[test]
     fn all_dasha_systems_count() {
-        assert_eq!(ALL_DASHA_SYSTEMS.len(), 23);
+        assert_eq!(ALL_DASHA_SYSTEMS.len(), 25);
     }
 
     #[test]
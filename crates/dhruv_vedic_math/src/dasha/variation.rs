@@ -1,5 +1,7 @@
 //! Dasha variation configuration: sub-period method selection per level.
 
+use super::types::{DAYS_PER_YEAR, DashaPeriod};
+
 /// How child periods are divided within a parent.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -50,6 +52,65 @@ impl YoginiScheme {
     }
 }
 
+/// Length of the "year" used to convert dasha years into days.
+///
+/// Period tables are in years; the engines convert them with
+/// [`DAYS_PER_YEAR`] (Julian year). Other conventions rescale every period
+/// about the birth instant, so balances and sub-period ratios are unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum DashaYearLength {
+    /// Julian year, 365.25 days.
+    #[default]
+    Julian = 0,
+    /// Savana year, 360 days.
+    Savana = 1,
+    /// Tropical (solar) year, 365.24219 days.
+    Tropical = 2,
+    /// Sidereal year, 365.25636 days.
+    Sidereal = 3,
+}
+
+impl DashaYearLength {
+    /// Create from raw u8 value.
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Self::Julian),
+            1 => Some(Self::Savana),
+            2 => Some(Self::Tropical),
+            3 => Some(Self::Sidereal),
+            _ => None,
+        }
+    }
+
+    /// Days in one dasha year.
+    pub fn days(self) -> f64 {
+        match self {
+            Self::Julian => DAYS_PER_YEAR,
+            Self::Savana => 360.0,
+            Self::Tropical => 365.242_19,
+            Self::Sidereal => 365.256_36,
+        }
+    }
+
+    /// Rescale level-0 periods computed with [`DAYS_PER_YEAR`] to this year
+    /// length, keeping `birth_jd` fixed.
+    ///
+    /// Child periods are proportional to their parent, so scaling level 0
+    /// is enough for the whole hierarchy.
+    pub fn rescale_level0(self, mut periods: Vec<DashaPeriod>, birth_jd: f64) -> Vec<DashaPeriod> {
+        if self == Self::Julian {
+            return periods;
+        }
+        let k = self.days() / DAYS_PER_YEAR;
+        for p in &mut periods {
+            p.start_jd = birth_jd + (p.start_jd - birth_jd) * k;
+            p.end_jd = birth_jd + (p.end_jd - birth_jd) * k;
+        }
+        periods
+    }
+}
+
 /// Per-level variation overrides.
 ///
 /// Array indices 0-4 correspond to DashaLevel 0-4.
@@ -64,6 +125,8 @@ pub struct DashaVariationConfig {
     pub yogini_scheme: YoginiScheme,
     /// For Ashtottari: use 28-nakshatra Abhijit detection.
     pub use_abhijit: bool,
+    /// Year length used to convert period years into days.
+    pub year_length: DashaYearLength,
 }
 
 impl Default for DashaVariationConfig {
@@ -72,6 +135,7 @@ impl Default for DashaVariationConfig {
             level_methods: [None; 5],
             yogini_scheme: YoginiScheme::Default,
             use_abhijit: true,
+            year_length: DashaYearLength::Julian,
         }
     }
 }
@@ -106,6 +170,14 @@ mod tests {
         assert_eq!(YoginiScheme::from_u8(2), None);
     }
 
+    #[test]
+    fn year_length_from_u8_and_days() {
+        assert_eq!(DashaYearLength::from_u8(1), Some(DashaYearLength::Savana));
+        assert_eq!(DashaYearLength::from_u8(4), None);
+        assert_eq!(DashaYearLength::default().days(), DAYS_PER_YEAR);
+        assert_eq!(DashaYearLength::Savana.days(), 360.0);
+    }
+
     #[test]
     fn default_variation_uses_system_default() {
         let cfg = DashaVariationConfig::default();
@@ -84,7 +84,9 @@ pub fn yogardha_hierarchy(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
-    let level0 = yogardha_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(yogardha_level0(birth_jd, inputs), birth_jd);
     let total = yogardha_total_years(inputs);
     let period_fn = |r: u8| yogardha_period_years(r, inputs);
     rashi_hierarchy(
@@ -107,7 +109,9 @@ pub fn yogardha_snapshot(
     max_level: u8,
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
-    let level0 = yogardha_level0(birth_jd, inputs);
+    let level0 = variation
+        .year_length
+        .rescale_level0(yogardha_level0(birth_jd, inputs), birth_jd);
     let total = yogardha_total_years(inputs);
     let period_fn = |r: u8| yogardha_period_years(r, inputs);
     rashi_snapshot(
@@ -159,7 +159,9 @@ pub fn yogini_hierarchy(
     variation: &DashaVariationConfig,
 ) -> Result<DashaHierarchy, VedicError> {
     let max_level = max_level.min(MAX_DASHA_LEVEL);
-    let level0 = yogini_level0(birth_jd, moon_sidereal_lon, config);
+    let level0 = variation
+        .year_length
+        .rescale_level0(yogini_level0(birth_jd, moon_sidereal_lon, config), birth_jd);
     let mut levels: Vec<Vec<DashaPeriod>> = vec![level0];
 
     for depth in 1..=max_level {
@@ -192,7 +194,9 @@ pub fn yogini_snapshot(
     variation: &DashaVariationConfig,
 ) -> DashaSnapshot {
     let max_level = max_level.min(MAX_DASHA_LEVEL);
-    let level0 = yogini_level0(birth_jd, moon_sidereal_lon, config);
+    let level0 = variation
+        .year_length
+        .rescale_level0(yogini_level0(birth_jd, moon_sidereal_lon, config), birth_jd);
     let mut active_periods: Vec<DashaPeriod> = Vec::with_capacity((max_level + 1) as usize);
 
     let active_idx = match find_active_period(&level0, query_jd) {
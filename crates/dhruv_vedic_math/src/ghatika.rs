@@ -11,6 +11,9 @@ pub const GHATIKA_MINUTES: f64 = 24.0;
 /// Number of ghatikas per Vedic day.
 pub const GHATIKA_COUNT: u8 = 60;
 
+/// Vighatikas per ghatika (one vighatika = 24 seconds).
+pub const VIGHATIKAS_PER_GHATIKA: f64 = 60.0;
+
 /// Result of ghatika computation (pure arithmetic, no times).
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GhatikaPosition {
@@ -42,6 +45,24 @@ pub fn ghatika_from_elapsed(
     }
 }
 
+/// Convert a "ghati-vighati since sunrise" reading to clock hours.
+///
+/// Uses the fixed 24-minute ghatika. Add the result (as days, `hours / 24`)
+/// to the sunrise JD to get the absolute instant.
+pub fn ghatika_to_duration(ghati: f64, vighati: f64) -> f64 {
+    (ghati + vighati / VIGHATIKAS_PER_GHATIKA) * GHATIKA_MINUTES / 60.0
+}
+
+/// Convert clock hours since sunrise to whole ghatis and remaining vighatis.
+///
+/// Inverse of [`ghatika_to_duration`]. Negative durations clamp to zero.
+/// Durations past the day are not wrapped: 30 hours is 75 ghatis.
+pub fn duration_to_ghatika(hours: f64) -> (u32, f64) {
+    let ghatis = hours.max(0.0) * 60.0 / GHATIKA_MINUTES;
+    let whole = ghatis.floor();
+    (whole as u32, (ghatis - whole) * VIGHATIKAS_PER_GHATIKA)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,6 +104,30 @@ mod tests {
         assert!((ghatika_secs / 60.0 - 24.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn two_ghati_thirty_vighati_is_one_hour() {
+        assert!((ghatika_to_duration(2.0, 30.0) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn duration_to_ghatika_round_trip() {
+        let (ghati, vighati) = duration_to_ghatika(1.0);
+        assert_eq!(ghati, 2);
+        assert!((vighati - 30.0).abs() < 1e-9);
+
+        let (ghati, vighati) = duration_to_ghatika(7.3);
+        assert!((ghatika_to_duration(ghati as f64, vighati) - 7.3).abs() < 1e-12);
+        assert_eq!(duration_to_ghatika(-1.0), (0, 0.0));
+    }
+
+    #[test]
+    fn duration_to_ghatika_does_not_saturate() {
+        // 120 hours = 300 ghatis, past the range of a u8.
+        let (ghati, vighati) = duration_to_ghatika(120.0);
+        assert_eq!(ghati, 300);
+        assert!(vighati.abs() < 1e-9);
+    }
+
     #[test]
     fn ghatika_variable_day_length() {
         // Vedic day of 87000 seconds (slightly longer)
@@ -7,9 +7,10 @@
 //!
 //! Clean-room implementation from BPHS (Brihat Parashara Hora Shastra).
 
-use crate::graha::{Graha, SAPTA_GRAHAS, rashi_lord_by_index};
+use crate::graha::{ALL_GRAHAS, Graha, SAPTA_GRAHAS, rashi_lord_by_index};
 use crate::hora::{Hora, hora_at};
 use crate::masa::Masa;
+use crate::rashi::rashi_from_longitude;
 use crate::samvatsara::Samvatsara;
 use crate::vaar::Vaar;
 
@@ -219,6 +220,29 @@ pub fn panchadha_maitri(
     }
 }
 
+/// Five-fold relationship of every graha toward every other.
+///
+/// `sidereal_lons` are indexed by [`Graha::index`]; their rashis drive the
+/// tatkalika part. Entry `[g][o]` is how `g` regards `o`. The diagonal is
+/// `None` since a graha has no relationship with itself.
+pub fn relationship_matrix(sidereal_lons: &[f64; 9]) -> [[Option<PanchadhaMaitri>; 9]; 9] {
+    let rashis = sidereal_lons.map(|lon| rashi_from_longitude(lon).rashi_index);
+    let mut matrix = [[None; 9]; 9];
+    for g in ALL_GRAHAS {
+        let gi = g.index() as usize;
+        for o in ALL_GRAHAS {
+            let oi = o.index() as usize;
+            if gi != oi {
+                matrix[gi][oi] = Some(panchadha_maitri(
+                    naisargika_maitri(g, o),
+                    tatkalika_maitri(rashis[gi], rashis[oi]),
+                ));
+            }
+        }
+    }
+    matrix
+}
+
 // ---------------------------------------------------------------------------
 // 1e. Dignity Determination (Sapta Grahas)
 // ---------------------------------------------------------------------------
@@ -753,7 +777,6 @@ fn longitude_rashi_index(sidereal_lon: f64) -> Option<u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graha::ALL_GRAHAS;
 
     // --- Exaltation/Debilitation ---
 
@@ -1039,6 +1062,47 @@ mod tests {
         assert_eq!(panchadha_maitri(N::Enemy, T::Enemy), P::AdhiShatru);
     }
 
+    #[test]
+    fn relationship_matrix_keeps_sun_saturn_enmity() {
+        use PanchadhaMaitri as P;
+
+        // Sun and Saturn both in Mesha: same sign is tatkalika enemy, so the
+        // natural enmity stands in full.
+        let mut lons = [45.0, 70.0, 100.0, 130.0, 160.0, 190.0, 10.0, 220.0, 40.0];
+        lons[Graha::Surya.index() as usize] = 5.0;
+        let m = relationship_matrix(&lons);
+        let (su, sa) = (Graha::Surya.index() as usize, Graha::Shani.index() as usize);
+        assert_eq!(m[su][sa], Some(P::AdhiShatru));
+        assert_eq!(m[sa][su], Some(P::AdhiShatru));
+
+        // Saturn in the 2nd from the Sun: temporal friendship softens the
+        // natural enmity to Sama but never lifts it to friendship.
+        lons[sa] = 35.0;
+        let m = relationship_matrix(&lons);
+        assert_eq!(m[su][sa], Some(P::Sama));
+        assert_eq!(m[sa][su], Some(P::Sama));
+    }
+
+    #[test]
+    fn relationship_matrix_diagonal_is_none() {
+        let lons = [0.0, 30.0, 60.0, 90.0, 120.0, 150.0, 180.0, 210.0, 30.0];
+        let m = relationship_matrix(&lons);
+        for (i, row) in m.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                assert_eq!(cell.is_none(), i == j, "[{i}][{j}]");
+            }
+        }
+        // Off-diagonal cells agree with the individual calls.
+        let (ma, gu) = (Graha::Mangal, Graha::Guru);
+        assert_eq!(
+            m[ma.index() as usize][gu.index() as usize],
+            Some(panchadha_maitri(
+                naisargika_maitri(ma, gu),
+                tatkalika_maitri(2, 4)
+            ))
+        );
+    }
+
     // --- Dignity ---
 
     #[test]
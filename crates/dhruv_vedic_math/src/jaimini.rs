@@ -0,0 +1,346 @@
+//! Jaimini rashi drishti (sign aspects).
+//!
+//! Unlike Parashari graha drishti, Jaimini aspects are cast sign to sign by
+//! quality:
+//! - Chara (movable) signs aspect the three Sthira (fixed) signs, except the
+//!   adjacent one (the 2nd).
+//! - Sthira signs aspect the three Chara signs, except the adjacent one
+//!   (the 12th).
+//! - Dvisvabhava (dual) signs aspect the other three Dvisvabhava signs.
+//!
+//! Grahas in a sign share its aspects.
+//!
+//! Argala (intervention) on a sign comes from grahas in its 2nd, 4th, and
+//! 11th; each is countered (virodhargala) by grahas in the 12th, 10th, and
+//! 3rd respectively. The 5th gives secondary argala, countered by the 9th.
+//!
+//! Clean-room implementation from Jaimini Sutras 1.1.
+//! See `docs/clean_room_drishti.md`.
+
+use crate::dasha::rashi_util::{SignType, sign_type};
+use crate::graha::{ALL_GRAHAS, Graha, nth_rashi_from};
+use crate::rashi::{ALL_RASHIS, Rashi};
+
+/// Whether `from` aspects `to` under Jaimini rashi drishti.
+///
+/// A sign never aspects itself. The relation is symmetric.
+pub fn rashi_drishti(from: Rashi, to: Rashi) -> bool {
+    let (from, to) = (from.index(), to.index());
+    match sign_type(from) {
+        SignType::Chara => sign_type(to) == SignType::Sthira && to != (from + 1) % 12,
+        SignType::Sthira => sign_type(to) == SignType::Chara && to != (from + 11) % 12,
+        SignType::Dvisvabhava => sign_type(to) == SignType::Dvisvabhava && to != from,
+    }
+}
+
+/// Full 12x12 rashi drishti table, `[from][to]` by rashi index.
+pub fn rashi_drishti_matrix() -> [[bool; 12]; 12] {
+    let mut matrix = [[false; 12]; 12];
+    for (row, &from) in matrix.iter_mut().zip(ALL_RASHIS.iter()) {
+        for (cell, &to) in row.iter_mut().zip(ALL_RASHIS.iter()) {
+            *cell = rashi_drishti(from, to);
+        }
+    }
+    matrix
+}
+
+/// Signs aspected by `from_sign` under Jaimini rashi drishti.
+///
+/// Always three signs, in zodiacal order counted from `from_sign`.
+pub fn rashi_aspects(from_sign: Rashi) -> Vec<Rashi> {
+    let from = from_sign.index();
+    (1..12)
+        .map(|offset| ALL_RASHIS[((from + offset) % 12) as usize])
+        .filter(|&to| rashi_drishti(from_sign, to))
+        .collect()
+}
+
+/// Primary argala houses paired with the house that counters each.
+pub const ARGALA_HOUSES: [(u8, u8); 3] = [(2, 12), (4, 10), (11, 3)];
+
+/// Secondary argala house paired with the house that counters it.
+pub const SECONDARY_ARGALA_HOUSE: (u8, u8) = (5, 9);
+
+/// Occupancy used to compute argala.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgalaInput<'a> {
+    /// 0-based rashi indices of the 9 grahas, indexed by [`Graha::index`].
+    Positions(&'a [u8; 9]),
+    /// Number of grahas occupying each sign, indexed by 0-based rashi index.
+    /// Entries then carry counts only; their graha lists are empty.
+    OccupantCounts(&'a [u8; 12]),
+}
+
+/// Argala options.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArgalaConfig {
+    /// Also report the secondary argala from the 5th, countered by the 9th.
+    pub include_secondary: bool,
+}
+
+/// One argala house and its virodhargala counterpart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgalaEntry {
+    /// House (2, 4, 11, or 5) from the reference sign giving argala.
+    pub house: u8,
+    /// House (12, 10, 3, or 9) from the reference sign giving virodhargala.
+    pub virodha_house: u8,
+    /// True for the 2nd/4th/11th, false for the secondary 5th.
+    pub primary: bool,
+    /// Grahas occupying `house` (empty for [`ArgalaInput::OccupantCounts`]).
+    pub argala_grahas: Vec<Graha>,
+    /// Grahas occupying `virodha_house` (empty for counts input).
+    pub virodha_grahas: Vec<Graha>,
+    /// Number of grahas occupying `house`.
+    pub argala_count: u8,
+    /// Number of grahas occupying `virodha_house`.
+    pub virodha_count: u8,
+}
+
+impl ArgalaEntry {
+    /// True when grahas form argala and at least as many counter it.
+    pub fn is_obstructed(&self) -> bool {
+        self.argala_count > 0 && self.virodha_count >= self.argala_count
+    }
+
+    /// Grahas forming argala minus those countering it, floored at zero.
+    pub fn net(&self) -> u8 {
+        self.argala_count.saturating_sub(self.virodha_count)
+    }
+}
+
+/// Argala and virodhargala on one reference sign.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgalaResult {
+    /// Reference sign (or house) receiving the intervention.
+    pub reference_sign: Rashi,
+    /// Entries in [`ARGALA_HOUSES`] order (2nd, 4th, 11th), followed by
+    /// [`SECONDARY_ARGALA_HOUSE`] when `include_secondary` is set.
+    pub entries: Vec<ArgalaEntry>,
+}
+
+impl ArgalaResult {
+    /// Total unobstructed argala: sum of per-house net graha counts.
+    pub fn net(&self) -> u8 {
+        self.entries.iter().map(ArgalaEntry::net).sum()
+    }
+}
+
+/// Argala on `reference_sign`.
+///
+/// Houses are counted inclusively from `reference_sign` (the sign itself is
+/// the 1st). The same obstruction rule applies to every house pair, whether
+/// occupancy comes from graha positions or per-sign counts.
+pub fn argala(
+    input: ArgalaInput<'_>,
+    reference_sign: Rashi,
+    config: &ArgalaConfig,
+) -> ArgalaResult {
+    let from = reference_sign.index();
+    let grahas_in_house = |house: u8| -> Vec<Graha> {
+        let sign = nth_rashi_from(from, house);
+        match input {
+            ArgalaInput::Positions(positions) => ALL_GRAHAS
+                .iter()
+                .copied()
+                .filter(|g| positions[g.index() as usize] % 12 == sign)
+                .collect(),
+            ArgalaInput::OccupantCounts(_) => Vec::new(),
+        }
+    };
+    let count_in = |house: u8, grahas: &[Graha]| match input {
+        ArgalaInput::Positions(_) => grahas.len() as u8,
+        ArgalaInput::OccupantCounts(counts) => counts[nth_rashi_from(from, house) as usize],
+    };
+    let secondary = config
+        .include_secondary
+        .then_some((SECONDARY_ARGALA_HOUSE, false));
+    let entries = ARGALA_HOUSES
+        .iter()
+        .map(|&pair| (pair, true))
+        .chain(secondary)
+        .map(|((house, virodha_house), primary)| {
+            let argala_grahas = grahas_in_house(house);
+            let virodha_grahas = grahas_in_house(virodha_house);
+            ArgalaEntry {
+                house,
+                virodha_house,
+                primary,
+                argala_count: count_in(house, &argala_grahas),
+                virodha_count: count_in(virodha_house, &virodha_grahas),
+                argala_grahas,
+                virodha_grahas,
+            }
+        })
+        .collect();
+    ArgalaResult {
+        reference_sign,
+        entries,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn movable_aspects_non_adjacent_fixed_signs() {
+        assert_eq!(
+            rashi_aspects(Rashi::Mesha),
+            vec![Rashi::Simha, Rashi::Vrischika, Rashi::Kumbha]
+        );
+        assert_eq!(
+            rashi_aspects(Rashi::Makara),
+            vec![Rashi::Vrishabha, Rashi::Simha, Rashi::Vrischika]
+        );
+    }
+
+    #[test]
+    fn fixed_aspects_non_adjacent_movable_signs() {
+        assert_eq!(
+            rashi_aspects(Rashi::Vrishabha),
+            vec![Rashi::Karka, Rashi::Tula, Rashi::Makara]
+        );
+    }
+
+    #[test]
+    fn dual_aspects_other_duals() {
+        assert_eq!(
+            rashi_aspects(Rashi::Mithuna),
+            vec![Rashi::Kanya, Rashi::Dhanu, Rashi::Meena]
+        );
+    }
+
+    /// Lagna Mesha: Guru in the 4th (Karka) is countered by Shani in the 10th
+    /// (Makara); Shukra in the 2nd (Vrishabha) is countered by Ketu in the 12th.
+    #[test]
+    fn fourth_house_argala_countered_by_tenth() {
+        // Sun, Moon, Mars, Mercury, Jupiter, Venus, Saturn, Rahu, Ketu.
+        let positions = [0, 6, 8, 0, 3, 1, 9, 5, 11];
+        let result = argala(
+            ArgalaInput::Positions(&positions),
+            Rashi::Mesha,
+            &ArgalaConfig::default(),
+        );
+
+        let second = &result.entries[0];
+        assert_eq!(second.argala_grahas, vec![Graha::Shukra]);
+        assert!(second.virodha_grahas.contains(&Graha::Ketu));
+        assert!(second.is_obstructed());
+
+        let fourth = &result.entries[1];
+        assert_eq!((fourth.house, fourth.virodha_house), (4, 10));
+        assert_eq!(fourth.argala_grahas, vec![Graha::Guru]);
+        assert_eq!(fourth.virodha_grahas, vec![Graha::Shani]);
+        assert!(fourth.is_obstructed());
+        assert_eq!(fourth.net(), 0);
+
+        let eleventh = &result.entries[2];
+        assert!(eleventh.argala_grahas.is_empty());
+        assert!(!eleventh.is_obstructed());
+        assert_eq!(result.net(), 0);
+    }
+
+    #[test]
+    fn unopposed_argala_counts_toward_net() {
+        // Moon and Mars in the 11th (Kumbha) from Mesha, nothing in the 3rd.
+        let positions = [0, 10, 10, 0, 3, 1, 9, 5, 11];
+        let result = argala(
+            ArgalaInput::Positions(&positions),
+            Rashi::Mesha,
+            &ArgalaConfig::default(),
+        );
+        assert_eq!(result.entries[2].net(), 2);
+        assert_eq!(result.net(), 2);
+        assert_eq!(result.entries.len(), 3);
+    }
+
+    /// Both input forms agree on the counts of the same chart.
+    #[test]
+    fn positions_and_counts_agree() {
+        let positions = [0, 6, 8, 0, 3, 1, 9, 5, 11];
+        let mut counts = [0u8; 12];
+        for &p in &positions {
+            counts[p as usize] += 1;
+        }
+        let config = ArgalaConfig {
+            include_secondary: true,
+        };
+        let by_graha = argala(ArgalaInput::Positions(&positions), Rashi::Karka, &config);
+        let by_count = argala(ArgalaInput::OccupantCounts(&counts), Rashi::Karka, &config);
+        assert_eq!(by_graha.entries.len(), by_count.entries.len());
+        for (g, c) in by_graha.entries.iter().zip(&by_count.entries) {
+            assert_eq!(
+                (g.argala_count, g.virodha_count, g.is_obstructed()),
+                (c.argala_count, c.virodha_count, c.is_obstructed())
+            );
+        }
+        assert_eq!(by_graha.net(), by_count.net());
+    }
+
+    #[test]
+    fn mesha_aspects_fixed_signs_except_vrishabha() {
+        for &to in &ALL_RASHIS {
+            let expected = matches!(to, Rashi::Simha | Rashi::Vrischika | Rashi::Kumbha);
+            assert_eq!(rashi_drishti(Rashi::Mesha, to), expected, "{to:?}");
+        }
+        assert!(!rashi_drishti(Rashi::Mesha, Rashi::Vrishabha));
+    }
+
+    #[test]
+    fn matrix_is_symmetric_with_three_aspects_per_sign() {
+        let m = rashi_drishti_matrix();
+        for (i, row) in m.iter().enumerate() {
+            assert!(!row[i]);
+            assert_eq!(row.iter().filter(|&&a| a).count(), 3, "row {i}");
+            for (j, &cell) in row.iter().enumerate() {
+                assert_eq!(cell, m[j][i], "{i} -> {j}");
+            }
+        }
+    }
+
+    /// Lagna Simha: one graha in the 5th (Dhanu) against two in the 9th
+    /// (Mesha) obstructs the secondary argala; two in the 11th (Mithuna)
+    /// outnumber the single graha in the 3rd (Tula).
+    #[test]
+    fn counts_obstruct_secondary_argala() {
+        let mut counts = [0u8; 12];
+        counts[Rashi::Dhanu.index() as usize] = 1;
+        counts[Rashi::Mesha.index() as usize] = 2;
+        counts[Rashi::Mithuna.index() as usize] = 2;
+        counts[Rashi::Tula.index() as usize] = 1;
+
+        let config = ArgalaConfig {
+            include_secondary: true,
+        };
+        let result = argala(ArgalaInput::OccupantCounts(&counts), Rashi::Simha, &config);
+        let entries = &result.entries;
+        assert_eq!(entries.len(), 4);
+        assert!(entries[..3].iter().all(|e| e.primary));
+        assert!(entries.iter().all(|e| e.argala_grahas.is_empty()));
+
+        let eleventh = &entries[2];
+        assert_eq!((eleventh.house, eleventh.virodha_house), (11, 3));
+        assert_eq!((eleventh.argala_count, eleventh.virodha_count), (2, 1));
+        assert!(!eleventh.is_obstructed());
+
+        let fifth = &entries[3];
+        assert!(!fifth.primary);
+        assert_eq!((fifth.house, fifth.virodha_house), (5, 9));
+        assert_eq!((fifth.argala_count, fifth.virodha_count), (1, 2));
+        assert!(fifth.is_obstructed());
+
+        // Empty argala houses are never obstructed.
+        assert!(!entries[0].is_obstructed() && !entries[1].is_obstructed());
+        assert_eq!(result.net(), 1);
+    }
+
+    #[test]
+    fn aspects_are_mutual() {
+        for &from in &ALL_RASHIS {
+            for to in rashi_aspects(from) {
+                assert!(rashi_aspects(to).contains(&from), "{from:?} -> {to:?}");
+            }
+        }
+    }
+}
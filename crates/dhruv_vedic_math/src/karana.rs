@@ -3,12 +3,12 @@
 //! The synodic month is divided into 60 karanas, each spanning 6 degrees of
 //! Moon-Sun elongation. There are 7 movable karanas and 4 fixed karanas.
 //!
-//! Traditional mapping:
-//! - Karana 0: Kinstugna (fixed, second half of Amavasya)
-//! - Karanas 1-56: cycle through 7 movable (Bava, Balava, Kaulava, Taitilla, Garija, Vanija, Vishti)
-//! - Karana 57: Shakuni (fixed)
-//! - Karana 58: Chatuspad (fixed)
-//! - Karana 59: Naga (fixed)
+//! Traditional mapping by 1-based half-tithi slot:
+//! - Slot 1: Kinstugna (fixed, first half of Shukla Pratipada)
+//! - Slots 2-57: cycle through 7 movable (Bava, Balava, Kaulava, Taitilla, Garija, Vanija, Vishti)
+//! - Slot 58: Shakuni (fixed, second half of Krishna Chaturdashi)
+//! - Slot 59: Chatuspad (fixed, first half of Amavasya)
+//! - Slot 60: Naga (fixed, second half of Amavasya)
 //!
 //! Clean-room implementation from standard Vedic convention.
 
@@ -101,6 +101,11 @@ impl Karana {
             Self::Shakuni | Self::Chatuspad | Self::Naga | Self::Kinstugna
         )
     }
+
+    /// Whether this is one of the 7 repeating movable (chara) karanas.
+    pub const fn is_movable(self) -> bool {
+        !self.is_fixed()
+    }
 }
 
 /// Result of karana-from-elongation computation (pure geometry, no times).
@@ -120,14 +125,19 @@ fn normalize_360(deg: f64) -> f64 {
     if r < 0.0 { r + 360.0 } else { r }
 }
 
-/// Map karana sequence index (0-59) to the karana name using traditional rules.
-fn karana_from_sequence_index(idx: u8) -> Karana {
-    match idx {
-        0 => Karana::Kinstugna,
-        57 => Karana::Shakuni,
-        58 => Karana::Chatuspad,
-        59 => Karana::Naga,
-        i => MOVABLE_KARANAS[((i - 1) % 7) as usize],
+/// Map a 1-based half-tithi slot (1..=60) in the synodic month to its karana.
+///
+/// The four fixed karanas occupy the slots around Amavasya (1, 58, 59, 60);
+/// slots 2-57 cycle through the 7 movable karanas eight times.
+/// Returns `None` for slots outside 1..=60.
+pub const fn karana_from_half_tithi(half_tithi: u8) -> Option<Karana> {
+    match half_tithi {
+        1 => Some(Karana::Kinstugna),
+        2..=57 => Some(MOVABLE_KARANAS[((half_tithi - 2) % 7) as usize]),
+        58 => Some(Karana::Shakuni),
+        59 => Some(Karana::Chatuspad),
+        60 => Some(Karana::Naga),
+        _ => None,
     }
 }
 
@@ -139,7 +149,7 @@ pub fn karana_from_elongation(elongation_deg: f64) -> KaranaPosition {
     let elong = normalize_360(elongation_deg);
     let idx = (elong / KARANA_SEGMENT_DEG).floor() as u8;
     let idx = idx.min(59);
-    let karana = karana_from_sequence_index(idx);
+    let karana = karana_from_half_tithi(idx + 1).expect("karana index clamped to 0..=59");
     let degrees_in_karana = elong - (idx as f64) * KARANA_SEGMENT_DEG;
 
     KaranaPosition {
@@ -182,6 +192,38 @@ mod tests {
         assert!(!Karana::Vishti.is_fixed());
     }
 
+    #[test]
+    fn karana_movable_is_complement_of_fixed() {
+        for k in ALL_KARANAS {
+            assert_eq!(k.is_movable(), !k.is_fixed());
+        }
+        assert_eq!(ALL_KARANAS.iter().filter(|k| k.is_movable()).count(), 7);
+    }
+
+    #[test]
+    fn fixed_karanas_land_on_amavasya_slots() {
+        assert_eq!(karana_from_half_tithi(1), Some(Karana::Kinstugna));
+        assert_eq!(karana_from_half_tithi(58), Some(Karana::Shakuni));
+        assert_eq!(karana_from_half_tithi(59), Some(Karana::Chatuspad));
+        assert_eq!(karana_from_half_tithi(60), Some(Karana::Naga));
+        for slot in 2..=57u8 {
+            assert!(karana_from_half_tithi(slot).unwrap().is_movable());
+        }
+        assert_eq!(karana_from_half_tithi(2), Some(Karana::Bava));
+        assert_eq!(karana_from_half_tithi(57), Some(Karana::Vishti));
+        assert_eq!(karana_from_half_tithi(0), None);
+        assert_eq!(karana_from_half_tithi(61), None);
+    }
+
+    #[test]
+    fn karana_from_elongation_matches_half_tithi_slot() {
+        for idx in 0..60u8 {
+            let pos = karana_from_elongation(idx as f64 * 6.0 + 3.0);
+            assert_eq!(pos.karana_index, idx);
+            assert_eq!(Some(pos.karana), karana_from_half_tithi(idx + 1));
+        }
+    }
+
     #[test]
     fn karana_sequence_0_is_kinstugna() {
         let pos = karana_from_elongation(0.0);
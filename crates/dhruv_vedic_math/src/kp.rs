@@ -0,0 +1,98 @@
+//! Krishnamurti Paddhati (KP) star lord and sub lord of a longitude.
+//!
+//! Each 13°20' nakshatra is split into nine subs, one per Vimshottari lord,
+//! in dasha order starting from the nakshatra's own lord. A sub's width is
+//! proportional to its lord's Vimshottari years (e.g. Ketu 7/120 of the
+//! nakshatra = 0°46'40"), so the nine subs exactly fill the nakshatra.
+//!
+//! Clean-room implementation from the standard KP sub table.
+
+use crate::dasha::VIMSHOTTARI_SEQUENCE;
+use crate::graha::Graha;
+use crate::nakshatra::{Nakshatra, nakshatra_from_longitude};
+
+/// Vimshottari cycle length in years (sum of all nine lords' periods).
+const VIMSHOTTARI_YEARS: f64 = 120.0;
+
+/// Vimshottari years per degree of nakshatra arc (exactly 9), written as a
+/// product so boundaries such as 3°00' map to whole years without rounding.
+const YEARS_PER_DEG: f64 = VIMSHOTTARI_YEARS * 27.0 / 360.0;
+
+/// Nakshatra, star lord, and sub lord of a sidereal longitude.
+///
+/// Subs are half-open `[start, end)`: a longitude exactly on a sub boundary
+/// belongs to the later sub. The last sub of a nakshatra ends at the next
+/// nakshatra's start, where that nakshatra's first sub (its own lord) begins.
+pub fn kp_sublord(sidereal_longitude_deg: f64) -> (Nakshatra, Graha, Graha) {
+    let info = nakshatra_from_longitude(sidereal_longitude_deg);
+    let star_lord = info.nakshatra.lord();
+    let start = VIMSHOTTARI_SEQUENCE
+        .iter()
+        .position(|&(g, _)| g == star_lord)
+        .unwrap_or(0);
+
+    // Walk the subs in years to keep boundaries exact; the last sub takes
+    // whatever remains so rounding can never leave a gap before the next
+    // nakshatra.
+    let elapsed_years = info.degrees_in_nakshatra * YEARS_PER_DEG;
+    let mut end_years = 0.0;
+    for k in 0..8 {
+        let (lord, years) = VIMSHOTTARI_SEQUENCE[(start + k) % 9];
+        end_years += years;
+        if elapsed_years < end_years {
+            return (info.nakshatra, star_lord, lord);
+        }
+    }
+    let last = VIMSHOTTARI_SEQUENCE[(start + 8) % 9].0;
+    (info.nakshatra, star_lord, last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nakshatra::NAKSHATRA_SPAN_27;
+
+    #[test]
+    fn aries_zero_is_ketu_ketu() {
+        assert_eq!(
+            kp_sublord(0.0),
+            (Nakshatra::Ashwini, Graha::Ketu, Graha::Ketu)
+        );
+        assert_eq!(
+            kp_sublord(360.0),
+            (Nakshatra::Ashwini, Graha::Ketu, Graha::Ketu)
+        );
+        // Just before Aries 0: last sub of Revati (Buddh star) is Shani.
+        assert_eq!(
+            kp_sublord(359.999_999),
+            (Nakshatra::Revati, Graha::Buddh, Graha::Shani)
+        );
+    }
+
+    #[test]
+    fn known_ashwini_sub_cusps() {
+        // Ketu 0°00'-0°46'40", Shukra to 3°00', Surya to 3°40'.
+        assert_eq!(kp_sublord(0.777).2, Graha::Ketu);
+        assert_eq!(kp_sublord(0.778).2, Graha::Shukra);
+        assert_eq!(kp_sublord(2.999_999).2, Graha::Shukra);
+        assert_eq!(kp_sublord(3.0).2, Graha::Surya);
+        assert_eq!(kp_sublord(3.666).2, Graha::Surya);
+        assert_eq!(kp_sublord(3.667).2, Graha::Chandra);
+    }
+
+    #[test]
+    fn last_sub_abuts_next_nakshatra_first_sub() {
+        for i in 1..27 {
+            let boundary = i as f64 * NAKSHATRA_SPAN_27;
+            let (_, prev_star, prev_sub) = kp_sublord(boundary - 1e-9);
+            let (_, star, sub) = kp_sublord(boundary + 1e-9);
+            // The sub before a boundary is the lord preceding the previous star.
+            let prev_idx = VIMSHOTTARI_SEQUENCE
+                .iter()
+                .position(|&(g, _)| g == prev_star)
+                .unwrap();
+            assert_eq!(prev_sub, VIMSHOTTARI_SEQUENCE[(prev_idx + 8) % 9].0);
+            assert_eq!(sub, star, "first sub of nakshatra {i} is its star lord");
+        }
+    }
+}
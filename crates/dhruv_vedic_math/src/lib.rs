@@ -15,11 +15,17 @@ pub mod ghatika;
 pub mod graha;
 pub mod graha_relationships;
 pub mod hora;
+pub mod jaimini;
 pub mod karana;
+pub mod kp;
+pub mod longevity;
+pub mod longitude;
 pub mod masa;
+pub mod muhurta;
 pub mod nakshatra;
 pub mod rashi;
 pub mod samvatsara;
+pub mod sandhi;
 pub mod shadbala;
 pub mod special_lagna;
 pub mod sphuta;
@@ -33,10 +39,11 @@ pub mod yoga;
 pub use amsha::{
     ALL_AMSHAS, Amsha, AmshaRequest, AmshaVariationCatalog, AmshaVariationCode, AmshaVariationInfo,
     D2_CANCER_LEO_ONLY_VARIATION_CODE, DEFAULT_AMSHA_VARIATION_CODE, RashiElement, SHODASHAVARGA,
-    amsha_from_rashi_position, amsha_longitude, amsha_longitudes, amsha_rashi_info,
-    amsha_rashi_infos, amsha_variation_by_name, amsha_variation_catalog, amsha_variation_info,
-    amsha_variations, default_amsha_variation, is_valid_amsha_variation, rashi_element,
-    rashi_position_to_longitude,
+    TrimsamsaDeity, VargottamaStatus, amsha_from_rashi_position, amsha_longitude, amsha_longitudes,
+    amsha_rashi_info, amsha_rashi_infos, amsha_variation_by_name, amsha_variation_catalog,
+    amsha_variation_info, amsha_variations, chaturvimsamsa_sign, default_amsha_variation,
+    is_valid_amsha_variation, khavedamsa_sign, rashi_element, rashi_position_to_longitude,
+    trimsamsa_deity, trimsamsa_lord, vargottama_status,
 };
 pub use arudha::{ALL_ARUDHA_PADAS, ArudhaPada, ArudhaResult, all_arudha_padas, arudha_pada};
 pub use ashtakavarga::{
@@ -67,21 +74,29 @@ pub use charakaraka::{
     CharakarakaEntry, CharakarakaResult, CharakarakaRole, CharakarakaScheme,
     charakarakas_from_longitudes,
 };
-pub use combustion::{all_combustion_status, combustion_threshold, is_combust};
+pub use combustion::{
+    CombustionConfig, GRAHA_YUDDHA_MAX_SEPARATION_DEG, GrahaYuddhaResult, MOON_COMBUSTION_DEG,
+    all_combustion_status, combustion_threshold, graha_yuddha, is_combust,
+};
 pub use dasha::{
-    ALL_DASHA_SYSTEMS, DAYS_PER_YEAR, DEFAULT_DASHA_LEVEL, DashaEntity, DashaHierarchy, DashaLevel,
-    DashaPeriod, DashaSnapshot, DashaSystem, DashaVariationConfig, MAX_DASHA_LEVEL,
-    MAX_DASHA_SYSTEMS, MAX_PERIODS_PER_LEVEL, NakshatraDashaConfig, SubPeriodMethod, YoginiScheme,
-    find_active_period, nakshatra_birth_balance, nakshatra_child_period, nakshatra_children,
-    nakshatra_complete_level, nakshatra_hierarchy, nakshatra_level0, nakshatra_level0_entity,
-    nakshatra_snapshot, snapshot_from_hierarchy, vimshottari_config,
+    ALL_DASHA_SYSTEMS, DAYS_PER_YEAR, DEFAULT_DASHA_LEVEL, DashaBalance, DashaEntity,
+    DashaHierarchy, DashaLevel, DashaPeriod, DashaSnapshot, DashaSystem, DashaVariationConfig,
+    MAX_DASHA_LEVEL, MAX_DASHA_SYSTEMS, MAX_PERIODS_PER_LEVEL, NakshatraDashaConfig,
+    SubPeriodMethod, VIMSHOTTARI_SEQUENCE, YoginiScheme, find_active_period, format_balance,
+    nakshatra_birth_balance, nakshatra_child_period, nakshatra_children, nakshatra_complete_level,
+    nakshatra_dasha_lord, nakshatra_hierarchy, nakshatra_level0, nakshatra_level0_entity,
+    nakshatra_snapshot, nakshatra28_birth_balance, period_boundary_jd, snapshot_from_hierarchy,
+    vimshottari_config, vimshottari28_config,
 };
 pub use drishti::{
     DrishtiEntry, GrahaDrishtiMatrix, base_virupa, graha_drishti, graha_drishti_matrix,
     special_virupa,
 };
 pub use error::VedicError;
-pub use ghatika::{GHATIKA_COUNT, GHATIKA_MINUTES, GhatikaPosition, ghatika_from_elapsed};
+pub use ghatika::{
+    GHATIKA_COUNT, GHATIKA_MINUTES, GhatikaPosition, VIGHATIKAS_PER_GHATIKA, duration_to_ghatika,
+    ghatika_from_elapsed, ghatika_to_duration,
+};
 pub use graha::{
     ALL_GRAHAS, GRAHA_KAKSHA_VALUES, Graha, SAPTA_GRAHAS, nth_rashi_from, rashi_lord,
     rashi_lord_by_index, rashi_lords, rashi_lords_by_index,
@@ -94,17 +109,41 @@ pub use graha_relationships::{
     is_own_sign_at_longitude, kala_abda_lord, kala_days_since_1900, kala_masa_lord, masa_lord,
     moolatrikone_range, moon_benefic_nature, moon_benefic_nature_with_rule, naisargika_maitri,
     natural_benefic_malefic, node_dignity_in_rashi, node_dignity_in_rashi_with_temporal_context,
-    own_signs, panchadha_maitri, samvatsara_lord, tatkalika_maitri, vaar_lord,
+    own_signs, panchadha_maitri, relationship_matrix, samvatsara_lord, tatkalika_maitri, vaar_lord,
 };
 pub use hora::{CHALDEAN_SEQUENCE, HORA_COUNT, Hora, hora_at, vaar_day_lord};
-pub use karana::{ALL_KARANAS, KARANA_SEGMENT_DEG, Karana, KaranaPosition, karana_from_elongation};
+pub use jaimini::{
+    ARGALA_HOUSES, ArgalaConfig, ArgalaEntry, ArgalaInput, ArgalaResult, SECONDARY_ARGALA_HOUSE,
+    argala, rashi_aspects, rashi_drishti, rashi_drishti_matrix,
+};
+pub use karana::{
+    ALL_KARANAS, KARANA_SEGMENT_DEG, Karana, KaranaPosition, karana_from_elongation,
+    karana_from_half_tithi,
+};
+pub use kp::kp_sublord;
+pub use longevity::{
+    GRAHA_GUNAKARA, LongevityInputs, RASHI_GUNAKARA, ShodhyaPinda, pinda_ayurdaya, shodhya_pindas,
+};
+pub use longitude::{SiderealLongitude, TropicalLongitude};
 pub use masa::{ALL_MASAS, Masa, masa_from_rashi_index};
+pub use muhurta::{
+    SpecialYoga, Vedha, VedhaDirection, sarvatobhadra_vedha, special_nakshatra_yogas,
+};
 pub use nakshatra::{
-    ALL_NAKSHATRAS_27, ALL_NAKSHATRAS_28, NAKSHATRA_SPAN_27, Nakshatra, Nakshatra28,
-    Nakshatra28Info, NakshatraInfo, nakshatra_from_longitude, nakshatra28_from_longitude,
+    ALL_NAKSHATRAS_27, ALL_NAKSHATRAS_28, ALL_NAVATARAS, NAKSHATRA_SPAN_27, Nakshatra, Nakshatra28,
+    Nakshatra28Info, NakshatraInfo, NavataraGroup, nakshatra_from_longitude,
+    nakshatra28_from_longitude, navatara_from,
 };
 pub use rashi::{ALL_RASHIS, Dms, Rashi, RashiInfo, deg_to_dms, dms_to_deg, rashi_from_longitude};
-pub use samvatsara::{ALL_SAMVATSARAS, SAMVATSARA_EPOCH_YEAR, Samvatsara, samvatsara_from_year};
+pub use samvatsara::{
+    ALL_SAMVATSARAS, NORTH_INDIAN_OFFSET, SAMVATSARA_EPOCH_YEAR, Samvatsara, SamvatsaraScheme,
+    samvatsara_from_year,
+};
+pub use sandhi::{
+    GANDANTA_HALF_WIDTH_DEG, GandantaHalf, GandantaKind, GandantaStatus, PUSHKARA_BHAGAS,
+    PUSHKARA_NAVAMSAS, RASHI_SANDHI_HALF_WIDTH_DEG, gandanta_status, is_gandanta, is_pushkara,
+    is_rashi_sandhi,
+};
 pub use shadbala::{
     DIG_BALA_BHAVA, KalaBalaBreakdown, KalaBalaInputs, MAX_SPEED, NAISARGIKA_BALA,
     REQUIRED_STRENGTH, ShadbalaBreakdown, ShadbalaInputs, SthanaBalaBreakdown, abda_bala,
@@ -113,9 +152,10 @@ pub use shadbala::{
     all_nathonnatha_balas, all_ojhayugma_balas, all_paksha_balas, all_shadbalas_from_inputs,
     all_sthana_balas, all_tribhaga_balas, all_uchcha_balas, all_vara_balas, all_yuddha_balas,
     ayana_bala, cheshta_bala, cheshta_kendra, dig_bala, drekkana_bala, drik_bala,
-    hora_bala as shadbala_hora_bala, kala_bala, kendradi_bala, masa_bala as shadbala_masa_bala,
-    naisargika_bala, nathonnatha_bala, ojhayugma_bala, paksha_bala, shadbala_from_inputs,
-    sthana_bala, tribhaga_bala, uchcha_bala, vara_bala, yuddha_bala,
+    hora_bala as shadbala_hora_bala, ishta_kashta_phala, kala_bala, kendradi_bala,
+    masa_bala as shadbala_masa_bala, naisargika_bala, nathonnatha_bala, ojhayugma_bala,
+    paksha_bala, rank_grahas_by_strength, shadbala_from_inputs, sthana_bala, tribhaga_bala,
+    uchcha_bala, vara_bala, yuddha_bala,
 };
 pub use special_lagna::{
     ALL_SPECIAL_LAGNAS, AllSpecialLagnas, SpecialLagna, all_special_lagnas, bhava_lagna,
@@ -129,7 +169,8 @@ pub use sphuta::{
     yoga_sphuta_normalized,
 };
 pub use tithi::{
-    ALL_TITHIS, Paksha, TITHI_SEGMENT_DEG, Tithi, TithiPosition, tithi_from_elongation,
+    ALL_TITHIS, Paksha, TITHI_SEGMENT_DEG, Tithi, TithiDayKind, TithiPosition, tithi_day_kind,
+    tithi_from_elongation,
 };
 pub use upagraha::{
     ALL_UPAGRAHAS, AllUpagrahas, GulikaMaandiPlanet, SunBasedUpagrahas, TIME_BASED_UPAGRAHAS,
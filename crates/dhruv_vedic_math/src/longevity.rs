@@ -0,0 +1,222 @@
+//! Ashtakavarga longevity (ayurdaya) from Shodhya Pindas.
+//!
+//! Each of the 7 Bhinna Ashtakavargas is reduced by Trikona and Ekadhipatya
+//! Sodhana, then weighted into a Shodhya Pinda (Rashi Pinda + Graha Pinda).
+//! A graha's span is `pinda * 7 / 27` years with whole multiples of 27
+//! expunged, reduced by harana for combustion and debilitation; the seven
+//! spans are summed.
+//!
+//! Clean-room implementation from BPHS (Ashtakavarga ayurdaya).
+//! See `docs/clean_room_ashtakavarga.md`.
+
+use crate::ashtakavarga::{AshtakavargaResult, ekadhipatya_sodhana, trikona_sodhana};
+use crate::graha::SAPTA_GRAHAS;
+use crate::graha_relationships::{Dignity, dignity_in_rashi};
+use crate::util::normalize_360;
+
+/// Rashi gunakara (multipliers), Mesha through Meena.
+pub const RASHI_GUNAKARA: [u16; 12] = [7, 10, 8, 4, 10, 5, 7, 8, 9, 5, 11, 12];
+
+/// Graha gunakara (multipliers), Sun through Saturn.
+pub const GRAHA_GUNAKARA: [u16; 7] = [5, 5, 8, 5, 10, 7, 5];
+
+/// Shodhya Pinda of one Bhinna Ashtakavarga.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShodhyaPinda {
+    /// Sum of reduced bindus weighted by rashi gunakara.
+    pub rashi_pinda: u16,
+    /// Reduced bindus under each graha weighted by graha gunakara.
+    pub graha_pinda: u16,
+}
+
+impl ShodhyaPinda {
+    /// Rashi Pinda + Graha Pinda.
+    pub fn total(&self) -> u16 {
+        self.rashi_pinda + self.graha_pinda
+    }
+}
+
+/// Chart facts needed for harana (reductions), Sun through Saturn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LongevityInputs {
+    /// Sidereal longitudes in degrees.
+    pub sidereal_lons: [f64; 7],
+    /// Combustion flags (see `all_combustion_status`).
+    pub is_combust: [bool; 7],
+    /// Retrograde flags.
+    pub is_retrograde: [bool; 7],
+}
+
+/// Shodhya Pindas for all 7 BAVs.
+///
+/// `graha_rashis` are the 0-based rashi indices of Sun..Saturn used to build
+/// `result`; they select the bindus counted in the Graha Pinda.
+pub fn shodhya_pindas(result: &AshtakavargaResult, graha_rashis: &[u8; 7]) -> [ShodhyaPinda; 7] {
+    let mut pindas = [ShodhyaPinda {
+        rashi_pinda: 0,
+        graha_pinda: 0,
+    }; 7];
+    for (pinda, bav) in pindas.iter_mut().zip(result.bavs.iter()) {
+        let reduced = ekadhipatya_sodhana(&trikona_sodhana(&bav.points));
+        pinda.rashi_pinda = reduced
+            .iter()
+            .zip(RASHI_GUNAKARA.iter())
+            .map(|(&b, &m)| u16::from(b) * m)
+            .sum();
+        pinda.graha_pinda = graha_rashis
+            .iter()
+            .zip(GRAHA_GUNAKARA.iter())
+            .map(|(&r, &m)| u16::from(reduced[(r % 12) as usize]) * m)
+            .sum();
+    }
+    pindas
+}
+
+/// Harana factor for one graha: 1/2 when combust (Venus and Saturn exempt),
+/// a further 2/3 when debilitated and not retrograde.
+fn harana_factor(index: usize, inputs: &LongevityInputs) -> f64 {
+    let graha = SAPTA_GRAHAS[index];
+    let mut factor = 1.0;
+    if inputs.is_combust[index] && !matches!(index, 5 | 6) {
+        factor *= 0.5;
+    }
+    let lon = normalize_360(inputs.sidereal_lons[index]);
+    let rashi = ((lon / 30.0) as u8).min(11);
+    if !inputs.is_retrograde[index] && dignity_in_rashi(graha, lon, rashi) == Dignity::Debilitated {
+        factor *= 2.0 / 3.0;
+    }
+    factor
+}
+
+/// Years contributed by one Shodhya Pinda: `pinda * 7 / 27` with whole
+/// multiples of 27 expunged, so always in `[0, 27)`.
+fn pinda_years(pinda: u16) -> f64 {
+    (f64::from(pinda) * 7.0 / 27.0).rem_euclid(27.0)
+}
+
+/// Ashtakavarga ayurdaya in years from Shodhya Pindas with harana applied.
+///
+/// Each graha contributes `(pinda * 7 / 27) mod 27` years, so the total lies
+/// in `[0, 189)` before harana.
+pub fn pinda_ayurdaya(result: &AshtakavargaResult, inputs: &LongevityInputs) -> f64 {
+    let mut graha_rashis = [0u8; 7];
+    for (r, &lon) in graha_rashis.iter_mut().zip(inputs.sidereal_lons.iter()) {
+        *r = ((normalize_360(lon) / 30.0) as u8).min(11);
+    }
+    shodhya_pindas(result, &graha_rashis)
+        .iter()
+        .enumerate()
+        .map(|(i, pinda)| pinda_years(pinda.total()) * harana_factor(i, inputs))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ashtakavarga::calculate_ashtakavarga;
+
+    fn sample() -> (AshtakavargaResult, LongevityInputs) {
+        // Sun Mesha, Moon Karka, Mars Makara, Mercury Mesha, Jupiter Karka,
+        // Venus Meena, Saturn Tula; Lagna Simha.
+        let lons = [10.0, 95.0, 290.0, 20.0, 100.0, 340.0, 190.0];
+        let rashis = lons.map(|l: f64| (l / 30.0) as u8);
+        let result = calculate_ashtakavarga(&rashis, 4);
+        let inputs = LongevityInputs {
+            sidereal_lons: lons,
+            is_combust: [false; 7],
+            is_retrograde: [false; 7],
+        };
+        (result, inputs)
+    }
+
+    #[test]
+    fn pinda_totals_use_gunakara() {
+        let (result, inputs) = sample();
+        let rashis = inputs.sidereal_lons.map(|l| (l / 30.0) as u8);
+        let pindas = shodhya_pindas(&result, &rashis);
+        for (pinda, bav) in pindas.iter().zip(result.bavs.iter()) {
+            let reduced = ekadhipatya_sodhana(&trikona_sodhana(&bav.points));
+            let max_rashi: u16 = reduced.iter().map(|&b| u16::from(b) * 12).sum();
+            assert!(pinda.rashi_pinda <= max_rashi);
+            assert_eq!(pinda.total(), pinda.rashi_pinda + pinda.graha_pinda);
+        }
+    }
+
+    #[test]
+    fn ayurdaya_in_human_range() {
+        let (result, inputs) = sample();
+        let years = pinda_ayurdaya(&result, &inputs);
+        assert!(years > 20.0 && years < 189.0, "years = {years}");
+    }
+
+    #[test]
+    fn pinda_years_expunge_whole_27s() {
+        // 104 * 7 / 27 = 26.96 stays; 105 * 7 / 27 = 27.22 wraps to 0.22.
+        assert!((pinda_years(104) - 728.0 / 27.0).abs() < 1e-12);
+        assert!((pinda_years(105) - 6.0 / 27.0).abs() < 1e-12);
+        assert!((pinda_years(216) - 2.0).abs() < 1e-12);
+        assert_eq!(pinda_years(0), 0.0);
+    }
+
+    #[test]
+    fn ayurdaya_bounded_across_expunge_boundary() {
+        // Sweep lagnas and graha placements so pindas fall on both sides of
+        // 105, where a graha's span wraps from just under 27 years toward 0.
+        let base = [10.0, 95.0, 290.0, 20.0, 100.0, 340.0, 190.0];
+        let (mut below, mut above) = (false, false);
+        for lagna in 0..12u8 {
+            for shift in 0..12 {
+                let lons: [f64; 7] = std::array::from_fn(|i| {
+                    normalize_360(base[i] + 30.0 * f64::from(shift) * (i + 1) as f64)
+                });
+                let rashis = lons.map(|l| (l / 30.0) as u8);
+                let result = calculate_ashtakavarga(&rashis, lagna);
+                for pinda in shodhya_pindas(&result, &rashis) {
+                    below |= pinda.total() < 105;
+                    above |= pinda.total() >= 105;
+                    assert!((0.0..27.0).contains(&pinda_years(pinda.total())));
+                }
+                let inputs = LongevityInputs {
+                    sidereal_lons: lons,
+                    is_combust: [false; 7],
+                    is_retrograde: [false; 7],
+                };
+                let years = pinda_ayurdaya(&result, &inputs);
+                assert!((0.0..189.0).contains(&years), "years = {years}");
+            }
+        }
+        assert!(below && above, "sweep did not cross the 27-year expunge");
+    }
+
+    #[test]
+    fn combustion_harana_reduces_span() {
+        let (result, mut inputs) = sample();
+        let base = pinda_ayurdaya(&result, &inputs);
+        let rashis = inputs.sidereal_lons.map(|l| (l / 30.0) as u8);
+        let buddh_pinda = shodhya_pindas(&result, &rashis)[3].total();
+        let buddh_years = pinda_years(buddh_pinda);
+        inputs.is_combust[3] = true;
+        let reduced = pinda_ayurdaya(&result, &inputs);
+        assert!((base - reduced - buddh_years / 2.0).abs() < 1e-9);
+        // Venus is exempt from combustion harana.
+        let (_, mut venus) = sample();
+        venus.is_combust[5] = true;
+        assert!((pinda_ayurdaya(&result, &venus) - base).abs() < 1e-12);
+    }
+
+    #[test]
+    fn debilitation_harana_skips_retrograde() {
+        // Mangal at 118 deg: Karka, its debilitation sign.
+        let (_, mut inputs) = sample();
+        inputs.sidereal_lons[2] = 118.0;
+        let rashis = inputs.sidereal_lons.map(|l| (l / 30.0) as u8);
+        let result = calculate_ashtakavarga(&rashis, 4);
+        let mars_pinda = shodhya_pindas(&result, &rashis)[2].total();
+        let mars_years = pinda_years(mars_pinda);
+
+        let debilitated = pinda_ayurdaya(&result, &inputs);
+        inputs.is_retrograde[2] = true;
+        let retro = pinda_ayurdaya(&result, &inputs);
+        assert!((retro - debilitated - mars_years / 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,109 @@
+//! Typed sidereal and tropical ecliptic longitudes.
+//!
+//! These newtypes make the reference explicit at the call site: the only
+//! way between them is through an ayanamsha. `rashi_from_longitude`,
+//! `nakshatra_from_longitude`, `nakshatra28_from_longitude` and
+//! `SphutalInputs` take [`SiderealLongitude`], so a [`TropicalLongitude`]
+//! cannot reach them. A bare `f64` converts into [`SiderealLongitude`] and
+//! is taken to be sidereal already.
+
+use crate::util::normalize_360;
+
+/// Sidereal ecliptic longitude in degrees (tropical minus ayanamsha).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SiderealLongitude(pub f64);
+
+/// Tropical ecliptic longitude in degrees (measured from the equinox of date).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct TropicalLongitude(pub f64);
+
+impl SiderealLongitude {
+    /// Wrap to [0, 360).
+    pub fn normalize(self) -> Self {
+        Self(normalize_360(self.0))
+    }
+
+    /// Longitude in degrees, as stored.
+    pub fn deg(self) -> f64 {
+        self.0
+    }
+
+    /// Convert to tropical by adding the ayanamsha (degrees).
+    pub fn into_tropical(self, ayanamsha_deg: f64) -> TropicalLongitude {
+        TropicalLongitude(self.0 + ayanamsha_deg).normalize()
+    }
+}
+
+impl TropicalLongitude {
+    /// Wrap to [0, 360).
+    pub fn normalize(self) -> Self {
+        Self(normalize_360(self.0))
+    }
+
+    /// Longitude in degrees, as stored.
+    pub fn deg(self) -> f64 {
+        self.0
+    }
+
+    /// Convert to sidereal by subtracting the ayanamsha (degrees).
+    pub fn into_sidereal(self, ayanamsha_deg: f64) -> SiderealLongitude {
+        SiderealLongitude(self.0 - ayanamsha_deg).normalize()
+    }
+}
+
+impl From<SiderealLongitude> for f64 {
+    fn from(lon: SiderealLongitude) -> Self {
+        lon.0
+    }
+}
+
+impl From<f64> for SiderealLongitude {
+    fn from(deg: f64) -> Self {
+        Self(deg)
+    }
+}
+
+impl From<TropicalLongitude> for f64 {
+    fn from(lon: TropicalLongitude) -> Self {
+        lon.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_wraps_into_range() {
+        assert_eq!(SiderealLongitude(-10.0).normalize().deg(), 350.0);
+        assert_eq!(TropicalLongitude(725.0).normalize().deg(), 5.0);
+        assert_eq!(TropicalLongitude(360.0).normalize().deg(), 0.0);
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let aya = 24.1;
+        let trop = TropicalLongitude(10.0);
+        let sid = trop.into_sidereal(aya);
+        assert!((sid.deg() - 345.9).abs() < 1e-12);
+        let back = sid.into_tropical(aya);
+        assert!((back.deg() - 10.0).abs() < 1e-12);
+        assert_eq!(f64::from(back), back.deg());
+    }
+
+    #[test]
+    fn typed_entry_points_match_bare_functions() {
+        use crate::nakshatra::nakshatra_from_longitude;
+        use crate::rashi::rashi_from_longitude;
+
+        let sid = TropicalLongitude(150.0).into_sidereal(24.0);
+        assert_eq!(
+            rashi_from_longitude(sid).rashi_index,
+            rashi_from_longitude(126.0).rashi_index
+        );
+        assert_eq!(
+            nakshatra_from_longitude(sid).nakshatra_index,
+            nakshatra_from_longitude(126.0).nakshatra_index
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Muhurta helpers: Sarvatobhadra Chakra vedha and special nakshatra yogas.
+//!
+//! The Sarvatobhadra Chakra is a 9x9 grid whose outer ring carries the 28
+//! nakshatras (Abhijit included), seven per side, with vowels at the corners.
+//! With East at the top:
+//! - East (top row, left to right): Krittika .. Ashlesha
+//! - South (right column, top to bottom): Magha .. Vishakha
+//! - West (bottom row, right to left): Anuradha .. Shravana
+//! - North (left column, bottom to top): Dhanishtha .. Bharani
+//!
+//! A graha in a nakshatra casts vedha along three lines across the chakra:
+//! straight ahead to the opposite side, and along both diagonals until the
+//! outer ring is reached. Traditions then pick one line by motion (front for
+//! mean motion, one diagonal for fast and the other for retrograde), so all
+//! three are reported with their direction and the caller filters.
+//!
+//! Special nakshatra yogas are the auspicious vaar/nakshatra/tithi
+//! combinations printed in panchangs (Sarvartha Siddhi, Ravi/Guru Pushya,
+//! Dwipushkar, Tripushkar).
+//!
+//! Clean-room implementation from the standard published chakra layout and
+//! panchang combination tables.
+
+use crate::vaar::Vaar;
+
+/// Number of nakshatras on the chakra (28-scheme with Abhijit).
+const CHAKRA_NAKSHATRAS: u8 = 28;
+
+/// Line along which a vedha is cast, relative to a graha facing across the
+/// chakra toward its centre (East at the top).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VedhaDirection {
+    /// Straight across to the opposite side (sammukha).
+    Front,
+    /// Diagonal toward the graha's left hand (vama).
+    Left,
+    /// Diagonal toward the graha's right hand (dakshina).
+    Right,
+}
+
+/// One vedha on the janma nakshatra from a transiting graha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vedha {
+    /// Position of the afflicting entry in the caller's `transit_nakshatras`.
+    pub transit_index: usize,
+    /// 0-based 28-scheme nakshatra index of the afflicting graha.
+    pub transit_nakshatra: u8,
+    /// Line along which the vedha falls.
+    pub direction: VedhaDirection,
+}
+
+/// Grid cell `(row, col)` of a 28-scheme nakshatra index, East at row 0.
+const fn chakra_cell(nakshatra: u8) -> (i8, i8) {
+    let n = nakshatra as i8;
+    match nakshatra {
+        0..=1 => (2 - n, 0),
+        2..=8 => (0, n - 1),
+        9..=15 => (n - 8, 8),
+        16..=22 => (8, 7 - (n - 16)),
+        _ => (7 - (n - 23), 0),
+    }
+}
+
+/// 28-scheme nakshatra index on an outer-ring (non-corner) cell.
+fn nakshatra_at_cell(row: i8, col: i8) -> u8 {
+    (0..CHAKRA_NAKSHATRAS)
+        .find(|&n| chakra_cell(n) == (row, col))
+        .expect("ring cell carries a nakshatra")
+}
+
+/// The three nakshatras receiving vedha from a graha in `nakshatra`.
+fn vedha_targets(nakshatra: u8) -> [(VedhaDirection, u8); 3] {
+    let (row, col) = chakra_cell(nakshatra);
+    // Inward unit step from the graha's side of the chakra.
+    let (dr, dc) = if row == 0 {
+        (1, 0)
+    } else if row == 8 {
+        (-1, 0)
+    } else if col == 0 {
+        (0, 1)
+    } else {
+        (0, -1)
+    };
+    // Right hand of a graha facing along (dr, dc).
+    let (rr, rc) = (dc, -dr);
+    let walk = |step_r: i8, step_c: i8| {
+        let (mut r, mut c) = (row + step_r, col + step_c);
+        while r > 0 && r < 8 && c > 0 && c < 8 {
+            r += step_r;
+            c += step_c;
+        }
+        nakshatra_at_cell(r, c)
+    };
+    [
+        (VedhaDirection::Front, walk(dr, dc)),
+        (VedhaDirection::Left, walk(dr - rr, dc - rc)),
+        (VedhaDirection::Right, walk(dr + rr, dc + rc)),
+    ]
+}
+
+/// Vedhas on `janma_nakshatra` from transiting grahas on the Sarvatobhadra Chakra.
+///
+/// All indices are 0-based 28-scheme (0 = Ashwini, 21 = Abhijit,
+/// 27 = Revati). `transit_nakshatras` holds one entry per transiting graha in
+/// caller order; every line that lands on the janma nakshatra is returned.
+/// Out-of-range indices produce no vedha.
+pub fn sarvatobhadra_vedha(janma_nakshatra: u8, transit_nakshatras: &[u8]) -> Vec<Vedha> {
+    if janma_nakshatra >= CHAKRA_NAKSHATRAS {
+        return Vec::new();
+    }
+    let mut vedhas = Vec::new();
+    for (transit_index, &transit_nakshatra) in transit_nakshatras.iter().enumerate() {
+        if transit_nakshatra >= CHAKRA_NAKSHATRAS {
+            continue;
+        }
+        for (direction, target) in vedha_targets(transit_nakshatra) {
+            if target == janma_nakshatra {
+                vedhas.push(Vedha {
+                    transit_index,
+                    transit_nakshatra,
+                    direction,
+                });
+            }
+        }
+    }
+    vedhas
+}
+
+/// Auspicious vaar/nakshatra/tithi combinations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialYoga {
+    /// Weekday paired with one of its listed nakshatras.
+    SarvarthaSiddhi,
+    /// Sunday with Pushya.
+    RaviPushya,
+    /// Thursday with Pushya.
+    GuruPushya,
+    /// Bhadra tithi + Sun/Tue/Sat + dwipada nakshatra (results repeat twice).
+    Dwipushkar,
+    /// Bhadra tithi + Sun/Tue/Sat + tripada nakshatra (results repeat thrice).
+    Tripushkar,
+}
+
+impl SpecialYoga {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::SarvarthaSiddhi => "Sarvartha Siddhi",
+            Self::RaviPushya => "Ravi Pushya",
+            Self::GuruPushya => "Guru Pushya",
+            Self::Dwipushkar => "Dwipushkar",
+            Self::Tripushkar => "Tripushkar",
+        }
+    }
+}
+
+/// Pushya (27-scheme index).
+const PUSHYA: u8 = 7;
+
+/// Sarvartha Siddhi nakshatras (27-scheme) for each vaar.
+const fn sarvartha_siddhi_nakshatras(vaar: Vaar) -> &'static [u8] {
+    match vaar {
+        // Ashwini, Pushya, U.Phalguni, Hasta, Mula, U.Ashadha, U.Bhadrapada
+        Vaar::Ravivaar => &[0, 7, 11, 12, 18, 20, 25],
+        // Rohini, Mrigashira, Pushya, Anuradha, Shravana
+        Vaar::Somvaar => &[3, 4, 7, 16, 21],
+        // Ashwini, Krittika, Ashlesha, U.Bhadrapada
+        Vaar::Mangalvaar => &[0, 2, 8, 25],
+        // Krittika, Rohini, Mrigashira, Hasta, Anuradha
+        Vaar::Budhvaar => &[2, 3, 4, 12, 16],
+        // Ashwini, Punarvasu, Pushya, Anuradha, Revati
+        Vaar::Guruvaar => &[0, 6, 7, 16, 26],
+        // Ashwini, Punarvasu, Anuradha, Shravana, Revati
+        Vaar::Shukravaar => &[0, 6, 16, 21, 26],
+        // Rohini, Swati, Shravana
+        Vaar::Shanivaar => &[3, 14, 21],
+    }
+}
+
+/// Dwipada nakshatras (two padas in each of two rashis): Mrigashira, Chitra, Dhanishtha.
+const DWIPADA_NAKSHATRAS: [u8; 3] = [4, 13, 22];
+
+/// Tripada nakshatras (three padas in one rashi, one in the next): Krittika,
+/// Punarvasu, U.Phalguni, Vishakha, U.Ashadha, P.Bhadrapada.
+const TRIPADA_NAKSHATRAS: [u8; 6] = [2, 6, 11, 15, 20, 24];
+
+/// Special yogas formed by the day's vaar, nakshatra, and tithi.
+///
+/// `nakshatra_index` is 0-based 27-scheme (0 = Ashwini); `tithi_index` is
+/// 0-based (0-14 Shukla, 15-29 Krishna). Pushkar yogas need a Bhadra tithi
+/// (Dwitiya, Saptami, Dwadashi of either paksha) on Sunday, Tuesday, or
+/// Saturday. Out-of-range indices form no yoga. Results follow enum order.
+pub fn special_nakshatra_yogas(
+    vaar: Vaar,
+    nakshatra_index: u8,
+    tithi_index: u8,
+) -> Vec<SpecialYoga> {
+    let mut yogas = Vec::new();
+    if nakshatra_index >= 27 || tithi_index >= 30 {
+        return yogas;
+    }
+
+    if sarvartha_siddhi_nakshatras(vaar).contains(&nakshatra_index) {
+        yogas.push(SpecialYoga::SarvarthaSiddhi);
+    }
+    if nakshatra_index == PUSHYA {
+        match vaar {
+            Vaar::Ravivaar => yogas.push(SpecialYoga::RaviPushya),
+            Vaar::Guruvaar => yogas.push(SpecialYoga::GuruPushya),
+            _ => {}
+        }
+    }
+
+    let bhadra_tithi = matches!(tithi_index % 15, 1 | 6 | 11);
+    let pushkar_vaar = matches!(vaar, Vaar::Ravivaar | Vaar::Mangalvaar | Vaar::Shanivaar);
+    if bhadra_tithi && pushkar_vaar {
+        if DWIPADA_NAKSHATRAS.contains(&nakshatra_index) {
+            yogas.push(SpecialYoga::Dwipushkar);
+        }
+        if TRIPADA_NAKSHATRAS.contains(&nakshatra_index) {
+            yogas.push(SpecialYoga::Tripushkar);
+        }
+    }
+
+    yogas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BHARANI: u8 = 1;
+    const KRITTIKA: u8 = 2;
+    const VISHAKHA: u8 = 15;
+    const ABHIJIT: u8 = 21;
+    const SHRAVANA: u8 = 22;
+    const ROHINI: u8 = 3;
+
+    #[test]
+    fn ring_cells_are_distinct_non_corner() {
+        for a in 0..CHAKRA_NAKSHATRAS {
+            let (r, c) = chakra_cell(a);
+            assert!(r == 0 || r == 8 || c == 0 || c == 8);
+            assert!(!((r == 0 || r == 8) && (c == 0 || c == 8)));
+            for b in (a + 1)..CHAKRA_NAKSHATRAS {
+                assert_ne!(chakra_cell(a), chakra_cell(b));
+            }
+        }
+    }
+
+    #[test]
+    fn krittika_vedhas_shravana_bharani_vishakha() {
+        let targets = vedha_targets(KRITTIKA);
+        assert_eq!(targets[0], (VedhaDirection::Front, SHRAVANA));
+        assert_eq!(targets[1], (VedhaDirection::Left, VISHAKHA));
+        assert_eq!(targets[2], (VedhaDirection::Right, BHARANI));
+    }
+
+    #[test]
+    fn rohini_front_vedha_is_abhijit() {
+        assert_eq!(vedha_targets(ROHINI)[0], (VedhaDirection::Front, ABHIJIT));
+    }
+
+    #[test]
+    fn vedha_lines_are_mutual() {
+        for a in 0..CHAKRA_NAKSHATRAS {
+            for (_, b) in vedha_targets(a) {
+                assert!(
+                    vedha_targets(b).iter().any(|&(_, t)| t == a),
+                    "{a} -> {b} not mutual"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reports_afflicting_transit_by_position() {
+        // Janma Shravana; grahas in Ashwini, Krittika, Revati, Krittika.
+        let vedhas = sarvatobhadra_vedha(SHRAVANA, &[0, KRITTIKA, 27, KRITTIKA]);
+        let hits: Vec<_> = vedhas.iter().map(|v| v.transit_index).collect();
+        assert_eq!(hits, vec![1, 3]);
+        assert!(
+            vedhas
+                .iter()
+                .filter(|v| v.transit_nakshatra == KRITTIKA)
+                .all(|v| v.direction == VedhaDirection::Front)
+        );
+    }
+
+    #[test]
+    fn thursday_pushya_is_guru_pushya() {
+        let yogas = special_nakshatra_yogas(Vaar::Guruvaar, PUSHYA, 4);
+        assert_eq!(
+            yogas,
+            vec![SpecialYoga::SarvarthaSiddhi, SpecialYoga::GuruPushya]
+        );
+        assert!(
+            special_nakshatra_yogas(Vaar::Ravivaar, PUSHYA, 4).contains(&SpecialYoga::RaviPushya)
+        );
+        assert!(
+            special_nakshatra_yogas(Vaar::Somvaar, PUSHYA, 4)
+                .iter()
+                .all(|y| !matches!(y, SpecialYoga::RaviPushya | SpecialYoga::GuruPushya))
+        );
+    }
+
+    #[test]
+    fn pushkar_yogas_need_bhadra_tithi_and_vaar() {
+        // Tuesday + Krishna Saptami (21) + Chitra (13) = Dwipushkar.
+        assert_eq!(
+            special_nakshatra_yogas(Vaar::Mangalvaar, 13, 21),
+            vec![SpecialYoga::Dwipushkar]
+        );
+        // Saturday + Shukla Dwadashi (11) + Vishakha (15) = Tripushkar.
+        assert_eq!(
+            special_nakshatra_yogas(Vaar::Shanivaar, 15, 11),
+            vec![SpecialYoga::Tripushkar]
+        );
+        // Non-Bhadra tithi or non-Pushkar vaar break the combination.
+        assert!(special_nakshatra_yogas(Vaar::Mangalvaar, 13, 22).is_empty());
+        assert!(special_nakshatra_yogas(Vaar::Budhvaar, 13, 21).is_empty());
+    }
+
+    #[test]
+    fn special_yogas_reject_out_of_range() {
+        assert!(special_nakshatra_yogas(Vaar::Guruvaar, 27, 0).is_empty());
+        assert!(special_nakshatra_yogas(Vaar::Guruvaar, PUSHYA, 30).is_empty());
+    }
+
+    #[test]
+    fn out_of_range_indices_are_ignored() {
+        assert!(sarvatobhadra_vedha(28, &[KRITTIKA]).is_empty());
+        assert!(sarvatobhadra_vedha(SHRAVANA, &[28, 255]).is_empty());
+    }
+}
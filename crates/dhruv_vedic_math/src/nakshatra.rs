@@ -10,6 +10,10 @@
 //! Clean-room implementation from universal Vedic convention.
 //! See `docs/clean_room_rashi_nakshatra.md`.
 
+use crate::dasha::VIMSHOTTARI_SEQUENCE;
+use crate::graha::Graha;
+use crate::longitude::SiderealLongitude;
+
 /// Span of one nakshatra in the 27-scheme: 360/27 = 13.3333... degrees.
 pub const NAKSHATRA_SPAN_27: f64 = 360.0 / 27.0;
 
@@ -154,6 +158,12 @@ impl Nakshatra {
     pub const fn all() -> &'static [Nakshatra; 27] {
         &ALL_NAKSHATRAS_27
     }
+
+    /// Ruling graha in the Vimshottari sequence (Ashwini = Ketu, Bharani =
+    /// Shukra, Krittika = Surya, ... repeating every nine nakshatras).
+    pub fn lord(self) -> Graha {
+        VIMSHOTTARI_SEQUENCE[self.index() as usize % 9].0
+    }
 }
 
 /// Result of 27-nakshatra lookup.
@@ -180,8 +190,9 @@ fn normalize_360(deg: f64) -> f64 {
 /// Determine nakshatra and pada from sidereal ecliptic longitude (27-scheme).
 ///
 /// Each nakshatra spans 13 deg 20' (13.3333... deg). Each pada spans 3 deg 20'.
-pub fn nakshatra_from_longitude(sidereal_lon_deg: f64) -> NakshatraInfo {
-    let lon = normalize_360(sidereal_lon_deg);
+/// Takes a bare `f64` (assumed sidereal) or a [`SiderealLongitude`].
+pub fn nakshatra_from_longitude(sidereal_lon_deg: impl Into<SiderealLongitude>) -> NakshatraInfo {
+    let lon = normalize_360(sidereal_lon_deg.into().deg());
     let nak_idx = (lon / NAKSHATRA_SPAN_27).floor() as u8;
     let nak_idx = nak_idx.min(26);
     let degrees_in_nakshatra = lon - (nak_idx as f64) * NAKSHATRA_SPAN_27;
@@ -198,6 +209,69 @@ pub fn nakshatra_from_longitude(sidereal_lon_deg: f64) -> NakshatraInfo {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Navatara (nine-star) classification
+// ---------------------------------------------------------------------------
+
+/// The nine taras, counted from a starting nakshatra (usually the janma
+/// nakshatra) in three cycles of nine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavataraGroup {
+    Janma,
+    Sampat,
+    Vipat,
+    Kshema,
+    Pratyak,
+    Sadhana,
+    Naidhana,
+    Mitra,
+    ParamaMitra,
+}
+
+/// All nine taras in counting order (0 = Janma).
+pub const ALL_NAVATARAS: [NavataraGroup; 9] = [
+    NavataraGroup::Janma,
+    NavataraGroup::Sampat,
+    NavataraGroup::Vipat,
+    NavataraGroup::Kshema,
+    NavataraGroup::Pratyak,
+    NavataraGroup::Sadhana,
+    NavataraGroup::Naidhana,
+    NavataraGroup::Mitra,
+    NavataraGroup::ParamaMitra,
+];
+
+impl NavataraGroup {
+    /// Sanskrit name of the tara.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Janma => "Janma",
+            Self::Sampat => "Sampat",
+            Self::Vipat => "Vipat",
+            Self::Kshema => "Kshema",
+            Self::Pratyak => "Pratyak",
+            Self::Sadhana => "Sadhana",
+            Self::Naidhana => "Naidhana",
+            Self::Mitra => "Mitra",
+            Self::ParamaMitra => "Parama Mitra",
+        }
+    }
+
+    /// 0-based index (Janma=0 .. Parama Mitra=8).
+    pub const fn index(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Tara of `target` counted from `start` (inclusive, so `start` itself is Janma).
+///
+/// The 1st, 10th, and 19th nakshatras are Janma, the 2nd, 11th, and 20th
+/// Sampat, and so on.
+pub fn navatara_from(start: Nakshatra, target: Nakshatra) -> NavataraGroup {
+    let count = (target.index() + 27 - start.index()) % 27;
+    ALL_NAVATARAS[(count % 9) as usize]
+}
+
 // ---------------------------------------------------------------------------
 // 28-Nakshatra scheme (with Abhijit)
 // ---------------------------------------------------------------------------
@@ -378,7 +452,7 @@ const ABHIJIT_END: f64 = 280.0 + 53.0 / 60.0 + 20.0 / 3600.0;
 /// Note: Uttara Ashadha's 27-scheme span is [266deg40', 280deg00']. In the
 /// 28-scheme, the portion [276deg40', 280deg00'] is given to Abhijit, and
 /// [280deg00', 280deg53'20"] is taken from Shravana's start.
-fn nakshatra_28_boundaries() -> [(f64, f64); 28] {
+pub(crate) fn nakshatra_28_boundaries() -> [(f64, f64); 28] {
     let span = NAKSHATRA_SPAN_27; // 13.3333...
     let mut bounds = [(0.0_f64, 0.0_f64); 28];
 
@@ -406,9 +480,12 @@ fn nakshatra_28_boundaries() -> [(f64, f64); 28] {
 /// Determine nakshatra from sidereal longitude (28-scheme with Abhijit).
 ///
 /// Abhijit spans approximately 276deg40' to 280deg53'20". Within Abhijit,
-/// pada is set to 0 (not applicable).
-pub fn nakshatra28_from_longitude(sidereal_lon_deg: f64) -> Nakshatra28Info {
-    let lon = normalize_360(sidereal_lon_deg);
+/// pada is set to 0 (not applicable). Takes a bare `f64` (assumed sidereal)
+/// or a [`SiderealLongitude`].
+pub fn nakshatra28_from_longitude(
+    sidereal_lon_deg: impl Into<SiderealLongitude>,
+) -> Nakshatra28Info {
+    let lon = normalize_360(sidereal_lon_deg.into().deg());
     let bounds = nakshatra_28_boundaries();
 
     for (i, &(start, end)) in bounds.iter().enumerate() {
@@ -485,6 +562,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn nakshatra_lords_follow_vimshottari() {
+        assert_eq!(Nakshatra::Ashwini.lord(), Graha::Ketu);
+        assert_eq!(Nakshatra::Bharani.lord(), Graha::Shukra);
+        assert_eq!(Nakshatra::Rohini.lord(), Graha::Chandra);
+        assert_eq!(Nakshatra::Magha.lord(), Graha::Ketu);
+        assert_eq!(Nakshatra::Revati.lord(), Graha::Buddh);
+    }
+
+    #[test]
+    fn navatara_counts_inclusively() {
+        use Nakshatra::*;
+        assert_eq!(navatara_from(Ashwini, Ashwini), NavataraGroup::Janma);
+        assert_eq!(navatara_from(Ashwini, Rohini), NavataraGroup::Kshema);
+        assert_eq!(navatara_from(Ashwini, Magha), NavataraGroup::Janma);
+        assert_eq!(navatara_from(Ashwini, Revati), NavataraGroup::ParamaMitra);
+        // Wraps past Revati.
+        assert_eq!(navatara_from(Revati, Bharani), NavataraGroup::Vipat);
+        for (i, t) in ALL_NAVATARAS.iter().enumerate() {
+            assert_eq!(t.index() as usize, i);
+        }
+    }
+
     #[test]
     fn nakshatra_span_correct() {
         assert!((NAKSHATRA_SPAN_27 - 13.333_333_333_333_334).abs() < 1e-10);
@@ -8,6 +8,8 @@
 //! 12 rashis of 30 deg each, starting from Mesha (Aries) at 0 deg.
 //! See `docs/clean_room_rashi_nakshatra.md`.
 
+use crate::longitude::SiderealLongitude;
+
 /// The 12 rashis (zodiac signs) starting from Mesha (Aries).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Rashi {
@@ -95,6 +97,61 @@ pub struct Dms {
     pub seconds: f64,
 }
 
+impl Dms {
+    /// Round seconds to `seconds_precision` decimals, carrying into minutes
+    /// and degrees when they round up to 60.
+    pub fn rounded(self, seconds_precision: usize) -> Dms {
+        let scale = 10f64.powi(seconds_precision.min(15) as i32);
+        let mut seconds = (self.seconds * scale).round() / scale;
+        let mut minutes = self.minutes;
+        let mut degrees = self.degrees;
+        if seconds >= 60.0 {
+            seconds -= 60.0;
+            minutes += 1;
+        }
+        if minutes >= 60 {
+            minutes -= 60;
+            degrees += 1;
+        }
+        Dms {
+            degrees,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Fixed-width `DD°MM'SS.s"` text with `seconds_precision` decimals.
+    ///
+    /// Degrees, minutes, and whole seconds are zero-padded to two digits;
+    /// rounding carries (59.96" at one decimal shows as the next minute).
+    pub fn format(self, seconds_precision: usize) -> String {
+        let d = self.rounded(seconds_precision);
+        let width = if seconds_precision == 0 {
+            2
+        } else {
+            seconds_precision + 3
+        };
+        format!(
+            "{:02}°{:02}'{:0width$.prec$}\"",
+            d.degrees,
+            d.minutes,
+            d.seconds,
+            width = width,
+            prec = seconds_precision
+        )
+    }
+
+    /// [`Dms::format`] with a leading `+` or `-`, for signed coordinates such
+    /// as latitude or declination.
+    ///
+    /// `Dms` stores a magnitude ([`deg_to_dms`] drops the sign), so the caller
+    /// passes `negative`, typically `deg < 0.0`.
+    pub fn format_signed(self, negative: bool, seconds_precision: usize) -> String {
+        let sign = if negative { '-' } else { '+' };
+        format!("{sign}{}", self.format(seconds_precision))
+    }
+}
+
 /// Full rashi position result.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RashiInfo {
@@ -139,8 +196,10 @@ fn normalize_360(deg: f64) -> f64 {
 ///
 /// The input is a sidereal longitude in degrees (tropical minus ayanamsha).
 /// Each rashi spans exactly 30 degrees: Mesha = [0, 30), Vrishabha = [30, 60), etc.
-pub fn rashi_from_longitude(sidereal_lon_deg: f64) -> RashiInfo {
-    let lon = normalize_360(sidereal_lon_deg);
+/// Takes a bare `f64` (assumed sidereal) or a [`SiderealLongitude`]; a
+/// [`crate::longitude::TropicalLongitude`] does not compile.
+pub fn rashi_from_longitude(sidereal_lon_deg: impl Into<SiderealLongitude>) -> RashiInfo {
+    let lon = normalize_360(sidereal_lon_deg.into().deg());
     let rashi_idx = (lon / 30.0).floor() as u8;
     // Clamp to 11 in case of floating point edge (exactly 360.0)
     let rashi_idx = rashi_idx.min(11);
@@ -179,6 +238,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn dms_format_pads_fields() {
+        let d = deg_to_dms(5.0 + 3.0 / 60.0 + 7.26 / 3600.0);
+        assert_eq!(d.format(1), "05°03'07.3\"");
+        assert_eq!(d.format(0), "05°03'07\"");
+    }
+
+    #[test]
+    fn dms_format_signed_negative_declination() {
+        let dec = -(23.0 + 26.0 / 60.0 + 21.448 / 3600.0);
+        assert_eq!(deg_to_dms(dec).format_signed(dec < 0.0, 1), "-23°26'21.4\"");
+        assert_eq!(deg_to_dms(-dec).format_signed(false, 1), "+23°26'21.4\"");
+    }
+
+    #[test]
+    fn dms_format_carries_rounded_seconds() {
+        let d = deg_to_dms(12.0 + 34.0 / 60.0 + 59.96 / 3600.0);
+        assert_eq!(d.format(1), "12°35'00.0\"");
+        let d = deg_to_dms(29.0 + 59.0 / 60.0 + 59.7 / 3600.0);
+        assert_eq!(d.format(0), "30°00'00\"");
+    }
+
     #[test]
     fn deg_to_dms_zero() {
         let d = deg_to_dms(0.0);
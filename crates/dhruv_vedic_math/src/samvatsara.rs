@@ -1,6 +1,7 @@
 //! Samvatsara (60-year cycle) enumeration.
 //!
 //! The 60 samvatsaras cycle continuously. The epoch is CE 1987 = Prabhava (order 1).
+//! Regional naming conventions are selected with [`SamvatsaraScheme`].
 //!
 //! Clean-room: standard Vedic 60-year cycle names, public domain.
 
@@ -273,15 +274,86 @@ const ALL_SAMVATSARA_NAMES: [&str; 60] = [
 /// Reference epoch: CE 1987 = Prabhava (order 1, index 0).
 pub const SAMVATSARA_EPOCH_YEAR: i32 = 1987;
 
-/// Determine the samvatsara for a given CE year.
+/// Naming convention used to map a CE year to a samvatsara.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum SamvatsaraScheme {
+    /// South Indian (chandramana) cycle: continuous, CE 1987 = Prabhava.
+    #[default]
+    SouthIndian = 0,
+    /// North Indian (Vikram Samvat) naming: the South Indian cycle shifted
+    /// ahead by [`NORTH_INDIAN_OFFSET`] names (CE 2024 = Kaalayukti).
+    NorthIndian = 1,
+    /// Barhaspatya cycle from mean Jupiter (Surya Siddhanta): one samvatsara
+    /// per mean sidereal sign of Jupiter (~361.03 days), so one name is
+    /// expunged roughly every 85.7 years.
+    Barhaspatya = 2,
+}
+
+impl SamvatsaraScheme {
+    /// Convert from the `repr(u8)` discriminant.
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Self::SouthIndian),
+            1 => Some(Self::NorthIndian),
+            2 => Some(Self::Barhaspatya),
+            _ => None,
+        }
+    }
+}
+
+/// Names by which the North Indian cycle runs ahead of the South Indian one.
 ///
-/// Returns `(samvatsara, order)` where order is 1-based (1..=60).
-pub fn samvatsara_from_year(ce_year: i32) -> (Samvatsara, u8) {
-    let offset = (ce_year - SAMVATSARA_EPOCH_YEAR).rem_euclid(60) as u8;
+/// Fixed since the last Barhaspatya expunction; see [`SamvatsaraScheme`].
+pub const NORTH_INDIAN_OFFSET: u8 = 14;
+
+/// Kali Yuga epoch (Surya Siddhanta), JD of 3102 BCE Feb 18 midnight.
+const KALI_EPOCH_JD: f64 = 588_465.5;
+
+/// Jupiter sidereal revolutions per mahayuga (Surya Siddhanta).
+const JUPITER_REVOLUTIONS_PER_MAHAYUGA: f64 = 364_220.0;
+
+/// Civil days per mahayuga (Surya Siddhanta).
+const CIVIL_DAYS_PER_MAHAYUGA: f64 = 1_577_917_828.0;
+
+/// Samvatsara index current at the Kali epoch (Vijaya).
+const KALI_EPOCH_SAMVATSARA_INDEX: i64 = 26;
+
+/// Determine the samvatsara for a given CE year under `scheme`.
+///
+/// The Barhaspatya scheme is evaluated at Gregorian April 14 (nominal
+/// Mesha sankranti) of `ce_year`. Returns `(samvatsara, order)` where order
+/// is 1-based (1..=60).
+pub fn samvatsara_from_year(ce_year: i32, scheme: SamvatsaraScheme) -> (Samvatsara, u8) {
+    let offset = match scheme {
+        SamvatsaraScheme::SouthIndian => (ce_year - SAMVATSARA_EPOCH_YEAR).rem_euclid(60) as u8,
+        SamvatsaraScheme::NorthIndian => {
+            ((ce_year - SAMVATSARA_EPOCH_YEAR).rem_euclid(60) as u8 + NORTH_INDIAN_OFFSET) % 60
+        }
+        SamvatsaraScheme::Barhaspatya => barhaspatya_index(nominal_mesha_jd(ce_year)),
+    };
     let samvatsara = ALL_SAMVATSARAS[offset as usize];
     (samvatsara, offset + 1)
 }
 
+/// Mean-Jupiter samvatsara index (0-based) in force at `jd`.
+fn barhaspatya_index(jd: f64) -> u8 {
+    let jupiter_signs =
+        (jd - KALI_EPOCH_JD) * 12.0 * JUPITER_REVOLUTIONS_PER_MAHAYUGA / CIVIL_DAYS_PER_MAHAYUGA;
+    (jupiter_signs.floor() as i64 + KALI_EPOCH_SAMVATSARA_INDEX).rem_euclid(60) as u8
+}
+
+/// JD (0h UT) of Gregorian April 14 of `year`.
+fn nominal_mesha_jd(year: i32) -> f64 {
+    // Days from 0000-03-01 (JD 1721119.5), proleptic Gregorian.
+    let y = year as i64;
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = 44; // April 14, counted from March 1
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    (era * 146_097 + doe) as f64 + 1_721_119.5
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,14 +379,14 @@ mod tests {
 
     #[test]
     fn epoch_year_is_prabhava() {
-        let (s, order) = samvatsara_from_year(1987);
+        let (s, order) = samvatsara_from_year(1987, SamvatsaraScheme::SouthIndian);
         assert_eq!(s, Samvatsara::Prabhava);
         assert_eq!(order, 1);
     }
 
     #[test]
     fn year_1988_is_vibhava() {
-        let (s, order) = samvatsara_from_year(1988);
+        let (s, order) = samvatsara_from_year(1988, SamvatsaraScheme::SouthIndian);
         assert_eq!(s, Samvatsara::Vibhava);
         assert_eq!(order, 2);
     }
@@ -322,7 +394,7 @@ mod tests {
     #[test]
     fn year_2046_wraps_to_prabhava() {
         // 1987 + 60 = 2047
-        let (s, order) = samvatsara_from_year(2047);
+        let (s, order) = samvatsara_from_year(2047, SamvatsaraScheme::SouthIndian);
         assert_eq!(s, Samvatsara::Prabhava);
         assert_eq!(order, 1);
     }
@@ -330,7 +402,7 @@ mod tests {
     #[test]
     fn year_2024() {
         // 2024 - 1987 = 37, index 37 = Krodhi, order 38
-        let (s, order) = samvatsara_from_year(2024);
+        let (s, order) = samvatsara_from_year(2024, SamvatsaraScheme::SouthIndian);
         assert_eq!(s, Samvatsara::Krodhi);
         assert_eq!(order, 38);
     }
@@ -338,8 +410,71 @@ mod tests {
     #[test]
     fn year_before_epoch() {
         // 1986: 1986-1987 = -1, rem_euclid(60) = 59 → Akshaya, order 60
-        let (s, order) = samvatsara_from_year(1986);
+        let (s, order) = samvatsara_from_year(1986, SamvatsaraScheme::SouthIndian);
         assert_eq!(s, Samvatsara::Akshaya);
         assert_eq!(order, 60);
     }
+
+    #[test]
+    fn nominal_mesha_jd_2000() {
+        assert_eq!(nominal_mesha_jd(2000), 2_451_648.5);
+    }
+
+    #[test]
+    fn north_and_south_differ_by_offset() {
+        let (south, south_order) = samvatsara_from_year(2024, SamvatsaraScheme::SouthIndian);
+        let (north, north_order) = samvatsara_from_year(2024, SamvatsaraScheme::NorthIndian);
+        assert_eq!(south, Samvatsara::Krodhi);
+        assert_eq!(north, Samvatsara::Kaalayukti);
+        assert_eq!(north_order - south_order, NORTH_INDIAN_OFFSET);
+    }
+
+    #[test]
+    fn barhaspatya_matches_north_indian_in_current_era() {
+        for year in 2020..2100 {
+            assert_eq!(
+                samvatsara_from_year(year, SamvatsaraScheme::Barhaspatya),
+                samvatsara_from_year(year, SamvatsaraScheme::NorthIndian),
+                "year {year}"
+            );
+        }
+    }
+
+    #[test]
+    fn barhaspatya_expunges_one_name_per_85_years() {
+        let skips: Vec<i32> = (1900..2200)
+            .filter(|&y| {
+                let (a, _) = samvatsara_from_year(y - 1, SamvatsaraScheme::Barhaspatya);
+                let (b, _) = samvatsara_from_year(y, SamvatsaraScheme::Barhaspatya);
+                (b.index() + 60 - a.index()) % 60 != 1
+            })
+            .collect();
+        assert_eq!(skips, vec![1933, 2019, 2104, 2190]);
+    }
+
+    #[test]
+    fn every_scheme_cycles_through_60_names() {
+        for scheme in [
+            SamvatsaraScheme::SouthIndian,
+            SamvatsaraScheme::NorthIndian,
+            SamvatsaraScheme::Barhaspatya,
+        ] {
+            let mut seen = [false; 60];
+            for year in 2020..2080 {
+                seen[samvatsara_from_year(year, scheme).0.index() as usize] = true;
+            }
+            // Barhaspatya skips at most one name in any 60-year window.
+            let count = seen.iter().filter(|&&s| s).count();
+            assert!(count >= 59, "{scheme:?}: {count}");
+        }
+        assert_eq!(ALL_SAMVATSARA_NAMES.len(), 60);
+    }
+
+    #[test]
+    fn scheme_from_u8_roundtrip() {
+        for v in 0..3u8 {
+            assert_eq!(SamvatsaraScheme::from_u8(v).unwrap() as u8, v);
+        }
+        assert_eq!(SamvatsaraScheme::from_u8(3), None);
+    }
 }
@@ -0,0 +1,203 @@
+//! Junction and pushkara placement flags for a sidereal longitude.
+//!
+//! - Gandanta: within one nakshatra pada (3°20') of a water-to-fire rashi
+//!   junction (Meena/Mesha, Karka/Simha, Vrischika/Dhanu), i.e. the last
+//!   pada of Revati/Ashlesha/Jyeshtha or the first of Ashwini/Magha/Mula.
+//! - Rashi sandhi: within one degree of any rashi boundary (the last or
+//!   first degree of a sign).
+//! - Pushkara: in one of the two pushkara navamsas of the rashi, or in its
+//!   pushkara bhaga degree.
+//!
+//! See `docs/clean_room_rashi_nakshatra.md`.
+
+use crate::rashi::rashi_from_longitude;
+
+/// Half-width of a gandanta zone in degrees (one nakshatra pada).
+pub const GANDANTA_HALF_WIDTH_DEG: f64 = 10.0 / 3.0;
+
+/// Half-width of a rashi sandhi zone in degrees.
+pub const RASHI_SANDHI_HALF_WIDTH_DEG: f64 = 1.0;
+
+/// Pushkara navamsas (1-9 within the rashi) for each rashi, Mesha first.
+///
+/// Fire rashis: 7th and 9th; earth: 3rd and 5th; air: 6th and 8th;
+/// water: 1st and 3rd.
+pub const PUSHKARA_NAVAMSAS: [[u8; 2]; 12] = [
+    [7, 9],
+    [3, 5],
+    [6, 8],
+    [1, 3],
+    [7, 9],
+    [3, 5],
+    [6, 8],
+    [1, 3],
+    [7, 9],
+    [3, 5],
+    [6, 8],
+    [1, 3],
+];
+
+/// Pushkara bhaga (1-based degree within the rashi) for each rashi, Mesha first.
+pub const PUSHKARA_BHAGAS: [u8; 12] = [21, 14, 18, 8, 19, 9, 24, 11, 23, 14, 19, 9];
+
+/// The three water-to-fire rashi junctions that carry a gandanta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GandantaKind {
+    /// Meena → Mesha (Revati → Ashwini), at 0°.
+    MeenaMesha,
+    /// Karka → Simha (Ashlesha → Magha), at 120°.
+    KarkaSimha,
+    /// Vrischika → Dhanu (Jyeshtha → Mula), at 240°.
+    VrischikaDhanu,
+}
+
+/// Which side of the junction a gandanta longitude lies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GandantaHalf {
+    /// Last pada of the water rashi, before the junction.
+    Approaching,
+    /// First pada of the fire rashi, at or after the junction.
+    Leaving,
+}
+
+/// Gandanta placement of a sidereal longitude.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GandantaStatus {
+    /// Water-to-fire junction the longitude falls around, named by the
+    /// water rashi and the fire rashi that follows it.
+    pub kind: GandantaKind,
+    /// `Approaching` in the last pada of the water rashi (longitude below the
+    /// junction); `Leaving` in the first pada of the fire rashi (at or past
+    /// it). Both halves of one junction share the same `kind`.
+    pub half: GandantaHalf,
+    /// Distance from the exact junction in degrees, [0, 3°20'). Measured
+    /// forward to the junction when approaching, back to it when leaving.
+    pub offset_deg: f64,
+}
+
+/// Gandanta junction, side, and offset of a sidereal longitude, if any.
+pub fn gandanta_status(sidereal_lon_deg: f64) -> Option<GandantaStatus> {
+    let info = rashi_from_longitude(sidereal_lon_deg);
+    let deg = info.degrees_in_rashi;
+    // Water rashis (Karka, Vrischika, Meena) approach a junction; the fire
+    // rashi that follows each one leaves it.
+    let (kind, half, offset_deg) = match info.rashi_index {
+        3 => (
+            GandantaKind::KarkaSimha,
+            GandantaHalf::Approaching,
+            30.0 - deg,
+        ),
+        7 => (
+            GandantaKind::VrischikaDhanu,
+            GandantaHalf::Approaching,
+            30.0 - deg,
+        ),
+        11 => (
+            GandantaKind::MeenaMesha,
+            GandantaHalf::Approaching,
+            30.0 - deg,
+        ),
+        0 => (GandantaKind::MeenaMesha, GandantaHalf::Leaving, deg),
+        4 => (GandantaKind::KarkaSimha, GandantaHalf::Leaving, deg),
+        8 => (GandantaKind::VrischikaDhanu, GandantaHalf::Leaving, deg),
+        _ => return None,
+    };
+    (offset_deg < GANDANTA_HALF_WIDTH_DEG).then_some(GandantaStatus {
+        kind,
+        half,
+        offset_deg,
+    })
+}
+
+/// Whether a sidereal longitude lies in a gandanta zone.
+pub fn is_gandanta(sidereal_lon_deg: f64) -> bool {
+    gandanta_status(sidereal_lon_deg).is_some()
+}
+
+/// Whether a sidereal longitude lies within one degree of a rashi boundary.
+pub fn is_rashi_sandhi(sidereal_lon_deg: f64) -> bool {
+    let deg = rashi_from_longitude(sidereal_lon_deg).degrees_in_rashi;
+    !(RASHI_SANDHI_HALF_WIDTH_DEG..30.0 - RASHI_SANDHI_HALF_WIDTH_DEG).contains(&deg)
+}
+
+/// Whether a sidereal longitude lies in a pushkara navamsa or pushkara bhaga.
+pub fn is_pushkara(sidereal_lon_deg: f64) -> bool {
+    let info = rashi_from_longitude(sidereal_lon_deg);
+    let ri = info.rashi_index as usize;
+    let deg = info.degrees_in_rashi;
+    let navamsa = ((deg * 3.0 / 10.0).floor() as u8).min(8) + 1;
+    let bhaga = (deg.floor() as u8).min(29) + 1;
+    PUSHKARA_NAVAMSAS[ri].contains(&navamsa) || PUSHKARA_BHAGAS[ri] == bhaga
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gandanta_at_water_fire_junctions() {
+        // 29°50' Karka, 2° Mesha, 28° Vrischika.
+        assert!(is_gandanta(90.0 + 29.0 + 50.0 / 60.0));
+        assert!(is_gandanta(2.0));
+        assert!(is_gandanta(238.0));
+        assert!(is_gandanta(359.5));
+        assert!(is_gandanta(-0.5));
+        // Other rashi junctions are not gandanta.
+        assert!(!is_gandanta(30.0));
+        assert!(!is_gandanta(124.0));
+    }
+
+    #[test]
+    fn gandanta_status_pisces_aries_halves() {
+        let five_min = 5.0 / 60.0;
+        // 29°55' Meena.
+        let s = gandanta_status(330.0 + 29.0 + 55.0 / 60.0).unwrap();
+        assert_eq!(s.kind, GandantaKind::MeenaMesha);
+        assert_eq!(s.half, GandantaHalf::Approaching);
+        assert!((s.offset_deg - five_min).abs() < 1e-9);
+        // 0°05' Mesha.
+        let s = gandanta_status(five_min).unwrap();
+        assert_eq!(s.kind, GandantaKind::MeenaMesha);
+        assert_eq!(s.half, GandantaHalf::Leaving);
+        assert!((s.offset_deg - five_min).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gandanta_status_other_junctions_and_limits() {
+        let s = gandanta_status(119.0).unwrap();
+        assert_eq!(
+            (s.kind, s.half),
+            (GandantaKind::KarkaSimha, GandantaHalf::Approaching)
+        );
+        let s = gandanta_status(240.0).unwrap();
+        assert_eq!(
+            (s.kind, s.half),
+            (GandantaKind::VrischikaDhanu, GandantaHalf::Leaving)
+        );
+        assert!(s.offset_deg.abs() < 1e-12);
+        // Exactly one pada away is outside the zone.
+        assert!(gandanta_status(240.0 + GANDANTA_HALF_WIDTH_DEG).is_none());
+        assert!(gandanta_status(60.0).is_none());
+    }
+
+    #[test]
+    fn rashi_sandhi_first_and_last_degree() {
+        assert!(is_rashi_sandhi(59.5));
+        assert!(is_rashi_sandhi(60.2));
+        assert!(!is_rashi_sandhi(61.0));
+        assert!(!is_rashi_sandhi(45.0));
+    }
+
+    #[test]
+    fn pushkara_navamsa_and_bhaga() {
+        // Mesha 7th navamsa starts at 20°.
+        assert!(is_pushkara(20.5));
+        assert!(!is_pushkara(15.0));
+        // Karka 1st navamsa.
+        assert!(is_pushkara(91.0));
+        // Tula 23°06' is in its 7th navamsa (not pushkara for air rashis)
+        // but in its pushkara bhaga, the 24th degree.
+        assert!(is_pushkara(180.0 + 23.1));
+        assert!(!is_pushkara(180.0 + 21.0));
+    }
+}
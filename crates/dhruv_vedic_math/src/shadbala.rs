@@ -13,7 +13,8 @@
 //!
 //! Clean-room implementation from BPHS.
 
-use crate::drishti::{base_virupa, special_virupa};
+use crate::combustion::graha_yuddha;
+use crate::drishti::graha_drishti;
 use crate::graha::{Graha, SAPTA_GRAHAS};
 use crate::graha_relationships::{
     BeneficNature, ChandraBeneficRule, Dignity, GrahaGender,
@@ -740,39 +741,25 @@ pub fn all_ayana_balas(declinations: &[f64; 7], moon_sun_elong: f64) -> [f64; 7]
 }
 
 /// Yuddha (planetary war) Bala: when two planets are within 1 deg longitude.
-/// Winner (northernmost declination) gets +60, loser gets -60.
+/// Winner (per [`graha_yuddha`]) gets +60, loser gets -60.
 /// Only Mars, Mercury, Jupiter, Venus, Saturn participate. Sun/Moon exempt.
 pub fn yuddha_bala(graha: Graha, sidereal_lons: &[f64; 7], declinations: &[f64; 7]) -> f64 {
     if !is_sapta_graha(graha) {
         return 0.0;
     }
-    // Sun (0) and Moon (1) don't participate
     let gi = graha.index() as usize;
-    if gi < 2 {
-        return 0.0;
-    }
-
-    let my_lon = normalize_360(sidereal_lons[gi]);
-    let my_dec = declinations[gi];
     let mut total = 0.0;
-
-    // Check against other participating planets (indices 2-6)
-    for oi in 2..7 {
-        if oi == gi {
-            continue;
-        }
-        let other_lon = normalize_360(sidereal_lons[oi]);
-        let diff = (my_lon - other_lon).abs();
-        let sep = if diff > 180.0 { 360.0 - diff } else { diff };
-        if sep < 1.0 {
-            // War! Winner = northernmost (highest declination)
-            let other_dec = declinations[oi];
-            if my_dec > other_dec {
-                total += 60.0; // Winner
-            } else if my_dec < other_dec {
-                total -= 60.0; // Loser
-            }
-            // Equal declination: no change
+    for (oi, other) in SAPTA_GRAHAS.iter().enumerate() {
+        let war = graha_yuddha(
+            graha,
+            sidereal_lons[gi],
+            Some(declinations[gi]),
+            *other,
+            sidereal_lons[oi],
+            Some(declinations[oi]),
+        );
+        if let Some(war) = war {
+            total += if war.winner == graha { 60.0 } else { -60.0 };
         }
     }
     total
@@ -790,13 +777,17 @@ pub fn all_yuddha_balas(sidereal_lons: &[f64; 7], declinations: &[f64; 7]) -> [f
 /// Kala Bala inputs.
 #[derive(Debug, Clone, Copy)]
 pub struct KalaBalaInputs {
+    /// Whether the birth instant lies between the Vedic-day sunrise and its sunset.
     pub is_daytime: bool,
     pub day_night_fraction: f64,
     pub local_day_fraction: f64,
     pub moon_sun_elongation: f64,
     pub year_lord: Graha,
     pub month_lord: Graha,
+    /// Lord of the vaar that began at the sunrise preceding the birth instant.
     pub weekday_lord: Graha,
+    /// Lord of the hora prevailing at the birth instant, counted in 24 equal
+    /// horas from that same sunrise to the next sunrise.
     pub hora_lord: Graha,
     /// Longitude-only Kranti values for Ayana Bala, indexed by sapta graha.
     pub ayana_krantis: [f64; 7],
@@ -1017,7 +1008,9 @@ pub fn all_naisargika_balas() -> [f64; 7] {
 /// divided balance. When `divide_guru_buddh_drishti_by_4` is false, their signed
 /// full incoming drishti virupa is added after the divided balance.
 /// `sidereal_lons` = all 9 grahas. `moon_sun_elong` classifies Chandra;
-/// Buddh is classified by same-rashi association.
+/// Buddh is classified by same-rashi association. Incoming virupa is taken
+/// from `graha_drishti`, the same source as `graha_drishti_matrix` and Bhava
+/// Drishti Bala.
 pub fn drik_bala_with_node_aspects(
     graha: Graha,
     sidereal_lons: &[f64; 9],
@@ -1042,10 +1035,7 @@ pub fn drik_bala_with_node_aspects(
             continue;
         }
         let src_lon = sidereal_lons[src.index() as usize];
-        let ang = normalize_360(target_lon - src_lon);
-        let bv = base_virupa(ang);
-        let sv = special_virupa(src, ang);
-        let total = bv + sv;
+        let total = graha_drishti(src, src_lon, target_lon).total_virupa;
         let nature = dynamic_benefic_nature(src, sidereal_lons, moon_sun_elong, chandra_rule);
 
         if matches!(src, Graha::Guru | Graha::Buddh) && !divide_guru_buddh_drishti_by_4 {
@@ -1108,6 +1098,16 @@ pub fn all_drik_balas(sidereal_lons: &[f64; 9], moon_sun_elong: f64) -> [f64; 7]
     )
 }
 
+/// Ishta and Kashta phala (benefic and malefic potency), in virupas.
+///
+/// Ishta = sqrt(uchcha * cheshta); Kashta = sqrt((60 - uchcha) * (60 - cheshta)).
+/// Inputs are clamped to 0..=60 so each phala stays within 0..=60.
+pub fn ishta_kashta_phala(uchcha_bala_virupa: f64, cheshta_bala_virupa: f64) -> (f64, f64) {
+    let u = uchcha_bala_virupa.clamp(0.0, 60.0);
+    let c = cheshta_bala_virupa.clamp(0.0, 60.0);
+    ((u * c).sqrt(), ((60.0 - u) * (60.0 - c)).sqrt())
+}
+
 // ---------------------------------------------------------------------------
 // 2h. Complete Shadbala
 // ---------------------------------------------------------------------------
@@ -1125,6 +1125,22 @@ pub struct ShadbalaBreakdown {
     pub total_rupas: f64,
     pub required_strength: f64,
     pub is_strong: bool,
+    /// Ishta phala from uchcha and cheshta bala (virupas, 0-60). Surya uses
+    /// ayana bala and Chandra paksha bala as cheshta.
+    pub ishta: f64,
+    /// Kashta phala, paired with `ishta` (virupas, 0-60).
+    pub kashta: f64,
+}
+
+/// Sapta grahas with their total rupas, strongest first.
+///
+/// `balas` is in [`SAPTA_GRAHAS`] order (as from `all_shadbalas_from_inputs`).
+/// Ties keep that order.
+pub fn rank_grahas_by_strength(balas: &[ShadbalaBreakdown; 7]) -> [(Graha, f64); 7] {
+    let mut ranked: [(Graha, f64); 7] =
+        std::array::from_fn(|i| (SAPTA_GRAHAS[i], balas[i].total_rupas));
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
 }
 
 /// All inputs needed for complete Shadbala computation.
@@ -1198,6 +1214,14 @@ pub fn shadbala_from_inputs(graha: Graha, inputs: &ShadbalaInputs) -> ShadbalaBr
     let total = sthana_result.total + dig + kala_result.total + cheshta + nais + drik;
     let rupas = total / 60.0;
     let required = REQUIRED_STRENGTH[gi];
+    // Surya and Chandra have no cheshta bala of their own; BPHS takes
+    // ayana bala and paksha bala in its place for the phalas.
+    let phala_cheshta = match graha {
+        Graha::Surya => kala_result.ayana,
+        Graha::Chandra => kala_result.paksha,
+        _ => cheshta,
+    };
+    let (ishta, kashta) = ishta_kashta_phala(sthana_result.uchcha, phala_cheshta);
 
     ShadbalaBreakdown {
         sthana: sthana_result,
@@ -1210,6 +1234,8 @@ pub fn shadbala_from_inputs(graha: Graha, inputs: &ShadbalaInputs) -> ShadbalaBr
         total_rupas: rupas,
         required_strength: required,
         is_strong: total >= required,
+        ishta,
+        kashta,
     }
 }
 
@@ -1252,6 +1278,8 @@ fn zero_shadbala() -> ShadbalaBreakdown {
         total_rupas: 0.0,
         required_strength: 0.0,
         is_strong: false,
+        ishta: 0.0,
+        kashta: 0.0,
     }
 }
 
@@ -1493,6 +1521,17 @@ mod tests {
 
     // --- Cheshta Bala ---
 
+    #[test]
+    fn yuddha_agrees_with_lost_planetary_war() {
+        let lons = [0.0, 0.0, 100.0, 100.5, 200.0, 300.0, 50.0];
+        let decls = [0.0, 0.0, -5.0, -10.0, 3.0, 7.0, 2.0];
+        // Mars is northernmost (-5 > -10) and wins.
+        assert!((yuddha_bala(Graha::Mangal, &lons, &decls) - 60.0).abs() < EPS);
+        assert!((yuddha_bala(Graha::Buddh, &lons, &decls) + 60.0).abs() < EPS);
+        assert!(!crate::avastha::lost_planetary_war(2, &lons, &decls));
+        assert!(crate::avastha::lost_planetary_war(3, &lons, &decls));
+    }
+
     #[test]
     fn circular_midpoint_handles_wrap() {
         assert!((circular_longitude_midpoint(350.0, 10.0) - 0.0).abs() < EPS);
@@ -1614,6 +1653,35 @@ mod tests {
         assert!((with_nodes + 15.0).abs() < EPS);
     }
 
+    #[test]
+    fn drik_bala_matches_drishti_matrix_net_virupa() {
+        use crate::drishti::graha_drishti_matrix;
+
+        let lons = [15.0, 200.0, 95.0, 310.0, 140.0, 250.0, 45.0, 170.0, 350.0];
+        let elong = 120.0;
+        let matrix = graha_drishti_matrix(&lons);
+        for target in SAPTA_GRAHAS {
+            let ti = target.index() as usize;
+            let mut net = 0.0;
+            for src in crate::graha::ALL_GRAHAS {
+                if src == target || matches!(src, Graha::Rahu | Graha::Ketu) {
+                    continue;
+                }
+                let virupa = matrix.entries[src.index() as usize][ti].total_virupa;
+                match dynamic_benefic_nature(src, &lons, elong, ChandraBeneficRule::default()) {
+                    BeneficNature::Benefic => net += virupa,
+                    BeneficNature::Malefic => net -= virupa,
+                }
+            }
+            let bala = drik_bala(target, &lons, elong);
+            assert!(
+                (bala - net / 4.0).abs() < EPS,
+                "{target:?}: drik_bala={bala} matrix net/4={}",
+                net / 4.0
+            );
+        }
+    }
+
     // --- Saptavargaja ---
 
     #[test]
@@ -1685,6 +1753,151 @@ mod tests {
         );
     }
 
+    // --- Ishta / Kashta phala ---
+
+    #[test]
+    fn ishta_kashta_bounds() {
+        assert_eq!(ishta_kashta_phala(60.0, 60.0), (60.0, 0.0));
+        assert_eq!(ishta_kashta_phala(0.0, 0.0), (0.0, 60.0));
+        let (i, k) = ishta_kashta_phala(30.0, 30.0);
+        assert!((i - 30.0).abs() < EPS && (k - 30.0).abs() < EPS);
+    }
+
+    /// Mangal at exact exaltation (Makara 28) with chaloccha opposite the
+    /// madhyama/sphuta midpoint; Surya at Mesha 0, Chandra 40 deg ahead.
+    fn phala_inputs() -> ShadbalaInputs {
+        let sidereal_lons = [0.0, 40.0, 298.0, 150.0, 200.0, 250.0, 320.0, 80.0, 260.0];
+        let mut sapta = [0.0; 7];
+        sapta.copy_from_slice(&sidereal_lons[..7]);
+        ShadbalaInputs {
+            sidereal_lons,
+            bhava_numbers: [1, 2, 10, 6, 8, 9, 11],
+            dig_bala_max_cusp_lons: [270.0, 90.0, 270.0, 0.0, 0.0, 90.0, 180.0],
+            cheshta_madhyama_lons: sapta,
+            cheshta_chaloccha_lons: [0.0, 0.0, 118.0, 0.0, 0.0, 0.0, 0.0],
+            kala: KalaBalaInputs {
+                is_daytime: true,
+                day_night_fraction: 0.5,
+                local_day_fraction: 0.5,
+                moon_sun_elongation: 40.0,
+                year_lord: Graha::Surya,
+                month_lord: Graha::Surya,
+                weekday_lord: Graha::Surya,
+                hora_lord: Graha::Surya,
+                ayana_krantis: [0.0; 7],
+                ayana_obliquity_deg: 23.44,
+                graha_declinations: [0.0; 7],
+                sidereal_lons: sapta,
+            },
+            include_node_aspects_for_drik_bala: false,
+            divide_guru_buddh_drishti_by_4_for_drik_bala: true,
+            chandra_benefic_rule: ChandraBeneficRule::default(),
+            varga_rashi_indices: [[0; 7]; 7],
+            varga_longitudes: [sapta; 7],
+        }
+    }
+
+    /// `phala_inputs` with graha `i`'s Dig Bala cusp placed to yield `dig` virupas.
+    fn inputs_with_dig(i: usize, dig: f64) -> ShadbalaInputs {
+        let mut inputs = phala_inputs();
+        let angle = 180.0 * (1.0 - dig / 60.0);
+        inputs.dig_bala_max_cusp_lons[i] = normalize_360(inputs.sidereal_lons[i] - angle);
+        inputs
+    }
+
+    #[test]
+    fn is_strong_flips_at_required_strength() {
+        // Chandra and Guru fall short by less than 60 virupas with zero Dig
+        // Bala, so Dig Bala alone can carry them across the threshold.
+        for graha in [Graha::Chandra, Graha::Guru] {
+            let i = graha.index() as usize;
+            let base = shadbala_from_inputs(graha, &inputs_with_dig(i, 0.0));
+            assert!(base.dig.abs() < EPS);
+            let needed = REQUIRED_STRENGTH[i] - base.total_shashtiamsas;
+            assert!(needed > 0.0 && needed < 60.0, "{graha:?} needs {needed}");
+
+            let below = shadbala_from_inputs(graha, &inputs_with_dig(i, needed - 1e-6));
+            assert!(below.total_shashtiamsas < below.required_strength);
+            assert!(!below.is_strong, "{graha:?} just below");
+
+            let at = shadbala_from_inputs(graha, &inputs_with_dig(i, needed + 1e-9));
+            assert!((at.total_shashtiamsas - REQUIRED_STRENGTH[i]).abs() < 1e-6);
+            assert!(at.is_strong, "{graha:?} at requirement");
+        }
+    }
+
+    #[test]
+    fn nodes_are_never_strong() {
+        for graha in [Graha::Rahu, Graha::Ketu] {
+            let b = shadbala_from_inputs(graha, &phala_inputs());
+            assert_eq!(b.required_strength, 0.0);
+            assert_eq!(b.total_shashtiamsas, 0.0);
+            assert!(!b.is_strong, "{graha:?}");
+        }
+    }
+
+    #[test]
+    fn exalted_mangal_with_max_cheshta_is_fully_ishta() {
+        let b = shadbala_from_inputs(Graha::Mangal, &phala_inputs());
+        assert!((b.sthana.uchcha - 60.0).abs() < EPS);
+        assert!((b.cheshta - 60.0).abs() < EPS);
+        assert!((b.ishta - 60.0).abs() < EPS, "ishta {}", b.ishta);
+        assert!(b.kashta.abs() < EPS, "kashta {}", b.kashta);
+    }
+
+    #[test]
+    fn surya_phala_uses_ayana_bala_as_cheshta() {
+        let b = shadbala_from_inputs(Graha::Surya, &phala_inputs());
+        assert_eq!(b.cheshta, 0.0);
+        assert!(b.kala.ayana > 0.0);
+        let (ishta, kashta) = ishta_kashta_phala(b.sthana.uchcha, b.kala.ayana);
+        assert!(b.ishta > 0.0, "ishta {}", b.ishta);
+        assert!((b.ishta - ishta).abs() < EPS && (b.kashta - kashta).abs() < EPS);
+        assert!(b.kashta < 60.0, "kashta {}", b.kashta);
+    }
+
+    #[test]
+    fn chandra_phala_uses_paksha_bala_as_cheshta() {
+        let b = shadbala_from_inputs(Graha::Chandra, &phala_inputs());
+        assert_eq!(b.cheshta, 0.0);
+        assert!(b.kala.paksha > 0.0);
+        let (ishta, kashta) = ishta_kashta_phala(b.sthana.uchcha, b.kala.paksha);
+        assert!(b.ishta > 0.0, "ishta {}", b.ishta);
+        assert!((b.ishta - ishta).abs() < EPS && (b.kashta - kashta).abs() < EPS);
+        assert!(b.kashta < 60.0, "kashta {}", b.kashta);
+    }
+
+    // --- Totals and ranking ---
+
+    fn breakdown_with_total(total_shashtiamsas: f64) -> ShadbalaBreakdown {
+        let mut b = zero_shadbala();
+        b.total_shashtiamsas = total_shashtiamsas;
+        b.total_rupas = total_shashtiamsas / 60.0;
+        b
+    }
+
+    #[test]
+    fn ranking_is_descending_by_rupas() {
+        let totals = [390.0, 420.0, 300.0, 500.0, 450.0, 300.0, 280.0];
+        let balas = totals.map(breakdown_with_total);
+        let ranked = rank_grahas_by_strength(&balas);
+        let order: Vec<Graha> = ranked.iter().map(|r| r.0).collect();
+        assert_eq!(
+            order,
+            [
+                Graha::Buddh,
+                Graha::Guru,
+                Graha::Chandra,
+                Graha::Surya,
+                Graha::Mangal,
+                Graha::Shukra,
+                Graha::Shani,
+            ]
+        );
+        assert!((ranked[0].1 - 500.0 / 60.0).abs() < EPS);
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+
     // --- all_* = individual ---
 
     #[test]
@@ -6,6 +6,7 @@
 //! Clean-room implementation from standard Vedic jyotish texts (BPHS).
 //! See `docs/clean_room_sphuta.md`.
 
+use crate::longitude::SiderealLongitude;
 use crate::util::normalize_360;
 
 /// The 16 sphuta types.
@@ -222,76 +223,65 @@ pub fn kunda(lagna: f64, moon: f64, mars: f64) -> f64 {
 
 /// Input longitudes for computing all 16 sphutas.
 ///
-/// All values are sidereal ecliptic longitudes in degrees.
+/// All values are sidereal ecliptic longitudes in degrees; the
+/// [`SiderealLongitude`] fields keep tropical values out.
 #[derive(Debug, Clone, Copy)]
 pub struct SphutalInputs {
-    pub sun: f64,
-    pub moon: f64,
-    pub mars: f64,
-    pub jupiter: f64,
-    pub venus: f64,
-    pub rahu: f64,
-    pub lagna: f64,
+    pub sun: SiderealLongitude,
+    pub moon: SiderealLongitude,
+    pub mars: SiderealLongitude,
+    pub jupiter: SiderealLongitude,
+    pub venus: SiderealLongitude,
+    pub rahu: SiderealLongitude,
+    pub lagna: SiderealLongitude,
     /// Longitude of the 8th house lord (needed for Mrityu Sphuta).
-    pub eighth_lord: f64,
+    pub eighth_lord: SiderealLongitude,
     /// Gulika longitude (needed for TriSphuta and derivatives).
-    pub gulika: f64,
+    pub gulika: SiderealLongitude,
 }
 
 /// Compute all 16 sphutas from the given inputs.
 ///
 /// Returns an array of (Sphuta, longitude_deg) pairs.
 pub fn all_sphutas(inputs: &SphutalInputs) -> [(Sphuta, f64); 16] {
-    let tri = trisphuta(inputs.lagna, inputs.moon, inputs.gulika);
-    let chatus = chatussphuta(tri, inputs.sun);
+    let sun = inputs.sun.deg();
+    let moon = inputs.moon.deg();
+    let mars = inputs.mars.deg();
+    let jupiter = inputs.jupiter.deg();
+    let venus = inputs.venus.deg();
+    let rahu = inputs.rahu.deg();
+    let lagna = inputs.lagna.deg();
+    let eighth_lord = inputs.eighth_lord.deg();
+    let gulika = inputs.gulika.deg();
+    let tri = trisphuta(lagna, moon, gulika);
+    let chatus = chatussphuta(tri, sun);
 
     [
-        (Sphuta::BhriguBindu, bhrigu_bindu(inputs.rahu, inputs.moon)),
-        (Sphuta::PranaSphuta, prana_sphuta(inputs.lagna, inputs.moon)),
-        (Sphuta::DehaSphuta, deha_sphuta(inputs.moon, inputs.lagna)),
-        (
-            Sphuta::MrityuSphuta,
-            mrityu_sphuta(inputs.eighth_lord, inputs.lagna),
-        ),
-        (
-            Sphuta::TithiSphuta,
-            tithi_sphuta(inputs.moon, inputs.sun, inputs.lagna),
-        ),
-        (Sphuta::YogaSphuta, yoga_sphuta(inputs.sun, inputs.moon)),
+        (Sphuta::BhriguBindu, bhrigu_bindu(rahu, moon)),
+        (Sphuta::PranaSphuta, prana_sphuta(lagna, moon)),
+        (Sphuta::DehaSphuta, deha_sphuta(moon, lagna)),
+        (Sphuta::MrityuSphuta, mrityu_sphuta(eighth_lord, lagna)),
+        (Sphuta::TithiSphuta, tithi_sphuta(moon, sun, lagna)),
+        (Sphuta::YogaSphuta, yoga_sphuta(sun, moon)),
         (
             Sphuta::YogaSphutaNormalized,
-            yoga_sphuta_normalized(inputs.sun, inputs.moon),
-        ),
-        (
-            Sphuta::RahuTithiSphuta,
-            rahu_tithi_sphuta(inputs.rahu, inputs.sun, inputs.lagna),
+            yoga_sphuta_normalized(sun, moon),
         ),
+        (Sphuta::RahuTithiSphuta, rahu_tithi_sphuta(rahu, sun, lagna)),
         (
             Sphuta::KshetraSphuta,
-            kshetra_sphuta(
-                inputs.venus,
-                inputs.moon,
-                inputs.mars,
-                inputs.jupiter,
-                inputs.lagna,
-            ),
-        ),
-        (
-            Sphuta::BeejaSphuta,
-            beeja_sphuta(inputs.sun, inputs.venus, inputs.jupiter),
+            kshetra_sphuta(venus, moon, mars, jupiter, lagna),
         ),
+        (Sphuta::BeejaSphuta, beeja_sphuta(sun, venus, jupiter)),
         (Sphuta::TriSphuta, tri),
         (Sphuta::ChatusSphuta, chatus),
-        (Sphuta::PanchaSphuta, panchasphuta(chatus, inputs.rahu)),
+        (Sphuta::PanchaSphuta, panchasphuta(chatus, rahu)),
         (
             Sphuta::SookshmaTrisphuta,
-            sookshma_trisphuta(inputs.lagna, inputs.moon, inputs.gulika, inputs.sun),
-        ),
-        (
-            Sphuta::AvayogaSphuta,
-            avayoga_sphuta(inputs.sun, inputs.moon),
+            sookshma_trisphuta(lagna, moon, gulika, sun),
         ),
-        (Sphuta::Kunda, kunda(inputs.lagna, inputs.moon, inputs.mars)),
+        (Sphuta::AvayogaSphuta, avayoga_sphuta(sun, moon)),
+        (Sphuta::Kunda, kunda(lagna, moon, mars)),
     ]
 }
 
@@ -461,15 +451,15 @@ mod tests {
     #[test]
     fn all_sphutas_output_in_range() {
         let inputs = SphutalInputs {
-            sun: 100.0,
-            moon: 200.0,
-            mars: 150.0,
-            jupiter: 250.0,
-            venus: 300.0,
-            rahu: 50.0,
-            lagna: 120.0,
-            eighth_lord: 180.0,
-            gulika: 270.0,
+            sun: SiderealLongitude(100.0),
+            moon: SiderealLongitude(200.0),
+            mars: SiderealLongitude(150.0),
+            jupiter: SiderealLongitude(250.0),
+            venus: SiderealLongitude(300.0),
+            rahu: SiderealLongitude(50.0),
+            lagna: SiderealLongitude(120.0),
+            eighth_lord: SiderealLongitude(180.0),
+            gulika: SiderealLongitude(270.0),
         };
         let results = all_sphutas(&inputs);
         assert_eq!(results.len(), 16);
@@ -486,15 +476,15 @@ mod tests {
     fn all_sphutas_consistency() {
         // Verify that batch matches individual functions
         let inputs = SphutalInputs {
-            sun: 100.0,
-            moon: 200.0,
-            mars: 150.0,
-            jupiter: 250.0,
-            venus: 300.0,
-            rahu: 50.0,
-            lagna: 120.0,
-            eighth_lord: 180.0,
-            gulika: 270.0,
+            sun: SiderealLongitude(100.0),
+            moon: SiderealLongitude(200.0),
+            mars: SiderealLongitude(150.0),
+            jupiter: SiderealLongitude(250.0),
+            venus: SiderealLongitude(300.0),
+            rahu: SiderealLongitude(50.0),
+            lagna: SiderealLongitude(120.0),
+            eighth_lord: SiderealLongitude(180.0),
+            gulika: SiderealLongitude(270.0),
         };
         let results = all_sphutas(&inputs);
         assert!((results[0].1 - bhrigu_bindu(50.0, 200.0)).abs() < 1e-10);
@@ -6,6 +6,8 @@
 //!
 //! Clean-room implementation from standard Vedic convention.
 
+use crate::error::VedicError;
+
 /// The two pakshas (fortnights) of a lunar month.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Paksha {
@@ -226,6 +228,41 @@ pub fn tithi_from_elongation(elongation_deg: f64) -> TithiPosition {
     }
 }
 
+/// How a Vedic day (sunrise to next sunrise) sits in the tithi sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TithiDayKind {
+    /// The next sunrise falls in the following tithi.
+    Normal,
+    /// A tithi began and ended within the day without being current at
+    /// either sunrise (skipped); carries the skipped tithi.
+    Kshaya(Tithi),
+    /// The sunrise tithi is still current at the next sunrise (repeated);
+    /// carries that tithi.
+    Vriddhi(Tithi),
+}
+
+/// Classify a Vedic day from the Moon-Sun elongation (degrees) at its
+/// sunrise and at the next sunrise.
+///
+/// The elongation advances 10–15 deg per day, so at most one tithi can be
+/// skipped. A gap of three or more tithis means the two elongations are not
+/// from consecutive sunrises and is rejected with `InvalidInput`.
+pub fn tithi_day_kind(
+    elongation_at_sunrise: f64,
+    elongation_at_next_sunrise: f64,
+) -> Result<TithiDayKind, VedicError> {
+    let first = tithi_from_elongation(elongation_at_sunrise).tithi_index;
+    let next = tithi_from_elongation(elongation_at_next_sunrise).tithi_index;
+    match (next + 30 - first) % 30 {
+        0 => Ok(TithiDayKind::Vriddhi(ALL_TITHIS[first as usize])),
+        1 => Ok(TithiDayKind::Normal),
+        2 => Ok(TithiDayKind::Kshaya(ALL_TITHIS[(first as usize + 1) % 30])),
+        _ => Err(VedicError::InvalidInput(
+            "elongations span more than one skipped tithi",
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -320,4 +357,53 @@ mod tests {
         // -6 mod 360 = 354 → Amavasya
         assert_eq!(pos.tithi, Tithi::Amavasya);
     }
+
+    /// Elongation at successive sunrises for a lunation with uniform daily
+    /// motion `rate` deg/day, starting at `start` deg.
+    fn sunrise_elongations(start: f64, rate: f64, days: usize) -> Vec<f64> {
+        (0..=days)
+            .map(|d| normalize_360(start + rate * d as f64))
+            .collect()
+    }
+
+    #[test]
+    fn fast_moon_skips_a_tithi() {
+        // Near perigee the elongation gains ~15 deg/day, so every few days a
+        // 12 deg tithi falls entirely between two sunrises.
+        let e = sunrise_elongations(5.0, 15.0, 4);
+        let kinds: Vec<TithiDayKind> = e
+            .windows(2)
+            .map(|w| tithi_day_kind(w[0], w[1]).unwrap())
+            .collect();
+        // Sunrise elongations 5, 20, 35, 50, 65 deg -> tithi indices 0, 1, 2, 4, 5.
+        assert_eq!(kinds[0], TithiDayKind::Normal);
+        assert_eq!(kinds[2], TithiDayKind::Kshaya(Tithi::ShuklaChaturthi));
+        assert!(kinds.iter().all(|k| !matches!(k, TithiDayKind::Vriddhi(_))));
+    }
+
+    #[test]
+    fn slow_moon_repeats_a_tithi() {
+        // Near apogee (~10 deg/day) a tithi can span two sunrises.
+        assert_eq!(
+            tithi_day_kind(13.0, 23.5),
+            Ok(TithiDayKind::Vriddhi(Tithi::ShuklaDwitiya))
+        );
+    }
+
+    #[test]
+    fn day_kind_wraps_at_amavasya() {
+        // Amavasya (348..360) to Shukla Dwitiya skips Pratipada.
+        assert_eq!(
+            tithi_day_kind(349.0, 13.0),
+            Ok(TithiDayKind::Kshaya(Tithi::ShuklaPratipada))
+        );
+        assert_eq!(tithi_day_kind(349.0, 2.0), Ok(TithiDayKind::Normal));
+    }
+
+    #[test]
+    fn day_kind_rejects_non_consecutive_sunrises() {
+        // Three tithis apart cannot happen between consecutive sunrises.
+        assert!(tithi_day_kind(5.0, 41.0).is_err());
+        assert!(tithi_day_kind(5.0, 185.0).is_err());
+    }
 }
@@ -493,6 +493,24 @@ mod tests {
         assert!((jd - 106.5).abs() < 1e-10);
     }
 
+    #[test]
+    fn gulika_points_resolve_to_ordered_distinct_instants() {
+        let jd = |point| {
+            let cfg = TimeUpagrahaConfig {
+                gulika_point: point,
+                ..TimeUpagrahaConfig::default()
+            };
+            time_upagraha_jd_with_config(Upagraha::Gulika, 2, true, 100.0, 108.0, 112.0, &cfg)
+        };
+        let (start, middle, end) = (
+            jd(TimeUpagrahaPoint::Start),
+            jd(TimeUpagrahaPoint::Middle),
+            jd(TimeUpagrahaPoint::End),
+        );
+        assert!(start < middle && middle < end);
+        assert!((end - start - 1.0).abs() < 1e-10);
+    }
+
     // --- Sun-based upagrahas ---
 
     #[test]
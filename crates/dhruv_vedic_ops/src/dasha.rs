@@ -19,10 +19,11 @@ use dhruv_vedic_base::dasha::{
     karaka_kendradi_snapshot, kendradi_hierarchy, kendradi_level0, kendradi_snapshot,
     mandooka_children, mandooka_complete_level, mandooka_hierarchy, mandooka_level0,
     mandooka_snapshot, nakshatra_children, nakshatra_complete_level, nakshatra_config_for_system,
-    nakshatra_hierarchy, nakshatra_level0, nakshatra_snapshot, shoola_hierarchy, shoola_level0,
-    shoola_snapshot, sthira_hierarchy, sthira_level0, sthira_snapshot, yogardha_hierarchy,
-    yogardha_level0, yogardha_snapshot, yogini_children, yogini_complete_level, yogini_config,
-    yogini_hierarchy, yogini_level0, yogini_snapshot,
+    nakshatra_hierarchy, nakshatra_level0, nakshatra_snapshot, narayana_hierarchy, narayana_level0,
+    narayana_snapshot, narayana_total_years, shoola_hierarchy, shoola_level0, shoola_snapshot,
+    sthira_hierarchy, sthira_level0, sthira_snapshot, yogardha_hierarchy, yogardha_level0,
+    yogardha_snapshot, yogini_children, yogini_complete_level, yogini_config, yogini_hierarchy,
+    yogini_level0, yogini_snapshot,
 };
 use dhruv_vedic_base::riseset::compute_rise_set;
 use dhruv_vedic_base::riseset_types::{GeoLocation, RiseSetConfig, RiseSetEvent, RiseSetResult};
@@ -49,13 +50,14 @@ pub(crate) fn is_rashi_system(system: DashaSystem) -> bool {
             | DashaSystem::Kendradi
             | DashaSystem::KarakaKendradi
             | DashaSystem::KarakaKendradiGraha
+            | DashaSystem::Narayana
     )
 }
 
 /// Check if a dasha system needs the Moon's sidereal longitude.
 ///
 /// Returns true for nakshatra-based (10), Yogini, and KaalChakra systems.
-/// Returns false for rashi-based (10) and Kala systems.
+/// Returns false for rashi-based (11) and Kala systems.
 pub(crate) fn needs_moon_lon(system: DashaSystem) -> bool {
     !is_rashi_system(system) && system != DashaSystem::Kala
 }
@@ -107,12 +109,7 @@ fn assemble_rashi_inputs(
     let graha_lons = graha_longitudes(
         engine,
         jd_tdb,
-        &GrahaLongitudesConfig::sidereal_with_model(
-            aya_config.ayanamsha_system,
-            aya_config.use_nutation,
-            aya_config.precession_model,
-            aya_config.reference_plane,
-        ),
+        &GrahaLongitudesConfig::from_sankranti_config(aya_config),
     )?;
 
     let jd_utc = utc_to_jd_utc(utc);
@@ -318,12 +315,13 @@ fn dispatch_level0(
     moon_sid_lon: f64,
     rashi_inputs: Option<&RashiDashaInputs>,
     sunrise_sunset: Option<(f64, f64)>,
+    variation: &DashaVariationConfig,
 ) -> Result<Vec<DashaPeriod>, SearchError> {
     if let Some(cfg) = nakshatra_config_for_system(system) {
-        return Ok(nakshatra_level0(birth_jd, moon_sid_lon, &cfg));
+        return Ok(nakshatra_level0(birth_jd, moon_sid_lon, &cfg, variation));
     }
 
-    match system {
+    let periods: Result<Vec<DashaPeriod>, SearchError> = match system {
         DashaSystem::Yogini => Ok(yogini_level0(birth_jd, moon_sid_lon, &yogini_config())),
         DashaSystem::Chara => Ok(chara_level0(
             birth_jd,
@@ -374,6 +372,10 @@ fn dispatch_level0(
                 rashi_inputs.ok_or(SearchError::InvalidConfig("rashi inputs required"))?,
             ),
         ),
+        DashaSystem::Narayana => Ok(narayana_level0(
+            birth_jd,
+            rashi_inputs.ok_or(SearchError::InvalidConfig("rashi inputs required"))?,
+        )),
         DashaSystem::Kala => {
             let (sunrise, sunset) = sunrise_sunset.ok_or(SearchError::InvalidConfig(
                 "sunrise/sunset required for Kala dasha",
@@ -382,7 +384,8 @@ fn dispatch_level0(
         }
         DashaSystem::KaalChakra => Ok(kaal_chakra_level0(birth_jd, moon_sid_lon)),
         _ => unreachable!("nakshatra systems handled before match"),
-    }
+    };
+    Ok(variation.year_length.rescale_level0(periods?, birth_jd))
 }
 
 fn dispatch_children(
@@ -543,6 +546,21 @@ fn dispatch_children(
                 method,
             ))
         }
+        DashaSystem::Narayana => {
+            let ri = rashi_inputs.ok_or(SearchError::InvalidConfig("rashi inputs required"))?;
+            let method = method_for_parent_level(
+                parent.level,
+                dhruv_vedic_base::dasha::narayana::NARAYANA_DEFAULT_METHOD,
+                variation,
+            );
+            Ok(dhruv_vedic_base::dasha::rashi_dasha::rashi_children(
+                parent,
+                &|r| chara_period_years(r, ri),
+                narayana_total_years(ri),
+                dhruv_vedic_base::dasha::narayana::NARAYANA_DEFAULT_METHOD,
+                method,
+            ))
+        }
         DashaSystem::Kala => {
             let method = method_for_parent_level(
                 parent.level,
@@ -710,6 +728,23 @@ fn dispatch_complete_level(
             )
             .map_err(SearchError::from)
         }
+        DashaSystem::Narayana => {
+            let ri = rashi_inputs.ok_or(SearchError::InvalidConfig("rashi inputs required"))?;
+            let method = method_for_parent_level(
+                parent_depth,
+                dhruv_vedic_base::dasha::narayana::NARAYANA_DEFAULT_METHOD,
+                variation,
+            );
+            dhruv_vedic_base::dasha::rashi_dasha::rashi_complete_level(
+                parent_level,
+                &|r| chara_period_years(r, ri),
+                narayana_total_years(ri),
+                child_level,
+                dhruv_vedic_base::dasha::narayana::NARAYANA_DEFAULT_METHOD,
+                method,
+            )
+            .map_err(SearchError::from)
+        }
         DashaSystem::Kala => {
             let method = method_for_parent_level(
                 parent_depth,
@@ -799,6 +834,10 @@ fn dispatch_hierarchy(
             karaka_kendradi_graha_hierarchy(birth_jd, ri, max_level, variation)
                 .map_err(SearchError::from)
         }
+        DashaSystem::Narayana => {
+            let ri = rashi_inputs.ok_or(SearchError::InvalidConfig("rashi inputs required"))?;
+            narayana_hierarchy(birth_jd, ri, max_level, variation).map_err(SearchError::from)
+        }
         DashaSystem::Kala => {
             let (sunrise, sunset) = sunrise_sunset.ok_or(SearchError::InvalidConfig(
                 "sunrise/sunset required for Kala dasha",
@@ -913,6 +952,12 @@ fn dispatch_snapshot(
                 birth_jd, ri, query_jd, max_level, variation,
             ))
         }
+        DashaSystem::Narayana => {
+            let ri = rashi_inputs.ok_or(SearchError::InvalidConfig("rashi inputs required"))?;
+            Ok(narayana_snapshot(
+                birth_jd, ri, query_jd, max_level, variation,
+            ))
+        }
         DashaSystem::Kala => {
             let (sunrise, sunset) = sunrise_sunset.ok_or(SearchError::InvalidConfig(
                 "sunrise/sunset required for Kala dasha",
@@ -1055,6 +1100,7 @@ pub fn dasha_level0_for_birth(
     _bhava_config: &BhavaConfig,
     riseset_config: &RiseSetConfig,
     aya_config: &SankrantiConfig,
+    variation: &DashaVariationConfig,
 ) -> Result<Vec<DashaPeriod>, SearchError> {
     let birth_jd = utc_to_jd_utc(birth_utc);
     let inputs = compute_dasha_inputs_for_birth(
@@ -1072,6 +1118,7 @@ pub fn dasha_level0_for_birth(
         inputs.moon_sid_lon.unwrap_or(0.0),
         inputs.rashi_inputs.as_ref(),
         inputs.sunrise_sunset,
+        variation,
     )
 }
 
@@ -1087,6 +1134,7 @@ pub fn dasha_level0_entity_for_birth(
     bhava_config: &BhavaConfig,
     riseset_config: &RiseSetConfig,
     aya_config: &SankrantiConfig,
+    variation: &DashaVariationConfig,
 ) -> Result<Option<DashaPeriod>, SearchError> {
     let periods = dasha_level0_for_birth(
         engine,
@@ -1097,6 +1145,7 @@ pub fn dasha_level0_entity_for_birth(
         bhava_config,
         riseset_config,
         aya_config,
+        variation,
     )?;
     Ok(periods.into_iter().find(|p| p.entity == entity))
 }
@@ -1196,11 +1245,56 @@ pub fn dasha_complete_level_for_birth(
     )
 }
 
+/// Dasha periods at one level that overlap a date range, in start order.
+///
+/// Builds the timeline level by level, expanding only the parents that
+/// overlap `[start_utc, end_utc]`, so a 20-year window at pratyantardasha
+/// depth never materializes the whole 120-year hierarchy.
+#[allow(clippy::too_many_arguments)]
+pub fn dasha_transitions_in_range(
+    engine: &Engine,
+    eop: &EopKernel,
+    birth_utc: &UtcTime,
+    start_utc: &UtcTime,
+    end_utc: &UtcTime,
+    location: &GeoLocation,
+    system: DashaSystem,
+    level: DashaLevel,
+    _bhava_config: &BhavaConfig,
+    riseset_config: &RiseSetConfig,
+    aya_config: &SankrantiConfig,
+    variation: &DashaVariationConfig,
+) -> Result<Vec<DashaRangePeriod>, SearchError> {
+    let computed = compute_dasha_inputs_for_birth(
+        engine,
+        eop,
+        birth_utc,
+        location,
+        system,
+        riseset_config,
+        aya_config,
+    )?;
+    let inputs = DashaInputs {
+        moon_sid_lon: computed.moon_sid_lon,
+        rashi_inputs: computed.rashi_inputs.as_ref(),
+        sunrise_sunset: computed.sunrise_sunset,
+    };
+    dasha_transitions_with_inputs(
+        utc_to_jd_utc(birth_utc),
+        utc_to_jd_utc(start_utc),
+        utc_to_jd_utc(end_utc),
+        system,
+        level,
+        variation,
+        &inputs,
+    )
+}
+
 /// Pre-computed inputs for context-sharing dasha computation.
 ///
 /// Callers populate only the fields needed by the target system:
 /// - `moon_sid_lon`: required for nakshatra-based, Yogini, KaalChakra
-/// - `rashi_inputs`: required for rashi-based systems (10)
+/// - `rashi_inputs`: required for rashi-based systems (11)
 /// - `sunrise_sunset`: required for Kala as `(sunrise_jd_utc, sunset_jd_utc)`,
 ///   optional for Chakra (BirthPeriod)
 #[derive(Debug, Clone, Default)]
@@ -1259,6 +1353,7 @@ pub fn dasha_snapshot_with_inputs(
 pub fn dasha_level0_with_inputs(
     birth_jd: f64,
     system: DashaSystem,
+    variation: &DashaVariationConfig,
     inputs: &DashaInputs<'_>,
 ) -> Result<Vec<DashaPeriod>, SearchError> {
     let moon_sid_lon = inputs.moon_sid_lon.unwrap_or(0.0);
@@ -1268,6 +1363,7 @@ pub fn dasha_level0_with_inputs(
         moon_sid_lon,
         inputs.rashi_inputs,
         inputs.sunrise_sunset,
+        variation,
     )
 }
 
@@ -1276,9 +1372,10 @@ pub fn dasha_level0_entity_with_inputs(
     birth_jd: f64,
     system: DashaSystem,
     entity: DashaEntity,
+    variation: &DashaVariationConfig,
     inputs: &DashaInputs<'_>,
 ) -> Result<Option<DashaPeriod>, SearchError> {
-    let periods = dasha_level0_with_inputs(birth_jd, system, inputs)?;
+    let periods = dasha_level0_with_inputs(birth_jd, system, variation, inputs)?;
     Ok(periods.into_iter().find(|period| period.entity == entity))
 }
 
@@ -1327,6 +1424,72 @@ pub fn dasha_complete_level_with_inputs(
     )
 }
 
+/// A dasha period overlapping a query range.
+///
+/// `period.start_jd`/`end_jd` are clipped to the range; the flags record
+/// which ends were cut, so the true boundary lies outside the range.
+#[derive(Debug, Clone, Copy)]
+pub struct DashaRangePeriod {
+    pub period: DashaPeriod,
+    /// The period began before the range start.
+    pub clipped_start: bool,
+    /// The period ends after the range end.
+    pub clipped_end: bool,
+}
+
+/// Context-sharing range query using pre-computed inputs.
+///
+/// `parent_idx` in the returned periods indexes the overlapping parents of
+/// the previous level, not the full hierarchy.
+pub fn dasha_transitions_with_inputs(
+    birth_jd: f64,
+    start_jd: f64,
+    end_jd: f64,
+    system: DashaSystem,
+    level: DashaLevel,
+    variation: &DashaVariationConfig,
+    inputs: &DashaInputs<'_>,
+) -> Result<Vec<DashaRangePeriod>, SearchError> {
+    if !(start_jd.is_finite() && end_jd.is_finite()) || start_jd >= end_jd {
+        return Err(SearchError::InvalidConfig(
+            "range start must be before range end",
+        ));
+    }
+    let overlaps = |p: &DashaPeriod| p.start_jd < end_jd && p.end_jd > start_jd;
+
+    let level0 = dasha_level0_with_inputs(birth_jd, system, variation, inputs)?;
+    let mut current: Vec<DashaPeriod> = level0.into_iter().filter(overlaps).collect();
+
+    let mut depth = DashaLevel::Mahadasha;
+    while depth < level && !current.is_empty() {
+        let Some(child_level) = depth.child_level() else {
+            break;
+        };
+        current =
+            dasha_complete_level_with_inputs(system, &current, child_level, variation, inputs)?
+                .into_iter()
+                .filter(overlaps)
+                .collect();
+        depth = child_level;
+    }
+
+    current.sort_by(|a, b| a.start_jd.total_cmp(&b.start_jd));
+    Ok(current
+        .into_iter()
+        .map(|mut period| {
+            let clipped_start = period.start_jd < start_jd;
+            let clipped_end = period.end_jd > end_jd;
+            period.start_jd = period.start_jd.max(start_jd);
+            period.end_jd = period.end_jd.min(end_jd);
+            DashaRangePeriod {
+                period,
+                clipped_start,
+                clipped_end,
+            }
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1375,6 +1538,7 @@ mod tests {
             DashaSystem::Kendradi,
             DashaSystem::KarakaKendradi,
             DashaSystem::KarakaKendradiGraha,
+            DashaSystem::Narayana,
         ];
         for system in systems {
             assert!(
@@ -1424,6 +1588,7 @@ mod tests {
             DashaSystem::Kendradi,
             DashaSystem::KarakaKendradi,
             DashaSystem::KarakaKendradiGraha,
+            DashaSystem::Narayana,
             DashaSystem::KaalChakra,
         ];
         for system in others {
@@ -1434,6 +1599,77 @@ mod tests {
         }
     }
 
+    // --- range transitions ---
+
+    #[test]
+    fn test_transitions_vimshottari_20_year_window() {
+        let birth_jd = 2451545.0;
+        let start_jd = birth_jd + 10.0 * 365.25;
+        let end_jd = birth_jd + 30.0 * 365.25;
+        let var = DashaVariationConfig::default();
+        let inputs = DashaInputs {
+            moon_sid_lon: Some(100.0),
+            ..DashaInputs::default()
+        };
+
+        let got = dasha_transitions_with_inputs(
+            birth_jd,
+            start_jd,
+            end_jd,
+            DashaSystem::Vimshottari,
+            DashaLevel::Antardasha,
+            &var,
+            &inputs,
+        )
+        .unwrap();
+
+        let h = dasha_hierarchy_with_inputs(birth_jd, DashaSystem::Vimshottari, 1, &var, &inputs)
+            .unwrap();
+        let expected: Vec<_> = h.levels[1]
+            .iter()
+            .filter(|p| p.start_jd < end_jd && p.end_jd > start_jd)
+            .collect();
+        assert_eq!(got.len(), expected.len());
+        for (g, e) in got.iter().zip(&expected) {
+            assert_eq!(g.period.entity, e.entity);
+            assert_eq!(g.period.level, DashaLevel::Antardasha);
+            assert!((g.period.start_jd - e.start_jd.max(start_jd)).abs() < 1e-9);
+            assert!((g.period.end_jd - e.end_jd.min(end_jd)).abs() < 1e-9);
+        }
+
+        // Only the ends straddle the window, and the result tiles it exactly.
+        assert!(got[0].clipped_start && !got[0].clipped_end);
+        assert!(got[got.len() - 1].clipped_end);
+        assert!(
+            got[1..got.len() - 1]
+                .iter()
+                .all(|r| !r.clipped_start && !r.clipped_end)
+        );
+        assert!((got[0].period.start_jd - start_jd).abs() < 1e-9);
+        assert!((got[got.len() - 1].period.end_jd - end_jd).abs() < 1e-9);
+        for w in got.windows(2) {
+            assert!((w[1].period.start_jd - w[0].period.end_jd).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_transitions_rejects_empty_range() {
+        let inputs = DashaInputs {
+            moon_sid_lon: Some(100.0),
+            ..DashaInputs::default()
+        };
+        let result = dasha_transitions_with_inputs(
+            2451545.0,
+            2451600.0,
+            2451600.0,
+            DashaSystem::Vimshottari,
+            DashaLevel::Mahadasha,
+            &DashaVariationConfig::default(),
+            &inputs,
+        );
+        assert!(result.is_err());
+    }
+
     // --- determine_birth_period tests (Phase A2) ---
 
     #[test]
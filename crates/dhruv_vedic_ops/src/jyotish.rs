@@ -19,19 +19,20 @@ use dhruv_vedic_base::vaar::vaar_from_jd;
 use dhruv_vedic_base::{
     ALL_GRAHAS, AllGrahaAvasthas, AllSpecialLagnas, AllUpagrahas, Amsha, AmshaRequest,
     ArudhaResult, AshtakavargaResult, AvasthaInputs, BhavaConfig, BhavaResult, CharakarakaResult,
-    CharakarakaScheme, DIG_BALA_BHAVA, Dignity, DrishtiEntry, Graha, GrahaAvasthas, KalaBalaInputs,
-    LajjitadiInputs, LunarNode, NodeDignityPolicy, NodeMode, SAPTA_GRAHAS, SayanadiInputs,
-    ShadbalaInputs, TimeUpagrahaConfig, Upagraha, all_avasthas, all_combustion_status,
-    all_dashavarga_vimsopaka, all_saptavarga_vimsopaka, all_shadbalas_from_inputs,
-    all_shadvarga_vimsopaka, all_shodasavarga_vimsopaka, all_sphutas, amsha_longitude,
-    bhrigu_bindu, calculate_ashtakavarga, charakarakas_from_longitudes, compute_bhavas,
-    default_amsha_variation, dignity_in_rashi_with_positions, ghati_lagna, ghatikas_since_sunrise,
-    graha_drishti, graha_drishti_matrix, hora_lagna, hora_lord as graha_hora_lord,
-    is_valid_amsha_variation, jd_tdb_to_centuries, kala_abda_lord, kala_masa_lord,
-    lagna_longitude_rad, lost_planetary_war, lunar_node_deg_for_epoch_on_plane,
-    nakshatra_from_longitude, navamsa_number, node_dignity_in_rashi, normalize_360, nth_rashi_from,
-    pranapada_lagna, rashi_from_longitude, rashi_lord_by_index, sree_lagna, sun_based_upagrahas,
-    time_upagraha_jd_with_config, vaar_lord as graha_vaar_lord,
+    CharakarakaScheme, CombustionConfig, DIG_BALA_BHAVA, Dignity, DrishtiEntry, Graha,
+    GrahaAvasthas, GrahaBodyExt, KalaBalaInputs, LajjitadiInputs, LunarNode, NodeDignityPolicy,
+    NodeMode, SAPTA_GRAHAS, SayanadiInputs, ShadbalaInputs, TimeUpagrahaConfig, Upagraha,
+    all_avasthas, all_combustion_status, all_dashavarga_vimsopaka, all_saptavarga_vimsopaka,
+    all_shadbalas_from_inputs, all_shadvarga_vimsopaka, all_shodasavarga_vimsopaka, all_sphutas,
+    amsha_longitude, bhrigu_bindu, calculate_ashtakavarga, charakarakas_from_longitudes,
+    compute_bhavas, default_amsha_variation, dignity_in_rashi_with_positions, ghati_lagna,
+    ghatikas_since_sunrise, graha_drishti, graha_drishti_matrix, hora_lagna,
+    hora_lord as graha_hora_lord, is_valid_amsha_variation, jd_tdb_to_centuries, kala_abda_lord,
+    kala_masa_lord, ketu_from_rahu, lagna_longitude_rad, lost_planetary_war,
+    lunar_node_deg_for_epoch_on_plane, nakshatra_from_longitude, navamsa_number,
+    node_dignity_in_rashi, normalize_360, nth_rashi_from, pranapada_lagna, rashi_from_longitude,
+    rashi_lord_by_index, sree_lagna, sun_based_upagrahas, time_upagraha_jd_with_config,
+    vaar_lord as graha_vaar_lord,
 };
 
 use crate::dasha::{
@@ -50,24 +51,10 @@ use crate::panchang::{
     hora_from_sunrises, masa_for_date, panchang_for_date, varsha_for_date, vedic_day_sunrises,
 };
 use dhruv_search::sankranti_types::SankrantiConfig;
-use dhruv_search::{body_ecliptic_lon_lat, body_lon_lat_on_plane};
+use dhruv_search::{bodies_lon_lat_on_plane, body_ecliptic_lon_lat, body_lon_lat_on_plane};
 
 const OUTER_PLANET_BODIES: [Body; 3] = [Body::Uranus, Body::Neptune, Body::Pluto];
 
-/// Map a Graha to its dhruv_core::Body for engine queries.
-fn graha_to_body(graha: Graha) -> Option<Body> {
-    match graha {
-        Graha::Surya => Some(Body::Sun),
-        Graha::Chandra => Some(Body::Moon),
-        Graha::Mangal => Some(Body::Mars),
-        Graha::Buddh => Some(Body::Mercury),
-        Graha::Guru => Some(Body::Jupiter),
-        Graha::Shukra => Some(Body::Venus),
-        Graha::Shani => Some(Body::Saturn),
-        Graha::Rahu | Graha::Ketu => None,
-    }
-}
-
 /// Convert an ecliptic longitude (e.g. bhava cusp, gulika) to sidereal
 /// on the specified reference plane.
 fn ecliptic_to_sidereal(ecl_lon_deg: f64, aya: f64, plane: ReferencePlane) -> f64 {
@@ -153,13 +140,7 @@ impl JyotishContext {
             let lons = graha_longitudes(
                 engine,
                 self.jd_tdb,
-                &GrahaLongitudesConfig::sidereal_with_model(
-                    aya_config.ayanamsha_system,
-                    aya_config.use_nutation,
-                    aya_config.precession_model,
-                    aya_config.reference_plane,
-                )
-                .with_outer_planets(false),
+                &GrahaLongitudesConfig::from_sankranti_config(aya_config).with_outer_planets(false),
             )?;
             self.graha_lons = Some(lons);
         }
@@ -265,7 +246,12 @@ impl JyotishContext {
         if let Some(speeds) = self.graha_speeds {
             return Ok(speeds);
         }
-        let speeds = query_sapta_graha_speeds(engine, self.jd_tdb)?;
+        let all = dhruv_search::graha_speeds(
+            engine,
+            self.jd_tdb,
+            &dhruv_search::GrahaLongitudesConfig::tropical(false),
+        )?;
+        let speeds = std::array::from_fn(|i| all[i]);
         self.graha_speeds = Some(speeds);
         Ok(speeds)
     }
@@ -291,36 +277,6 @@ impl JyotishContext {
     }
 }
 
-/// Query ecliptic-of-date longitude speed (deg/day) for all 7 sapta grahas.
-///
-/// Uses `body_ecliptic_state` which finite-differences fully-precessed
-/// of-date longitudes to capture the complete velocity frame correction.
-fn query_sapta_graha_speeds(engine: &Engine, jd_tdb: f64) -> Result<[f64; 7], SearchError> {
-    let mut speeds = [0.0f64; 7];
-    for graha in SAPTA_GRAHAS {
-        let body = graha_to_body(graha).expect("sapta graha has body");
-        let (_, _, lon_speed) = body_ecliptic_state(engine, body, jd_tdb)?;
-        speeds[graha.index() as usize] = lon_speed;
-    }
-    Ok(speeds)
-}
-
-fn body_ecliptic_state(
-    engine: &Engine,
-    body: Body,
-    jd_tdb: f64,
-) -> Result<(f64, f64, f64), SearchError> {
-    let (lon, lat) = body_ecliptic_lon_lat(engine, body, jd_tdb)?;
-
-    const DT: f64 = 1.0 / 1440.0;
-    let (lon_plus, _) = body_ecliptic_lon_lat(engine, body, jd_tdb + DT)?;
-    let (lon_minus, _) = body_ecliptic_lon_lat(engine, body, jd_tdb - DT)?;
-    let lon_delta = (lon_plus - lon_minus + 180.0).rem_euclid(360.0) - 180.0;
-    let lon_speed = lon_delta / (2.0 * DT);
-
-    Ok((lon, lat, lon_speed))
-}
-
 /// Query ecliptic declination (deg) for all 7 sapta grahas.
 ///
 /// Declination = arcsin(sin(lat)*cos(eps) + cos(lat)*sin(eps)*sin(lon))
@@ -333,7 +289,7 @@ fn query_sapta_graha_declinations(engine: &Engine, jd_tdb: f64) -> Result<[f64;
     let cos_eps = eps.cos();
     let mut decls = [0.0f64; 7];
     for graha in SAPTA_GRAHAS {
-        let body = graha_to_body(graha).expect("sapta graha has body");
+        let body = graha.to_body().expect("sapta graha has body");
         let (lon_deg, lat_deg) = body_ecliptic_lon_lat(engine, body, jd_tdb)?;
         let lon_rad = lon_deg.to_radians();
         let lat_rad = lat_deg.to_radians();
@@ -356,7 +312,7 @@ fn query_sapta_graha_longitude_only_krantis(
     let sin_eps = eps.sin();
     let mut krantis = [0.0f64; 7];
     for graha in SAPTA_GRAHAS {
-        let body = graha_to_body(graha).expect("sapta graha has body");
+        let body = graha.to_body().expect("sapta graha has body");
         let (lon_deg, _) = body_ecliptic_lon_lat(engine, body, jd_tdb)?;
         let sin_dec = sin_eps * lon_deg.to_radians().sin();
         krantis[graha.index() as usize] = sin_dec.clamp(-1.0, 1.0).asin().to_degrees();
@@ -398,13 +354,7 @@ fn outer_planet_longitudes_for_config(
         0.0
     };
     let aya = if config.kind == GrahaLongitudeKind::Sidereal {
-        dhruv_vedic_base::ayanamsha_deg_on_plane(
-            config.ayanamsha_system,
-            jd_tdb_to_centuries(jd_tdb),
-            config.use_nutation,
-            config.precession_model,
-            config.reference_plane,
-        )
+        config.ayanamsha_deg_at_centuries(jd_tdb_to_centuries(jd_tdb))
     } else {
         0.0
     };
@@ -429,13 +379,7 @@ fn graha_sidereal_longitudes_for_config(
     config: &GrahaLongitudesConfig,
 ) -> Result<GrahaLongitudes, SearchError> {
     let t = jd_tdb_to_centuries(jd_tdb);
-    let aya = dhruv_vedic_base::ayanamsha_deg_on_plane(
-        config.ayanamsha_system,
-        t,
-        config.use_nutation,
-        config.precession_model,
-        config.reference_plane,
-    );
+    let aya = config.ayanamsha_deg_at_centuries(t);
     let rahu_on_plane = lunar_node_deg_for_epoch_on_plane(
         engine,
         LunarNode::Rahu,
@@ -444,32 +388,17 @@ fn graha_sidereal_longitudes_for_config(
         config.precession_model,
         config.reference_plane,
     )?;
-    let ketu_on_plane = normalize(rahu_on_plane + 180.0);
+    let rahu = normalize(rahu_on_plane - aya);
 
     let mut longitudes = [0.0f64; 9];
-
-    for graha in ALL_GRAHAS {
-        let idx = graha.index() as usize;
-        match graha {
-            Graha::Rahu => {
-                longitudes[idx] = normalize(rahu_on_plane - aya);
-            }
-            Graha::Ketu => {
-                longitudes[idx] = normalize(ketu_on_plane - aya);
-            }
-            _ => {
-                let body = graha_to_body(graha).expect("sapta graha has body");
-                let (lon, _lat) = body_lon_lat_on_plane(
-                    engine,
-                    body,
-                    jd_tdb,
-                    config.precession_model,
-                    config.reference_plane,
-                )?;
-                longitudes[idx] = normalize(lon - aya);
-            }
-        }
+    for (idx, lon) in sapta_graha_plane_longitudes(engine, jd_tdb, config)?
+        .into_iter()
+        .enumerate()
+    {
+        longitudes[idx] = normalize(lon - aya);
     }
+    longitudes[Graha::Rahu.index() as usize] = rahu;
+    longitudes[Graha::Ketu.index() as usize] = ketu_from_rahu(rahu);
 
     let outer_planets = if config.include_outer_planets {
         Some(outer_planet_longitudes_for_config(engine, jd_tdb, config)?)
@@ -483,6 +412,24 @@ fn graha_sidereal_longitudes_for_config(
     })
 }
 
+/// Surya..Shani longitudes on `config.reference_plane` (indexed like
+/// `SAPTA_GRAHAS`), from one batched engine query at `jd_tdb`.
+fn sapta_graha_plane_longitudes(
+    engine: &Engine,
+    jd_tdb: f64,
+    config: &GrahaLongitudesConfig,
+) -> Result<[f64; 7], SearchError> {
+    let bodies = SAPTA_GRAHAS.map(|graha| graha.to_body().expect("sapta graha has body"));
+    let lon_lat = bodies_lon_lat_on_plane(
+        engine,
+        &bodies,
+        jd_tdb,
+        config.precession_model,
+        config.reference_plane,
+    )?;
+    Ok(std::array::from_fn(|i| lon_lat[i].0))
+}
+
 fn graha_reference_plane_longitudes(
     engine: &Engine,
     jd_tdb: f64,
@@ -504,27 +451,17 @@ fn graha_reference_plane_longitudes(
         config.precession_model,
         config.reference_plane,
     )?;
-    let ketu_tropical = normalize(rahu_tropical + 180.0);
+    let rahu = normalize(rahu_tropical + dpsi_deg);
 
     let mut longitudes = [0.0f64; 9];
-    for graha in ALL_GRAHAS {
-        let idx = graha.index() as usize;
-        match graha {
-            Graha::Rahu => longitudes[idx] = normalize(rahu_tropical + dpsi_deg),
-            Graha::Ketu => longitudes[idx] = normalize(ketu_tropical + dpsi_deg),
-            _ => {
-                let body = graha_to_body(graha).expect("sapta graha has body");
-                let (lon, _lat) = body_lon_lat_on_plane(
-                    engine,
-                    body,
-                    jd_tdb,
-                    config.precession_model,
-                    config.reference_plane,
-                )?;
-                longitudes[idx] = normalize(lon + dpsi_deg);
-            }
-        }
+    for (idx, lon) in sapta_graha_plane_longitudes(engine, jd_tdb, config)?
+        .into_iter()
+        .enumerate()
+    {
+        longitudes[idx] = normalize(lon + dpsi_deg);
     }
+    longitudes[Graha::Rahu.index() as usize] = rahu;
+    longitudes[Graha::Ketu.index() as usize] = ketu_from_rahu(rahu);
     let outer_planets = if config.include_outer_planets {
         Some(outer_planet_longitudes_for_config(engine, jd_tdb, config)?)
     } else {
@@ -1623,15 +1560,15 @@ fn all_sphuta_lons_with_ctx(
     let eighth_lord_lon = gl.longitude(eighth_lord);
 
     let inputs = dhruv_vedic_base::SphutalInputs {
-        sun: sun_sid,
-        moon: moon_sid,
-        mars: mars_sid,
-        jupiter: jupiter_sid,
-        venus: venus_sid,
-        rahu: rahu_sid,
-        lagna: lagna_sid,
-        eighth_lord: eighth_lord_lon,
-        gulika: gulika_sid,
+        sun: sun_sid.into(),
+        moon: moon_sid.into(),
+        mars: mars_sid.into(),
+        jupiter: jupiter_sid.into(),
+        venus: venus_sid.into(),
+        rahu: rahu_sid.into(),
+        lagna: lagna_sid.into(),
+        eighth_lord: eighth_lord_lon.into(),
+        gulika: gulika_sid.into(),
     };
     let all = all_sphutas(&inputs);
     let mut lons = [0.0f64; 16];
@@ -1942,12 +1879,7 @@ fn assemble_shadbala_inputs(
     }
 
     // 3. Correction-model motion inputs for Cheshta Bala (Surya/Chandra remain 0).
-    let cheshta_config = dhruv_search::GrahaLongitudesConfig::sidereal_with_model(
-        aya_config.ayanamsha_system,
-        aya_config.use_nutation,
-        aya_config.precession_model,
-        aya_config.reference_plane,
-    );
+    let cheshta_config = dhruv_search::GrahaLongitudesConfig::from_sankranti_config(aya_config);
     let cheshta_motions = dhruv_search::jyotish::cheshta_motion_entries(
         engine,
         ctx.jd_tdb,
@@ -2241,8 +2173,9 @@ fn assemble_avastha_inputs(
             node_dignity_in_rashi(graha, rashi_indices[idx], &rashi_indices, node_policy);
     }
 
-    // 6. Combustion
-    let is_combust = all_combustion_status(&sidereal_lons, &is_retrograde);
+    // 6. Combustion (avasthas use the default BPHS Moon orb)
+    let is_combust =
+        all_combustion_status(&sidereal_lons, &is_retrograde, &CombustionConfig::default());
 
     // 7. War detection (indices 2-6 only)
     let mut lost_war = [false; 9];
@@ -2790,21 +2723,3 @@ fn all_special_lagna_lons(s: &AllSpecialLagnas) -> [f64; 8] {
         s.indu_lagna,
     ]
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn graha_to_body_mapping() {
-        assert_eq!(graha_to_body(Graha::Surya), Some(Body::Sun));
-        assert_eq!(graha_to_body(Graha::Chandra), Some(Body::Moon));
-        assert_eq!(graha_to_body(Graha::Mangal), Some(Body::Mars));
-        assert_eq!(graha_to_body(Graha::Buddh), Some(Body::Mercury));
-        assert_eq!(graha_to_body(Graha::Guru), Some(Body::Jupiter));
-        assert_eq!(graha_to_body(Graha::Shukra), Some(Body::Venus));
-        assert_eq!(graha_to_body(Graha::Shani), Some(Body::Saturn));
-        assert_eq!(graha_to_body(Graha::Rahu), None);
-        assert_eq!(graha_to_body(Graha::Ketu), None);
-    }
-}
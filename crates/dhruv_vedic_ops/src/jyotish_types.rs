@@ -2,10 +2,11 @@
 
 use crate::panchang_types::PanchangInfo;
 use dhruv_frames::{DEFAULT_PRECESSION_MODEL, PrecessionModel, ReferencePlane};
+use dhruv_search::sankranti_types::SankrantiConfig;
 use dhruv_time::UtcTime;
 use dhruv_vedic_base::{
     AllGrahaAvasthas, AllSpecialLagnas, AllUpagrahas, Amsha, AshtakavargaResult, AyanamshaSystem,
-    BhavaResult, CharakarakaResult, CharakarakaScheme, Dms, DrishtiEntry, Graha,
+    BhavaResult, CharakarakaResult, CharakarakaScheme, CustomAyanamsha, Dms, DrishtiEntry, Graha,
     GrahaDrishtiMatrix, KalaBalaBreakdown, Nakshatra, NodeDignityPolicy, Rashi, ShadbalaBreakdown,
     SthanaBalaBreakdown, TimeUpagrahaConfig,
 };
@@ -54,10 +55,12 @@ pub enum GrahaLongitudeKind {
 }
 
 /// Configuration for graha longitude computation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GrahaLongitudesConfig {
     pub kind: GrahaLongitudeKind,
     pub ayanamsha_system: AyanamshaSystem,
+    /// User-defined anchor that overrides `ayanamsha_system` when set.
+    pub custom_ayanamsha: Option<CustomAyanamsha>,
     pub use_nutation: bool,
     pub precession_model: PrecessionModel,
     pub reference_plane: ReferencePlane,
@@ -83,6 +86,7 @@ impl GrahaLongitudesConfig {
         Self {
             kind: GrahaLongitudeKind::Sidereal,
             ayanamsha_system,
+            custom_ayanamsha: None,
             use_nutation,
             precession_model,
             reference_plane,
@@ -90,6 +94,20 @@ impl GrahaLongitudesConfig {
         }
     }
 
+    /// Sidereal longitudes using the ayanamsha settings of `aya_config`,
+    /// including its custom anchor.
+    pub fn from_sankranti_config(aya_config: &SankrantiConfig) -> Self {
+        Self {
+            custom_ayanamsha: aya_config.custom_ayanamsha,
+            ..Self::sidereal_with_model(
+                aya_config.ayanamsha_system,
+                aya_config.use_nutation,
+                aya_config.precession_model,
+                aya_config.reference_plane,
+            )
+        }
+    }
+
     pub fn tropical(use_nutation: bool) -> Self {
         Self::tropical_with_model(
             use_nutation,
@@ -106,6 +124,7 @@ impl GrahaLongitudesConfig {
         Self {
             kind: GrahaLongitudeKind::Tropical,
             ayanamsha_system: AyanamshaSystem::Lahiri,
+            custom_ayanamsha: None,
             use_nutation,
             precession_model,
             reference_plane,
@@ -117,6 +136,21 @@ impl GrahaLongitudesConfig {
         self.include_outer_planets = include_outer_planets;
         self
     }
+
+    /// Ayanamsha at `t_centuries` on the configured plane; a custom anchor,
+    /// when set, is always evaluated on the ecliptic.
+    pub fn ayanamsha_deg_at_centuries(&self, t_centuries: f64) -> f64 {
+        if let Some(custom) = self.custom_ayanamsha {
+            return custom.deg_with_model(t_centuries, self.use_nutation, self.precession_model);
+        }
+        dhruv_vedic_base::ayanamsha_deg_on_plane(
+            self.ayanamsha_system,
+            t_centuries,
+            self.use_nutation,
+            self.precession_model,
+            self.reference_plane,
+        )
+    }
 }
 
 impl Default for GrahaLongitudesConfig {
@@ -332,6 +366,8 @@ pub struct ShadbalaEntry {
     pub total_rupas: f64,
     pub required_strength: f64,
     pub is_strong: bool,
+    pub ishta: f64,
+    pub kashta: f64,
 }
 
 impl ShadbalaEntry {
@@ -348,6 +384,8 @@ impl ShadbalaEntry {
             total_rupas: b.total_rupas,
             required_strength: b.required_strength,
             is_strong: b.is_strong,
+            ishta: b.ishta,
+            kashta: b.kashta,
         }
     }
 }
@@ -404,6 +442,8 @@ pub struct DashaSelectionConfig {
     pub yogini_scheme: u8,
     /// Whether to use Abhijit for Ashtottari (1=yes, 0=no).
     pub use_abhijit: u8,
+    /// Dasha year length (0=Julian 365.25, 1=Savana 360, 2=tropical, 3=sidereal).
+    pub year_length: u8,
     /// Optional snapshot time for full-kundali dasha snapshots.
     /// None = skip snapshots, only compute hierarchy.
     pub snapshot_time: Option<DashaSnapshotTime>,
@@ -419,6 +459,7 @@ impl Default for DashaSelectionConfig {
             level_methods: [0xFF; 5],
             yogini_scheme: 0,
             use_abhijit: 1,
+            year_length: 0,
             snapshot_time: None,
         }
     }
@@ -480,6 +521,10 @@ impl DashaSelectionConfig {
             return Err(VedicError::InvalidInput("invalid yogini scheme"));
         }
 
+        if dhruv_vedic_base::dasha::DashaYearLength::from_u8(self.year_length).is_none() {
+            return Err(VedicError::InvalidInput("invalid dasha year length"));
+        }
+
         Ok(())
     }
 
@@ -506,6 +551,8 @@ impl DashaSelectionConfig {
             yogini_scheme: dhruv_vedic_base::dasha::YoginiScheme::from_u8(self.yogini_scheme)
                 .unwrap_or_default(),
             use_abhijit: self.use_abhijit != 0,
+            year_length: dhruv_vedic_base::dasha::DashaYearLength::from_u8(self.year_length)
+                .unwrap_or_default(),
         }
     }
 }
@@ -10,11 +10,12 @@ pub mod panchang_types;
 mod search_util;
 
 pub use dasha::{
-    DashaInputs, dasha_child_period_for_birth, dasha_child_period_with_inputs,
+    DashaInputs, DashaRangePeriod, dasha_child_period_for_birth, dasha_child_period_with_inputs,
     dasha_children_for_birth, dasha_children_with_inputs, dasha_complete_level_for_birth,
     dasha_complete_level_with_inputs, dasha_hierarchy_for_birth, dasha_hierarchy_with_inputs,
     dasha_level0_entity_for_birth, dasha_level0_entity_with_inputs, dasha_level0_for_birth,
     dasha_level0_with_inputs, dasha_snapshot_at, dasha_snapshot_with_inputs,
+    dasha_transitions_in_range, dasha_transitions_with_inputs,
 };
 pub use error::SearchError;
 pub use jyotish::{
@@ -43,8 +44,9 @@ pub use operations::{
 pub use panchang::{
     ayana_for_date, elongation_at, ghatika_for_date, ghatika_from_sunrises, hora_for_date,
     hora_from_sunrises, karana_at, karana_for_date, masa_for_date, moon_sidereal_longitude_at,
-    nakshatra_at, nakshatra_for_date, panchang_for_date, sidereal_sum_at, tithi_at, tithi_for_date,
-    vaar_for_date, vaar_from_sunrises, varsha_for_date, vedic_day_sunrises, yoga_at, yoga_for_date,
+    nakshatra_at, nakshatra_for_date, panchang_for_date, sidereal_sum_at, tithi_at,
+    tithi_day_kind_for_date, tithi_for_date, vaar_for_date, vaar_from_sunrises, varsha_for_date,
+    vedic_day_sunrises, yoga_at, yoga_for_date,
 };
 pub use panchang_types::{
     AyanaInfo, GhatikaInfo, HoraInfo, KaranaInfo, MasaInfo, PanchangInfo, PanchangNakshatraInfo,
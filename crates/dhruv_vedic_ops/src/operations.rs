@@ -1,7 +1,7 @@
 //! Canonical non-search operation APIs shared across wrappers and frontends.
 
 use dhruv_core::Engine;
-use dhruv_frames::SphericalCoords;
+use dhruv_frames::{PrecessionModel, SphericalCoords};
 use dhruv_search::sankranti_types::SankrantiConfig;
 use dhruv_tara::{
     EarthState, EquatorialPosition, TaraCatalog, TaraConfig, TaraError, TaraId,
@@ -9,9 +9,10 @@ use dhruv_tara::{
 };
 use dhruv_time::{EopKernel, UtcTime};
 use dhruv_vedic_base::{
-    AyanamshaSystem, GeoLocation, LunarNode, NodeMode, RiseSetConfig, ayanamsha_deg,
-    ayanamsha_mean_deg, ayanamsha_true_deg, jd_tdb_to_centuries, lunar_node_deg,
-    lunar_node_deg_for_epoch,
+    AyanamshaSystem, CustomAyanamsha, GeoLocation, LunarNode, NodeMode, RiseSetConfig,
+    TithiDayKind, ayanamsha_deg_with_model, ayanamsha_mean_deg_with_model,
+    ayanamsha_rate_deg_per_century, ayanamsha_true_deg_with_model, jd_tdb_to_centuries,
+    lunar_node_deg, lunar_node_deg_for_epoch,
 };
 
 use crate::error::SearchError;
@@ -47,6 +48,8 @@ pub enum AyanamshaMode {
     True,
     /// Unified ayanamsha (`use_nutation` flag controls mean/true behavior).
     Unified,
+    /// Instantaneous mean-ayanamsha rate in degrees per Julian century.
+    Rate,
 }
 
 /// Canonical ayanamsha operation request.
@@ -54,6 +57,8 @@ pub enum AyanamshaMode {
 pub struct AyanamshaOperation {
     /// Ayanamsha system.
     pub system: AyanamshaSystem,
+    /// User-defined anchor that overrides `system` when set.
+    pub custom_ayanamsha: Option<CustomAyanamsha>,
     /// Computation mode selector.
     pub mode: AyanamshaMode,
     /// Epoch as JD TDB.
@@ -62,17 +67,36 @@ pub struct AyanamshaOperation {
     pub use_nutation: bool,
     /// Delta-psi arcseconds used by `True` mode.
     pub delta_psi_arcsec: f64,
+    /// Precession model used to carry the anchor to the epoch.
+    pub precession_model: PrecessionModel,
 }
 
 /// Execute an ayanamsha operation request.
+///
+/// Returns degrees for `Mean`/`True`/`Unified` and degrees per Julian
+/// century for `Rate`.
 pub fn ayanamsha(op: &AyanamshaOperation) -> Result<f64, SearchError> {
     let t = jd_tdb_to_centuries(op.at_jd_tdb);
-    let deg = match op.mode {
-        AyanamshaMode::Mean => ayanamsha_mean_deg(op.system, t),
-        AyanamshaMode::True => ayanamsha_true_deg(op.system, t, op.delta_psi_arcsec),
-        AyanamshaMode::Unified => ayanamsha_deg(op.system, t, op.use_nutation),
+    let model = op.precession_model;
+    if let Some(custom) = op.custom_ayanamsha {
+        return Ok(match op.mode {
+            AyanamshaMode::Mean => custom.mean_deg_with_model(t, model),
+            AyanamshaMode::True => {
+                custom.mean_deg_with_model(t, model) + op.delta_psi_arcsec / 3600.0
+            }
+            AyanamshaMode::Unified => custom.deg_with_model(t, op.use_nutation, model),
+            AyanamshaMode::Rate => custom.rate_deg_per_century(t, model),
+        });
+    }
+    let value = match op.mode {
+        AyanamshaMode::Mean => ayanamsha_mean_deg_with_model(op.system, t, model),
+        AyanamshaMode::True => {
+            ayanamsha_true_deg_with_model(op.system, t, op.delta_psi_arcsec, model)
+        }
+        AyanamshaMode::Unified => ayanamsha_deg_with_model(op.system, t, op.use_nutation, model),
+        AyanamshaMode::Rate => ayanamsha_rate_deg_per_century(op.system, t, model),
     };
-    Ok(deg)
+    Ok(value)
 }
 
 /// Lunar-node backend selector.
@@ -169,6 +193,7 @@ pub struct PanchangOperation {
 #[derive(Debug, Clone, PartialEq)]
 pub struct PanchangResult {
     pub tithi: Option<TithiInfo>,
+    pub tithi_day_kind: Option<TithiDayKind>,
     pub karana: Option<KaranaInfo>,
     pub yoga: Option<YogaInfo>,
     pub vaar: Option<VaarInfo>,
@@ -196,6 +221,7 @@ pub fn panchang(
 
     let mut result = PanchangResult {
         tithi: None,
+        tithi_day_kind: None,
         karana: None,
         yoga: None,
         vaar: None,
@@ -222,6 +248,7 @@ pub fn panchang(
         )?;
         if include(op.include_mask, PANCHANG_INCLUDE_TITHI) {
             result.tithi = Some(full.tithi);
+            result.tithi_day_kind = Some(full.tithi_day_kind);
         }
         if include(op.include_mask, PANCHANG_INCLUDE_KARANA) {
             result.karana = Some(full.karana);
@@ -378,6 +405,7 @@ pub fn tara(catalog: &TaraCatalog, op: &TaraOperation) -> Result<TaraResult, Tar
 #[cfg(test)]
 mod tests {
     use super::*;
+    use dhruv_frames::DEFAULT_PRECESSION_MODEL;
 
     #[test]
     fn query_mode_at_date_is_stable() {
@@ -388,14 +416,47 @@ mod tests {
     fn ayanamsha_mode_is_stable() {
         let op = AyanamshaOperation {
             system: AyanamshaSystem::Lahiri,
+            custom_ayanamsha: None,
             mode: AyanamshaMode::Mean,
             at_jd_tdb: 2_451_545.0,
             use_nutation: false,
             delta_psi_arcsec: 0.0,
+            precession_model: DEFAULT_PRECESSION_MODEL,
         };
         assert!(ayanamsha(&op).is_ok());
     }
 
+    #[test]
+    fn ayanamsha_custom_anchor_overrides_system() {
+        let op = AyanamshaOperation {
+            system: AyanamshaSystem::Lahiri,
+            custom_ayanamsha: Some(CustomAyanamsha::new(20.0, 2_451_545.0)),
+            mode: AyanamshaMode::True,
+            at_jd_tdb: 2_451_545.0,
+            use_nutation: false,
+            delta_psi_arcsec: 36.0,
+            precession_model: DEFAULT_PRECESSION_MODEL,
+        };
+        assert!((ayanamsha(&op).unwrap() - 20.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ayanamsha_rate_mode_uses_precession_model() {
+        let mut op = AyanamshaOperation {
+            system: AyanamshaSystem::KP,
+            custom_ayanamsha: None,
+            mode: AyanamshaMode::Rate,
+            at_jd_tdb: 2_451_545.0,
+            use_nutation: false,
+            delta_psi_arcsec: 0.0,
+            precession_model: PrecessionModel::Newcomb1895,
+        };
+        let newcomb = ayanamsha(&op).unwrap();
+        assert!((newcomb * 36.0 - 50.2786).abs() < 1e-3, "{newcomb}");
+        op.custom_ayanamsha = Some(CustomAyanamsha::new(23.85, 2_451_545.0));
+        assert!((ayanamsha(&op).unwrap() - newcomb).abs() < 1e-6);
+    }
+
     #[test]
     fn node_backend_is_stable() {
         assert_eq!(NodeBackend::Analytic, NodeBackend::Analytic);
@@ -10,11 +10,11 @@ use dhruv_core::{Body, Engine};
 use dhruv_time::{EopKernel, LeapSecondKernel, UtcTime, calendar_to_jd};
 use dhruv_vedic_base::{
     Ayana, GeoLocation, HORA_COUNT, KARANA_SEGMENT_DEG, NAKSHATRA_SPAN_27, Rashi, RiseSetConfig,
-    RiseSetEvent, RiseSetResult, TITHI_SEGMENT_DEG, YOGA_SEGMENT_DEG, approximate_local_noon_jd,
-    ayana_from_sidereal_longitude, compute_rise_set, ghatika_from_elapsed, hora_at,
-    jd_tdb_to_centuries, karana_from_elongation, masa_from_rashi_index, nakshatra_from_longitude,
-    rashi_from_longitude, samvatsara_from_year, tithi_from_elongation, utc_day_start_jd,
-    vaar_from_jd, yoga_from_sum,
+    RiseSetEvent, RiseSetResult, TITHI_SEGMENT_DEG, TithiDayKind, YOGA_SEGMENT_DEG,
+    approximate_local_noon_jd, ayana_from_sidereal_longitude, compute_rise_set,
+    ghatika_from_elapsed, hora_at, jd_tdb_to_centuries, karana_from_elongation,
+    masa_from_rashi_index, nakshatra_from_longitude, rashi_from_longitude, samvatsara_from_year,
+    tithi_day_kind, tithi_from_elongation, utc_day_start_jd, vaar_from_jd, yoga_from_sum,
 };
 
 use crate::error::SearchError;
@@ -168,7 +168,8 @@ pub(crate) fn ayana_for_date_with_eop(
 /// Determine the Varsha (60-year samvatsara cycle position) for a given date.
 ///
 /// The Vedic year starts at Chaitra Pratipada: the first new moon after Mesha Sankranti
-/// (Sun entering sidereal 0 deg / Mesha rashi).
+/// (Sun entering sidereal 0 deg / Mesha rashi). The samvatsara is named per
+/// `config.samvatsara_scheme`.
 pub fn varsha_for_date(
     engine: &Engine,
     utc: &UtcTime,
@@ -212,7 +213,7 @@ pub(crate) fn varsha_for_date_with_eop(
     };
 
     // Use the calendar year of the start to determine the samvatsara
-    let (samvatsara, order) = samvatsara_from_year(actual_start.year);
+    let (samvatsara, order) = samvatsara_from_year(actual_start.year, config.samvatsara_scheme);
 
     Ok(VarshaInfo {
         samvatsara,
@@ -427,6 +428,28 @@ pub fn tithi_at(
     })
 }
 
+/// Classify the Vedic day containing `utc` as a normal, kshaya, or vriddhi
+/// tithi day.
+///
+/// Companion to [`tithi_for_date`]: the day kind needs the sunrise
+/// boundaries, so it takes the location and rise/set config. Compares the
+/// tithi current at the day's sunrise with the one current at the next
+/// sunrise; see [`tithi_day_kind`].
+pub fn tithi_day_kind_for_date(
+    engine: &Engine,
+    eop: &EopKernel,
+    utc: &UtcTime,
+    location: &GeoLocation,
+    riseset_config: &RiseSetConfig,
+) -> Result<TithiDayKind, SearchError> {
+    let (sunrise_jd, next_sunrise_jd) =
+        vedic_day_sunrises(engine, eop, utc, location, riseset_config)?;
+    Ok(tithi_day_kind(
+        elongation_at(engine, sunrise_jd)?,
+        elongation_at(engine, next_sunrise_jd)?,
+    )?)
+}
+
 /// Determine the Karana (half-tithi) for a given date.
 ///
 /// Karana = which of 60 segments of Moon-Sun elongation (6 deg each) the
@@ -771,6 +794,10 @@ pub fn panchang_for_date(
     let (sunrise_jd, next_sunrise_jd) =
         vedic_day_sunrises(engine, eop, utc, location, riseset_config)?;
 
+    let tithi_day_kind = tithi_day_kind(
+        elongation_at(engine, sunrise_jd)?,
+        elongation_at(engine, next_sunrise_jd)?,
+    )?;
     let vaar = vaar_from_sunrises(sunrise_jd, next_sunrise_jd, engine.lsk());
     let hora = hora_from_sunrises(jd, sunrise_jd, next_sunrise_jd, engine.lsk());
     let ghatika = ghatika_from_sunrises(jd, sunrise_jd, next_sunrise_jd, engine.lsk());
@@ -787,6 +814,7 @@ pub fn panchang_for_date(
 
     Ok(PanchangInfo {
         tithi,
+        tithi_day_kind,
         karana,
         yoga,
         vaar,
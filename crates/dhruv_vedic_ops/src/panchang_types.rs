@@ -2,7 +2,7 @@
 
 use dhruv_time::UtcTime;
 use dhruv_vedic_base::{
-    Ayana, Hora, Karana, Masa, Nakshatra, Paksha, Samvatsara, Tithi, Vaar, Yoga,
+    Ayana, Hora, Karana, Masa, Nakshatra, Paksha, Samvatsara, Tithi, TithiDayKind, Vaar, Yoga,
 };
 
 /// Masa (lunar month) classification result.
@@ -141,6 +141,9 @@ pub struct PanchangNakshatraInfo {
 pub struct PanchangInfo {
     /// Tithi (lunar day).
     pub tithi: TithiInfo,
+    /// Whether the Vedic day skips (kshaya) or repeats (vriddhi) a tithi
+    /// between its sunrise and the next.
+    pub tithi_day_kind: TithiDayKind,
     /// Karana (half-tithi).
     pub karana: KaranaInfo,
     /// Yoga (luni-solar yoga).
@@ -44,6 +44,23 @@ fn eval_benchmarks(c: &mut Criterion) {
             kernel.resolve_to_ssb(301, epoch).unwrap()
         });
     });
+
+    // 10k hourly epochs (~14 months) for the Moon: per-epoch resolve
+    // versus a single series traversal with coefficient reuse.
+    let epochs: Vec<f64> = (0..10_000).map(|i| i as f64 * 3600.0).collect();
+
+    c.bench_function("resolve_to_ssb_moon_10k_loop", |b| {
+        b.iter(|| {
+            epochs
+                .iter()
+                .map(|&e| kernel.resolve_to_ssb(301, e).unwrap())
+                .collect::<Vec<_>>()
+        });
+    });
+
+    c.bench_function("resolve_to_ssb_moon_10k_series", |b| {
+        b.iter(|| kernel.resolve_to_ssb_series(301, &epochs).unwrap());
+    });
 }
 
 criterion_group!(benches, eval_benchmarks);
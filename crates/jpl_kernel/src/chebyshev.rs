@@ -77,6 +77,31 @@ pub fn clenshaw_derivative(coeffs: &[f64], s: f64) -> f64 {
     result
 }
 
+/// Evaluate a three-axis Chebyshev segment at time `t`.
+///
+/// `mid` and `radius` are the interval midpoint and half-width in the same
+/// units as `t`; `t` is normalised internally to `s = (t - mid) / radius`.
+/// Returns `(position, velocity)`, where velocity is per unit of `t` (the
+/// chain-rule factor `1 / radius` is applied). This is the evaluator used
+/// for SPK Type 2 records, exposed for user-supplied coefficient sets.
+pub fn eval_position_velocity(
+    coeffs_x: &[f64],
+    coeffs_y: &[f64],
+    coeffs_z: &[f64],
+    mid: f64,
+    radius: f64,
+    t: f64,
+) -> ([f64; 3], [f64; 3]) {
+    let s = (t - mid) / radius;
+    let mut position = [0.0f64; 3];
+    let mut velocity = [0.0f64; 3];
+    for (axis, coeffs) in [coeffs_x, coeffs_y, coeffs_z].into_iter().enumerate() {
+        position[axis] = clenshaw(coeffs, s);
+        velocity[axis] = clenshaw_derivative(coeffs, s) / radius;
+    }
+    (position, velocity)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,4 +178,23 @@ mod tests {
         let at_neg_one = 2.0 - 3.0 + 5.0;
         assert!((clenshaw(&coeffs, -1.0) - at_neg_one).abs() < EPS);
     }
+
+    #[test]
+    fn position_velocity_of_known_polynomial() {
+        // x(t) = 1 + 2s + 3(2s^2 - 1), s = (t - 10) / 4; y = -x; z = 0.
+        let x = [1.0, 2.0, 3.0];
+        let y = [-1.0, -2.0, -3.0];
+        let (mid, radius, t) = (10.0, 4.0, 11.6);
+        let s = (t - mid) / radius;
+        let (pos, vel) = eval_position_velocity(&x, &y, &[], mid, radius, t);
+
+        let expected = 1.0 + 2.0 * s + 3.0 * (2.0 * s * s - 1.0);
+        let expected_d = (2.0 + 12.0 * s) / radius;
+        assert!((pos[0] - expected).abs() < EPS);
+        assert!((pos[1] + expected).abs() < EPS);
+        assert_eq!(pos[2], 0.0);
+        assert!((vel[0] - expected_d).abs() < EPS);
+        assert!((vel[1] + expected_d).abs() < EPS);
+        assert_eq!(vel[2], 0.0);
+    }
 }
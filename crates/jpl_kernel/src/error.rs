@@ -25,6 +25,12 @@ pub enum KernelError {
         center: i32,
         epoch_tdb_s: f64,
     },
+    /// The epoch is inside a segment's time bounds but outside the span of
+    /// its stored states (discrete-state types such as Type 13).
+    EpochInGap { epoch_tdb_s: f64 },
+    /// The center chain from `body` to SSB revisits a body code (or exceeds
+    /// the maximum chain depth).
+    CircularChain { body: i32 },
     /// A segment's internal metadata is inconsistent.
     BadSegmentData(String),
     /// I/O error message (we store the string, not the io::Error, to keep Clone + PartialEq).
@@ -54,6 +60,15 @@ impl Display for KernelError {
                     "epoch {epoch_tdb_s} s out of range for target={target} center={center}"
                 )
             }
+            Self::EpochInGap { epoch_tdb_s } => {
+                write!(
+                    f,
+                    "epoch {epoch_tdb_s} s falls outside stored segment states"
+                )
+            }
+            Self::CircularChain { body } => {
+                write!(f, "circular center chain resolving body {body} to SSB")
+            }
             Self::BadSegmentData(msg) => write!(f, "bad segment data: {msg}"),
             Self::Io(msg) => write!(f, "I/O error: {msg}"),
         }
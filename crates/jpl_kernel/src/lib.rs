@@ -16,7 +16,7 @@ pub mod spk;
 use std::path::Path;
 
 pub use error::KernelError;
-pub use spk::{SpkEvaluation, SpkSegment};
+pub use spk::{SegmentCoverage, SpkEvaluation, SpkSegment};
 
 /// Map a planet body code (x99) to its parent barycenter (x).
 ///
@@ -34,10 +34,64 @@ pub fn planet_body_to_barycenter(code: i32) -> i32 {
     }
 }
 
+/// Maximum number of bodies visited while walking a center chain to SSB.
+///
+/// Real kernels need at most four hops (e.g. 301 → 3 → 0); the cap is a
+/// backstop against malformed chains.
+pub const MAX_CHAIN_DEPTH: usize = 32;
+
+/// Body codes visited by one center-chain walk.
+struct ChainVisits {
+    body: i32,
+    codes: [i32; MAX_CHAIN_DEPTH],
+    len: usize,
+}
+
+impl ChainVisits {
+    fn new(body: i32) -> Self {
+        Self {
+            body,
+            codes: [0; MAX_CHAIN_DEPTH],
+            len: 0,
+        }
+    }
+
+    /// Record `code`, failing if it was already visited or the walk is too deep.
+    fn visit(&mut self, code: i32) -> Result<(), KernelError> {
+        if self.len == MAX_CHAIN_DEPTH || self.codes[..self.len].contains(&code) {
+            return Err(KernelError::CircularChain { body: self.body });
+        }
+        self.codes[self.len] = code;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+/// Backing store for kernel bytes: an owned buffer, or a read-only file
+/// mapping with the `mmap` feature. Parsing and evaluation only see `&[u8]`.
+#[derive(Debug, Clone)]
+enum KernelBytes {
+    Owned(Vec<u8>),
+    #[cfg(feature = "mmap")]
+    Mapped(std::sync::Arc<memmap2::Mmap>),
+}
+
+impl std::ops::Deref for KernelBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Owned(bytes) => bytes,
+            #[cfg(feature = "mmap")]
+            Self::Mapped(map) => map,
+        }
+    }
+}
+
 /// A loaded SPK kernel, ready for evaluation.
 #[derive(Debug, Clone)]
 pub struct SpkKernel {
-    data: Vec<u8>,
+    data: KernelBytes,
     endianness: daf::Endianness,
     segments: Vec<SpkSegment>,
 }
@@ -52,8 +106,28 @@ impl SpkKernel {
         Self::from_bytes(data)
     }
 
+    /// Load an SPK kernel by memory-mapping the file (`mmap` feature).
+    ///
+    /// Only the summary records are read up front; segment data is paged in
+    /// on demand, so large kernels start fast and only touched bodies cost
+    /// memory. Evaluation results are identical to [`Self::load`].
+    ///
+    /// The file must not be truncated or modified while the kernel is alive.
+    #[cfg(feature = "mmap")]
+    pub fn load_mmap(path: &Path) -> Result<Self, KernelError> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: the mapping is read-only and owned by this kernel; callers
+        // are documented to keep the file unmodified while it is mapped.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        Self::from_store(KernelBytes::Mapped(std::sync::Arc::new(map)))
+    }
+
     /// Load an SPK kernel from raw bytes (useful for testing).
     pub fn from_bytes(data: Vec<u8>) -> Result<Self, KernelError> {
+        Self::from_store(KernelBytes::Owned(data))
+    }
+
+    fn from_store(data: KernelBytes) -> Result<Self, KernelError> {
         let file_record = daf::parse_file_record(&data)?;
 
         if file_record.nd != 2 || file_record.ni != 6 {
@@ -81,8 +155,22 @@ impl SpkKernel {
         &self.segments
     }
 
-    /// Find the first segment matching `target` whose center field matches,
-    /// and whose time range covers `epoch_tdb_s`.
+    /// Target, center, time span, and data type of every segment, in file
+    /// order. Nothing is evaluated.
+    pub fn coverage(&self) -> Vec<SegmentCoverage> {
+        self.segments.iter().map(SegmentCoverage::from).collect()
+    }
+
+    /// Whether some segment for `(target, center)` covers `epoch_tdb_s`,
+    /// i.e. whether [`evaluate`](Self::evaluate) can avoid
+    /// [`KernelError::EpochOutOfRange`].
+    pub fn covers(&self, target: i32, center: i32, epoch_tdb_s: f64) -> bool {
+        self.find_segment(target, center, epoch_tdb_s).is_ok()
+    }
+
+    /// Find the segment for `(target, center)` whose time range covers
+    /// `epoch_tdb_s`. When segments overlap, the last one in file order
+    /// wins, as in SPICE.
     fn find_segment(
         &self,
         target: i32,
@@ -91,6 +179,7 @@ impl SpkKernel {
     ) -> Result<&SpkSegment, KernelError> {
         self.segments
             .iter()
+            .rev()
             .find(|seg| {
                 seg.target == target
                     && seg.center == center
@@ -120,6 +209,8 @@ impl SpkKernel {
 
         match segment.data_type {
             2 => spk::evaluate_type2(&self.data, segment, epoch_tdb_s, self.endianness),
+            3 => spk::evaluate_type3(&self.data, segment, epoch_tdb_s, self.endianness),
+            13 => spk::evaluate_type13(&self.data, segment, epoch_tdb_s, self.endianness),
             other => Err(KernelError::UnsupportedDataType(other)),
         }
     }
@@ -141,7 +232,9 @@ impl SpkKernel {
     /// the planet's barycenter (x). This matches DE kernel conventions
     /// where Mars(499)=MarsBary(4), Jupiter(599)=JupiterBary(5), etc.
     ///
-    /// Returns `[x, y, z, vx, vy, vz]` in km and km/s.
+    /// Returns `[x, y, z, vx, vy, vz]` in km and km/s, or
+    /// [`KernelError::CircularChain`] if the chain revisits a body or is
+    /// longer than [`MAX_CHAIN_DEPTH`].
     pub fn resolve_to_ssb(
         &self,
         body_code: i32,
@@ -149,8 +242,10 @@ impl SpkKernel {
     ) -> Result<[f64; 6], KernelError> {
         let mut code = body_code;
         let mut state = [0.0f64; 6];
+        let mut visits = ChainVisits::new(body_code);
 
         while code != 0 {
+            visits.visit(code)?;
             let center = match self.center_for(code) {
                 Some(c) => c,
                 None => {
@@ -181,4 +276,83 @@ impl SpkKernel {
 
         Ok(state)
     }
+
+    /// Resolve a body to SSB at many epochs in one traversal.
+    ///
+    /// The center chain is walked once, then every link is evaluated for
+    /// all epochs. Within a link, the covering segment and the decoded
+    /// Chebyshev record are cached, so adjacent epochs that fall in the
+    /// same interval reuse coefficients instead of re-reading them.
+    ///
+    /// Produces the same states as calling [`Self::resolve_to_ssb`] once
+    /// per epoch. Returns one `[x, y, z, vx, vy, vz]` (km, km/s) per epoch,
+    /// in input order.
+    pub fn resolve_to_ssb_series(
+        &self,
+        body_code: i32,
+        epochs_tdb_s: &[f64],
+    ) -> Result<Vec<[f64; 6]>, KernelError> {
+        let chain = self.center_chain(body_code)?;
+        let mut states = vec![[0.0f64; 6]; epochs_tdb_s.len()];
+
+        for &(target, center) in &chain {
+            let mut current: Option<(&SpkSegment, spk::Type2Cache)> = None;
+
+            for (&epoch_tdb_s, state) in epochs_tdb_s.iter().zip(states.iter_mut()) {
+                // Look the segment up every time: with overlapping segments
+                // the cached one may still cover the epoch but not be the
+                // one that takes precedence.
+                let segment = self.find_segment(target, center, epoch_tdb_s)?;
+                let cache = match current {
+                    Some((seg, ref mut cache)) if std::ptr::eq(seg, segment) => cache,
+                    _ => &mut current.insert((segment, spk::Type2Cache::default())).1,
+                };
+
+                let eval = match segment.data_type {
+                    2 => cache.evaluate(&self.data, segment, epoch_tdb_s, self.endianness)?,
+                    _ => self.evaluate(target, center, epoch_tdb_s)?,
+                };
+                state[0] += eval.position_km[0];
+                state[1] += eval.position_km[1];
+                state[2] += eval.position_km[2];
+                state[3] += eval.velocity_km_s[0];
+                state[4] += eval.velocity_km_s[1];
+                state[5] += eval.velocity_km_s[2];
+            }
+        }
+
+        Ok(states)
+    }
+
+    /// Walk the segment chain from `body_code` to SSB without evaluating,
+    /// returning the `(target, center)` links in order.
+    ///
+    /// Applies the same x99 → barycenter fallback as [`Self::resolve_to_ssb`].
+    fn center_chain(&self, body_code: i32) -> Result<Vec<(i32, i32)>, KernelError> {
+        let mut code = body_code;
+        let mut chain = Vec::new();
+        let mut visits = ChainVisits::new(body_code);
+
+        while code != 0 {
+            visits.visit(code)?;
+            let center = match self.center_for(code) {
+                Some(c) => c,
+                None => {
+                    let bary = planet_body_to_barycenter(code);
+                    if bary != code {
+                        code = bary;
+                        continue;
+                    }
+                    return Err(KernelError::SegmentNotFound {
+                        target: code,
+                        center: -1,
+                    });
+                }
+            };
+            chain.push((code, center));
+            code = center;
+        }
+
+        Ok(chain)
+    }
 }
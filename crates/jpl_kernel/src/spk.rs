@@ -22,6 +22,30 @@ pub struct SpkSegment {
     pub end_addr: i32,
 }
 
+/// Time coverage of one SPK segment, as listed by `SpkKernel::coverage`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentCoverage {
+    pub target: i32,
+    pub center: i32,
+    /// Coverage start, TDB seconds past J2000.0.
+    pub start_tdb_s: f64,
+    /// Coverage end (inclusive), TDB seconds past J2000.0.
+    pub end_tdb_s: f64,
+    pub data_type: i32,
+}
+
+impl From<&SpkSegment> for SegmentCoverage {
+    fn from(seg: &SpkSegment) -> Self {
+        Self {
+            target: seg.target,
+            center: seg.center,
+            start_tdb_s: seg.start_epoch,
+            end_tdb_s: seg.end_epoch,
+            data_type: seg.data_type,
+        }
+    }
+}
+
 /// Result of evaluating an SPK segment at a single epoch.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SpkEvaluation {
@@ -30,6 +54,8 @@ pub struct SpkEvaluation {
 }
 
 /// SPK Type 2 segment descriptor (stored at the end of segment data).
+///
+/// Type 3 segments use the same trailing directory layout.
 #[derive(Debug, Clone, Copy)]
 struct Type2Descriptor {
     init: f64,
@@ -101,20 +127,56 @@ fn read_type2_descriptor(
     })
 }
 
-/// Evaluate an SPK Type 2 (Chebyshev position-only) segment.
-///
-/// Returns position (km) and velocity (km/s) in the segment's reference frame.
-pub fn evaluate_type2(
+/// Stack-allocated coefficient buffer size. DE442s uses 14 coefficients;
+/// 32 covers all foreseeable Type 2 segments without heap allocation.
+const MAX_COEFFS: usize = 32;
+
+/// One decoded Type 2 record: its interval midpoint, half-width, and the
+/// Chebyshev coefficients for the X, Y, Z axes.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Type2Record {
+    record_index: usize,
+    mid: f64,
+    radius: f64,
+    n_coeffs: usize,
+    coeffs: [[f64; MAX_COEFFS]; 3],
+}
+
+impl Type2Record {
+    /// Evaluate position (km) and velocity (km/s) at `epoch_tdb_s`.
+    fn evaluate(&self, epoch_tdb_s: f64) -> SpkEvaluation {
+        let n = self.n_coeffs;
+        let (position_km, velocity_km_s) = chebyshev::eval_position_velocity(
+            &self.coeffs[0][..n],
+            &self.coeffs[1][..n],
+            &self.coeffs[2][..n],
+            self.mid,
+            self.radius,
+            epoch_tdb_s,
+        );
+
+        SpkEvaluation {
+            position_km,
+            velocity_km_s,
+        }
+    }
+}
+
+/// Locate the record covering `epoch_tdb_s` within a Type 2 segment.
+fn type2_record_index(desc: &Type2Descriptor, epoch_tdb_s: f64) -> usize {
+    let record_index = ((epoch_tdb_s - desc.init) / desc.intlen).floor() as usize;
+    record_index.min((desc.n as usize).saturating_sub(1))
+}
+
+/// Read and decode one Type 2 record.
+fn read_type2_record(
     data: &[u8],
     segment: &SpkSegment,
-    epoch_tdb_s: f64,
+    desc: &Type2Descriptor,
+    record_index: usize,
     endian: Endianness,
-) -> Result<SpkEvaluation, KernelError> {
-    let desc = read_type2_descriptor(data, segment, endian)?;
-
-    let n = desc.n as usize;
+) -> Result<Type2Record, KernelError> {
     let rsize = desc.rsize as usize;
-    let intlen = desc.intlen;
 
     if rsize < 3 || !(rsize - 2).is_multiple_of(3) {
         return Err(KernelError::BadSegmentData(format!(
@@ -122,10 +184,11 @@ pub fn evaluate_type2(
         )));
     }
     let n_coeffs = (rsize - 2) / 3;
-
-    // Find the record index.
-    let record_index = ((epoch_tdb_s - desc.init) / intlen).floor() as usize;
-    let record_index = record_index.min(n.saturating_sub(1));
+    if n_coeffs > MAX_COEFFS {
+        return Err(KernelError::BadSegmentData(format!(
+            "n_coeffs {n_coeffs} exceeds MAX_COEFFS {MAX_COEFFS}"
+        )));
+    }
 
     // Byte offset of this record within the file.
     let seg_start_byte = (segment.start_addr as usize - 1) * 8;
@@ -145,34 +208,197 @@ pub fn evaluate_type2(
         return Err(KernelError::BadSegmentData("RADIUS is zero".into()));
     }
 
-    // Normalised time in [-1, 1].
-    let s = (epoch_tdb_s - mid) / radius;
-
-    // Read coefficients for X, Y, Z and evaluate.
+    // Read coefficients for X, Y, Z.
     let coeff_base = record_byte + 16; // skip MID + RADIUS
+    let mut coeffs = [[0.0f64; MAX_COEFFS]; 3];
+    for (axis, buf) in coeffs.iter_mut().enumerate() {
+        let axis_offset = coeff_base + axis * n_coeffs * 8;
+        for (c, slot) in buf[..n_coeffs].iter_mut().enumerate() {
+            *slot = read_f64(data, axis_offset + c * 8, endian);
+        }
+    }
 
-    // Stack-allocate coefficient buffer. DE442s uses 14 coefficients;
-    // 32 covers all foreseeable Type 2 segments without heap allocation.
-    const MAX_COEFFS: usize = 32;
+    Ok(Type2Record {
+        record_index,
+        mid,
+        radius,
+        n_coeffs,
+        coeffs,
+    })
+}
+
+/// Evaluate an SPK Type 2 (Chebyshev position-only) segment.
+///
+/// Returns position (km) and velocity (km/s) in the segment's reference frame.
+pub fn evaluate_type2(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let desc = read_type2_descriptor(data, segment, endian)?;
+    let record_index = type2_record_index(&desc, epoch_tdb_s);
+    let record = read_type2_record(data, segment, &desc, record_index, endian)?;
+    Ok(record.evaluate(epoch_tdb_s))
+}
+
+// ---------------------------------------------------------------------------
+// Type 3 evaluation
+// ---------------------------------------------------------------------------
+
+/// Evaluate an SPK Type 3 (Chebyshev position and velocity) segment.
+///
+/// Each record holds MID, RADIUS, then Chebyshev coefficients for X, Y, Z,
+/// VX, VY, VZ. Velocity comes from its own stored polynomials, not from
+/// differentiating position. Returns position (km) and velocity (km/s) in
+/// the segment's reference frame.
+pub fn evaluate_type3(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let desc = read_type2_descriptor(data, segment, endian)?;
+    let record_index = type2_record_index(&desc, epoch_tdb_s);
+    let rsize = desc.rsize as usize;
+
+    if rsize < 2 || !(rsize - 2).is_multiple_of(6) {
+        return Err(KernelError::BadSegmentData(format!(
+            "invalid RSIZE {rsize}: must satisfy (RSIZE-2) mod 6 == 0"
+        )));
+    }
+    let n_coeffs = (rsize - 2) / 6;
     if n_coeffs > MAX_COEFFS {
         return Err(KernelError::BadSegmentData(format!(
             "n_coeffs {n_coeffs} exceeds MAX_COEFFS {MAX_COEFFS}"
         )));
     }
 
-    let mut position_km = [0.0f64; 3];
-    let mut velocity_km_s = [0.0f64; 3];
+    let seg_start_byte = (segment.start_addr as usize - 1) * 8;
+    let record_byte = seg_start_byte + record_index * rsize * 8;
+    if record_byte + rsize * 8 > data.len() {
+        return Err(KernelError::BadSegmentData(
+            "record extends past end of file".into(),
+        ));
+    }
 
-    for axis in 0..3 {
-        let axis_offset = coeff_base + axis * n_coeffs * 8;
-        let mut buf = [0.0f64; MAX_COEFFS];
+    let mid = read_f64(data, record_byte, endian);
+    let radius = read_f64(data, record_byte + 8, endian);
+    if radius == 0.0 {
+        return Err(KernelError::BadSegmentData("RADIUS is zero".into()));
+    }
+    let s = (epoch_tdb_s - mid) / radius;
+
+    // Components 0-2 are position, 3-5 velocity.
+    let coeff_base = record_byte + 16;
+    let mut state = [0.0f64; 6];
+    let mut buf = [0.0f64; MAX_COEFFS];
+    for (component, value) in state.iter_mut().enumerate() {
+        let offset = coeff_base + component * n_coeffs * 8;
         for (c, slot) in buf[..n_coeffs].iter_mut().enumerate() {
-            *slot = read_f64(data, axis_offset + c * 8, endian);
+            *slot = read_f64(data, offset + c * 8, endian);
         }
-        let coeffs = &buf[..n_coeffs];
+        *value = chebyshev::clenshaw(&buf[..n_coeffs], s);
+    }
 
-        position_km[axis] = chebyshev::clenshaw(coeffs, s);
-        velocity_km_s[axis] = chebyshev::clenshaw_derivative(coeffs, s) / radius;
+    Ok(SpkEvaluation {
+        position_km: [state[0], state[1], state[2]],
+        velocity_km_s: [state[3], state[4], state[5]],
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Type 13 evaluation
+// ---------------------------------------------------------------------------
+
+/// Epochs between Type 13 epoch-directory entries.
+const TYPE13_DIRECTORY_STRIDE: usize = 100;
+
+/// Evaluate an SPK Type 13 (Hermite interpolation, unequal time steps) segment.
+///
+/// Segment layout: N states (x, y, z, vx, vy, vz), N epochs, an epoch
+/// directory holding every 100th epoch, then WINDOW_SIZE - 1 and N. A
+/// window of states around `epoch_tdb_s` is fitted with a Hermite
+/// polynomial per axis (matching both position and velocity at each node);
+/// velocity is the derivative of that polynomial. Epochs before the first
+/// or after the last stored state return [`KernelError::EpochInGap`].
+pub fn evaluate_type13(
+    data: &[u8],
+    segment: &SpkSegment,
+    epoch_tdb_s: f64,
+    endian: Endianness,
+) -> Result<SpkEvaluation, KernelError> {
+    let seg_start_byte = (segment.start_addr as usize - 1) * 8;
+    let end_byte = segment.end_addr as usize * 8;
+    if end_byte > data.len() || end_byte < seg_start_byte + 16 {
+        return Err(KernelError::BadSegmentData(
+            "segment end_addr extends past file".into(),
+        ));
+    }
+    let word = |i: usize| read_f64(data, seg_start_byte + i * 8, endian);
+
+    // N and WINDOW_SIZE - 1 are stored as doubles; bound them by the segment
+    // length before converting so a malformed trailer cannot overflow.
+    let seg_words = (end_byte - seg_start_byte) / 8;
+    let n_raw = read_f64(data, end_byte - 8, endian);
+    let window_raw = read_f64(data, end_byte - 16, endian);
+    let in_range = |x: f64| x.fract() == 0.0 && x >= 1.0 && x <= seg_words as f64;
+    if !in_range(n_raw) || !in_range(window_raw) {
+        return Err(KernelError::BadSegmentData(format!(
+            "invalid Type 13 trailer: N={n_raw}, window size - 1={window_raw}"
+        )));
+    }
+    let n = n_raw as usize;
+    let window = window_raw as usize + 1;
+    let n_dir = n.saturating_sub(1) / TYPE13_DIRECTORY_STRIDE;
+    if 7 * n + n_dir + 2 != seg_words {
+        return Err(KernelError::BadSegmentData(format!(
+            "inconsistent Type 13 layout: N={n}, window={window}, {seg_words} words"
+        )));
+    }
+    let epoch = |i: usize| word(6 * n + i);
+    let dir = |i: usize| word(7 * n + i);
+
+    if epoch_tdb_s < epoch(0) || epoch_tdb_s > epoch(n - 1) {
+        return Err(KernelError::EpochInGap { epoch_tdb_s });
+    }
+
+    // Directory entry i is epoch 100(i+1) - 1, so it narrows the search to
+    // one block of epochs; `near` is the last state at or before the epoch.
+    let block = (0..n_dir).take_while(|&i| dir(i) <= epoch_tdb_s).count();
+    let lo = (block * TYPE13_DIRECTORY_STRIDE).saturating_sub(1);
+    let hi = ((block + 1) * TYPE13_DIRECTORY_STRIDE).min(n);
+    let near = (lo..hi)
+        .take_while(|&i| epoch(i) <= epoch_tdb_s)
+        .last()
+        .unwrap_or(lo);
+
+    // Even windows straddle the epoch; odd windows centre on the nearest state.
+    let window = window.min(n);
+    let first = if window.is_multiple_of(2) {
+        (near + 1).saturating_sub(window / 2)
+    } else {
+        let nearest = if near + 1 < n && epoch(near + 1) - epoch_tdb_s < epoch_tdb_s - epoch(near) {
+            near + 1
+        } else {
+            near
+        };
+        nearest.saturating_sub(window / 2)
+    };
+    let first = first.min(n - window);
+
+    let nodes: Vec<f64> = (first..first + window).map(epoch).collect();
+    let mut position_km = [0.0f64; 3];
+    let mut velocity_km_s = [0.0f64; 3];
+    for axis in 0..3 {
+        let values: Vec<f64> = (first..first + window)
+            .map(|i| word(6 * i + axis))
+            .collect();
+        let slopes: Vec<f64> = (first..first + window)
+            .map(|i| word(6 * i + 3 + axis))
+            .collect();
+        (position_km[axis], velocity_km_s[axis]) =
+            hermite_eval(&nodes, &values, &slopes, epoch_tdb_s);
     }
 
     Ok(SpkEvaluation {
@@ -181,6 +407,84 @@ pub fn evaluate_type2(
     })
 }
 
+/// Hermite interpolation through `(x, y, y')` nodes, evaluated at `t`.
+///
+/// Builds the divided-difference table on doubled nodes and evaluates the
+/// Newton form; returns the value and first derivative.
+fn hermite_eval(xs: &[f64], ys: &[f64], dys: &[f64], t: f64) -> (f64, f64) {
+    let m = 2 * xs.len();
+    let z: Vec<f64> = xs.iter().flat_map(|&x| [x, x]).collect();
+    // `col` holds the current column of the table; coefficients are its heads.
+    let mut col: Vec<f64> = ys.iter().flat_map(|&y| [y, y]).collect();
+    let mut coeffs = Vec::with_capacity(m);
+    coeffs.push(col[0]);
+    for order in 1..m {
+        for k in 0..m - order {
+            col[k] = if order == 1 && k % 2 == 0 {
+                dys[k / 2]
+            } else {
+                (col[k + 1] - col[k]) / (z[k + order] - z[k])
+            };
+        }
+        coeffs.push(col[0]);
+    }
+
+    let mut value = coeffs[m - 1];
+    let mut deriv = 0.0;
+    for j in (0..m - 1).rev() {
+        deriv = deriv * (t - z[j]) + value;
+        value = value * (t - z[j]) + coeffs[j];
+    }
+    (value, deriv)
+}
+
+/// Per-segment Type 2 coefficient cache for evaluating many epochs.
+///
+/// Keeps the descriptor and the most recently decoded record, so that
+/// consecutive epochs falling in the same Chebyshev interval skip the
+/// descriptor read and coefficient decode.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Type2Cache {
+    desc: Option<Type2Descriptor>,
+    record: Option<Type2Record>,
+}
+
+impl Type2Cache {
+    /// Evaluate `segment` at `epoch_tdb_s`, reusing cached coefficients
+    /// when the epoch lies in the same record as the previous call.
+    ///
+    /// The cache must only be used with a single segment; callers reset it
+    /// (via `Default`) when switching segments.
+    pub(crate) fn evaluate(
+        &mut self,
+        data: &[u8],
+        segment: &SpkSegment,
+        epoch_tdb_s: f64,
+        endian: Endianness,
+    ) -> Result<SpkEvaluation, KernelError> {
+        let desc = match self.desc {
+            Some(desc) => desc,
+            None => {
+                let desc = read_type2_descriptor(data, segment, endian)?;
+                self.desc = Some(desc);
+                desc
+            }
+        };
+        let record_index = type2_record_index(&desc, epoch_tdb_s);
+        let record = match &self.record {
+            Some(record) if record.record_index == record_index => record,
+            _ => self.record.insert(read_type2_record(
+                data,
+                segment,
+                &desc,
+                record_index,
+                endian,
+            )?),
+        };
+        Ok(record.evaluate(epoch_tdb_s))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,4 +511,315 @@ mod tests {
         assert_eq!(seg.start_addr, 100);
         assert_eq!(seg.end_addr, 200);
     }
+
+    /// First word of record 4, where synthetic segment data starts.
+    const TEST_START_ADDR: usize = 3 * 128 + 1;
+
+    /// Build a little-endian SPK holding one segment (target -99, center 399)
+    /// with `words` as its data.
+    fn single_segment_kernel(
+        data_type: i32,
+        start: f64,
+        end: f64,
+        words: &[f64],
+    ) -> crate::SpkKernel {
+        crate::SpkKernel::from_bytes(single_segment_bytes(data_type, start, end, words)).unwrap()
+    }
+
+    /// Raw file bytes for [`single_segment_kernel`].
+    fn single_segment_bytes(data_type: i32, start: f64, end: f64, words: &[f64]) -> Vec<u8> {
+        let end_addr = TEST_START_ADDR + words.len() - 1;
+
+        let mut data = vec![0u8; 1024 * 3];
+        data[0..8].copy_from_slice(b"DAF/SPK ");
+        data[8..12].copy_from_slice(&2i32.to_le_bytes());
+        data[12..16].copy_from_slice(&6i32.to_le_bytes());
+        data[76..80].copy_from_slice(&2i32.to_le_bytes());
+        data[80..84].copy_from_slice(&2i32.to_le_bytes());
+        data[88..96].copy_from_slice(b"LTL-IEEE");
+
+        let sum = 1024;
+        data[sum + 16..sum + 24].copy_from_slice(&1.0f64.to_le_bytes());
+        data[sum + 24..sum + 32].copy_from_slice(&start.to_le_bytes());
+        data[sum + 32..sum + 40].copy_from_slice(&end.to_le_bytes());
+        let ints = [
+            -99,
+            399,
+            1,
+            data_type,
+            TEST_START_ADDR as i32,
+            end_addr as i32,
+        ];
+        for (i, v) in ints.iter().enumerate() {
+            let off = sum + 40 + i * 4;
+            data[off..off + 4].copy_from_slice(&v.to_le_bytes());
+        }
+
+        for w in words {
+            data.extend_from_slice(&w.to_le_bytes());
+        }
+        data
+    }
+
+    /// Quadratic trajectory p(t) = A + B t + C t² per axis.
+    const A: [f64; 3] = [1.0e6, -2.0e5, 3.0e4];
+    const B: [f64; 3] = [12.0, -3.5, 0.25];
+    const C: [f64; 3] = [1.0e-3, 2.0e-4, -5.0e-4];
+
+    /// Build a little-endian SPK with one Type 3 segment of two 100 s
+    /// records covering [0, 200] s. Coefficients reproduce the quadratic
+    /// exactly; `velocity_scale` multiplies the stored velocity polynomials.
+    fn type3_kernel(velocity_scale: f64) -> crate::SpkKernel {
+        const N: usize = 3;
+        const RSIZE: usize = 2 + 6 * N;
+
+        let mut words = Vec::new();
+        for mid in [50.0, 150.0] {
+            let r = 50.0;
+            words.extend([mid, r]);
+            for axis in 0..3 {
+                let (a, b, c) = (A[axis], B[axis], C[axis]);
+                words.extend([
+                    a + b * mid + c * mid * mid + c * r * r / 2.0,
+                    r * (b + 2.0 * c * mid),
+                    c * r * r / 2.0,
+                ]);
+            }
+            for axis in 0..3 {
+                let (b, c) = (B[axis], C[axis]);
+                words.extend([
+                    velocity_scale * (b + 2.0 * c * mid),
+                    velocity_scale * 2.0 * c * r,
+                    0.0,
+                ]);
+            }
+        }
+        words.extend([0.0, 100.0, RSIZE as f64, 2.0]);
+        single_segment_kernel(3, 0.0, 200.0, &words)
+    }
+
+    #[test]
+    fn type3_continuous_across_record_boundary() {
+        let kernel = type3_kernel(1.0);
+        let before = kernel.evaluate(-99, 399, 100.0 - 1e-6).unwrap();
+        let after = kernel.evaluate(-99, 399, 100.0 + 1e-6).unwrap();
+        for axis in 0..3 {
+            let pos = A[axis] + B[axis] * 100.0 + C[axis] * 1.0e4;
+            let vel = B[axis] + 2.0 * C[axis] * 100.0;
+            assert!((before.position_km[axis] - pos).abs() < 1e-4);
+            assert!((after.position_km[axis] - pos).abs() < 1e-4);
+            assert!((before.velocity_km_s[axis] - vel).abs() < 1e-8);
+            assert!((after.velocity_km_s[axis] - vel).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn type3_uses_stored_velocity() {
+        // Doubled velocity polynomials must show up as-is, with position unchanged.
+        let plain = type3_kernel(1.0).evaluate(-99, 399, 42.0).unwrap();
+        let scaled = type3_kernel(2.0).evaluate(-99, 399, 42.0).unwrap();
+        assert_eq!(plain.position_km, scaled.position_km);
+        for axis in 0..3 {
+            assert!((scaled.velocity_km_s[axis] - 2.0 * plain.velocity_km_s[axis]).abs() < 1e-12);
+        }
+    }
+
+    /// Cubic trajectory sampled at uneven epochs for Type 13 tests.
+    fn cubic_state(t: f64) -> [f64; 6] {
+        let mut state = [0.0; 6];
+        for axis in 0..3 {
+            let (a, b, c, d) = (A[axis], B[axis], C[axis], 1.0e-6 * (axis as f64 + 1.0));
+            state[axis] = a + b * t + c * t * t + d * t * t * t;
+            state[3 + axis] = b + 2.0 * c * t + 3.0 * d * t * t;
+        }
+        state
+    }
+
+    /// Type 13 segment: `n` states at uneven epochs starting at 10 s, with
+    /// segment bounds [0, last epoch], so [0, 10) is a gap.
+    fn type13_kernel(n: usize, window: usize) -> (crate::SpkKernel, Vec<f64>) {
+        let epochs: Vec<f64> = (0..n)
+            .map(|i| 10.0 + 7.0 * i as f64 + 3.0 * (i % 3) as f64)
+            .collect();
+        let mut words: Vec<f64> = epochs.iter().flat_map(|&t| cubic_state(t)).collect();
+        words.extend(&epochs);
+        words.extend((1..=(n - 1) / 100).map(|k| epochs[100 * k - 1]));
+        words.extend([(window - 1) as f64, n as f64]);
+        let kernel = single_segment_kernel(13, 0.0, epochs[n - 1], &words);
+        (kernel, epochs)
+    }
+
+    #[test]
+    fn type13_reproduces_stored_state_on_node() {
+        let (kernel, epochs) = type13_kernel(250, 4);
+        for &i in &[0, 1, 99, 100, 137, 249] {
+            let eval = kernel.evaluate(-99, 399, epochs[i]).unwrap();
+            let stored = cubic_state(epochs[i]);
+            for axis in 0..3 {
+                assert!((eval.position_km[axis] - stored[axis]).abs() < 1e-9);
+                assert!((eval.velocity_km_s[axis] - stored[3 + axis]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn type13_interpolates_between_nodes() {
+        // A 4-state Hermite window is degree 7, exact for the cubic.
+        let (kernel, epochs) = type13_kernel(250, 4);
+        for t in [epochs[5] + 1.3, epochs[150] + 4.2] {
+            let eval = kernel.evaluate(-99, 399, t).unwrap();
+            let truth = cubic_state(t);
+            for axis in 0..3 {
+                assert!((eval.position_km[axis] - truth[axis]).abs() < 1e-6);
+                assert!((eval.velocity_km_s[axis] - truth[3 + axis]).abs() < 1e-8);
+            }
+        }
+        // Odd window.
+        let (kernel, epochs) = type13_kernel(20, 3);
+        let t = epochs[7] + 2.0;
+        let eval = kernel.evaluate(-99, 399, t).unwrap();
+        assert!((eval.position_km[0] - cubic_state(t)[0]).abs() < 1e-6);
+    }
+
+    #[test]
+    fn type13_epoch_in_gap_errors() {
+        let (kernel, _) = type13_kernel(20, 4);
+        assert_eq!(
+            kernel.evaluate(-99, 399, 5.0),
+            Err(KernelError::EpochInGap { epoch_tdb_s: 5.0 })
+        );
+    }
+
+    #[test]
+    fn type13_malformed_trailer_errors() {
+        let epochs: Vec<f64> = (0..5).map(|i| 10.0 + 5.0 * i as f64).collect();
+        let mut words: Vec<f64> = epochs.iter().flat_map(|&t| cubic_state(t)).collect();
+        words.extend(&epochs);
+        for bad_n in [1.0e30, f64::NAN, -3.0, 2.5] {
+            let mut w = words.clone();
+            w.extend([3.0, bad_n]);
+            let kernel = single_segment_kernel(13, 10.0, epochs[4], &w);
+            assert!(matches!(
+                kernel.evaluate(-99, 399, 12.0),
+                Err(KernelError::BadSegmentData(_))
+            ));
+        }
+        let mut w = words.clone();
+        w.extend([f64::MAX, 5.0]);
+        let kernel = single_segment_kernel(13, 10.0, epochs[4], &w);
+        assert!(matches!(
+            kernel.evaluate(-99, 399, 12.0),
+            Err(KernelError::BadSegmentData(_))
+        ));
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_and_owned_kernels_agree() {
+        let epochs: Vec<f64> = (0..30).map(|i| 10.0 + 5.0 * i as f64).collect();
+        let mut words: Vec<f64> = epochs.iter().flat_map(|&t| cubic_state(t)).collect();
+        words.extend(&epochs);
+        words.extend([3.0, epochs.len() as f64]);
+        let bytes = single_segment_bytes(13, 10.0, epochs[29], &words);
+
+        let path = std::env::temp_dir().join(format!("jpl_kernel_mmap_{}.bsp", std::process::id()));
+        std::fs::write(&path, &bytes).unwrap();
+        let mapped = crate::SpkKernel::load_mmap(&path).unwrap();
+        let owned = crate::SpkKernel::load(&path).unwrap();
+        for t in [10.0, 37.5, 100.0, 155.0] {
+            assert_eq!(
+                mapped.evaluate(-99, 399, t).unwrap(),
+                owned.evaluate(-99, 399, t).unwrap()
+            );
+        }
+        drop(mapped);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// Two Type 2 segments forming a cycle: -99 centered on 399 and
+    /// 399 centered on -99.
+    fn circular_kernel() -> crate::SpkKernel {
+        // One constant record over [0, 200] s, then the Type 2 directory.
+        let words = [100.0, 100.0, 1.0, 2.0, 3.0, 0.0, 200.0, 5.0, 1.0];
+        let mut bytes = single_segment_bytes(2, 0.0, 200.0, &words);
+        let sum = 1024;
+        bytes[sum + 16..sum + 24].copy_from_slice(&2.0f64.to_le_bytes());
+        bytes.copy_within(sum + 24..sum + 64, sum + 64);
+        bytes[sum + 80..sum + 84].copy_from_slice(&399i32.to_le_bytes());
+        bytes[sum + 84..sum + 88].copy_from_slice(&(-99i32).to_le_bytes());
+        crate::SpkKernel::from_bytes(bytes).unwrap()
+    }
+
+    #[test]
+    fn circular_chain_is_rejected() {
+        let kernel = circular_kernel();
+        assert_eq!(kernel.center_for(399), Some(-99));
+        assert_eq!(
+            kernel.resolve_to_ssb(-99, 50.0),
+            Err(KernelError::CircularChain { body: -99 })
+        );
+        assert_eq!(
+            kernel.resolve_to_ssb_series(399, &[50.0, 60.0]),
+            Err(KernelError::CircularChain { body: 399 })
+        );
+    }
+
+    /// Two constant Type 2 segments for -99 centered on the SSB: (1, 2, 3)
+    /// over [0, 200] s, then (4, 5, 6) over [100, 300] s.
+    fn overlapping_kernel() -> crate::SpkKernel {
+        let mut words = vec![100.0, 100.0, 1.0, 2.0, 3.0, 0.0, 200.0, 5.0, 1.0];
+        words.extend([200.0, 100.0, 4.0, 5.0, 6.0, 100.0, 200.0, 5.0, 1.0]);
+        let mut bytes = single_segment_bytes(2, 0.0, 200.0, &words);
+        let sum = 1024;
+        bytes[sum + 16..sum + 24].copy_from_slice(&2.0f64.to_le_bytes());
+        bytes[sum + 44..sum + 48].copy_from_slice(&0i32.to_le_bytes());
+        bytes[sum + 60..sum + 64].copy_from_slice(&((TEST_START_ADDR + 8) as i32).to_le_bytes());
+        bytes.copy_within(sum + 24..sum + 64, sum + 64);
+        bytes[sum + 64..sum + 72].copy_from_slice(&100.0f64.to_le_bytes());
+        bytes[sum + 72..sum + 80].copy_from_slice(&300.0f64.to_le_bytes());
+        bytes[sum + 96..sum + 100].copy_from_slice(&((TEST_START_ADDR + 9) as i32).to_le_bytes());
+        bytes[sum + 100..sum + 104].copy_from_slice(&((TEST_START_ADDR + 17) as i32).to_le_bytes());
+        crate::SpkKernel::from_bytes(bytes).unwrap()
+    }
+
+    #[test]
+    fn overlapping_segments_use_the_last_one() {
+        let kernel = overlapping_kernel();
+        let at = |t: f64| kernel.evaluate(-99, 0, t).unwrap().position_km;
+        assert_eq!(at(50.0), [1.0, 2.0, 3.0]);
+        assert_eq!(at(150.0), [4.0, 5.0, 6.0]);
+        assert_eq!(at(250.0), [4.0, 5.0, 6.0]);
+
+        // The series path must switch segments even while the earlier one
+        // still covers the epoch.
+        let epochs = [50.0, 150.0, 90.0, 250.0];
+        let series = kernel.resolve_to_ssb_series(-99, &epochs).unwrap();
+        for (state, &t) in series.iter().zip(&epochs) {
+            assert_eq!(*state, kernel.resolve_to_ssb(-99, t).unwrap());
+        }
+        assert_eq!(series[1][..3], [4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn coverage_lists_segments_and_covers_checks_bounds() {
+        let kernel = circular_kernel();
+        let coverage = kernel.coverage();
+        assert_eq!(coverage.len(), 2);
+        assert_eq!(
+            coverage[0],
+            SegmentCoverage {
+                target: -99,
+                center: 399,
+                start_tdb_s: 0.0,
+                end_tdb_s: 200.0,
+                data_type: 2,
+            }
+        );
+        assert_eq!((coverage[1].target, coverage[1].center), (399, -99));
+
+        assert!(kernel.covers(-99, 399, 0.0));
+        assert!(kernel.covers(-99, 399, 200.0));
+        assert!(!kernel.covers(-99, 399, 200.5));
+        assert!(!kernel.covers(-99, 10, 100.0));
+    }
 }
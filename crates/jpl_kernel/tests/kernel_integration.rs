@@ -97,3 +97,64 @@ fn resolve_mars_to_ssb() {
         "Mars(499) SSB distance {r:.0} km out of range"
     );
 }
+
+#[test]
+fn resolve_series_matches_individual_calls() {
+    let kernel = match load_kernel() {
+        Some(k) => k,
+        None => return,
+    };
+
+    // 2000 epochs at ~0.37 day spacing, so several consecutive epochs
+    // share a Chebyshev record and the series also crosses record edges.
+    let epochs: Vec<f64> = (0..2000).map(|i| i as f64 * 32_000.0).collect();
+
+    // Moon/Earth: 2-hop chains; Mars 499: barycenter fallback; Sun: 1 hop.
+    for body in [301, 399, 499, 10] {
+        let series = kernel
+            .resolve_to_ssb_series(body, &epochs)
+            .expect("series should resolve");
+        assert_eq!(series.len(), epochs.len());
+        for (epoch, state) in epochs.iter().zip(&series) {
+            let single = kernel
+                .resolve_to_ssb(body, *epoch)
+                .expect("single epoch should resolve");
+            assert_eq!(*state, single, "body {body} mismatch at epoch {epoch}");
+        }
+    }
+}
+
+#[test]
+fn resolve_series_empty_and_out_of_range() {
+    let kernel = match load_kernel() {
+        Some(k) => k,
+        None => return,
+    };
+
+    let empty = kernel.resolve_to_ssb_series(301, &[]).unwrap();
+    assert!(empty.is_empty());
+
+    // One epoch far outside DE442s coverage fails the whole series.
+    let result = kernel.resolve_to_ssb_series(301, &[0.0, 1.0e13]);
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn mmap_matches_owned_load() {
+    let owned = match load_kernel() {
+        Some(k) => k,
+        None => return,
+    };
+    let mapped = SpkKernel::load_mmap(&kernel_path()).expect("should mmap de442s.bsp");
+
+    for body in [301, 399, 499, 10] {
+        for epoch in [0.0, 1.0e8, -3.0e8] {
+            assert_eq!(
+                mapped.resolve_to_ssb(body, epoch).unwrap(),
+                owned.resolve_to_ssb(body, epoch).unwrap(),
+                "body {body} mismatch at epoch {epoch}"
+            );
+        }
+    }
+}
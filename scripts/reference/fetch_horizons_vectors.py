@@ -0,0 +1,131 @@
+#!/usr/bin/env python3
+"""Fetch the Horizons state vectors the Lahiri reference table needs.
+
+Adds geometric ICRF states (km, km/s, TDB) for every body used by
+generate_reference_longitudes.py at epochs spread across the DE442s span
+to testdata/horizons_golden/vectors.json. Cases already present for the same
+(target, observer, epoch) are kept untouched, so the script is idempotent.
+
+After fetching, regenerate the table:
+    python3 scripts/reference/fetch_horizons_vectors.py
+    python3 scripts/reference/generate_reference_longitudes.py \\
+        > crates/dhruv_search/tests/data/reference_longitudes_lahiri.csv
+and restore the comment header of the CSV.
+
+Requires network access to ssd.jpl.nasa.gov; standard library only.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+import time
+import urllib.parse
+import urllib.request
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parents[2]
+VECTORS = ROOT / "testdata" / "horizons_golden" / "vectors.json"
+API = "https://ssd.jpl.nasa.gov/api/horizons.api"
+
+# 1860, 1900, 1950, J2000, 2050, 2100 and 2140 (0h TDB, Jan 1 except J2000);
+# all inside the DE442s coverage of 1849-12-26 .. 2150-01-22.
+EPOCHS = [
+    2400410.5,
+    2415020.5,
+    2433282.5,
+    2451545.0,
+    2469807.5,
+    2488069.5,
+    2502678.5,
+]
+
+# (target, observer, id stem) for every state the generator reads.
+BODIES = [
+    (399, 0, "earth_ssb"),
+    (10, 0, "sun_ssb"),
+    (199, 0, "mercury_ssb"),
+    (299, 0, "venus_ssb"),
+    (301, 399, "moon_earth"),
+    (4, 0, "mars_bary_ssb"),
+    (5, 0, "jupiter_bary_ssb"),
+    (6, 0, "saturn_bary_ssb"),
+]
+
+
+def fetch_state(target: int, observer: int, jd: float):
+    params = {
+        "format": "json",
+        "COMMAND": f"'{target}'",
+        "OBJ_DATA": "'NO'",
+        "MAKE_EPHEM": "'YES'",
+        "EPHEM_TYPE": "'VECTORS'",
+        "CENTER": f"'500@{observer}'",
+        "REF_PLANE": "'FRAME'",
+        "REF_SYSTEM": "'ICRF'",
+        "VEC_TABLE": "'2'",
+        "VEC_CORR": "'NONE'",
+        "OUT_UNITS": "'KM-S'",
+        "CSV_FORMAT": "'YES'",
+        "TIME_TYPE": "'TDB'",
+        "TLIST": f"'{jd}'",
+    }
+    url = f"{API}?{urllib.parse.urlencode(params)}"
+    with urllib.request.urlopen(url, timeout=60) as response:
+        payload = json.loads(response.read().decode("utf-8"))
+    result = payload["result"]
+    try:
+        block = result.split("$$SOE", 1)[1].split("$$EOE", 1)[0]
+    except IndexError:
+        raise RuntimeError(f"no ephemeris for {target} wrt {observer} at {jd}:\n{result}")
+    # CSV row: JDTDB, Calendar Date (TDB), X, Y, Z, VX, VY, VZ,
+    fields = [f.strip() for f in block.strip().splitlines()[0].split(",")]
+    values = [float(f) for f in fields[2:8]]
+    return values[:3], values[3:]
+
+
+def epoch_label(jd: float) -> str:
+    return "j2000" if jd == 2451545.0 else f"{jd:.1f}".replace(".", "_")
+
+
+def main() -> None:
+    data = json.loads(VECTORS.read_text(encoding="utf-8"))
+    cases = data["cases"]
+    have = {
+        (c["target"], c["observer"], c["epoch_tdb_jd"])
+        for c in cases
+        if not c["id"].endswith("_ecliptic")
+    }
+    added = 0
+    for jd in EPOCHS:
+        for target, observer, stem in BODIES:
+            if (target, observer, jd) in have:
+                continue
+            position, velocity = fetch_state(target, observer, jd)
+            cases.append(
+                {
+                    "id": f"{stem}_{epoch_label(jd)}",
+                    "description": f"{stem} state at JD {jd} TDB (reference table)",
+                    "target": target,
+                    "observer": observer,
+                    "epoch_tdb_jd": jd,
+                    "position_km": position,
+                    "velocity_km_s": velocity,
+                    "horizons_source": data["metadata"]["horizons_ephemeris"],
+                }
+            )
+            added += 1
+            time.sleep(0.5)
+    text = json.dumps(data, indent=2)
+    # Keep numeric arrays on one line, as in the hand-frozen cases.
+    text = re.sub(
+        r"\[\s+([-0-9.e+,\s]+?)\s+\]",
+        lambda m: "[" + ", ".join(v.strip() for v in m.group(1).split(",")) + "]",
+        text,
+    )
+    VECTORS.write_text(text + "\n", encoding="utf-8")
+    print(f"added {added} cases")
+
+
+if __name__ == "__main__":
+    main()
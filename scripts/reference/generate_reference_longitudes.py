@@ -0,0 +1,235 @@
+#!/usr/bin/env python3
+"""Generate the Lahiri sidereal reference table from Horizons state vectors.
+
+Independent of the Rust pipeline: reads the frozen JPL Horizons vectors in
+testdata/horizons_golden/vectors.json and applies published constants only.
+
+- Geocentric geometric positions (no light-time or aberration), as in
+  `graha_longitudes`.
+- ICRF -> ecliptic J2000 with the IAU 1976 obliquity 84381.448".
+- Ecliptic J2000 -> ecliptic of date with the IAU 2006 (Capitaine et al.
+  2003) angles pi_A, Pi_A, p_A (Meeus, Astronomical Algorithms, eq. 21.5).
+- Lahiri: 23 deg 15' 00.658" true ayanamsha at 1956-03-21 0h (JD 2435553.5),
+  Indian Astronomical Ephemeris. The nutation in longitude at that epoch
+  (IAU 1980 series, leading terms) is removed to get the mean value, which is
+  carried to other epochs with p_A.
+- True Rahu: ascending node of the osculating geocentric lunar orbit,
+  N = k x (r x v), on the ecliptic of date. Ketu = Rahu + 180.
+
+Only epochs where Horizons vectors exist for every needed body get a value;
+other cells are left empty.
+
+scripts/reference/fetch_horizons_vectors.py adds the vectors for full rows at
+epochs across the DE442s span.
+
+Usage: python3 scripts/reference/generate_reference_longitudes.py
+"""
+
+from __future__ import annotations
+
+import json
+import math
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parents[2]
+VECTORS = ROOT / "testdata" / "horizons_golden" / "vectors.json"
+
+J2000 = 2451545.0
+ARCSEC = math.pi / (180.0 * 3600.0)
+OBLIQUITY_J2000 = 84381.448 * ARCSEC
+
+LAHIRI_EPOCH_JD = 2435553.5
+LAHIRI_TRUE_AT_EPOCH_DEG = 23.0 + 15.0 / 60.0 + 0.658 / 3600.0
+
+EARTH = 399
+SSB = 0
+COLUMNS = "jd_tdb,surya,chandra,mangal,buddh,guru,shukra,shani,rahu,ketu"
+# NAIF codes in graha order: Surya, Chandra, Mangal, Buddh, Guru, Shukra, Shani.
+TARGET_ORDER = [10, 301, 4, 199, 5, 299, 6]
+
+
+def centuries(jd: float) -> float:
+    return (jd - J2000) / 36525.0
+
+
+def icrf_to_ecliptic(v):
+    c, s = math.cos(OBLIQUITY_J2000), math.sin(OBLIQUITY_J2000)
+    return [v[0], c * v[1] + s * v[2], -s * v[1] + c * v[2]]
+
+
+def precession_angles(t: float):
+    """IAU 2006 ecliptic precession angles (radians) at `t` centuries."""
+    pi_a = (
+        46.998973 * t - 0.0334926 * t**2 - 0.00012559 * t**3 + 0.000000113 * t**4
+    ) * ARCSEC
+    big_pi_a = (
+        629546.7936 - 867.95758 * t + 0.157992 * t**2 - 0.0005371 * t**3
+        - 0.00004797 * t**4
+    ) * ARCSEC
+    p_a = (
+        5028.796195 * t + 1.1054348 * t**2 + 0.00007964 * t**3 - 0.000023857 * t**4
+    ) * ARCSEC
+    return pi_a, big_pi_a, p_a
+
+
+def rot_z(v, angle):
+    c, s = math.cos(angle), math.sin(angle)
+    return [c * v[0] + s * v[1], -s * v[0] + c * v[1], v[2]]
+
+
+def rot_x(v, angle):
+    c, s = math.cos(angle), math.sin(angle)
+    return [v[0], c * v[1] + s * v[2], -s * v[1] + c * v[2]]
+
+
+def ecliptic_j2000_to_date(v, t):
+    """R3(-(Pi_A + p_A)) R1(pi_A) R3(Pi_A): ecliptic J2000 -> of date."""
+    pi_a, big_pi_a, p_a = precession_angles(t)
+    return rot_z(rot_x(rot_z(v, big_pi_a), pi_a), -(big_pi_a + p_a))
+
+
+def longitude_deg(v) -> float:
+    return math.degrees(math.atan2(v[1], v[0])) % 360.0
+
+
+def nutation_longitude_arcsec(t: float) -> float:
+    """IAU 1980 nutation in longitude, terms above 0.005"."""
+    d = math.radians(297.85036 + 445267.111480 * t)
+    m = math.radians(357.52772 + 35999.050340 * t)
+    mp = math.radians(134.96298 + 477198.867398 * t)
+    f = math.radians(93.27191 + 483202.017538 * t)
+    om = math.radians(125.04452 - 1934.136261 * t)
+    terms = [
+        # (D, M, M', F, Omega, coeff, t_coeff) in 0.0001"
+        (0, 0, 0, 0, 1, -171996, -174.2),
+        (-2, 0, 0, 2, 2, -13187, -1.6),
+        (0, 0, 0, 2, 2, -2274, -0.2),
+        (0, 0, 0, 0, 2, 2062, 0.2),
+        (0, 1, 0, 0, 0, 1426, -3.4),
+        (0, 0, 1, 0, 0, 712, 0.1),
+        (-2, 1, 0, 2, 2, -517, 1.2),
+        (0, 0, 0, 2, 1, -386, -0.4),
+        (0, 0, 1, 2, 2, -301, 0.0),
+        (-2, -1, 0, 2, 2, 217, -0.5),
+        (-2, 0, 1, 0, 0, -158, 0.0),
+        (-2, 0, 0, 2, 1, 129, 0.1),
+        (0, 0, -1, 2, 2, 123, 0.0),
+        (2, 0, 0, 0, 0, 63, 0.0),
+        (0, 0, 1, 0, 1, 63, 0.1),
+        (2, 0, -1, 2, 2, -59, 0.0),
+        (0, 0, -1, 0, 1, -58, -0.1),
+        (0, 0, 1, 2, 1, -51, 0.0),
+        (-2, 0, 2, 0, 0, 48, 0.0),
+        (0, 0, -2, 2, 1, 46, 0.0),
+        (2, 0, 0, 2, 2, -38, 0.0),
+        (0, 0, 2, 2, 2, -31, 0.0),
+        (0, 0, 2, 0, 0, 29, 0.0),
+        (-2, 0, 1, 2, 2, 29, 0.0),
+        (0, 0, 0, 2, 0, 26, 0.0),
+        (-2, 0, 0, 2, 0, -22, 0.0),
+        (0, 0, -1, 2, 1, 21, 0.0),
+        (0, 2, 0, 0, 0, 17, -0.1),
+        (2, 0, -1, 0, 1, 16, 0.0),
+        (-2, 2, 0, 2, 2, -16, 0.1),
+        (0, 1, 0, 0, 1, -15, 0.0),
+        (-2, 0, 1, 0, 1, -13, 0.0),
+        (0, -1, 0, 0, 1, -12, 0.0),
+        (0, 0, 2, -2, 0, 11, 0.0),
+        (2, 0, -1, 2, 1, -10, 0.0),
+        (2, 0, 1, 2, 2, -8, 0.0),
+        (0, 1, 0, 2, 2, 7, 0.0),
+        (-2, 1, 1, 0, 0, -7, 0.0),
+        (0, -1, 0, 2, 2, -7, 0.0),
+        (2, 0, 0, 2, 1, -7, 0.0),
+        (2, 0, 1, 0, 0, 6, 0.0),
+        (-2, 0, 2, 2, 2, 6, 0.0),
+        (-2, 0, 1, 2, 1, 6, 0.0),
+        (2, 0, -2, 0, 1, -6, 0.0),
+        (2, 0, 0, 0, 1, -6, 0.0),
+    ]
+    total = 0.0
+    for cd, cm, cmp, cf, com, coeff, tcoeff in terms:
+        arg = cd * d + cm * m + cmp * mp + cf * f + com * om
+        total += (coeff + tcoeff * t) * math.sin(arg)
+    return total * 1e-4
+
+
+def lahiri_mean_deg(jd: float) -> float:
+    t0 = centuries(LAHIRI_EPOCH_JD)
+    mean_at_epoch = LAHIRI_TRUE_AT_EPOCH_DEG - nutation_longitude_arcsec(t0) / 3600.0
+    _, _, p_now = precession_angles(centuries(jd))
+    _, _, p_epoch = precession_angles(t0)
+    return mean_at_epoch + math.degrees(p_now - p_epoch)
+
+
+def load_states():
+    cases = json.loads(VECTORS.read_text(encoding="utf-8"))["cases"]
+    states = {}
+    for case in cases:
+        if case["id"].endswith("_ecliptic"):
+            continue
+        key = (case["target"], case["observer"], case["epoch_tdb_jd"])
+        states[key] = (case["position_km"], case["velocity_km_s"])
+    return states
+
+
+def geocentric_position(states, target, jd):
+    if target == 301:
+        state = states.get((301, EARTH, jd))
+        return None if state is None else state[0]
+    body = states.get((target, SSB, jd))
+    earth = states.get((EARTH, SSB, jd))
+    if body is None or earth is None:
+        return None
+    return [b - e for b, e in zip(body[0], earth[0])]
+
+
+def true_rahu_deg(states, jd):
+    state = states.get((301, EARTH, jd))
+    if state is None:
+        return None
+    t = centuries(jd)
+    r = ecliptic_j2000_to_date(icrf_to_ecliptic(state[0]), t)
+    v = ecliptic_j2000_to_date(icrf_to_ecliptic(state[1]), t)
+    h = [
+        r[1] * v[2] - r[2] * v[1],
+        r[2] * v[0] - r[0] * v[2],
+        r[0] * v[1] - r[1] * v[0],
+    ]
+    return math.degrees(math.atan2(h[0], -h[1])) % 360.0
+
+
+def row(states, jd):
+    t = centuries(jd)
+    aya = lahiri_mean_deg(jd)
+    cells = []
+    for target in TARGET_ORDER:
+        pos = geocentric_position(states, target, jd)
+        if pos is None:
+            cells.append(None)
+            continue
+        tropical = longitude_deg(ecliptic_j2000_to_date(icrf_to_ecliptic(pos), t))
+        cells.append((tropical - aya) % 360.0)
+    rahu = true_rahu_deg(states, jd)
+    if rahu is None:
+        cells += [None, None]
+    else:
+        rahu = (rahu - aya) % 360.0
+        cells += [rahu, (rahu + 180.0) % 360.0]
+    if all(cell is None for cell in cells):
+        return None
+    return f"{jd:.1f}," + ",".join("" if c is None else f"{c:.7f}" for c in cells)
+
+
+def main() -> None:
+    states = load_states()
+    epochs = sorted({jd for (_, _, jd) in states})
+    print(COLUMNS)
+    for jd in epochs:
+        line = row(states, jd)
+        if line is not None:
+            print(line)
+
+
+if __name__ == "__main__":
+    main()